@@ -1,23 +1,88 @@
+use crate::app_dir::AppDirs;
+use crate::balance_monitor::{BalanceMonitor, DueTopUp};
+use crate::clipboard_monitor::{ClipboardItem, ClipboardMonitor};
+use crate::config::ConfigError;
 use crate::context::AppContext;
 use crate::database::Database;
+use crate::deep_link::{link_from_args, listen_for_links, DeepLink};
 use crate::logging::initialize_logger;
+use crate::metrics_export::{start_metrics_export, AppMetrics};
+use crate::model::approval::AuditEntry;
+use crate::model::health_check::CheckStatus;
+use crate::model::notification_sound::SoundEvent;
+use crate::model::preferences::DisplayScale;
+use crate::model::price_feed::set_fiat_price;
+use crate::model::rate_limit::{is_transient_error, Endpoint};
+use crate::model::schedule::ScheduledTask;
+use crate::model::session::Session;
+use crate::model::spending_limit::OverLimitAction;
+use crate::model::webhook::{WebhookEvent, WebhookNotification};
+use crate::notification_sound::SoundAlerts;
+use crate::platform::contract::ContractTask;
+use crate::platform::health::HealthTask;
+use crate::platform::identity::{IdentityTask, IdentityTopUpInfo};
+use crate::platform::price::PriceTask;
 use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::request_metrics::RequestMetrics;
+use crate::scheduler::{scheduled_backend_task, Scheduler};
+use crate::ui::components::approval_window::{
+    approval_reason, spending_limit_excess, ApprovalOutcome, ApprovalRequest,
+};
+use crate::ui::components::clipboard_popup::{show_clipboard_popup, ClipboardAction};
+use crate::ui::components::help_overlay::{is_help_open, show_help_overlay, toggle_help};
 use crate::ui::document_query_screen::DocumentQueryScreen;
 use crate::ui::dpns_contested_names_screen::DPNSContestedNamesScreen;
+use crate::ui::dpns_lookup_screen::DpnsLookupScreen;
+use crate::ui::helpers::display_scale::apply_display_scale;
+use crate::ui::helpers::number_format::NumberFormatter;
 use crate::ui::identities::identities_screen::IdentitiesScreen;
+use crate::ui::identities::identity_detail_screen::IdentityDetailScreen;
+use crate::ui::identities::top_up_identity_screen::TopUpIdentityScreen;
 use crate::ui::network_chooser_screen::NetworkChooserScreen;
+use crate::ui::transfers::TransferScreen;
 use crate::ui::transition_visualizer_screen::TransitionVisualizerScreen;
-use crate::ui::{MessageType, RootScreenType, Screen, ScreenLike, ScreenType};
+use crate::ui::{restore_screen, MessageType, RootScreenType, Screen, ScreenLike, ScreenType};
+use crate::webhooks::WebhookAlerts;
 use dash_sdk::dpp::dashcore::Network;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use derive_more::From;
 use eframe::{egui, App};
 use std::collections::BTreeMap;
 use std::ops::BitOrAssign;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use std::vec;
 use tokio::sync::mpsc;
 
+/// A change in the app's data that other screens may need to pick up. Events are published on
+/// the `AppContext` by screens and finished backend tasks, and delivered to every open screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppEvent {
+    /// Contested names or their vote counts were re-queried
+    ContestsUpdated,
+    /// A new local identity was loaded, imported or registered
+    IdentityAdded,
+    /// Local identities changed, e.g. their balances, keys or groups
+    IdentitiesUpdated,
+    VoteSubmitted,
+    VotingBlocsChanged,
+    VoteListsChanged,
+}
+
+/// A request to the app that anything holding an `AppContext` can send, without returning it
+/// up through the screens. Commands are carried out at the end of the frame, in the order sent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppCommand {
+    /// Answers the task from the response cache while its cached result is fresh, or runs it.
+    /// For fetches made when a screen opens, refresh buttons run the task anyway.
+    RunCachedTask(BackendTask),
+    /// Shows a contest, identity or payment request, as a link to it would
+    OpenLink(DeepLink),
+    /// Runs the scheduled task with the id now, whether it is due or not
+    RunScheduledTask(u32),
+}
+
 #[derive(Debug, From)]
 pub enum TaskResult {
     Refresh,
@@ -41,11 +106,43 @@ pub struct AppState {
     pub chosen_network: Network,
     pub mainnet_app_context: Arc<AppContext>,
     pub testnet_app_context: Option<Arc<AppContext>>,
+    pub devnet_app_context: Option<Arc<AppContext>>,
     pub task_result_sender: mpsc::Sender<TaskResult>, // Channel sender for sending task results
     pub task_result_receiver: mpsc::Receiver<TaskResult>, // Channel receiver for receiving task results
     last_repaint: Instant, // Track the last time we requested a repaint
+    last_contract_update_check: Option<Instant>,
+    last_clock_skew_check: Instant,
+    last_price_fetch: Option<Instant>,
+    last_telemetry_attempt: Option<Instant>,
+    metrics: Arc<AppMetrics>,
+    deep_link_receiver: std::sync::mpsc::Receiver<String>, // Links the tool was opened with
+    pending_approval: Option<ApprovalRequest>, // A sensitive task waiting for the approver
+    clipboard_monitor: ClipboardMonitor,
+    clipboard_item: Option<ClipboardItem>, // An identifier copied while the monitor is on
+    session_to_restore: Option<Session>, // The last session, until the user restores or discards it
+    saved_session: Option<Session>,      // What was saved last, to only write changes
+    last_session_save: Instant,
+    applied_display_scale: Option<DisplayScale>, // To only restyle when the preference changes
+    sound_alerts: SoundAlerts,
+    webhook_alerts: WebhookAlerts,
+    balance_monitor: BalanceMonitor,
+    scheduler: Scheduler,
+    pending_top_up_confirmations: Vec<IdentityTopUpInfo>, // First runs of automatic top up rules
+    health_problems_dismissed: bool, // The startup check window is only shown until dismissed
 }
 
+/// How often the cached contracts are compared with their on-chain versions
+const CONTRACT_UPDATE_CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// How often the local clock is compared with the network again
+const CLOCK_SKEW_CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// How soon sending usage statistics is tried again after an attempt
+const TELEMETRY_RETRY_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How often the open screens and unsent votes are saved for restoring after a crash
+const SESSION_SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
 #[derive(Debug, PartialEq)]
 pub enum DesiredAppAction {
     None,
@@ -97,16 +194,76 @@ impl BitOrAssign for AppAction {
     }
 }
 impl AppState {
-    pub fn new() -> Self {
-        initialize_logger();
-        let db = Arc::new(Database::new("identities.db").unwrap());
+    /// Opens the profile whose directories are given. Fails when its configuration file is
+    /// invalid or has no mainnet section.
+    pub fn new(app_dirs: AppDirs) -> Result<Self, ConfigError> {
+        let app_dirs = Arc::new(app_dirs);
+        let request_metrics = Arc::new(RequestMetrics::default());
+        initialize_logger(
+            &app_dirs.log_path(),
+            &app_dirs.logs_dir(),
+            request_metrics.clone(),
+        );
+        tracing::info!(
+            "Using data directory {} (profile: {}, portable: {})",
+            app_dirs.data_dir.display(),
+            app_dirs.profile,
+            app_dirs.portable
+        );
+        let db = Arc::new(Database::new(app_dirs.database_path()).unwrap());
         db.initialize().unwrap();
+        if let Err(e) = db.interrupt_scheduled_runs() {
+            tracing::warn!("Failed to end the interrupted scheduled runs: {}", e);
+        }
 
         let settings = db.get_settings().expect("expected to get settings");
+        let preferences = Arc::new(RwLock::new(db.get_preferences().unwrap_or_default()));
 
-        let mainnet_app_context =
-            AppContext::new(Network::Dash, db.clone()).expect("expected Dash config for mainnet");
-        let testnet_app_context = AppContext::new(Network::Testnet, db.clone());
+        // Fiat values use the last cached price until the price feed answers
+        let currency = preferences.read().unwrap().price_feed.currency.clone();
+        if let Ok(Some(price)) = db.get_fiat_price(&currency) {
+            set_fiat_price(price);
+        }
+
+        let metrics = Arc::new(AppMetrics::default());
+        let mainnet_app_context = AppContext::new(
+            Network::Dash,
+            db.clone(),
+            preferences.clone(),
+            request_metrics.clone(),
+            metrics.clone(),
+            app_dirs.clone(),
+        )?
+        .ok_or_else(|| ConfigError::MissingNetwork {
+            path: app_dirs.config_path().display().to_string(),
+            section: "mainnet".to_string(),
+        })?;
+        let testnet_app_context = AppContext::new(
+            Network::Testnet,
+            db.clone(),
+            preferences.clone(),
+            request_metrics.clone(),
+            metrics.clone(),
+            app_dirs.clone(),
+        )?;
+        let devnet_app_context = AppContext::new(
+            Network::Devnet,
+            db.clone(),
+            preferences.clone(),
+            request_metrics.clone(),
+            metrics.clone(),
+            app_dirs,
+        )?;
+
+        start_metrics_export(
+            &preferences.read().unwrap().metrics_export,
+            metrics.clone(),
+            request_metrics,
+            std::iter::once(mainnet_app_context.clone())
+                .chain(testnet_app_context.clone())
+                .chain(devnet_app_context.clone())
+                .collect(),
+        );
 
         let mut identities_screen = IdentitiesScreen::new(&mainnet_app_context);
         let mut dpns_contested_names_screen = DPNSContestedNamesScreen::new(&mainnet_app_context);
@@ -116,6 +273,7 @@ impl AppState {
         let mut network_chooser_screen = NetworkChooserScreen::new(
             &mainnet_app_context,
             testnet_app_context.as_ref(),
+            devnet_app_context.as_ref(),
             Network::Dash,
         );
 
@@ -125,13 +283,18 @@ impl AppState {
 
         if let Some((network, screen_type)) = settings {
             selected_main_screen = screen_type;
-            chosen_network = network;
-            if network == Network::Testnet && testnet_app_context.is_some() {
-                let testnet_app_context = testnet_app_context.as_ref().unwrap();
-                identities_screen = IdentitiesScreen::new(testnet_app_context);
-                dpns_contested_names_screen = DPNSContestedNamesScreen::new(testnet_app_context);
-                transition_visualizer_screen = TransitionVisualizerScreen::new(testnet_app_context);
-                document_query_screen = DocumentQueryScreen::new(testnet_app_context);
+            // A network that is no longer configured falls back to mainnet
+            let saved_app_context = match network {
+                Network::Testnet => testnet_app_context.as_ref(),
+                Network::Devnet => devnet_app_context.as_ref(),
+                _ => None,
+            };
+            if let Some(app_context) = saved_app_context {
+                chosen_network = network;
+                identities_screen = IdentitiesScreen::new(app_context);
+                dpns_contested_names_screen = DPNSContestedNamesScreen::new(app_context);
+                transition_visualizer_screen = TransitionVisualizerScreen::new(app_context);
+                document_query_screen = DocumentQueryScreen::new(app_context);
             }
             network_chooser_screen.current_network = chosen_network;
         }
@@ -142,7 +305,14 @@ impl AppState {
         // Initialize the last repaint time to the current instant
         let last_repaint = Instant::now();
 
-        Self {
+        // Links come from the command line at startup and from later launches afterwards
+        let (deep_link_sender, deep_link_receiver) = std::sync::mpsc::channel();
+        if let Some(link) = link_from_args() {
+            deep_link_sender.send(link).ok();
+        }
+        listen_for_links(deep_link_sender);
+
+        let mut app_state = Self {
             main_screens: [
                 (
                     RootScreenType::RootScreenIdentities,
@@ -171,29 +341,122 @@ impl AppState {
             chosen_network,
             mainnet_app_context,
             testnet_app_context,
+            devnet_app_context,
             task_result_sender,
             task_result_receiver,
             last_repaint,
+            last_contract_update_check: None,
+            last_clock_skew_check: Instant::now(), // Measured by the startup health checks
+            last_price_fetch: None,
+            last_telemetry_attempt: None,
+            metrics,
+            deep_link_receiver,
+            pending_approval: None,
+            clipboard_monitor: ClipboardMonitor::default(),
+            clipboard_item: None,
+            session_to_restore: None,
+            saved_session: None,
+            last_session_save: Instant::now(),
+            applied_display_scale: None,
+            sound_alerts: SoundAlerts::default(),
+            webhook_alerts: WebhookAlerts::default(),
+            balance_monitor: BalanceMonitor::default(),
+            scheduler: Scheduler::default(),
+            pending_top_up_confirmations: Vec::new(),
+            health_problems_dismissed: false,
+        };
+        let app_context = app_state.current_app_context().clone();
+        app_state.session_to_restore = app_context
+            .db
+            .get_session(&app_context)
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to load the last session: {}", e);
+                None
+            })
+            .filter(|session| !session.is_empty());
+        app_state.handle_background_task(BackendTask::HealthTask(HealthTask::RunChecks));
+        Ok(app_state)
+    }
+
+    /// The context of a network, if the network is configured
+    pub fn app_context_for(&self, network: Network) -> Option<&Arc<AppContext>> {
+        match network {
+            Network::Dash => Some(&self.mainnet_app_context),
+            Network::Testnet => self.testnet_app_context.as_ref(),
+            Network::Devnet => self.devnet_app_context.as_ref(),
+            _ => None,
         }
     }
 
     pub fn current_app_context(&self) -> &Arc<AppContext> {
-        match self.chosen_network {
-            Network::Dash => &self.mainnet_app_context,
-            Network::Testnet => self.testnet_app_context.as_ref().expect("expected testnet"),
-            Network::Devnet => todo!(),
-            Network::Regtest => todo!(),
-            _ => todo!(),
+        // Only configured networks can be chosen
+        self.app_context_for(self.chosen_network)
+            .unwrap_or(&self.mainnet_app_context)
+    }
+
+    /// Runs the task, or blocks it or holds it back for the approver according to the spending
+    /// limits and the approval policy
+    pub fn handle_backend_task(&mut self, task: BackendTask) {
+        let app_context = self.current_app_context().clone();
+        let preferences = app_context.preferences();
+        let number_format = NumberFormatter::new(&preferences);
+        let mut reasons = Vec::new();
+        if let Some(excess) = spending_limit_excess(&task, &app_context, &number_format) {
+            if preferences.spending_limits.over_limit == OverLimitAction::Block {
+                let entry = AuditEntry {
+                    action: excess.clone(),
+                    outcome: "Blocked by the daily limit".to_string(),
+                    approver: None,
+                    logged_at: chrono::Utc::now().timestamp(),
+                };
+                if let Err(e) = app_context.db.insert_audit_entry(&entry, &app_context) {
+                    tracing::error!("Failed to write the audit trail: {}", e);
+                }
+                self.visible_screen_mut()
+                    .display_message(&excess, MessageType::Error);
+                return;
+            }
+            reasons.push(excess);
+        }
+        reasons.extend(approval_reason(
+            &task,
+            &preferences.approval_policy,
+            &number_format,
+        ));
+        if reasons.is_empty() {
+            self.start_backend_task(task);
+        } else {
+            self.pending_approval = Some(ApprovalRequest::new(task, reasons.join("\n")));
         }
     }
 
     // Handle the backend task and send the result through the channel
-    pub fn handle_backend_task(&self, task: BackendTask) {
+    fn start_backend_task(&mut self, task: BackendTask) {
         let sender = self.task_result_sender.clone();
         let app_context = self.current_app_context().clone();
+        let metrics = self.metrics.clone();
+
+        // Identical queries are coalesced, tell the user instead of starting another one
+        let ticket = match app_context.task_gate.try_start(&task) {
+            Ok(ticket) => ticket,
+            Err(rejection) => {
+                self.visible_screen_mut()
+                    .display_message(&rejection.to_string(), MessageType::Info);
+                return;
+            }
+        };
 
         tokio::spawn(async move {
-            let result = app_context.run_backend_task(task, sender.clone()).await;
+            let kind = task.kind();
+            let events = task.events_on_success();
+            let result = run_dispatched(&app_context, task, sender.clone()).await;
+            drop(ticket);
+            metrics.record_task(kind, &result);
+            if result.is_ok() {
+                events
+                    .into_iter()
+                    .for_each(|event| app_context.publish(event));
+            }
 
             // Send the result back to the main thread
             if let Err(e) = sender.send(result.into()).await {
@@ -202,6 +465,80 @@ impl AppState {
         });
     }
 
+    /// Runs a backend task without reporting its result to the visible screen
+    pub fn handle_background_task(&self, task: BackendTask) {
+        self.spawn_background_task(task, |_| {});
+    }
+
+    /// Runs a backend task in the background and hands its result to `on_finished`. Returns
+    /// false without running it when the same task is already running.
+    fn spawn_background_task(
+        &self,
+        task: BackendTask,
+        on_finished: impl FnOnce(&Result<BackendTaskSuccessResult, String>) + Send + 'static,
+    ) -> bool {
+        let sender = self.task_result_sender.clone();
+        let app_context = self.current_app_context().clone();
+
+        let metrics = self.metrics.clone();
+
+        let Ok(ticket) = app_context.task_gate.try_start(&task) else {
+            return false;
+        };
+
+        tokio::spawn(async move {
+            let kind = task.kind();
+            let events = task.events_on_success();
+            let result = run_dispatched(&app_context, task, sender).await;
+            drop(ticket);
+            metrics.record_task(kind, &result);
+            on_finished(&result);
+            if result.is_ok() {
+                events
+                    .into_iter()
+                    .for_each(|event| app_context.publish(event));
+            }
+            if let Err(e) = result {
+                tracing::warn!("Background task failed: {}", e);
+            }
+        });
+        true
+    }
+
+    /// Starts a scheduled task in the background and records how its run ends
+    fn run_scheduled_task(&self, scheduled: ScheduledTask) {
+        let app_context = self.current_app_context().clone();
+        let record = move |error: Option<&str>| {
+            if let Err(e) = app_context
+                .db
+                .finish_scheduled_run(scheduled.id, error, &app_context)
+            {
+                tracing::warn!("Failed to record the scheduled run: {}", e);
+            }
+        };
+        if let Err(e) = self.current_app_context().db.start_scheduled_run(
+            scheduled.id,
+            chrono::Utc::now().timestamp(),
+            self.current_app_context(),
+        ) {
+            tracing::warn!("Failed to record the scheduled run: {}", e);
+            return;
+        }
+        let task = match scheduled_backend_task(&scheduled.job, self.current_app_context()) {
+            Ok(task) => task,
+            Err(e) => {
+                record(Some(&e));
+                return;
+            }
+        };
+        let recorder = record.clone();
+        if !self.spawn_background_task(task, move |result| {
+            recorder(result.as_ref().err().map(String::as_str))
+        }) {
+            record(Some("Skipped, the same task was already running"));
+        }
+    }
+
     pub fn active_root_screen(&self) -> &Screen {
         self.main_screens
             .get(&self.selected_main_screen)
@@ -215,6 +552,18 @@ impl AppState {
     }
 
     pub fn change_network(&mut self, network: Network) {
+        if self.app_context_for(network).is_none() {
+            let message = match network {
+                Network::Testnet => "Testnet isn't configured, add it in the network settings",
+                Network::Devnet => {
+                    "No devnet is configured, add a [devnet] section to the configuration file"
+                }
+                _ => "This network isn't supported",
+            };
+            self.visible_screen_mut()
+                .display_message(message, MessageType::Error);
+            return;
+        }
         self.chosen_network = network;
         let app_context = self.current_app_context().clone();
         for screen in self.main_screens.values_mut() {
@@ -238,6 +587,470 @@ impl AppState {
         }
     }
 
+    /// Carries out the commands sent on the current network's context
+    fn run_commands(&mut self) {
+        for command in self.current_app_context().take_commands() {
+            match command {
+                AppCommand::RunCachedTask(task) => {
+                    match cached_result(self.current_app_context(), &task) {
+                        Some(result) => {
+                            if let Err(e) = self
+                                .task_result_sender
+                                .try_send(TaskResult::Success(result))
+                            {
+                                eprintln!("Failed to send cached task result: {}", e);
+                            }
+                        }
+                        None => self.handle_backend_task(task),
+                    }
+                }
+                AppCommand::OpenLink(link) => self.open_link(link),
+                AppCommand::RunScheduledTask(id) => {
+                    let scheduled = self
+                        .current_app_context()
+                        .scheduled_tasks()
+                        .into_iter()
+                        .find(|scheduled| scheduled.id == id);
+                    if let Some(scheduled) = scheduled {
+                        self.run_scheduled_task(scheduled);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Delivers the events published on any network to every open screen
+    fn deliver_events(&mut self) {
+        let mut events = self.mainnet_app_context.take_events();
+        for app_context in [&self.testnet_app_context, &self.devnet_app_context]
+            .into_iter()
+            .flatten()
+        {
+            events.extend(app_context.take_events());
+        }
+        if events
+            .iter()
+            .any(|event| matches!(event, AppEvent::IdentitiesUpdated | AppEvent::IdentityAdded))
+        {
+            let app_context = self.current_app_context().clone();
+            let dropped = self.balance_monitor.check_balances(&app_context);
+            if !dropped.is_empty() {
+                let sounds = app_context.preferences().notification_sounds;
+                self.sound_alerts.play(SoundEvent::LowBalance, &sounds);
+            }
+            let number_format = NumberFormatter::new(&app_context.preferences());
+            for (qualified_identity, minimum) in dropped {
+                let balance = qualified_identity.identity.balance();
+                app_context.notify_webhooks(
+                    WebhookNotification::new(
+                        WebhookEvent::BalanceBelowMinimum,
+                        format!(
+                            "{} has {}, below its minimum of {}",
+                            qualified_identity.display_string(),
+                            number_format.format_credits_as_dash(balance),
+                            number_format.format_credits_as_dash(minimum)
+                        ),
+                    )
+                    .with_field("identity", qualified_identity.display_string())
+                    .with_field("balance", balance)
+                    .with_field("minimum", minimum),
+                );
+            }
+            for due in self.balance_monitor.due_top_ups(&app_context) {
+                match due {
+                    DueTopUp::Run(top_up_info) => self.handle_background_task(
+                        BackendTask::IdentityTask(IdentityTask::AutoTopUpIdentity(top_up_info)),
+                    ),
+                    DueTopUp::Confirm(top_up_info) => {
+                        let identity_id = top_up_info.qualified_identity.identity.id();
+                        if !self
+                            .pending_top_up_confirmations
+                            .iter()
+                            .any(|pending| pending.qualified_identity.identity.id() == identity_id)
+                        {
+                            self.pending_top_up_confirmations.push(top_up_info);
+                        }
+                    }
+                }
+            }
+        }
+        for event in events {
+            for screen in self.main_screens.values_mut() {
+                screen.on_event(event);
+            }
+            for screen in self.screen_stack.iter_mut() {
+                screen.on_event(event);
+            }
+        }
+    }
+
+    /// Shows the contest or identity a link points to
+    fn open_deep_link(&mut self, uri: &str) {
+        match DeepLink::parse(uri) {
+            Ok(link) => self.open_link(link),
+            Err(e) => self
+                .visible_screen_mut()
+                .display_message(&e, MessageType::Error),
+        }
+    }
+
+    fn open_link(&mut self, link: DeepLink) {
+        match link {
+            DeepLink::Contest(name) => {
+                self.screen_stack = vec![];
+                self.selected_main_screen = RootScreenType::RootScreenDPNSContestedNames;
+                if let Screen::DPNSContestedNamesScreen(screen) = self.active_root_screen_mut() {
+                    screen.refresh_on_arrival();
+                    screen.open_contest(name);
+                }
+            }
+            DeepLink::Identity(identity_id) => {
+                let screen = IdentityDetailScreen::new(identity_id, self.current_app_context());
+                self.screen_stack.push(Screen::IdentityDetailScreen(screen));
+            }
+            DeepLink::Payment(request) => {
+                let app_context = self.current_app_context().clone();
+                let payer = app_context
+                    .load_local_qualified_identities()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|identity| !identity.available_transfer_keys().is_empty());
+                match payer {
+                    Some(payer) => {
+                        let screen =
+                            TransferScreen::new(payer, &app_context).with_payment_request(request);
+                        self.screen_stack.push(Screen::TransferScreen(screen));
+                    }
+                    None => self.visible_screen_mut().display_message(
+                        "Paying a request needs an identity with a transfer key loaded",
+                        MessageType::Error,
+                    ),
+                }
+            }
+        }
+    }
+
+    /// The open screens and unsent votes, as saved for restoring on the next launch
+    fn current_session(&self) -> Session {
+        let votes = match self
+            .main_screens
+            .get(&RootScreenType::RootScreenDPNSContestedNames)
+        {
+            Some(Screen::DPNSContestedNamesScreen(screen)) => screen.saved_votes(),
+            _ => Default::default(),
+        };
+        Session {
+            screens: self
+                .screen_stack
+                .iter()
+                .filter_map(|screen| screen.to_saved())
+                .collect(),
+            votes,
+        }
+    }
+
+    /// Saves the session every few seconds when it changed. Nothing is saved while the last
+    /// session still waits to be restored or discarded.
+    fn autosave_session(&mut self) {
+        if self.session_to_restore.is_some()
+            || self.last_session_save.elapsed() < SESSION_SAVE_INTERVAL
+        {
+            return;
+        }
+        self.last_session_save = Instant::now();
+        let session = self.current_session();
+        if self.saved_session.as_ref() == Some(&session) {
+            return;
+        }
+        let app_context = self.current_app_context().clone();
+        match app_context.db.save_session(&session, &app_context) {
+            Ok(()) => self.saved_session = Some(session),
+            Err(e) => tracing::warn!("Failed to save the session: {}", e),
+        }
+    }
+
+    fn restore_session(&mut self, session: Session) {
+        let app_context = self.current_app_context().clone();
+        let identities = app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default();
+        let screens: Vec<Screen> = session
+            .screens
+            .into_iter()
+            .filter_map(|saved| restore_screen(saved, &identities, &app_context))
+            .collect();
+        self.screen_stack.extend(screens);
+        if let Some(Screen::DPNSContestedNamesScreen(screen)) = self
+            .main_screens
+            .get_mut(&RootScreenType::RootScreenDPNSContestedNames)
+        {
+            screen.restore_votes(session.votes);
+        }
+    }
+
+    /// Offers to bring back what was open when the tool last ran
+    fn show_session_restore(&mut self, ctx: &egui::Context) {
+        let Some(session) = &self.session_to_restore else {
+            return;
+        };
+        let mut restore = false;
+        let mut discard = false;
+        egui::Window::new("Restore Last Session")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "The tool was closed with {}. Do you want to pick up where you left off?",
+                    session.summary()
+                ));
+                ui.horizontal(|ui| {
+                    restore = ui.button("Restore").clicked();
+                    discard = ui.button("Start fresh").clicked();
+                });
+            });
+        if restore {
+            if let Some(session) = self.session_to_restore.take() {
+                self.restore_session(session);
+            }
+        } else if discard {
+            self.session_to_restore = None;
+            let app_context = self.current_app_context().clone();
+            if let Err(e) = app_context.db.clear_session(&app_context) {
+                tracing::warn!("Failed to clear the last session: {}", e);
+            }
+        }
+    }
+
+    /// Lists what the startup checks found wrong, until the user dismisses it
+    fn show_health_problems(&mut self, ctx: &egui::Context) {
+        if self.health_problems_dismissed {
+            return;
+        }
+        let app_context = self.current_app_context().clone();
+        let Some(report) = app_context.health_report() else {
+            return;
+        };
+        if !report.has_problems() {
+            return;
+        }
+        let mut open_diagnostics = false;
+        let mut dismiss = false;
+        egui::Window::new("Startup Check Found Problems")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                for check in report.problems() {
+                    let color = match check.status {
+                        CheckStatus::Failed => egui::Color32::RED,
+                        _ => egui::Color32::from_rgb(200, 150, 0),
+                    };
+                    ui.colored_label(color, format!("{}: {}", check.name, check.status.label()));
+                    ui.label(&check.detail);
+                    if let Some(suggestion) = &check.suggestion {
+                        ui.label(egui::RichText::new(suggestion).italics());
+                    }
+                    ui.add_space(5.0);
+                }
+                ui.horizontal(|ui| {
+                    open_diagnostics = ui.button("Open diagnostics").clicked();
+                    dismiss = ui.button("Dismiss").clicked();
+                });
+            });
+        if open_diagnostics {
+            self.screen_stack
+                .push(ScreenType::Diagnostics.create_screen(&app_context));
+        }
+        self.health_problems_dismissed = open_diagnostics || dismiss;
+    }
+
+    /// Lists identities that dropped below their minimum balance, each with a way to top it up
+    fn show_low_balance_alerts(&mut self, ctx: &egui::Context) {
+        if self.balance_monitor.alerts.is_empty() {
+            return;
+        }
+        let app_context = self.current_app_context().clone();
+        let balance_alerts = app_context.preferences().balance_alerts;
+        let number_format = NumberFormatter::new(&app_context.preferences());
+        let mut top_up = None;
+        let mut dismiss = false;
+        egui::Window::new("Low Identity Balance")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                for (i, qualified_identity) in self.balance_monitor.alerts.iter().enumerate() {
+                    let identity_id = qualified_identity.identity.id().to_string(Encoding::Base58);
+                    let minimum = balance_alerts.minimum_for(&identity_id).unwrap_or_default();
+                    ui.horizontal(|ui| {
+                        ui.colored_label(
+                            egui::Color32::RED,
+                            format!(
+                                "⚠ {} has {}, below its minimum of {}",
+                                qualified_identity.display_short_string(),
+                                number_format
+                                    .format_credits_as_dash(qualified_identity.identity.balance()),
+                                number_format.format_credits_as_dash(minimum)
+                            ),
+                        );
+                        if ui.button("Top up").clicked() {
+                            top_up = Some(i);
+                        }
+                    });
+                }
+                dismiss = ui.button("Dismiss").clicked();
+            });
+        if let Some(i) = top_up {
+            let qualified_identity = self.balance_monitor.alerts.remove(i);
+            self.screen_stack.push(Screen::TopUpIdentityScreen(
+                TopUpIdentityScreen::new(qualified_identity, &app_context).with_shortfall_amount(),
+            ));
+        }
+        if dismiss {
+            self.balance_monitor.alerts.clear();
+        }
+    }
+
+    /// Asks before the first top up of an automatic top up rule. Confirming lets the rule run
+    /// unattended from then on, declining disables it. Both go to the audit trail.
+    fn show_top_up_confirmation(&mut self, ctx: &egui::Context) {
+        let Some(top_up_info) = self.pending_top_up_confirmations.first() else {
+            return;
+        };
+        let app_context = self.current_app_context().clone();
+        let number_format = NumberFormatter::new(&app_context.preferences());
+        let identity_id = top_up_info
+            .qualified_identity
+            .identity
+            .id()
+            .to_string(Encoding::Base58);
+        let Some(rule) = app_context
+            .preferences()
+            .auto_top_up_rules
+            .into_iter()
+            .find(|rule| rule.identity_id == identity_id)
+        else {
+            self.pending_top_up_confirmations.remove(0);
+            return;
+        };
+        let wallet_alias = top_up_info
+            .wallet
+            .read()
+            .unwrap()
+            .alias
+            .clone()
+            .unwrap_or_else(|| "Unnamed Wallet".to_string());
+        let mut confirmed = None;
+        // Above the center, where the low balance alert of the same identity may show
+        egui::Window::new("Confirm Automatic Top Up")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0])
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "{} has {}, below the {} its rule keeps it above.",
+                    top_up_info.qualified_identity.display_short_string(),
+                    number_format
+                        .format_credits_as_dash(top_up_info.qualified_identity.identity.balance()),
+                    number_format.format_credits_as_dash(rule.minimum)
+                ));
+                ui.label(format!(
+                    "The rule tops it up with {:.8} DASH from {}, up to {:.8} DASH per week.",
+                    top_up_info.amount as f64 * 1e-8,
+                    wallet_alias,
+                    rule.weekly_limit as f64 * 1e-8
+                ));
+                ui.label(
+                    egui::RichText::new(
+                        "This is the rule's first top up. Once confirmed, later top ups run \
+                         without asking.",
+                    )
+                    .italics(),
+                );
+                ui.horizontal(|ui| {
+                    if ui.button("Top up and run unattended").clicked() {
+                        confirmed = Some(true);
+                    }
+                    if ui.button("Disable rule").clicked() {
+                        confirmed = Some(false);
+                    }
+                });
+            });
+        let Some(confirmed) = confirmed else {
+            return;
+        };
+        let top_up_info = self.pending_top_up_confirmations.remove(0);
+        let result = app_context.update_preferences(|preferences| {
+            for rule in preferences
+                .auto_top_up_rules
+                .iter_mut()
+                .filter(|rule| rule.identity_id == identity_id)
+            {
+                if confirmed {
+                    rule.confirmed = true;
+                } else {
+                    rule.enabled = false;
+                }
+            }
+        });
+        if let Err(e) = result {
+            tracing::error!("Failed to save the automatic top up rule: {}", e);
+        }
+        let entry = AuditEntry {
+            action: format!(
+                "First automatic top up of {}",
+                top_up_info.qualified_identity.display_short_string()
+            ),
+            outcome: if confirmed {
+                "Confirmed, the rule runs unattended from now on".to_string()
+            } else {
+                "Declined, the rule is disabled".to_string()
+            },
+            approver: None,
+            logged_at: chrono::Utc::now().timestamp(),
+        };
+        if let Err(e) = app_context.db.insert_audit_entry(&entry, &app_context) {
+            tracing::error!("Failed to write the audit trail: {}", e);
+        }
+        if confirmed {
+            self.handle_background_task(BackendTask::IdentityTask(
+                IdentityTask::AutoTopUpIdentity(top_up_info),
+            ));
+        }
+    }
+
+    /// Polls the clipboard when the user opted in and offers to look up what was copied
+    fn show_clipboard_item(&mut self, ctx: &egui::Context, app_context: &Arc<AppContext>) {
+        if !app_context.preferences().clipboard_monitor {
+            self.clipboard_monitor.stop();
+            self.clipboard_item = None;
+            return;
+        }
+        if let Some(item) = self.clipboard_monitor.poll(app_context.network) {
+            self.clipboard_item = Some(item);
+        }
+        let Some(item) = &self.clipboard_item else {
+            return;
+        };
+        match show_clipboard_popup(ctx, app_context, item) {
+            None => {}
+            Some(ClipboardAction::Dismiss) => self.clipboard_item = None,
+            Some(ClipboardAction::OpenIdentity(identity_id)) => {
+                self.clipboard_item = None;
+                let screen = IdentityDetailScreen::new(identity_id, app_context);
+                self.screen_stack.push(Screen::IdentityDetailScreen(screen));
+            }
+            Some(ClipboardAction::LookUpName(label)) => {
+                self.clipboard_item = None;
+                let mut screen = DpnsLookupScreen::new(app_context);
+                let task = screen.search(&label);
+                self.screen_stack.push(Screen::DpnsLookupScreen(screen));
+                self.handle_backend_task(task);
+            }
+        }
+    }
+
     pub fn visible_screen_type(&self) -> ScreenType {
         if let Some(last_screen) = self.screen_stack.last() {
             last_screen.screen_type()
@@ -269,11 +1082,66 @@ impl App for AppState {
                     BackendTaskSuccessResult::CoreItem(_) => {
                         self.visible_screen_mut().display_task_result(message);
                     }
+                    BackendTaskSuccessResult::IdentityDetails(_) => {
+                        self.visible_screen_mut().display_task_result(message);
+                    }
+                    BackendTaskSuccessResult::IdentityNonces(_) => {
+                        self.visible_screen_mut().display_task_result(message);
+                    }
+                    BackendTaskSuccessResult::Withdrawals(ref records) => {
+                        let sounds = self.current_app_context().preferences().notification_sounds;
+                        self.sound_alerts.check_withdrawals(records, &sounds);
+                        self.visible_screen_mut().display_task_result(message);
+                    }
+                    BackendTaskSuccessResult::LoadedIdentities(_) => {
+                        self.visible_screen_mut().display_task_result(message);
+                    }
+                    BackendTaskSuccessResult::UpdatedIdentityKeys(_) => {
+                        self.visible_screen_mut().display_task_result(message);
+                    }
+                    BackendTaskSuccessResult::DpnsNames(_) => {
+                        self.visible_screen_mut().display_task_result(message);
+                    }
+                    BackendTaskSuccessResult::DpnsNameChecks(_) => {
+                        self.visible_screen_mut().display_task_result(message);
+                    }
+                    BackendTaskSuccessResult::HealthReport(_)
+                    | BackendTaskSuccessResult::DapiConnectivity(_) => {
+                        self.visible_screen_mut().display_task_result(message);
+                    }
+                    BackendTaskSuccessResult::MasternodePayouts(_)
+                    | BackendTaskSuccessResult::MasternodeDashboard(_)
+                    | BackendTaskSuccessResult::DocumentSnapshot(_)
+                    | BackendTaskSuccessResult::ContractSnapshots(_)
+                    | BackendTaskSuccessResult::DocumentImportProgress { .. }
+                    | BackendTaskSuccessResult::ImportedDocuments(_)
+                    | BackendTaskSuccessResult::DeletionPreview(_)
+                    | BackendTaskSuccessResult::DocumentDeletionProgress { .. }
+                    | BackendTaskSuccessResult::DeletedDocuments(_)
+                    | BackendTaskSuccessResult::ColdSigningFile(_)
+                    | BackendTaskSuccessResult::UnsignedAssetLock(_)
+                    | BackendTaskSuccessResult::VerifiedOwnershipProof(_)
+                    | BackendTaskSuccessResult::VerifiedMasternodeMessage(_)
+                    | BackendTaskSuccessResult::VerifiedVoteReceipt(_)
+                    | BackendTaskSuccessResult::ContestantPreviews(..)
+                    | BackendTaskSuccessResult::ContestantReputations(_)
+                    | BackendTaskSuccessResult::TransferMemos(_) => {
+                        self.visible_screen_mut().display_task_result(message);
+                    }
                     BackendTaskSuccessResult::SuccessfulVotes(_) => {
                         self.visible_screen_mut().refresh();
                     }
                 },
                 TaskResult::Error(message) => {
+                    let sounds = self.current_app_context().preferences().notification_sounds;
+                    self.sound_alerts.play(SoundEvent::TaskFailed, &sounds);
+                    self.current_app_context().notify_webhooks(
+                        WebhookNotification::new(
+                            WebhookEvent::TaskFailed,
+                            format!("A task failed: {}", message),
+                        )
+                        .with_field("error", &message),
+                    );
                     self.visible_screen_mut()
                         .display_message(&message, MessageType::Error);
                 }
@@ -283,10 +1151,110 @@ impl App for AppState {
             }
         }
 
+        self.deliver_events();
+
+        while let Ok(uri) = self.deep_link_receiver.try_recv() {
+            self.open_deep_link(&uri);
+            ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+        }
+
+        // Periodically check whether the cached contracts are outdated
+        if self.last_contract_update_check.map_or(true, |last_check| {
+            last_check.elapsed() >= CONTRACT_UPDATE_CHECK_INTERVAL
+        }) {
+            self.last_contract_update_check = Some(Instant::now());
+            self.handle_background_task(BackendTask::ContractTask(
+                ContractTask::CheckContractUpdates,
+            ));
+        }
+
+        // Periodically measure the clock skew again, the local clock may drift or be corrected
+        if self.last_clock_skew_check.elapsed() >= CLOCK_SKEW_CHECK_INTERVAL {
+            self.last_clock_skew_check = Instant::now();
+            self.handle_background_task(BackendTask::HealthTask(HealthTask::MeasureClockSkew));
+        }
+
+        // Periodically refresh the price of DASH, the cached one stays in use while it fails
+        let price_feed = self.current_app_context().preferences().price_feed;
+        if price_feed.enabled
+            && self.last_price_fetch.map_or(true, |last_fetch| {
+                last_fetch.elapsed() >= price_feed.refresh_interval()
+            })
+        {
+            self.last_price_fetch = Some(Instant::now());
+            self.handle_background_task(BackendTask::PriceTask(PriceTask::FetchPrice));
+        }
+
+        // Usage statistics go out once a day when the user opted in, failures retry hourly
+        let telemetry = self.current_app_context().preferences().telemetry;
+        if telemetry.is_due(chrono::Utc::now().timestamp())
+            && self.last_telemetry_attempt.map_or(true, |last_attempt| {
+                last_attempt.elapsed() >= TELEMETRY_RETRY_INTERVAL
+            })
+        {
+            self.last_telemetry_attempt = Some(Instant::now());
+            self.current_app_context().send_telemetry();
+        }
+
+        self.sound_alerts
+            .check_contests(&self.current_app_context().clone());
+        self.webhook_alerts
+            .check_contests(&self.current_app_context().clone());
+
+        // Start the scheduled tasks whose interval passed
+        for scheduled in self
+            .scheduler
+            .due_tasks(&self.current_app_context().clone())
+        {
+            self.run_scheduled_task(scheduled);
+        }
+
+        // Keep the balances of identities with a minimum fresh, drops are noticed on delivery
+        if let Some(task) = self
+            .balance_monitor
+            .poll(&self.current_app_context().clone())
+        {
+            self.handle_background_task(task);
+        }
+
         // Use a timer to repaint the UI every 0.05 seconds
         ctx.request_repaint_after(std::time::Duration::from_millis(50));
 
+        let display_scale = self.current_app_context().preferences().display_scale;
+        if self.applied_display_scale != Some(display_scale) {
+            apply_display_scale(ctx, &display_scale);
+            self.applied_display_scale = Some(display_scale);
+        }
+
         let action = self.visible_screen_mut().ui(ctx);
+        if ctx.input(|input| input.key_pressed(egui::Key::F1)) {
+            toggle_help(ctx);
+        }
+        if is_help_open(ctx) {
+            show_help_overlay(ctx, &self.visible_screen_type());
+        }
+
+        let app_context = self.current_app_context().clone();
+        self.show_clipboard_item(ctx, &app_context);
+        self.show_session_restore(ctx);
+        self.show_health_problems(ctx);
+        self.show_low_balance_alerts(ctx);
+        self.show_top_up_confirmation(ctx);
+        self.autosave_session();
+        if let Some(request) = self.pending_approval.as_mut() {
+            match request.show(ctx, &app_context) {
+                ApprovalOutcome::Pending => {}
+                ApprovalOutcome::Approved(task) => {
+                    self.pending_approval = None;
+                    self.start_backend_task(task);
+                }
+                ApprovalOutcome::Rejected => {
+                    self.pending_approval = None;
+                    self.visible_screen_mut()
+                        .display_message("The approver rejected the action", MessageType::Error);
+                }
+            }
+        }
 
         match action {
             AppAction::AddScreen(screen) => self.screen_stack.push(screen),
@@ -314,6 +1282,8 @@ impl App for AppState {
                 self.handle_backend_task(task);
             }
             AppAction::SetMainScreen(root_screen_type) => {
+                // The breadcrumb menu can pick a main screen from a sub-screen
+                self.screen_stack = vec![];
                 self.selected_main_screen = root_screen_type;
                 self.active_root_screen_mut().refresh_on_arrival();
                 self.current_app_context()
@@ -322,5 +1292,81 @@ impl App for AppState {
             }
             AppAction::SwitchNetwork(network) => self.change_network(network),
         }
+        self.run_commands();
+    }
+}
+
+/// Runs a task started by a screen or in the background, following its endpoint's rate limit
+async fn run_dispatched(
+    app_context: &Arc<AppContext>,
+    task: BackendTask,
+    sender: mpsc::Sender<TaskResult>,
+) -> Result<BackendTaskSuccessResult, String> {
+    let cache_key = task.cache_key();
+    let invalidated = task.invalidated_classes();
+    let result = match task.endpoint() {
+        Some(endpoint) => run_rate_limited(app_context, task, endpoint, sender).await,
+        None => app_context.run_backend_task(task, sender).await,
+    };
+    if let Ok(success) = &result {
+        for class in invalidated {
+            app_context.response_cache.invalidate(class);
+        }
+        if let Some((class, key)) = cache_key {
+            app_context
+                .response_cache
+                .insert(class, key, success.clone());
+        }
+    }
+    result
+}
+
+/// The cached result of the task if it is still fresh
+fn cached_result(
+    app_context: &Arc<AppContext>,
+    task: &BackendTask,
+) -> Option<BackendTaskSuccessResult> {
+    let (class, key) = task.cache_key()?;
+    let ttl = app_context.preferences().cache_ttls.ttl(class);
+    let result = app_context.response_cache.get(class, &key, ttl)?;
+    tracing::debug!("Answered a {} task from the cache", task.kind());
+    Some(result)
+}
+
+/// Runs the task in the endpoint's next slot, retrying queries that failed because the endpoint
+/// was overloaded or unreachable after the backoff
+async fn run_rate_limited(
+    app_context: &Arc<AppContext>,
+    task: BackendTask,
+    endpoint: Endpoint,
+    sender: mpsc::Sender<TaskResult>,
+) -> Result<BackendTaskSuccessResult, String> {
+    let mut retries = 0;
+    loop {
+        let policy = *app_context.preferences().rate_limits.policy(endpoint);
+        app_context.rate_limiter.acquire(endpoint, &policy).await;
+        let result = app_context
+            .run_backend_task(task.clone(), sender.clone())
+            .await;
+        // Errors of the request itself, like a rejected transition, say nothing about the endpoint
+        let reachable = result
+            .as_ref()
+            .map_or_else(|e| !is_transient_error(e), |_| true);
+        app_context
+            .rate_limiter
+            .record(endpoint, &policy, reachable);
+        match &result {
+            Err(e) if task.is_query() && retries < policy.max_retries && is_transient_error(e) => {
+                retries += 1;
+                tracing::info!(
+                    "Retrying a {} task after: {} (retry {} of {})",
+                    task.kind(),
+                    e,
+                    retries,
+                    policy.max_retries
+                );
+            }
+            _ => return result,
+        }
     }
 }
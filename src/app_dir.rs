@@ -0,0 +1,247 @@
+use directories::ProjectDirs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Command line flag that enables portable mode for one run
+pub const PORTABLE_FLAG: &str = "--portable";
+
+/// File beside the executable that enables portable mode permanently
+const PORTABLE_MARKER: &str = "portable";
+
+/// Directory beside the executable holding all data in portable mode
+const PORTABLE_DATA_DIR: &str = "data";
+
+/// Command line flag opening a profile without the picker, e.g. `--profile Company`
+pub const PROFILE_FLAG: &str = "--profile";
+
+/// The profile kept directly in the data directory, as before profiles existed
+pub const DEFAULT_PROFILE: &str = "Default";
+
+/// Directory of the data directory holding one directory per other profile
+const PROFILES_DIR: &str = "profiles";
+
+/// File of the data directory naming the profile opened last, preselected by the picker
+const LAST_PROFILE_FILE: &str = "last_profile";
+
+const PROFILE_NAME_MAX_LENGTH: usize = 32;
+
+const DATABASE_FILE: &str = "identities.db";
+const CONFIG_FILE: &str = "config.toml";
+const LOG_FILE: &str = "explorer.log";
+const LOGS_DIR: &str = "logs";
+
+/// Where the database, config and logs are stored.
+///
+/// Normally this is the platform's app data directory. In portable mode everything is kept in
+/// a directory beside the executable, so the tool can run from a removable drive.
+///
+/// Each profile has its own database, config and logs. The default profile uses the data
+/// directory itself, the others a directory below it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppDirs {
+    /// Directory of the profile's files
+    pub data_dir: PathBuf,
+    /// Directory of the default profile, holding the other profiles
+    pub root_dir: PathBuf,
+    pub profile: String,
+    pub portable: bool,
+}
+
+impl AppDirs {
+    /// Picks the data directory for this run and makes sure it exists
+    pub fn resolve() -> io::Result<Self> {
+        let portable =
+            std::env::args().any(|arg| arg == PORTABLE_FLAG) || Self::portable_marker()?.exists();
+        let data_dir = if portable {
+            executable_dir()?.join(PORTABLE_DATA_DIR)
+        } else {
+            ProjectDirs::from("org", "Dash", "Dash-Evo-Tool")
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No home directory found"))?
+                .data_dir()
+                .to_path_buf()
+        };
+        std::fs::create_dir_all(&data_dir)?;
+
+        let app_dirs = Self {
+            root_dir: data_dir.clone(),
+            data_dir,
+            profile: DEFAULT_PROFILE.to_string(),
+            portable,
+        };
+        if !portable {
+            app_dirs.migrate_from_working_dir();
+        }
+        Ok(app_dirs)
+    }
+
+    /// The profile named on the command line, if any
+    pub fn profile_from_args() -> Option<String> {
+        let mut args = std::env::args();
+        while let Some(arg) = args.next() {
+            if arg == PROFILE_FLAG {
+                return args.next();
+            }
+            if let Some(profile) = arg.strip_prefix(&format!("{}=", PROFILE_FLAG)) {
+                return Some(profile.to_string());
+            }
+        }
+        None
+    }
+
+    /// The default profile followed by the others in alphabetical order
+    pub fn profiles(&self) -> Vec<String> {
+        let mut profiles: Vec<String> = std::fs::read_dir(self.root_dir.join(PROFILES_DIR))
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter(|entry| entry.path().is_dir())
+                    .filter_map(|entry| entry.file_name().into_string().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        profiles.sort_by_key(|profile| profile.to_lowercase());
+        profiles.insert(0, DEFAULT_PROFILE.to_string());
+        profiles
+    }
+
+    /// The directories of an existing profile
+    pub fn for_profile(&self, profile: &str) -> io::Result<Self> {
+        if profile == DEFAULT_PROFILE {
+            return Ok(Self {
+                data_dir: self.root_dir.clone(),
+                profile: DEFAULT_PROFILE.to_string(),
+                ..self.clone()
+            });
+        }
+        let data_dir = self.root_dir.join(PROFILES_DIR).join(profile);
+        if !data_dir.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("There is no profile named {}", profile),
+            ));
+        }
+        Ok(Self {
+            data_dir,
+            profile: profile.to_string(),
+            ..self.clone()
+        })
+    }
+
+    /// Creates an empty profile. It starts with a copy of the default profile's config, so it
+    /// connects to the same nodes, but nothing else is shared.
+    pub fn create_profile(&self, profile: &str) -> Result<Self, String> {
+        let profile = profile.trim();
+        validate_profile_name(profile)?;
+        if self
+            .profiles()
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(profile))
+        {
+            return Err(format!("A profile named {} already exists", profile));
+        }
+        let data_dir = self.root_dir.join(PROFILES_DIR).join(profile);
+        std::fs::create_dir_all(&data_dir).map_err(|e| e.to_string())?;
+        let default_config = self.root_dir.join(CONFIG_FILE);
+        if default_config.exists() {
+            std::fs::copy(default_config, data_dir.join(CONFIG_FILE)).map_err(|e| e.to_string())?;
+        }
+        self.for_profile(profile).map_err(|e| e.to_string())
+    }
+
+    pub fn last_profile(&self) -> Option<String> {
+        std::fs::read_to_string(self.root_dir.join(LAST_PROFILE_FILE))
+            .ok()
+            .map(|profile| profile.trim().to_string())
+            .filter(|profile| !profile.is_empty())
+    }
+
+    pub fn save_last_profile(&self) -> io::Result<()> {
+        std::fs::write(self.root_dir.join(LAST_PROFILE_FILE), &self.profile)
+    }
+
+    pub fn database_path(&self) -> PathBuf {
+        self.data_dir.join(DATABASE_FILE)
+    }
+
+    pub fn config_path(&self) -> PathBuf {
+        self.data_dir.join(CONFIG_FILE)
+    }
+
+    pub fn log_path(&self) -> PathBuf {
+        self.data_dir.join(LOG_FILE)
+    }
+
+    /// Directory of the JSON logs kept per session
+    pub fn logs_dir(&self) -> PathBuf {
+        self.data_dir.join(LOGS_DIR)
+    }
+
+    /// Whether portable mode is enabled permanently, as opposed to by the command line flag
+    pub fn is_portable_mode_saved() -> bool {
+        Self::portable_marker().is_ok_and(|marker| marker.exists())
+    }
+
+    /// Enables or disables portable mode for the next start
+    pub fn save_portable_mode(enabled: bool) -> io::Result<()> {
+        let marker = Self::portable_marker()?;
+        if enabled {
+            std::fs::write(
+                marker,
+                "Dash Evo Tool keeps its data beside the executable while this file exists.\n",
+            )
+        } else if marker.exists() {
+            std::fs::remove_file(marker)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn portable_marker() -> io::Result<PathBuf> {
+        Ok(executable_dir()?.join(PORTABLE_MARKER))
+    }
+
+    /// Earlier versions kept their files in the working directory, copy them over once so
+    /// nothing is lost. The originals are left in place.
+    fn migrate_from_working_dir(&self) {
+        for (file, destination) in [
+            (DATABASE_FILE, self.database_path()),
+            (CONFIG_FILE, self.config_path()),
+        ] {
+            let source = Path::new(file);
+            if source.exists() && !destination.exists() {
+                match std::fs::copy(source, &destination) {
+                    Ok(_) => eprintln!("Copied {} to {}", file, destination.display()),
+                    Err(e) => eprintln!("Failed to copy {}: {}", file, e),
+                }
+            }
+        }
+    }
+}
+
+/// Profile names become directory names, so only plain characters are allowed
+fn validate_profile_name(profile: &str) -> Result<(), String> {
+    if profile.is_empty() {
+        return Err("Enter a name for the profile".to_string());
+    }
+    if profile.chars().count() > PROFILE_NAME_MAX_LENGTH {
+        return Err(format!(
+            "Profile names are at most {} characters",
+            PROFILE_NAME_MAX_LENGTH
+        ));
+    }
+    if !profile
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == ' ' || c == '-' || c == '_')
+    {
+        return Err("Profile names may only contain letters, digits, spaces, - and _".to_string());
+    }
+    Ok(())
+}
+
+fn executable_dir() -> io::Result<PathBuf> {
+    let executable = std::env::current_exe()?;
+    executable
+        .parent()
+        .map(Path::to_path_buf)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Executable has no directory"))
+}
@@ -0,0 +1,207 @@
+use crate::context::AppContext;
+use crate::model::approval::AuditEntry;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::platform::identity::{IdentityTask, IdentityTopUpInfo};
+use crate::platform::BackendTask;
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// Keeps the balances of identities with a minimum fresh and notices when one drops below it
+#[derive(Default)]
+pub struct BalanceMonitor {
+    last_poll: Option<Instant>,
+    /// Identities below their minimum at the last check, by network and base58 id
+    low_identities: HashSet<(String, String)>,
+    /// Identities that dropped below their minimum and were not dismissed yet
+    pub alerts: Vec<QualifiedIdentity>,
+    /// Automatic top ups skipped while their identity stays low, by network and base58 id, so
+    /// the audit trail gets each skip once
+    skipped_top_ups: HashSet<(String, String)>,
+}
+
+/// A top up an automatic top up rule asks for
+pub enum DueTopUp {
+    /// The rule ran before, the top up runs unattended
+    Run(IdentityTopUpInfo),
+    /// The first top up of the rule, which the user confirms
+    Confirm(IdentityTopUpInfo),
+}
+
+impl BalanceMonitor {
+    /// The refresh of the identities that have a minimum or an automatic top up rule, once per
+    /// poll interval
+    pub fn poll(&mut self, app_context: &AppContext) -> Option<BackendTask> {
+        let preferences = app_context.preferences();
+        let balance_alerts = preferences.balance_alerts;
+        let interval = Duration::from_secs(balance_alerts.poll_interval_minutes.max(1) as u64 * 60);
+        if self
+            .last_poll
+            .is_some_and(|last_poll| last_poll.elapsed() < interval)
+        {
+            return None;
+        }
+        self.last_poll = Some(Instant::now());
+        let identities: Vec<_> = app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|qualified_identity| {
+                let identity_id = qualified_identity.identity.id().to_string(Encoding::Base58);
+                balance_alerts.minimum_for(&identity_id).is_some()
+                    || preferences
+                        .auto_top_up_rules
+                        .iter()
+                        .any(|rule| rule.enabled && rule.identity_id == identity_id)
+            })
+            .collect();
+        if identities.is_empty() {
+            return None;
+        }
+        Some(BackendTask::IdentityTask(IdentityTask::RefreshIdentities(
+            identities,
+        )))
+    }
+
+    /// Compares the stored balances with their minimums and queues an alert for each identity
+    /// that dropped below since the last check. Returns those identities with their minimum.
+    pub fn check_balances(
+        &mut self,
+        app_context: &AppContext,
+    ) -> Vec<(QualifiedIdentity, Credits)> {
+        let balance_alerts = app_context.preferences().balance_alerts;
+        let network = app_context.network_string();
+        let identities = match app_context.load_local_qualified_identities() {
+            Ok(identities) => identities,
+            Err(e) => {
+                tracing::warn!("Failed to load identities for the balance check: {}", e);
+                return vec![];
+            }
+        };
+        let mut still_low = HashSet::new();
+        let mut dropped = vec![];
+        for qualified_identity in identities {
+            let identity_id = qualified_identity.identity.id().to_string(Encoding::Base58);
+            let Some(minimum) = balance_alerts
+                .shortfall_minimum(&identity_id, qualified_identity.identity.balance())
+            else {
+                continue;
+            };
+            let key = (network.clone(), identity_id);
+            if !self.low_identities.contains(&key) {
+                self.alerts
+                    .retain(|alert| alert.identity.id() != qualified_identity.identity.id());
+                self.alerts.push(qualified_identity.clone());
+                dropped.push((qualified_identity, minimum));
+            }
+            still_low.insert(key);
+        }
+        // Identities topped up or with a lowered minimum alert again on their next drop
+        self.low_identities
+            .retain(|(low_network, _)| *low_network != network);
+        self.low_identities.extend(still_low);
+        dropped
+    }
+
+    /// The top ups of enabled automatic top up rules whose identity is below the rule's minimum,
+    /// within the rule's weekly limit
+    pub fn due_top_ups(&mut self, app_context: &AppContext) -> Vec<DueTopUp> {
+        let preferences = app_context.preferences();
+        let rules = preferences.auto_top_up_rules;
+        let fee = preferences.fee_choice;
+        if rules.is_empty() {
+            return Vec::new();
+        }
+        let network = app_context.network_string();
+        let identities = app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default();
+        let mut due = Vec::new();
+        for rule in rules.iter().filter(|rule| rule.enabled) {
+            let Some(qualified_identity) = identities.iter().find(|qualified_identity| {
+                qualified_identity.identity.id().to_string(Encoding::Base58) == rule.identity_id
+            }) else {
+                continue;
+            };
+            let key = (network.clone(), rule.identity_id.clone());
+            if qualified_identity.identity.balance() >= rule.minimum {
+                self.skipped_top_ups.remove(&key);
+                continue;
+            }
+            let wallet = app_context
+                .wallets
+                .read()
+                .unwrap()
+                .iter()
+                .find(|wallet| wallet.read().unwrap().seed_hash() == rule.wallet_seed_hash)
+                .cloned();
+            let Some(wallet) = wallet else {
+                self.skip_top_up(
+                    app_context,
+                    key,
+                    qualified_identity,
+                    "its wallet is not loaded",
+                );
+                continue;
+            };
+            let spent = match app_context
+                .db
+                .get_auto_top_up_spent_this_week(&qualified_identity.identity.id(), app_context)
+            {
+                Ok(spent) => spent,
+                Err(e) => {
+                    tracing::warn!("Failed to load the automatic top ups of the week: {}", e);
+                    continue;
+                }
+            };
+            let amount = rule.next_amount(spent);
+            if amount == 0 {
+                self.skip_top_up(
+                    app_context,
+                    key,
+                    qualified_identity,
+                    "the weekly limit is reached",
+                );
+                continue;
+            }
+            let top_up_info = IdentityTopUpInfo {
+                qualified_identity: qualified_identity.clone(),
+                amount,
+                wallet,
+                fee,
+            };
+            due.push(if rule.confirmed {
+                DueTopUp::Run(top_up_info)
+            } else {
+                DueTopUp::Confirm(top_up_info)
+            });
+        }
+        due
+    }
+
+    fn skip_top_up(
+        &mut self,
+        app_context: &AppContext,
+        key: (String, String),
+        qualified_identity: &QualifiedIdentity,
+        reason: &str,
+    ) {
+        if !self.skipped_top_ups.insert(key) {
+            return;
+        }
+        let entry = AuditEntry {
+            action: format!(
+                "Automatic top up of {}",
+                qualified_identity.display_short_string()
+            ),
+            outcome: format!("Skipped, {}", reason),
+            approver: None,
+            logged_at: chrono::Utc::now().timestamp(),
+        };
+        if let Err(e) = app_context.db.insert_audit_entry(&entry, app_context) {
+            tracing::error!("Failed to write the audit trail: {}", e);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::model::dpns_lookup::validate_label;
+use arboard::Clipboard;
+use dash_sdk::dpp::dashcore::{Address, Network};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// How often the clipboard is read while the monitor is on
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Longest clipboard text that is looked at, anything longer is not a single identifier
+const MAX_TEXT_LENGTH: usize = 100;
+
+/// Something recognized in the clipboard that the tool can look up
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClipboardItem {
+    Identity(Identifier),
+    /// A DPNS label, without the `.dash` suffix
+    DpnsName(String),
+    /// A 64 character hex hash, either a core txid or a state transition hash
+    Hash(String),
+    Address(String),
+}
+
+impl ClipboardItem {
+    /// Recognizes a single identifier in the text, addresses only for the given network
+    pub fn detect(text: &str, network: Network) -> Option<Self> {
+        let text = text.trim();
+        if text.is_empty() || text.len() > MAX_TEXT_LENGTH || text.contains(char::is_whitespace) {
+            return None;
+        }
+        if text.len() == 64 && text.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Some(ClipboardItem::Hash(text.to_lowercase()));
+        }
+        if let Some(label) = text.strip_suffix(".dash") {
+            return validate_label(label)
+                .is_ok()
+                .then(|| ClipboardItem::DpnsName(label.to_string()));
+        }
+        if let Ok(address) = Address::from_str(text) {
+            return address
+                .require_network(network)
+                .ok()
+                .map(|address| ClipboardItem::Address(address.to_string()));
+        }
+        if (42..=44).contains(&text.len()) {
+            return Identifier::from_string(text, Encoding::Base58)
+                .ok()
+                .map(ClipboardItem::Identity);
+        }
+        None
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            ClipboardItem::Identity(identity_id) => {
+                format!("Identity {}", identity_id.to_string(Encoding::Base58))
+            }
+            ClipboardItem::DpnsName(label) => format!("DPNS name {}.dash", label),
+            ClipboardItem::Hash(hash) => format!("Transaction or transition {}", hash),
+            ClipboardItem::Address(address) => format!("Dash address {}", address),
+        }
+    }
+}
+
+/// Watches the clipboard for identifiers while the user opted in. Only text that changed since
+/// the last poll is reported, so dismissing an item does not bring it back.
+#[derive(Default)]
+pub struct ClipboardMonitor {
+    clipboard: Option<Clipboard>,
+    last_text: Option<String>,
+    /// When the clipboard was last read, `None` until the monitor starts
+    last_poll: Option<Instant>,
+}
+
+impl ClipboardMonitor {
+    /// Reads the clipboard when the poll interval passed, returns a newly copied identifier
+    pub fn poll(&mut self, network: Network) -> Option<ClipboardItem> {
+        if self
+            .last_poll
+            .is_some_and(|last_poll| last_poll.elapsed() < POLL_INTERVAL)
+        {
+            return None;
+        }
+        let first_poll = self.last_poll.is_none();
+        self.last_poll = Some(Instant::now());
+        if self.clipboard.is_none() {
+            match Clipboard::new() {
+                Ok(clipboard) => self.clipboard = Some(clipboard),
+                Err(e) => {
+                    tracing::debug!("Clipboard is not available: {}", e);
+                    return None;
+                }
+            }
+        }
+        let text = self.clipboard.as_mut()?.get_text().ok();
+        if first_poll || text == self.last_text {
+            // The first read only learns what was copied before the monitor started
+            self.last_text = text;
+            return None;
+        }
+        self.last_text = text;
+        ClipboardItem::detect(self.last_text.as_deref()?, network)
+    }
+
+    /// Forgets the clipboard, e.g. when the user turns the monitor off
+    pub fn stop(&mut self) {
+        self.clipboard = None;
+        self.last_text = None;
+        self.last_poll = None;
+    }
+}
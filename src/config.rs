@@ -1,9 +1,43 @@
+use std::path::Path;
 use std::str::FromStr;
 
 use dash_sdk::dapi_client::AddressList;
 use dash_sdk::dpp::dashcore::Network;
 use dash_sdk::sdk::Uri;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
+
+/// Version of the configuration file format this build understands
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Written to the configuration file on first run
+const DEFAULT_CONFIG: &str = include_str!("../config.example.toml");
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("Invalid {path}: {message}")]
+    Parse { path: String, message: String },
+    #[error("{path} has version {found}, but this build only supports version {supported}")]
+    UnsupportedVersion {
+        path: String,
+        found: u32,
+        supported: u32,
+    },
+    #[error("Invalid value for {key} in {path}: {message}")]
+    InvalidValue {
+        path: String,
+        key: String,
+        message: String,
+    },
+    #[error("Invalid value of the environment variable {name}: {message}")]
+    InvalidEnvironmentValue { name: String, message: String },
+    #[error("{path} has no [{section}] section")]
+    MissingNetwork { path: String, section: String },
+}
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct Config {
@@ -11,6 +45,18 @@ pub struct Config {
     pub mainnet_config: Option<NetworkConfig>,
     /// The testnet network config
     pub testnet_config: Option<NetworkConfig>,
+    /// The devnet network config
+    pub devnet_config: Option<NetworkConfig>,
+}
+
+/// The layout of the configuration file
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    version: u32,
+    mainnet: Option<NetworkConfig>,
+    testnet: Option<NetworkConfig>,
+    devnet: Option<NetworkConfig>,
 }
 
 impl Config {
@@ -18,7 +64,7 @@ impl Config {
         match network {
             Network::Dash => &self.mainnet_config,
             Network::Testnet => &self.testnet_config,
-            Network::Devnet => &None,
+            Network::Devnet => &self.devnet_config,
             Network::Regtest => &None,
             _ => &None,
         }
@@ -26,8 +72,10 @@ impl Config {
 }
 
 #[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
 pub struct NetworkConfig {
-    /// Hostname of the Dash Platform node to connect to
+    /// Comma separated DAPI addresses of the Dash Platform nodes to connect to
+    #[serde(deserialize_with = "deserialize_addresses")]
     pub dapi_addresses: String,
     /// Host of the Dash Core RPC interface
     pub core_host: String,
@@ -44,49 +92,107 @@ pub struct NetworkConfig {
     /// Optional wallet private key to instantiate the wallet
     pub wallet_private_key: Option<String>,
     /// Should this network be visible in the UI
+    #[serde(default = "default_show_in_ui")]
     pub show_in_ui: bool,
+    /// PEM file with the certificate authority that signed the DAPI certificates, for devnets
+    /// with their own CA
+    #[serde(default)]
+    pub dapi_ca_certificate: Option<String>,
+    /// Trust whatever certificates the DAPI nodes present, e.g. self-signed ones of a private
+    /// devnet. Anyone between the tool and the nodes can then read and change the traffic.
+    #[serde(default)]
+    pub dapi_insecure_tls: bool,
+}
+
+fn default_show_in_ui() -> bool {
+    true
+}
+
+/// Accepts the addresses either as a list or as a comma separated string
+fn deserialize_addresses<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Addresses {
+        List(Vec<String>),
+        Joined(String),
+    }
+    Ok(match Addresses::deserialize(deserializer)? {
+        Addresses::List(addresses) => addresses.join(","),
+        Addresses::Joined(addresses) => addresses,
+    })
 }
 
 impl Config {
-    /// Loads the configuration for all networks from environment variables and `.env` file.
-    pub fn load() -> Self {
-        // Load the .env file if available
+    /// Loads the configuration from `path`, writing the defaults first if it does not exist
+    /// yet, and applies environment variable overrides.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        // Variables from a .env file are overrides like any other environment variable
         if let Err(err) = dotenvy::from_path(".env") {
-            tracing::warn!(
-                ?err,
-                "Failed to load .env file. Continuing with environment variables."
-            );
+            tracing::debug!(?err, "No .env file loaded");
         } else {
             tracing::info!("Successfully loaded .env file");
         }
 
-        // Load individual network configs and log if they fail
-        let mainnet_config = match envy::prefixed("MAINNET_").from_env::<NetworkConfig>() {
-            Ok(config) => {
-                tracing::info!("Mainnet configuration loaded successfully");
-                Some(config)
-            }
-            Err(err) => {
-                tracing::error!(?err, "Failed to load mainnet configuration");
-                None
+        let path_display = path.display().to_string();
+        if !path.exists() {
+            match std::fs::write(path, DEFAULT_CONFIG) {
+                Ok(_) => tracing::info!("Wrote default configuration to {}", path_display),
+                Err(err) => tracing::warn!(?err, "Failed to write default configuration"),
             }
+        }
+        let contents = if path.exists() {
+            std::fs::read_to_string(path).map_err(|source| ConfigError::Io {
+                path: path_display.clone(),
+                source,
+            })?
+        } else {
+            DEFAULT_CONFIG.to_string()
         };
 
-        let testnet_config = match envy::prefixed("TESTNET_").from_env::<NetworkConfig>() {
-            Ok(config) => {
-                tracing::info!("Testnet configuration loaded successfully");
-                Some(config)
-            }
-            Err(err) => {
-                tracing::error!(?err, "Failed to load testnet configuration");
-                None
+        Self::parse(&contents, &path_display)
+    }
+
+    /// Checks the contents of a configuration file before it is written
+    pub fn check(contents: &str, path: &str) -> Result<(), ConfigError> {
+        Self::parse(contents, path).map(|_| ())
+    }
+
+    /// Parses the contents of a configuration file and applies environment variable overrides
+    fn parse(contents: &str, path: &str) -> Result<Self, ConfigError> {
+        let config_file: ConfigFile = toml::from_str(contents).map_err(|e| ConfigError::Parse {
+            path: path.to_string(),
+            message: e.to_string(),
+        })?;
+        if config_file.version != CONFIG_VERSION {
+            return Err(ConfigError::UnsupportedVersion {
+                path: path.to_string(),
+                found: config_file.version,
+                supported: CONFIG_VERSION,
+            });
+        }
+
+        let mut networks = [
+            (config_file.mainnet, "mainnet", "MAINNET_"),
+            (config_file.testnet, "testnet", "TESTNET_"),
+            (config_file.devnet, "devnet", "DEVNET_"),
+        ];
+        for (network_config, section, prefix) in networks.iter_mut() {
+            if let Some(network_config) = network_config {
+                network_config.apply_env_overrides(prefix)?;
+                network_config.validate(section, path)?;
+                tracing::info!("{} configuration loaded successfully", section);
             }
-        };
+        }
+        let [(mainnet_config, ..), (testnet_config, ..), (devnet_config, ..)] = networks;
 
-        Config {
+        Ok(Config {
             mainnet_config,
             testnet_config,
-        }
+            devnet_config,
+        })
     }
 }
 
@@ -100,6 +206,121 @@ impl NetworkConfig {
             && Uri::from_str(&self.insight_api_url).is_ok()
     }
 
+    /// Replaces values with the environment variables named after the prefix and the key, like
+    /// `MAINNET_CORE_RPC_PORT`
+    fn apply_env_overrides(&mut self, prefix: &str) -> Result<(), ConfigError> {
+        let var = |key: &str| {
+            let name = format!("{}{}", prefix, key);
+            std::env::var(&name).ok().map(|value| (name, value))
+        };
+        let invalid =
+            |name: String, message: String| ConfigError::InvalidEnvironmentValue { name, message };
+
+        if let Some((_, value)) = var("DAPI_ADDRESSES") {
+            self.dapi_addresses = value;
+        }
+        if let Some((_, value)) = var("CORE_HOST") {
+            self.core_host = value;
+        }
+        if let Some((name, value)) = var("CORE_RPC_PORT") {
+            self.core_rpc_port = value.trim().parse().map_err(|_| {
+                invalid(
+                    name,
+                    format!("\"{}\" is not a port between 1 and 65535", value),
+                )
+            })?;
+        }
+        if let Some((_, value)) = var("CORE_RPC_USER") {
+            self.core_rpc_user = value;
+        }
+        if let Some((_, value)) = var("CORE_RPC_PASSWORD") {
+            self.core_rpc_password = value;
+        }
+        if let Some((_, value)) = var("INSIGHT_API_URL") {
+            self.insight_api_url = value;
+        }
+        if let Some((_, value)) = var("DEVNET_NAME") {
+            self.devnet_name = (!value.is_empty()).then_some(value);
+        }
+        if let Some((_, value)) = var("WALLET_PRIVATE_KEY") {
+            self.wallet_private_key = (!value.is_empty()).then_some(value);
+        }
+        if let Some((name, value)) = var("SHOW_IN_UI") {
+            self.show_in_ui = value
+                .trim()
+                .parse()
+                .map_err(|_| invalid(name, format!("\"{}\" is not true or false", value)))?;
+        }
+        if let Some((_, value)) = var("DAPI_CA_CERTIFICATE") {
+            self.dapi_ca_certificate = (!value.is_empty()).then_some(value);
+        }
+        if let Some((name, value)) = var("DAPI_INSECURE_TLS") {
+            self.dapi_insecure_tls = value
+                .trim()
+                .parse()
+                .map_err(|_| invalid(name, format!("\"{}\" is not true or false", value)))?;
+        }
+        Ok(())
+    }
+
+    /// Checks values that parse but cannot work
+    fn validate(&self, section: &str, path: &str) -> Result<(), ConfigError> {
+        let invalid = |key: &str, message: String| ConfigError::InvalidValue {
+            path: path.to_string(),
+            key: format!("{}.{}", section, key),
+            message,
+        };
+        if self.core_rpc_port == 0 {
+            return Err(invalid(
+                "core_rpc_port",
+                "must be a port between 1 and 65535".to_string(),
+            ));
+        }
+        if self.dapi_addresses.trim().is_empty() {
+            return Err(invalid(
+                "dapi_addresses",
+                "at least one address is required".to_string(),
+            ));
+        }
+        for address in self.dapi_addresses.split(',') {
+            if let Err(e) = Uri::from_str(address.trim()) {
+                return Err(invalid(
+                    "dapi_addresses",
+                    format!("\"{}\" is not a valid address: {}", address, e),
+                ));
+            }
+        }
+        if let Some(path) = &self.dapi_ca_certificate {
+            if !Path::new(path).is_file() {
+                return Err(invalid(
+                    "dapi_ca_certificate",
+                    format!("{} is not a file", path),
+                ));
+            }
+        }
+        if self.dapi_insecure_tls && section != "devnet" {
+            return Err(invalid(
+                "dapi_insecure_tls",
+                "is only allowed for devnets, mainnet and testnet certificates are always \
+                 verified"
+                    .to_string(),
+            ));
+        }
+        if section == "devnet" && self.devnet_name.as_deref().unwrap_or_default().is_empty() {
+            return Err(invalid(
+                "devnet_name",
+                "is required for a devnet".to_string(),
+            ));
+        }
+        if let Err(e) = Uri::from_str(&self.insight_api_url) {
+            return Err(invalid(
+                "insight_api_url",
+                format!("\"{}\" is not a valid URL: {}", self.insight_api_url, e),
+            ));
+        }
+        Ok(())
+    }
+
     /// List of DAPI addresses
     pub fn dapi_address_list(&self) -> AddressList {
         AddressList::from(self.dapi_addresses.as_str())
@@ -1,11 +1,29 @@
-use crate::config::{Config, NetworkConfig};
+use crate::app::{AppCommand, AppEvent};
+use crate::app_dir::AppDirs;
+use crate::config::{Config, ConfigError, NetworkConfig};
 use crate::context_provider::Provider;
+use crate::dapi_tls::{configured_dapi_certificate, presented_dapi_certificates};
 use crate::database::Database;
+use crate::metrics_export::AppMetrics;
+use crate::model::app_profile::AppProfile;
+use crate::model::clock_skew::ClockSkew;
 use crate::model::contested_name::ContestedName;
-use crate::model::qualified_contract::QualifiedContract;
+use crate::model::health_check::HealthReport;
+use crate::model::preferences::Preferences;
+use crate::model::proxy::ProxySettings;
+use crate::model::qualified_contract::{ContractKind, QualifiedContract, RegisteredContract};
 use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::rate_limit::Endpoint;
+use crate::model::schedule::ScheduledTask;
 use crate::model::wallet::Wallet;
-use crate::sdk_wrapper::initialize_sdk;
+use crate::platform::endpoint_health::EndpointHealth;
+use crate::platform::rate_limit::RateLimiter;
+use crate::platform::response_cache::ResponseCache;
+use crate::platform::task_gate::TaskGate;
+use crate::platform::BackendTaskSuccessResult;
+use crate::request_metrics::RequestMetrics;
+use crate::sdk_wrapper::{initialize_network_sdks, NetworkSdks};
+use crate::socks_proxy::spawn_bridge;
 use crate::ui::RootScreenType;
 use dash_sdk::dashcore_rpc::{Auth, Client};
 use dash_sdk::dpp::dashcore::Network;
@@ -16,7 +34,8 @@ use dash_sdk::platform::DataContract;
 use dash_sdk::Sdk;
 use rusqlite::Result;
 use std::sync::atomic::AtomicBool;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct AppContext {
@@ -24,43 +43,111 @@ pub struct AppContext {
     pub(crate) developer_mode: bool,
     pub(crate) devnet_name: Option<String>,
     pub(crate) db: Arc<Database>,
-    pub(crate) sdk: Sdk,
+    /// Rebuilt once the certificates the DAPI nodes present are pinned, see
+    /// [`AppContext::pin_dapi_certificates`]
+    pub(crate) sdks: RwLock<NetworkSdks>,
+    /// Bound to this context, builds the SDKs again
+    provider: Provider,
+    /// Set once the pinning ran, before the first DAPI request
+    dapi_certificates_pinned: tokio::sync::OnceCell<()>,
     pub(crate) config: NetworkConfig,
     pub(crate) dpns_contract: Arc<DataContract>,
+    pub(crate) dashpay_contract: Arc<DataContract>,
+    pub(crate) withdrawals_contract: Arc<DataContract>,
     pub(crate) core_client: Client,
     pub(crate) has_wallet: AtomicBool,
     pub(crate) wallets: RwLock<Vec<Arc<RwLock<Wallet>>>>,
     pub(crate) platform_version: &'static PlatformVersion,
+    /// Shared by the contexts of all networks
+    pub(crate) preferences: Arc<RwLock<Preferences>>,
+    /// Timings of recent SDK requests, shared by the contexts of all networks
+    pub(crate) request_metrics: Arc<RequestMetrics>,
+    /// Counts of tasks, shared by both networks
+    pub(crate) app_metrics: Arc<AppMetrics>,
+    /// Where the database, config and logs live
+    pub(crate) app_dirs: Arc<AppDirs>,
+    /// Coalesces repeated queries started from this network's screens
+    pub(crate) task_gate: TaskGate,
+    /// Spaces out the tasks of this network by endpoint and backs off after failures
+    pub(crate) rate_limiter: RateLimiter,
+    /// DAPI nodes of this network that answered with proofs that did not verify
+    pub(crate) endpoint_health: EndpointHealth,
+    /// Recent query results of this network, reused by screens opened again
+    pub(crate) response_cache: ResponseCache<BackendTaskSuccessResult>,
+    /// Events published since the UI last delivered them to the screens
+    events: Mutex<Vec<AppEvent>>,
+    /// Commands sent since the UI last carried them out
+    commands: Mutex<Vec<AppCommand>>,
+    /// Results of the last health checks of this network
+    pub(crate) health_report: Mutex<Option<HealthReport>>,
+    /// The local clock compared with this network, last measured
+    pub(crate) clock_skew: Mutex<Option<ClockSkew>>,
 }
 
 impl AppContext {
-    pub fn new(network: Network, db: Arc<Database>) -> Option<Arc<Self>> {
-        let config = Config::load();
+    pub fn new(
+        network: Network,
+        db: Arc<Database>,
+        preferences: Arc<RwLock<Preferences>>,
+        request_metrics: Arc<RequestMetrics>,
+        app_metrics: Arc<AppMetrics>,
+        app_dirs: Arc<AppDirs>,
+    ) -> Result<Option<Arc<Self>>, ConfigError> {
+        let config = Config::load(&app_dirs.config_path())?;
+        let Some(network_config) = config.config_for_network(network).clone() else {
+            return Ok(None);
+        };
 
-        let network_config = config.config_for_network(network).clone()?;
+        let proxy = preferences.read().unwrap().proxy.clone();
+        let core_config = core_config_through_proxy(&network_config, network, &proxy);
 
         // we create provider, but we need to set app context to it later, as we have a circular dependency
-        let provider =
-            Provider::new(db.clone(), &network_config).expect("Failed to initialize SDK");
+        let provider = Provider::new(db.clone(), &core_config).expect("Failed to initialize SDK");
 
-        let sdk = initialize_sdk(&network_config, network, provider.clone());
+        if network_config.dapi_insecure_tls {
+            tracing::warn!(
+                "dapi_insecure_tls is on for {}, DAPI certificates are trusted without \
+                 verification",
+                network
+            );
+        }
+        // The certificates the nodes present are pinned later, off the UI thread
+        let ca_certificate = configured_dapi_certificate(&network_config).unwrap_or_else(|e| {
+            tracing::error!("{}, only the public roots are trusted", e);
+            None
+        });
+        let sdks = initialize_network_sdks(
+            &network_config,
+            network,
+            provider.clone(),
+            ca_certificate.as_deref(),
+        );
 
         let dpns_contract =
             load_system_data_contract(SystemDataContract::DPNS, PlatformVersion::latest())
                 .expect("expected to load dpns contract");
 
+        let dashpay_contract =
+            load_system_data_contract(SystemDataContract::Dashpay, PlatformVersion::latest())
+                .expect("expected to load dashpay contract");
+
+        let withdrawals_contract =
+            load_system_data_contract(SystemDataContract::Withdrawals, PlatformVersion::latest())
+                .expect("expected to load withdrawals contract");
+
         let addr = format!(
             "http://{}:{}",
-            network_config.core_host, network_config.core_rpc_port
+            core_config.core_host, core_config.core_rpc_port
         );
-        let core_client = Client::new(
+        let Ok(core_client) = Client::new(
             &addr,
             Auth::UserPass(
                 network_config.core_rpc_user.to_string(),
                 network_config.core_rpc_password.to_string(),
             ),
-        )
-        .ok()?;
+        ) else {
+            return Ok(None);
+        };
 
         let wallets: Vec<_> = db
             .get_wallets(&network)
@@ -72,21 +159,90 @@ impl AppContext {
         let app_context = AppContext {
             network,
             developer_mode: false,
-            devnet_name: None,
+            devnet_name: network_config.devnet_name.clone(),
             db,
-            sdk,
+            sdks: RwLock::new(sdks),
+            provider,
+            dapi_certificates_pinned: tokio::sync::OnceCell::new(),
             config: network_config,
             dpns_contract: Arc::new(dpns_contract),
+            dashpay_contract: Arc::new(dashpay_contract),
+            withdrawals_contract: Arc::new(withdrawals_contract),
             core_client,
             has_wallet: (!wallets.is_empty()).into(),
             wallets: RwLock::new(wallets),
             platform_version: PlatformVersion::latest(),
+            preferences,
+            request_metrics,
+            app_metrics,
+            app_dirs,
+            task_gate: TaskGate::default(),
+            rate_limiter: RateLimiter::default(),
+            endpoint_health: EndpointHealth::default(),
+            response_cache: ResponseCache::default(),
+            events: Mutex::new(Vec::new()),
+            commands: Mutex::new(Vec::new()),
+            health_report: Mutex::new(None),
+            clock_skew: Mutex::new(None),
         };
 
         let app_context = Arc::new(app_context);
-        provider.bind_app_context(app_context.clone());
+        app_context.provider.bind_app_context(app_context.clone());
+        app_context.register_system_contracts();
+
+        Ok(Some(app_context))
+    }
+
+    pub(crate) fn sdk(&self) -> Sdk {
+        self.sdks.read().unwrap().sdk.clone()
+    }
+
+    /// Same as [`Self::sdk`] but without proofs, for queries run in fast mode
+    pub(crate) fn unproved_sdk(&self) -> Sdk {
+        self.sdks.read().unwrap().unproved_sdk.clone()
+    }
 
-        Some(app_context)
+    /// An SDK with proofs for each DAPI address on its own, to retry a query on another node
+    /// after a proof did not verify
+    pub(crate) fn endpoint_sdks(&self) -> Vec<(String, Sdk)> {
+        self.sdks.read().unwrap().endpoint_sdks.clone()
+    }
+
+    /// Trusts the certificates the DAPI nodes present when `dapi_insecure_tls` is on. Runs
+    /// once, before the first DAPI request, so the TLS handshakes happen on the backend runtime
+    /// instead of holding up the UI while the context is built.
+    pub(crate) async fn pin_dapi_certificates(&self) {
+        self.dapi_certificates_pinned
+            .get_or_init(|| async {
+                if !self.config.dapi_insecure_tls {
+                    return;
+                }
+                let config = self.config.clone();
+                let presented =
+                    match tokio::task::spawn_blocking(move || presented_dapi_certificates(&config))
+                        .await
+                    {
+                        Ok(presented) if !presented.is_empty() => presented,
+                        Ok(_) => return,
+                        Err(e) => {
+                            tracing::error!("Fetching the DAPI certificates failed: {}", e);
+                            return;
+                        }
+                    };
+                let mut pem = configured_dapi_certificate(&self.config)
+                    .ok()
+                    .flatten()
+                    .unwrap_or_default();
+                pem.extend_from_slice(&presented);
+                let sdks = initialize_network_sdks(
+                    &self.config,
+                    self.network,
+                    self.provider.clone(),
+                    Some(&pem),
+                );
+                *self.sdks.write().unwrap() = sdks;
+            })
+            .await;
     }
 
     pub(crate) fn network_string(&self) -> String {
@@ -117,11 +273,15 @@ impl AppContext {
     }
 
     pub fn all_contested_names(&self) -> Result<Vec<ContestedName>> {
-        self.db.get_all_contested_names(self)
+        let mut contested_names = self.db.get_all_contested_names(self)?;
+        self.db.fill_my_votes(&mut contested_names, self)?;
+        Ok(contested_names)
     }
 
     pub fn ongoing_contested_names(&self) -> Result<Vec<ContestedName>> {
-        self.db.get_ongoing_contested_names(self)
+        let mut contested_names = self.db.get_ongoing_contested_names(self)?;
+        self.db.fill_my_votes(&mut contested_names, self)?;
+        Ok(contested_names)
     }
 
     /// Updates the `start_root_screen` in the settings table
@@ -135,24 +295,186 @@ impl AppContext {
         self.db.get_settings()
     }
 
-    /// Retrieves the DPNS contract along with other contracts from the database.
+    /// Returns a copy of the current user preferences
+    pub fn preferences(&self) -> Preferences {
+        self.preferences.read().unwrap().clone()
+    }
+
+    /// The app profiles of the current network, in the order they were added
+    pub fn app_profiles(&self) -> Vec<AppProfile> {
+        let network = self.network_string();
+        self.preferences
+            .read()
+            .unwrap()
+            .app_profiles
+            .iter()
+            .filter(|profile| profile.network == network)
+            .cloned()
+            .collect()
+    }
+
+    /// The scheduled tasks of the current network
+    pub fn scheduled_tasks(&self) -> Vec<ScheduledTask> {
+        let network = self.network_string();
+        self.preferences
+            .read()
+            .unwrap()
+            .scheduled_tasks
+            .iter()
+            .filter(|task| task.network == network)
+            .cloned()
+            .collect()
+    }
+
+    /// Waits for the next slot of the endpoint, between the items of bulk operations
+    pub async fn throttle(&self, endpoint: Endpoint) {
+        let policy = *self.preferences().rate_limits.policy(endpoint);
+        self.rate_limiter.acquire(endpoint, &policy).await;
+    }
+
+    /// A client for HTTP integrations, going through the proxy when it is on for this network
+    pub fn http_client(&self, timeout: Duration) -> std::result::Result<reqwest::Client, String> {
+        let proxy = self.preferences().proxy;
+        let mut builder = reqwest::Client::builder().timeout(timeout);
+        if let Some(url) = proxy.url_for(self.network) {
+            let proxy = reqwest::Proxy::all(url)
+                .map_err(|e| format!("The proxy {} is not valid: {}", url, e))?;
+            builder = builder.proxy(proxy);
+        }
+        builder.build().map_err(|e| e.to_string())
+    }
+
+    /// Why DAPI can't be used, `None` unless the proxy is on for this network. The Platform SDK
+    /// can't connect through a proxy, so its requests are refused rather than sent directly,
+    /// unless direct DAPI connections were allowed in the proxy settings. The message avoids
+    /// the words of transient errors, it must not be retried.
+    pub fn dapi_refused_by_proxy(&self) -> Option<String> {
+        self.preferences()
+            .proxy
+            .refuses_dapi(self.network)
+            .then(|| {
+                format!(
+                "The proxy is on for {} and DAPI can't go through it, so Platform requests are \
+                 refused instead of going out directly. Allow direct DAPI connections or turn \
+                 the proxy off for this network to use Platform.",
+                self.network
+            )
+            })
+    }
+
+    /// Tells every open screen about a change, delivered on the next frame
+    pub fn publish(&self, event: AppEvent) {
+        let mut events = self.events.lock().unwrap();
+        if !events.contains(&event) {
+            events.push(event);
+        }
+    }
+
+    /// Asks the app to carry out the command at the end of the frame
+    pub fn send(&self, command: AppCommand) {
+        self.commands.lock().unwrap().push(command);
+    }
+
+    /// Results of the last health checks, `None` until they finished once
+    pub fn health_report(&self) -> Option<HealthReport> {
+        self.health_report.lock().unwrap().clone()
+    }
+
+    /// The last clock skew measurement, `None` until the first one finished
+    pub fn clock_skew(&self) -> Option<ClockSkew> {
+        *self.clock_skew.lock().unwrap()
+    }
+
+    pub(crate) fn take_events(&self) -> Vec<AppEvent> {
+        std::mem::take(&mut *self.events.lock().unwrap())
+    }
+
+    pub(crate) fn take_commands(&self) -> Vec<AppCommand> {
+        std::mem::take(&mut *self.commands.lock().unwrap())
+    }
+
+    /// Applies a change to the user preferences and persists them
+    pub fn update_preferences(&self, update: impl FnOnce(&mut Preferences)) -> Result<()> {
+        let mut preferences = self.preferences.write().unwrap();
+        update(&mut preferences);
+        self.db.update_preferences(&preferences)
+    }
+
+    /// Makes sure the system contracts are in the known contracts registry
+    fn register_system_contracts(&self) {
+        let system_contracts = [
+            (&self.dpns_contract, "dpns", ContractKind::Dpns),
+            (&self.dashpay_contract, "dashpay", ContractKind::Dashpay),
+            (
+                &self.withdrawals_contract,
+                "withdrawals",
+                ContractKind::Withdrawals,
+            ),
+        ];
+        for (contract, name, kind) in system_contracts {
+            if let Err(e) = self.db.register_contract(contract, Some(name), kind, self) {
+                tracing::error!("Failed to register {} contract: {}", name, e);
+            }
+        }
+    }
+
+    /// Retrieves the system contracts along with other contracts from the database.
     pub fn get_contracts(
         &self,
         limit: Option<u32>,
         offset: Option<u32>,
     ) -> Result<Vec<QualifiedContract>> {
-        // Get contracts from the database
-        let mut contracts = self.db.get_contracts(self, limit, offset)?;
-
-        // Add the DPNS contract to the list
-        let dpns_contract = QualifiedContract {
-            contract: Arc::clone(&self.dpns_contract).as_ref().clone(),
-            alias: Some("dpns".to_string()), // You can adjust the alias as needed
-        };
+        self.db.get_contracts(self, limit, offset)
+    }
 
-        // Insert the DPNS contract at the beginning
-        contracts.insert(0, dpns_contract);
+    /// Retrieves the known contracts registry, system contracts first
+    pub fn get_registered_contracts(&self) -> Result<Vec<RegisteredContract>> {
+        self.db.get_registered_contracts(self)
+    }
+}
 
-        Ok(contracts)
+/// The network configuration with the Core RPC endpoint replaced by a local bridge through the
+/// proxy, when the proxy is on for the network and Core is not on this machine. When the bridge
+/// can't be set up Core RPC is refused, it never falls back to a direct connection.
+fn core_config_through_proxy(
+    network_config: &NetworkConfig,
+    network: Network,
+    proxy: &ProxySettings,
+) -> NetworkConfig {
+    let host = network_config.core_host.trim();
+    let is_local = host == "localhost"
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback());
+    if !proxy.enabled_for(network) || is_local {
+        return network_config.clone();
+    }
+    let bridge = proxy.address().and_then(|proxy_address| {
+        spawn_bridge(
+            proxy_address,
+            host.to_string(),
+            network_config.core_rpc_port,
+        )
+        .map_err(|e| e.to_string())
+    });
+    match bridge {
+        Ok(local_address) => NetworkConfig {
+            core_host: local_address.ip().to_string(),
+            core_rpc_port: local_address.port(),
+            ..network_config.clone()
+        },
+        Err(e) => {
+            tracing::error!(
+                "Core RPC is refused, the proxy is on for {} but not usable: {}",
+                network,
+                e
+            );
+            // Nothing listens on port 0, every Core request fails instead of going out directly
+            NetworkConfig {
+                core_host: "127.0.0.1".to_string(),
+                core_rpc_port: 0,
+                ..network_config.clone()
+            }
+        }
     }
 }
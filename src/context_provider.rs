@@ -42,7 +42,12 @@ impl Provider {
         ac.replace(cloned);
         drop(ac);
 
-        app_context.sdk.set_context_provider(self.clone());
+        let sdks = app_context.sdks.read().unwrap();
+        sdks.sdk.set_context_provider(self.clone());
+        sdks.unproved_sdk.set_context_provider(self.clone());
+        for (_, sdk) in &sdks.endpoint_sdks {
+            sdk.set_context_provider(self.clone());
+        }
     }
 }
 
@@ -58,6 +63,10 @@ impl ContextProvider for Provider {
 
         if data_contract_id == &app_ctx.dpns_contract.id() {
             Ok(Some(app_ctx.dpns_contract.clone()))
+        } else if data_contract_id == &app_ctx.dashpay_contract.id() {
+            Ok(Some(app_ctx.dashpay_contract.clone()))
+        } else if data_contract_id == &app_ctx.withdrawals_contract.id() {
+            Ok(Some(app_ctx.withdrawals_contract.clone()))
         } else {
             let dc = self
                 .db
@@ -91,6 +100,12 @@ impl ContextProvider for Provider {
     }
 }
 
+impl std::fmt::Debug for Provider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Provider").finish_non_exhaustive()
+    }
+}
+
 impl Clone for Provider {
     fn clone(&self) -> Self {
         let app_guard = self.app_context.lock().expect("lock poisoned");
@@ -0,0 +1,201 @@
+use crate::config::NetworkConfig;
+use base64::Engine;
+use dash_sdk::sdk::Uri;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_rustls::rustls::client::danger::{
+    HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier,
+};
+use tokio_rustls::rustls::crypto::{self, CryptoProvider};
+use tokio_rustls::rustls::pki_types::pem::PemObject;
+use tokio_rustls::rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use tokio_rustls::rustls::{
+    ClientConfig, ClientConnection, DigitallySignedStruct, Error, RootCertStore, SignatureScheme,
+};
+
+/// How long fetching the certificate of a node may take in insecure mode
+const FETCH_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// The configured DAPI CA certificate in PEM, trusted by the SDK besides the public roots.
+/// `None` when there is none.
+pub fn configured_dapi_certificate(config: &NetworkConfig) -> Result<Option<Vec<u8>>, String> {
+    let Some(path) = &config.dapi_ca_certificate else {
+        return Ok(None);
+    };
+    let mut ca = std::fs::read(path)
+        .map_err(|e| format!("Failed to read the DAPI CA certificate {}: {}", path, e))?;
+    if CertificateDer::pem_slice_iter(&ca).next().is_none() {
+        return Err(format!("{} holds no PEM certificate", path));
+    }
+    ca.push(b'\n');
+    Ok(Some(ca))
+}
+
+/// The certificates the DAPI nodes present right now in PEM, trusted in insecure mode. Runs a
+/// blocking TLS handshake with every node, so it belongs on a blocking thread of the backend
+/// runtime rather than the UI thread.
+pub fn presented_dapi_certificates(config: &NetworkConfig) -> Vec<u8> {
+    let addresses: Vec<&str> = config
+        .dapi_addresses
+        .split(',')
+        .map(str::trim)
+        .filter(|address| address.starts_with("https://"))
+        .collect();
+    let fetched: Vec<_> = std::thread::scope(|scope| {
+        let handles: Vec<_> = addresses
+            .iter()
+            .map(|address| scope.spawn(move || fetch_presented_certificate(address)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Err("panicked".to_string()))
+            })
+            .collect()
+    });
+    let mut pem = Vec::new();
+    for (address, certificate) in addresses.iter().zip(fetched) {
+        match certificate {
+            Ok(certificate) => pem.extend_from_slice(to_pem(&certificate).as_bytes()),
+            Err(e) => tracing::warn!("No certificate of {} to trust: {}", address, e),
+        }
+    }
+    pem
+}
+
+/// The TLS configuration the tool's own connections to DAPI use, following the same settings
+/// as the SDK
+pub fn dapi_client_config(config: &NetworkConfig) -> Result<ClientConfig, String> {
+    let provider = Arc::new(crypto::ring::default_provider());
+    let builder = ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| e.to_string())?;
+    if config.dapi_insecure_tls {
+        return Ok(builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate(provider)))
+            .with_no_client_auth());
+    }
+    let mut roots = RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    if let Some(path) = &config.dapi_ca_certificate {
+        let certificates = CertificateDer::pem_file_iter(path)
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        for certificate in certificates {
+            let certificate = certificate.map_err(|e| format!("{}: {}", path, e))?;
+            roots
+                .add(certificate)
+                .map_err(|e| format!("{}: {}", path, e))?;
+        }
+    }
+    Ok(builder.with_root_certificates(roots).with_no_client_auth())
+}
+
+/// Runs a TLS handshake without verification and returns the node's own certificate
+fn fetch_presented_certificate(address: &str) -> Result<CertificateDer<'static>, String> {
+    let uri = Uri::from_str(address).map_err(|e| e.to_string())?;
+    let host = uri
+        .host()
+        .ok_or("no host")?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    let port = uri.port_u16().unwrap_or(443);
+    let socket_address = (host.as_str(), port)
+        .to_socket_addrs()
+        .map_err(|e| e.to_string())?
+        .next()
+        .ok_or("resolves to no address")?;
+    let mut socket =
+        TcpStream::connect_timeout(&socket_address, FETCH_TIMEOUT).map_err(|e| e.to_string())?;
+    socket
+        .set_read_timeout(Some(FETCH_TIMEOUT))
+        .and_then(|()| socket.set_write_timeout(Some(FETCH_TIMEOUT)))
+        .map_err(|e| e.to_string())?;
+    let provider = Arc::new(crypto::ring::default_provider());
+    let config = ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| e.to_string())?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate(provider)))
+        .with_no_client_auth();
+    let server_name = ServerName::try_from(host).map_err(|e| e.to_string())?;
+    let mut connection =
+        ClientConnection::new(Arc::new(config), server_name).map_err(|e| e.to_string())?;
+    while connection.is_handshaking() {
+        connection
+            .complete_io(&mut socket)
+            .map_err(|e| e.to_string())?;
+    }
+    connection
+        .peer_certificates()
+        .and_then(|certificates| certificates.first())
+        .map(|certificate| certificate.clone().into_owned())
+        .ok_or_else(|| "presented no certificate".to_string())
+}
+
+fn to_pem(certificate: &CertificateDer) -> String {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(certificate.as_ref());
+    let mut pem = String::from("-----BEGIN CERTIFICATE-----\n");
+    for line in encoded.as_bytes().chunks(64) {
+        pem.push_str(&String::from_utf8_lossy(line));
+        pem.push('\n');
+    }
+    pem.push_str("-----END CERTIFICATE-----\n");
+    pem
+}
+
+/// Accepts any certificate while still checking the handshake signatures, for
+/// `dapi_insecure_tls`
+#[derive(Debug)]
+struct AcceptAnyCertificate(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+        crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+        crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
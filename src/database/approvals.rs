@@ -0,0 +1,72 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::approval::{Approver, AuditEntry};
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Sets the approver, replacing the previous one. The approver is the same on all networks.
+    pub fn set_approver(&self, approver: &Approver) -> Result<()> {
+        self.execute(
+            "INSERT INTO approver (id, name, salt, password_hash) VALUES (1, ?, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                salt = excluded.salt,
+                password_hash = excluded.password_hash",
+            params![approver.name, approver.salt, approver.password_hash],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_approver(&self) -> Result<Option<Approver>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt =
+            conn.prepare("SELECT name, salt, password_hash FROM approver WHERE id = 1")?;
+        let result = stmt.query_row([], |row| {
+            Ok(Approver {
+                name: row.get(0)?,
+                salt: row.get(1)?,
+                password_hash: row.get(2)?,
+            })
+        });
+        match result {
+            Ok(approver) => Ok(Some(approver)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn insert_audit_entry(&self, entry: &AuditEntry, app_context: &AppContext) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "INSERT INTO audit_log (action, outcome, approver, logged_at, network)
+             VALUES (?, ?, ?, ?, ?)",
+            params![
+                entry.action,
+                entry.outcome,
+                entry.approver,
+                entry.logged_at,
+                network
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The most recent entries of the audit trail, newest first
+    pub fn get_audit_log(&self, limit: u32, app_context: &AppContext) -> Result<Vec<AuditEntry>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT action, outcome, approver, logged_at FROM audit_log
+             WHERE network = ? ORDER BY logged_at DESC, id DESC LIMIT ?",
+        )?;
+        let entries = stmt.query_map(params![network, limit], |row| {
+            Ok(AuditEntry {
+                action: row.get(0)?,
+                outcome: row.get(1)?,
+                approver: row.get(2)?,
+                logged_at: row.get(3)?,
+            })
+        })?;
+        entries.collect()
+    }
+}
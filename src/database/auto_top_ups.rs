@@ -0,0 +1,44 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::auto_top_up::TOP_UP_WINDOW_MS;
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::identifier::Identifier;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Records duffs an automatic top up funded, for the weekly limits of the rules
+    pub fn record_auto_top_up(
+        &self,
+        identity_id: &Identifier,
+        duffs: Duffs,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        let topped_up_at = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        self.execute(
+            "INSERT INTO auto_top_up (identity_id, duffs, topped_up_at, network)
+             VALUES (?, ?, ?, ?)",
+            params![identity_id.to_vec(), duffs, topped_up_at, network],
+        )?;
+        Ok(())
+    }
+
+    /// Duffs automatic top ups funded for the identity within the last week
+    pub fn get_auto_top_up_spent_this_week(
+        &self,
+        identity_id: &Identifier,
+        app_context: &AppContext,
+    ) -> Result<Duffs> {
+        let network = app_context.network_string();
+        let since = (std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64)
+            .saturating_sub(TOP_UP_WINDOW_MS);
+        let conn = self.conn.lock().unwrap();
+        let spent: Option<i64> = conn.query_row(
+            "SELECT SUM(duffs) FROM auto_top_up
+             WHERE identity_id = ? AND network = ? AND topped_up_at >= ?",
+            params![identity_id.to_vec(), network, since],
+            |row| row.get(0),
+        )?;
+        Ok(spent.unwrap_or_default() as Duffs)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::cold_signing::{ColdSigningRecord, ColdSigningStatus};
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Stores the status of a cold signed transition, replacing the previous one
+    pub fn set_cold_signing_status(
+        &self,
+        record: &ColdSigningRecord,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "INSERT INTO cold_signing
+             (request_id, identity_id, description, status, error, updated_at, network)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(request_id, network) DO UPDATE SET
+                status = excluded.status,
+                error = excluded.error,
+                updated_at = excluded.updated_at",
+            params![
+                record.request_id,
+                record.identity_id,
+                record.description,
+                record.status.as_str(),
+                record.error,
+                record.updated_at,
+                network
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The cold signed transitions of the network, most recently updated first
+    pub fn get_cold_signing_records(
+        &self,
+        app_context: &AppContext,
+    ) -> Result<Vec<ColdSigningRecord>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT request_id, identity_id, description, status, error, updated_at
+             FROM cold_signing WHERE network = ? ORDER BY updated_at DESC",
+        )?;
+        let rows = stmt.query_map(params![network], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, Option<String>>(4)?,
+                row.get::<_, i64>(5)?,
+            ))
+        })?;
+        let mut records = Vec::new();
+        for row in rows {
+            let (request_id, identity_id, description, status, error, updated_at) = row?;
+            let Some(status) = ColdSigningStatus::from_str(&status) else {
+                continue;
+            };
+            records.push(ColdSigningRecord {
+                request_id,
+                identity_id,
+                description,
+                status,
+                error,
+                updated_at,
+            });
+        }
+        Ok(records)
+    }
+}
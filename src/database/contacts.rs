@@ -0,0 +1,70 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::identity_details::Contact;
+use dash_sdk::platform::Identifier;
+use rusqlite::{params, OptionalExtension, Result};
+
+impl Database {
+    /// Adds an identity to the address book, or updates its alias
+    pub fn insert_or_update_contact(
+        &self,
+        identity_id: &Identifier,
+        alias: Option<&str>,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "INSERT OR REPLACE INTO contact (identity_id, alias, network) VALUES (?, ?, ?)",
+            params![identity_id.to_vec(), alias, network],
+        )?;
+        Ok(())
+    }
+
+    pub fn remove_contact(&self, identity_id: &Identifier, app_context: &AppContext) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "DELETE FROM contact WHERE identity_id = ? AND network = ?",
+            params![identity_id.to_vec(), network],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_contact(
+        &self,
+        identity_id: &Identifier,
+        app_context: &AppContext,
+    ) -> Result<Option<Contact>> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt =
+            conn.prepare("SELECT alias FROM contact WHERE identity_id = ? AND network = ?")?;
+        stmt.query_row(params![identity_id.to_vec(), network], |row| {
+            Ok(Contact {
+                identity_id: *identity_id,
+                alias: row.get(0)?,
+            })
+        })
+        .optional()
+    }
+
+    pub fn get_contacts(&self, app_context: &AppContext) -> Result<Vec<Contact>> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT identity_id, alias FROM contact WHERE network = ?")?;
+        let contacts = stmt.query_map(params![network], |row| {
+            let identity_id: Vec<u8> = row.get(0)?;
+            Ok((identity_id, row.get::<_, Option<String>>(1)?))
+        })?;
+
+        let mut result = Vec::new();
+        for contact in contacts {
+            let (identity_id, alias) = contact?;
+            if let Ok(identity_id) = Identifier::from_bytes(&identity_id) {
+                result.push(Contact { identity_id, alias });
+            }
+        }
+        Ok(result)
+    }
+}
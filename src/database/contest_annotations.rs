@@ -0,0 +1,63 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::contest_annotation::ContestAnnotation;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Stores a note, replacing the one on the same contest or contestant. An empty note is
+    /// removed.
+    pub fn save_contest_annotation(
+        &self,
+        annotation: &ContestAnnotation,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        let contestant = annotation.contestant.clone().unwrap_or_default();
+        if annotation.is_empty() {
+            self.execute(
+                "DELETE FROM contest_annotation
+                 WHERE contest = ? AND contestant = ? AND network = ?",
+                params![annotation.contest, contestant, network],
+            )?;
+            return Ok(());
+        }
+        self.execute(
+            "INSERT OR REPLACE INTO contest_annotation
+             (contest, contestant, tags, note, updated_at, network)
+             VALUES (?, ?, ?, ?, ?, ?)",
+            params![
+                annotation.contest,
+                contestant,
+                annotation.tags.join(","),
+                annotation.note.trim(),
+                chrono::Utc::now().timestamp(),
+                network
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The notes on contests and contestants, by contest and with the contest's own note first
+    pub fn get_contest_annotations(
+        &self,
+        app_context: &AppContext,
+    ) -> Result<Vec<ContestAnnotation>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT contest, contestant, tags, note FROM contest_annotation
+             WHERE network = ? ORDER BY contest, contestant",
+        )?;
+        let annotations = stmt.query_map(params![network], |row| {
+            let contestant: String = row.get(1)?;
+            let tags: String = row.get(2)?;
+            Ok(ContestAnnotation {
+                contest: row.get(0)?,
+                contestant: (!contestant.is_empty()).then_some(contestant),
+                tags: ContestAnnotation::parse_tags(&tags),
+                note: row.get(3)?,
+            })
+        })?;
+        annotations.collect()
+    }
+}
@@ -0,0 +1,116 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::contestant_reputation::{ContestOutcomes, ContestantReputation};
+use dash_sdk::platform::Identifier;
+use rusqlite::{params, Result};
+use std::collections::HashMap;
+
+impl Database {
+    /// Stores the signals fetched from Platform, replacing an earlier fetch
+    pub fn save_contestant_reputation(
+        &self,
+        reputation: &ContestantReputation,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "INSERT OR REPLACE INTO contestant_reputation
+             (identity_id, names_held, has_dashpay_profile, active_since, fetched_at, network)
+             VALUES (?, ?, ?, ?, ?, ?)",
+            params![
+                reputation.identity_id.to_vec(),
+                reputation.names_held,
+                reputation.has_dashpay_profile,
+                reputation.active_since,
+                reputation.fetched_at,
+                network
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The reputation of every contestant fetched so far, with the outcomes of the contests
+    /// stored locally. Contested domains count towards the time the identity is active.
+    pub fn get_contestant_reputations(
+        &self,
+        app_context: &AppContext,
+    ) -> Result<HashMap<Identifier, ContestantReputation>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+
+        let mut outcomes: HashMap<Vec<u8>, ContestOutcomes> = HashMap::new();
+        let mut first_contested: HashMap<Vec<u8>, u64> = HashMap::new();
+        let mut stmt = conn.prepare(
+            "SELECT c.identity_id, n.awarded_to, n.locked, c.created_at
+             FROM contestant c
+             JOIN contested_name n
+               ON c.normalized_contested_name = n.normalized_contested_name
+              AND c.network = n.network
+             WHERE c.network = ?",
+        )?;
+        let rows = stmt.query_map(params![network], |row| {
+            Ok((
+                row.get::<_, Vec<u8>>(0)?,
+                row.get::<_, Option<Vec<u8>>>(1)?,
+                row.get::<_, bool>(2)?,
+                row.get::<_, Option<u64>>(3)?,
+            ))
+        })?;
+        for row in rows {
+            let (identity_id, awarded_to, locked, created_at) = row?;
+            if let Some(created_at) = created_at {
+                let first = first_contested
+                    .entry(identity_id.clone())
+                    .or_insert(created_at);
+                *first = (*first).min(created_at);
+            }
+            let outcome = outcomes.entry(identity_id.clone()).or_default();
+            match awarded_to {
+                _ if locked => outcome.locked += 1,
+                Some(winner) if winner == identity_id => outcome.won += 1,
+                Some(_) => outcome.lost += 1,
+                None => outcome.ongoing += 1,
+            }
+        }
+
+        let mut stmt = conn.prepare(
+            "SELECT identity_id, names_held, has_dashpay_profile, active_since, fetched_at
+             FROM contestant_reputation WHERE network = ?",
+        )?;
+        let rows = stmt.query_map(params![network], |row| {
+            Ok((
+                row.get::<_, Vec<u8>>(0)?,
+                row.get::<_, u32>(1)?,
+                row.get::<_, bool>(2)?,
+                row.get::<_, Option<u64>>(3)?,
+                row.get::<_, i64>(4)?,
+            ))
+        })?;
+        let mut reputations = HashMap::new();
+        for row in rows {
+            let (identity_id_bytes, names_held, has_dashpay_profile, active_since, fetched_at) =
+                row?;
+            let Ok(identity_id) = Identifier::from_bytes(&identity_id_bytes) else {
+                continue;
+            };
+            reputations.insert(
+                identity_id,
+                ContestantReputation {
+                    identity_id,
+                    names_held,
+                    has_dashpay_profile,
+                    active_since: active_since
+                        .into_iter()
+                        .chain(first_contested.get(&identity_id_bytes).copied())
+                        .min(),
+                    fetched_at,
+                    outcomes: outcomes
+                        .get(&identity_id_bytes)
+                        .copied()
+                        .unwrap_or_default(),
+                },
+            );
+        }
+        Ok(reputations)
+    }
+}
@@ -1,11 +1,13 @@
 use crate::context::AppContext;
 use crate::database::Database;
 use crate::model::contested_name::{ContestState, Contestant, ContestedName};
+use crate::model::identity_details::IdentityActivity;
+use crate::model::qualified_identity::EncryptedPrivateKeyTarget;
 use dash_sdk::dpp::dashcore::Network;
 use dash_sdk::dpp::data_contract::document_type::DocumentTypeRef;
 use dash_sdk::dpp::document::DocumentV0Getters;
 use dash_sdk::dpp::identifier::Identifier;
-use dash_sdk::dpp::identity::TimestampMillis;
+use dash_sdk::dpp::identity::{KeyID, TimestampMillis};
 use dash_sdk::dpp::prelude::{BlockHeight, CoreBlockHeight};
 use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
 use dash_sdk::dpp::voting::vote_info_storage::contested_document_vote_poll_winner_info::ContestedDocumentVotePollWinnerInfo;
@@ -768,4 +770,183 @@ impl Database {
         info!("Vote tally updated successfully for '{}'", contested_name);
         Ok(())
     }
+
+    /// Marks a contest as watched or removes it from the watch list
+    pub fn set_contest_watched(
+        &self,
+        normalized_contested_name: &str,
+        watched: bool,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        if watched {
+            self.execute(
+                "INSERT OR IGNORE INTO watched_contest (normalized_contested_name, network)
+                 VALUES (?, ?)",
+                params![normalized_contested_name, network],
+            )?;
+        } else {
+            self.execute(
+                "DELETE FROM watched_contest WHERE normalized_contested_name = ? AND network = ?",
+                params![normalized_contested_name, network],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Retrieves the normalized names of all watched contests
+    pub fn get_watched_contests(&self, app_context: &AppContext) -> Result<HashSet<String>> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn
+            .prepare("SELECT normalized_contested_name FROM watched_contest WHERE network = ?")?;
+        let names = stmt.query_map(params![network], |row| row.get(0))?;
+
+        names.collect()
+    }
+
+    /// Records a vote cast by one of our identities, replacing an earlier vote on the same contest
+    pub fn insert_my_vote(
+        &self,
+        normalized_contested_name: &str,
+        voter_id: Identifier,
+        key_id: KeyID,
+        vote_choice: ResourceVoteChoice,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        let vote_choice_bytes = bincode::encode_to_vec(vote_choice, bincode::config::standard())
+            .expect("expected to encode vote choice");
+        let voted_at = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+
+        self.execute(
+            "INSERT OR REPLACE INTO my_vote (normalized_contested_name, voter_id, key_id, vote_choice, voted_at, network)
+             VALUES (?, ?, ?, ?, ?, ?)",
+            params![
+                normalized_contested_name,
+                voter_id.to_vec(),
+                key_id,
+                vote_choice_bytes,
+                voted_at,
+                network
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Fills in `my_votes` on the given contested names from the votes we recorded
+    pub fn fill_my_votes(
+        &self,
+        contested_names: &mut [ContestedName],
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT normalized_contested_name, voter_id, key_id, vote_choice
+             FROM my_vote WHERE network = ?",
+        )?;
+        let rows = stmt.query_map(params![network], |row| {
+            let name: String = row.get(0)?;
+            let voter_id: Vec<u8> = row.get(1)?;
+            let key_id: KeyID = row.get(2)?;
+            let vote_choice: Vec<u8> = row.get(3)?;
+            Ok((name, voter_id, key_id, vote_choice))
+        })?;
+
+        let mut votes_by_name: HashMap<String, Vec<(Identifier, KeyID, ResourceVoteChoice)>> =
+            HashMap::new();
+        for row in rows {
+            let (name, voter_id, key_id, vote_choice) = row?;
+            let Ok(voter_id) = Identifier::from_bytes(&voter_id) else {
+                continue;
+            };
+            let Ok((vote_choice, _)) =
+                bincode::decode_from_slice(&vote_choice, bincode::config::standard())
+            else {
+                continue;
+            };
+            votes_by_name
+                .entry(name)
+                .or_default()
+                .push((voter_id, key_id, vote_choice));
+        }
+
+        for contested_name in contested_names.iter_mut() {
+            if let Some(votes) = votes_by_name.remove(&contested_name.normalized_contested_name) {
+                contested_name.my_votes = votes
+                    .into_iter()
+                    .map(|(voter_id, key_id, vote_choice)| {
+                        (
+                            (
+                                voter_id,
+                                EncryptedPrivateKeyTarget::PrivateKeyOnVoterIdentity,
+                                key_id,
+                            ),
+                            vote_choice,
+                        )
+                    })
+                    .collect();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Contest participation and votes of an identity, most recent first
+    pub fn get_identity_activity(
+        &self,
+        identifier: &Identifier,
+        app_context: &AppContext,
+    ) -> Result<Vec<IdentityActivity>> {
+        let network = app_context.network_string();
+        let id = identifier.to_vec();
+
+        let conn = self.conn.lock().unwrap();
+        let mut activity = Vec::new();
+
+        let mut stmt = conn.prepare(
+            "SELECT normalized_contested_name, votes, created_at
+             FROM contestant WHERE identity_id = ? AND network = ?",
+        )?;
+        let contestants = stmt.query_map(params![id, network], |row| {
+            Ok(IdentityActivity::Contestant {
+                normalized_contested_name: row.get(0)?,
+                votes: row.get::<_, Option<u32>>(1)?.unwrap_or_default(),
+                created_at: row.get(2)?,
+            })
+        })?;
+        for contestant in contestants {
+            activity.push(contestant?);
+        }
+
+        let mut stmt = conn.prepare(
+            "SELECT normalized_contested_name, vote_choice, voted_at
+             FROM my_vote WHERE voter_id = ? AND network = ?",
+        )?;
+        let votes = stmt.query_map(params![id, network], |row| {
+            let name: String = row.get(0)?;
+            let vote_choice: Vec<u8> = row.get(1)?;
+            let voted_at: TimestampMillis = row.get(2)?;
+            Ok((name, vote_choice, voted_at))
+        })?;
+        for vote in votes {
+            let (normalized_contested_name, vote_choice, voted_at) = vote?;
+            let Ok((vote_choice, _)) =
+                bincode::decode_from_slice(&vote_choice, bincode::config::standard())
+            else {
+                continue;
+            };
+            activity.push(IdentityActivity::Vote {
+                normalized_contested_name,
+                vote_choice,
+                voted_at,
+            });
+        }
+
+        activity.sort_by(|a, b| b.timestamp().cmp(&a.timestamp()));
+        Ok(activity)
+    }
 }
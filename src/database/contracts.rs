@@ -1,6 +1,6 @@
 use crate::context::AppContext;
 use crate::database::Database;
-use crate::model::qualified_contract::QualifiedContract;
+use crate::model::qualified_contract::{ContractKind, QualifiedContract, RegisteredContract};
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::DataContract;
 use dash_sdk::dpp::identifier::Identifier;
@@ -184,4 +184,108 @@ impl Database {
 
         Ok(contracts)
     }
+
+    /// Adds a contract to the known contracts registry, caching its schema if needed
+    pub fn register_contract(
+        &self,
+        data_contract: &DataContract,
+        contract_name: Option<&str>,
+        kind: ContractKind,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        self.insert_contract_if_not_exists(data_contract, contract_name, app_context)?;
+        let network = app_context.network_string();
+        self.execute(
+            "INSERT OR IGNORE INTO contract_registry (contract_id, kind, network) VALUES (?, ?, ?)",
+            params![data_contract.id().to_vec(), kind.as_str(), network],
+        )?;
+        Ok(())
+    }
+
+    /// Replaces the cached schema of a contract with a newer version
+    pub fn replace_contract(
+        &self,
+        data_contract: &DataContract,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let contract_bytes = data_contract
+            .serialize_to_bytes_with_platform_version(app_context.platform_version)
+            .expect("expected to serialize contract");
+        let network = app_context.network_string();
+        self.execute(
+            "UPDATE contract SET contract = ? WHERE contract_id = ? AND network = ?",
+            params![contract_bytes, data_contract.id().to_vec(), network],
+        )?;
+        self.update_contract_onchain_version(
+            data_contract.id(),
+            Some(data_contract.version()),
+            app_context,
+        )
+    }
+
+    /// Records the version found on Platform, `None` if the contract wasn't found
+    pub fn update_contract_onchain_version(
+        &self,
+        contract_id: Identifier,
+        onchain_version: Option<u32>,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        let last_checked = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        self.execute(
+            "UPDATE contract_registry SET onchain_version = ?, last_checked = ?
+             WHERE contract_id = ? AND network = ?",
+            params![onchain_version, last_checked, contract_id.to_vec(), network],
+        )?;
+        Ok(())
+    }
+
+    /// Retrieves all contracts of the registry, system contracts first
+    pub fn get_registered_contracts(
+        &self,
+        app_context: &AppContext,
+    ) -> Result<Vec<RegisteredContract>> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT c.contract, c.name, r.kind, r.onchain_version, r.last_checked
+             FROM contract c
+             LEFT JOIN contract_registry r
+                ON r.contract_id = c.contract_id AND r.network = c.network
+             WHERE c.network = ?",
+        )?;
+        let mut rows = stmt.query(params![network])?;
+
+        let mut contracts = Vec::new();
+        while let Some(row) = rows.next()? {
+            let contract_bytes: Vec<u8> = row.get(0)?;
+            let alias: Option<String> = row.get(1)?;
+            let kind: Option<String> = row.get(2)?;
+            let onchain_version: Option<u32> = row.get(3)?;
+            let last_checked: Option<u64> = row.get(4)?;
+
+            match DataContract::versioned_deserialize(
+                &contract_bytes,
+                false,
+                app_context.platform_version,
+            ) {
+                Ok(contract) => contracts.push(RegisteredContract {
+                    qualified_contract: QualifiedContract { contract, alias },
+                    kind: kind
+                        .as_deref()
+                        .map_or(ContractKind::Custom, ContractKind::from_str),
+                    onchain_version,
+                    last_checked,
+                }),
+                Err(e) => {
+                    eprintln!("Deserialization error: {}", e);
+                    continue;
+                }
+            }
+        }
+
+        contracts.sort_by_key(|contract| !contract.kind.is_system());
+        Ok(contracts)
+    }
 }
@@ -0,0 +1,72 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::credit_operation::{CreditOperation, CreditOperationKind};
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::platform::Identifier;
+use rusqlite::{params, Result};
+
+impl Database {
+    pub fn insert_credit_operation(
+        &self,
+        operation: &CreditOperation,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "INSERT INTO credit_operation
+             (identity_id, kind, credits, fee, description, occurred_at, network)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+            params![
+                operation.identity_id.to_vec(),
+                operation.kind.as_str(),
+                operation.credits,
+                operation.fee,
+                operation.description,
+                operation.occurred_at,
+                network
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// All recorded credit operations of the network, oldest first
+    pub fn get_credit_operations(&self, app_context: &AppContext) -> Result<Vec<CreditOperation>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT identity_id, kind, credits, fee, description, occurred_at
+             FROM credit_operation WHERE network = ? ORDER BY occurred_at, rowid",
+        )?;
+        let rows = stmt.query_map(params![network], |row| {
+            let identity_id: Vec<u8> = row.get(0)?;
+            let kind: String = row.get(1)?;
+            Ok((
+                identity_id,
+                kind,
+                row.get::<_, Credits>(2)?,
+                row.get::<_, Credits>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, i64>(5)?,
+            ))
+        })?;
+        let mut operations = Vec::new();
+        for row in rows {
+            let (identity_id, kind, credits, fee, description, occurred_at) = row?;
+            let (Ok(identity_id), Some(kind)) = (
+                Identifier::from_bytes(&identity_id),
+                CreditOperationKind::from_str(&kind),
+            ) else {
+                continue;
+            };
+            operations.push(CreditOperation {
+                identity_id,
+                kind,
+                credits,
+                fee,
+                description,
+                occurred_at,
+            });
+        }
+        Ok(operations)
+    }
+}
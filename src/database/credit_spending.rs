@@ -0,0 +1,44 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::spending_limit::SPENDING_WINDOW_MS;
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identifier::Identifier;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Records credits that left an identity through a withdrawal or transfer
+    pub fn record_credit_spending(
+        &self,
+        identity_id: &Identifier,
+        credits: Credits,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        let spent_at = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        self.execute(
+            "INSERT INTO credit_spending (identity_id, credits, spent_at, network)
+             VALUES (?, ?, ?, ?)",
+            params![identity_id.to_vec(), credits, spent_at, network],
+        )?;
+        Ok(())
+    }
+
+    /// Credits the identity spent within the last 24 hours
+    pub fn get_credits_spent_today(
+        &self,
+        identity_id: &Identifier,
+        app_context: &AppContext,
+    ) -> Result<Credits> {
+        let network = app_context.network_string();
+        let since =
+            std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64 - SPENDING_WINDOW_MS;
+        let conn = self.conn.lock().unwrap();
+        let spent: Option<i64> = conn.query_row(
+            "SELECT SUM(credits) FROM credit_spending
+             WHERE identity_id = ? AND network = ? AND spent_at >= ?",
+            params![identity_id.to_vec(), network, since],
+            |row| row.get(0),
+        )?;
+        Ok(spent.unwrap_or_default() as Credits)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::document_revision::DocumentRevision;
+use dash_sdk::dpp::prelude::Identifier;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Keeps a fetched copy of a document. A revision that was fetched before keeps its first
+    /// copy.
+    pub fn save_document_revision(
+        &self,
+        contract_id: &Identifier,
+        document_type: &str,
+        revision: &DocumentRevision,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "INSERT OR IGNORE INTO document_revision
+             (document_id, revision, contract_id, document_type, json, fetched_at, network)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+            params![
+                revision.document_id.to_vec(),
+                revision.revision,
+                contract_id.to_vec(),
+                document_type,
+                revision.json.to_string(),
+                revision.fetched_at,
+                network
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The cached copies of a document, oldest revision first
+    pub fn get_document_revisions(
+        &self,
+        document_id: &Identifier,
+        app_context: &AppContext,
+    ) -> Result<Vec<DocumentRevision>> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT revision, json, fetched_at FROM document_revision
+             WHERE document_id = ? AND network = ? ORDER BY revision",
+        )?;
+        let revisions = stmt.query_map(params![document_id.to_vec(), network], |row| {
+            let json: String = row.get(1)?;
+            Ok(DocumentRevision {
+                document_id: *document_id,
+                revision: row.get(0)?,
+                json: serde_json::from_str(&json).unwrap_or_default(),
+                fetched_at: row.get(2)?,
+            })
+        })?;
+        revisions.collect()
+    }
+}
@@ -0,0 +1,116 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::document_snapshot::{DocumentSnapshot, SnapshotDocument};
+use dash_sdk::dpp::prelude::Identifier;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Stores a snapshot with its documents, returns its id
+    pub fn insert_document_snapshot(
+        &self,
+        contract_id: &Identifier,
+        document_type: &str,
+        label: &str,
+        documents: &[SnapshotDocument],
+        truncated: bool,
+        app_context: &AppContext,
+    ) -> Result<i64> {
+        let network = app_context.network_string();
+        let taken_at = chrono::Utc::now().timestamp();
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "INSERT INTO document_snapshot
+             (contract_id, document_type, label, taken_at, truncated, network)
+             VALUES (?, ?, ?, ?, ?, ?)",
+            params![
+                contract_id.to_vec(),
+                document_type,
+                label,
+                taken_at,
+                truncated,
+                network
+            ],
+        )?;
+        let snapshot_id = tx.last_insert_rowid();
+        for document in documents {
+            tx.execute(
+                "INSERT OR REPLACE INTO document_snapshot_entry
+                 (snapshot_id, document_id, revision, json) VALUES (?, ?, ?, ?)",
+                params![
+                    snapshot_id,
+                    document.document_id,
+                    document.revision,
+                    document.json.to_string()
+                ],
+            )?;
+        }
+        tx.commit()?;
+        Ok(snapshot_id)
+    }
+
+    /// The snapshots of a document type, newest first
+    pub fn get_document_snapshots(
+        &self,
+        contract_id: &Identifier,
+        document_type: &str,
+        app_context: &AppContext,
+    ) -> Result<Vec<DocumentSnapshot>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT s.id, s.label, s.taken_at, s.truncated,
+                    (SELECT COUNT(*) FROM document_snapshot_entry e WHERE e.snapshot_id = s.id)
+             FROM document_snapshot s
+             WHERE s.contract_id = ? AND s.document_type = ? AND s.network = ?
+             ORDER BY s.taken_at DESC, s.id DESC",
+        )?;
+        let snapshots = stmt.query_map(
+            params![contract_id.to_vec(), document_type, network],
+            |row| {
+                Ok(DocumentSnapshot {
+                    id: row.get(0)?,
+                    contract_id: *contract_id,
+                    document_type: document_type.to_string(),
+                    label: row.get(1)?,
+                    taken_at: row.get(2)?,
+                    truncated: row.get(3)?,
+                    document_count: row.get::<_, i64>(4)? as usize,
+                })
+            },
+        )?;
+        snapshots.collect()
+    }
+
+    /// The documents of a snapshot, by document id
+    pub fn get_snapshot_documents(&self, snapshot_id: i64) -> Result<Vec<SnapshotDocument>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT document_id, revision, json FROM document_snapshot_entry
+             WHERE snapshot_id = ? ORDER BY document_id",
+        )?;
+        let documents = stmt.query_map(params![snapshot_id], |row| {
+            let json: String = row.get(2)?;
+            Ok(SnapshotDocument {
+                document_id: row.get(0)?,
+                revision: row.get(1)?,
+                json: serde_json::from_str(&json).unwrap_or_default(),
+            })
+        })?;
+        documents.collect()
+    }
+
+    pub fn delete_document_snapshot(&self, snapshot_id: i64) -> Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM document_snapshot_entry WHERE snapshot_id = ?",
+            params![snapshot_id],
+        )?;
+        tx.execute(
+            "DELETE FROM document_snapshot WHERE id = ?",
+            params![snapshot_id],
+        )?;
+        tx.commit()
+    }
+}
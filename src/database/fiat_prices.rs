@@ -0,0 +1,33 @@
+use crate::database::Database;
+use crate::model::price_feed::FiatPrice;
+use rusqlite::{params, OptionalExtension, Result};
+
+impl Database {
+    /// Keeps the last fetched price of each currency, to show fiat values while offline
+    pub fn save_fiat_price(&self, price: &FiatPrice) -> Result<()> {
+        self.execute(
+            "INSERT INTO fiat_price (currency, price, fetched_at) VALUES (?, ?, ?)
+             ON CONFLICT(currency) DO UPDATE SET
+                price = excluded.price,
+                fetched_at = excluded.fetched_at",
+            params![price.currency, price.price, price.fetched_at],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_fiat_price(&self, currency: &str) -> Result<Option<FiatPrice>> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            "SELECT currency, price, fetched_at FROM fiat_price WHERE currency = ?",
+            params![currency.trim().to_uppercase()],
+            |row| {
+                Ok(FiatPrice {
+                    currency: row.get(0)?,
+                    price: row.get(1)?,
+                    fetched_at: row.get(2)?,
+                })
+            },
+        )
+        .optional()
+    }
+}
@@ -0,0 +1,61 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::form_draft::FormDraft;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Saves a draft of the form, over the draft with the given id if there is one.
+    /// Returns the id of the saved draft.
+    pub fn save_form_draft(
+        &self,
+        id: Option<i64>,
+        form: &str,
+        title: &str,
+        content: &str,
+        app_context: &AppContext,
+    ) -> Result<i64> {
+        let network = app_context.network_string();
+        let saved_at = chrono::Utc::now().timestamp();
+        let conn = self.conn.lock().unwrap();
+        if let Some(id) = id {
+            let updated = conn.execute(
+                "UPDATE form_draft SET title = ?, content = ?, saved_at = ? WHERE id = ?",
+                params![title, content, saved_at, id],
+            )?;
+            if updated > 0 {
+                return Ok(id);
+            }
+        }
+        conn.execute(
+            "INSERT INTO form_draft (form, title, content, saved_at, network)
+             VALUES (?, ?, ?, ?, ?)",
+            params![form, title, content, saved_at, network],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// The drafts of the form, most recently saved first
+    pub fn get_form_drafts(&self, form: &str, app_context: &AppContext) -> Result<Vec<FormDraft>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, form, title, content, saved_at FROM form_draft
+             WHERE form = ? AND network = ? ORDER BY saved_at DESC, id DESC",
+        )?;
+        let drafts = stmt.query_map(params![form, network], |row| {
+            Ok(FormDraft {
+                id: row.get(0)?,
+                form: row.get(1)?,
+                title: row.get(2)?,
+                content: row.get(3)?,
+                saved_at: row.get(4)?,
+            })
+        })?;
+        drafts.collect()
+    }
+
+    pub fn delete_form_draft(&self, id: i64) -> Result<()> {
+        self.execute("DELETE FROM form_draft WHERE id = ?", params![id])?;
+        Ok(())
+    }
+}
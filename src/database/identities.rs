@@ -1,9 +1,10 @@
 use crate::context::AppContext;
 use crate::database::Database;
-use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::qualified_identity::{IdentityGroup, QualifiedIdentity};
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::platform::Identifier;
-use rusqlite::params;
+use rusqlite::{params, OptionalExtension};
+use std::collections::HashMap;
 
 impl Database {
     pub fn insert_local_qualified_identity(
@@ -120,4 +121,112 @@ impl Database {
         let identities: rusqlite::Result<Vec<QualifiedIdentity>> = identity_iter.collect();
         identities
     }
+
+    /// Retrieves a stored identity by its identifier, whether local or remote
+    pub fn get_qualified_identity_by_id(
+        &self,
+        identifier: &Identifier,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<Option<QualifiedIdentity>> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT data FROM identity WHERE id = ? AND network = ? AND data IS NOT NULL",
+        )?;
+        stmt.query_row(params![identifier.to_vec(), network], |row| {
+            let data: Vec<u8> = row.get(0)?;
+            Ok(QualifiedIdentity::from_bytes(&data))
+        })
+        .optional()
+    }
+
+    /// Marks an identity as watched or removes it from the watch list
+    pub fn set_identity_watched(
+        &self,
+        identifier: &Identifier,
+        watched: bool,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network_string();
+        if watched {
+            self.execute(
+                "INSERT OR IGNORE INTO watched_identity (identity_id, network) VALUES (?, ?)",
+                params![identifier.to_vec(), network],
+            )?;
+        } else {
+            self.execute(
+                "DELETE FROM watched_identity WHERE identity_id = ? AND network = ?",
+                params![identifier.to_vec(), network],
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn is_identity_watched(
+        &self,
+        identifier: &Identifier,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<bool> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT COUNT(*) FROM watched_identity WHERE identity_id = ? AND network = ?",
+        )?;
+        let count: i64 = stmt.query_row(params![identifier.to_vec(), network], |row| row.get(0))?;
+        Ok(count > 0)
+    }
+
+    /// Moves an identity to a group, or back to its default group when `group` is `None`
+    pub fn set_identity_group(
+        &self,
+        identifier: &Identifier,
+        group: Option<IdentityGroup>,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<()> {
+        let network = app_context.network_string();
+        match group {
+            Some(group) => self.execute(
+                "INSERT OR REPLACE INTO identity_group (identity_id, group_name, network)
+                 VALUES (?, ?, ?)",
+                params![identifier.to_vec(), group.id(), network],
+            )?,
+            None => self.execute(
+                "DELETE FROM identity_group WHERE identity_id = ? AND network = ?",
+                params![identifier.to_vec(), network],
+            )?,
+        };
+        Ok(())
+    }
+
+    /// Groups the user moved identities to, identities not in the map are in their default group
+    pub fn get_identity_groups(
+        &self,
+        app_context: &AppContext,
+    ) -> rusqlite::Result<HashMap<Identifier, IdentityGroup>> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt =
+            conn.prepare("SELECT identity_id, group_name FROM identity_group WHERE network = ?")?;
+        let rows = stmt.query_map(params![network], |row| {
+            let identity_id: Vec<u8> = row.get(0)?;
+            let group_name: String = row.get(1)?;
+            Ok((identity_id, group_name))
+        })?;
+
+        let mut groups = HashMap::new();
+        for row in rows {
+            let (identity_id, group_name) = row?;
+            let (Ok(identifier), Some(group)) = (
+                Identifier::from_bytes(&identity_id),
+                IdentityGroup::from_id(&group_name),
+            ) else {
+                continue;
+            };
+            groups.insert(identifier, group);
+        }
+        Ok(groups)
+    }
 }
@@ -12,6 +12,15 @@ impl Database {
             [],
         )?;
 
+        // Create the preferences table
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS preferences (
+            id INTEGER PRIMARY KEY CHECK (id = 1),
+            data TEXT NOT NULL
+        )",
+            [],
+        )?;
+
         // Create the wallet table
         self.execute(
             "CREATE TABLE IF NOT EXISTS wallet (
@@ -119,6 +128,320 @@ impl Database {
             [],
         )?;
 
+        // Create the table of votes cast by local identities
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS my_vote (
+                normalized_contested_name TEXT NOT NULL,
+                voter_id BLOB NOT NULL,
+                key_id INTEGER NOT NULL,
+                vote_choice BLOB NOT NULL,
+                voted_at INTEGER NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (normalized_contested_name, voter_id, network)
+            )",
+            [],
+        )?;
+
+        // Create the watched contests table
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS watched_contest (
+                normalized_contested_name TEXT NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (normalized_contested_name, network)
+            )",
+            [],
+        )?;
+
+        // Create the watched identities table
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS watched_identity (
+                identity_id BLOB NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (identity_id, network)
+            )",
+            [],
+        )?;
+
+        // Create the table of identities moved to another group than their default one
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS identity_group (
+                identity_id BLOB NOT NULL,
+                group_name TEXT NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (identity_id, network)
+            )",
+            [],
+        )?;
+
+        // Create the voting blocs table and the table of their members
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS voting_bloc (
+                name TEXT NOT NULL,
+                default_vote TEXT,
+                network TEXT NOT NULL,
+                PRIMARY KEY (name, network)
+            )",
+            [],
+        )?;
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS voting_bloc_member (
+                bloc_name TEXT NOT NULL,
+                identity_id BLOB NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (bloc_name, identity_id, network)
+            )",
+            [],
+        )?;
+
+        // Create the table of fetched document copies, one per revision, stored as their JSON
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS document_revision (
+                document_id BLOB NOT NULL,
+                revision INTEGER NOT NULL,
+                contract_id BLOB NOT NULL,
+                document_type TEXT NOT NULL,
+                json TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (document_id, revision, network)
+            )",
+            [],
+        )?;
+
+        // Create the tables of document snapshots, taken to compare a document type over time
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS document_snapshot (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                contract_id BLOB NOT NULL,
+                document_type TEXT NOT NULL,
+                label TEXT NOT NULL,
+                taken_at INTEGER NOT NULL,
+                truncated INTEGER NOT NULL,
+                network TEXT NOT NULL
+            )",
+            [],
+        )?;
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS document_snapshot_entry (
+                snapshot_id INTEGER NOT NULL,
+                document_id TEXT NOT NULL,
+                revision INTEGER NOT NULL,
+                json TEXT NOT NULL,
+                PRIMARY KEY (snapshot_id, document_id)
+            )",
+            [],
+        )?;
+
+        // Create the table of keys the tool signed state transitions with, one row per use
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS key_usage (
+                identity_id BLOB NOT NULL,
+                key_id INTEGER NOT NULL,
+                transition TEXT NOT NULL,
+                used_at INTEGER NOT NULL,
+                network TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create the table of credits withdrawn or transferred, for the daily spending limits
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS credit_spending (
+                identity_id BLOB NOT NULL,
+                credits INTEGER NOT NULL,
+                spent_at INTEGER NOT NULL,
+                network TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create the table of operations that changed credit balances, for the accounting export
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS credit_operation (
+                identity_id BLOB NOT NULL,
+                kind TEXT NOT NULL,
+                credits INTEGER NOT NULL,
+                fee INTEGER NOT NULL,
+                description TEXT NOT NULL,
+                occurred_at INTEGER NOT NULL,
+                network TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create the table of the last fetched price of DASH per fiat currency
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS fiat_price (
+                currency TEXT PRIMARY KEY,
+                price REAL NOT NULL,
+                fetched_at INTEGER NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create the table of automatic top ups, for the weekly limits of the rules
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS auto_top_up (
+                identity_id BLOB NOT NULL,
+                duffs INTEGER NOT NULL,
+                topped_up_at INTEGER NOT NULL,
+                network TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create the table of coinbase payouts to our masternodes' payout addresses
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS masternode_payout (
+                txid TEXT NOT NULL,
+                output_index INTEGER NOT NULL,
+                identity_id BLOB NOT NULL,
+                payout_address TEXT NOT NULL,
+                duffs INTEGER NOT NULL,
+                height INTEGER NOT NULL,
+                paid_at INTEGER NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (txid, output_index, network)
+            )",
+            [],
+        )?;
+
+        // Create the table of addresses and identities credits were sent to
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS used_recipient (
+                recipient TEXT NOT NULL,
+                last_used_at INTEGER NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (recipient, network)
+            )",
+            [],
+        )?;
+
+        // Create the table of the screens and unsent votes open when the tool last ran
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS session (
+                network TEXT PRIMARY KEY,
+                session TEXT NOT NULL,
+                saved_at INTEGER NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create the table of form drafts, saved while long forms are filled in
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS form_draft (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                form TEXT NOT NULL,
+                title TEXT NOT NULL,
+                content TEXT NOT NULL,
+                saved_at INTEGER NOT NULL,
+                network TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create the approver table, at most one approver shared by all networks
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS approver (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                name TEXT NOT NULL,
+                salt TEXT NOT NULL,
+                password_hash TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create the audit trail of sensitive actions
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS audit_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                action TEXT NOT NULL,
+                outcome TEXT NOT NULL,
+                approver TEXT,
+                logged_at INTEGER NOT NULL,
+                network TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create the table tracking transitions signed on an offline machine
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS cold_signing (
+                request_id TEXT NOT NULL,
+                identity_id TEXT NOT NULL,
+                description TEXT NOT NULL,
+                status TEXT NOT NULL,
+                error TEXT,
+                updated_at INTEGER NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (request_id, network)
+            )",
+            [],
+        )?;
+
+        // Create the table of imported community vote lists, stored as their JSON
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS vote_list (
+                title TEXT NOT NULL,
+                list TEXT NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (title, network)
+            )",
+            [],
+        )?;
+
+        // Create the table of vote receipts, the proofs of our votes stored as their JSON
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS vote_proof (
+                contest TEXT NOT NULL,
+                voter_id TEXT NOT NULL,
+                receipt TEXT NOT NULL,
+                proven_at INTEGER NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (contest, voter_id, network)
+            )",
+            [],
+        )?;
+
+        // Create the table of contestant reputation signals fetched from Platform
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS contestant_reputation (
+                identity_id BLOB NOT NULL,
+                names_held INTEGER NOT NULL,
+                has_dashpay_profile INTEGER NOT NULL,
+                active_since INTEGER,
+                fetched_at INTEGER NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (identity_id, network)
+            )",
+            [],
+        )?;
+
+        // Create the table of notes on contests, an empty contestant is a note on the contest
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS contest_annotation (
+                contest TEXT NOT NULL,
+                contestant TEXT NOT NULL DEFAULT '',
+                tags TEXT NOT NULL,
+                note TEXT NOT NULL,
+                updated_at INTEGER NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (contest, contestant, network)
+            )",
+            [],
+        )?;
+
+        // Create the contacts table
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS contact (
+                identity_id BLOB NOT NULL,
+                alias TEXT,
+                network TEXT NOT NULL,
+                PRIMARY KEY (identity_id, network)
+            )",
+            [],
+        )?;
+
         // Create the contracts table
         self.execute(
             "CREATE TABLE IF NOT EXISTS contract (
@@ -136,6 +459,32 @@ impl Database {
             [],
         )?;
 
+        // Create the known contracts registry
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS contract_registry (
+                contract_id BLOB NOT NULL,
+                kind TEXT NOT NULL,
+                onchain_version INTEGER,
+                last_checked INTEGER,
+                network TEXT NOT NULL,
+                PRIMARY KEY (contract_id, network)
+            )",
+            [],
+        )?;
+
+        // Create the table of the last run of each scheduled task
+        self.execute(
+            "CREATE TABLE IF NOT EXISTS scheduled_run (
+                task_id INTEGER NOT NULL,
+                started_at INTEGER NOT NULL,
+                finished_at INTEGER,
+                error TEXT,
+                network TEXT NOT NULL,
+                PRIMARY KEY (task_id, network)
+            )",
+            [],
+        )?;
+
         Ok(())
     }
 }
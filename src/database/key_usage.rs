@@ -0,0 +1,69 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::key_usage::{KeyUsageRecord, TRANSITION_MASTERNODE_VOTE};
+use dash_sdk::dpp::identifier::Identifier;
+use dash_sdk::dpp::identity::{KeyID, TimestampMillis};
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Records that a key of an identity signed a state transition
+    pub fn record_key_usage(
+        &self,
+        identity_id: &Identifier,
+        key_id: KeyID,
+        transition: &str,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        let used_at = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        self.execute(
+            "INSERT INTO key_usage (identity_id, key_id, transition, used_at, network)
+             VALUES (?, ?, ?, ?, ?)",
+            params![identity_id.to_vec(), key_id, transition, used_at, network],
+        )?;
+        Ok(())
+    }
+
+    /// Every recorded use of the identity's keys, including the votes it cast
+    pub fn get_key_usage(
+        &self,
+        identity_id: &Identifier,
+        app_context: &AppContext,
+    ) -> Result<Vec<KeyUsageRecord>> {
+        let network = app_context.network_string();
+        let id = identity_id.to_vec();
+
+        let conn = self.conn.lock().unwrap();
+        let mut records = Vec::new();
+
+        let mut stmt = conn.prepare(
+            "SELECT key_id, transition, used_at FROM key_usage
+             WHERE identity_id = ? AND network = ?",
+        )?;
+        let rows = stmt.query_map(params![id, network], |row| {
+            Ok(KeyUsageRecord {
+                key_id: row.get(0)?,
+                transition: row.get(1)?,
+                used_at: row.get(2)?,
+            })
+        })?;
+        for row in rows {
+            records.push(row?);
+        }
+
+        let mut stmt = conn
+            .prepare("SELECT key_id, voted_at FROM my_vote WHERE voter_id = ? AND network = ?")?;
+        let votes = stmt.query_map(params![id, network], |row| {
+            Ok(KeyUsageRecord {
+                key_id: row.get(0)?,
+                transition: TRANSITION_MASTERNODE_VOTE.to_string(),
+                used_at: row.get::<_, TimestampMillis>(1)?,
+            })
+        })?;
+        for vote in votes {
+            records.push(vote?);
+        }
+
+        Ok(records)
+    }
+}
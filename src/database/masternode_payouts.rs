@@ -0,0 +1,88 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::masternode_payout::MasternodePayout;
+use dash_sdk::platform::Identifier;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Stores payouts found on chain, payouts already stored are left as they are
+    pub fn insert_masternode_payouts(
+        &self,
+        payouts: &[MasternodePayout],
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        for payout in payouts {
+            tx.execute(
+                "INSERT OR IGNORE INTO masternode_payout
+                 (txid, output_index, identity_id, payout_address, duffs, height, paid_at, network)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                params![
+                    payout.txid,
+                    payout.output_index,
+                    payout.identity_id.to_vec(),
+                    payout.payout_address,
+                    payout.duffs,
+                    payout.height,
+                    payout.paid_at,
+                    network
+                ],
+            )?;
+        }
+        tx.commit()
+    }
+
+    /// Height of the last stored payout to the address, to only scan newer blocks
+    pub fn get_last_payout_height(
+        &self,
+        payout_address: &str,
+        app_context: &AppContext,
+    ) -> Result<Option<u32>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            "SELECT MAX(height) FROM masternode_payout WHERE payout_address = ? AND network = ?",
+            params![payout_address, network],
+            |row| row.get(0),
+        )
+    }
+
+    /// All stored payouts, oldest first
+    pub fn get_masternode_payouts(
+        &self,
+        app_context: &AppContext,
+    ) -> Result<Vec<MasternodePayout>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT identity_id, payout_address, txid, output_index, duffs, height, paid_at
+             FROM masternode_payout WHERE network = ? ORDER BY height, txid, output_index",
+        )?;
+        let rows = stmt.query_map(params![network], |row| {
+            let identity_id: Vec<u8> = row.get(0)?;
+            Ok((
+                identity_id,
+                MasternodePayout {
+                    identity_id: Identifier::default(),
+                    payout_address: row.get(1)?,
+                    txid: row.get(2)?,
+                    output_index: row.get(3)?,
+                    duffs: row.get(4)?,
+                    height: row.get(5)?,
+                    paid_at: row.get(6)?,
+                },
+            ))
+        })?;
+        let mut payouts = Vec::new();
+        for row in rows {
+            let (identity_id, mut payout) = row?;
+            if let Ok(identity_id) = Identifier::from_bytes(&identity_id) {
+                payout.identity_id = identity_id;
+                payouts.push(payout);
+            }
+        }
+        Ok(payouts)
+    }
+}
@@ -1,8 +1,29 @@
+mod approvals;
+mod auto_top_ups;
+mod cold_signing;
+mod contacts;
+mod contest_annotations;
+mod contestant_reputations;
 mod contested_names;
 mod contracts;
+mod credit_operations;
+mod credit_spending;
+mod document_revisions;
+mod document_snapshots;
+mod fiat_prices;
+mod form_drafts;
 mod identities;
 mod initialization;
+mod key_usage;
+mod masternode_payouts;
+mod preferences;
+mod scheduled_runs;
+mod session;
 mod settings;
+mod used_recipients;
+mod vote_lists;
+mod vote_proofs;
+mod voting_blocs;
 mod wallet;
 
 use rusqlite::{Connection, Params};
@@ -25,4 +46,16 @@ impl Database {
         let conn = self.conn.lock().unwrap();
         conn.execute(sql, params)
     }
+
+    /// Problems SQLite finds in the database file, empty when it is intact
+    pub fn integrity_problems(&self) -> rusqlite::Result<Vec<String>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        let problems = rows.collect::<rusqlite::Result<Vec<String>>>()?;
+        Ok(problems
+            .into_iter()
+            .filter(|problem| problem != "ok")
+            .collect())
+    }
 }
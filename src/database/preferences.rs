@@ -0,0 +1,34 @@
+use crate::database::Database;
+use crate::model::preferences::Preferences;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Stores the preferences, replacing any previously saved ones.
+    pub fn update_preferences(&self, preferences: &Preferences) -> Result<()> {
+        let data = serde_json::to_string(preferences)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+        self.execute(
+            "INSERT INTO preferences (id, data) VALUES (1, ?)
+             ON CONFLICT(id) DO UPDATE SET data = excluded.data",
+            params![data],
+        )?;
+        Ok(())
+    }
+
+    /// Retrieves the saved preferences, or the defaults if none were saved yet.
+    pub fn get_preferences(&self) -> Result<Preferences> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT data FROM preferences WHERE id = 1")?;
+
+        let result = stmt.query_row([], |row| row.get::<_, String>(0));
+
+        match result {
+            Ok(data) => Ok(serde_json::from_str(&data).unwrap_or_else(|e| {
+                tracing::error!("Failed to parse saved preferences, using defaults: {}", e);
+                Preferences::default()
+            })),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(Preferences::default()),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::schedule::ScheduledRun;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Records that the scheduled task started, replacing its previous run
+    pub fn start_scheduled_run(
+        &self,
+        task_id: u32,
+        started_at: i64,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        self.execute(
+            "INSERT OR REPLACE INTO scheduled_run (task_id, started_at, finished_at, error, network)
+             VALUES (?, ?, NULL, NULL, ?)",
+            params![task_id, started_at, app_context.network_string()],
+        )?;
+        Ok(())
+    }
+
+    /// Records how the current run of the scheduled task ended
+    pub fn finish_scheduled_run(
+        &self,
+        task_id: u32,
+        error: Option<&str>,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        self.execute(
+            "UPDATE scheduled_run SET finished_at = ?, error = ? WHERE task_id = ? AND network = ?",
+            params![
+                chrono::Utc::now().timestamp(),
+                error,
+                task_id,
+                app_context.network_string()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Ends the runs that were still going when the tool was closed, so their tasks are due
+    /// again
+    pub fn interrupt_scheduled_runs(&self) -> Result<()> {
+        self.execute(
+            "UPDATE scheduled_run SET finished_at = started_at, error = ?
+             WHERE finished_at IS NULL",
+            params!["Interrupted, the tool was closed"],
+        )?;
+        Ok(())
+    }
+
+    /// The last run of each scheduled task of the network
+    pub fn get_scheduled_runs(&self, app_context: &AppContext) -> Result<Vec<ScheduledRun>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT task_id, started_at, finished_at, error FROM scheduled_run WHERE network = ?",
+        )?;
+        let runs = stmt.query_map(params![app_context.network_string()], |row| {
+            Ok(ScheduledRun {
+                task_id: row.get(0)?,
+                started_at: row.get(1)?,
+                finished_at: row.get(2)?,
+                error: row.get(3)?,
+            })
+        })?;
+        runs.collect()
+    }
+
+    /// Forgets the runs of a task that was removed
+    pub fn delete_scheduled_run(&self, task_id: u32, app_context: &AppContext) -> Result<()> {
+        self.execute(
+            "DELETE FROM scheduled_run WHERE task_id = ? AND network = ?",
+            params![task_id, app_context.network_string()],
+        )?;
+        Ok(())
+    }
+}
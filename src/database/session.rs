@@ -0,0 +1,42 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::session::Session;
+use rusqlite::{params, OptionalExtension, Result};
+
+impl Database {
+    /// Saves the session of the network, replacing the previous one
+    pub fn save_session(&self, session: &Session, app_context: &AppContext) -> Result<()> {
+        let network = app_context.network_string();
+        let json = serde_json::to_string(session)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+        self.execute(
+            "INSERT INTO session (network, session, saved_at) VALUES (?, ?, ?)
+             ON CONFLICT(network) DO UPDATE SET
+                session = excluded.session,
+                saved_at = excluded.saved_at",
+            params![network, json, chrono::Utc::now().timestamp()],
+        )?;
+        Ok(())
+    }
+
+    /// The last saved session of the network. A session that no longer parses, e.g. after an
+    /// update, is treated as none.
+    pub fn get_session(&self, app_context: &AppContext) -> Result<Option<Session>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+        let json: Option<String> = conn
+            .query_row(
+                "SELECT session FROM session WHERE network = ?",
+                params![network],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(json.and_then(|json| serde_json::from_str(&json).ok()))
+    }
+
+    pub fn clear_session(&self, app_context: &AppContext) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute("DELETE FROM session WHERE network = ?", params![network])?;
+        Ok(())
+    }
+}
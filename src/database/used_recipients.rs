@@ -0,0 +1,27 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Remembers an address or identity credits were sent to, for the lookalike warnings
+    pub fn record_used_recipient(&self, recipient: &str, app_context: &AppContext) -> Result<()> {
+        let network = app_context.network_string();
+        let used_at = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        self.execute(
+            "INSERT INTO used_recipient (recipient, last_used_at, network) VALUES (?, ?, ?)
+             ON CONFLICT(recipient, network) DO UPDATE SET last_used_at = excluded.last_used_at",
+            params![recipient, used_at, network],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_used_recipients(&self, app_context: &AppContext) -> Result<Vec<String>> {
+        let network = app_context.network_string();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT recipient FROM used_recipient WHERE network = ? ORDER BY last_used_at DESC",
+        )?;
+        let recipients = stmt.query_map(params![network], |row| row.get(0))?;
+        recipients.collect()
+    }
+}
@@ -0,0 +1,40 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::vote_list::VoteList;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Stores an imported vote list, replacing an earlier list with the same title
+    pub fn save_vote_list(&self, list: &VoteList, app_context: &AppContext) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "INSERT OR REPLACE INTO vote_list (title, list, network) VALUES (?, ?, ?)",
+            params![list.contents.title, list.to_json(), network],
+        )?;
+        Ok(())
+    }
+
+    pub fn delete_vote_list(&self, title: &str, app_context: &AppContext) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "DELETE FROM vote_list WHERE title = ? AND network = ?",
+            params![title, network],
+        )?;
+        Ok(())
+    }
+
+    /// The imported vote lists, lists that no longer parse or verify are left out
+    pub fn get_vote_lists(&self, app_context: &AppContext) -> Result<Vec<VoteList>> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt =
+            conn.prepare("SELECT list FROM vote_list WHERE network = ? ORDER BY title")?;
+        let lists = stmt.query_map(params![network], |row| row.get::<_, String>(0))?;
+        Ok(lists
+            .collect::<Result<Vec<String>>>()?
+            .iter()
+            .filter_map(|json| VoteList::from_json(json).ok())
+            .collect())
+    }
+}
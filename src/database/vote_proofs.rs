@@ -0,0 +1,111 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::operator_report::ReportedVote;
+use crate::model::vote_proof::{vote_choice_label, VoteProofReceipt};
+use dash_sdk::dpp::identity::TimestampMillis;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dash_sdk::platform::Identifier;
+use rusqlite::{params, Result};
+use std::collections::HashMap;
+
+impl Database {
+    /// Stores the receipt of a vote, replacing the one of an earlier vote of the masternode on
+    /// the same contest
+    pub fn save_vote_proof(
+        &self,
+        receipt: &VoteProofReceipt,
+        app_context: &AppContext,
+    ) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "INSERT OR REPLACE INTO vote_proof (contest, voter_id, receipt, proven_at, network)
+             VALUES (?, ?, ?, ?, ?)",
+            params![
+                receipt.contest,
+                receipt.voter_id,
+                receipt.to_json(),
+                (receipt.metadata.time_ms / 1000) as i64,
+                network
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The receipts of our votes, newest first
+    pub fn get_vote_proofs(&self, app_context: &AppContext) -> Result<Vec<VoteProofReceipt>> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT receipt FROM vote_proof WHERE network = ? ORDER BY proven_at DESC, contest",
+        )?;
+        let receipts = stmt.query_map(params![network], |row| row.get::<_, String>(0))?;
+        Ok(receipts
+            .collect::<Result<Vec<String>>>()?
+            .iter()
+            .filter_map(|json| VoteProofReceipt::from_json(json).ok())
+            .collect())
+    }
+
+    /// The votes our identities cast in `[from, to)`, with their receipts where there is one
+    pub fn get_reported_votes(
+        &self,
+        from: TimestampMillis,
+        to: TimestampMillis,
+        app_context: &AppContext,
+    ) -> Result<Vec<ReportedVote>> {
+        let network = app_context.network_string();
+        let mut votes = Vec::new();
+        {
+            let conn = self.conn.lock().unwrap();
+            let mut stmt = conn.prepare(
+                "SELECT normalized_contested_name, voter_id, vote_choice, voted_at
+                 FROM my_vote WHERE network = ? AND voted_at >= ? AND voted_at < ?",
+            )?;
+            let rows = stmt.query_map(params![network, from, to], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Vec<u8>>(1)?,
+                    row.get::<_, Vec<u8>>(2)?,
+                    row.get::<_, TimestampMillis>(3)?,
+                ))
+            })?;
+            for row in rows {
+                let (contest, voter_id, vote_choice, voted_at) = row?;
+                let Ok(voter_id) = Identifier::from_bytes(&voter_id) else {
+                    continue;
+                };
+                let Ok((vote_choice, _)) = bincode::decode_from_slice::<ResourceVoteChoice, _>(
+                    &vote_choice,
+                    bincode::config::standard(),
+                ) else {
+                    continue;
+                };
+                votes.push(ReportedVote {
+                    contest,
+                    voter_id,
+                    choice: vote_choice_label(&vote_choice),
+                    voted_at,
+                    receipt: None,
+                });
+            }
+        }
+
+        let mut receipts: HashMap<(String, String), VoteProofReceipt> = self
+            .get_vote_proofs(app_context)?
+            .into_iter()
+            .map(|receipt| ((receipt.contest.clone(), receipt.voter_id.clone()), receipt))
+            .collect();
+        for vote in &mut votes {
+            let key = (
+                vote.contest.clone(),
+                vote.voter_id.to_string(Encoding::Base58),
+            );
+            vote.receipt = receipts
+                .remove(&key)
+                .filter(|receipt| receipt.choice == vote.choice);
+        }
+        Ok(votes)
+    }
+}
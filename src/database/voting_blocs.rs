@@ -0,0 +1,80 @@
+use crate::context::AppContext;
+use crate::database::Database;
+use crate::model::voting_bloc::{BlocDefaultVote, VotingBloc};
+use dash_sdk::platform::Identifier;
+use rusqlite::{params, Result};
+
+impl Database {
+    /// Creates or replaces a voting bloc together with its members
+    pub fn save_voting_bloc(&self, bloc: &VotingBloc, app_context: &AppContext) -> Result<()> {
+        let network = app_context.network_string();
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "INSERT OR REPLACE INTO voting_bloc (name, default_vote, network) VALUES (?, ?, ?)",
+            params![bloc.name, bloc.default_vote.map(|vote| vote.id()), network],
+        )?;
+        tx.execute(
+            "DELETE FROM voting_bloc_member WHERE bloc_name = ? AND network = ?",
+            params![bloc.name, network],
+        )?;
+        for member in &bloc.members {
+            tx.execute(
+                "INSERT OR IGNORE INTO voting_bloc_member (bloc_name, identity_id, network)
+                 VALUES (?, ?, ?)",
+                params![bloc.name, member.to_vec(), network],
+            )?;
+        }
+        tx.commit()
+    }
+
+    pub fn delete_voting_bloc(&self, name: &str, app_context: &AppContext) -> Result<()> {
+        let network = app_context.network_string();
+        self.execute(
+            "DELETE FROM voting_bloc_member WHERE bloc_name = ? AND network = ?",
+            params![name, network],
+        )?;
+        self.execute(
+            "DELETE FROM voting_bloc WHERE name = ? AND network = ?",
+            params![name, network],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_voting_blocs(&self, app_context: &AppContext) -> Result<Vec<VotingBloc>> {
+        let network = app_context.network_string();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT name, default_vote FROM voting_bloc WHERE network = ? ORDER BY name",
+        )?;
+        let blocs = stmt.query_map(params![network], |row| {
+            let name: String = row.get(0)?;
+            let default_vote: Option<String> = row.get(1)?;
+            Ok(VotingBloc {
+                name,
+                members: Vec::new(),
+                default_vote: default_vote.as_deref().and_then(BlocDefaultVote::from_id),
+            })
+        })?;
+        let mut blocs = blocs.collect::<Result<Vec<VotingBloc>>>()?;
+
+        let mut stmt = conn
+            .prepare("SELECT bloc_name, identity_id FROM voting_bloc_member WHERE network = ?")?;
+        let members = stmt.query_map(params![network], |row| {
+            let bloc_name: String = row.get(0)?;
+            let identity_id: Vec<u8> = row.get(1)?;
+            Ok((bloc_name, identity_id))
+        })?;
+        for member in members {
+            let (bloc_name, identity_id) = member?;
+            let Ok(identity_id) = Identifier::from_bytes(&identity_id) else {
+                continue;
+            };
+            if let Some(bloc) = blocs.iter_mut().find(|bloc| bloc.name == bloc_name) {
+                bloc.members.push(identity_id);
+            }
+        }
+        Ok(blocs)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::model::payment_request::PaymentRequest;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::util::strings::convert_to_homograph_safe_chars;
+use dash_sdk::platform::Identifier;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// URI scheme of links that open the tool, e.g. `dash-evo-tool://contest/alice`
+pub const URI_SCHEME: &str = "dash-evo-tool";
+
+/// Port on localhost where the running instance accepts links opened while it runs
+const DEEP_LINK_PORT: u16 = 19735;
+
+/// A place in the tool a link points to
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeepLink {
+    /// A DPNS contest by its normalized name
+    Contest(String),
+    Identity(Identifier),
+    /// A payment request, e.g. scanned from its QR code
+    Payment(PaymentRequest),
+}
+
+impl DeepLink {
+    /// Parses `dash-evo-tool://contest/<name>`, `dash-evo-tool://identity/<id>`, the id in
+    /// Base58 or hex, and `dash-evo-tool://pay?...` payment requests
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let rest = uri
+            .trim()
+            .strip_prefix(URI_SCHEME)
+            .and_then(|rest| rest.strip_prefix("://"))
+            .ok_or_else(|| format!("Not a {}:// link: {}", URI_SCHEME, uri))?;
+        if rest == "pay" || rest.starts_with("pay?") || rest.starts_with("pay/") {
+            return PaymentRequest::parse(uri).map(DeepLink::Payment);
+        }
+        let rest = rest.trim_end_matches('/');
+        let (kind, value) = rest
+            .split_once('/')
+            .ok_or_else(|| format!("Link has no target: {}", uri))?;
+        match kind {
+            "contest" if !value.is_empty() => Ok(DeepLink::Contest(
+                convert_to_homograph_safe_chars(&value.to_lowercase()),
+            )),
+            "identity" => Identifier::from_string(value, Encoding::Base58)
+                .or_else(|_| Identifier::from_string(value, Encoding::Hex))
+                .map(DeepLink::Identity)
+                .map_err(|_| format!("Invalid identity id in link: {}", value)),
+            _ => Err(format!("Unknown link: {}", uri)),
+        }
+    }
+
+    pub fn to_uri(&self) -> String {
+        match self {
+            DeepLink::Contest(name) => format!("{}://contest/{}", URI_SCHEME, name),
+            DeepLink::Identity(identity_id) => format!(
+                "{}://identity/{}",
+                URI_SCHEME,
+                identity_id.to_string(Encoding::Base58)
+            ),
+            DeepLink::Payment(request) => request.to_uri(),
+        }
+    }
+}
+
+/// The link the tool was started with, if any. The OS passes it as an argument.
+pub fn link_from_args() -> Option<String> {
+    std::env::args()
+        .skip(1)
+        .find(|arg| arg.starts_with(&format!("{}://", URI_SCHEME)))
+}
+
+/// Hands the link to an instance that is already running, returns whether one took it
+pub fn forward_to_running_instance(uri: &str) -> bool {
+    let Ok(mut stream) = TcpStream::connect_timeout(
+        &([127, 0, 0, 1], DEEP_LINK_PORT).into(),
+        Duration::from_millis(500),
+    ) else {
+        return false;
+    };
+    writeln!(stream, "{}", uri).is_ok()
+}
+
+/// Accepts links from instances started later and passes them on. Does nothing when the port
+/// is taken, e.g. by another instance.
+pub fn listen_for_links(sender: mpsc::Sender<String>) {
+    let listener = match TcpListener::bind(("127.0.0.1", DEEP_LINK_PORT)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            tracing::warn!("Not listening for {}:// links: {}", URI_SCHEME, e);
+            return;
+        }
+    };
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut line = String::new();
+            if BufReader::new(stream).read_line(&mut line).is_ok() && sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Registers the tool as the handler of its links with the operating system
+pub fn register_uri_scheme() -> io::Result<()> {
+    let executable = std::env::current_exe()?;
+    if cfg!(target_os = "windows") {
+        let key = format!("HKCU\\Software\\Classes\\{}", URI_SCHEME);
+        let command_key = format!("{}\\shell\\open\\command", key);
+        let command = format!("\"{}\" \"%1\"", executable.display());
+        for args in [
+            vec!["add", key.as_str(), "/ve", "/d", "URL:Dash Evo Tool", "/f"],
+            vec!["add", key.as_str(), "/v", "URL Protocol", "/d", "", "/f"],
+            vec![
+                "add",
+                command_key.as_str(),
+                "/ve",
+                "/d",
+                command.as_str(),
+                "/f",
+            ],
+        ] {
+            run("reg", &args)?;
+        }
+        Ok(())
+    } else if cfg!(target_os = "linux") {
+        let applications = directories::BaseDirs::new()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No home directory found"))?
+            .data_dir()
+            .join("applications");
+        std::fs::create_dir_all(&applications)?;
+        let desktop_file = format!("{}.desktop", URI_SCHEME);
+        std::fs::write(
+            applications.join(&desktop_file),
+            format!(
+                "[Desktop Entry]\nType=Application\nName=Dash Evo Tool\nExec=\"{}\" %u\n\
+                 NoDisplay=true\nMimeType=x-scheme-handler/{};\n",
+                executable.display(),
+                URI_SCHEME
+            ),
+        )?;
+        let mime_type = format!("x-scheme-handler/{}", URI_SCHEME);
+        run("xdg-mime", &["default", &desktop_file, &mime_type])
+    } else {
+        // On macOS the scheme is declared in the app bundle's Info.plist
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Links are registered by the app bundle on this platform",
+        ))
+    }
+}
+
+fn run(program: &str, args: &[&str]) -> io::Result<()> {
+    let status = std::process::Command::new(program).args(args).status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "{} exited with {}",
+            program, status
+        )))
+    }
+}
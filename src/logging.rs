@@ -1,24 +1,193 @@
+use crate::request_metrics::{RequestMetrics, RequestMetricsLayer};
+use std::fs::File;
+use std::io::{self, Write};
 use std::panic;
-use tracing::{error, info};
-use tracing_subscriber::EnvFilter;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::{error, info, warn};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::{EnvFilter, Layer};
 
-pub fn initialize_logger() {
+const LOG_FILTER: &str =
+    "error,dash_sdk=debug,tenderdash_abci=debug,drive=debug,drive_proof_verifier=debug,rs_dapi_client=debug";
+
+/// The JSON logs also keep the tool's own warnings and progress, for support bundles
+const JSON_LOG_FILTER: &str = "dash_evo_tool=info";
+
+/// How many sessions keep their JSON logs
+const SESSION_LOGS_KEPT: usize = 10;
+
+/// Size at which a session's JSON log continues in a new file
+const MAX_LOG_FILE_SIZE: u64 = 20 * 1024 * 1024;
+
+const SESSION_LOG_PREFIX: &str = "session-";
+
+/// The JSON log of this session, `session-<start>.jsonl`, continued in
+/// `session-<start>.1.jsonl` and so on once a file reaches the size limit
+struct SessionLog {
+    dir: PathBuf,
+    session: String,
+    part: u32,
+    file: File,
+    written: u64,
+}
+
+impl SessionLog {
+    fn create(dir: &Path) -> io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+        let session = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+        let file = File::create(dir.join(session_log_file_name(&session, 0)))?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            session,
+            part: 0,
+            file,
+            written: 0,
+        })
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.part += 1;
+        self.file = File::create(
+            self.dir
+                .join(session_log_file_name(&self.session, self.part)),
+        )?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+fn session_log_file_name(session: &str, part: u32) -> String {
+    if part == 0 {
+        format!("{}{}.jsonl", SESSION_LOG_PREFIX, session)
+    } else {
+        format!("{}{}.{}.jsonl", SESSION_LOG_PREFIX, session, part)
+    }
+}
+
+/// The session a JSON log file belongs to and its part, e.g. ("20240301-101500", 1)
+fn session_of(file_name: &str) -> Option<(&str, u32)> {
+    let rest = file_name
+        .strip_prefix(SESSION_LOG_PREFIX)?
+        .strip_suffix(".jsonl")?;
+    match rest.split_once('.') {
+        Some((session, part)) => Some((session, part.parse().ok()?)),
+        None => Some((rest, 0)),
+    }
+}
+
+#[derive(Clone)]
+struct SessionLogWriter(Arc<Mutex<SessionLog>>);
+
+impl Write for SessionLogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut log = self.0.lock().unwrap();
+        if log.written > 0 && log.written + buf.len() as u64 > MAX_LOG_FILE_SIZE {
+            log.rotate()?;
+        }
+        let written = log.file.write(buf)?;
+        log.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.lock().unwrap().file.flush()
+    }
+}
+
+impl<'a> MakeWriter<'a> for SessionLogWriter {
+    type Writer = SessionLogWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// The JSON log files of the most recent sessions, oldest first
+pub fn session_log_files(logs_dir: &Path, sessions: usize) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(logs_dir) else {
+        return vec![];
+    };
+    let mut files: Vec<(String, u32, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let (session, part) = session_of(&name)?;
+            Some((session.to_string(), part, entry.path()))
+        })
+        .collect();
+    files.sort();
+    let mut found: Vec<&String> = files.iter().map(|(session, _, _)| session).collect();
+    found.dedup();
+    let recent: Vec<String> = found[found.len().saturating_sub(sessions)..]
+        .iter()
+        .map(|session| session.to_string())
+        .collect();
+    files
+        .into_iter()
+        .filter(|(session, _, _)| recent.contains(session))
+        .map(|(_, _, path)| path)
+        .collect()
+}
+
+/// Deletes the JSON logs of all but the most recent sessions
+fn prune_session_logs(logs_dir: &Path) {
+    let kept = session_log_files(logs_dir, SESSION_LOGS_KEPT);
+    let Ok(entries) = std::fs::read_dir(logs_dir) else {
+        return;
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        let is_session_log = session_of(&entry.file_name().to_string_lossy()).is_some();
+        if is_session_log && !kept.contains(&path) {
+            if let Err(e) = std::fs::remove_file(&path) {
+                warn!("Failed to delete old log {}: {}", path.display(), e);
+            }
+        }
+    }
+}
+
+/// Logs to the plain log file, replaced every start, and as JSON to a file per session in
+/// `logs_dir`, of which the last few sessions are kept
+pub fn initialize_logger(log_path: &Path, logs_dir: &Path, request_metrics: Arc<RequestMetrics>) {
     // Initialize log file, with improved error handling
-    let log_file = match std::fs::File::create("explorer.log") {
+    let log_file = match std::fs::File::create(log_path) {
         Ok(file) => file,
         Err(e) => panic!("Failed to create log file: {:?}", e),
     };
 
-    let filter = EnvFilter::try_new(
-        "error,dash_sdk=debug,tenderdash_abci=debug,drive=debug,drive_proof_verifier=debug,rs_dapi_client=debug",
-    )
+    let filter = EnvFilter::try_new(LOG_FILTER)
         .unwrap_or_else(|e| panic!("Failed to create EnvFilter: {:?}", e));
 
-    let subscriber = tracing_subscriber::fmt()
-        .with_env_filter(filter)
-        .with_writer(log_file)
-        .with_ansi(false)
-        .finish();
+    // Logging goes on without the JSON log when its directory can't be written
+    let session_log = match SessionLog::create(logs_dir) {
+        Ok(session_log) => Some(SessionLogWriter(Arc::new(Mutex::new(session_log)))),
+        Err(e) => {
+            eprintln!("Failed to create the session log: {:?}", e);
+            None
+        }
+    };
+    let json_layer = session_log.map(|writer| {
+        let json_filter = EnvFilter::try_new(format!("{},{}", LOG_FILTER, JSON_LOG_FILTER))
+            .unwrap_or_else(|e| panic!("Failed to create EnvFilter: {:?}", e));
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_writer(writer)
+            .with_filter(json_filter)
+    });
+
+    let subscriber = tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(log_file)
+                .with_ansi(false)
+                .with_filter(filter),
+        )
+        .with(json_layer)
+        .with(
+            RequestMetricsLayer::new(request_metrics).with_filter(RequestMetricsLayer::targets()),
+        );
 
     // Set global subscriber with proper error handling
     if let Err(e) = tracing::subscriber::set_global_default(subscriber) {
@@ -46,5 +215,7 @@ pub fn initialize_logger() {
         default_panic_hook(panic_info);
     }));
 
+    prune_session_logs(logs_dir);
+
     info!("Logger initialized successfully");
 }
@@ -1,9 +1,24 @@
 mod app;
+mod app_dir;
+mod balance_monitor;
+mod clipboard_monitor;
 mod config;
+mod dapi_tls;
 mod database;
+mod deep_link;
 mod logging;
+mod metrics_export;
+mod node_log_tail;
+mod notification_sound;
+mod profile_picker;
+mod request_metrics;
+mod scheduler;
 mod sdk_wrapper;
+mod socks_proxy;
+mod support_bundle;
+mod telemetry;
 mod ui;
+mod webhooks;
 
 mod components;
 mod context;
@@ -12,6 +27,13 @@ mod model;
 mod platform;
 
 fn main() -> eframe::Result<()> {
+    // A link opened while the tool runs is handled by the running instance
+    if let Some(link) = deep_link::link_from_args() {
+        if deep_link::forward_to_running_instance(&link) {
+            return Ok(());
+        }
+    }
+
     // Initialize the Tokio runtime
     let runtime = tokio::runtime::Builder::new_multi_thread()
         .worker_threads(40)
@@ -29,7 +51,7 @@ fn main() -> eframe::Result<()> {
         eframe::run_native(
             "Identity Manager",
             native_options,
-            Box::new(|_cc| Ok(Box::new(app::AppState::new()))),
+            Box::new(|_cc| Ok(Box::new(profile_picker::ProfileLauncher::new()))),
         )
     })
 }
@@ -0,0 +1,293 @@
+use crate::context::AppContext;
+use crate::model::contest_calendar::contests_to_ics;
+use crate::model::preferences::MetricsExport;
+use crate::platform::BackendTaskSuccessResult;
+use crate::request_metrics::RequestMetrics;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// How often the metrics file is rewritten
+const METRICS_FILE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Counters about the work the tool does, exported in the Prometheus text format
+#[derive(Debug, Default)]
+pub struct AppMetrics {
+    /// Finished backend tasks keyed by task kind and outcome
+    tasks: Mutex<BTreeMap<(&'static str, &'static str), u64>>,
+    votes_submitted: AtomicU64,
+    /// Failed backend tasks keyed by error category
+    errors: Mutex<BTreeMap<&'static str, u64>>,
+    /// The counts already sent as telemetry
+    reported: Mutex<MetricsSnapshot>,
+}
+
+/// The counters of `AppMetrics` at one point, or the difference between two points
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    /// Finished backend tasks keyed by task kind and outcome
+    pub tasks: BTreeMap<(&'static str, &'static str), u64>,
+    pub votes_submitted: u64,
+    /// Failed backend tasks keyed by error category
+    pub errors: BTreeMap<&'static str, u64>,
+}
+
+impl MetricsSnapshot {
+    fn minus(&self, earlier: &MetricsSnapshot) -> MetricsSnapshot {
+        fn minus_counts<K: Ord + Copy>(
+            later: &BTreeMap<K, u64>,
+            earlier: &BTreeMap<K, u64>,
+        ) -> BTreeMap<K, u64> {
+            later
+                .iter()
+                .map(|(key, count)| {
+                    (
+                        *key,
+                        count.saturating_sub(earlier.get(key).copied().unwrap_or_default()),
+                    )
+                })
+                .filter(|(_, count)| *count > 0)
+                .collect()
+        }
+        MetricsSnapshot {
+            tasks: minus_counts(&self.tasks, &earlier.tasks),
+            votes_submitted: self.votes_submitted.saturating_sub(earlier.votes_submitted),
+            errors: minus_counts(&self.errors, &earlier.errors),
+        }
+    }
+}
+
+impl AppMetrics {
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            tasks: self.tasks.lock().unwrap().clone(),
+            votes_submitted: self.votes_submitted.load(Ordering::Relaxed),
+            errors: self.errors.lock().unwrap().clone(),
+        }
+    }
+
+    /// The counts since the last telemetry report, and the snapshot they were taken from to
+    /// pass to `mark_reported` once the report is sent
+    pub fn unreported(&self) -> (MetricsSnapshot, MetricsSnapshot) {
+        let snapshot = self.snapshot();
+        let unreported = snapshot.minus(&self.reported.lock().unwrap());
+        (unreported, snapshot)
+    }
+
+    pub fn mark_reported(&self, snapshot: MetricsSnapshot) {
+        *self.reported.lock().unwrap() = snapshot;
+    }
+
+    pub fn record_task(
+        &self,
+        kind: &'static str,
+        result: &Result<BackendTaskSuccessResult, String>,
+    ) {
+        let outcome = if result.is_ok() { "success" } else { "error" };
+        *self
+            .tasks
+            .lock()
+            .unwrap()
+            .entry((kind, outcome))
+            .or_default() += 1;
+        match result {
+            Ok(BackendTaskSuccessResult::SuccessfulVotes(votes)) => {
+                self.votes_submitted
+                    .fetch_add(votes.len() as u64, Ordering::Relaxed);
+            }
+            Err(e) => {
+                *self
+                    .errors
+                    .lock()
+                    .unwrap()
+                    .entry(error_category(e))
+                    .or_default() += 1;
+            }
+            _ => {}
+        }
+    }
+
+    /// Renders all metrics, including the request latencies, in the Prometheus text format
+    pub fn render(&self, request_metrics: &RequestMetrics) -> String {
+        let mut output = String::new();
+
+        output.push_str("# HELP dash_evo_tool_tasks_total Finished backend tasks.\n");
+        output.push_str("# TYPE dash_evo_tool_tasks_total counter\n");
+        for ((kind, outcome), count) in self.tasks.lock().unwrap().iter() {
+            let _ = writeln!(
+                output,
+                "dash_evo_tool_tasks_total{{kind=\"{}\",outcome=\"{}\"}} {}",
+                kind, outcome, count
+            );
+        }
+
+        output.push_str("# HELP dash_evo_tool_votes_submitted_total Votes cast successfully.\n");
+        output.push_str("# TYPE dash_evo_tool_votes_submitted_total counter\n");
+        let _ = writeln!(
+            output,
+            "dash_evo_tool_votes_submitted_total {}",
+            self.votes_submitted.load(Ordering::Relaxed)
+        );
+
+        output.push_str("# HELP dash_evo_tool_errors_total Failed backend tasks by category.\n");
+        output.push_str("# TYPE dash_evo_tool_errors_total counter\n");
+        for (category, count) in self.errors.lock().unwrap().iter() {
+            let _ = writeln!(
+                output,
+                "dash_evo_tool_errors_total{{category=\"{}\"}} {}",
+                category, count
+            );
+        }
+
+        output.push_str(
+            "# HELP dash_evo_tool_request_duration_seconds Latency of recent SDK requests.\n",
+        );
+        output.push_str("# TYPE dash_evo_tool_request_duration_seconds summary\n");
+        for summary in request_metrics.summaries() {
+            let method = summary.method.replace('"', "'");
+            for (quantile, latency) in [
+                ("0.5", summary.latency_p50),
+                ("0.9", summary.latency_p90),
+                ("0.99", summary.latency_p99),
+            ] {
+                let _ = writeln!(
+                    output,
+                    "dash_evo_tool_request_duration_seconds{{method=\"{}\",quantile=\"{}\"}} {}",
+                    method,
+                    quantile,
+                    latency.as_secs_f64()
+                );
+            }
+            let _ = writeln!(
+                output,
+                "dash_evo_tool_request_duration_seconds_count{{method=\"{}\"}} {}",
+                method, summary.count
+            );
+        }
+
+        output
+    }
+}
+
+/// Buckets an error message of a backend task into a coarse category
+fn error_category(message: &str) -> &'static str {
+    let message = message.to_lowercase();
+    if message.contains("database") {
+        "database"
+    } else if message.contains("proof") {
+        "proof"
+    } else if ["timeout", "timed out", "transport", "connect", "dapi"]
+        .iter()
+        .any(|network| message.contains(network))
+    {
+        "network"
+    } else if message.contains("rpc") || message.contains("core") {
+        "core"
+    } else {
+        "platform"
+    }
+}
+
+/// Path under which the endpoint serves the contest deadlines of a network as a calendar
+pub fn contest_calendar_path(network: &str) -> String {
+    format!("/contests/{}.ics", network)
+}
+
+/// Starts exporting metrics as configured, must be called from within the Tokio runtime.
+/// The endpoint also serves the contest calendars of the given networks.
+pub fn start_metrics_export(
+    export: &MetricsExport,
+    metrics: Arc<AppMetrics>,
+    request_metrics: Arc<RequestMetrics>,
+    app_contexts: Vec<Arc<AppContext>>,
+) {
+    match export.clone() {
+        MetricsExport::Disabled => {}
+        MetricsExport::Endpoint { port } => {
+            tokio::spawn(async move {
+                if let Err(e) = serve_metrics(port, metrics, request_metrics, app_contexts).await {
+                    tracing::error!("Metrics endpoint on port {} failed: {}", port, e);
+                }
+            });
+        }
+        MetricsExport::File { path } => {
+            tokio::spawn(async move {
+                let path = PathBuf::from(path);
+                let mut interval = tokio::time::interval(METRICS_FILE_INTERVAL);
+                loop {
+                    interval.tick().await;
+                    if let Err(e) =
+                        write_metrics_file(&path, &metrics.render(&request_metrics)).await
+                    {
+                        tracing::error!("Failed to write metrics to {}: {}", path.display(), e);
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// Answers connections on localhost with the contest calendar for calendar paths and with the
+/// current metrics for every other path
+async fn serve_metrics(
+    port: u16,
+    metrics: Arc<AppMetrics>,
+    request_metrics: Arc<RequestMetrics>,
+    app_contexts: Vec<Arc<AppContext>>,
+) -> std::io::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+    tracing::info!("Serving metrics on http://127.0.0.1:{}/metrics", port);
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        let metrics = metrics.clone();
+        let request_metrics = request_metrics.clone();
+        let app_contexts = app_contexts.clone();
+        tokio::spawn(async move {
+            let mut request = [0u8; 1024];
+            let read = stream.read(&mut request).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&request[..read]);
+            let path = request.split_whitespace().nth(1).unwrap_or("/");
+            let calendar = app_contexts.iter().find_map(|app_context| {
+                let network = app_context.network_string();
+                (path == contest_calendar_path(&network)).then(|| {
+                    contests_to_ics(
+                        &app_context.ongoing_contested_names().unwrap_or_default(),
+                        &app_context
+                            .db
+                            .get_contest_annotations(app_context)
+                            .unwrap_or_default(),
+                        &network,
+                    )
+                })
+            });
+            let (content_type, body) = match calendar {
+                Some(calendar) => ("text/calendar; charset=utf-8", calendar),
+                None => (
+                    "text/plain; version=0.0.4",
+                    metrics.render(&request_metrics),
+                ),
+            };
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                content_type,
+                body.len(),
+                body
+            );
+            if let Err(e) = stream.write_all(response.as_bytes()).await {
+                tracing::debug!("Failed to send metrics: {}", e);
+            }
+        });
+    }
+}
+
+/// Writes through a temporary file so readers never see a partial file
+async fn write_metrics_file(path: &Path, contents: &str) -> std::io::Result<()> {
+    let temporary_path = path.with_extension("tmp");
+    tokio::fs::write(&temporary_path, contents).await?;
+    tokio::fs::rename(&temporary_path, path).await
+}
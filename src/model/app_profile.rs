@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Documents fetched for a table unless its description sets a limit
+const DEFAULT_TABLE_LIMIT: u32 = 50;
+
+/// Most documents a table fetches, the most Platform returns for one query
+pub const MAX_TABLE_LIMIT: u32 = 100;
+
+/// Columns a generated table starts with besides the document's own properties
+const SYSTEM_COLUMNS: [&str; 2] = ["$id", "$ownerId"];
+
+/// Properties every document has besides those of its type
+const SYSTEM_FIELDS: [&str; 5] = ["$id", "$ownerId", "$revision", "$createdAt", "$updatedAt"];
+
+/// Properties a generated table shows as columns, so wide types stay readable
+const GENERATED_PROPERTY_COLUMNS: usize = 4;
+
+/// Something a table of an app profile lets the user do with its document type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppProfileAction {
+    /// Show the JSON of a document of the table
+    View,
+    /// Create documents from a CSV or JSON file
+    Import,
+    /// Delete documents selected by a query
+    Delete,
+    /// Show the usage analytics of the contract
+    Analytics,
+}
+
+impl AppProfileAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AppProfileAction::View => "View",
+            AppProfileAction::Import => "Import",
+            AppProfileAction::Delete => "Delete",
+            AppProfileAction::Analytics => "Analytics",
+        }
+    }
+}
+
+fn default_limit() -> u32 {
+    DEFAULT_TABLE_LIMIT
+}
+
+/// A document type of the contract shown as a table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppProfileTable {
+    pub document_type: String,
+    /// Shown instead of the document type's name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Properties shown as columns in order, `$id`, `$ownerId`, `$revision`, `$createdAt` and
+    /// `$updatedAt` included
+    pub columns: Vec<String>,
+    #[serde(default)]
+    pub actions: Vec<AppProfileAction>,
+    /// Documents fetched at a time
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+}
+
+impl AppProfileTable {
+    pub fn title(&self) -> &str {
+        self.title.as_deref().unwrap_or(&self.document_type)
+    }
+
+    pub fn has_action(&self, action: AppProfileAction) -> bool {
+        self.actions.contains(&action)
+    }
+}
+
+/// A contract with a description of its admin panel: which document types are shown as
+/// tables, with which columns and actions. Each profile of the current network gets an entry
+/// in the left panel.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppProfile {
+    pub name: String,
+    /// Base58 id of the contract
+    pub contract_id: String,
+    pub tables: Vec<AppProfileTable>,
+    /// The network the contract is on, set when the profile is saved
+    #[serde(default)]
+    pub network: String,
+}
+
+impl AppProfile {
+    /// A profile showing every document type of the contract, with the system columns and the
+    /// first properties of each type. `document_types` holds the name and JSON schema of each
+    /// type.
+    pub fn template(
+        name: &str,
+        contract_id: &str,
+        document_types: &[(String, Value)],
+    ) -> AppProfile {
+        AppProfile {
+            name: name.to_string(),
+            contract_id: contract_id.to_string(),
+            tables: document_types
+                .iter()
+                .map(|(document_type, schema)| AppProfileTable {
+                    document_type: document_type.clone(),
+                    title: None,
+                    columns: SYSTEM_COLUMNS
+                        .iter()
+                        .map(|column| column.to_string())
+                        .chain(
+                            schema_properties(schema)
+                                .into_iter()
+                                .take(GENERATED_PROPERTY_COLUMNS),
+                        )
+                        .collect(),
+                    actions: vec![AppProfileAction::View],
+                    limit: DEFAULT_TABLE_LIMIT,
+                })
+                .collect(),
+            network: String::new(),
+        }
+    }
+
+    /// Reads the description a user wrote or edited
+    pub fn from_json(json: &str) -> Result<AppProfile, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid app profile: {}", e))
+    }
+
+    /// The description without the network, for editing
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_value(self).expect("app profile serializes to JSON");
+        if let Some(object) = json.as_object_mut() {
+            object.remove("network");
+        }
+        serde_json::to_string_pretty(&json).expect("app profile serializes to JSON")
+    }
+
+    /// Checks the profile against the contract. `document_types` holds the name and JSON schema
+    /// of each document type of the contract.
+    pub fn validate(&self, document_types: &[(String, Value)]) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("The profile needs a name".to_string());
+        }
+        if self.tables.is_empty() {
+            return Err("The profile needs at least one table".to_string());
+        }
+        for table in &self.tables {
+            let Some((_, schema)) = document_types
+                .iter()
+                .find(|(name, _)| name == &table.document_type)
+            else {
+                return Err(format!(
+                    "The contract has no document type \"{}\"",
+                    table.document_type
+                ));
+            };
+            if table.columns.is_empty() {
+                return Err(format!("The table {} has no columns", table.title()));
+            }
+            let properties = schema_properties(schema);
+            if let Some(column) = table.columns.iter().find(|column| {
+                !SYSTEM_FIELDS.contains(&column.as_str()) && !properties.contains(column)
+            }) {
+                return Err(format!(
+                    "{} documents have no property \"{}\"",
+                    table.document_type, column
+                ));
+            }
+            if table.limit == 0 || table.limit > MAX_TABLE_LIMIT {
+                return Err(format!(
+                    "The limit of the table {} must be between 1 and {}",
+                    table.title(),
+                    MAX_TABLE_LIMIT
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The property names of a document type's JSON schema
+pub fn schema_properties(schema: &Value) -> Vec<String> {
+    schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| properties.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// A column's value in a document as JSON, see `document_to_json`, shown in a table cell
+pub fn cell_text(document: &Value, column: &str) -> String {
+    match document.get(column) {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(text)) => text.clone(),
+        Some(value) => value.to_string(),
+    }
+}
@@ -0,0 +1,67 @@
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Rounds of hashing the approver password goes through, to slow down guessing
+const PASSWORD_HASH_ROUNDS: u32 = 100_000;
+
+/// Actions that need a second confirmation with the approver password before they run
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApprovalPolicy {
+    /// Withdrawals of more credits than this need approval, `None` for never
+    pub withdrawal_threshold: Option<u64>,
+    /// Adding, rotating and disabling identity keys
+    pub key_changes: bool,
+    /// Replacing the cached schema of a contract with the version on Platform
+    pub contract_updates: bool,
+}
+
+impl ApprovalPolicy {
+    pub fn is_enabled(&self) -> bool {
+        self.withdrawal_threshold.is_some() || self.key_changes || self.contract_updates
+    }
+}
+
+/// The approver as stored in the database
+#[derive(Debug, Clone, PartialEq)]
+pub struct Approver {
+    pub name: String,
+    pub salt: String,
+    pub password_hash: String,
+}
+
+impl Approver {
+    pub fn new(name: &str, password: &str) -> Self {
+        let mut salt = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let salt = hex::encode(salt);
+        Self {
+            name: name.trim().to_string(),
+            password_hash: hash_password(password, &salt),
+            salt,
+        }
+    }
+
+    pub fn verify(&self, password: &str) -> bool {
+        hash_password(password, &self.salt) == self.password_hash
+    }
+}
+
+fn hash_password(password: &str, salt: &str) -> String {
+    let mut hash = Sha256::digest(format!("{}{}", salt, password).as_bytes());
+    for _ in 1..PASSWORD_HASH_ROUNDS {
+        hash = Sha256::digest(hash);
+    }
+    hex::encode(hash)
+}
+
+/// One line of the audit trail
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub action: String,
+    pub outcome: String,
+    /// Who approved, if approval was needed
+    pub approver: Option<String>,
+    pub logged_at: i64,
+}
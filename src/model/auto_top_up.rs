@@ -0,0 +1,47 @@
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::fee::Credits;
+use serde::{Deserialize, Serialize};
+
+/// Length of the window the weekly limit of a rule applies to, in milliseconds
+pub const TOP_UP_WINDOW_MS: u64 = 7 * 24 * 60 * 60 * 1000;
+
+/// Keeps an identity above a balance by topping it up from one of our wallets, up to a weekly
+/// amount
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoTopUpRule {
+    pub enabled: bool,
+    /// Base58 id of the identity to keep topped up
+    pub identity_id: String,
+    /// Balance the identity is kept above
+    pub minimum: Credits,
+    /// The wallet funding the top ups, see [`crate::model::wallet::Wallet::seed_hash`]
+    pub wallet_seed_hash: String,
+    /// Funded per top up
+    pub amount: Duffs,
+    /// Most funded within a rolling week
+    pub weekly_limit: Duffs,
+    /// Whether the user confirmed the first top up, after which the rule runs unattended
+    pub confirmed: bool,
+}
+
+impl Default for AutoTopUpRule {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            identity_id: String::new(),
+            minimum: 10_000_000_000,
+            wallet_seed_hash: String::new(),
+            amount: 10_000_000,
+            weekly_limit: 50_000_000,
+            confirmed: false,
+        }
+    }
+}
+
+impl AutoTopUpRule {
+    /// What the next top up funds after `spent` duffs this week, 0 once the limit is reached
+    pub fn next_amount(&self, spent: Duffs) -> Duffs {
+        self.amount.min(self.weekly_limit.saturating_sub(spent))
+    }
+}
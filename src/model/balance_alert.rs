@@ -0,0 +1,34 @@
+use dash_sdk::dpp::fee::Credits;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Minimum credit balances of identities, below which they are flagged and the user is alerted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BalanceAlerts {
+    /// Minimums of single identities keyed by their base58 id
+    pub identity_minimums: BTreeMap<String, Credits>,
+    /// How often the balances of identities with a minimum are refreshed
+    pub poll_interval_minutes: u32,
+}
+
+impl Default for BalanceAlerts {
+    fn default() -> Self {
+        Self {
+            identity_minimums: BTreeMap::new(),
+            poll_interval_minutes: 10,
+        }
+    }
+}
+
+impl BalanceAlerts {
+    pub fn minimum_for(&self, identity_id: &str) -> Option<Credits> {
+        self.identity_minimums.get(identity_id).copied()
+    }
+
+    /// The minimum the balance is below, `None` when it has no minimum or is above it
+    pub fn shortfall_minimum(&self, identity_id: &str, balance: Credits) -> Option<Credits> {
+        self.minimum_for(identity_id)
+            .filter(|minimum| balance < *minimum)
+    }
+}
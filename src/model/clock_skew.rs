@@ -0,0 +1,64 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// Skew above which the user is warned that contest end times are off
+pub const CLOCK_SKEW_WARNING_SECS: i64 = 60;
+
+/// Platform blocks can be this far apart, so a local clock ahead of the last block time by less
+/// than this may just be the time since that block
+const MAX_PLATFORM_BLOCK_GAP_SECS: i64 = 300;
+
+/// How far the local clock is ahead of the network in milliseconds. Process wide, the local
+/// clock is the same whichever network is chosen.
+static CLOCK_OFFSET_MS: AtomicI64 = AtomicI64::new(0);
+
+/// The local clock compared with the network
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockSkew {
+    /// Local time minus the time of the last Platform block
+    pub platform_offset_ms: Option<i64>,
+    /// Local time minus the median time of Dash Core's peers
+    pub core_offset_ms: Option<i64>,
+    pub measured_at: DateTime<Utc>,
+}
+
+impl ClockSkew {
+    /// How far the local clock is ahead, negative when it is behind. Core's peer time is precise
+    /// to seconds, the Platform block time is only used when it can't be the gap between blocks.
+    pub fn offset_ms(&self) -> Option<i64> {
+        self.core_offset_ms.or(self
+            .platform_offset_ms
+            .filter(|offset| *offset < 0 || *offset > MAX_PLATFORM_BLOCK_GAP_SECS * 1000))
+    }
+
+    pub fn exceeds_threshold(&self) -> bool {
+        self.offset_ms()
+            .is_some_and(|offset| offset.abs() > CLOCK_SKEW_WARNING_SECS * 1000)
+    }
+
+    /// E.g. "Local clock is 3m 20s ahead of the network"
+    pub fn describe(&self) -> String {
+        let Some(offset) = self.offset_ms() else {
+            return "Local clock matches the network within the time between Platform blocks"
+                .to_string();
+        };
+        let seconds = offset.abs() / 1000;
+        let amount = if seconds >= 60 {
+            format!("{}m {}s", seconds / 60, seconds % 60)
+        } else {
+            format!("{}s", seconds)
+        };
+        let direction = if offset >= 0 { "ahead of" } else { "behind" };
+        format!("Local clock is {} {} the network", amount, direction)
+    }
+}
+
+/// Makes [`network_now`] correct the local clock by the measured skew
+pub fn apply_clock_offset(skew: &ClockSkew) {
+    CLOCK_OFFSET_MS.store(skew.offset_ms().unwrap_or(0), Ordering::Relaxed);
+}
+
+/// The current time by the network's clock, for counting down to times set by the network
+pub fn network_now() -> DateTime<Utc> {
+    Utc::now() - Duration::milliseconds(CLOCK_OFFSET_MS.load(Ordering::Relaxed))
+}
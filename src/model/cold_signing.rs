@@ -0,0 +1,209 @@
+use crate::model::transition_sandbox::SandboxTransitionKind;
+use dash_sdk::dpp::dashcore::hashes::{sha256, Hash};
+use dash_sdk::dpp::dashcore::{Address, Network};
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::signer::Signer;
+use dash_sdk::dpp::identity::KeyID;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::platform_value::BinaryData;
+use dash_sdk::dpp::serialization::{PlatformDeserializable, PlatformSerializable};
+use dash_sdk::dpp::state_transition::identity_credit_withdrawal_transition::accessors::IdentityCreditWithdrawalTransitionAccessorsV0;
+use dash_sdk::dpp::state_transition::StateTransition;
+use dash_sdk::dpp::ProtocolError;
+use dash_sdk::platform::IdentityPublicKey;
+use serde::{Deserialize, Serialize};
+
+/// Version of the file format, files of other versions are refused
+pub const COLD_SIGNING_FORMAT_VERSION: u32 = 1;
+
+/// Where a cold signed transition is in its round trip between the machines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColdSigningStatus {
+    /// Built and exported by the online machine
+    AwaitingSignature,
+    /// Signed by the offline machine
+    Signed,
+    Broadcast,
+    Failed,
+}
+
+impl ColdSigningStatus {
+    pub const ALL: [ColdSigningStatus; 4] = [
+        ColdSigningStatus::AwaitingSignature,
+        ColdSigningStatus::Signed,
+        ColdSigningStatus::Broadcast,
+        ColdSigningStatus::Failed,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColdSigningStatus::AwaitingSignature => "Awaiting signature",
+            ColdSigningStatus::Signed => "Signed",
+            ColdSigningStatus::Broadcast => "Broadcast",
+            ColdSigningStatus::Failed => "Failed",
+        }
+    }
+
+    /// Name stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColdSigningStatus::AwaitingSignature => "awaiting_signature",
+            ColdSigningStatus::Signed => "signed",
+            ColdSigningStatus::Broadcast => "broadcast",
+            ColdSigningStatus::Failed => "failed",
+        }
+    }
+
+    pub fn from_str(status: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.as_str() == status)
+    }
+}
+
+/// A transition passed between the online and the offline machine, as a JSON file or a QR
+/// code of it. The online machine exports it unsigned, the offline machine signs it and the
+/// online machine broadcasts the signed one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColdSigningFile {
+    pub version: u32,
+    /// Network the transition was built for, e.g. "testnet"
+    pub network: String,
+    /// Hex of the hash of the unsigned transition, the same on both machines
+    pub request_id: String,
+    /// Identity id in base58
+    pub identity_id: String,
+    pub key_id: KeyID,
+    /// What the transition does, e.g. "Withdraw 1 DASH to yX...". Only a label for the
+    /// activity, the signer shows what it reads from the transition.
+    pub description: String,
+    /// Credits the transition moves out of the identity, 0 if none
+    pub credits: Credits,
+    /// Hex of the serialized transition
+    pub transition: String,
+    pub signed: bool,
+}
+
+impl ColdSigningFile {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("cold signing file serializes")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let file: ColdSigningFile = serde_json::from_str(json.trim())
+            .map_err(|e| format!("Not a cold signing file: {}", e))?;
+        if file.version != COLD_SIGNING_FORMAT_VERSION {
+            return Err(format!(
+                "The file has version {}, this version of the tool reads version {}",
+                file.version, COLD_SIGNING_FORMAT_VERSION
+            ));
+        }
+        Ok(file)
+    }
+
+    /// The withdrawal of the file, checked to be from the identity and key the file names and
+    /// to hash to its request id. What is shown and signed comes from the transition, not from
+    /// the description and credits of the file.
+    pub fn decode_withdrawal(
+        &self,
+        network: Network,
+    ) -> Result<(StateTransition, ColdWithdrawal), String> {
+        let bytes =
+            hex::decode(&self.transition).map_err(|e| format!("Invalid transition hex: {}", e))?;
+        let transition = StateTransition::deserialize_from_bytes(&bytes)
+            .map_err(|e| format!("Failed to parse the transition: {}", e))?;
+        let StateTransition::IdentityCreditWithdrawal(withdrawal) = &transition else {
+            return Err(format!(
+                "Only withdrawals are cold signed, the file holds a {} transition",
+                SandboxTransitionKind::of(&transition)
+                    .label()
+                    .to_lowercase()
+            ));
+        };
+        if request_id(&transition)? != self.request_id {
+            return Err("The transition doesn't match the request id of the file".to_string());
+        }
+        let owner_id = transition.owner_id().to_string(Encoding::Base58);
+        if owner_id != self.identity_id {
+            return Err(format!(
+                "The transition is from {}, not from the identity of the file",
+                owner_id
+            ));
+        }
+        if transition.signature_public_key_id() != Some(self.key_id) {
+            return Err("The transition names another key than the file".to_string());
+        }
+        let to_address = match withdrawal.output_script() {
+            Some(script) => Some(Address::from_script(&script, network).map_err(|e| {
+                format!("The withdrawal pays to a script without an address: {}", e)
+            })?),
+            None => None,
+        };
+        let withdrawal = ColdWithdrawal {
+            credits: withdrawal.amount(),
+            to_address,
+        };
+        if withdrawal.credits != self.credits {
+            return Err(format!(
+                "The transition withdraws {} credits, not the {} of the file",
+                withdrawal.credits, self.credits
+            ));
+        }
+        Ok((transition, withdrawal))
+    }
+}
+
+/// Hex of the hash of the transition without its signature, the same before and after signing
+pub fn request_id(transition: &StateTransition) -> Result<String, String> {
+    let mut unsigned = transition.clone();
+    unsigned.set_signature(BinaryData::default());
+    let bytes = unsigned
+        .serialize_to_bytes()
+        .map_err(|e| format!("Failed to serialize the transition: {}", e))?;
+    Ok(hex::encode(sha256::Hash::hash(&bytes).to_byte_array()))
+}
+
+/// What a cold signed withdrawal does, as read from its transition
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColdWithdrawal {
+    pub credits: Credits,
+    /// `None` for the payout address of the identity
+    pub to_address: Option<Address>,
+}
+
+impl ColdWithdrawal {
+    pub fn description(&self) -> String {
+        match &self.to_address {
+            Some(address) => format!("Withdraw {} credits to {}", self.credits, address),
+            None => format!("Withdraw {} credits to the payout address", self.credits),
+        }
+    }
+}
+
+/// One tracked cold signed transition
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColdSigningRecord {
+    pub request_id: String,
+    pub identity_id: String,
+    pub description: String,
+    pub status: ColdSigningStatus,
+    pub error: Option<String>,
+    pub updated_at: i64,
+}
+
+/// Signer that leaves signatures empty, to build a transition on a machine without the keys.
+/// The offline machine replaces the signature, the signed data excludes it.
+#[derive(Debug, Clone, Copy)]
+pub struct UnsignedSigner;
+
+impl Signer for UnsignedSigner {
+    fn sign(
+        &self,
+        _identity_public_key: &IdentityPublicKey,
+        _data: &[u8],
+    ) -> Result<BinaryData, ProtocolError> {
+        Ok(BinaryData::default())
+    }
+
+    fn can_sign_with(&self, _identity_public_key: &IdentityPublicKey) -> bool {
+        true
+    }
+}
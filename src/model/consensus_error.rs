@@ -0,0 +1,320 @@
+use dash_sdk::dpp::consensus::basic::BasicError;
+use dash_sdk::dpp::consensus::codes::ErrorWithCode;
+use dash_sdk::dpp::consensus::fee::fee_error::FeeError;
+use dash_sdk::dpp::consensus::signature::SignatureError;
+use dash_sdk::dpp::consensus::state::state_error::StateError;
+use dash_sdk::dpp::consensus::ConsensusError;
+use dash_sdk::dpp::ProtocolError;
+
+/// The name and meaning of the consensus errors the tool explains, `None` for the others
+fn known_error(error: &ConsensusError) -> Option<(&'static str, &'static str)> {
+    Some(match error {
+        ConsensusError::BasicError(BasicError::UnsupportedProtocolVersionError(_)) => (
+            "UnsupportedProtocolVersionError",
+            "The transition was built for a protocol version the network doesn't run. \
+            Update the app or check that it is connected to the right network.",
+        ),
+        ConsensusError::BasicError(BasicError::JsonSchemaError(_)) => (
+            "JsonSchemaError",
+            "A value doesn't match the data contract's schema.",
+        ),
+        ConsensusError::BasicError(BasicError::InvalidIdentifierError(_)) => (
+            "InvalidIdentifierError",
+            "An identifier isn't 32 bytes of base58.",
+        ),
+        ConsensusError::BasicError(BasicError::InvalidDocumentTypeError(_)) => (
+            "InvalidDocumentTypeError",
+            "The data contract has no document type with this name. The contract may \
+            have been updated since it was cached, refresh it.",
+        ),
+        ConsensusError::BasicError(BasicError::MaxDocumentsTransitionsExceededError(_)) => (
+            "MaxDocumentsTransitionsExceededError",
+            "The batch holds more documents than one transition may.",
+        ),
+        ConsensusError::BasicError(BasicError::DocumentFieldMaxSizeExceededError(_)) => (
+            "DocumentFieldMaxSizeExceededError",
+            "A document field is larger than Platform allows.",
+        ),
+        ConsensusError::BasicError(
+            BasicError::IdentityAssetLockTransactionOutPointAlreadyConsumedError(_),
+        ) => (
+            "IdentityAssetLockTransactionOutPointAlreadyConsumedError",
+            "The asset lock already funded an identity or top up. Fund the transition \
+            with a new asset lock.",
+        ),
+        ConsensusError::BasicError(BasicError::InvalidAssetLockProofCoreChainHeightError(_)) => (
+            "InvalidAssetLockProofCoreChainHeightError",
+            "Platform hasn't seen the Core block of the asset lock yet. Wait a few \
+            minutes and try again.",
+        ),
+        ConsensusError::BasicError(BasicError::InvalidInstantAssetLockProofSignatureError(_)) => (
+            "InvalidInstantAssetLockProofSignatureError",
+            "The InstantSend lock of the asset lock couldn't be verified, it may have \
+            been signed by an old quorum. Try again with a chain lock proof.",
+        ),
+        ConsensusError::BasicError(BasicError::MissingMasterPublicKeyError(_)) => (
+            "MissingMasterPublicKeyError",
+            "A new identity needs a master authentication key.",
+        ),
+        ConsensusError::BasicError(
+            BasicError::InvalidIdentityCreditWithdrawalTransitionCoreFeeError(_),
+        ) => (
+            "InvalidIdentityCreditWithdrawalTransitionCoreFeeError",
+            "The withdrawal's fee rate isn't a Fibonacci number.",
+        ),
+        ConsensusError::BasicError(
+            BasicError::InvalidIdentityCreditWithdrawalTransitionAmountError(_),
+        ) => (
+            "InvalidIdentityCreditWithdrawalTransitionAmountError",
+            "The withdrawal amount is below the minimum or above the maximum.",
+        ),
+        ConsensusError::BasicError(BasicError::IdentityCreditTransferToSelfError(_)) => (
+            "IdentityCreditTransferToSelfError",
+            "Credits can't be transferred to the identity sending them.",
+        ),
+        ConsensusError::BasicError(BasicError::StateTransitionMaxSizeExceededError(_)) => (
+            "StateTransitionMaxSizeExceededError",
+            "The transition is larger than Platform accepts.",
+        ),
+        ConsensusError::SignatureError(SignatureError::IdentityNotFoundError(_)) => (
+            "IdentityNotFoundError",
+            "The identity signing the transition doesn't exist on this network.",
+        ),
+        ConsensusError::SignatureError(SignatureError::InvalidStateTransitionSignatureError(_)) => {
+            (
+                "InvalidStateTransitionSignatureError",
+                "The signature doesn't match the key. Check that the private key belongs \
+            to the selected public key.",
+            )
+        }
+        ConsensusError::SignatureError(SignatureError::MissingPublicKeyError(_)) => (
+            "MissingPublicKeyError",
+            "The identity has no key with the id the transition was signed with.",
+        ),
+        ConsensusError::SignatureError(
+            SignatureError::InvalidSignaturePublicKeySecurityLevelError(_),
+        ) => (
+            "InvalidSignaturePublicKeySecurityLevelError",
+            "The key's security level isn't allowed for this kind of transition.",
+        ),
+        ConsensusError::SignatureError(SignatureError::WrongPublicKeyPurposeError(_)) => (
+            "WrongPublicKeyPurposeError",
+            "The key's purpose doesn't allow signing this kind of transition.",
+        ),
+        ConsensusError::SignatureError(SignatureError::PublicKeyIsDisabledError(_)) => (
+            "PublicKeyIsDisabledError",
+            "The transition was signed with a disabled key.",
+        ),
+        ConsensusError::SignatureError(SignatureError::PublicKeySecurityLevelNotMetError(_)) => (
+            "PublicKeySecurityLevelNotMetError",
+            "The transition needs a key with a higher security level.",
+        ),
+        ConsensusError::FeeError(FeeError::BalanceIsNotEnoughError(_)) => (
+            "BalanceIsNotEnoughError",
+            "The identity doesn't have enough credits to pay for the transition. Top \
+            it up and try again.",
+        ),
+        ConsensusError::StateError(StateError::DataContractAlreadyPresentError(_)) => (
+            "DataContractAlreadyPresentError",
+            "A data contract with this id is already registered.",
+        ),
+        ConsensusError::StateError(StateError::DataContractIsReadonlyError(_)) => (
+            "DataContractIsReadonlyError",
+            "The data contract is read only and can't be updated.",
+        ),
+        ConsensusError::StateError(StateError::DocumentAlreadyPresentError(_)) => (
+            "DocumentAlreadyPresentError",
+            "A document with this id already exists.",
+        ),
+        ConsensusError::StateError(StateError::DocumentNotFoundError(_)) => (
+            "DocumentNotFoundError",
+            "The document doesn't exist, it may have been deleted.",
+        ),
+        ConsensusError::StateError(StateError::DocumentOwnerIdMismatchError(_)) => (
+            "DocumentOwnerIdMismatchError",
+            "Only the owner of the document can change it.",
+        ),
+        ConsensusError::StateError(StateError::DocumentTimestampWindowViolationError(_)) => (
+            "DocumentTimestampWindowViolationError",
+            "The document's timestamp is too far from Platform's time. Check the \
+            computer's clock.",
+        ),
+        ConsensusError::StateError(StateError::DuplicateUniqueIndexError(_)) => (
+            "DuplicateUniqueIndexError",
+            "Another document already has these values in a unique index.",
+        ),
+        ConsensusError::StateError(StateError::InvalidDocumentRevisionError(_)) => (
+            "InvalidDocumentRevisionError",
+            "The document was changed since it was loaded. Refresh it and try again.",
+        ),
+        ConsensusError::StateError(StateError::DocumentNotForSaleError(_)) => (
+            "DocumentNotForSaleError",
+            "The document isn't for sale anymore.",
+        ),
+        ConsensusError::StateError(StateError::DocumentIncorrectPurchasePriceError(_)) => (
+            "DocumentIncorrectPurchasePriceError",
+            "The price offered doesn't match the document's price, it may have changed.",
+        ),
+        ConsensusError::StateError(StateError::DocumentContestCurrentlyLockedError(_)) => (
+            "DocumentContestCurrentlyLockedError",
+            "The contested name is locked, the voters decided nobody gets it for now.",
+        ),
+        ConsensusError::StateError(StateError::DocumentContestNotJoinableError(_)) => (
+            "DocumentContestNotJoinableError",
+            "The contest can't be joined anymore, it started too long ago.",
+        ),
+        ConsensusError::StateError(StateError::DocumentContestIdentityAlreadyContestantError(
+            _,
+        )) => (
+            "DocumentContestIdentityAlreadyContestantError",
+            "The identity already is a contestant of this contest.",
+        ),
+        ConsensusError::StateError(StateError::IdentityAlreadyExistsError(_)) => (
+            "IdentityAlreadyExistsError",
+            "An identity was already registered with this asset lock.",
+        ),
+        ConsensusError::StateError(StateError::IdentityPublicKeyIsReadOnlyError(_)) => (
+            "IdentityPublicKeyIsReadOnlyError",
+            "The key is read only and can't be disabled.",
+        ),
+        ConsensusError::StateError(StateError::InvalidIdentityRevisionError(_)) => (
+            "InvalidIdentityRevisionError",
+            "The identity was updated since it was loaded. Refresh it and try again.",
+        ),
+        ConsensusError::StateError(StateError::InvalidIdentityNonceError(_)) => (
+            "InvalidIdentityNonceError",
+            "The transition's nonce was already used or is too far ahead. Another \
+            transition of the identity may still be in flight, wait and try again.",
+        ),
+        ConsensusError::StateError(StateError::MaxIdentityPublicKeyLimitReachedError(_)) => (
+            "MaxIdentityPublicKeyLimitReachedError",
+            "The identity already has as many keys as it may.",
+        ),
+        ConsensusError::StateError(StateError::DuplicatedIdentityPublicKeyStateError(_)) => (
+            "DuplicatedIdentityPublicKeyStateError",
+            "The key is already registered, by this or another identity.",
+        ),
+        ConsensusError::StateError(StateError::IdentityPublicKeyIsDisabledError(_)) => (
+            "IdentityPublicKeyIsDisabledError",
+            "The key is already disabled.",
+        ),
+        ConsensusError::StateError(StateError::IdentityInsufficientBalanceError(_)) => (
+            "IdentityInsufficientBalanceError",
+            "The identity doesn't have enough credits for the amount and the fees.",
+        ),
+        ConsensusError::StateError(StateError::MasternodeNotFoundError(_)) => (
+            "MasternodeNotFoundError",
+            "The masternode isn't in the current masternode list.",
+        ),
+        ConsensusError::StateError(StateError::VotePollNotAvailableForVotingError(_)) => (
+            "VotePollNotAvailableForVotingError",
+            "The contest is over, votes aren't accepted anymore.",
+        ),
+        ConsensusError::StateError(StateError::MasternodeVotedTooManyTimesError(_)) => (
+            "MasternodeVotedTooManyTimesError",
+            "The masternode has changed its vote on this contest as often as it may.",
+        ),
+        ConsensusError::StateError(StateError::MasternodeVoteAlreadyPresentError(_)) => (
+            "MasternodeVoteAlreadyPresentError",
+            "The masternode already cast this vote.",
+        ),
+        ConsensusError::StateError(StateError::MasternodeIncorrectVotingAddressError(_)) => (
+            "MasternodeIncorrectVotingAddressError",
+            "The voting key doesn't belong to the masternode's voting address.",
+        ),
+        _ => return None,
+    })
+}
+
+/// Why Platform rejected a transition, in words, and the field it objected to if it named one
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsensusErrorExplanation {
+    pub code: u32,
+    pub name: &'static str,
+    pub explanation: &'static str,
+    /// The offending field, e.g. "document field 'label' violates pattern"
+    pub field: Option<String>,
+}
+
+impl ConsensusErrorExplanation {
+    /// The offending field as a sentence of its own
+    pub fn field_sentence(&self) -> Option<String> {
+        let field = self.field.as_ref()?;
+        let mut chars = field.chars();
+        let first = chars.next()?;
+        Some(format!("{}{}.", first.to_uppercase(), chars.as_str()))
+    }
+
+    /// One line for the activity log
+    pub fn summary(&self) -> String {
+        match &self.field {
+            Some(field) => format!(
+                "{} ({}): {}. {}",
+                self.name, self.code, field, self.explanation
+            ),
+            None => format!("{} ({}): {}", self.name, self.code, self.explanation),
+        }
+    }
+}
+
+/// The consensus error Platform rejected a transition with, `None` when the SDK call failed for
+/// another reason, like an unreachable node
+pub fn rejection_cause(error: &dash_sdk::Error) -> Option<&ConsensusError> {
+    match error {
+        dash_sdk::Error::Protocol(ProtocolError::ConsensusError(error)) => Some(error),
+        dash_sdk::Error::StateTransitionBroadcastError(error) => error.cause.as_ref(),
+        _ => None,
+    }
+}
+
+/// What the consensus error means, with its code as DPP numbers it
+pub fn explain_consensus_error(error: &ConsensusError) -> Option<ConsensusErrorExplanation> {
+    let (name, explanation) = known_error(error)?;
+    Some(ConsensusErrorExplanation {
+        code: error.code(),
+        name,
+        explanation,
+        field: offending_field(error),
+    })
+}
+
+/// The field the error points at, from the schema path or the properties it lists
+fn offending_field(error: &ConsensusError) -> Option<String> {
+    match error {
+        ConsensusError::BasicError(BasicError::JsonSchemaError(error)) => {
+            let keyword = Some(error.keyword().as_str()).filter(|keyword| !keyword.is_empty());
+            let path = error
+                .instance_path()
+                .trim_start_matches('/')
+                .replace('/', ".");
+            // A missing or unexpected property is reported on its parent, with its name
+            let path = Some(path)
+                .filter(|path| !path.is_empty())
+                .or_else(|| Some(error.property_name().clone()).filter(|name| !name.is_empty()));
+            match (path, keyword) {
+                (Some(path), Some(keyword)) => {
+                    Some(format!("field '{}' violates {}", path, keyword))
+                }
+                (Some(path), None) => Some(format!("field '{}' is invalid", path)),
+                (None, Some(keyword)) => Some(format!("a field violates {}", keyword)),
+                (None, None) => None,
+            }
+        }
+        ConsensusError::StateError(StateError::DuplicateUniqueIndexError(error)) => {
+            let fields = error
+                .duplicating_properties()
+                .iter()
+                .map(|field| format!("'{}'", field))
+                .collect::<Vec<_>>();
+            (!fields.is_empty()).then(|| format!("document fields {} are taken", fields.join(", ")))
+        }
+        ConsensusError::BasicError(BasicError::InvalidDocumentTypeError(error)) => Some(format!(
+            "document type '{}' doesn't exist",
+            error.document_type()
+        )),
+        ConsensusError::BasicError(BasicError::DocumentFieldMaxSizeExceededError(error)) => {
+            Some(format!("document field '{}' is too large", error.field()))
+        }
+        _ => None,
+    }
+}
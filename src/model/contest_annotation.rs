@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// A note the user keeps on a contest or on one of its contestants, e.g. tagged
+/// "impersonation" with a link to the evidence. Notes stay local unless exported with a vote
+/// list or a calendar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContestAnnotation {
+    /// Normalized name of the contest
+    pub contest: String,
+    /// Base58 id of the contestant, None for a note on the whole contest
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contestant: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub note: String,
+}
+
+impl ContestAnnotation {
+    /// Tags from a comma separated input, trimmed, lowercased and without duplicates
+    pub fn parse_tags(input: &str) -> Vec<String> {
+        let mut tags: Vec<String> = vec![];
+        for tag in input.split(',') {
+            let tag = tag.trim().to_lowercase();
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.note.trim().is_empty()
+    }
+
+    /// The tags in brackets followed by the note, e.g. "[impersonation] Not the brand"
+    pub fn summary(&self) -> String {
+        match (self.tags.is_empty(), self.note.trim()) {
+            (true, note) => note.to_string(),
+            (false, "") => format!("[{}]", self.tags.join(", ")),
+            (false, note) => format!("[{}] {}", self.tags.join(", "), note),
+        }
+    }
+}
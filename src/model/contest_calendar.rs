@@ -0,0 +1,116 @@
+use crate::model::contest_annotation::ContestAnnotation;
+use crate::model::contested_name::ContestedName;
+use chrono::{DateTime, Utc};
+use std::fmt::Write as _;
+
+/// Reminders added to every contest, in minutes before the contest ends
+const REMINDER_MINUTES: [u32; 2] = [24 * 60, 60];
+
+/// Renders the end times of the contests as an iCalendar file with reminders, with the notes
+/// on each contest in its description. Contests whose end time is not known yet are left out.
+pub fn contests_to_ics(
+    contests: &[ContestedName],
+    annotations: &[ContestAnnotation],
+    network: &str,
+) -> String {
+    let now = format_ics_time(Utc::now());
+    let mut ics = String::new();
+    ics.push_str("BEGIN:VCALENDAR\r\n");
+    ics.push_str("VERSION:2.0\r\n");
+    ics.push_str("PRODID:-//Dash Evo Tool//Contested Names//EN\r\n");
+    ics.push_str("CALSCALE:GREGORIAN\r\n");
+    let _ = write!(ics, "X-WR-CALNAME:DPNS contests ({})\r\n", network);
+
+    for contest in contests {
+        let Some(end_time) = contest
+            .end_time
+            .and_then(|end_time| DateTime::from_timestamp_millis(end_time as i64))
+        else {
+            continue;
+        };
+        let name = &contest.normalized_contested_name;
+        let contestants = contest
+            .contestants
+            .as_ref()
+            .map(|contestants| {
+                contestants
+                    .iter()
+                    .map(|contestant| format!("{} ({} votes)", contestant.name, contestant.votes))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+        let mut description = format!(
+            "Voting on the DPNS name \"{}\" ends. Contestants: {}. Lock votes: {}. Abstain votes: {}.",
+            name,
+            if contestants.is_empty() {
+                "unknown"
+            } else {
+                &contestants
+            },
+            contest.locked_votes.unwrap_or(0),
+            contest.abstain_votes.unwrap_or(0)
+        );
+        let notes: Vec<String> = annotations
+            .iter()
+            .filter(|annotation| &annotation.contest == name)
+            .map(|annotation| annotation.summary())
+            .collect();
+        if !notes.is_empty() {
+            let _ = write!(description, " Notes: {}", notes.join("; "));
+        }
+
+        ics.push_str("BEGIN:VEVENT\r\n");
+        let _ = write!(ics, "UID:{}-{}@dash-evo-tool\r\n", name, network);
+        let _ = write!(ics, "DTSTAMP:{}\r\n", now);
+        let _ = write!(ics, "DTSTART:{}\r\n", format_ics_time(end_time));
+        let _ = write!(ics, "DTEND:{}\r\n", format_ics_time(end_time));
+        push_folded(
+            &mut ics,
+            &format!("SUMMARY:DPNS contest ends: {}", escape_text(name)),
+        );
+        push_folded(
+            &mut ics,
+            &format!("DESCRIPTION:{}", escape_text(&description)),
+        );
+        for minutes in REMINDER_MINUTES {
+            ics.push_str("BEGIN:VALARM\r\n");
+            ics.push_str("ACTION:DISPLAY\r\n");
+            let _ = write!(ics, "TRIGGER:-PT{}M\r\n", minutes);
+            push_folded(
+                &mut ics,
+                &format!("DESCRIPTION:Contest for {} ends soon", escape_text(name)),
+            );
+            ics.push_str("END:VALARM\r\n");
+        }
+        ics.push_str("END:VEVENT\r\n");
+    }
+
+    ics.push_str("END:VCALENDAR\r\n");
+    ics
+}
+
+fn format_ics_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Lines longer than 75 bytes are folded onto continuation lines starting with a space
+fn push_folded(ics: &mut String, line: &str) {
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > 75 {
+            ics.push_str("\r\n ");
+            length = 1;
+        }
+        ics.push(c);
+        length += c.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
@@ -0,0 +1,107 @@
+use crate::model::contested_name::ContestedName;
+use dash_sdk::dpp::identity::TimestampMillis;
+use serde::{Deserialize, Serialize};
+
+/// Which contests the contested names table shows
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContestFilter {
+    /// Only contests none of our identities voted on
+    pub unvoted_only: bool,
+    /// Only contests ending within this many hours
+    pub ending_within_hours: Option<u32>,
+    pub watched_only: bool,
+    /// Hide names the junk name rules flag
+    pub hide_junk: bool,
+    /// Only names containing this text, ignoring case
+    pub name_contains: String,
+}
+
+impl ContestFilter {
+    pub fn is_active(&self) -> bool {
+        *self != Self::default()
+    }
+
+    /// Whether the filter lets the contest through. `watched` and `junk` are whether the user
+    /// watches it and whether the junk name rules flag it.
+    pub fn matches(
+        &self,
+        contested_name: &ContestedName,
+        watched: bool,
+        junk: bool,
+        now: TimestampMillis,
+    ) -> bool {
+        if self.unvoted_only && !contested_name.my_votes.is_empty() {
+            return false;
+        }
+        if let Some(hours) = self.ending_within_hours {
+            let deadline = now + hours as u64 * 60 * 60 * 1000;
+            if contested_name
+                .end_time
+                .map_or(true, |end_time| end_time > deadline)
+            {
+                return false;
+            }
+        }
+        if self.watched_only && !watched {
+            return false;
+        }
+        if self.hide_junk && junk {
+            return false;
+        }
+        let needle = self.name_contains.trim().to_lowercase();
+        needle.is_empty()
+            || contested_name
+                .normalized_contested_name
+                .to_lowercase()
+                .contains(&needle)
+    }
+}
+
+/// A named layout of the contested names table, e.g. "Ending soon un-voted"
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContestTableView {
+    pub name: String,
+    #[serde(default)]
+    pub filter: ContestFilter,
+    /// Id of the column the table is sorted by
+    pub sort_column: String,
+    #[serde(default)]
+    pub descending: bool,
+    /// Ids of the visible columns, in display order
+    pub columns: Vec<String>,
+}
+
+/// The saved views of the contested names table and the one applied when it opens
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContestTableViews {
+    pub views: Vec<ContestTableView>,
+    /// Name of the view applied when the screen opens
+    pub default_view: Option<String>,
+}
+
+impl ContestTableViews {
+    pub fn get(&self, name: &str) -> Option<&ContestTableView> {
+        self.views.iter().find(|view| view.name == name)
+    }
+
+    /// Adds the view, replacing a view of the same name
+    pub fn save(&mut self, view: ContestTableView) {
+        match self.views.iter_mut().find(|saved| saved.name == view.name) {
+            Some(saved) => *saved = view,
+            None => self.views.push(view),
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.views.retain(|view| view.name != name);
+        if self.default_view.as_deref() == Some(name) {
+            self.default_view = None;
+        }
+    }
+
+    pub fn default_view(&self) -> Option<&ContestTableView> {
+        self.get(self.default_view.as_deref()?)
+    }
+}
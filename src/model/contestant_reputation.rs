@@ -0,0 +1,77 @@
+use dash_sdk::dpp::identity::TimestampMillis;
+use dash_sdk::dpp::prelude::Identifier;
+
+/// A contestant that registered less than this long ago is flagged as new
+const NEW_IDENTITY_DAYS: i64 = 7;
+
+/// A contestant holding at least this many names is flagged, squatters often hold many
+const MANY_NAMES: u32 = 10;
+
+/// Signals about the history of a contestant, to weigh its claim to a name. They are
+/// heuristics: a squatter can build any of them up and a new honest user has none.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContestantReputation {
+    pub identity_id: Identifier,
+    /// Names registered to the identity on Platform
+    pub names_held: u32,
+    pub has_dashpay_profile: bool,
+    /// Time of the earliest DPNS document of the identity, Platform doesn't record when
+    /// identities are created
+    pub active_since: Option<TimestampMillis>,
+    /// Unix time in seconds of the fetch from Platform
+    pub fetched_at: i64,
+    /// Contests the identity took part in, as far as this tool has seen them
+    pub outcomes: ContestOutcomes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ContestOutcomes {
+    pub won: u32,
+    /// Contests awarded to another contestant
+    pub lost: u32,
+    /// Contests voters locked
+    pub locked: u32,
+    pub ongoing: u32,
+}
+
+impl ContestantReputation {
+    /// Short signals for the contest detail, with whether each is worth a closer look
+    pub fn signals(&self, now: TimestampMillis) -> Vec<(String, bool)> {
+        let mut signals = vec![(
+            match self.names_held {
+                1 => "1 name".to_string(),
+                names => format!("{} names", names),
+            },
+            self.names_held >= MANY_NAMES,
+        )];
+        signals.push(if self.has_dashpay_profile {
+            ("Dashpay profile".to_string(), false)
+        } else {
+            ("No Dashpay profile".to_string(), false)
+        });
+        if let Some(active_since) = self.active_since {
+            let days = (now.saturating_sub(active_since) / 86_400_000) as i64;
+            signals.push((
+                format!("Active for {} days", days),
+                days < NEW_IDENTITY_DAYS,
+            ));
+        }
+        let outcomes = self.outcomes;
+        if outcomes.won + outcomes.lost + outcomes.locked > 0 {
+            signals.push((
+                format!(
+                    "Earlier contests: {} won, {} lost, {} locked",
+                    outcomes.won, outcomes.lost, outcomes.locked
+                ),
+                outcomes.locked > 0,
+            ));
+        }
+        if outcomes.ongoing > 1 {
+            signals.push((
+                format!("In {} open contests", outcomes.ongoing),
+                outcomes.ongoing >= MANY_NAMES,
+            ));
+        }
+        signals
+    }
+}
@@ -1,5 +1,6 @@
 use crate::model::qualified_identity::EncryptedPrivateKeyTarget;
 use bincode::{Decode, Encode};
+use dash_sdk::dpp::fee::Credits;
 use dash_sdk::dpp::identity::{KeyID, TimestampMillis};
 use dash_sdk::dpp::prelude::{BlockHeight, CoreBlockHeight, Identifier};
 use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
@@ -36,6 +37,14 @@ pub struct ContestedName {
     pub my_votes: BTreeMap<(Identifier, EncryptedPrivateKeyTarget, KeyID), ResourceVoteChoice>,
 }
 
+impl ContestedName {
+    /// Whether the end time passed at `now`. Until the result is synced the contest is still
+    /// listed as ongoing, but Platform rejects votes on it.
+    pub fn has_ended(&self, now: TimestampMillis) -> bool {
+        self.end_time.is_some_and(|end_time| end_time <= now)
+    }
+}
+
 #[derive(Debug, Encode, Decode, Clone)]
 pub struct Contestant {
     pub id: Identifier,
@@ -47,3 +56,34 @@ pub struct Contestant {
     pub created_at_core_block_height: Option<CoreBlockHeight>,
     pub document_id: Identifier,
 }
+
+/// What Platform shows about a contestant beyond its votes, fetched for the contest detail
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContestantPreview {
+    pub identity_id: Identifier,
+    /// The label as the contestant wrote it, e.g. "Alice"
+    pub label: String,
+    pub domain_document_id: Identifier,
+    /// The identity the name would resolve to
+    pub records_identity: Option<Identifier>,
+    pub domain_created_at: Option<TimestampMillis>,
+    pub domain_created_at_block_height: Option<BlockHeight>,
+    /// The preorder the domain was registered from, None when it wasn't found
+    pub preorder: Option<PreorderPreview>,
+    /// None when the identity wasn't found
+    pub balance: Option<Credits>,
+    pub public_key_count: usize,
+    /// Names already registered to the identity
+    pub other_names: Vec<String>,
+    /// Time of the earliest DPNS document of the identity that was found. Platform doesn't
+    /// record when identities are created, so the identity is at least this old.
+    pub active_since: Option<TimestampMillis>,
+}
+
+/// The preorder document committing to a contestant's name before it was revealed
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreorderPreview {
+    pub document_id: Identifier,
+    pub created_at: Option<TimestampMillis>,
+    pub created_at_block_height: Option<BlockHeight>,
+}
@@ -0,0 +1,120 @@
+use crate::model::document_snapshot::{DocumentSnapshot, SnapshotDocument};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Owners listed in the analytics, by their number of documents
+pub const TOP_OWNER_COUNT: usize = 10;
+
+/// Bytes Platform stores per document besides its properties: the id, owner id, revision and
+/// timestamps
+const DOCUMENT_OVERHEAD_BYTES: u64 = 100;
+
+/// Bytes of one index entry besides the indexed values, a rough average of the tree nodes
+const INDEX_ENTRY_BYTES: u64 = 64;
+
+/// Usage of one document type, from its latest snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeUsage {
+    pub document_type: String,
+    /// The latest snapshot, `None` while the type has none
+    pub snapshot: Option<DocumentSnapshot>,
+    /// Estimated bytes of the documents and their index entries
+    pub estimated_bytes: u64,
+    /// Number of documents by owner id, most first
+    pub owners: Vec<(String, usize)>,
+    /// Document count of each snapshot, oldest first
+    pub growth: Vec<(i64, usize)>,
+}
+
+impl TypeUsage {
+    /// Builds the usage from the snapshots of the type, newest first, and the documents of the
+    /// newest one
+    pub fn new(
+        document_type: String,
+        snapshots: Vec<DocumentSnapshot>,
+        latest_documents: &[SnapshotDocument],
+        index_count: usize,
+    ) -> Self {
+        let mut owners: BTreeMap<&str, usize> = BTreeMap::new();
+        for document in latest_documents {
+            if let Some(owner) = document.json.get("$ownerId").and_then(Value::as_str) {
+                *owners.entry(owner).or_default() += 1;
+            }
+        }
+        let mut owners: Vec<(String, usize)> = owners
+            .into_iter()
+            .map(|(owner, count)| (owner.to_string(), count))
+            .collect();
+        owners.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let estimated_bytes = latest_documents
+            .iter()
+            .map(|document| estimate_document_bytes(&document.json, index_count))
+            .sum();
+        let growth = snapshots
+            .iter()
+            .rev()
+            .map(|snapshot| (snapshot.taken_at, snapshot.document_count))
+            .collect();
+        Self {
+            document_type,
+            snapshot: snapshots.into_iter().next(),
+            estimated_bytes,
+            owners,
+            growth,
+        }
+    }
+
+    pub fn document_count(&self) -> usize {
+        self.snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.document_count)
+            .unwrap_or_default()
+    }
+}
+
+/// The owners with the most documents over all types
+pub fn top_owners(types: &[TypeUsage]) -> Vec<(String, usize)> {
+    let mut owners: BTreeMap<&str, usize> = BTreeMap::new();
+    for usage in types {
+        for (owner, count) in &usage.owners {
+            *owners.entry(owner.as_str()).or_default() += count;
+        }
+    }
+    let mut owners: Vec<(String, usize)> = owners
+        .into_iter()
+        .map(|(owner, count)| (owner.to_string(), count))
+        .collect();
+    owners.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    owners.truncate(TOP_OWNER_COUNT);
+    owners
+}
+
+/// A rough estimate of the bytes a document takes on Platform: its property values, the
+/// fixed fields and one entry per index of its type
+pub fn estimate_document_bytes(json: &Value, index_count: usize) -> u64 {
+    let properties: u64 = json
+        .as_object()
+        .map(|object| {
+            object
+                .iter()
+                .filter(|(name, _)| !name.starts_with('$'))
+                .map(|(name, value)| name.len() as u64 + value_bytes(value))
+                .sum()
+        })
+        .unwrap_or_default();
+    DOCUMENT_OVERHEAD_BYTES + properties + index_count as u64 * INDEX_ENTRY_BYTES
+}
+
+fn value_bytes(value: &Value) -> u64 {
+    match value {
+        Value::Null => 1,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 8,
+        Value::String(text) => text.len() as u64,
+        Value::Array(items) => items.iter().map(value_bytes).sum(),
+        Value::Object(object) => object
+            .iter()
+            .map(|(name, value)| name.len() as u64 + value_bytes(value))
+            .sum(),
+    }
+}
@@ -0,0 +1,289 @@
+use serde_json::{Map, Value};
+use std::fmt::Write as _;
+
+/// Document system fields added to every generated type. Ids are Base58 strings in JSON.
+const SYSTEM_FIELDS: [&str; 5] = ["$id", "$ownerId", "$revision", "$createdAt", "$updatedAt"];
+
+/// A struct to generate, nested objects become structs of their own
+struct TypeDef {
+    name: String,
+    fields: Vec<FieldDef>,
+}
+
+struct FieldDef {
+    /// Name in the document JSON
+    json_name: String,
+    kind: FieldKind,
+    required: bool,
+}
+
+enum FieldKind {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Bytes,
+    /// A nested object, by the name of its generated type
+    Object(String),
+    Array(Box<FieldKind>),
+    /// Anything the schema does not describe precisely
+    Any,
+}
+
+/// Rust structs with serde attributes for the document types of a contract, from the
+/// `documentSchemas` of the contract JSON
+pub fn generate_rust(contract_name: &str, document_schemas: &Map<String, Value>) -> String {
+    let mut code = format!(
+        "//! Document types of the {} contract, generated by Dash Evo Tool.\n\n\
+         use serde::{{Deserialize, Serialize}};\n",
+        contract_name
+    );
+    for type_def in type_defs(document_schemas) {
+        let _ = write!(
+            code,
+            "\n#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n\
+             #[serde(rename_all = \"camelCase\")]\n\
+             pub struct {} {{\n",
+            type_def.name
+        );
+        for field in &type_def.fields {
+            let field_name = snake_case(field.json_name.trim_start_matches('$'));
+            if field.json_name.starts_with('$') || camel_case(&field_name) != field.json_name {
+                let _ = writeln!(code, "    #[serde(rename = \"{}\")]", field.json_name);
+            }
+            let rust_type = rust_type(&field.kind);
+            let _ = if field.required {
+                writeln!(
+                    code,
+                    "    pub {}: {},",
+                    rust_field_name(&field_name),
+                    rust_type
+                )
+            } else {
+                writeln!(
+                    code,
+                    "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub {}: Option<{}>,",
+                    rust_field_name(&field_name),
+                    rust_type
+                )
+            };
+        }
+        code.push_str("}\n");
+    }
+    code
+}
+
+/// TypeScript interfaces for the document types of a contract
+pub fn generate_typescript(contract_name: &str, document_schemas: &Map<String, Value>) -> String {
+    let mut code = format!(
+        "// Document types of the {} contract, generated by Dash Evo Tool.\n",
+        contract_name
+    );
+    for type_def in type_defs(document_schemas) {
+        let _ = write!(code, "\nexport interface {} {{\n", type_def.name);
+        for field in &type_def.fields {
+            let name = if field
+                .json_name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+            {
+                field.json_name.clone()
+            } else {
+                format!("\"{}\"", field.json_name)
+            };
+            let _ = writeln!(
+                code,
+                "  {}{}: {};",
+                name,
+                if field.required { "" } else { "?" },
+                typescript_type(&field.kind)
+            );
+        }
+        code.push_str("}\n");
+    }
+    code
+}
+
+fn type_defs(document_schemas: &Map<String, Value>) -> Vec<TypeDef> {
+    let mut type_defs = Vec::new();
+    for (document_type, schema) in document_schemas {
+        let name = pascal_case(document_type);
+        let required = required_fields(schema);
+        let mut fields: Vec<FieldDef> = SYSTEM_FIELDS
+            .iter()
+            .map(|json_name| FieldDef {
+                json_name: json_name.to_string(),
+                kind: match *json_name {
+                    "$id" | "$ownerId" => FieldKind::String,
+                    _ => FieldKind::Integer,
+                },
+                required: matches!(*json_name, "$id" | "$ownerId")
+                    || required.iter().any(|field| field == json_name),
+            })
+            .collect();
+        fields.extend(object_fields(&name, schema, &mut type_defs));
+        type_defs.push(TypeDef { name, fields });
+    }
+    type_defs.sort_by(|a, b| a.name.cmp(&b.name));
+    type_defs
+}
+
+fn required_fields(schema: &Value) -> Vec<String> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| {
+            required
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The properties of an object schema ordered by their `position`, nested types are added to
+/// `type_defs`
+fn object_fields(type_name: &str, schema: &Value, type_defs: &mut Vec<TypeDef>) -> Vec<FieldDef> {
+    let required = required_fields(schema);
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    let mut properties: Vec<(&String, &Value)> = properties.iter().collect();
+    properties.sort_by_key(|(_, property)| {
+        property
+            .get("position")
+            .and_then(Value::as_u64)
+            .unwrap_or(u64::MAX)
+    });
+    properties
+        .into_iter()
+        .map(|(json_name, property)| FieldDef {
+            json_name: json_name.clone(),
+            kind: field_kind(
+                &format!("{}{}", type_name, pascal_case(json_name)),
+                property,
+                type_defs,
+            ),
+            required: required.contains(json_name),
+        })
+        .collect()
+}
+
+fn field_kind(type_name: &str, schema: &Value, type_defs: &mut Vec<TypeDef>) -> FieldKind {
+    match schema.get("type").and_then(Value::as_str) {
+        Some("string") => FieldKind::String,
+        Some("integer") => FieldKind::Integer,
+        Some("number") => FieldKind::Number,
+        Some("boolean") => FieldKind::Boolean,
+        Some("array") if schema.get("byteArray").and_then(Value::as_bool) == Some(true) => {
+            FieldKind::Bytes
+        }
+        Some("array") => match schema.get("items") {
+            Some(items) => FieldKind::Array(Box::new(field_kind(type_name, items, type_defs))),
+            None => FieldKind::Array(Box::new(FieldKind::Any)),
+        },
+        Some("object") if schema.get("properties").is_some() => {
+            let fields = object_fields(type_name, schema, type_defs);
+            type_defs.push(TypeDef {
+                name: type_name.to_string(),
+                fields,
+            });
+            FieldKind::Object(type_name.to_string())
+        }
+        _ => FieldKind::Any,
+    }
+}
+
+fn rust_type(kind: &FieldKind) -> String {
+    match kind {
+        FieldKind::String => "String".to_string(),
+        FieldKind::Integer => "i64".to_string(),
+        FieldKind::Number => "f64".to_string(),
+        FieldKind::Boolean => "bool".to_string(),
+        FieldKind::Bytes => "Vec<u8>".to_string(),
+        FieldKind::Object(name) => name.clone(),
+        FieldKind::Array(items) => format!("Vec<{}>", rust_type(items)),
+        FieldKind::Any => "serde_json::Value".to_string(),
+    }
+}
+
+fn typescript_type(kind: &FieldKind) -> String {
+    match kind {
+        FieldKind::String => "string".to_string(),
+        FieldKind::Integer | FieldKind::Number => "number".to_string(),
+        FieldKind::Boolean => "boolean".to_string(),
+        FieldKind::Bytes => "Uint8Array".to_string(),
+        FieldKind::Object(name) => name.clone(),
+        FieldKind::Array(items) => format!("{}[]", typescript_type(items)),
+        FieldKind::Any => "unknown".to_string(),
+    }
+}
+
+/// Splits a name at underscores, dashes and lowercase to uppercase changes
+fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lowercase = false;
+    for c in name.chars() {
+        if !c.is_ascii_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            previous_lowercase = false;
+            continue;
+        }
+        if c.is_ascii_uppercase() && previous_lowercase && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        previous_lowercase = c.is_ascii_lowercase() || c.is_ascii_digit();
+        current.push(c.to_ascii_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+fn pascal_case(name: &str) -> String {
+    let name: String = words(name).iter().map(|word| capitalize(word)).collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) || name.is_empty() {
+        format!("Type{}", name)
+    } else {
+        name
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    words(name).join("_")
+}
+
+/// The camelCase name serde derives from a snake_case field name
+fn camel_case(snake_name: &str) -> String {
+    let mut words = snake_name.split('_');
+    let first = words.next().unwrap_or_default().to_string();
+    first + &words.map(capitalize).collect::<String>()
+}
+
+/// Keeps field names that are Rust keywords valid
+fn rust_field_name(name: &str) -> String {
+    const KEYWORDS: [&str; 12] = [
+        "type", "ref", "self", "match", "move", "mod", "use", "impl", "fn", "struct", "enum",
+        "where",
+    ];
+    if KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("field_{}", name)
+    } else {
+        name.to_string()
+    }
+}
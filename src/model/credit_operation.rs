@@ -0,0 +1,246 @@
+use crate::model::identity_csv::escape_csv;
+use chrono::{DateTime, NaiveDate, Utc};
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+/// Credits per DASH, one duff is 1000 credits
+const CREDITS_PER_DASH: f64 = 1e11;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreditOperationKind {
+    /// Credits an identity was created with from an asset lock
+    Registration,
+    TopUp,
+    /// Credits withdrawn to a Core address
+    Withdrawal,
+    /// Only a processing fee, for transitions that move no credits such as key updates
+    Fee,
+    /// Credits paid to another identity for a document it sold
+    DocumentPurchase,
+    /// Credits sent to another identity
+    Transfer,
+}
+
+impl CreditOperationKind {
+    pub const ALL: [CreditOperationKind; 6] = [
+        CreditOperationKind::Registration,
+        CreditOperationKind::TopUp,
+        CreditOperationKind::Withdrawal,
+        CreditOperationKind::Fee,
+        CreditOperationKind::DocumentPurchase,
+        CreditOperationKind::Transfer,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CreditOperationKind::Registration => "Registration",
+            CreditOperationKind::TopUp => "Top up",
+            CreditOperationKind::Withdrawal => "Withdrawal",
+            CreditOperationKind::Fee => "Fee",
+            CreditOperationKind::DocumentPurchase => "Document purchase",
+            CreditOperationKind::Transfer => "Transfer",
+        }
+    }
+
+    /// Name stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CreditOperationKind::Registration => "registration",
+            CreditOperationKind::TopUp => "top_up",
+            CreditOperationKind::Withdrawal => "withdrawal",
+            CreditOperationKind::Fee => "fee",
+            CreditOperationKind::DocumentPurchase => "document_purchase",
+            CreditOperationKind::Transfer => "transfer",
+        }
+    }
+
+    pub fn from_str(kind: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == kind)
+    }
+
+    /// Whether the credits came into the identity's balance
+    pub fn is_incoming(&self) -> bool {
+        matches!(
+            self,
+            CreditOperationKind::Registration | CreditOperationKind::TopUp
+        )
+    }
+}
+
+/// An operation that changed an identity's credit balance, recorded when the tool ran it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreditOperation {
+    pub identity_id: Identifier,
+    pub kind: CreditOperationKind,
+    /// Credits added to or taken from the balance, not counting the fee
+    pub credits: Credits,
+    /// Processing fee paid from the balance or the asset lock
+    pub fee: Credits,
+    /// What the operation was, e.g. the withdrawal address or the registered name
+    pub description: String,
+    /// Seconds since the Unix epoch
+    pub occurred_at: i64,
+}
+
+impl CreditOperation {
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.occurred_at, 0).unwrap_or_default()
+    }
+}
+
+/// Where the fiat value of DASH on the day of an operation comes from
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum PriceSource {
+    /// Exports without fiat values
+    #[default]
+    None,
+    /// One price for every operation
+    Fixed { price: f64 },
+    /// A CSV file with a `YYYY-MM-DD,price` line per day, as most price sites export them
+    DailyFile { path: String },
+}
+
+impl PriceSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PriceSource::None => "No fiat values",
+            PriceSource::Fixed { .. } => "Fixed price",
+            PriceSource::DailyFile { .. } => "Daily prices from a CSV file",
+        }
+    }
+}
+
+/// Settings of the accounting export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountingExport {
+    /// Code of the fiat currency the prices are in, e.g. USD
+    pub currency: String,
+    pub price_source: PriceSource,
+}
+
+impl Default for AccountingExport {
+    fn default() -> Self {
+        Self {
+            currency: "USD".to_string(),
+            price_source: PriceSource::None,
+        }
+    }
+}
+
+/// Prices of DASH by day
+#[derive(Debug, Clone, Default)]
+pub struct DailyPrices(BTreeMap<NaiveDate, f64>);
+
+impl DailyPrices {
+    /// Loads the prices the source gives, nothing for [`PriceSource::None`] and
+    /// [`PriceSource::Fixed`]
+    pub fn load(source: &PriceSource) -> Result<Self, String> {
+        let PriceSource::DailyFile { path } = source else {
+            return Ok(Self::default());
+        };
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read the price file {}: {}", path, e))?;
+        Self::parse(&contents)
+    }
+
+    /// Reads `date,price` lines, skipping a header and any further columns
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut prices = BTreeMap::new();
+        for (index, line) in contents.lines().enumerate() {
+            let mut fields = line.split([',', ';', '\t']).map(str::trim);
+            let (Some(date), Some(price)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            // Dates may carry a time, only the day counts
+            let date = date.get(..10).unwrap_or(date);
+            match (
+                NaiveDate::parse_from_str(date, "%Y-%m-%d"),
+                price.trim_matches('"').parse::<f64>(),
+            ) {
+                (Ok(date), Ok(price)) => {
+                    prices.insert(date, price);
+                }
+                _ if index == 0 => continue,
+                _ => {
+                    return Err(format!(
+                        "Line {} of the price file is not a date and a price",
+                        index + 1
+                    ))
+                }
+            }
+        }
+        if prices.is_empty() {
+            return Err("The price file holds no prices".to_string());
+        }
+        Ok(Self(prices))
+    }
+
+    /// The price of the day, or of the closest day before it when the day is missing
+    pub fn price_on(&self, date: NaiveDate) -> Option<f64> {
+        self.0.range(..=date).next_back().map(|(_, price)| *price)
+    }
+}
+
+/// Price of DASH at the time of the operation, if the source has one
+pub fn price_at(
+    source: &PriceSource,
+    daily_prices: &DailyPrices,
+    operation: &CreditOperation,
+) -> Option<f64> {
+    match source {
+        PriceSource::None => None,
+        PriceSource::Fixed { price } => Some(*price),
+        PriceSource::DailyFile { .. } => {
+            daily_prices.price_on(operation.occurred_at().date_naive())
+        }
+    }
+}
+
+/// Renders the operations as CSV for accountants, one line per operation. Amounts are signed,
+/// positive when credits came in. Fiat columns are empty when no price is known.
+pub fn operations_to_csv(
+    operations: &[CreditOperation],
+    identity_name: impl Fn(&Identifier) -> String,
+    settings: &AccountingExport,
+    daily_prices: &DailyPrices,
+) -> String {
+    let currency = settings.currency.trim().to_lowercase();
+    let mut csv = format!(
+        "date_utc,identity,identity_id,operation,description,amount_credits,amount_dash,\
+         fee_credits,fee_dash,dash_price_{0},amount_{0},fee_{0}\n",
+        currency
+    );
+    for operation in operations {
+        let sign = if operation.kind.is_incoming() { 1 } else { -1 };
+        let amount_dash = sign as f64 * operation.credits as f64 / CREDITS_PER_DASH;
+        let fee_dash = operation.fee as f64 / CREDITS_PER_DASH;
+        let price = price_at(&settings.price_source, daily_prices, operation);
+        let fiat = |dash: f64| {
+            price
+                .map(|price| format!("{:.2}", dash * price))
+                .unwrap_or_default()
+        };
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{},{},{:.11},{},{:.11},{},{},{}",
+            operation.occurred_at().format("%Y-%m-%d %H:%M:%S"),
+            escape_csv(&identity_name(&operation.identity_id)),
+            operation.identity_id.to_string(Encoding::Base58),
+            operation.kind.label(),
+            escape_csv(&operation.description),
+            sign * operation.credits as i128,
+            amount_dash,
+            operation.fee,
+            fee_dash,
+            price.map(|price| price.to_string()).unwrap_or_default(),
+            fiat(amount_dash),
+            fiat(fee_dash)
+        );
+    }
+    csv
+}
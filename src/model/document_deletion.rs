@@ -0,0 +1,132 @@
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::platform_value::Value;
+use dash_sdk::drive::query::{WhereClause, WhereOperator};
+use dash_sdk::platform::Identifier;
+use serde_json::Value as JsonValue;
+
+/// Most documents selected for deletion in one go
+pub const MAX_DELETION_DOCUMENTS: usize = 1000;
+
+/// Processing fee of a transition deleting one document. Platform only knows the real fee once
+/// it ran the transition, the storage refund of the deleted document is not counted.
+pub const ESTIMATED_DELETION_FEE: Credits = 2_000_000;
+
+/// System fields documents can be selected by, besides the properties of their type
+pub const SYSTEM_FIELDS: [&str; 4] = ["$id", "$ownerId", "$createdAt", "$updatedAt"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionOperator {
+    Equal,
+    GreaterThan,
+    GreaterThanOrEquals,
+    LessThan,
+    LessThanOrEquals,
+    StartsWith,
+}
+
+impl ConditionOperator {
+    pub const ALL: [ConditionOperator; 6] = [
+        ConditionOperator::Equal,
+        ConditionOperator::GreaterThan,
+        ConditionOperator::GreaterThanOrEquals,
+        ConditionOperator::LessThan,
+        ConditionOperator::LessThanOrEquals,
+        ConditionOperator::StartsWith,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConditionOperator::Equal => "=",
+            ConditionOperator::GreaterThan => ">",
+            ConditionOperator::GreaterThanOrEquals => ">=",
+            ConditionOperator::LessThan => "<",
+            ConditionOperator::LessThanOrEquals => "<=",
+            ConditionOperator::StartsWith => "starts with",
+        }
+    }
+
+    fn where_operator(&self) -> WhereOperator {
+        match self {
+            ConditionOperator::Equal => WhereOperator::Equal,
+            ConditionOperator::GreaterThan => WhereOperator::GreaterThan,
+            ConditionOperator::GreaterThanOrEquals => WhereOperator::GreaterThanOrEquals,
+            ConditionOperator::LessThan => WhereOperator::LessThan,
+            ConditionOperator::LessThanOrEquals => WhereOperator::LessThanOrEquals,
+            ConditionOperator::StartsWith => WhereOperator::StartsWith,
+        }
+    }
+}
+
+/// A condition of the query selecting documents, as entered
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectionCondition {
+    pub field: String,
+    pub operator: ConditionOperator,
+    pub value: String,
+}
+
+impl SelectionCondition {
+    /// The condition as a where clause, with the value typed by the schema of the document type
+    pub fn where_clause(&self, schema: &JsonValue) -> Result<WhereClause, String> {
+        let text = self.value.trim();
+        let invalid = |kind: &str| format!("{} needs {}, got \"{}\"", self.field, kind, text);
+        let value = match self.field.as_str() {
+            "$id" | "$ownerId" => {
+                Value::Identifier(parse_identifier(text).ok_or(invalid("an identifier"))?)
+            }
+            "$createdAt" | "$updatedAt" => Value::U64(
+                text.parse()
+                    .map_err(|_| invalid("a time in milliseconds"))?,
+            ),
+            field => {
+                let property = schema
+                    .get("properties")
+                    .and_then(|properties| properties.get(field))
+                    .ok_or_else(|| format!("The document type has no property {}", field))?;
+                match property.get("type").and_then(JsonValue::as_str) {
+                    Some("integer") => Value::I64(text.parse().map_err(|_| invalid("an integer"))?),
+                    Some("number") => Value::Float(text.parse().map_err(|_| invalid("a number"))?),
+                    Some("boolean") => {
+                        Value::Bool(text.parse().map_err(|_| invalid("true or false"))?)
+                    }
+                    Some("array") if property.get("byteArray") == Some(&JsonValue::Bool(true)) => {
+                        if property.get("contentMediaType").and_then(JsonValue::as_str)
+                            == Some("application/x.dash.dpp.identifier")
+                        {
+                            Value::Identifier(
+                                parse_identifier(text).ok_or(invalid("an identifier"))?,
+                            )
+                        } else {
+                            Value::Bytes(hex::decode(text).map_err(|_| invalid("hex bytes"))?)
+                        }
+                    }
+                    _ => Value::Text(text.to_string()),
+                }
+            }
+        };
+        if self.operator == ConditionOperator::StartsWith && !matches!(value, Value::Text(_)) {
+            return Err(format!(
+                "{} is not text, it can't be matched by its start",
+                self.field
+            ));
+        }
+        Ok(WhereClause {
+            field: self.field.clone(),
+            operator: self.operator.where_operator(),
+            value,
+        })
+    }
+}
+
+fn parse_identifier(text: &str) -> Option<[u8; 32]> {
+    Identifier::from_string(text, Encoding::Base58)
+        .or_else(|_| Identifier::from_string(text, Encoding::Hex))
+        .ok()
+        .map(|identifier| identifier.to_buffer())
+}
+
+/// Fee estimated for deleting the documents, one transition each
+pub fn estimate_deletion_fee(document_count: usize) -> Credits {
+    ESTIMATED_DELETION_FEE * document_count as Credits
+}
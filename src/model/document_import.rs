@@ -0,0 +1,137 @@
+use crate::model::identity_csv::split_csv_line;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Most documents imported in one go
+pub const MAX_IMPORT_DOCUMENTS: usize = 5000;
+
+/// One record of an import file, with the value of each of its columns
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRecord {
+    /// Line of a CSV file or position in a JSON array, starting at 1
+    pub number: usize,
+    pub fields: BTreeMap<String, Value>,
+}
+
+/// Reads a JSON array of objects, or a CSV file with a header line. Returns the columns in the
+/// order they first appear and the records.
+pub fn parse_import_file(contents: &str) -> Result<(Vec<String>, Vec<ImportRecord>), String> {
+    let (columns, records) = if contents.trim_start().starts_with('[') {
+        parse_json_array(contents)?
+    } else {
+        parse_csv(contents)?
+    };
+    if records.is_empty() {
+        return Err("The file holds no records".to_string());
+    }
+    if records.len() > MAX_IMPORT_DOCUMENTS {
+        return Err(format!(
+            "The file holds {} records, at most {} can be imported at once",
+            records.len(),
+            MAX_IMPORT_DOCUMENTS
+        ));
+    }
+    Ok((columns, records))
+}
+
+fn parse_json_array(contents: &str) -> Result<(Vec<String>, Vec<ImportRecord>), String> {
+    let items: Vec<Value> =
+        serde_json::from_str(contents).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let mut columns: Vec<String> = Vec::new();
+    let mut records = Vec::with_capacity(items.len());
+    for (i, item) in items.into_iter().enumerate() {
+        let Value::Object(object) = item else {
+            return Err(format!("Item {} of the array is not an object", i + 1));
+        };
+        for key in object.keys() {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+        records.push(ImportRecord {
+            number: i + 1,
+            fields: object.into_iter().collect(),
+        });
+    }
+    Ok((columns, records))
+}
+
+fn parse_csv(contents: &str) -> Result<(Vec<String>, Vec<ImportRecord>), String> {
+    let mut lines = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines.next().ok_or("The file is empty")?;
+    let columns: Vec<String> = split_csv_line(header)
+        .into_iter()
+        .map(|column| column.trim().to_string())
+        .collect();
+    if columns.iter().any(String::is_empty) {
+        return Err("The header line has an empty column name".to_string());
+    }
+    let records = lines
+        .map(|(i, line)| ImportRecord {
+            number: i + 1,
+            fields: columns
+                .iter()
+                .cloned()
+                .zip(split_csv_line(line))
+                .filter(|(_, value)| !value.trim().is_empty())
+                .map(|(column, value)| (column, Value::String(value.trim().to_string())))
+                .collect(),
+        })
+        .collect();
+    Ok((columns, records))
+}
+
+/// Maps each property to the column of the same name, ignoring case, `-` and `_`
+pub fn auto_map(properties: &[String], columns: &[String]) -> Vec<(String, Option<String>)> {
+    let simplify = |name: &str| name.to_lowercase().replace(['_', '-'], "");
+    properties
+        .iter()
+        .map(|property| {
+            let column = columns
+                .iter()
+                .find(|column| simplify(column) == simplify(property))
+                .cloned();
+            (property.clone(), column)
+        })
+        .collect()
+}
+
+/// The record as input of a document form, see `JsonSchemaForm::restore_inputs`. Numbers are
+/// turned into the text the form expects.
+pub fn form_inputs(record: &ImportRecord, mapping: &[(String, Option<String>)]) -> Value {
+    Value::Object(
+        mapping
+            .iter()
+            .filter_map(|(property, column)| {
+                let value = record.fields.get(column.as_ref()?)?;
+                Some((property.clone(), as_form_input(value)))
+            })
+            .collect(),
+    )
+}
+
+fn as_form_input(value: &Value) -> Value {
+    match value {
+        Value::Number(number) => Value::String(number.to_string()),
+        Value::Array(items) => Value::Array(items.iter().map(as_form_input).collect()),
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| (key.clone(), as_form_input(value)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// A CSV of the records that were not imported, with the reason, to fix and import again
+pub fn failure_report(failures: &[(usize, String)]) -> String {
+    let mut report = String::from("record,error\n");
+    for (number, error) in failures {
+        report.push_str(&format!("{},\"{}\"\n", number, error.replace('"', "\"\"")));
+    }
+    report
+}
@@ -0,0 +1,112 @@
+use dash_sdk::dpp::document::{Document, DocumentV0Getters};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::prelude::{Identifier, Revision};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A copy of a document as it was fetched, kept so later revisions can be compared with it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentRevision {
+    pub document_id: Identifier,
+    pub revision: Revision,
+    pub json: Value,
+    /// Unix time in seconds
+    pub fetched_at: i64,
+}
+
+/// The document's system fields, prefixed with `$` like in contracts, and its properties
+pub fn document_to_json(document: &Document) -> Value {
+    let mut json = serde_json::Map::new();
+    json.insert(
+        "$id".to_string(),
+        Value::String(document.id().to_string(Encoding::Base58)),
+    );
+    json.insert(
+        "$ownerId".to_string(),
+        Value::String(document.owner_id().to_string(Encoding::Base58)),
+    );
+    if let Some(revision) = document.revision() {
+        json.insert("$revision".to_string(), revision.into());
+    }
+    if let Some(created_at) = document.created_at() {
+        json.insert("$createdAt".to_string(), created_at.into());
+    }
+    if let Some(updated_at) = document.updated_at() {
+        json.insert("$updatedAt".to_string(), updated_at.into());
+    }
+    for (name, value) in document.properties() {
+        json.insert(
+            name.clone(),
+            serde_json::to_value(value).unwrap_or(Value::Null),
+        );
+    }
+    Value::Object(json)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Unchanged,
+    Added,
+    Removed,
+    Changed,
+}
+
+/// One leaf of two documents compared side by side
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffRow {
+    /// JSON path of the leaf, e.g. `$.label` or `$.records[0]`
+    pub path: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+    pub kind: DiffKind,
+}
+
+/// Compares two JSON values leaf by leaf, ordered by path
+pub fn diff_json(old: &Value, new: &Value) -> Vec<DiffRow> {
+    let mut old_leaves = BTreeMap::new();
+    flatten("$", old, &mut old_leaves);
+    let mut new_leaves = BTreeMap::new();
+    flatten("$", new, &mut new_leaves);
+
+    let mut paths: Vec<&String> = old_leaves.keys().chain(new_leaves.keys()).collect();
+    paths.sort();
+    paths.dedup();
+    paths
+        .into_iter()
+        .map(|path| {
+            let old = old_leaves.get(path).cloned();
+            let new = new_leaves.get(path).cloned();
+            let kind = match (&old, &new) {
+                (Some(old), Some(new)) if old == new => DiffKind::Unchanged,
+                (Some(_), Some(_)) => DiffKind::Changed,
+                (None, _) => DiffKind::Added,
+                (_, None) => DiffKind::Removed,
+            };
+            DiffRow {
+                path: path.clone(),
+                old,
+                new,
+                kind,
+            }
+        })
+        .collect()
+}
+
+/// Collects the leaves of a value by path, empty objects and arrays count as leaves
+fn flatten(path: &str, value: &Value, leaves: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                flatten(&format!("{}.{}", path, key), child, leaves);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (i, item) in items.iter().enumerate() {
+                flatten(&format!("{}[{}]", path, i), item, leaves);
+            }
+        }
+        leaf => {
+            leaves.insert(path.to_string(), leaf.to_string());
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::model::document_revision::{diff_json, DiffKind, DiffRow};
+use dash_sdk::dpp::prelude::{Identifier, Revision};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Most documents a snapshot holds, larger document types are cut off
+pub const MAX_SNAPSHOT_DOCUMENTS: usize = 10_000;
+
+/// All documents of a type of a contract as they were at one time
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentSnapshot {
+    pub id: i64,
+    pub contract_id: Identifier,
+    pub document_type: String,
+    /// Given by the user, e.g. "before migration"
+    pub label: String,
+    /// Unix time in seconds
+    pub taken_at: i64,
+    pub document_count: usize,
+    /// Whether the document type had more than `MAX_SNAPSHOT_DOCUMENTS` documents
+    pub truncated: bool,
+}
+
+/// A document as it was stored in a snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotDocument {
+    /// Base58 document id
+    pub document_id: String,
+    pub revision: Revision,
+    pub json: Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotChange {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl SnapshotChange {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SnapshotChange::Created => "Created",
+            SnapshotChange::Updated => "Updated",
+            SnapshotChange::Deleted => "Deleted",
+        }
+    }
+}
+
+/// A document that differs between two snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotDifference {
+    pub document_id: String,
+    pub change: SnapshotChange,
+    pub old_revision: Option<Revision>,
+    pub new_revision: Option<Revision>,
+    /// The changed leaves of an updated document
+    pub rows: Vec<DiffRow>,
+}
+
+/// The documents created, updated and deleted from the old snapshot to the new one, by
+/// document id
+pub fn compare_snapshots(
+    old: &[SnapshotDocument],
+    new: &[SnapshotDocument],
+) -> Vec<SnapshotDifference> {
+    let old: BTreeMap<&str, &SnapshotDocument> = old
+        .iter()
+        .map(|document| (document.document_id.as_str(), document))
+        .collect();
+    let new: BTreeMap<&str, &SnapshotDocument> = new
+        .iter()
+        .map(|document| (document.document_id.as_str(), document))
+        .collect();
+    let mut ids: Vec<&str> = old.keys().chain(new.keys()).copied().collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids.into_iter()
+        .filter_map(|id| {
+            let (old, new) = (old.get(id), new.get(id));
+            let (change, rows) = match (old, new) {
+                (None, Some(_)) => (SnapshotChange::Created, Vec::new()),
+                (Some(_), None) => (SnapshotChange::Deleted, Vec::new()),
+                (Some(old), Some(new)) => {
+                    let rows: Vec<DiffRow> = diff_json(&old.json, &new.json)
+                        .into_iter()
+                        .filter(|row| row.kind != DiffKind::Unchanged)
+                        .collect();
+                    if rows.is_empty() {
+                        return None;
+                    }
+                    (SnapshotChange::Updated, rows)
+                }
+                (None, None) => return None,
+            };
+            Some(SnapshotDifference {
+                document_id: id.to_string(),
+                change,
+                old_revision: old.map(|document| document.revision),
+                new_revision: new.map(|document| document.revision),
+                rows,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,30 @@
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::platform::Document;
+
+/// System property holding the price a document is listed for
+pub const PRICE_PROPERTY: &str = "$price";
+
+/// Processing fee of a price update or purchase transition, estimated
+pub const ESTIMATED_TRADE_FEE: Credits = 5_000_000;
+
+/// The price the document is listed for, `None` while it is not for sale
+pub fn listed_price(document: &Document) -> Option<Credits> {
+    document
+        .properties()
+        .get(PRICE_PROPERTY)
+        .and_then(|price| price.to_integer::<Credits>().ok())
+        .filter(|price| *price > 0)
+}
+
+/// Checks that a buyer with the balance can pay the price and the fee
+pub fn check_purchase_balance(balance: Credits, price: Credits) -> Result<(), String> {
+    let needed = price.saturating_add(ESTIMATED_TRADE_FEE);
+    if balance < needed {
+        return Err(format!(
+            "The balance of {} credits doesn't cover the price of {} credits and the fee",
+            balance, price
+        ));
+    }
+    Ok(())
+}
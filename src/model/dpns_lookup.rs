@@ -0,0 +1,110 @@
+use dash_sdk::dpp::util::strings::convert_to_homograph_safe_chars;
+use dash_sdk::platform::Identifier;
+
+/// Most names a search returns
+pub const DPNS_SEARCH_LIMIT: u32 = 100;
+
+/// A name registered under the `dash` domain
+#[derive(Debug, Clone, PartialEq)]
+pub struct DpnsNameRecord {
+    pub label: String,
+    pub normalized_label: String,
+    /// The identity the name resolves to
+    pub identity_id: Identifier,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NameAvailability {
+    Available,
+    /// Free, but short names with only letters, 0, 1 and hyphens go to a masternode vote first
+    AvailableContested,
+    Taken(Identifier),
+    /// Someone already applied for it and the vote is not over
+    InContest,
+    /// Masternodes voted to lock the name
+    Locked,
+    Invalid(&'static str),
+}
+
+impl NameAvailability {
+    pub fn label(&self) -> String {
+        match self {
+            NameAvailability::Available => "Available".to_string(),
+            NameAvailability::AvailableContested => "Available, goes to a vote".to_string(),
+            NameAvailability::Taken(identity_id) => format!("Taken by {}", identity_id),
+            NameAvailability::InContest => "In a contest".to_string(),
+            NameAvailability::Locked => "Locked".to_string(),
+            NameAvailability::Invalid(reason) => format!("Invalid: {}", reason),
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        matches!(
+            self,
+            NameAvailability::Available | NameAvailability::AvailableContested
+        )
+    }
+}
+
+/// The availability of one candidate name
+#[derive(Debug, Clone, PartialEq)]
+pub struct NameCheck {
+    pub name: String,
+    pub normalized_label: String,
+    pub availability: NameAvailability,
+}
+
+/// Checks a label against the rules of the DPNS contract
+pub fn validate_label(label: &str) -> Result<(), &'static str> {
+    if label.len() < 3 || label.len() > 63 {
+        return Err("names have 3 to 63 characters");
+    }
+    if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err("only letters, digits and hyphens are allowed");
+    }
+    if label.starts_with('-') || label.ends_with('-') {
+        return Err("names can not start or end with a hyphen");
+    }
+    Ok(())
+}
+
+/// Whether registering the label starts a masternode vote instead of registering it directly
+pub fn is_contested_label(normalized_label: &str) -> bool {
+    normalized_label.len() < 20
+        && normalized_label
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c == '0' || c == '1' || c == '-')
+}
+
+/// Splits a search pattern into the normalized prefix Platform can search for and the whole
+/// normalized pattern, in which `*` matches any characters
+pub fn split_search_pattern(pattern: &str) -> Result<(String, String), &'static str> {
+    let pattern = convert_to_homograph_safe_chars(pattern.trim().trim_end_matches(".dash"));
+    let prefix = pattern.split('*').next().unwrap_or_default().to_string();
+    if prefix.is_empty() {
+        return Err("The pattern has to start with at least one character before any *");
+    }
+    Ok((prefix, pattern))
+}
+
+/// Whether a normalized label matches a normalized pattern where `*` matches any characters
+pub fn matches_pattern(label: &str, pattern: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return true;
+    };
+    let Some(mut rest) = label.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+
+/// How long a DAPI node that answered with a bad proof is avoided
+pub const FLAG_DURATION_MINUTES: i64 = 30;
+
+/// How many other DAPI nodes a query is retried on after a proof failed to verify
+pub const MAX_PROOF_RETRIES: usize = 3;
+
+/// Whether an error means the response came with a proof that did not verify, so the node that
+/// answered can't be trusted rather than the request being wrong
+pub fn is_proof_error(error: &str) -> bool {
+    let error = error.to_lowercase();
+    [
+        "proof verification",
+        "invalid proof",
+        "proof error",
+        "invalid quorum",
+        "quorum not found",
+        "root hash",
+        "grovedb",
+    ]
+    .iter()
+    .any(|pattern| error.contains(pattern))
+}
+
+/// Whether two DAPI addresses name the same node, ignoring case and a trailing slash
+pub fn same_address(a: &str, b: &str) -> bool {
+    let normalize = |address: &str| address.trim().trim_end_matches('/').to_lowercase();
+    normalize(a) == normalize(b)
+}
+
+/// A DAPI node that answered with a proof that did not verify
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointFlag {
+    pub address: String,
+    /// The verification error of the last bad response
+    pub reason: String,
+    pub first_flagged_at: DateTime<Utc>,
+    pub last_flagged_at: DateTime<Utc>,
+    /// Bad responses since the node was first flagged
+    pub failures: u32,
+}
+
+impl EndpointFlag {
+    /// Whether the node is still avoided
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        now.signed_duration_since(self.last_flagged_at)
+            .num_minutes()
+            < FLAG_DURATION_MINUTES
+    }
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Placeholder for the txid in block explorer URL templates
+pub const TXID_PLACEHOLDER: &str = "{txid}";
+
+/// Explorer web sites that shared links and "Open in explorer" actions point to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExplorerSettings {
+    /// Base URL of the platform explorer keyed by network, e.g. "dash" or "testnet". Identities,
+    /// transitions and names are below it at `/identity/`, `/transaction/` and `/name/`.
+    pub platform_base_urls: BTreeMap<String, String>,
+    /// URL template of core chain transactions in the block explorer keyed by network, with
+    /// `{txid}` where the txid goes
+    pub block_explorer_urls: BTreeMap<String, String>,
+}
+
+impl Default for ExplorerSettings {
+    fn default() -> Self {
+        Self {
+            platform_base_urls: [
+                ("dash", "https://platform-explorer.com"),
+                ("testnet", "https://testnet.platform-explorer.com"),
+            ]
+            .into_iter()
+            .map(|(network, url)| (network.to_string(), url.to_string()))
+            .collect(),
+            block_explorer_urls: [
+                ("dash", "https://insight.dash.org/insight/tx/{txid}"),
+                (
+                    "testnet",
+                    "https://insight.testnet.networks.dash.org/insight/tx/{txid}",
+                ),
+            ]
+            .into_iter()
+            .map(|(network, url)| (network.to_string(), url.to_string()))
+            .collect(),
+        }
+    }
+}
+
+impl ExplorerSettings {
+    /// The platform explorer base URL of the network, `None` when not configured
+    pub fn platform_base_url(&self, network: &str) -> Option<&str> {
+        self.platform_base_urls
+            .get(network)
+            .map(|url| url.trim().trim_end_matches('/'))
+            .filter(|url| !url.is_empty())
+    }
+
+    pub fn contest_url(&self, network: &str, normalized_contested_name: &str) -> Option<String> {
+        self.platform_base_url(network)
+            .map(|base| format!("{}/name/{}", base, normalized_contested_name))
+    }
+
+    /// The identity page, the id in Base58
+    pub fn identity_url(&self, network: &str, identity_id: &str) -> Option<String> {
+        self.platform_base_url(network)
+            .map(|base| format!("{}/identity/{}", base, identity_id))
+    }
+
+    /// The state transition page, the hash in hex
+    pub fn transition_url(&self, network: &str, hash: &str) -> Option<String> {
+        self.platform_base_url(network)
+            .map(|base| format!("{}/transaction/{}", base, hash.to_uppercase()))
+    }
+
+    /// The address page of block explorers that keep addresses next to transactions, like
+    /// Insight does with `/tx/` and `/address/`
+    pub fn address_url(&self, network: &str, address: &str) -> Option<String> {
+        self.block_explorer_urls
+            .get(network)
+            .map(|template| template.trim())
+            .and_then(|template| template.strip_suffix(&format!("/tx/{}", TXID_PLACEHOLDER)))
+            .map(|base| format!("{}/address/{}", base, address))
+    }
+
+    /// The core chain transaction page, `None` when the template has no `{txid}`
+    pub fn transaction_url(&self, network: &str, txid: &str) -> Option<String> {
+        self.block_explorer_urls
+            .get(network)
+            .map(|template| template.trim())
+            .filter(|template| template.contains(TXID_PLACEHOLDER))
+            .map(|template| template.replace(TXID_PLACEHOLDER, txid))
+    }
+}
@@ -0,0 +1,92 @@
+use crate::model::fee::asset_lock_fee;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::dashcore::consensus::encode::deserialize;
+use dash_sdk::dpp::dashcore::psbt::PartiallySignedTransaction;
+use dash_sdk::dpp::dashcore::{OutPoint, Transaction};
+use std::str::FromStr;
+
+/// Fee rate of asset locks funded from an external wallet, in duffs per byte
+const FEE_PER_BYTE: u64 = 1;
+
+/// The same minimum fee the tool's own wallets pay for an asset lock
+const MIN_FEE: Duffs = 3_000;
+
+/// Fee of an asset lock spending the number of inputs
+pub fn estimate_asset_lock_fee(inputs: usize) -> Duffs {
+    asset_lock_fee(inputs, FEE_PER_BYTE).max(MIN_FEE)
+}
+
+/// Parses the outputs of the external wallet to spend, one `txid:vout` per line
+pub fn parse_outpoints(text: &str) -> Result<Vec<OutPoint>, String> {
+    let outpoints = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            OutPoint::from_str(line).map_err(|_| format!("{} isn't a txid:vout output", line))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if outpoints.is_empty() {
+        return Err("Enter at least one output of the external wallet to spend".to_string());
+    }
+    Ok(outpoints)
+}
+
+pub fn encode_psbt(psbt: &PartiallySignedTransaction) -> String {
+    STANDARD.encode(psbt.serialize())
+}
+
+pub fn decode_psbt(text: &str) -> Result<PartiallySignedTransaction, String> {
+    let bytes = STANDARD
+        .decode(text.trim())
+        .map_err(|e| format!("The PSBT isn't base64: {}", e))?;
+    PartiallySignedTransaction::deserialize(&bytes).map_err(|e| format!("Invalid PSBT: {}", e))
+}
+
+/// The signed transaction from what the external wallet returned: a finalized PSBT in base64,
+/// or the raw transaction in hex
+pub fn decode_signed_transaction(text: &str) -> Result<Transaction, String> {
+    let text = text.trim();
+    if let Ok(bytes) = hex::decode(text) {
+        return deserialize::<Transaction>(&bytes)
+            .map_err(|e| format!("Invalid transaction hex: {}", e));
+    }
+    let psbt = decode_psbt(text)?;
+    if psbt
+        .inputs
+        .iter()
+        .any(|input| input.final_script_sig.is_none())
+    {
+        return Err("The PSBT isn't finalized, sign all inputs and finalize it".to_string());
+    }
+    Ok(psbt.extract_tx())
+}
+
+/// Checks that the signed transaction spends the same outputs to the same outputs and payload
+/// as the exported one, and that every input is signed
+pub fn check_signed_transaction(
+    unsigned: &Transaction,
+    signed: &Transaction,
+) -> Result<(), String> {
+    let outpoints = |transaction: &Transaction| {
+        transaction
+            .input
+            .iter()
+            .map(|input| input.previous_output)
+            .collect::<Vec<_>>()
+    };
+    if outpoints(unsigned) != outpoints(signed) {
+        return Err("The signed transaction spends other outputs than the exported one".into());
+    }
+    if unsigned.output != signed.output
+        || unsigned.special_transaction_payload != signed.special_transaction_payload
+    {
+        return Err("The signed transaction pays elsewhere than the exported one".to_string());
+    }
+    if signed.input.iter().any(|input| input.script_sig.is_empty()) {
+        return Err("Not every input of the transaction is signed".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::dashcore::{Address, OutPoint, TxOut};
+use serde::{Deserialize, Serialize};
+
+/// Lowest fee rate Core relays, in duffs per byte
+pub const MIN_FEE_PER_BYTE: u64 = 1;
+
+/// Bytes of an asset lock besides its inputs: the outputs and the payload
+pub const ASSET_LOCK_BASE_BYTES: u64 = 200;
+
+/// Bytes of one signed P2PKH input
+pub const INPUT_BYTES: u64 = 150;
+
+/// Least change an asset lock keeps, its confirmation is followed on the change output
+pub const MIN_CHANGE: Duffs = 1_000;
+
+/// How soon a transaction should be mined, which sets its fee rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FeeSpeed {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+}
+
+impl FeeSpeed {
+    pub const ALL: [FeeSpeed; 3] = [FeeSpeed::Slow, FeeSpeed::Normal, FeeSpeed::Fast];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FeeSpeed::Slow => "Slow",
+            FeeSpeed::Normal => "Normal",
+            FeeSpeed::Fast => "Fast",
+        }
+    }
+
+    /// Blocks within which Core's estimate aims to get the transaction mined
+    pub fn confirmation_target(&self) -> u16 {
+        match self {
+            FeeSpeed::Slow => 24,
+            FeeSpeed::Normal => 6,
+            FeeSpeed::Fast => 2,
+        }
+    }
+}
+
+/// Which outputs of the wallet fund a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CoinSelection {
+    /// The largest outputs first, for the smallest transaction and fee
+    #[default]
+    MinimizeInputs,
+    /// The outputs closest to the amount, leaving as little change as possible
+    MinimizeChange,
+}
+
+impl CoinSelection {
+    pub const ALL: [CoinSelection; 2] =
+        [CoinSelection::MinimizeInputs, CoinSelection::MinimizeChange];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CoinSelection::MinimizeInputs => "Minimize inputs",
+            CoinSelection::MinimizeChange => "Minimize change",
+        }
+    }
+}
+
+/// The fee speed and coin selection a funding transaction is built with. Withdrawals are
+/// built by Platform, only their speed applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeeChoice {
+    pub speed: FeeSpeed,
+    pub coin_selection: CoinSelection,
+}
+
+/// The fee rate and coin selection an asset lock is built with, once the speed was turned
+/// into a rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FundingFee {
+    pub fee_per_byte: u64,
+    pub coin_selection: CoinSelection,
+}
+
+/// Fee rates estimated by Core for each speed, in duffs per byte
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeEstimates {
+    pub slow: u64,
+    pub normal: u64,
+    pub fast: u64,
+    /// Whether Core had enough data for the estimates, otherwise they are the relay minimum
+    pub estimated: bool,
+}
+
+impl Default for FeeEstimates {
+    fn default() -> Self {
+        Self {
+            slow: MIN_FEE_PER_BYTE,
+            normal: MIN_FEE_PER_BYTE,
+            fast: MIN_FEE_PER_BYTE,
+            estimated: false,
+        }
+    }
+}
+
+impl FeeEstimates {
+    pub fn rate(&self, speed: FeeSpeed) -> u64 {
+        match speed {
+            FeeSpeed::Slow => self.slow,
+            FeeSpeed::Normal => self.normal,
+            FeeSpeed::Fast => self.fast,
+        }
+    }
+}
+
+/// Core's estimate in DASH per kilobyte as duffs per byte, rounded up
+pub fn fee_per_byte_from_kilobyte(duffs_per_kilobyte: u64) -> u64 {
+    duffs_per_kilobyte.div_ceil(1000).max(MIN_FEE_PER_BYTE)
+}
+
+/// Fee of an asset lock spending the number of inputs at the rate
+pub fn asset_lock_fee(inputs: usize, fee_per_byte: u64) -> Duffs {
+    (ASSET_LOCK_BASE_BYTES + inputs as u64 * INPUT_BYTES) * fee_per_byte.max(MIN_FEE_PER_BYTE)
+}
+
+/// The fee rate of a withdrawal, which Platform only accepts as a Fibonacci number: the
+/// smallest one at or above the rate
+pub fn withdrawal_fee_per_byte(fee_per_byte: u64) -> u32 {
+    let (mut previous, mut current) = (1u32, 1u32);
+    while (current as u64) < fee_per_byte {
+        (previous, current) = (current, previous.saturating_add(current));
+    }
+    current
+}
+
+/// Outputs picked to fund an amount, with the fee they need and the change left
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoinSelectionResult {
+    pub inputs: Vec<(OutPoint, TxOut, Address)>,
+    pub fee: Duffs,
+    pub change: Duffs,
+}
+
+/// Picks the outputs paying `amount`, the asset lock fee at the rate and at least
+/// [MIN_CHANGE]. `None` when the outputs don't cover it.
+pub fn select_coins(
+    mut available: Vec<(OutPoint, TxOut, Address)>,
+    amount: Duffs,
+    fee_per_byte: u64,
+    strategy: CoinSelection,
+) -> Option<CoinSelectionResult> {
+    let needed = |inputs: usize| amount + asset_lock_fee(inputs, fee_per_byte) + MIN_CHANGE;
+    // Largest first, both strategies take from the top until the rest fits in one output
+    available.sort_by(|a, b| b.1.value.cmp(&a.1.value));
+    let mut inputs: Vec<(OutPoint, TxOut, Address)> = Vec::new();
+    let mut total = 0;
+    while total < needed(inputs.len()) {
+        if strategy == CoinSelection::MinimizeChange {
+            // The smallest single output that covers what is missing, if there is one
+            let missing = needed(inputs.len() + 1) - total;
+            if let Some(index) = available
+                .iter()
+                .rposition(|(_, tx_out, _)| tx_out.value >= missing)
+            {
+                let output = available.remove(index);
+                total += output.1.value;
+                inputs.push(output);
+                break;
+            }
+        }
+        if available.is_empty() {
+            return None;
+        }
+        let output = available.remove(0);
+        total += output.1.value;
+        inputs.push(output);
+    }
+    let fee = asset_lock_fee(inputs.len(), fee_per_byte);
+    Some(CoinSelectionResult {
+        change: total - amount - fee,
+        inputs,
+        fee,
+    })
+}
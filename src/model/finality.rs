@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// Most confirmations a policy can ask for
+pub const MAX_CONFIRMATIONS: u32 = 100;
+
+/// What Core knows about a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct L1State {
+    /// Blocks including and on top of the one the transaction was mined in
+    pub confirmations: u32,
+    pub instant_locked: bool,
+    pub chain_locked: bool,
+}
+
+/// When a Core transaction is treated as final. A chain locked transaction is always final,
+/// chain locks can't be reorganized away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FinalityPolicy {
+    /// Confirmations required, 0 accepts an InstantSend lock
+    pub confirmations: u32,
+    /// Whether only a chain lock makes the transaction final
+    pub require_chainlock: bool,
+}
+
+impl FinalityPolicy {
+    pub fn is_final(&self, state: &L1State) -> bool {
+        if state.chain_locked {
+            return true;
+        }
+        if self.require_chainlock {
+            return false;
+        }
+        if self.confirmations == 0 {
+            state.instant_locked || state.confirmations > 0
+        } else {
+            state.confirmations >= self.confirmations
+        }
+    }
+
+    /// What the policy waits for, e.g. "an InstantSend lock" or "3 confirmations"
+    pub fn describe(&self) -> String {
+        if self.require_chainlock {
+            "a chain lock".to_string()
+        } else if self.confirmations == 0 {
+            "an InstantSend lock".to_string()
+        } else if self.confirmations == 1 {
+            "1 confirmation or a chain lock".to_string()
+        } else {
+            format!("{} confirmations or a chain lock", self.confirmations)
+        }
+    }
+}
+
+/// How final asset locks and withdrawals must be before the tool treats them as done
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FinalitySettings {
+    /// Asset locks funding registrations and top ups, waited for before they are used
+    pub asset_locks: FinalityPolicy,
+    /// Asset unlock transactions paying out withdrawals
+    pub withdrawals: FinalityPolicy,
+}
+
+impl Default for FinalitySettings {
+    fn default() -> Self {
+        Self {
+            asset_locks: FinalityPolicy::default(),
+            withdrawals: FinalityPolicy {
+                confirmations: 1,
+                require_chainlock: true,
+            },
+        }
+    }
+}
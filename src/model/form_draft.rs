@@ -0,0 +1,11 @@
+/// The input of a long form, saved while it is filled in so it survives closing the screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormDraft {
+    pub id: i64,
+    /// Which form the draft belongs to, drafts are listed per form
+    pub form: String,
+    pub title: String,
+    /// The input in whatever JSON shape the form saves it
+    pub content: String,
+    pub saved_at: i64,
+}
@@ -0,0 +1,187 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Passed,
+    Warning,
+    Failed,
+}
+
+impl CheckStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CheckStatus::Passed => "OK",
+            CheckStatus::Warning => "Warning",
+            CheckStatus::Failed => "Failed",
+        }
+    }
+}
+
+/// Outcome of one startup check
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthCheck {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// What the user can do about a warning or failure
+    pub suggestion: Option<String>,
+}
+
+impl HealthCheck {
+    pub fn passed(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Passed,
+            detail: detail.into(),
+            suggestion: None,
+        }
+    }
+
+    pub fn warning(
+        name: &'static str,
+        detail: impl Into<String>,
+        suggestion: impl Into<String>,
+    ) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warning,
+            detail: detail.into(),
+            suggestion: Some(suggestion.into()),
+        }
+    }
+
+    pub fn failed(
+        name: &'static str,
+        detail: impl Into<String>,
+        suggestion: impl Into<String>,
+    ) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Failed,
+            detail: detail.into(),
+            suggestion: Some(suggestion.into()),
+        }
+    }
+}
+
+/// Results of the checks run on launch or from the diagnostics screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthReport {
+    pub network: String,
+    pub checked_at: DateTime<Utc>,
+    pub checks: Vec<HealthCheck>,
+}
+
+impl HealthReport {
+    /// The checks that did not pass
+    pub fn problems(&self) -> impl Iterator<Item = &HealthCheck> {
+        self.checks
+            .iter()
+            .filter(|check| check.status != CheckStatus::Passed)
+    }
+
+    pub fn has_problems(&self) -> bool {
+        self.problems().next().is_some()
+    }
+
+    /// The report as plain text, for pasting into bug reports
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "Health checks on {} at {}\n",
+            self.network,
+            self.checked_at.to_rfc3339()
+        );
+        for check in &self.checks {
+            text.push_str(&format!(
+                "- {}: {} ({})\n",
+                check.name,
+                check.status.label(),
+                check.detail
+            ));
+            if let Some(suggestion) = &check.suggestion {
+                text.push_str(&format!("  Suggestion: {}\n", suggestion));
+            }
+        }
+        text
+    }
+}
+
+/// The layers a DAPI connection is built from, each one tried only when the one below worked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionLayer {
+    Dns,
+    Tcp,
+    Tls,
+    Grpc,
+}
+
+impl ConnectionLayer {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConnectionLayer::Dns => "DNS",
+            ConnectionLayer::Tcp => "TCP",
+            ConnectionLayer::Tls => "TLS",
+            ConnectionLayer::Grpc => "gRPC",
+        }
+    }
+}
+
+/// Outcome of one layer of a DAPI connection
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerCheck {
+    pub layer: ConnectionLayer,
+    pub status: CheckStatus,
+    pub detail: String,
+    pub elapsed_ms: u128,
+}
+
+/// Which layer of the connection to a DAPI address fails, and what to do about it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DapiConnectivityReport {
+    pub address: String,
+    pub layers: Vec<LayerCheck>,
+    pub suggestion: Option<String>,
+}
+
+impl DapiConnectivityReport {
+    pub fn status(&self) -> CheckStatus {
+        self.layers
+            .iter()
+            .map(|layer| layer.status)
+            .find(|status| *status != CheckStatus::Passed)
+            .unwrap_or(CheckStatus::Passed)
+    }
+
+    /// The layer the connection breaks at, `None` when every layer worked
+    pub fn failed_layer(&self) -> Option<ConnectionLayer> {
+        self.layers
+            .iter()
+            .find(|layer| layer.status == CheckStatus::Failed)
+            .map(|layer| layer.layer)
+    }
+
+    /// The reports as plain text, for pasting into bug reports
+    pub fn to_text(reports: &[DapiConnectivityReport]) -> String {
+        let mut text = String::from("DAPI connectivity\n");
+        for report in reports {
+            text.push_str(&format!(
+                "- {}: {}\n",
+                report.address,
+                report.status().label()
+            ));
+            for layer in &report.layers {
+                text.push_str(&format!(
+                    "  {}: {} in {} ms ({})\n",
+                    layer.layer.label(),
+                    layer.status.label(),
+                    layer.elapsed_ms,
+                    layer.detail
+                ));
+            }
+            if let Some(suggestion) = &report.suggestion {
+                text.push_str(&format!("  Suggestion: {}\n", suggestion));
+            }
+        }
+        text
+    }
+}
@@ -0,0 +1,191 @@
+use dash_sdk::dashcore_rpc::dashcore::PrivateKey;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use std::fmt::{Display, Formatter};
+
+/// What a private key in an identity import CSV is used for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CsvKeyType {
+    /// A key of a user identity, listed in key id order
+    User,
+    Voting,
+    Owner,
+    Payout,
+}
+
+impl CsvKeyType {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "user" | "authentication" => Some(CsvKeyType::User),
+            "voting" | "voter" => Some(CsvKeyType::Voting),
+            "owner" => Some(CsvKeyType::Owner),
+            "payout" | "payout_address" => Some(CsvKeyType::Payout),
+            _ => None,
+        }
+    }
+
+    pub fn is_masternode_key(&self) -> bool {
+        !matches!(self, CsvKeyType::User)
+    }
+}
+
+impl Display for CsvKeyType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvKeyType::User => write!(f, "user"),
+            CsvKeyType::Voting => write!(f, "voting"),
+            CsvKeyType::Owner => write!(f, "owner"),
+            CsvKeyType::Payout => write!(f, "payout"),
+        }
+    }
+}
+
+/// One line of an identity import CSV with the problems found in it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvIdentityRow {
+    /// Line number in the file, starting at 1
+    pub line: usize,
+    pub identity_id: String,
+    pub key_type: Option<CsvKeyType>,
+    pub private_key: String,
+    pub alias: String,
+    pub errors: Vec<String>,
+}
+
+impl CsvIdentityRow {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Parses a CSV of `identity_id_or_protx,key_type,private_key,alias` lines. A header line and
+/// blank lines are skipped. Every row is returned, with its problems listed in `errors`.
+pub fn parse_identity_csv(contents: &str) -> Vec<CsvIdentityRow> {
+    let mut rows: Vec<CsvIdentityRow> = Vec::new();
+    for (i, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = split_csv_line(line);
+        if i == 0
+            && fields
+                .first()
+                .is_some_and(|field| field.trim().to_lowercase().starts_with("identity"))
+        {
+            continue;
+        }
+        let field = |index: usize| {
+            fields
+                .get(index)
+                .map(|field| field.trim().to_string())
+                .unwrap_or_default()
+        };
+        let mut row = CsvIdentityRow {
+            line: i + 1,
+            identity_id: field(0),
+            key_type: CsvKeyType::parse(&field(1)),
+            private_key: field(2),
+            alias: field(3),
+            errors: Vec::new(),
+        };
+
+        if fields.len() < 3 || fields.len() > 4 {
+            row.errors
+                .push(format!("expected 3 or 4 columns, found {}", fields.len()));
+        }
+        if Identifier::from_string(&row.identity_id, Encoding::Base58)
+            .or_else(|_| Identifier::from_string(&row.identity_id, Encoding::Hex))
+            .is_err()
+        {
+            row.errors
+                .push("identity id is neither Base58 nor hex".to_string());
+        }
+        if row.key_type.is_none() {
+            row.errors.push(format!(
+                "unknown key type \"{}\", use user, voting, owner or payout",
+                field(1)
+            ));
+        }
+        if let Err(e) = check_private_key(&row.private_key) {
+            row.errors.push(e);
+        }
+        rows.push(row);
+    }
+
+    // An identity is either a user or a masternode, and has at most one key of each
+    // masternode key type
+    for i in 0..rows.len() {
+        let (earlier, rest) = rows.split_at_mut(i);
+        let row = &mut rest[0];
+        let Some(key_type) = row.key_type else {
+            continue;
+        };
+        let same_identity = earlier
+            .iter()
+            .filter(|other| other.identity_id == row.identity_id && other.key_type.is_some());
+        for other in same_identity {
+            let other_type = other.key_type.unwrap();
+            if other_type.is_masternode_key() != key_type.is_masternode_key() {
+                row.errors.push(format!(
+                    "line {} already uses this identity as a {}",
+                    other.line,
+                    if other_type.is_masternode_key() {
+                        "masternode"
+                    } else {
+                        "user"
+                    }
+                ));
+                break;
+            }
+            if key_type.is_masternode_key() && other_type == key_type {
+                row.errors.push(format!(
+                    "line {} already has the {} key of this identity",
+                    other.line, key_type
+                ));
+                break;
+            }
+        }
+    }
+    rows
+}
+
+fn check_private_key(private_key: &str) -> Result<(), String> {
+    match private_key.len() {
+        0 => Err("private key is missing".to_string()),
+        64 if hex::decode(private_key).is_ok() => Ok(()),
+        51 | 52 if PrivateKey::from_wif(private_key).is_ok() => Ok(()),
+        _ => Err("private key is neither 64 hex characters nor WIF".to_string()),
+    }
+}
+
+/// Splits a line on commas, keeping commas inside double quotes. Doubled quotes inside a
+/// quoted field stand for one quote.
+pub fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Quotes the field when it holds a comma, quote or line break, the inverse of
+/// [`split_csv_line`]
+pub fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
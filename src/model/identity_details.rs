@@ -0,0 +1,73 @@
+use dash_sdk::dpp::identity::TimestampMillis;
+use dash_sdk::dpp::prelude::Identifier;
+use dash_sdk::dpp::prelude::IdentityNonce;
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dash_sdk::platform::Identity;
+
+/// Public information about an identity fetched from Platform
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentityDetails {
+    pub identity_id: Identifier,
+    pub identity: Option<Identity>,
+    pub dpns_names: Vec<String>,
+    pub dashpay_profile: Option<DashpayProfile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DashpayProfile {
+    pub display_name: Option<String>,
+    pub public_message: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+/// Something an identity did that the tool has seen, built from the local database
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdentityActivity {
+    /// The identity is a contestant for a name
+    Contestant {
+        normalized_contested_name: String,
+        votes: u32,
+        created_at: Option<TimestampMillis>,
+    },
+    /// The identity voted on a contest from this tool
+    Vote {
+        normalized_contested_name: String,
+        vote_choice: ResourceVoteChoice,
+        voted_at: TimestampMillis,
+    },
+}
+
+impl IdentityActivity {
+    pub fn timestamp(&self) -> Option<TimestampMillis> {
+        match self {
+            IdentityActivity::Contestant { created_at, .. } => *created_at,
+            IdentityActivity::Vote { voted_at, .. } => Some(*voted_at),
+        }
+    }
+}
+
+/// An identity saved to the local address book
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contact {
+    pub identity_id: Identifier,
+    pub alias: Option<String>,
+}
+
+/// Nonces of an identity as seen on Platform, used to debug stuck state transitions
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentityNonces {
+    pub identity_id: Identifier,
+    /// The identity nonce on Platform, `None` if the identity has none yet
+    pub identity_nonce: Option<IdentityNonce>,
+    /// The nonce the SDK would base the next transition on
+    pub sdk_cached_nonce: Option<IdentityNonce>,
+    /// Nonces for the known contracts the identity has used
+    pub contract_nonces: Vec<ContractNonce>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractNonce {
+    pub contract_id: Identifier,
+    pub contract_alias: Option<String>,
+    pub nonce: IdentityNonce,
+}
@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Brands flagged by default, users can edit the list in the settings
+const DEFAULT_BRANDS: [&str; 16] = [
+    "dash",
+    "bitcoin",
+    "ethereum",
+    "binance",
+    "coinbase",
+    "kraken",
+    "google",
+    "apple",
+    "amazon",
+    "microsoft",
+    "facebook",
+    "paypal",
+    "visa",
+    "tesla",
+    "netflix",
+    "twitter",
+];
+
+/// Rules that flag contested names which are likely squatting or abuse, so voters can spot
+/// the names that deserve a Lock vote. Stored in the preferences.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JunkNameRules {
+    pub enabled: bool,
+    /// Names of at most this many characters are flagged as short, 0 disables the rule
+    pub max_short_length: usize,
+    /// Runs of the same character at least this long are flagged, 0 disables the rule
+    pub max_repeated_chars: usize,
+    /// Names containing one of these are flagged as impersonating a brand
+    pub brands: Vec<String>,
+    /// Also match brands spelled with look-alike digits, e.g. "g00gle" or "p4ypal"
+    pub match_homographs: bool,
+}
+
+impl Default for JunkNameRules {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_short_length: 3,
+            max_repeated_chars: 4,
+            brands: DEFAULT_BRANDS
+                .iter()
+                .map(|brand| brand.to_string())
+                .collect(),
+            match_homographs: true,
+        }
+    }
+}
+
+/// Why a name was flagged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JunkReason {
+    Short(usize),
+    RepeatedCharacter(char, usize),
+    Brand(String),
+    BrandHomograph(String),
+}
+
+impl Display for JunkReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JunkReason::Short(length) => write!(f, "only {} characters long", length),
+            JunkReason::RepeatedCharacter(c, count) => {
+                write!(f, "'{}' repeated {} times", c, count)
+            }
+            JunkReason::Brand(brand) => write!(f, "contains the brand \"{}\"", brand),
+            JunkReason::BrandHomograph(brand) => {
+                write!(f, "looks like the brand \"{}\"", brand)
+            }
+        }
+    }
+}
+
+impl JunkNameRules {
+    /// Returns every rule the name breaks, empty if it looks fine or the rules are disabled
+    pub fn classify(&self, name: &str) -> Vec<JunkReason> {
+        let mut reasons = Vec::new();
+        if !self.enabled {
+            return reasons;
+        }
+        let name = name.to_lowercase();
+
+        let length = name.chars().count();
+        if self.max_short_length > 0 && length <= self.max_short_length {
+            reasons.push(JunkReason::Short(length));
+        }
+
+        if self.max_repeated_chars > 0 {
+            if let Some((c, count)) = longest_run(&name) {
+                if count >= self.max_repeated_chars {
+                    reasons.push(JunkReason::RepeatedCharacter(c, count));
+                }
+            }
+        }
+
+        let deleeted_name = deleet(&name);
+        for brand in &self.brands {
+            let brand = brand.trim().to_lowercase();
+            if brand.is_empty() {
+                continue;
+            }
+            if name.contains(&brand) || name.contains(&dpns_normalize(&brand)) {
+                reasons.push(JunkReason::Brand(brand));
+            } else if self.match_homographs && deleeted_name.contains(&deleet(&brand)) {
+                reasons.push(JunkReason::BrandHomograph(brand));
+            }
+        }
+        reasons
+    }
+}
+
+/// The most repeated character in a row and how often it repeats
+fn longest_run(name: &str) -> Option<(char, usize)> {
+    let mut longest: Option<(char, usize)> = None;
+    let mut current: Option<(char, usize)> = None;
+    for c in name.chars() {
+        current = match current {
+            Some((previous, count)) if previous == c => Some((c, count + 1)),
+            _ => Some((c, 1)),
+        };
+        if current.map(|(_, count)| count) > longest.map(|(_, count)| count) {
+            longest = current;
+        }
+    }
+    longest
+}
+
+/// The brand as DPNS stores it in normalized names
+fn dpns_normalize(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            'o' => '0',
+            'i' | 'l' => '1',
+            c => c,
+        })
+        .collect()
+}
+
+/// Maps look-alike digits and letters to one spelling. DPNS already normalizes o, i and l to
+/// 0 and 1, so both the name and the brand go through this before comparing.
+fn deleet(value: &str) -> String {
+    value
+        .chars()
+        .filter_map(|c| match c {
+            '0' => Some('o'),
+            '1' | 'i' | '!' | '|' => Some('l'),
+            '3' => Some('e'),
+            '4' | '@' => Some('a'),
+            '5' | '$' => Some('s'),
+            '7' => Some('t'),
+            '8' => Some('b'),
+            '9' => Some('g'),
+            '-' | '_' | '.' => None,
+            c => Some(c),
+        })
+        .collect()
+}
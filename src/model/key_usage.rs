@@ -0,0 +1,147 @@
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::identity::{KeyID, KeyType, Purpose, SecurityLevel, TimestampMillis};
+use dash_sdk::platform::Identity;
+use std::collections::BTreeMap;
+
+/// Kinds of transitions recorded when the tool signs them
+pub const TRANSITION_IDENTITY_UPDATE: &str = "Identity update";
+pub const TRANSITION_WITHDRAWAL: &str = "Withdrawal";
+pub const TRANSITION_CREDIT_TRANSFER: &str = "Credit transfer";
+pub const TRANSITION_DPNS_REGISTRATION: &str = "DPNS name registration";
+pub const TRANSITION_MASTERNODE_VOTE: &str = "Masternode vote";
+pub const TRANSITION_DOCUMENT_CREATE: &str = "Document creation";
+pub const TRANSITION_DOCUMENT_DELETE: &str = "Document deletion";
+pub const TRANSITION_DOCUMENT_PRICE: &str = "Document price update";
+pub const TRANSITION_DOCUMENT_PURCHASE: &str = "Document purchase";
+
+/// A state transition signed with a key of an identity, as recorded by this tool
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyUsageRecord {
+    pub key_id: KeyID,
+    pub transition: String,
+    pub used_at: TimestampMillis,
+}
+
+/// Documents of an identity found on Platform, whose signing key is not known
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentActivity {
+    /// What the identity did, e.g. "DPNS names (alice.dash)"
+    pub description: String,
+    /// The security level the documents had to be signed with
+    pub security_level: SecurityLevel,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyAssessment {
+    Disabled,
+    /// Signed transitions the tool has seen
+    Used,
+    /// Could have signed activity found on Platform
+    PossiblyUsed(String),
+    /// Unused so far but still needed, with the reason
+    Required(&'static str),
+    /// No known use, may be safely disabled
+    Unused,
+}
+
+impl KeyAssessment {
+    pub fn label(&self) -> String {
+        match self {
+            KeyAssessment::Disabled => "Disabled".to_string(),
+            KeyAssessment::Used => "In use".to_string(),
+            KeyAssessment::PossiblyUsed(activity) => format!("May have signed {}", activity),
+            KeyAssessment::Required(reason) => format!("Keep: {}", reason),
+            KeyAssessment::Unused => "Unused, may be safely disabled".to_string(),
+        }
+    }
+}
+
+/// Uses of one transition kind by a key
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitionUsage {
+    pub count: u32,
+    pub last_used: TimestampMillis,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyUsageRow {
+    pub key_id: KeyID,
+    pub purpose: Purpose,
+    pub security_level: SecurityLevel,
+    pub key_type: KeyType,
+    pub usages: BTreeMap<String, TransitionUsage>,
+    pub assessment: KeyAssessment,
+}
+
+/// Which keys of the identity signed which kinds of transitions. `chain_activity` adds what was
+/// found on Platform, `masternode` tells that the keys come from a masternode registration.
+pub fn analyze_key_usage(
+    identity: &Identity,
+    records: &[KeyUsageRecord],
+    chain_activity: &[DocumentActivity],
+    masternode: bool,
+) -> Vec<KeyUsageRow> {
+    let enabled_transfer_keys = identity
+        .public_keys()
+        .values()
+        .filter(|key| key.purpose() == Purpose::TRANSFER && key.disabled_at().is_none())
+        .count();
+
+    identity
+        .public_keys()
+        .iter()
+        .map(|(key_id, key)| {
+            let mut usages: BTreeMap<String, TransitionUsage> = BTreeMap::new();
+            for record in records.iter().filter(|record| record.key_id == *key_id) {
+                let usage = usages
+                    .entry(record.transition.clone())
+                    .or_insert(TransitionUsage {
+                        count: 0,
+                        last_used: record.used_at,
+                    });
+                usage.count += 1;
+                usage.last_used = usage.last_used.max(record.used_at);
+            }
+
+            // Documents are signed with authentication keys at least as secure as their type
+            // requires, master keys excluded
+            let could_sign: Vec<&str> = chain_activity
+                .iter()
+                .filter(|activity| {
+                    key.purpose() == Purpose::AUTHENTICATION
+                        && key.security_level() != SecurityLevel::MASTER
+                        && key.security_level() as u8 <= activity.security_level as u8
+                })
+                .map(|activity| activity.description.as_str())
+                .collect();
+
+            let assessment = if key.disabled_at().is_some() {
+                KeyAssessment::Disabled
+            } else if !usages.is_empty() {
+                KeyAssessment::Used
+            } else if !could_sign.is_empty() {
+                KeyAssessment::PossiblyUsed(could_sign.join(", "))
+            } else if masternode {
+                KeyAssessment::Required("set by the masternode registration")
+            } else if key.purpose() == Purpose::AUTHENTICATION
+                && key.security_level() == SecurityLevel::MASTER
+            {
+                KeyAssessment::Required("the master key is needed to add and disable keys")
+            } else if key.purpose() == Purpose::TRANSFER && enabled_transfer_keys == 1 {
+                KeyAssessment::Required("the only transfer key, needed to withdraw")
+            } else {
+                KeyAssessment::Unused
+            };
+
+            KeyUsageRow {
+                key_id: *key_id,
+                purpose: key.purpose(),
+                security_level: key.security_level(),
+                key_type: key.key_type(),
+                usages,
+                assessment,
+            }
+        })
+        .collect()
+}
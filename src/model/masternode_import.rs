@@ -0,0 +1,244 @@
+use crate::model::qualified_identity::IdentityType;
+use serde_json::{Map, Value};
+
+/// A masternode found in a dashmate config, `protx info` output or dash.conf, ready to be
+/// reviewed before it is loaded as a voting identity
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedMasternode {
+    pub pro_tx_hash: String,
+    pub identity_type: IdentityType,
+    pub alias: String,
+    /// Voting private key in WIF or hex, empty when the source only knew the voting address
+    pub voting_private_key: String,
+    /// Used to look the voting key up in the Core wallet when no private key was found
+    pub voting_address: Option<String>,
+}
+
+impl ImportedMasternode {
+    fn new(pro_tx_hash: String) -> Self {
+        Self {
+            pro_tx_hash,
+            identity_type: IdentityType::Masternode,
+            alias: String::new(),
+            voting_private_key: String::new(),
+            voting_address: None,
+        }
+    }
+
+    /// Fills in what `other` knows about the same masternode and we do not
+    fn merge(&mut self, other: ImportedMasternode) {
+        if self.alias.is_empty() {
+            self.alias = other.alias;
+        }
+        if self.voting_private_key.is_empty() {
+            self.voting_private_key = other.voting_private_key;
+        }
+        if self.voting_address.is_none() {
+            self.voting_address = other.voting_address;
+        }
+        if other.identity_type == IdentityType::Evonode {
+            self.identity_type = IdentityType::Evonode;
+        }
+    }
+}
+
+/// Finds masternodes in the contents of a file.
+///
+/// JSON input, like a dashmate config or the output of `protx info` and `protx list`, is
+/// searched for objects holding a ProTxHash. Anything else is read as dash.conf style
+/// `key=value` lines, where `[section]` headers name the masternodes.
+pub fn parse_masternode_import(contents: &str) -> Result<Vec<ImportedMasternode>, String> {
+    let contents = contents.trim();
+    if contents.is_empty() {
+        return Err("Nothing to import".to_string());
+    }
+
+    let found = match serde_json::from_str::<Value>(contents) {
+        Ok(json) => {
+            let mut found = Vec::new();
+            collect_from_json(&json, None, &mut found);
+            found
+        }
+        Err(_) if contents.starts_with('{') || contents.starts_with('[') => {
+            return Err("The input looks like JSON but could not be parsed".to_string());
+        }
+        Err(_) => parse_conf(contents),
+    };
+
+    let mut masternodes: Vec<ImportedMasternode> = Vec::new();
+    for masternode in found {
+        match masternodes
+            .iter_mut()
+            .find(|existing| existing.pro_tx_hash == masternode.pro_tx_hash)
+        {
+            Some(existing) => existing.merge(masternode),
+            None => masternodes.push(masternode),
+        }
+    }
+
+    if masternodes.is_empty() {
+        Err("No ProTxHash found in the input".to_string())
+    } else {
+        Ok(masternodes)
+    }
+}
+
+/// Lowercases a key and drops separators, so `proTxHash`, `pro-tx-hash` and `protx_hash`
+/// compare equal
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_pro_tx_hash(value: &str) -> bool {
+    value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn is_pro_tx_hash_key(key: &str) -> bool {
+    matches!(key, "protxhash" | "protxtxhash" | "protx")
+}
+
+fn is_voting_key_key(key: &str) -> bool {
+    matches!(
+        key,
+        "votingprivatekey" | "voterprivatekey" | "votingkey" | "votingprivkey"
+    )
+}
+
+fn is_evonode_type(value: &str) -> bool {
+    matches!(
+        normalize_key(value).as_str(),
+        "evo" | "evonode" | "highperformance" | "hpmn"
+    )
+}
+
+fn get_str<'a>(object: &'a Map<String, Value>, keys: fn(&str) -> bool) -> Option<&'a str> {
+    object
+        .iter()
+        .find(|(key, _)| keys(&normalize_key(key)))
+        .and_then(|(_, value)| value.as_str())
+}
+
+fn collect_from_json(value: &Value, name: Option<&str>, found: &mut Vec<ImportedMasternode>) {
+    match value {
+        Value::Object(object) => {
+            match get_str(object, is_pro_tx_hash_key).filter(|hash| is_pro_tx_hash(hash)) {
+                Some(pro_tx_hash) => found.push(masternode_from_json(pro_tx_hash, object, name)),
+                None => {
+                    for (key, child) in object {
+                        collect_from_json(child, Some(key), found);
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_from_json(item, name, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn masternode_from_json(
+    pro_tx_hash: &str,
+    object: &Map<String, Value>,
+    name: Option<&str>,
+) -> ImportedMasternode {
+    let mut masternode = ImportedMasternode::new(pro_tx_hash.to_lowercase());
+    masternode.alias = get_str(object, |key| matches!(key, "name" | "alias"))
+        .or(name)
+        .unwrap_or_default()
+        .to_string();
+
+    // `protx info` keeps the addresses under `state`, dashmate and the testnet node files
+    // keep each key in its own object like `voter: { address, private_key }`
+    let mut objects = vec![object];
+    objects.extend(object.iter().filter_map(|(key, value)| {
+        matches!(normalize_key(key).as_str(), "state" | "voter" | "voting")
+            .then(|| value.as_object())
+            .flatten()
+    }));
+    for (i, object) in objects.into_iter().enumerate() {
+        let nested_voter = i > 0 && get_str(object, |key| key == "address").is_some();
+        if masternode.voting_private_key.is_empty() {
+            let private_key = get_str(object, is_voting_key_key).or_else(|| {
+                nested_voter
+                    .then(|| get_str(object, |key| key == "privatekey"))
+                    .flatten()
+            });
+            masternode.voting_private_key = private_key.unwrap_or_default().to_string();
+        }
+        if masternode.voting_address.is_none() {
+            let address = get_str(object, |key| key == "votingaddress").or_else(|| {
+                nested_voter
+                    .then(|| get_str(object, |key| key == "address"))
+                    .flatten()
+            });
+            masternode.voting_address = address.map(str::to_string);
+        }
+        let evonode = get_str(object, |key| matches!(key, "type" | "masternodetype"))
+            .is_some_and(is_evonode_type)
+            || object.keys().any(|key| {
+                matches!(
+                    normalize_key(key).as_str(),
+                    "platformnodeid" | "nodekey" | "platformp2pport"
+                )
+            });
+        if evonode {
+            masternode.identity_type = IdentityType::Evonode;
+        }
+    }
+    if name.is_some_and(|name| {
+        let name = normalize_key(name);
+        name.starts_with("hp") || name.contains("evo")
+    }) {
+        masternode.identity_type = IdentityType::Evonode;
+    }
+    masternode
+}
+
+/// Reads dash.conf style `key=value` lines. A new masternode starts at every ProTxHash.
+fn parse_conf(contents: &str) -> Vec<ImportedMasternode> {
+    let mut found: Vec<ImportedMasternode> = Vec::new();
+    let mut section: Option<String> = None;
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = Some(name.trim().to_string());
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = normalize_key(key);
+        let value = value.trim().trim_matches('"');
+
+        if is_pro_tx_hash_key(&key) {
+            if is_pro_tx_hash(value) {
+                let mut masternode = ImportedMasternode::new(value.to_lowercase());
+                masternode.alias = section.clone().unwrap_or_default();
+                found.push(masternode);
+            }
+            continue;
+        }
+        let Some(current) = found.last_mut() else {
+            continue;
+        };
+        if is_voting_key_key(&key) {
+            current.voting_private_key = value.to_string();
+        } else if key == "votingaddress" {
+            current.voting_address = Some(value.to_string());
+        } else if matches!(key.as_str(), "type" | "masternodetype") && is_evonode_type(value) {
+            current.identity_type = IdentityType::Evonode;
+        } else if matches!(key.as_str(), "alias" | "name") {
+            current.alias = value.to_string();
+        }
+    }
+    found
+}
@@ -0,0 +1,196 @@
+use crate::model::ownership_proof::{armor, dearmor};
+use dash_sdk::dashcore_rpc::dashcore::{signer, Address, Network, PublicKey};
+use dash_sdk::dpp::bls::native_bls::NativeBlsModule;
+use dash_sdk::dpp::bls::BlsModule;
+use dash_sdk::dpp::bls_signatures::{self, Bls12381G2Impl, SignatureSchemes};
+use dash_sdk::dpp::identity::KeyType;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// Name in the armor lines of messages signed with a masternode key
+pub const MASTERNODE_MESSAGE_KIND: &str = "DASH MASTERNODE MESSAGE";
+
+/// Signed before the claim, so a message can never be mistaken for a signed transaction or vote
+const SIGNING_PREFIX: &[u8] = b"Dash masternode signed message:\n";
+
+/// The key of a masternode a message is signed with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MasternodeKeyRole {
+    Owner,
+    Voting,
+    Operator,
+}
+
+impl MasternodeKeyRole {
+    pub const ALL: [MasternodeKeyRole; 3] = [
+        MasternodeKeyRole::Owner,
+        MasternodeKeyRole::Voting,
+        MasternodeKeyRole::Operator,
+    ];
+
+    /// The type of the key, operator keys are BLS and the others ECDSA
+    pub fn key_type(&self) -> KeyType {
+        match self {
+            MasternodeKeyRole::Owner | MasternodeKeyRole::Voting => KeyType::ECDSA_SECP256K1,
+            MasternodeKeyRole::Operator => KeyType::BLS12_381,
+        }
+    }
+}
+
+impl fmt::Display for MasternodeKeyRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MasternodeKeyRole::Owner => write!(f, "Owner"),
+            MasternodeKeyRole::Voting => write!(f, "Voting"),
+            MasternodeKeyRole::Operator => write!(f, "Operator"),
+        }
+    }
+}
+
+/// What the holder of the masternode key states. The signature covers the compact JSON of
+/// this struct, with the fields in this order, after the signing prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MasternodeMessageClaim {
+    pub network: String,
+    /// ProTx hash of the masternode in hex
+    pub pro_tx_hash: String,
+    pub role: MasternodeKeyRole,
+    /// Hex of the public key, compressed ECDSA or BLS. The masternode list only has the hash
+    /// of the owner and voting keys.
+    pub public_key: String,
+    pub message: String,
+    /// Unix time in seconds
+    pub signed_at: i64,
+}
+
+/// A message signed with one of a masternode's keys, passed around as a text blob
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedMasternodeMessage {
+    #[serde(flatten)]
+    pub claim: MasternodeMessageClaim,
+    /// Hex of the compact recoverable ECDSA signature or the compressed BLS signature
+    pub signature: String,
+}
+
+impl SignedMasternodeMessage {
+    /// Signs the message with the private key of the role. The private key is not kept.
+    pub fn sign(
+        network: Network,
+        pro_tx_hash: String,
+        role: MasternodeKeyRole,
+        message: String,
+        private_key: &[u8; 32],
+    ) -> Result<Self, String> {
+        let public_key = role
+            .key_type()
+            .public_key_data_from_private_key_data(private_key, network)
+            .map_err(|e| format!("The {} key is not valid: {}", role, e))?;
+        let claim = MasternodeMessageClaim {
+            network: network.to_string(),
+            pro_tx_hash,
+            role,
+            public_key: hex::encode(public_key),
+            message,
+            signed_at: chrono::Utc::now().timestamp(),
+        };
+        let data = signing_bytes(&claim);
+        let signature = match role.key_type() {
+            KeyType::BLS12_381 => {
+                let secret_key =
+                    bls_signatures::SecretKey::<Bls12381G2Impl>::from_be_bytes(private_key)
+                        .into_option()
+                        .ok_or("The operator key is not a BLS private key".to_string())?;
+                secret_key
+                    .sign(SignatureSchemes::Basic, &data)
+                    .map_err(|e| format!("Failed to sign the message: {}", e))?
+                    .as_raw_value()
+                    .to_compressed()
+                    .to_vec()
+            }
+            _ => signer::sign(&data, private_key)
+                .map_err(|e| format!("Failed to sign the message: {}", e))?
+                .to_vec(),
+        };
+        Ok(Self {
+            claim,
+            signature: hex::encode(signature),
+        })
+    }
+
+    /// Checks the signature, and that its key is the one of the role in the masternode's
+    /// `protx info`
+    pub fn verify(&self, protx_info: &Value, network: Network) -> Result<(), String> {
+        let state = protx_info
+            .get("state")
+            .ok_or("Core's answer has no masternode state".to_string())?;
+        let listed = |key: &str| {
+            state
+                .get(key)
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or(format!(
+                    "The masternode list has no {} for the masternode",
+                    key
+                ))
+        };
+        let public_key = hex::decode(&self.claim.public_key)
+            .map_err(|_| "The public key is not hex".to_string())?;
+        let signature =
+            hex::decode(&self.signature).map_err(|_| "The signature is not hex".to_string())?;
+        let data = signing_bytes(&self.claim);
+
+        match self.claim.role {
+            MasternodeKeyRole::Owner | MasternodeKeyRole::Voting => {
+                let listed_address = listed(match self.claim.role {
+                    MasternodeKeyRole::Owner => "ownerAddress",
+                    _ => "votingAddress",
+                })?;
+                let key = PublicKey::from_slice(&public_key)
+                    .map_err(|_| "The public key is not an ECDSA key".to_string())?;
+                if Address::p2pkh(&key, network).to_string() != listed_address {
+                    return Err(format!(
+                        "The key is not the masternode's {} key, that is {}",
+                        self.claim.role.to_string().to_lowercase(),
+                        listed_address
+                    ));
+                }
+                signer::verify_data_signature(&data, &signature, &public_key)
+                    .map_err(|_| "The signature does not match the message and key".to_string())
+            }
+            MasternodeKeyRole::Operator => {
+                let listed_key = listed("pubKeyOperator")?;
+                if !listed_key.eq_ignore_ascii_case(&self.claim.public_key) {
+                    return Err(
+                        "The key is not the masternode's operator key. Masternodes registered \
+                         before the basic BLS scheme list their key in the legacy format."
+                            .to_string(),
+                    );
+                }
+                match NativeBlsModule.verify_signature(&signature, &data, &public_key) {
+                    Ok(true) => Ok(()),
+                    _ => Err("The signature does not match the message and key".to_string()),
+                }
+            }
+        }
+    }
+
+    /// The message as armored base64, to paste into a chat or a forum post
+    pub fn to_blob(&self) -> String {
+        armor(
+            MASTERNODE_MESSAGE_KIND,
+            &serde_json::to_vec(self).expect("signed messages serialize to JSON"),
+        )
+    }
+
+    pub fn from_blob(blob: &str) -> Result<Self, String> {
+        let json = dearmor(blob)?;
+        serde_json::from_slice(&json).map_err(|e| format!("Not a signed masternode message: {}", e))
+    }
+}
+
+fn signing_bytes(claim: &MasternodeMessageClaim) -> Vec<u8> {
+    let mut bytes = SIGNING_PREFIX.to_vec();
+    bytes.extend(serde_json::to_vec(claim).expect("claims serialize to JSON"));
+    bytes
+}
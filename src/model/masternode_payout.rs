@@ -0,0 +1,74 @@
+use crate::model::identity_csv::escape_csv;
+use chrono::{DateTime, Datelike, Utc};
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+/// A coinbase output paying one of our masternodes, found through Core's address index
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasternodePayout {
+    /// The masternode's identity, the ProTx hash
+    pub identity_id: Identifier,
+    pub payout_address: String,
+    pub txid: String,
+    pub output_index: u32,
+    pub duffs: Duffs,
+    pub height: u32,
+    /// Block time in seconds since the Unix epoch
+    pub paid_at: i64,
+}
+
+impl MasternodePayout {
+    pub fn paid_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.paid_at, 0).unwrap_or_default()
+    }
+
+    /// Year and month of the payout in UTC
+    pub fn month(&self) -> (i32, u32) {
+        let paid_at = self.paid_at();
+        (paid_at.year(), paid_at.month())
+    }
+}
+
+/// Payouts summed per UTC month and node, months in order
+pub fn monthly_totals(
+    payouts: &[MasternodePayout],
+) -> BTreeMap<(i32, u32), BTreeMap<Identifier, Duffs>> {
+    let mut totals: BTreeMap<(i32, u32), BTreeMap<Identifier, Duffs>> = BTreeMap::new();
+    for payout in payouts {
+        *totals
+            .entry(payout.month())
+            .or_default()
+            .entry(payout.identity_id)
+            .or_default() += payout.duffs;
+    }
+    totals
+}
+
+/// Renders the payouts as CSV for accounting, one line per payout with the node's name as
+/// `node_name` returns it
+pub fn payouts_to_csv(
+    payouts: &[MasternodePayout],
+    node_name: impl Fn(&Identifier) -> String,
+) -> String {
+    let mut csv = String::from(
+        "date_utc,node,protx_hash,payout_address,amount_dash,block_height,txid,output_index\n",
+    );
+    for payout in payouts {
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{:.8},{},{},{}",
+            payout.paid_at().format("%Y-%m-%d %H:%M:%S"),
+            escape_csv(&node_name(&payout.identity_id)),
+            payout.identity_id.to_string(Encoding::Hex),
+            payout.payout_address,
+            payout.duffs as f64 * 1e-8,
+            payout.height,
+            payout.txid,
+            payout.output_index
+        );
+    }
+    csv
+}
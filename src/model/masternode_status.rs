@@ -0,0 +1,172 @@
+use dash_sdk::platform::Identifier;
+use serde_json::Value;
+
+/// A masternode's state in Core's deterministic masternode list, from `protx info`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoreMasternodeState {
+    /// IP and port of the node
+    pub service: String,
+    pub registered_height: u64,
+    /// 0 when it was never paid
+    pub last_paid_height: u64,
+    pub pose_penalty: u64,
+    /// Height of the PoSe ban, `None` while not banned
+    pub pose_ban_height: Option<u64>,
+    /// Blocks since the ProTx was mined
+    pub confirmations: u64,
+}
+
+impl CoreMasternodeState {
+    pub fn from_protx_info(info: &Value) -> Result<Self, String> {
+        let state = info
+            .get("state")
+            .ok_or("Core's answer has no masternode state".to_string())?;
+        let number = |value: &Value, key: &str| value.get(key).and_then(Value::as_i64);
+        Ok(Self {
+            service: state
+                .get("service")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            registered_height: number(state, "registeredHeight").unwrap_or_default().max(0) as u64,
+            last_paid_height: number(state, "lastPaidHeight").unwrap_or_default().max(0) as u64,
+            pose_penalty: number(state, "PoSePenalty").unwrap_or_default().max(0) as u64,
+            pose_ban_height: number(state, "PoSeBanHeight")
+                .filter(|height| *height > 0)
+                .map(|height| height as u64),
+            confirmations: number(info, "confirmations").unwrap_or_default().max(0) as u64,
+        })
+    }
+
+    pub fn status(&self) -> &'static str {
+        if self.pose_ban_height.is_some() {
+            "PoSe banned"
+        } else if self.pose_penalty > 0 {
+            "Enabled, PoSe penalized"
+        } else {
+            "Enabled"
+        }
+    }
+}
+
+/// A peer of a Tenderdash node, from `net_info`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TenderdashPeer {
+    pub node_id: String,
+    pub moniker: String,
+    pub remote_ip: String,
+}
+
+/// An evonode's Tenderdash, from its `status` and `net_info` RPC
+#[derive(Debug, Clone, PartialEq)]
+pub struct TenderdashStatus {
+    pub moniker: String,
+    /// Chain id of the Platform network
+    pub network: String,
+    pub version: String,
+    pub latest_block_height: u64,
+    pub latest_block_time: String,
+    pub catching_up: bool,
+    /// ProTx hash the node validates as, empty when it is not configured as a validator
+    pub validator_pro_tx_hash: String,
+    pub voting_power: u64,
+    pub peers: Vec<TenderdashPeer>,
+}
+
+impl TenderdashStatus {
+    /// Reads the answers of `status` and `net_info`, with or without the JSON-RPC envelope
+    pub fn from_rpc(status: &Value, net_info: &Value) -> Result<Self, String> {
+        let status = status.get("result").unwrap_or(status);
+        let net_info = net_info.get("result").unwrap_or(net_info);
+        let node_info = status
+            .get("node_info")
+            .ok_or("The answer to status has no node_info".to_string())?;
+        let sync_info = status
+            .get("sync_info")
+            .ok_or("The answer to status has no sync_info".to_string())?;
+        let validator_info = status.get("validator_info");
+        let peers = net_info
+            .get("peers")
+            .and_then(Value::as_array)
+            .map(|peers| {
+                peers
+                    .iter()
+                    .map(|peer| {
+                        let peer_node_info = peer.get("node_info").unwrap_or(peer);
+                        TenderdashPeer {
+                            node_id: text(peer_node_info, "id")
+                                .or_else(|| text(peer, "node_id"))
+                                .unwrap_or_default(),
+                            moniker: text(peer_node_info, "moniker").unwrap_or_default(),
+                            remote_ip: text(peer, "remote_ip")
+                                .or_else(|| text(peer, "url"))
+                                .unwrap_or_default(),
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Self {
+            moniker: text(node_info, "moniker").unwrap_or_default(),
+            network: text(node_info, "network").unwrap_or_default(),
+            version: text(node_info, "version").unwrap_or_default(),
+            latest_block_height: integer(sync_info, "latest_block_height").unwrap_or_default(),
+            latest_block_time: text(sync_info, "latest_block_time").unwrap_or_default(),
+            catching_up: sync_info
+                .get("catching_up")
+                .and_then(Value::as_bool)
+                .unwrap_or_default(),
+            validator_pro_tx_hash: validator_info
+                .and_then(|info| text(info, "pro_tx_hash"))
+                .unwrap_or_default(),
+            voting_power: validator_info
+                .and_then(|info| integer(info, "voting_power"))
+                .unwrap_or_default(),
+            peers,
+        })
+    }
+
+    pub fn validator_status(&self) -> &'static str {
+        if self.catching_up {
+            "Catching up"
+        } else if self.voting_power > 0 {
+            "Validating"
+        } else {
+            "Not in the active validator set"
+        }
+    }
+}
+
+/// Core's chain and the status of each of our masternodes and evonodes
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasternodeDashboard {
+    pub core_block_height: Result<u64, String>,
+    pub nodes: Vec<MasternodeStatus>,
+}
+
+/// Status of one of our masternodes, each part with the error that kept it from loading
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasternodeStatus {
+    pub identity_id: Identifier,
+    pub core: Result<CoreMasternodeState, String>,
+    /// `None` when no Tenderdash RPC is configured for the node
+    pub tenderdash: Option<Result<TenderdashStatus, String>>,
+}
+
+/// A string field, Tenderdash sends numbers as strings too
+fn text(value: &Value, key: &str) -> Option<String> {
+    match value.get(key)? {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    }
+}
+
+/// A number field given as a number or a string
+fn integer(value: &Value, key: &str) -> Option<u64> {
+    match value.get(key)? {
+        Value::Number(number) => number.as_u64(),
+        Value::String(text) => text.parse().ok(),
+        _ => None,
+    }
+}
@@ -1,4 +1,64 @@
+pub mod app_profile;
+pub mod approval;
+pub mod auto_top_up;
+pub mod balance_alert;
+pub mod clock_skew;
+pub mod cold_signing;
+pub mod consensus_error;
+pub mod contest_annotation;
+pub mod contest_calendar;
+pub mod contest_view;
+pub mod contestant_reputation;
 pub mod contested_name;
+pub mod contract_analytics;
+pub mod contract_codegen;
+pub mod credit_operation;
+pub mod document_deletion;
+pub mod document_import;
+pub mod document_revision;
+pub mod document_snapshot;
+pub mod document_trade;
+pub mod dpns_lookup;
+pub mod endpoint_health;
+pub mod explorer;
+pub mod external_funding;
+pub mod fee;
+pub mod finality;
+pub mod form_draft;
+pub mod health_check;
+pub mod identity_csv;
+pub mod identity_details;
+pub mod junk_name;
+pub mod key_usage;
+pub mod masternode_import;
+pub mod masternode_message;
+pub mod masternode_payout;
+pub mod masternode_status;
+pub mod node_log;
+pub mod notification_sound;
+pub mod operator_report;
+pub mod ownership_proof;
+pub mod payment_request;
+pub mod preferences;
+pub mod price_feed;
+pub mod proxy;
 pub mod qualified_contract;
 pub mod qualified_identity;
+pub mod rate_limit;
+pub mod recipient_check;
+pub mod response_cache;
+pub mod schedule;
+pub mod session;
+pub mod settings_export;
+pub mod spending_limit;
+pub mod telemetry;
+pub mod transfer_memo;
+pub mod transition_sandbox;
+pub mod vote_broadcast;
+pub mod vote_list;
+pub mod vote_proof;
+pub mod vote_webhook;
+pub mod voting_bloc;
 pub mod wallet;
+pub mod webhook;
+pub mod withdrawal;
@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+
+/// Where the lines of a node's log come from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NodeLogOrigin {
+    /// A log file that is followed as it grows, e.g. `~/.dashcore/debug.log`
+    File { path: String },
+    /// The output of a docker container, as `docker logs` prints it
+    Docker { container: String },
+}
+
+impl NodeLogOrigin {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NodeLogOrigin::File { .. } => "Log file",
+            NodeLogOrigin::Docker { .. } => "Docker container",
+        }
+    }
+
+    /// The file path or container name
+    pub fn target(&self) -> &str {
+        match self {
+            NodeLogOrigin::File { path } => path,
+            NodeLogOrigin::Docker { container } => container,
+        }
+    }
+}
+
+/// A log of a local dashd, drive or dapi that can be tailed in the tool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeLogSource {
+    /// Shown on the tab, e.g. "dashd"
+    pub name: String,
+    pub origin: NodeLogOrigin,
+}
+
+impl NodeLogSource {
+    /// Sources of a single box running dashd directly and Platform with dashmate
+    pub fn defaults() -> Vec<NodeLogSource> {
+        vec![
+            NodeLogSource {
+                name: "dashd".to_string(),
+                origin: NodeLogOrigin::File {
+                    path: "~/.dashcore/debug.log".to_string(),
+                },
+            },
+            NodeLogSource {
+                name: "drive".to_string(),
+                origin: NodeLogOrigin::Docker {
+                    container: "dashmate_mainnet-drive_abci-1".to_string(),
+                },
+            },
+            NodeLogSource {
+                name: "dapi".to_string(),
+                origin: NodeLogOrigin::Docker {
+                    container: "dashmate_mainnet-dapi_api-1".to_string(),
+                },
+            },
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogLevelFilter {
+    #[default]
+    All,
+    /// Warnings and errors
+    Warnings,
+    Errors,
+}
+
+impl LogLevelFilter {
+    pub const ALL: [LogLevelFilter; 3] = [
+        LogLevelFilter::All,
+        LogLevelFilter::Warnings,
+        LogLevelFilter::Errors,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevelFilter::All => "All lines",
+            LogLevelFilter::Warnings => "Warnings and errors",
+            LogLevelFilter::Errors => "Errors",
+        }
+    }
+}
+
+/// How serious a log line is, guessed from the words dashd, drive and dapi use for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLineLevel {
+    Other,
+    Warning,
+    Error,
+}
+
+impl LogLineLevel {
+    pub fn of(line: &str) -> Self {
+        let line = line.to_lowercase();
+        if ["error", "fatal", "panic", "failed"]
+            .iter()
+            .any(|word| line.contains(word))
+        {
+            LogLineLevel::Error
+        } else if line.contains("warn") {
+            LogLineLevel::Warning
+        } else {
+            LogLineLevel::Other
+        }
+    }
+}
+
+/// Whether a line passes the level filter and contains the text, ignoring case
+pub fn line_matches(line: &str, level: LogLevelFilter, text: &str) -> bool {
+    let level_matches = match level {
+        LogLevelFilter::All => true,
+        LogLevelFilter::Warnings => LogLineLevel::of(line) >= LogLineLevel::Warning,
+        LogLevelFilter::Errors => LogLineLevel::of(line) == LogLineLevel::Error,
+    };
+    level_matches && (text.is_empty() || line.to_lowercase().contains(&text.to_lowercase()))
+}
+
+/// Expands a leading `~` to the home directory
+pub fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => format!("{}/{}", home.to_string_lossy(), rest),
+        _ => path.to_string(),
+    }
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+/// Something worth a sound even when the tool is in the background
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEvent {
+    /// A watched contest ends soon and none of our identities voted on it
+    ContestEndingUnvoted,
+    /// A withdrawal of one of our identities was paid out
+    WithdrawalCompleted,
+    TaskFailed,
+    /// The balance of one of our identities fell below its minimum
+    LowBalance,
+}
+
+impl SoundEvent {
+    pub const ALL: [SoundEvent; 4] = [
+        SoundEvent::ContestEndingUnvoted,
+        SoundEvent::WithdrawalCompleted,
+        SoundEvent::TaskFailed,
+        SoundEvent::LowBalance,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SoundEvent::ContestEndingUnvoted => "Watched contest ending without my vote",
+            SoundEvent::WithdrawalCompleted => "Withdrawal completed",
+            SoundEvent::TaskFailed => "Task failed",
+            SoundEvent::LowBalance => "Identity balance below its minimum",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SoundChoice {
+    #[default]
+    Chime,
+    Beep,
+    Alarm,
+    /// A WAV file chosen by the user
+    File {
+        path: String,
+    },
+}
+
+impl SoundChoice {
+    /// The built-in sounds, a file is chosen separately
+    pub const BUILT_IN: [SoundChoice; 3] =
+        [SoundChoice::Chime, SoundChoice::Beep, SoundChoice::Alarm];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SoundChoice::Chime => "Chime",
+            SoundChoice::Beep => "Beep",
+            SoundChoice::Alarm => "Alarm",
+            SoundChoice::File { .. } => "WAV file",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventSound {
+    pub enabled: bool,
+    pub sound: SoundChoice,
+}
+
+/// Sounds played for critical events, all off until the user turns them on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationSounds {
+    /// From 0.0 to 1.0
+    pub volume: f32,
+    pub contest_ending: EventSound,
+    pub withdrawal_completed: EventSound,
+    pub task_failed: EventSound,
+    pub low_balance: EventSound,
+    /// How long before a watched contest ends the reminder plays
+    pub contest_warning_minutes: u32,
+}
+
+impl Default for NotificationSounds {
+    fn default() -> Self {
+        Self {
+            volume: 0.7,
+            contest_ending: EventSound {
+                enabled: false,
+                sound: SoundChoice::Alarm,
+            },
+            withdrawal_completed: EventSound::default(),
+            task_failed: EventSound {
+                enabled: false,
+                sound: SoundChoice::Beep,
+            },
+            low_balance: EventSound::default(),
+            contest_warning_minutes: 60,
+        }
+    }
+}
+
+impl NotificationSounds {
+    pub fn for_event(&self, event: SoundEvent) -> &EventSound {
+        match event {
+            SoundEvent::ContestEndingUnvoted => &self.contest_ending,
+            SoundEvent::WithdrawalCompleted => &self.withdrawal_completed,
+            SoundEvent::TaskFailed => &self.task_failed,
+            SoundEvent::LowBalance => &self.low_balance,
+        }
+    }
+
+    pub fn for_event_mut(&mut self, event: SoundEvent) -> &mut EventSound {
+        match event {
+            SoundEvent::ContestEndingUnvoted => &mut self.contest_ending,
+            SoundEvent::WithdrawalCompleted => &mut self.withdrawal_completed,
+            SoundEvent::TaskFailed => &mut self.task_failed,
+            SoundEvent::LowBalance => &mut self.low_balance,
+        }
+    }
+
+    /// The sound to play for the event, `None` if it is turned off
+    pub fn sound_for(&self, event: SoundEvent) -> Option<&SoundChoice> {
+        let event_sound = self.for_event(event);
+        event_sound.enabled.then_some(&event_sound.sound)
+    }
+}
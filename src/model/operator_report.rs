@@ -0,0 +1,138 @@
+use crate::model::identity_csv::escape_csv;
+use crate::model::vote_proof::VoteProofReceipt;
+use crate::model::voting_bloc::VotingBloc;
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::identity::TimestampMillis;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Name of the report of masternodes that are in no voting bloc
+pub const UNASSIGNED_OWNER: &str = "Unassigned";
+
+/// A vote cast by one of our masternodes, with its receipt when the proof was fetched
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportedVote {
+    pub contest: String,
+    pub voter_id: Identifier,
+    pub choice: String,
+    pub voted_at: TimestampMillis,
+    pub receipt: Option<VoteProofReceipt>,
+}
+
+/// The votes cast for one owner over the reporting period. A hosting provider keeps the
+/// masternodes of each owner in a voting bloc named after them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnerReport {
+    pub owner: String,
+    pub votes: Vec<ReportedVote>,
+}
+
+/// One report per voting bloc with votes in the period, and one for masternodes in no bloc. A
+/// masternode in several blocs is reported to each of them.
+pub fn owner_reports(votes: &[ReportedVote], blocs: &[VotingBloc]) -> Vec<OwnerReport> {
+    let mut reports: Vec<OwnerReport> = blocs
+        .iter()
+        .map(|bloc| OwnerReport {
+            owner: bloc.name.clone(),
+            votes: votes
+                .iter()
+                .filter(|vote| bloc.members.contains(&vote.voter_id))
+                .cloned()
+                .collect(),
+        })
+        .collect();
+    let in_blocs: HashSet<&Identifier> = blocs.iter().flat_map(|bloc| &bloc.members).collect();
+    reports.push(OwnerReport {
+        owner: UNASSIGNED_OWNER.to_string(),
+        votes: votes
+            .iter()
+            .filter(|vote| !in_blocs.contains(&vote.voter_id))
+            .cloned()
+            .collect(),
+    });
+    reports.retain(|report| !report.votes.is_empty());
+    reports
+}
+
+impl OwnerReport {
+    /// One line per vote, oldest first. The proof columns are empty for votes without receipt.
+    pub fn to_csv(&self, voter_name: impl Fn(&Identifier) -> String) -> String {
+        let mut csv = "date_utc,contest,choice,masternode,masternode_id,transition_hash,\
+                       platform_height,quorum_hash,receipt_file\n"
+            .to_string();
+        let mut votes: Vec<&ReportedVote> = self.votes.iter().collect();
+        votes.sort_by_key(|vote| vote.voted_at);
+        for vote in votes {
+            let date = DateTime::<Utc>::from_timestamp_millis(vote.voted_at as i64)
+                .map(|date| date.format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_default();
+            let receipt = vote.receipt.as_ref();
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{},{}",
+                date,
+                escape_csv(&vote.contest),
+                escape_csv(&vote.choice),
+                escape_csv(&voter_name(&vote.voter_id)),
+                vote.voter_id.to_string(Encoding::Base58),
+                receipt
+                    .map(|receipt| receipt.transition_hash.as_str())
+                    .unwrap_or_default(),
+                receipt
+                    .map(|receipt| receipt.metadata.height.to_string())
+                    .unwrap_or_default(),
+                receipt
+                    .map(|receipt| receipt.proof.quorum_hash.as_str())
+                    .unwrap_or_default(),
+                receipt.map(VoteProofReceipt::file_name).unwrap_or_default()
+            );
+        }
+        csv
+    }
+
+    /// Writes the CSV and the receipt files into a folder named after the owner. Returns the
+    /// folder.
+    pub fn write(
+        &self,
+        directory: &Path,
+        voter_name: impl Fn(&Identifier) -> String,
+    ) -> Result<std::path::PathBuf, String> {
+        let folder = directory.join(folder_name(&self.owner));
+        let write_error =
+            |path: &Path, e: std::io::Error| format!("Failed to write {}: {}", path.display(), e);
+        std::fs::create_dir_all(&folder).map_err(|e| write_error(&folder, e))?;
+        let csv_path = folder.join("votes.csv");
+        std::fs::write(&csv_path, self.to_csv(voter_name))
+            .map_err(|e| write_error(&csv_path, e))?;
+        for receipt in self.votes.iter().filter_map(|vote| vote.receipt.as_ref()) {
+            let path = folder.join(receipt.file_name());
+            std::fs::write(&path, receipt.to_json()).map_err(|e| write_error(&path, e))?;
+        }
+        Ok(folder)
+    }
+}
+
+/// The owner's name with characters that are not allowed in file names replaced
+fn folder_name(owner: &str) -> String {
+    let name = owner
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>()
+        .trim_start_matches('.')
+        .to_string();
+    if name.is_empty() {
+        "owner".to_string()
+    } else {
+        name
+    }
+}
@@ -0,0 +1,141 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use dash_sdk::dashcore_rpc::dashcore::signer;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::identity::signer::Signer;
+use dash_sdk::dpp::identity::{IdentityPublicKey, KeyID, KeyType};
+use serde::{Deserialize, Serialize};
+
+/// Name in the armor lines of identity proofs
+const PROOF_KIND: &str = "DASH IDENTITY PROOF";
+
+/// Signed before the claim, so a proof can never be mistaken for a signed transition
+const SIGNING_PREFIX: &[u8] = b"Dash identity ownership proof:\n";
+
+const BLOB_LINE_LENGTH: usize = 64;
+
+/// What the owner of the identity states. The signature covers the compact JSON of this
+/// struct, with the fields in this order, after the signing prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnershipClaim {
+    pub network: String,
+    /// Base58 id of the identity
+    pub identity_id: String,
+    pub key_id: KeyID,
+    pub message: String,
+    /// Unix time in seconds
+    pub signed_at: i64,
+}
+
+/// A message signed with one of an identity's keys, passed around as a text blob
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnershipProof {
+    #[serde(flatten)]
+    pub claim: OwnershipClaim,
+    /// Hex of the compact recoverable signature
+    pub signature: String,
+}
+
+impl OwnershipProof {
+    /// Whether proofs can be signed and checked with the key. Only plain ECDSA keys are, their
+    /// public key is on Platform to check against.
+    pub fn supports_key(public_key: &IdentityPublicKey) -> bool {
+        public_key.key_type() == KeyType::ECDSA_SECP256K1 && public_key.disabled_at().is_none()
+    }
+
+    pub fn sign(
+        claim: OwnershipClaim,
+        signer: &impl Signer,
+        public_key: &IdentityPublicKey,
+    ) -> Result<Self, String> {
+        if !Self::supports_key(public_key) {
+            return Err("Proofs are signed with enabled ECDSA keys only".to_string());
+        }
+        let signature = signer
+            .sign(public_key, &signing_bytes(&claim))
+            .map_err(|e| format!("Failed to sign the message: {}", e))?;
+        Ok(Self {
+            claim,
+            signature: hex::encode(signature.as_slice()),
+        })
+    }
+
+    /// Checks the signature against the key as it is on Platform now
+    pub fn verify(&self, public_key: &IdentityPublicKey) -> Result<(), String> {
+        if public_key.id() != self.claim.key_id {
+            return Err("The key is not the one the proof names".to_string());
+        }
+        if public_key.disabled_at().is_some() {
+            return Err(format!(
+                "Key {} of the identity is disabled, the proof no longer counts",
+                public_key.id()
+            ));
+        }
+        if public_key.key_type() != KeyType::ECDSA_SECP256K1 {
+            return Err(format!(
+                "Key {} is not an ECDSA key, proofs can't be signed with it",
+                public_key.id()
+            ));
+        }
+        let signature =
+            hex::decode(&self.signature).map_err(|_| "The signature is not hex".to_string())?;
+        signer::verify_data_signature(
+            &signing_bytes(&self.claim),
+            &signature,
+            public_key.data().as_slice(),
+        )
+        .map_err(|_| "The signature does not match the message and key".to_string())
+    }
+
+    /// The proof as armored base64, to paste into a chat or an email
+    pub fn to_blob(&self) -> String {
+        armor(
+            PROOF_KIND,
+            &serde_json::to_vec(self).expect("proofs serialize to JSON"),
+        )
+    }
+
+    /// Reads a blob, with or without its armor lines and however its lines were wrapped
+    pub fn from_blob(blob: &str) -> Result<Self, String> {
+        let json = dearmor(blob)?;
+        serde_json::from_slice(&json).map_err(|e| format!("Not a proof blob: {}", e))
+    }
+}
+
+/// Wraps the bytes in base64 lines between BEGIN and END lines naming the kind
+pub fn armor(kind: &str, bytes: &[u8]) -> String {
+    let encoded = STANDARD.encode(bytes);
+    let mut blob = format!("-----BEGIN {}-----\n", kind);
+    for line in encoded.as_bytes().chunks(BLOB_LINE_LENGTH) {
+        blob.push_str(std::str::from_utf8(line).expect("base64 is ascii"));
+        blob.push('\n');
+    }
+    blob.push_str(&format!("-----END {}-----", kind));
+    blob
+}
+
+/// The kind named by the BEGIN line of an armored blob, if it has one
+pub fn armor_kind(blob: &str) -> Option<&str> {
+    blob.lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix("-----BEGIN "))
+        .and_then(|line| line.strip_suffix("-----"))
+}
+
+/// The bytes of an armored blob, also accepted without its armor lines
+pub fn dearmor(blob: &str) -> Result<Vec<u8>, String> {
+    let encoded: String = blob
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+    STANDARD
+        .decode(encoded)
+        .map_err(|_| "Not a signed blob: the text isn't base64".to_string())
+}
+
+fn signing_bytes(claim: &OwnershipClaim) -> Vec<u8> {
+    let mut bytes = SIGNING_PREFIX.to_vec();
+    bytes.extend(serde_json::to_vec(claim).expect("claims serialize to JSON"));
+    bytes
+}
@@ -0,0 +1,115 @@
+use dash_sdk::dpp::fee::Credits;
+
+/// Scheme and path of the URIs, so they aren't mistaken for plain `dash:` payment URIs and
+/// open the tool like its other links
+const URI_PREFIX: &str = "dash-evo-tool://pay";
+
+/// Prefix of requests made before they were links, still accepted when pasted
+const LEGACY_URI_PREFIX: &str = "dash-evo-tool:pay";
+
+/// A request to be paid, shared as a URI or a QR code of it
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PaymentRequest {
+    /// Identity id in base58, or a Core address
+    pub recipient: String,
+    /// Amount in credits, `None` leaves it to the payer
+    pub amount: Option<Credits>,
+    pub memo: String,
+}
+
+impl PaymentRequest {
+    /// The URI, e.g. `dash-evo-tool://pay?to=<recipient>&amount=<credits>&memo=<memo>`
+    pub fn to_uri(&self) -> String {
+        let mut uri = format!("{}?to={}", URI_PREFIX, percent_encode(&self.recipient));
+        if let Some(amount) = self.amount {
+            uri.push_str(&format!("&amount={}", amount));
+        }
+        if !self.memo.is_empty() {
+            uri.push_str(&format!("&memo={}", percent_encode(&self.memo)));
+        }
+        uri
+    }
+
+    /// Parses a URI made by [`PaymentRequest::to_uri`]. Unknown parameters are ignored so
+    /// newer versions can add some.
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let uri = uri.trim();
+        let query = uri
+            .strip_prefix(URI_PREFIX)
+            .or_else(|| uri.strip_prefix(LEGACY_URI_PREFIX))
+            .ok_or(format!(
+                "Not a payment request, it should start with {}",
+                URI_PREFIX
+            ))?;
+        let query = query.strip_prefix('/').unwrap_or(query);
+        let query = query.strip_prefix('?').unwrap_or(query);
+        let mut request = PaymentRequest::default();
+        for parameter in query.split('&').filter(|parameter| !parameter.is_empty()) {
+            let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+            let value = percent_decode(value)?;
+            match name {
+                "to" => request.recipient = value,
+                "amount" => {
+                    request.amount = Some(
+                        value
+                            .parse::<Credits>()
+                            .map_err(|_| format!("Invalid amount {}", value))?,
+                    )
+                }
+                "memo" => request.memo = value,
+                _ => {}
+            }
+        }
+        if request.recipient.is_empty() {
+            return Err("The payment request has no recipient".to_string());
+        }
+        Ok(request)
+    }
+
+    /// Whether the recipient is an identity rather than an address. Identity ids are 32 bytes
+    /// in base58, addresses are shorter.
+    pub fn is_identity_recipient(&self) -> bool {
+        bs58::decode(&self.recipient)
+            .into_vec()
+            .is_ok_and(|bytes| bytes.len() == 32)
+    }
+}
+
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+fn percent_decode(text: &str) -> Result<String, String> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let byte = text
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or(format!("Invalid escape in {}", text))?;
+                decoded.push(byte);
+                i += 3;
+            }
+            b'+' => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| format!("Invalid text in {}", text))
+}
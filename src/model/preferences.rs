@@ -0,0 +1,329 @@
+use crate::model::app_profile::AppProfile;
+use crate::model::approval::ApprovalPolicy;
+use crate::model::auto_top_up::AutoTopUpRule;
+use crate::model::balance_alert::BalanceAlerts;
+use crate::model::contest_view::ContestTableViews;
+use crate::model::credit_operation::AccountingExport;
+use crate::model::explorer::ExplorerSettings;
+use crate::model::fee::FeeChoice;
+use crate::model::finality::FinalitySettings;
+use crate::model::junk_name::JunkNameRules;
+use crate::model::node_log::NodeLogSource;
+use crate::model::notification_sound::NotificationSounds;
+use crate::model::price_feed::PriceFeedSettings;
+use crate::model::proxy::ProxySettings;
+use crate::model::rate_limit::RateLimits;
+use crate::model::response_cache::CacheTtls;
+use crate::model::schedule::ScheduledTask;
+use crate::model::spending_limit::SpendingLimits;
+use crate::model::telemetry::TelemetrySettings;
+use crate::model::vote_broadcast::RedundantBroadcast;
+use crate::model::vote_webhook::VoteWebhookSettings;
+use crate::model::webhook::WebhookSettings;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// User interface preferences, stored in the database as JSON.
+///
+/// Every field has a default so that preferences written by an older version keep loading
+/// after new fields are added.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    /// Visible table columns keyed by screen, in display order
+    pub visible_columns: BTreeMap<String, Vec<String>>,
+    /// Saved filters, sorts and columns of the contested names table
+    pub contest_views: ContestTableViews,
+    /// How timestamps are displayed unless a column overrides it
+    pub time_display: TimeDisplayMode,
+    /// Time zone used for absolute timestamps
+    pub time_zone: TimeZoneSetting,
+    /// Per-column time display overrides, keyed by "screen.column"
+    pub column_time_display: BTreeMap<String, TimeDisplayMode>,
+    /// Digit grouping and decimal separators for vote counts and balances
+    pub number_format: NumberFormatSetting,
+    /// Where metrics for monitoring are exported, read on startup
+    pub metrics_export: MetricsExport,
+    /// Heuristics that flag likely abusive names in the contested names table
+    pub junk_name_rules: JunkNameRules,
+    /// Explorer web sites that shared links point to
+    pub explorer: ExplorerSettings,
+    /// Whether queries request and verify proofs, by task type
+    pub query_verification: QueryVerification,
+    /// Actions that need the approver's confirmation
+    pub approval_policy: ApprovalPolicy,
+    /// Daily caps on withdrawals and transfers per identity
+    pub spending_limits: SpendingLimits,
+    /// Offer to look up identifiers copied to the clipboard
+    pub clipboard_monitor: bool,
+    /// Hide identity ids, balances and keys for sharing screenshots
+    pub privacy_mode: bool,
+    /// Zoom of the interface and size of its text
+    pub display_scale: DisplayScale,
+    /// How tightly table rows are packed
+    pub table_density: TableDensity,
+    /// Sounds for watched contests, completed withdrawals, failed tasks and low balances
+    pub notification_sounds: NotificationSounds,
+    /// Minimum balances of identities, checked by the balance poller
+    pub balance_alerts: BalanceAlerts,
+    /// Identities kept above a balance by topping them up from a wallet
+    pub auto_top_up_rules: Vec<AutoTopUpRule>,
+    /// Currency and price source of the accounting export
+    pub accounting_export: AccountingExport,
+    /// Fiat values shown next to balances and fees
+    pub price_feed: PriceFeedSettings,
+    /// Tenderdash RPC addresses of evonodes keyed by their base58 id, e.g. http://1.2.3.4:26657
+    pub tenderdash_rpc_urls: BTreeMap<String, String>,
+    /// Logs of the local dashd, drive and dapi that can be tailed in the tool
+    pub node_logs: Vec<NodeLogSource>,
+    /// SOCKS5 proxy for outbound connections, e.g. Tor
+    pub proxy: ProxySettings,
+    /// Request rates and backoff after failures, by endpoint
+    pub rate_limits: RateLimits,
+    /// How long query results are reused when a screen is opened again
+    pub cache_ttls: CacheTtls,
+    /// Where a summary of each voting session is posted
+    pub vote_webhook: VoteWebhookSettings,
+    /// Whether votes near the end of a contest are sent through several DAPI nodes
+    pub redundant_vote_broadcast: RedundantBroadcast,
+    /// How many confirmations asset locks and withdrawals need before they count as final
+    pub finality: FinalitySettings,
+    /// Fee speed and coin selection funding transactions and withdrawals start with
+    pub fee_choice: FeeChoice,
+    /// Webhooks posted to when watched events happen
+    pub webhooks: WebhookSettings,
+    /// Anonymous usage statistics, off unless turned on
+    pub telemetry: TelemetrySettings,
+    /// Contracts shown as admin panels with their tables, on all networks
+    pub app_profiles: Vec<AppProfile>,
+    /// Tasks run at an interval while the tool is open, on all networks
+    pub scheduled_tasks: Vec<ScheduledTask>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimeDisplayMode {
+    /// "in 3 days"
+    Relative,
+    /// "2024-10-21 14:00:00 UTC"
+    Absolute,
+    /// Absolute followed by relative
+    #[default]
+    Both,
+}
+
+impl TimeDisplayMode {
+    pub const ALL: [TimeDisplayMode; 3] = [
+        TimeDisplayMode::Relative,
+        TimeDisplayMode::Absolute,
+        TimeDisplayMode::Both,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeDisplayMode::Relative => "Relative",
+            TimeDisplayMode::Absolute => "Absolute",
+            TimeDisplayMode::Both => "Absolute and relative",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimeZoneSetting {
+    Utc,
+    /// The time zone of the operating system
+    #[default]
+    Local,
+    /// A fixed offset from UTC in minutes
+    FixedOffset {
+        minutes: i32,
+    },
+}
+
+impl TimeZoneSetting {
+    pub fn label(&self) -> String {
+        match self {
+            TimeZoneSetting::Utc => "UTC".to_string(),
+            TimeZoneSetting::Local => "Local time".to_string(),
+            TimeZoneSetting::FixedOffset { minutes } => format!(
+                "UTC{}{:02}:{:02}",
+                if *minutes < 0 { '-' } else { '+' },
+                minutes.abs() / 60,
+                minutes.abs() % 60
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NumberFormatSetting {
+    /// Derived from the operating system locale
+    #[default]
+    System,
+    /// 1,234,567.89
+    CommaPeriod,
+    /// 1.234.567,89
+    PeriodComma,
+    /// 1 234 567,89
+    SpaceComma,
+    /// 1234567.89
+    Plain,
+}
+
+impl NumberFormatSetting {
+    pub const ALL: [NumberFormatSetting; 5] = [
+        NumberFormatSetting::System,
+        NumberFormatSetting::CommaPeriod,
+        NumberFormatSetting::PeriodComma,
+        NumberFormatSetting::SpaceComma,
+        NumberFormatSetting::Plain,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            NumberFormatSetting::System => "System locale",
+            NumberFormatSetting::CommaPeriod => "1,234,567.89",
+            NumberFormatSetting::PeriodComma => "1.234.567,89",
+            NumberFormatSetting::SpaceComma => "1 234 567,89",
+            NumberFormatSetting::Plain => "1234567.89",
+        }
+    }
+}
+
+/// Bounds of the interface zoom, on top of the scale factor of the operating system
+pub const MIN_UI_SCALE: f32 = 0.5;
+pub const MAX_UI_SCALE: f32 = 3.0;
+
+/// Zoom of the whole interface and the size of its text, for high-DPI displays and low vision
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayScale {
+    /// Multiplies the scale factor of the operating system
+    pub ui_scale: f32,
+    pub font_size: FontSizePreset,
+}
+
+impl Default for DisplayScale {
+    fn default() -> Self {
+        Self {
+            ui_scale: 1.0,
+            font_size: FontSizePreset::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FontSizePreset {
+    Small,
+    #[default]
+    Normal,
+    Large,
+    ExtraLarge,
+}
+
+impl FontSizePreset {
+    pub const ALL: [FontSizePreset; 4] = [
+        FontSizePreset::Small,
+        FontSizePreset::Normal,
+        FontSizePreset::Large,
+        FontSizePreset::ExtraLarge,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FontSizePreset::Small => "Small",
+            FontSizePreset::Normal => "Normal",
+            FontSizePreset::Large => "Large",
+            FontSizePreset::ExtraLarge => "Extra large",
+        }
+    }
+
+    /// Text sizes relative to egui's defaults
+    pub fn factor(&self) -> f32 {
+        match self {
+            FontSizePreset::Small => 0.85,
+            FontSizePreset::Normal => 1.0,
+            FontSizePreset::Large => 1.25,
+            FontSizePreset::ExtraLarge => 1.5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TableDensity {
+    #[default]
+    Comfortable,
+    /// Shorter rows, less padding and smaller text, to see more rows at once
+    Compact,
+}
+
+impl TableDensity {
+    pub const ALL: [TableDensity; 2] = [TableDensity::Comfortable, TableDensity::Compact];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TableDensity::Comfortable => "Comfortable",
+            TableDensity::Compact => "Compact",
+        }
+    }
+}
+
+/// Default port of the local metrics endpoint
+pub const DEFAULT_METRICS_PORT: u16 = 9464;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MetricsExport {
+    #[default]
+    Disabled,
+    /// Served in the Prometheus text format on localhost
+    Endpoint { port: u16 },
+    /// Written periodically to a file, e.g. for the node exporter's textfile collector
+    File { path: String },
+}
+
+impl MetricsExport {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MetricsExport::Disabled => "Disabled",
+            MetricsExport::Endpoint { .. } => "Local HTTP endpoint",
+            MetricsExport::File { .. } => "File",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VerificationMode {
+    /// Proofs are requested and verified against the quorum signatures
+    #[default]
+    Verified,
+    /// Responses are trusted as the DAPI node sent them, without proofs
+    Fast,
+}
+
+impl VerificationMode {
+    pub const ALL: [VerificationMode; 2] = [VerificationMode::Verified, VerificationMode::Fast];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            VerificationMode::Verified => "Verified",
+            VerificationMode::Fast => "Fast (unproved)",
+        }
+    }
+}
+
+/// Verification of queries. State transitions are always verified, whatever the settings.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QueryVerification {
+    pub default_mode: VerificationMode,
+    /// Overrides of the default keyed by task kind, e.g. "identity" or "document"
+    pub task_modes: BTreeMap<String, VerificationMode>,
+}
+
+impl QueryVerification {
+    pub fn mode_for(&self, task_kind: &str) -> VerificationMode {
+        self.task_modes
+            .get(task_kind)
+            .copied()
+            .unwrap_or(self.default_mode)
+    }
+}
@@ -0,0 +1,135 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+use std::time::Duration;
+
+/// The last known price of DASH, shared by the contexts of all networks
+static FIAT_PRICE: RwLock<Option<FiatPrice>> = RwLock::new(None);
+
+/// Where the price of DASH is fetched from
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PriceProvider {
+    #[default]
+    CoinGecko,
+    /// Any endpoint answering with JSON. `{currency}` in the URL and the path is replaced with
+    /// the lowercase currency code.
+    Custom {
+        url: String,
+        /// Dot separated keys leading to the price, e.g. `data.rates.{currency}`
+        json_path: String,
+    },
+}
+
+impl PriceProvider {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PriceProvider::CoinGecko => "CoinGecko",
+            PriceProvider::Custom { .. } => "Custom endpoint",
+        }
+    }
+
+    /// The URL to request and the path of the price in the answer
+    pub fn request(&self, currency: &str) -> (String, String) {
+        let currency = currency.trim().to_lowercase();
+        match self {
+            PriceProvider::CoinGecko => (
+                format!(
+                    "https://api.coingecko.com/api/v3/simple/price?ids=dash&vs_currencies={}",
+                    currency
+                ),
+                format!("dash.{}", currency),
+            ),
+            PriceProvider::Custom { url, json_path } => (
+                url.replace("{currency}", &currency),
+                json_path.replace("{currency}", &currency),
+            ),
+        }
+    }
+}
+
+/// Approximate fiat values next to balances and fees, from a price fetched periodically
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PriceFeedSettings {
+    pub enabled: bool,
+    pub provider: PriceProvider,
+    /// Code of the fiat currency, e.g. USD
+    pub currency: String,
+    pub refresh_minutes: u32,
+}
+
+impl Default for PriceFeedSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: PriceProvider::CoinGecko,
+            currency: "USD".to_string(),
+            refresh_minutes: 15,
+        }
+    }
+}
+
+impl PriceFeedSettings {
+    pub fn refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.refresh_minutes.max(1) as u64 * 60)
+    }
+
+    /// The last known price in the configured currency, also when it could not be refreshed
+    /// lately
+    pub fn current_price(&self) -> Option<FiatPrice> {
+        if !self.enabled {
+            return None;
+        }
+        fiat_price().filter(|price| price.currency.eq_ignore_ascii_case(self.currency.trim()))
+    }
+}
+
+/// A price of DASH in a fiat currency
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiatPrice {
+    /// Uppercase currency code
+    pub currency: String,
+    /// Price of one DASH
+    pub price: f64,
+    /// Seconds since the Unix epoch
+    pub fetched_at: i64,
+}
+
+impl FiatPrice {
+    pub fn fetched_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.fetched_at, 0).unwrap_or_default()
+    }
+
+    /// Whether the price missed two refreshes, e.g. because the provider is unreachable
+    pub fn is_stale(&self, settings: &PriceFeedSettings) -> bool {
+        let age = Utc::now().timestamp() - self.fetched_at;
+        age > 2 * settings.refresh_interval().as_secs() as i64
+    }
+}
+
+/// The last known price of DASH
+pub fn fiat_price() -> Option<FiatPrice> {
+    FIAT_PRICE.read().unwrap().clone()
+}
+
+pub fn set_fiat_price(price: FiatPrice) {
+    *FIAT_PRICE.write().unwrap() = Some(price);
+}
+
+/// Follows the dot separated keys of `path` through a JSON answer to a price. Prices given as
+/// strings are accepted too.
+pub fn extract_price(json: &serde_json::Value, path: &str) -> Option<f64> {
+    let value = path
+        .split('.')
+        .filter(|key| !key.is_empty())
+        .try_fold(json, |value, key| match value {
+            serde_json::Value::Array(items) => items.get(key.parse::<usize>().ok()?),
+            _ => value.get(key),
+        })?;
+    let price = match value {
+        serde_json::Value::Number(number) => number.as_f64()?,
+        serde_json::Value::String(text) => text.trim().parse().ok()?,
+        _ => return None,
+    };
+    (price.is_finite() && price > 0.0).then_some(price)
+}
@@ -0,0 +1,66 @@
+use dash_sdk::dpp::dashcore::Network;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// A SOCKS5 proxy such as Tor that outbound connections of the chosen networks go through
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProxySettings {
+    /// `socks5h://host:port`, host names are then resolved by the proxy
+    pub url: String,
+    /// Networks whose connections use the proxy, e.g. "dash" and "testnet"
+    pub networks: BTreeSet<String>,
+    /// DAPI requests go out directly on those networks instead of being refused, the Platform
+    /// SDK can't connect through a proxy
+    pub dapi_direct: bool,
+}
+
+impl Default for ProxySettings {
+    fn default() -> Self {
+        Self {
+            // Tor's SOCKS port
+            url: "socks5h://127.0.0.1:9050".to_string(),
+            networks: BTreeSet::new(),
+            dapi_direct: false,
+        }
+    }
+}
+
+impl ProxySettings {
+    pub fn enabled_for(&self, network: Network) -> bool {
+        self.networks.contains(&network.to_string())
+    }
+
+    /// Whether DAPI requests of the network are refused: the proxy is on for it and direct
+    /// DAPI connections were not allowed
+    pub fn refuses_dapi(&self, network: Network) -> bool {
+        self.enabled_for(network) && !self.dapi_direct
+    }
+
+    /// The proxy URL, `None` when the network's connections go out directly
+    pub fn url_for(&self, network: Network) -> Option<&str> {
+        self.enabled_for(network).then_some(self.url.trim())
+    }
+
+    /// Host and port of the proxy
+    pub fn address(&self) -> Result<(String, u16), String> {
+        let url = self.url.trim();
+        let rest = url
+            .strip_prefix("socks5h://")
+            .or_else(|| url.strip_prefix("socks5://"))
+            .ok_or(format!("{} is not a socks5:// or socks5h:// URL", url))?;
+        let (host, port) = rest
+            .trim_end_matches('/')
+            .rsplit_once(':')
+            .ok_or(format!("{} has no port", url))?;
+        let port = port
+            .parse()
+            .map_err(|_| format!("{} is not a valid port", port))?;
+        Ok((
+            host.trim_start_matches('[')
+                .trim_end_matches(']')
+                .to_string(),
+            port,
+        ))
+    }
+}
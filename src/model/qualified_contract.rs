@@ -1,3 +1,5 @@
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::identity::TimestampMillis;
 use dash_sdk::platform::DataContract;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -5,3 +7,59 @@ pub struct QualifiedContract {
     pub contract: DataContract,
     pub alias: Option<String>,
 }
+
+/// Where a known contract comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractKind {
+    Dpns,
+    Dashpay,
+    Withdrawals,
+    /// Added by the user
+    Custom,
+}
+
+impl ContractKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContractKind::Dpns => "dpns",
+            ContractKind::Dashpay => "dashpay",
+            ContractKind::Withdrawals => "withdrawals",
+            ContractKind::Custom => "custom",
+        }
+    }
+
+    pub fn from_str(kind: &str) -> Self {
+        match kind {
+            "dpns" => ContractKind::Dpns,
+            "dashpay" => ContractKind::Dashpay,
+            "withdrawals" => ContractKind::Withdrawals,
+            _ => ContractKind::Custom,
+        }
+    }
+
+    pub fn is_system(&self) -> bool {
+        *self != ContractKind::Custom
+    }
+}
+
+/// A contract from the known contracts registry, with the result of the last on-chain check
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisteredContract {
+    pub qualified_contract: QualifiedContract,
+    pub kind: ContractKind,
+    /// Version of the contract on Platform when it was last checked
+    pub onchain_version: Option<u32>,
+    pub last_checked: Option<TimestampMillis>,
+}
+
+impl RegisteredContract {
+    pub fn cached_version(&self) -> u32 {
+        self.qualified_contract.contract.version()
+    }
+
+    /// Whether Platform has a newer version than the cached schema
+    pub fn is_outdated(&self) -> bool {
+        self.onchain_version
+            .is_some_and(|onchain_version| onchain_version > self.cached_version())
+    }
+}
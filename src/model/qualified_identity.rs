@@ -48,6 +48,58 @@ impl Display for IdentityType {
     }
 }
 
+/// The role an identity is grouped under on the identities screen. Unless the user moved it
+/// to another group it follows from the identity type and the keys we hold.
+#[derive(Debug, Clone, Copy, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum IdentityGroup {
+    User,
+    MasternodeVoting,
+    Evonode,
+    WatchOnly,
+}
+
+impl IdentityGroup {
+    pub const ALL: [IdentityGroup; 4] = [
+        IdentityGroup::User,
+        IdentityGroup::MasternodeVoting,
+        IdentityGroup::Evonode,
+        IdentityGroup::WatchOnly,
+    ];
+
+    /// Name stored in the database
+    pub fn id(&self) -> &'static str {
+        match self {
+            IdentityGroup::User => "user",
+            IdentityGroup::MasternodeVoting => "masternode_voting",
+            IdentityGroup::Evonode => "evonode",
+            IdentityGroup::WatchOnly => "watch_only",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|group| group.id() == id)
+    }
+
+    /// Whether the members of the group are expected to vote on contested resources
+    pub fn is_voting(&self) -> bool {
+        matches!(
+            self,
+            IdentityGroup::MasternodeVoting | IdentityGroup::Evonode
+        )
+    }
+}
+
+impl Display for IdentityGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdentityGroup::User => write!(f, "Users"),
+            IdentityGroup::MasternodeVoting => write!(f, "Masternode Voting"),
+            IdentityGroup::Evonode => write!(f, "Evonodes"),
+            IdentityGroup::WatchOnly => write!(f, "Watch-only"),
+        }
+    }
+}
+
 #[derive(Debug, Encode, Decode, Clone, Hash, Ord, PartialOrd, Eq, PartialEq)]
 pub enum EncryptedPrivateKeyTarget {
     PrivateKeyOnMainIdentity,
@@ -158,6 +210,18 @@ impl QualifiedIdentity {
             .unwrap_or(self.identity.id().to_string(Encoding::Base58))
     }
 
+    /// The group the identity belongs to when the user has not moved it elsewhere
+    pub fn default_group(&self) -> IdentityGroup {
+        if self.encrypted_private_keys.is_empty() {
+            return IdentityGroup::WatchOnly;
+        }
+        match self.identity_type {
+            IdentityType::User => IdentityGroup::User,
+            IdentityType::Masternode => IdentityGroup::MasternodeVoting,
+            IdentityType::Evonode => IdentityGroup::Evonode,
+        }
+    }
+
     pub fn display_short_string(&self) -> String {
         self.alias.clone().unwrap_or_else(|| {
             let id_str = self.identity.id().to_string(Encoding::Base58);
@@ -247,6 +311,34 @@ impl QualifiedIdentity {
         keys
     }
 
+    /// Keys of the identity that can sign withdrawals, whether or not their private keys are
+    /// loaded, e.g. when they are on an offline machine
+    pub fn withdrawal_public_keys(&self) -> Vec<&IdentityPublicKey> {
+        self.identity
+            .public_keys()
+            .values()
+            .filter(|key| match self.identity_type {
+                IdentityType::User => key.purpose() == Purpose::TRANSFER,
+                IdentityType::Masternode | IdentityType::Evonode => {
+                    matches!(key.purpose(), Purpose::TRANSFER | Purpose::OWNER)
+                }
+            })
+            .collect()
+    }
+
+    /// An enabled ECDSA key of the purpose whose private key is loaded, with the private key.
+    /// Memos are encrypted with an ENCRYPTION key and decrypted with a DECRYPTION key.
+    pub fn ecdsa_private_key(&self, purpose: Purpose) -> Option<(&IdentityPublicKey, &[u8; 32])> {
+        self.encrypted_private_keys
+            .values()
+            .find(|(public_key, _)| {
+                public_key.purpose() == purpose
+                    && public_key.key_type() == KeyType::ECDSA_SECP256K1
+                    && public_key.disabled_at().is_none()
+            })
+            .map(|(public_key, private_key)| (public_key, private_key))
+    }
+
     pub fn available_transfer_keys(&self) -> Vec<&IdentityPublicKey> {
         let mut keys = vec![];
 
@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The services backend tasks talk to, each with its own rate limit and backoff
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    /// DAPI nodes of Platform, often run by community members
+    Dapi,
+    /// The Dash Core RPC interface
+    CoreRpc,
+    /// Price feeds, Tenderdash RPC and other HTTP services
+    Http,
+}
+
+impl Endpoint {
+    pub const ALL: [Endpoint; 3] = [Endpoint::Dapi, Endpoint::CoreRpc, Endpoint::Http];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Endpoint::Dapi => "DAPI",
+            Endpoint::CoreRpc => "Core RPC",
+            Endpoint::Http => "HTTP services",
+        }
+    }
+}
+
+/// How fast requests to an endpoint may be made and how long to back off after failures
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EndpointPolicy {
+    /// Tasks started per minute, 0 for no limit. Bulk operations count each item.
+    pub max_per_minute: u32,
+    /// Pause after the first failure, doubled by the multiplier after each further one
+    pub initial_backoff_ms: u64,
+    pub backoff_multiplier: f64,
+    pub max_backoff_ms: u64,
+    /// How often a failed query is retried, changes to Platform are never retried
+    pub max_retries: u32,
+}
+
+impl Default for EndpointPolicy {
+    fn default() -> Self {
+        Self {
+            max_per_minute: 0,
+            initial_backoff_ms: 1000,
+            backoff_multiplier: 2.0,
+            max_backoff_ms: 60_000,
+            max_retries: 2,
+        }
+    }
+}
+
+impl EndpointPolicy {
+    /// Pause after the given number of failures in a row
+    pub fn backoff(&self, failures: u32) -> Duration {
+        if failures == 0 {
+            return Duration::ZERO;
+        }
+        let exponent = (failures - 1).min(30) as i32;
+        let millis =
+            self.initial_backoff_ms as f64 * self.backoff_multiplier.max(1.0).powi(exponent);
+        Duration::from_millis(millis.min(self.max_backoff_ms as f64) as u64)
+    }
+
+    /// Time between two tasks, zero without a limit
+    pub fn interval(&self) -> Duration {
+        if self.max_per_minute == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs(60) / self.max_per_minute
+        }
+    }
+}
+
+/// Rate limits and backoff of each endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RateLimits {
+    pub dapi: EndpointPolicy,
+    pub core_rpc: EndpointPolicy,
+    pub http: EndpointPolicy,
+}
+
+impl Default for RateLimits {
+    fn default() -> Self {
+        Self {
+            // Gentle on community-run nodes during bulk operations
+            dapi: EndpointPolicy {
+                max_per_minute: 120,
+                ..EndpointPolicy::default()
+            },
+            core_rpc: EndpointPolicy::default(),
+            http: EndpointPolicy {
+                max_per_minute: 30,
+                ..EndpointPolicy::default()
+            },
+        }
+    }
+}
+
+impl RateLimits {
+    pub fn policy(&self, endpoint: Endpoint) -> &EndpointPolicy {
+        match endpoint {
+            Endpoint::Dapi => &self.dapi,
+            Endpoint::CoreRpc => &self.core_rpc,
+            Endpoint::Http => &self.http,
+        }
+    }
+
+    pub fn policy_mut(&mut self, endpoint: Endpoint) -> &mut EndpointPolicy {
+        match endpoint {
+            Endpoint::Dapi => &mut self.dapi,
+            Endpoint::CoreRpc => &mut self.core_rpc,
+            Endpoint::Http => &mut self.http,
+        }
+    }
+}
+
+/// Whether an error looks like an overloaded or unreachable endpoint rather than a rejected
+/// request, so retrying later can help
+pub fn is_transient_error(error: &str) -> bool {
+    let error = error.to_lowercase();
+    [
+        "timeout",
+        "timed out",
+        "unavailable",
+        "connection",
+        "too many requests",
+        "resource exhausted",
+        "resourceexhausted",
+        "no available addresses",
+    ]
+    .iter()
+    .any(|pattern| error.contains(pattern))
+}
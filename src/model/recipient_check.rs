@@ -0,0 +1,67 @@
+/// Characters at the start and the end that poisoned addresses usually copy from the real one
+const MATCHING_ENDS: usize = 4;
+
+/// A recipient the user knows, either a contact, a local identity or an address used before
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnownRecipient {
+    pub recipient: String,
+    /// Where the recipient is known from, e.g. "contact alice"
+    pub source: String,
+}
+
+/// A known recipient that the entered one resembles without being equal to it
+#[derive(Debug, Clone, PartialEq)]
+pub struct LookalikeRecipient {
+    pub entered: String,
+    pub known: KnownRecipient,
+    pub distance: usize,
+}
+
+/// Levenshtein distance between two strings, counted in characters
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Whether two different recipients look alike, either because only a few characters differ
+/// or because they share the start and the end that people usually compare
+fn looks_alike(entered: &str, known: &str, distance: usize) -> bool {
+    let max_distance = (known.chars().count() / 10).max(2);
+    if distance <= max_distance {
+        return true;
+    }
+    let entered: Vec<char> = entered.chars().collect();
+    let known: Vec<char> = known.chars().collect();
+    entered.len() > MATCHING_ENDS * 2
+        && known.len() > MATCHING_ENDS * 2
+        && entered[..MATCHING_ENDS] == known[..MATCHING_ENDS]
+        && entered[entered.len() - MATCHING_ENDS..] == known[known.len() - MATCHING_ENDS..]
+}
+
+/// The known recipient closest to the entered one when they look alike but differ.
+/// Returns `None` when the entered recipient is itself known or resembles nothing.
+pub fn find_lookalike(entered: &str, known: &[KnownRecipient]) -> Option<LookalikeRecipient> {
+    let entered = entered.trim();
+    if entered.is_empty() || known.iter().any(|known| known.recipient == entered) {
+        return None;
+    }
+    known
+        .iter()
+        .map(|known| (known, edit_distance(entered, &known.recipient)))
+        .filter(|(known, distance)| looks_alike(entered, &known.recipient, *distance))
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(known, distance)| LookalikeRecipient {
+            entered: entered.to_string(),
+            known: known.clone(),
+            distance,
+        })
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Kinds of queries whose results are cached, each kept for its own time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueryClass {
+    /// Data contracts, which rarely change
+    Contracts,
+    Documents,
+    /// DPNS name searches and availability checks
+    Names,
+    /// Contested names and their contenders
+    Contests,
+    /// Identities with their balances and keys
+    Identities,
+    Withdrawals,
+}
+
+impl QueryClass {
+    pub const ALL: [QueryClass; 6] = [
+        QueryClass::Contracts,
+        QueryClass::Documents,
+        QueryClass::Names,
+        QueryClass::Contests,
+        QueryClass::Identities,
+        QueryClass::Withdrawals,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            QueryClass::Contracts => "Contracts",
+            QueryClass::Documents => "Documents",
+            QueryClass::Names => "DPNS names",
+            QueryClass::Contests => "Contested names",
+            QueryClass::Identities => "Identities and balances",
+            QueryClass::Withdrawals => "Withdrawals",
+        }
+    }
+}
+
+/// How many seconds a cached result of each query class stays fresh, 0 to not cache it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheTtls {
+    pub contracts_secs: u64,
+    pub documents_secs: u64,
+    pub names_secs: u64,
+    pub contests_secs: u64,
+    pub identities_secs: u64,
+    pub withdrawals_secs: u64,
+}
+
+impl Default for CacheTtls {
+    fn default() -> Self {
+        Self {
+            contracts_secs: 60 * 60,
+            documents_secs: 60,
+            names_secs: 5 * 60,
+            contests_secs: 60,
+            identities_secs: 15,
+            withdrawals_secs: 30,
+        }
+    }
+}
+
+impl CacheTtls {
+    pub fn ttl(&self, class: QueryClass) -> Duration {
+        let secs = match class {
+            QueryClass::Contracts => self.contracts_secs,
+            QueryClass::Documents => self.documents_secs,
+            QueryClass::Names => self.names_secs,
+            QueryClass::Contests => self.contests_secs,
+            QueryClass::Identities => self.identities_secs,
+            QueryClass::Withdrawals => self.withdrawals_secs,
+        };
+        Duration::from_secs(secs)
+    }
+
+    pub fn secs_mut(&mut self, class: QueryClass) -> &mut u64 {
+        match class {
+            QueryClass::Contracts => &mut self.contracts_secs,
+            QueryClass::Documents => &mut self.documents_secs,
+            QueryClass::Names => &mut self.names_secs,
+            QueryClass::Contests => &mut self.contests_secs,
+            QueryClass::Identities => &mut self.identities_secs,
+            QueryClass::Withdrawals => &mut self.withdrawals_secs,
+        }
+    }
+}
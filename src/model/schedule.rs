@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+/// Intervals offered when scheduling a task, in minutes with their labels
+pub const INTERVAL_PRESETS: [(u32, &str); 5] = [
+    (15, "Every 15 minutes"),
+    (60, "Hourly"),
+    (6 * 60, "Every 6 hours"),
+    (24 * 60, "Daily"),
+    (7 * 24 * 60, "Weekly"),
+];
+
+/// Shortest interval a task can be scheduled at, so a typo can't flood the network
+pub const MIN_INTERVAL_MINUTES: u32 = 5;
+
+/// What a scheduled task does when it runs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledJob {
+    /// Queries the ongoing contests and their vote counts
+    RefreshContests,
+    /// Refreshes the balances and keys of every local identity
+    RefreshBalances,
+    /// Snapshots every document type of the contract, by base58 id
+    SnapshotContract { contract_id: String },
+    /// Compares the cached contracts with their versions on Platform
+    CheckContractUpdates,
+}
+
+impl ScheduledJob {
+    pub fn label(&self) -> String {
+        match self {
+            ScheduledJob::RefreshContests => "Refresh contests".to_string(),
+            ScheduledJob::RefreshBalances => "Refresh identity balances".to_string(),
+            ScheduledJob::SnapshotContract { contract_id } => {
+                format!("Snapshot contract {}", contract_id)
+            }
+            ScheduledJob::CheckContractUpdates => "Check contracts for updates".to_string(),
+        }
+    }
+}
+
+/// A task run every `interval_minutes` while the tool is open
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledTask {
+    pub id: u32,
+    pub job: ScheduledJob,
+    pub interval_minutes: u32,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// The network the task runs on, as in the database
+    pub network: String,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl ScheduledTask {
+    /// Whether the task should run, given when it last started. A task that never ran is due
+    /// right away.
+    pub fn is_due(&self, last_started_at: Option<i64>, now: i64) -> bool {
+        self.enabled
+            && last_started_at.map_or(true, |started_at| {
+                now - started_at >= self.interval_minutes.max(MIN_INTERVAL_MINUTES) as i64 * 60
+            })
+    }
+
+    /// How the interval is shown, the preset's label when it is one
+    pub fn interval_label(&self) -> String {
+        interval_label(self.interval_minutes)
+    }
+}
+
+pub fn interval_label(minutes: u32) -> String {
+    match INTERVAL_PRESETS
+        .iter()
+        .find(|(preset, _)| *preset == minutes)
+    {
+        Some((_, label)) => label.to_string(),
+        None if minutes % 60 == 0 => format!("Every {} hours", minutes / 60),
+        None => format!("Every {} minutes", minutes),
+    }
+}
+
+/// The last run of a scheduled task. A run without `finished_at` is still going.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledRun {
+    pub task_id: u32,
+    /// Unix time in seconds
+    pub started_at: i64,
+    pub finished_at: Option<i64>,
+    /// Why the run failed, `None` when it succeeded
+    pub error: Option<String>,
+}
+
+impl ScheduledRun {
+    pub fn is_running(&self) -> bool {
+        self.finished_at.is_none()
+    }
+}
@@ -0,0 +1,164 @@
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dash_sdk::platform::Identifier;
+use serde::{Deserialize, Serialize};
+
+/// A screen of the stack as saved between runs, with the input of its form when it has one.
+/// Private keys typed into forms are never saved.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SavedScreen {
+    AddNewIdentity,
+    AddExistingIdentity,
+    AddNewWallet,
+    ImportMasternodes,
+    ImportIdentitiesCsv,
+    IdentityDetail {
+        identity_id: String,
+    },
+    KeyUsage {
+        identity_id: String,
+    },
+    Keys {
+        identity_id: String,
+    },
+    AddKey {
+        identity_id: String,
+    },
+    KeyRotation {
+        identity_id: String,
+    },
+    Withdrawal {
+        identity_id: String,
+        amount: String,
+        address: String,
+    },
+    Transfer {
+        identity_id: String,
+        amount: String,
+        address: String,
+    },
+    TopUpIdentity {
+        identity_id: String,
+        amount: String,
+    },
+    RegisterDpnsName {
+        identity_id: Option<String>,
+        name: String,
+    },
+    DpnsLookup {
+        pattern: String,
+        candidates: String,
+    },
+    WithdrawalQueue,
+    MasternodePayouts,
+    MasternodeDashboard,
+    AccountingExport,
+    Diagnostics,
+    NodeLogs,
+    VotingBlocs,
+    VoteLists,
+    VoteReceipts,
+    ImportDocuments {
+        contract_id: String,
+        document_type: String,
+    },
+    DeleteDocuments {
+        contract_id: String,
+        document_type: String,
+    },
+    ContractAnalytics {
+        contract_id: String,
+    },
+    ColdSigning,
+    OwnershipProof,
+    AppProfiles,
+    AppProfile {
+        name: String,
+    },
+    Scheduler,
+    TransitionSandbox,
+    Settings,
+}
+
+/// A vote choice in a form that can be saved, see [`ResourceVoteChoice`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SavedVoteChoice {
+    TowardsIdentity(String),
+    Abstain,
+    Lock,
+}
+
+impl From<&ResourceVoteChoice> for SavedVoteChoice {
+    fn from(choice: &ResourceVoteChoice) -> Self {
+        match choice {
+            ResourceVoteChoice::TowardsIdentity(identity_id) => {
+                SavedVoteChoice::TowardsIdentity(identity_id.to_string(Encoding::Base58))
+            }
+            ResourceVoteChoice::Abstain => SavedVoteChoice::Abstain,
+            ResourceVoteChoice::Lock => SavedVoteChoice::Lock,
+        }
+    }
+}
+
+impl SavedVoteChoice {
+    pub fn to_vote_choice(&self) -> Option<ResourceVoteChoice> {
+        match self {
+            SavedVoteChoice::TowardsIdentity(identity_id) => {
+                Identifier::from_string(identity_id, Encoding::Base58)
+                    .ok()
+                    .map(ResourceVoteChoice::TowardsIdentity)
+            }
+            SavedVoteChoice::Abstain => Some(ResourceVoteChoice::Abstain),
+            SavedVoteChoice::Lock => Some(ResourceVoteChoice::Lock),
+        }
+    }
+}
+
+/// Votes prepared on the contested names screen but not sent yet
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedVotes {
+    /// Contests ticked for a bulk vote, by normalized name
+    pub selected_contests: Vec<String>,
+    /// The vote waiting in the confirmation popup, with its message
+    pub pending_vote: Option<(String, Vec<(String, SavedVoteChoice)>)>,
+}
+
+impl SavedVotes {
+    pub fn is_empty(&self) -> bool {
+        self.selected_contests.is_empty() && self.pending_vote.is_none()
+    }
+}
+
+/// What was open when the tool last ran on a network, offered for restoring on the next launch
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    /// The screen stack above the root screen, bottom first
+    pub screens: Vec<SavedScreen>,
+    pub votes: SavedVotes,
+}
+
+impl Session {
+    pub fn is_empty(&self) -> bool {
+        self.screens.is_empty() && self.votes.is_empty()
+    }
+
+    /// What the session holds, e.g. "2 screens and 3 selected contests"
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.screens.is_empty() {
+            parts.push(format!("{} open screens", self.screens.len()));
+        }
+        if !self.votes.selected_contests.is_empty() {
+            parts.push(format!(
+                "{} contests selected for voting",
+                self.votes.selected_contests.len()
+            ));
+        }
+        if self.votes.pending_vote.is_some() {
+            parts.push("an unsent vote".to_string());
+        }
+        parts.join(", ")
+    }
+}
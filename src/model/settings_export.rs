@@ -0,0 +1,170 @@
+use crate::config::Config;
+use crate::model::preferences::Preferences;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use toml_edit::{DocumentMut, Item};
+
+/// Version of the settings file format this build writes and reads
+pub const SETTINGS_EXPORT_VERSION: u32 = 1;
+
+/// Keys of a network in the configuration file that are never exported. Imports keep the
+/// values the machine already has.
+const SECRET_CONFIG_KEYS: [&str; 2] = ["core_rpc_password", "wallet_private_key"];
+
+/// Put in place of values left out of a support bundle
+const REDACTED: &str = "<redacted>";
+
+/// Sections of the configuration file holding a network
+const NETWORK_SECTIONS: [&str; 3] = ["mainnet", "testnet", "devnet"];
+
+/// The settings of a profile as a JSON file, to configure other machines the same way.
+///
+/// Holds the preferences and the network sections of the configuration file without RPC
+/// passwords and wallet keys. Identities, wallets and keys are not part of it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsExport {
+    pub version: u32,
+    /// Unix time in seconds
+    pub exported_at: i64,
+    pub preferences: Preferences,
+    /// Network sections of the configuration file, keyed by section name
+    #[serde(default)]
+    pub networks: BTreeMap<String, toml::Table>,
+}
+
+impl SettingsExport {
+    /// Collects the preferences and the networks as written in the configuration file, so
+    /// environment variable overrides of this machine are left out
+    pub fn collect(preferences: Preferences, config_path: &Path) -> Result<Self, String> {
+        let config = read_config_table(config_path)?;
+        let networks = NETWORK_SECTIONS
+            .iter()
+            .filter_map(|section| {
+                let mut network = config.get(*section)?.as_table()?.clone();
+                for key in SECRET_CONFIG_KEYS {
+                    network.remove(key);
+                }
+                Some((section.to_string(), network))
+            })
+            .collect();
+        Ok(Self {
+            version: SETTINGS_EXPORT_VERSION,
+            exported_at: chrono::Utc::now().timestamp(),
+            preferences,
+            networks,
+        })
+    }
+
+    /// Blanks what shouldn't be posted with a support request: webhook and proxy URLs, which
+    /// can hold tokens or credentials, and the RPC addresses of the user's evonodes
+    pub fn redact_for_support(&mut self) {
+        let redact = |url: &mut String| {
+            if !url.is_empty() {
+                *url = REDACTED.to_string();
+            }
+        };
+        let preferences = &mut self.preferences;
+        preferences
+            .webhooks
+            .webhooks
+            .iter_mut()
+            .for_each(|webhook| redact(&mut webhook.url));
+        redact(&mut preferences.vote_webhook.url);
+        redact(&mut preferences.proxy.url);
+        preferences
+            .tenderdash_rpc_urls
+            .values_mut()
+            .for_each(|url| redact(url));
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("settings serialize to JSON")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let export: Self =
+            serde_json::from_str(json).map_err(|e| format!("Not a settings file: {}", e))?;
+        if export.version != SETTINGS_EXPORT_VERSION {
+            return Err(format!(
+                "The settings file has version {}, but this build only reads version {}",
+                export.version, SETTINGS_EXPORT_VERSION
+            ));
+        }
+        if let Some(section) = export
+            .networks
+            .keys()
+            .find(|section| !NETWORK_SECTIONS.contains(&section.as_str()))
+        {
+            return Err(format!(
+                "The settings file has an unknown network {}",
+                section
+            ));
+        }
+        Ok(export)
+    }
+
+    /// Writes the networks into the configuration file in place, keeping its comments, its
+    /// layout and the secrets it already has. The file is checked before it is written and
+    /// takes effect after a restart. Returns the sections written.
+    pub fn apply_networks(&self, config_path: &Path) -> Result<Vec<String>, String> {
+        if self.networks.is_empty() {
+            return Ok(vec![]);
+        }
+        let path_display = config_path.display().to_string();
+        let config = read_config_table(config_path)?;
+        let mut document = std::fs::read_to_string(config_path)
+            .map_err(|e| format!("Failed to read {}: {}", path_display, e))?
+            .parse::<DocumentMut>()
+            .map_err(|e| format!("Invalid {}: {}", path_display, e))?;
+        for (section, imported) in &self.networks {
+            let current = config.get(section).and_then(toml::Value::as_table);
+            let table = document
+                .entry(section)
+                .or_insert(toml_edit::table())
+                .as_table_mut()
+                .ok_or(format!("[{}] of {} is not a table", section, path_display))?;
+            let stale: Vec<String> = table
+                .iter()
+                .map(|(key, _)| key.to_string())
+                .filter(|key| {
+                    !imported.contains_key(key) && !SECRET_CONFIG_KEYS.contains(&key.as_str())
+                })
+                .collect();
+            for key in stale {
+                table.remove(&key);
+            }
+            for (key, value) in imported {
+                // Unchanged values keep their formatting, like arrays written over several lines
+                if SECRET_CONFIG_KEYS.contains(&key.as_str())
+                    || current.and_then(|current| current.get(key)) == Some(value)
+                {
+                    continue;
+                }
+                let mut new_value = value
+                    .to_string()
+                    .parse::<toml_edit::Value>()
+                    .map_err(|e| format!("Invalid value for {}.{}: {}", section, key, e))?;
+                if let Some(old_value) = table.get(key).and_then(Item::as_value) {
+                    *new_value.decor_mut() = old_value.decor().clone();
+                }
+                // Indexing keeps the comments above the key
+                table[key.as_str()] = toml_edit::value(new_value);
+            }
+        }
+
+        let contents = document.to_string();
+        Config::check(&contents, &path_display).map_err(|e| e.to_string())?;
+        std::fs::write(config_path, contents)
+            .map_err(|e| format!("Failed to write {}: {}", path_display, e))?;
+        Ok(self.networks.keys().cloned().collect())
+    }
+}
+
+fn read_config_table(config_path: &Path) -> Result<toml::Table, String> {
+    let contents = std::fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read {}: {}", config_path.display(), e))?;
+    contents
+        .parse()
+        .map_err(|e| format!("Invalid {}: {}", config_path.display(), e))
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Length of the window a daily limit applies to, in milliseconds
+pub const SPENDING_WINDOW_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// What happens to an operation that would go over an identity's daily limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum OverLimitAction {
+    #[default]
+    Block,
+    /// Let the approver confirm it, see [`crate::model::approval::ApprovalPolicy`]
+    RequireApproval,
+}
+
+impl OverLimitAction {
+    pub const ALL: [OverLimitAction; 2] =
+        [OverLimitAction::Block, OverLimitAction::RequireApproval];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            OverLimitAction::Block => "Block it",
+            OverLimitAction::RequireApproval => "Ask the approver",
+        }
+    }
+}
+
+/// Daily caps on the credits an identity can withdraw or transfer, over a rolling 24 hours
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpendingLimits {
+    /// Limit for identities without their own one, `None` for no limit
+    pub default_daily_limit: Option<u64>,
+    /// Limits of single identities keyed by their base58 id
+    pub identity_limits: BTreeMap<String, u64>,
+    pub over_limit: OverLimitAction,
+}
+
+impl SpendingLimits {
+    pub fn limit_for(&self, identity_id: &str) -> Option<u64> {
+        self.identity_limits
+            .get(identity_id)
+            .copied()
+            .or(self.default_daily_limit)
+    }
+}
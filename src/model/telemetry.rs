@@ -0,0 +1,81 @@
+use crate::metrics_export::MetricsSnapshot;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How often usage statistics are sent, in seconds
+const TELEMETRY_INTERVAL_SECS: i64 = 24 * 60 * 60;
+
+/// Anonymous usage statistics, never sent unless the user turns them on and names where to
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelemetrySettings {
+    pub enabled: bool,
+    /// URL the reports are posted to as JSON
+    pub endpoint: String,
+    /// Unix time in seconds of the last report sent
+    pub last_sent_at: Option<i64>,
+}
+
+impl TelemetrySettings {
+    pub fn is_due(&self, now: i64) -> bool {
+        self.enabled
+            && !self.endpoint.trim().is_empty()
+            && self
+                .last_sent_at
+                .map_or(true, |sent_at| now - sent_at >= TELEMETRY_INTERVAL_SECS)
+    }
+}
+
+/// Everything a telemetry report holds: which features were used and how tasks failed since the
+/// last report. It has no ids, names, addresses, amounts or error messages, and nothing that
+/// tells one installation apart from another.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TelemetryReport {
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    /// "mainnet", "testnet" or "other", without a devnet's name
+    pub network: String,
+    /// Finished tasks by kind, e.g. "contested_resource"
+    pub feature_usage: BTreeMap<String, u64>,
+    /// Failed tasks by kind
+    pub feature_failures: BTreeMap<String, u64>,
+    /// Failed tasks by coarse cause, e.g. "network" or "proof"
+    pub error_categories: BTreeMap<String, u64>,
+    pub votes_submitted: u64,
+}
+
+impl TelemetryReport {
+    pub fn new(network: &str, usage: &MetricsSnapshot) -> Self {
+        let mut feature_usage = BTreeMap::new();
+        let mut feature_failures = BTreeMap::new();
+        for ((kind, outcome), count) in &usage.tasks {
+            *feature_usage.entry(kind.to_string()).or_default() += count;
+            if *outcome == "error" {
+                *feature_failures.entry(kind.to_string()).or_default() += count;
+            }
+        }
+        Self {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            network: network.to_string(),
+            feature_usage,
+            feature_failures,
+            error_categories: usage
+                .errors
+                .iter()
+                .map(|(category, count)| (category.to_string(), *count))
+                .collect(),
+            votes_submitted: usage.votes_submitted,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.feature_usage.is_empty() && self.votes_submitted == 0
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("telemetry report serializes to JSON")
+    }
+}
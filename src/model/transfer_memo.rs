@@ -0,0 +1,131 @@
+use aes::cipher::block_padding::Pkcs7;
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use dash_sdk::dpp::dashcore::hashes::{hmac, sha256, Hash, HashEngine};
+use dash_sdk::dpp::dashcore::secp256k1::ecdh::SharedSecret;
+use dash_sdk::dpp::dashcore::secp256k1::{PublicKey, SecretKey};
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::identity::{KeyID, KeyType, Purpose};
+use dash_sdk::platform::{Identifier, IdentityPublicKey};
+use rand::RngCore;
+
+/// Dashpay document type the memos travel in, as the encrypted account label of a contact
+/// request, so any Dashpay wallet shows them
+pub const CONTACT_REQUEST_DOCUMENT_TYPE: &str = "contactRequest";
+
+/// Longest memo in bytes. The account label holds at most 80 bytes: the IV and the padded
+/// ciphertext.
+pub const MAX_MEMO_BYTES: usize = 63;
+
+/// The label holds at least 48 bytes, shorter memos are padded with spaces
+const MIN_MEMO_BYTES: usize = 16;
+
+/// Received memos shown per identity, newest first
+pub const MEMO_QUERY_LIMIT: u16 = 50;
+
+const IV_LENGTH: usize = 16;
+
+/// Version of the account reference, the top 4 bits
+const ACCOUNT_REFERENCE_VERSION: u32 = 0;
+
+/// Whether the key can take part in the ECDH of a memo: an enabled ECDSA key of the purpose,
+/// ENCRYPTION on the sender's side and DECRYPTION on the recipient's
+pub fn is_memo_key(key: &IdentityPublicKey, purpose: Purpose) -> bool {
+    key.purpose() == purpose
+        && key.key_type() == KeyType::ECDSA_SECP256K1
+        && key.disabled_at().is_none()
+}
+
+/// The account reference of a contact request as DIP-15 derives it, from the sender's
+/// encryption key and the extended public key of the contact account
+pub fn account_reference(private_key: &[u8; 32], extended_public_key: &[u8], account: u32) -> u32 {
+    let mut engine = hmac::HmacEngine::<sha256::Hash>::new(private_key);
+    engine.input(extended_public_key);
+    let ask = hmac::Hmac::<sha256::Hash>::from_engine(engine).to_byte_array();
+    let ask28 = u32::from_be_bytes([ask[0], ask[1], ask[2], ask[3]]) >> 4;
+    (ACCOUNT_REFERENCE_VERSION << 28) | (ask28 ^ (account & 0x0FFF_FFFF))
+}
+
+/// A memo sent to the identity in a Dashpay contact request
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceivedMemo {
+    pub document_id: Identifier,
+    pub sender_id: Identifier,
+    /// Milliseconds since the Unix epoch
+    pub created_at: Option<u64>,
+    /// The memo, or why it couldn't be decrypted
+    pub memo: Result<String, String>,
+}
+
+/// The memo as sent on Platform, in a Dashpay contact request: the ECDH is between the
+/// sender's encryption key and the recipient's decryption key
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncryptedMemo {
+    pub sender_key_id: KeyID,
+    pub recipient_key_id: KeyID,
+    pub account_reference: u32,
+    /// The IV followed by the AES-256-CBC ciphertext of the contact account's extended public
+    /// key
+    pub encrypted_public_key: Vec<u8>,
+    /// The IV followed by the AES-256-CBC ciphertext of the memo
+    pub encrypted_label: Vec<u8>,
+}
+
+/// The AES key both sides derive, the SHA256 of the ECDH point as libsecp256k1 computes it
+fn shared_key(private_key: &[u8; 32], public_key: &[u8]) -> Result<[u8; 32], String> {
+    let secret_key =
+        SecretKey::from_slice(private_key).map_err(|e| format!("Invalid private key: {}", e))?;
+    let public_key =
+        PublicKey::from_slice(public_key).map_err(|e| format!("Invalid public key: {}", e))?;
+    Ok(SharedSecret::new(&public_key, &secret_key).secret_bytes())
+}
+
+/// Encrypts the data for the owner of `their_public_key` with our private key
+pub fn encrypt_for(
+    private_key: &[u8; 32],
+    their_public_key: &[u8],
+    data: &[u8],
+) -> Result<Vec<u8>, String> {
+    let key = shared_key(private_key, their_public_key)?;
+    let mut iv = [0u8; IV_LENGTH];
+    rand::thread_rng().fill_bytes(&mut iv);
+    let ciphertext = cbc::Encryptor::<aes::Aes256>::new(&key.into(), &iv.into())
+        .encrypt_padded_vec_mut::<Pkcs7>(data);
+    Ok([iv.as_slice(), &ciphertext].concat())
+}
+
+/// Encrypts the memo for the owner of `their_public_key` with our private key
+pub fn encrypt_memo(
+    private_key: &[u8; 32],
+    their_public_key: &[u8],
+    memo: &str,
+) -> Result<Vec<u8>, String> {
+    if memo.len() > MAX_MEMO_BYTES {
+        return Err(format!(
+            "The memo is {} bytes long, at most {} fit",
+            memo.len(),
+            MAX_MEMO_BYTES
+        ));
+    }
+    let padded = format!("{:<width$}", memo, width = MIN_MEMO_BYTES);
+    encrypt_for(private_key, their_public_key, padded.as_bytes())
+}
+
+/// Decrypts a memo sent by the owner of `their_public_key` to us
+pub fn decrypt_memo(
+    private_key: &[u8; 32],
+    their_public_key: &[u8],
+    data: &[u8],
+) -> Result<String, String> {
+    if data.len() <= IV_LENGTH {
+        return Err("The memo is too short".to_string());
+    }
+    let key = shared_key(private_key, their_public_key)?;
+    let (iv, ciphertext) = data.split_at(IV_LENGTH);
+    let iv: [u8; IV_LENGTH] = iv.try_into().expect("split at the IV length");
+    let plaintext = cbc::Decryptor::<aes::Aes256>::new(&key.into(), &iv.into())
+        .decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+        .map_err(|_| "The memo doesn't decrypt with our key".to_string())?;
+    String::from_utf8(plaintext)
+        .map(|memo| memo.trim_end().to_string())
+        .map_err(|_| "The memo isn't text".to_string())
+}
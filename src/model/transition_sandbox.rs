@@ -0,0 +1,339 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use dash_sdk::dashcore_rpc::dashcore::signer;
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::identity::signer::Signer;
+use dash_sdk::dpp::identity::{IdentityPublicKey, KeyType, SecurityLevel};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::prelude::Identifier;
+use dash_sdk::dpp::serialization::{PlatformDeserializable, PlatformSerializable, Signable};
+use dash_sdk::dpp::state_transition::data_contract_create_transition::DataContractCreateTransition;
+use dash_sdk::dpp::state_transition::data_contract_update_transition::DataContractUpdateTransition;
+use dash_sdk::dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+use dash_sdk::dpp::state_transition::identity_create_transition::IdentityCreateTransition;
+use dash_sdk::dpp::state_transition::identity_credit_transfer_transition::accessors::IdentityCreditTransferTransitionAccessorsV0;
+use dash_sdk::dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use dash_sdk::dpp::state_transition::identity_credit_withdrawal_transition::accessors::IdentityCreditWithdrawalTransitionAccessorsV0;
+use dash_sdk::dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
+use dash_sdk::dpp::state_transition::identity_topup_transition::IdentityTopUpTransition;
+use dash_sdk::dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
+use dash_sdk::dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
+use dash_sdk::dpp::state_transition::StateTransition;
+use dash_sdk::dpp::ProtocolError;
+use serde::de::DeserializeOwned;
+
+/// The kinds of state transition the sandbox builds. The JSON of a transition doesn't name its
+/// kind, it is picked next to the editor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxTransitionKind {
+    DataContractCreate,
+    DataContractUpdate,
+    DocumentsBatch,
+    IdentityCreate,
+    IdentityTopUp,
+    IdentityUpdate,
+    IdentityCreditWithdrawal,
+    IdentityCreditTransfer,
+    MasternodeVote,
+}
+
+impl SandboxTransitionKind {
+    pub const ALL: [SandboxTransitionKind; 9] = [
+        SandboxTransitionKind::DataContractCreate,
+        SandboxTransitionKind::DataContractUpdate,
+        SandboxTransitionKind::DocumentsBatch,
+        SandboxTransitionKind::IdentityCreate,
+        SandboxTransitionKind::IdentityTopUp,
+        SandboxTransitionKind::IdentityUpdate,
+        SandboxTransitionKind::IdentityCreditWithdrawal,
+        SandboxTransitionKind::IdentityCreditTransfer,
+        SandboxTransitionKind::MasternodeVote,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SandboxTransitionKind::DataContractCreate => "Data contract create",
+            SandboxTransitionKind::DataContractUpdate => "Data contract update",
+            SandboxTransitionKind::DocumentsBatch => "Documents batch",
+            SandboxTransitionKind::IdentityCreate => "Identity create",
+            SandboxTransitionKind::IdentityTopUp => "Identity top up",
+            SandboxTransitionKind::IdentityUpdate => "Identity update",
+            SandboxTransitionKind::IdentityCreditWithdrawal => "Identity credit withdrawal",
+            SandboxTransitionKind::IdentityCreditTransfer => "Identity credit transfer",
+            SandboxTransitionKind::MasternodeVote => "Masternode vote",
+        }
+    }
+
+    /// Whether the transition is signed with a key of its identity. Identity creations and top
+    /// ups are signed with the key of their asset lock, their JSON carries the signature.
+    pub fn signed_by_identity(&self) -> bool {
+        !matches!(
+            self,
+            SandboxTransitionKind::IdentityCreate | SandboxTransitionKind::IdentityTopUp
+        )
+    }
+
+    pub fn of(transition: &StateTransition) -> Self {
+        match transition {
+            StateTransition::DataContractCreate(_) => SandboxTransitionKind::DataContractCreate,
+            StateTransition::DataContractUpdate(_) => SandboxTransitionKind::DataContractUpdate,
+            StateTransition::DocumentsBatch(_) => SandboxTransitionKind::DocumentsBatch,
+            StateTransition::IdentityCreate(_) => SandboxTransitionKind::IdentityCreate,
+            StateTransition::IdentityTopUp(_) => SandboxTransitionKind::IdentityTopUp,
+            StateTransition::IdentityUpdate(_) => SandboxTransitionKind::IdentityUpdate,
+            StateTransition::IdentityCreditWithdrawal(_) => {
+                SandboxTransitionKind::IdentityCreditWithdrawal
+            }
+            StateTransition::IdentityCreditTransfer(_) => {
+                SandboxTransitionKind::IdentityCreditTransfer
+            }
+            StateTransition::MasternodeVote(_) => SandboxTransitionKind::MasternodeVote,
+        }
+    }
+}
+
+/// Builds a transition of the kind from its JSON, as the transition visualizer shows it
+pub fn transition_from_json(
+    kind: SandboxTransitionKind,
+    json: &str,
+) -> Result<StateTransition, String> {
+    fn parse<T: DeserializeOwned>(json: &str) -> Result<T, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid transition JSON: {}", e))
+    }
+    Ok(match kind {
+        SandboxTransitionKind::DataContractCreate => {
+            StateTransition::DataContractCreate(parse::<DataContractCreateTransition>(json)?)
+        }
+        SandboxTransitionKind::DataContractUpdate => {
+            StateTransition::DataContractUpdate(parse::<DataContractUpdateTransition>(json)?)
+        }
+        SandboxTransitionKind::DocumentsBatch => {
+            StateTransition::DocumentsBatch(parse::<DocumentsBatchTransition>(json)?)
+        }
+        SandboxTransitionKind::IdentityCreate => {
+            StateTransition::IdentityCreate(parse::<IdentityCreateTransition>(json)?)
+        }
+        SandboxTransitionKind::IdentityTopUp => {
+            StateTransition::IdentityTopUp(parse::<IdentityTopUpTransition>(json)?)
+        }
+        SandboxTransitionKind::IdentityUpdate => {
+            StateTransition::IdentityUpdate(parse::<IdentityUpdateTransition>(json)?)
+        }
+        SandboxTransitionKind::IdentityCreditWithdrawal => {
+            StateTransition::IdentityCreditWithdrawal(parse::<IdentityCreditWithdrawalTransition>(
+                json,
+            )?)
+        }
+        SandboxTransitionKind::IdentityCreditTransfer => StateTransition::IdentityCreditTransfer(
+            parse::<IdentityCreditTransferTransition>(json)?,
+        ),
+        SandboxTransitionKind::MasternodeVote => {
+            StateTransition::MasternodeVote(parse::<MasternodeVoteTransition>(json)?)
+        }
+    })
+}
+
+/// The JSON of a transition, to edit in the sandbox
+pub fn transition_to_json(transition: &StateTransition) -> Result<String, String> {
+    serde_json::to_string_pretty(transition)
+        .map_err(|e| format!("Failed to convert the transition to JSON: {}", e))
+}
+
+/// Reads a serialized transition in hex or base64, as the transition visualizer does
+pub fn decode_transition(encoded: &str) -> Result<StateTransition, String> {
+    let encoded = encoded.trim();
+    let bytes = hex::decode(encoded)
+        .or_else(|_| STANDARD.decode(encoded))
+        .map_err(|_| "The transition is neither hex nor base64".to_string())?;
+    StateTransition::deserialize_from_bytes(&bytes)
+        .map_err(|e| format!("Failed to parse the transition: {}", e))
+}
+
+/// What a transfer or withdrawal spends, from which identity and how many credits, `None` for
+/// transitions that don't move credits out of an identity
+pub fn transition_credit_spending(
+    transition: &StateTransition,
+) -> Option<(&'static str, Identifier, Credits)> {
+    match transition {
+        StateTransition::IdentityCreditWithdrawal(withdrawal) => {
+            Some(("Withdrawal", withdrawal.identity_id(), withdrawal.amount()))
+        }
+        StateTransition::IdentityCreditTransfer(transfer) => {
+            Some(("Transfer", transfer.identity_id(), transfer.amount()))
+        }
+        _ => None,
+    }
+}
+
+/// Signs the transition with a key of its identity, the signer holding the private key
+pub fn sign_transition(
+    transition: &mut StateTransition,
+    public_key: &IdentityPublicKey,
+    signer: &impl Signer,
+) -> Result<(), String> {
+    if !SandboxTransitionKind::of(transition).signed_by_identity() {
+        return Err(
+            "This transition is signed with the key of its asset lock, put the signature in the \
+             JSON"
+                .to_string(),
+        );
+    }
+    transition
+        .sign_external(
+            public_key,
+            signer,
+            None::<fn(Identifier, String) -> Result<SecurityLevel, ProtocolError>>,
+        )
+        .map_err(|e| format!("Signing error: {}", e))
+}
+
+/// One local check of a transition and how it went
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SandboxCheck {
+    pub name: &'static str,
+    /// Why the check failed, or a note on what it could not check
+    pub result: Result<Option<String>, String>,
+}
+
+impl SandboxCheck {
+    fn passed(name: &'static str) -> Self {
+        Self {
+            name,
+            result: Ok(None),
+        }
+    }
+
+    fn note(name: &'static str, note: impl Into<String>) -> Self {
+        Self {
+            name,
+            result: Ok(Some(note.into())),
+        }
+    }
+
+    fn failed(name: &'static str, error: impl Into<String>) -> Self {
+        Self {
+            name,
+            result: Err(error.into()),
+        }
+    }
+}
+
+/// Validates the transition against the rules of DPP that hold without Platform's state: that
+/// it encodes within the size limit and decodes back the same, and that it is signed by a key
+/// of its identity that may sign it. `signing_key` is the key of the owner named by the
+/// transition, when it is known locally.
+pub fn validate_transition(
+    transition: &StateTransition,
+    signing_key: Option<&IdentityPublicKey>,
+) -> Vec<SandboxCheck> {
+    let mut checks = Vec::new();
+
+    match transition.serialize_to_bytes() {
+        Ok(bytes) => match StateTransition::deserialize_from_bytes(&bytes) {
+            Ok(decoded) if &decoded == transition => checks.push(SandboxCheck::note(
+                "Encoding",
+                format!("{} bytes", bytes.len()),
+            )),
+            Ok(_) => checks.push(SandboxCheck::failed(
+                "Encoding",
+                "The transition decodes to something else than was encoded",
+            )),
+            Err(e) => checks.push(SandboxCheck::failed("Encoding", e.to_string())),
+        },
+        Err(e) => checks.push(SandboxCheck::failed("Encoding", e.to_string())),
+    }
+
+    if !SandboxTransitionKind::of(transition).signed_by_identity() {
+        checks.push(SandboxCheck::note(
+            "Signature",
+            "Signed with the asset lock key, checked by Platform",
+        ));
+        return checks;
+    }
+
+    let Some(key_id) = transition.signature_public_key_id() else {
+        checks.push(SandboxCheck::failed(
+            "Signing key",
+            "The transition names no signing key",
+        ));
+        return checks;
+    };
+    let Some(public_key) = signing_key.filter(|key| key.id() == key_id) else {
+        checks.push(SandboxCheck::failed(
+            "Signing key",
+            format!(
+                "Key {} of {} isn't known locally",
+                key_id,
+                transition.owner_id().to_string(Encoding::Base58)
+            ),
+        ));
+        return checks;
+    };
+    checks.push(check_signing_key(transition, public_key));
+    checks.push(check_signature(transition, public_key));
+    checks
+}
+
+fn check_signing_key(transition: &StateTransition, public_key: &IdentityPublicKey) -> SandboxCheck {
+    const NAME: &str = "Signing key";
+    if public_key.disabled_at().is_some() {
+        return SandboxCheck::failed(NAME, format!("Key {} is disabled", public_key.id()));
+    }
+    if let Some(purpose) = transition.purpose_requirement() {
+        if public_key.purpose() != purpose {
+            return SandboxCheck::failed(
+                NAME,
+                format!(
+                    "Key {} has purpose {}, the transition needs {}",
+                    public_key.id(),
+                    public_key.purpose(),
+                    purpose
+                ),
+            );
+        }
+    }
+    if let Some(levels) = transition.security_level_requirement() {
+        if !levels.contains(&public_key.security_level()) {
+            return SandboxCheck::failed(
+                NAME,
+                format!(
+                    "Key {} has security level {}, the transition needs one of {}",
+                    public_key.id(),
+                    public_key.security_level(),
+                    levels
+                        .iter()
+                        .map(|level| level.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            );
+        }
+    }
+    SandboxCheck::passed(NAME)
+}
+
+fn check_signature(transition: &StateTransition, public_key: &IdentityPublicKey) -> SandboxCheck {
+    const NAME: &str = "Signature";
+    let Some(signature) = transition.signature().filter(|s| !s.is_empty()) else {
+        return SandboxCheck::failed(NAME, "The transition isn't signed");
+    };
+    if public_key.key_type() != KeyType::ECDSA_SECP256K1 {
+        return SandboxCheck::note(
+            NAME,
+            format!(
+                "Only ECDSA signatures are checked locally, key {} is {}",
+                public_key.id(),
+                public_key.key_type()
+            ),
+        );
+    }
+    let data = match transition.signable_bytes() {
+        Ok(data) => data,
+        Err(e) => return SandboxCheck::failed(NAME, e.to_string()),
+    };
+    match signer::verify_data_signature(&data, signature.as_slice(), public_key.data().as_slice()) {
+        Ok(()) => SandboxCheck::passed(NAME),
+        Err(_) => SandboxCheck::failed(NAME, "The signature does not match the transition and key"),
+    }
+}
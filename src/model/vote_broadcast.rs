@@ -0,0 +1,127 @@
+use crate::model::consensus_error::rejection_cause;
+use dash_sdk::dpp::consensus::state::state_error::StateError;
+use dash_sdk::dpp::consensus::ConsensusError;
+use serde::{Deserialize, Serialize};
+
+/// Most DAPI nodes a vote is sent through at once
+pub const MAX_BROADCAST_ENDPOINTS: usize = 5;
+
+/// Whether votes are sent through several DAPI nodes at the same time, so a single flaky node
+/// can't make a vote near the end of a contest miss it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedundantBroadcast {
+    pub enabled: bool,
+    /// How many DAPI nodes each vote is sent through
+    pub endpoints: usize,
+    /// Only votes on contests ending within this many minutes are sent through several nodes,
+    /// 0 for every vote
+    pub within_minutes_of_end: u32,
+}
+
+impl Default for RedundantBroadcast {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoints: 3,
+            within_minutes_of_end: 60,
+        }
+    }
+}
+
+impl RedundantBroadcast {
+    /// Whether a vote on a contest ending at `end_time_ms` is sent through several nodes. A
+    /// contest whose end is not known yet is treated as ending soon.
+    pub fn applies(&self, end_time_ms: Option<u64>, now_ms: u64) -> bool {
+        if !self.enabled || self.endpoint_count() < 2 {
+            return false;
+        }
+        if self.within_minutes_of_end == 0 {
+            return true;
+        }
+        end_time_ms.map_or(true, |end_time_ms| {
+            end_time_ms.saturating_sub(now_ms) <= self.within_minutes_of_end as u64 * 60_000
+        })
+    }
+
+    pub fn endpoint_count(&self) -> usize {
+        self.endpoints.min(MAX_BROADCAST_ENDPOINTS)
+    }
+}
+
+/// How sending the vote through one DAPI node went
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BroadcastAttempt {
+    pub address: String,
+    pub result: Result<(), BroadcastFailure>,
+}
+
+/// Why the node didn't confirm the vote
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BroadcastFailure {
+    pub message: String,
+    /// Platform already had the transition, as happens when it arrived through another node first
+    pub duplicate: bool,
+}
+
+impl From<dash_sdk::Error> for BroadcastFailure {
+    fn from(error: dash_sdk::Error) -> Self {
+        Self {
+            duplicate: is_duplicate_error(&error),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Whether the error means Platform already had the transition or the masternode's vote
+fn is_duplicate_error(error: &dash_sdk::Error) -> bool {
+    matches!(error, dash_sdk::Error::AlreadyExists(_))
+        || matches!(
+            rejection_cause(error),
+            Some(ConsensusError::StateError(
+                StateError::MasternodeVoteAlreadyPresentError(_)
+            ))
+        )
+}
+
+/// Combines the attempts into one outcome: the vote went through when any node confirmed it.
+/// Returns how it went on success, and why it failed on each node otherwise.
+pub fn reconcile(attempts: &[BroadcastAttempt]) -> Result<String, String> {
+    let confirmed = attempts
+        .iter()
+        .filter(|attempt| attempt.result.is_ok())
+        .count();
+    if confirmed > 0 {
+        return Ok(format!(
+            "Confirmed through {} of {} DAPI nodes",
+            confirmed,
+            attempts.len()
+        ));
+    }
+    let errors: Vec<String> = attempts
+        .iter()
+        .filter_map(|attempt| {
+            attempt
+                .result
+                .as_ref()
+                .err()
+                .map(|e| format!("{}: {}", attempt.address, e.message))
+        })
+        .collect();
+    let reached_platform = attempts
+        .iter()
+        .any(|attempt| attempt.result.as_ref().is_err_and(|e| e.duplicate));
+    if reached_platform {
+        Err(format!(
+            "The vote reached Platform but no DAPI node confirmed it, check the contest before \
+             voting again ({})",
+            errors.join("; ")
+        ))
+    } else {
+        Err(format!(
+            "Failed on all {} DAPI nodes: {}",
+            attempts.len(),
+            errors.join("; ")
+        ))
+    }
+}
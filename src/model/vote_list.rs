@@ -0,0 +1,162 @@
+use crate::model::contest_annotation::ContestAnnotation;
+use dash_sdk::dashcore_rpc::dashcore::{signer, PrivateKey};
+use dash_sdk::dpp::dashcore::secp256k1::Secp256k1;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dash_sdk::platform::Identifier;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// A vote recommended by a list, written as "lock", "abstain" or the Base58 id of a contestant
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum SuggestedVote {
+    Lock,
+    Abstain,
+    TowardsIdentity(Identifier),
+}
+
+impl SuggestedVote {
+    pub fn vote_choice(&self) -> ResourceVoteChoice {
+        match self {
+            SuggestedVote::Lock => ResourceVoteChoice::Lock,
+            SuggestedVote::Abstain => ResourceVoteChoice::Abstain,
+            SuggestedVote::TowardsIdentity(identity_id) => {
+                ResourceVoteChoice::TowardsIdentity(*identity_id)
+            }
+        }
+    }
+
+    pub fn from_vote_choice(vote_choice: &ResourceVoteChoice) -> Self {
+        match vote_choice {
+            ResourceVoteChoice::Lock => SuggestedVote::Lock,
+            ResourceVoteChoice::Abstain => SuggestedVote::Abstain,
+            ResourceVoteChoice::TowardsIdentity(identity_id) => {
+                SuggestedVote::TowardsIdentity(*identity_id)
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for SuggestedVote {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.trim().to_lowercase().as_str() {
+            "lock" => Ok(SuggestedVote::Lock),
+            "abstain" => Ok(SuggestedVote::Abstain),
+            _ => Identifier::from_string(value.trim(), Encoding::Base58)
+                .map(SuggestedVote::TowardsIdentity)
+                .map_err(|_| format!("\"{}\" is neither lock, abstain nor a contestant id", value)),
+        }
+    }
+}
+
+impl From<SuggestedVote> for String {
+    fn from(vote: SuggestedVote) -> Self {
+        vote.to_string()
+    }
+}
+
+impl Display for SuggestedVote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SuggestedVote::Lock => write!(f, "lock"),
+            SuggestedVote::Abstain => write!(f, "abstain"),
+            SuggestedVote::TowardsIdentity(identity_id) => {
+                write!(f, "{}", identity_id.to_string(Encoding::Base58))
+            }
+        }
+    }
+}
+
+/// The signed part of a vote list. The signature covers the compact JSON of this struct, with
+/// the fields in this order and the votes sorted by name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoteListContents {
+    pub title: String,
+    pub author: String,
+    /// Hex of the compressed secp256k1 public key the list is signed with
+    #[serde(default)]
+    pub public_key: Option<String>,
+    /// Suggested vote keyed by normalized contested name
+    pub votes: BTreeMap<String, SuggestedVote>,
+    /// The author's notes on the contests, e.g. the evidence behind a lock. Left out of lists
+    /// without notes, so their signatures stay the same.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<ContestAnnotation>,
+}
+
+/// A recommended vote list as published by a community member
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoteList {
+    #[serde(flatten)]
+    pub contents: VoteListContents,
+    /// Hex of the compact recoverable signature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+impl VoteList {
+    /// Parses a list and checks its signature. A list with a signature that does not match is
+    /// rejected, an unsigned list is accepted.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let list: VoteList =
+            serde_json::from_str(json).map_err(|e| format!("Not a valid vote list: {}", e))?;
+        if list.contents.title.trim().is_empty() {
+            return Err("The vote list has no title".to_string());
+        }
+        list.verify()?;
+        Ok(list)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("vote lists serialize to JSON")
+    }
+
+    /// Builds a list, signed when a private key is given
+    pub fn new(
+        mut contents: VoteListContents,
+        private_key: Option<&PrivateKey>,
+    ) -> Result<Self, String> {
+        let Some(private_key) = private_key else {
+            contents.public_key = None;
+            return Ok(VoteList {
+                contents,
+                signature: None,
+            });
+        };
+        let public_key = private_key.public_key(&Secp256k1::new());
+        contents.public_key = Some(hex::encode(public_key.to_bytes()));
+        let signature = signer::sign(&signing_bytes(&contents), &private_key.inner.secret_bytes())
+            .map_err(|e| format!("Failed to sign the vote list: {}", e))?;
+        Ok(VoteList {
+            contents,
+            signature: Some(hex::encode(signature)),
+        })
+    }
+
+    pub fn is_signed(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    fn verify(&self) -> Result<(), String> {
+        let Some(signature) = &self.signature else {
+            return Ok(());
+        };
+        let Some(public_key) = &self.contents.public_key else {
+            return Err("The vote list is signed but has no public key".to_string());
+        };
+        let signature =
+            hex::decode(signature).map_err(|_| "The signature is not hex".to_string())?;
+        let public_key =
+            hex::decode(public_key).map_err(|_| "The public key is not hex".to_string())?;
+        signer::verify_data_signature(&signing_bytes(&self.contents), &signature, &public_key)
+            .map_err(|_| "The signature does not match the vote list".to_string())
+    }
+}
+
+fn signing_bytes(contents: &VoteListContents) -> Vec<u8> {
+    serde_json::to_vec(contents).expect("vote lists serialize to JSON")
+}
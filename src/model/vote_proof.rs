@@ -0,0 +1,155 @@
+use dash_sdk::dapi_grpc::platform::v0::get_contested_resource_identity_votes_response::{
+    get_contested_resource_identity_votes_response_v0, GetContestedResourceIdentityVotesResponseV0,
+    Version,
+};
+use dash_sdk::dapi_grpc::platform::v0::{
+    GetContestedResourceIdentityVotesResponse, Proof, ResponseMetadata,
+};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use serde::{Deserialize, Serialize};
+
+/// Version of the vote receipt format this build writes and reads
+pub const VOTE_RECEIPT_VERSION: u32 = 1;
+
+/// A vote of one of our masternodes with the Platform proof of its inclusion, as fetched right
+/// after the vote was accepted.
+///
+/// The receipt is a JSON file anyone can check against the quorum that signed the proof,
+/// without trusting the operator who cast the vote.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteProofReceipt {
+    pub version: u32,
+    pub network: String,
+    /// Normalized name of the contest
+    pub contest: String,
+    /// The choice as shown in the tool, e.g. "Lock" or "for <identity id>"
+    pub choice: String,
+    /// Base58 id of the masternode's identity
+    pub voter_id: String,
+    /// Alias or id of the masternode when the vote was cast
+    pub voter_name: String,
+    /// Base58 id of the contest's vote poll
+    pub vote_poll_id: String,
+    /// Hex of the SHA256 of the serialized vote transition
+    pub transition_hash: String,
+    pub proof: ReceiptProof,
+    pub metadata: ReceiptMetadata,
+}
+
+/// The proof returned with the vote, all bytes in hex
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptProof {
+    pub grovedb_proof: String,
+    pub quorum_hash: String,
+    pub signature: String,
+    pub round: u32,
+    pub block_id_hash: String,
+    pub quorum_type: u32,
+}
+
+/// The block the proof was made at
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptMetadata {
+    pub height: u64,
+    pub core_chain_locked_height: u32,
+    pub epoch: u32,
+    pub time_ms: u64,
+    pub protocol_version: u32,
+    pub chain_id: String,
+}
+
+impl From<&Proof> for ReceiptProof {
+    fn from(proof: &Proof) -> Self {
+        Self {
+            grovedb_proof: hex::encode(&proof.grovedb_proof),
+            quorum_hash: hex::encode(&proof.quorum_hash),
+            signature: hex::encode(&proof.signature),
+            round: proof.round,
+            block_id_hash: hex::encode(&proof.block_id_hash),
+            quorum_type: proof.quorum_type,
+        }
+    }
+}
+
+impl From<&ResponseMetadata> for ReceiptMetadata {
+    fn from(metadata: &ResponseMetadata) -> Self {
+        Self {
+            height: metadata.height,
+            core_chain_locked_height: metadata.core_chain_locked_height,
+            epoch: metadata.epoch,
+            time_ms: metadata.time_ms,
+            protocol_version: metadata.protocol_version,
+            chain_id: metadata.chain_id.clone(),
+        }
+    }
+}
+
+impl VoteProofReceipt {
+    /// The response Platform answered the vote query with, rebuilt to verify its proof again
+    pub fn to_response(&self) -> Result<GetContestedResourceIdentityVotesResponse, String> {
+        let bytes = |field: &str, value: &str| {
+            hex::decode(value).map_err(|_| format!("The {} of the proof is not hex", field))
+        };
+        let proof = Proof {
+            grovedb_proof: bytes("GroveDB proof", &self.proof.grovedb_proof)?,
+            quorum_hash: bytes("quorum hash", &self.proof.quorum_hash)?,
+            signature: bytes("signature", &self.proof.signature)?,
+            round: self.proof.round,
+            block_id_hash: bytes("block id hash", &self.proof.block_id_hash)?,
+            quorum_type: self.proof.quorum_type,
+        };
+        let metadata = ResponseMetadata {
+            height: self.metadata.height,
+            core_chain_locked_height: self.metadata.core_chain_locked_height,
+            epoch: self.metadata.epoch,
+            time_ms: self.metadata.time_ms,
+            protocol_version: self.metadata.protocol_version,
+            chain_id: self.metadata.chain_id.clone(),
+        };
+        Ok(GetContestedResourceIdentityVotesResponse {
+            version: Some(Version::V0(GetContestedResourceIdentityVotesResponseV0 {
+                result: Some(
+                    get_contested_resource_identity_votes_response_v0::Result::Proof(proof),
+                ),
+                metadata: Some(metadata),
+            })),
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("vote receipts serialize to JSON")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let receipt: Self =
+            serde_json::from_str(json).map_err(|e| format!("Not a vote receipt: {}", e))?;
+        if receipt.version != VOTE_RECEIPT_VERSION {
+            return Err(format!(
+                "The vote receipt has version {}, but this build only reads version {}",
+                receipt.version, VOTE_RECEIPT_VERSION
+            ));
+        }
+        Ok(receipt)
+    }
+
+    /// A file name naming the contest and the masternode
+    pub fn file_name(&self) -> String {
+        format!(
+            "vote-receipt-{}-{}.json",
+            self.contest,
+            self.voter_id.chars().take(8).collect::<String>()
+        )
+    }
+}
+
+/// How a vote choice is shown in receipts and summaries
+pub fn vote_choice_label(vote_choice: &ResourceVoteChoice) -> String {
+    match vote_choice {
+        ResourceVoteChoice::TowardsIdentity(identity_id) => {
+            format!("for {}", identity_id.to_string(Encoding::Base58))
+        }
+        ResourceVoteChoice::Abstain => "Abstain".to_string(),
+        ResourceVoteChoice::Lock => "Lock".to_string(),
+    }
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+
+/// Discord refuses messages longer than this
+const DISCORD_MESSAGE_LIMIT: usize = 2000;
+
+/// The shape of the body posted to the webhook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VoteWebhookFormat {
+    Discord,
+    Slack,
+    /// The summary as JSON, for scripts and other chat tools
+    #[default]
+    Generic,
+}
+
+impl VoteWebhookFormat {
+    pub const ALL: [VoteWebhookFormat; 3] = [
+        VoteWebhookFormat::Discord,
+        VoteWebhookFormat::Slack,
+        VoteWebhookFormat::Generic,
+    ];
+}
+
+impl fmt::Display for VoteWebhookFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VoteWebhookFormat::Discord => write!(f, "Discord"),
+            VoteWebhookFormat::Slack => write!(f, "Slack"),
+            VoteWebhookFormat::Generic => write!(f, "Generic JSON"),
+        }
+    }
+}
+
+/// Where the summary of each voting session is posted, so people co-managing the nodes see
+/// what was voted
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VoteWebhookSettings {
+    pub enabled: bool,
+    pub url: String,
+    pub format: VoteWebhookFormat,
+}
+
+impl VoteWebhookSettings {
+    /// The URL to post to, `None` when the webhook is off or has no URL
+    pub fn url(&self) -> Option<&str> {
+        let url = self.url.trim();
+        (self.enabled && !url.is_empty()).then_some(url)
+    }
+}
+
+/// One vote cast by one identity
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VoteReceipt {
+    /// Normalized name of the contest
+    pub contest: String,
+    /// e.g. "Lock", "Abstain" or the id of the contestant voted for
+    pub choice: String,
+    /// Alias or id of the masternode identity that voted
+    pub voter: String,
+    /// Hash of the vote transition in hex
+    pub transition_hash: String,
+}
+
+/// The votes of one voting session, a single vote or a bulk vote on several contests
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VoteSessionSummary {
+    pub network: String,
+    /// Unix time in seconds
+    pub finished_at: i64,
+    pub receipts: Vec<VoteReceipt>,
+    /// Contests whose votes failed, with the error
+    pub failures: Vec<String>,
+}
+
+impl VoteSessionSummary {
+    pub fn text(&self) -> String {
+        let finished_at = chrono::DateTime::from_timestamp(self.finished_at, 0)
+            .map(|time| time.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+            .unwrap_or_default();
+        let mut text = format!(
+            "Voting session on {} finished {}: {} vote(s) cast",
+            self.network,
+            finished_at,
+            self.receipts.len()
+        );
+        if !self.failures.is_empty() {
+            text.push_str(&format!(", {} failed", self.failures.len()));
+        }
+        for receipt in &self.receipts {
+            text.push_str(&format!(
+                "\n• {}: {} by {} (tx {})",
+                receipt.contest, receipt.choice, receipt.voter, receipt.transition_hash
+            ));
+        }
+        for failure in &self.failures {
+            text.push_str(&format!("\n✗ {}", failure));
+        }
+        text
+    }
+
+    /// The body to post to a webhook of the format
+    pub fn webhook_body(&self, format: VoteWebhookFormat) -> serde_json::Value {
+        match format {
+            VoteWebhookFormat::Discord => {
+                let mut text = self.text();
+                if text.chars().count() > DISCORD_MESSAGE_LIMIT {
+                    text = text.chars().take(DISCORD_MESSAGE_LIMIT - 1).collect();
+                    text.push('…');
+                }
+                json!({ "content": text })
+            }
+            VoteWebhookFormat::Slack => json!({ "text": self.text() }),
+            VoteWebhookFormat::Generic => json!({
+                "event": "vote_session",
+                "summary": self,
+            }),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dash_sdk::platform::Identifier;
+use std::fmt::{Display, Formatter};
+
+/// A named set of voting identities that vote together, like "datacenter A"
+#[derive(Debug, Clone, PartialEq)]
+pub struct VotingBloc {
+    pub name: String,
+    pub members: Vec<Identifier>,
+    /// Offered as a one click vote on every contest
+    pub default_vote: Option<BlocDefaultVote>,
+}
+
+/// Vote a bloc casts by default on contests it has no particular opinion on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlocDefaultVote {
+    Abstain,
+    Lock,
+}
+
+impl BlocDefaultVote {
+    pub const ALL: [BlocDefaultVote; 2] = [BlocDefaultVote::Abstain, BlocDefaultVote::Lock];
+
+    /// Name stored in the database
+    pub fn id(&self) -> &'static str {
+        match self {
+            BlocDefaultVote::Abstain => "abstain",
+            BlocDefaultVote::Lock => "lock",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|vote| vote.id() == id)
+    }
+
+    pub fn vote_choice(&self) -> ResourceVoteChoice {
+        match self {
+            BlocDefaultVote::Abstain => ResourceVoteChoice::Abstain,
+            BlocDefaultVote::Lock => ResourceVoteChoice::Lock,
+        }
+    }
+}
+
+impl Display for BlocDefaultVote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlocDefaultVote::Abstain => write!(f, "Abstain"),
+            BlocDefaultVote::Lock => write!(f, "Lock"),
+        }
+    }
+}
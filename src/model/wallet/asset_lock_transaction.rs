@@ -1,4 +1,5 @@
 use crate::context::AppContext;
+use crate::model::fee::FundingFee;
 use crate::model::wallet::Wallet;
 use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
 use dash_sdk::dpp::dashcore::psbt::serialize::Serialize;
@@ -6,7 +7,10 @@ use dash_sdk::dpp::dashcore::secp256k1::Message;
 use dash_sdk::dpp::dashcore::sighash::SighashCache;
 use dash_sdk::dpp::dashcore::transaction::special_transaction::asset_lock::AssetLockPayload;
 use dash_sdk::dpp::dashcore::transaction::special_transaction::TransactionPayload;
-use dash_sdk::dpp::dashcore::{Address, Network, PrivateKey, ScriptBuf, Transaction, TxIn, TxOut};
+use dash_sdk::dpp::dashcore::{
+    Address, Network, OutPoint, PrivateKey, ScriptBuf, Transaction, TxIn, TxOut,
+};
+use std::collections::BTreeMap;
 
 impl Wallet {
     pub fn asset_lock_transaction(
@@ -14,17 +18,63 @@ impl Wallet {
         network: Network,
         amount: u64,
         identity_index: u32,
+        fee: FundingFee,
         register_addresses: Option<&AppContext>,
     ) -> Result<(Transaction, PrivateKey, Address), String> {
-        let secp = Secp256k1::new();
         let private_key = self.identity_registration_ecdsa_private_key(network, identity_index);
+        self.asset_lock_transaction_for_key(network, amount, private_key, fee, register_addresses)
+    }
+
+    /// Asset lock for topping up an existing identity, locked to a fresh key of the wallet
+    pub fn top_up_asset_lock_transaction(
+        &mut self,
+        network: Network,
+        amount: u64,
+        fee: FundingFee,
+        register_addresses: &AppContext,
+    ) -> Result<(Transaction, PrivateKey, Address), String> {
+        let public_key = self.unused_bip_44_public_key(network, true, Some(register_addresses))?;
+        let address = Address::p2pkh(&public_key, network);
+        let derivation_path = self
+            .watched_addresses
+            .iter()
+            .find(|(_, address_info)| address_info.address == address)
+            .map(|(derivation_path, _)| derivation_path.clone())
+            .ok_or("The new asset lock key is not watched by the wallet".to_string())?;
+        let private_key = derivation_path
+            .derive_priv_ecdsa_for_master_seed(&self.seed, network)
+            .map_err(|e| e.to_string())?
+            .to_priv();
+        self.asset_lock_transaction_for_key(
+            network,
+            amount,
+            private_key,
+            fee,
+            Some(register_addresses),
+        )
+    }
+
+    fn asset_lock_transaction_for_key(
+        &mut self,
+        network: Network,
+        amount: u64,
+        private_key: PrivateKey,
+        fee: FundingFee,
+        register_addresses: Option<&AppContext>,
+    ) -> Result<(Transaction, PrivateKey, Address), String> {
+        let secp = Secp256k1::new();
         let asset_lock_public_key = private_key.public_key(&secp);
 
         let one_time_key_hash = asset_lock_public_key.pubkey_hash();
-        let fee = 3_000;
-        let (mut utxos, change) = self
-            .take_unspent_utxos_for(amount + fee)
-            .ok_or("take_unspent_utxos_for() returned None".to_string())?;
+        let selection = self
+            .take_utxos_for_asset_lock(amount, fee.fee_per_byte, fee.coin_selection)
+            .ok_or("The wallet's outputs don't cover the amount and the fee".to_string())?;
+        let change = selection.change;
+        let mut utxos: BTreeMap<OutPoint, (TxOut, Address)> = selection
+            .inputs
+            .into_iter()
+            .map(|(outpoint, tx_out, address)| (outpoint, (tx_out, address)))
+            .collect();
 
         let change_address = self.change_address(network, register_addresses)?;
 
@@ -36,11 +86,8 @@ impl Wallet {
             value: amount,
             script_pubkey: ScriptBuf::new_op_return(&[]),
         };
-        if change < fee {
-            return Err("Change < Fee in asset_lock_transaction()".to_string());
-        }
         let change_output = TxOut {
-            value: change - fee,
+            value: change,
             script_pubkey: change_address.script_pubkey(),
         };
         let payload = AssetLockPayload {
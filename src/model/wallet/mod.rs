@@ -2,8 +2,9 @@ mod asset_lock_transaction;
 mod utxos;
 
 use dash_sdk::dashcore_rpc::dashcore::bip32::KeyDerivationType;
-use dash_sdk::dpp::dashcore::bip32::DerivationPath;
+use dash_sdk::dpp::dashcore::bip32::{ChildNumber, DerivationPath};
 use dash_sdk::dpp::dashcore::{Address, Network, OutPoint, PrivateKey, PublicKey, TxOut};
+use dash_sdk::platform::Identifier;
 use std::collections::{BTreeMap, HashMap};
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum DerivationPathReference {
@@ -57,6 +58,7 @@ impl TryFrom<u32> for DerivationPathReference {
 use crate::context::AppContext;
 use bitflags::bitflags;
 use dash_sdk::dpp::balances::credits::Duffs;
+use sha2::{Digest, Sha256};
 
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -99,6 +101,11 @@ pub struct Wallet {
 }
 
 impl Wallet {
+    /// Identifies the wallet in settings without storing its seed
+    pub fn seed_hash(&self) -> String {
+        hex::encode(Sha256::digest(self.seed))
+    }
+
     pub fn has_balance(&self) -> bool {
         self.max_balance() > 0
     }
@@ -235,6 +242,39 @@ impl Wallet {
         extended_public_key.to_priv()
     }
 
+    /// The extended public key of the DIP-15 account the contact pays us on, serialized for a
+    /// Dashpay contact request: parent fingerprint, chain code and public key
+    pub fn contact_extended_public_key(
+        &self,
+        network: Network,
+        account: u32,
+        identity_id: &Identifier,
+        contact_id: &Identifier,
+    ) -> Result<Vec<u8>, String> {
+        let coin_type = if network == Network::Dash { 5 } else { 1 };
+        let derivation_path = DerivationPath::from(vec![
+            ChildNumber::Hardened { index: 9 },
+            ChildNumber::Hardened { index: coin_type },
+            ChildNumber::Hardened { index: 15 },
+            ChildNumber::Hardened { index: account },
+            ChildNumber::Normal256 {
+                index: identity_id.to_buffer(),
+            },
+            ChildNumber::Normal256 {
+                index: contact_id.to_buffer(),
+            },
+        ]);
+        let extended_public_key = derivation_path
+            .derive_pub_ecdsa_for_master_seed(&self.seed, network)
+            .map_err(|e| e.to_string())?;
+        Ok([
+            extended_public_key.parent_fingerprint.as_bytes().as_slice(),
+            extended_public_key.chain_code.as_bytes().as_slice(),
+            &extended_public_key.public_key.serialize(),
+        ]
+        .concat())
+    }
+
     pub fn receive_address(
         &mut self,
         network: Network,
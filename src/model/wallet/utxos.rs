@@ -1,55 +1,43 @@
+use crate::model::fee::{select_coins, CoinSelection, CoinSelectionResult};
 use crate::model::wallet::Wallet;
 use dash_sdk::dashcore_rpc::{Client, RpcApi};
 use dash_sdk::dpp::dashcore::{Address, OutPoint, PublicKey, TxOut};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::HashMap;
 use tracing::info;
 
 impl Wallet {
-    pub fn take_unspent_utxos_for(
+    /// Takes the outputs funding an asset lock of `amount` out of the wallet, picked by the
+    /// strategy with the fee at the rate. `None` when the wallet can't cover it.
+    pub fn take_utxos_for_asset_lock(
         &mut self,
         amount: u64,
-    ) -> Option<(BTreeMap<OutPoint, (TxOut, Address)>, u64)> {
+        fee_per_byte: u64,
+        strategy: CoinSelection,
+    ) -> Option<CoinSelectionResult> {
         // Ensure UTXOs exist
-        let Some(utxos) = self.utxos.as_mut() else {
-            return None;
-        };
+        let utxos = self.utxos.as_mut()?;
 
-        let mut required: i64 = amount as i64;
-        let mut taken_utxos = BTreeMap::new();
-        let mut utxos_to_remove = Vec::new();
-
-        // Iterate over the UTXOs to collect enough to cover the required amount
-        for (address, outpoints) in utxos.iter_mut() {
-            for (outpoint, tx_out) in outpoints.iter() {
-                if required <= 0 {
-                    break;
-                }
-
-                // Add the UTXO to the result
-                taken_utxos.insert(outpoint.clone(), (tx_out.clone(), address.clone()));
-
-                required -= tx_out.value as i64;
-                utxos_to_remove.push((address.clone(), outpoint.clone()));
-            }
-        }
-
-        // If not enough UTXOs were found, return None
-        if required > 0 {
-            return None;
-        }
+        let available = utxos
+            .iter()
+            .flat_map(|(address, outpoints)| {
+                outpoints
+                    .iter()
+                    .map(|(outpoint, tx_out)| (*outpoint, tx_out.clone(), address.clone()))
+            })
+            .collect();
+        let selection = select_coins(available, amount, fee_per_byte, strategy)?;
 
         // Remove the collected UTXOs from the wallet's UTXO map
-        for (address, outpoint) in utxos_to_remove {
-            if let Some(outpoints) = utxos.get_mut(&address) {
-                outpoints.remove(&outpoint);
+        for (outpoint, _, address) in &selection.inputs {
+            if let Some(outpoints) = utxos.get_mut(address) {
+                outpoints.remove(outpoint);
                 if outpoints.is_empty() {
-                    utxos.remove(&address);
+                    utxos.remove(address);
                 }
             }
         }
 
-        // Return the collected UTXOs and the remaining amount (which should be zero or positive)
-        Some((taken_utxos, required.abs() as u64))
+        Some(selection)
     }
 
     pub fn reload_utxos(
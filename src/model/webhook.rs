@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Payload of the generic template, every common placeholder as a JSON field
+pub const TEMPLATE_GENERIC: &str = r#"{
+  "event": "{{event}}",
+  "network": "{{network}}",
+  "time": "{{time}}",
+  "message": "{{message}}"
+}"#;
+
+pub const TEMPLATE_DISCORD: &str = r#"{"content": "{{message}}"}"#;
+
+pub const TEMPLATE_SLACK: &str = r#"{"text": "{{message}}"}"#;
+
+/// Something that happened that a webhook can be told about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum WebhookEvent {
+    /// Contests that appeared since the last refresh of the contested names
+    NewContest,
+    /// Contests ending soon without a vote of any of our identities
+    ContestEndingUnvoted,
+    /// An identity dropped below its minimum balance
+    BalanceBelowMinimum,
+    TaskFailed,
+}
+
+impl WebhookEvent {
+    pub const ALL: [WebhookEvent; 4] = [
+        WebhookEvent::NewContest,
+        WebhookEvent::ContestEndingUnvoted,
+        WebhookEvent::BalanceBelowMinimum,
+        WebhookEvent::TaskFailed,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            WebhookEvent::NewContest => "New contest",
+            WebhookEvent::ContestEndingUnvoted => "Contest ending un-voted",
+            WebhookEvent::BalanceBelowMinimum => "Balance below minimum",
+            WebhookEvent::TaskFailed => "Task failed",
+        }
+    }
+
+    /// Name of the event in payloads, the `{{event}}` placeholder
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::NewContest => "new_contest",
+            WebhookEvent::ContestEndingUnvoted => "contest_ending_unvoted",
+            WebhookEvent::BalanceBelowMinimum => "balance_below_minimum",
+            WebhookEvent::TaskFailed => "task_failed",
+        }
+    }
+
+    /// Placeholders the event fills besides the common ones
+    pub fn placeholders(&self) -> &'static [&'static str] {
+        match self {
+            WebhookEvent::NewContest => &["names", "count"],
+            WebhookEvent::ContestEndingUnvoted => &["names", "count", "hours"],
+            WebhookEvent::BalanceBelowMinimum => &["identity", "balance", "minimum"],
+            WebhookEvent::TaskFailed => &["error"],
+        }
+    }
+}
+
+/// Placeholders every event fills
+pub const COMMON_PLACEHOLDERS: [&str; 4] = ["event", "network", "time", "message"];
+
+/// A URL posted to when one of its events happens, with a JSON body rendered from a template
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventWebhook {
+    pub name: String,
+    pub enabled: bool,
+    pub url: String,
+    pub events: BTreeSet<WebhookEvent>,
+    /// JSON with `{{placeholder}}`s, each replaced by its value escaped for a JSON string
+    pub template: String,
+}
+
+impl Default for EventWebhook {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            enabled: true,
+            url: String::new(),
+            events: WebhookEvent::ALL.into_iter().collect(),
+            template: TEMPLATE_GENERIC.to_string(),
+        }
+    }
+}
+
+impl EventWebhook {
+    pub fn wants(&self, event: WebhookEvent) -> bool {
+        self.enabled && !self.url.trim().is_empty() && self.events.contains(&event)
+    }
+
+    /// Checks that the template gives valid JSON, with every placeholder filled
+    pub fn validate_template(&self) -> Result<(), String> {
+        let sample = WebhookNotification {
+            event: WebhookEvent::TaskFailed,
+            message: "Sample \"message\"".to_string(),
+            fields: BTreeMap::new(),
+        };
+        sample.render(&self.template, "testnet", 0).map(|_| ())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookSettings {
+    pub webhooks: Vec<EventWebhook>,
+    /// How long before its end an un-voted contest is notified
+    pub contest_ending_hours: u32,
+}
+
+impl Default for WebhookSettings {
+    fn default() -> Self {
+        Self {
+            webhooks: Vec::new(),
+            contest_ending_hours: 24,
+        }
+    }
+}
+
+impl WebhookSettings {
+    pub fn wants(&self, event: WebhookEvent) -> bool {
+        self.webhooks.iter().any(|webhook| webhook.wants(event))
+    }
+}
+
+/// One occurrence of an event, rendered into the template of each webhook wanting it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookNotification {
+    pub event: WebhookEvent,
+    /// Readable summary of what happened, the `{{message}}` placeholder
+    pub message: String,
+    /// Values of the event's own placeholders
+    pub fields: BTreeMap<&'static str, String>,
+}
+
+impl WebhookNotification {
+    pub fn new(event: WebhookEvent, message: String) -> Self {
+        Self {
+            event,
+            message,
+            fields: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_field(mut self, placeholder: &'static str, value: impl ToString) -> Self {
+        self.fields.insert(placeholder, value.to_string());
+        self
+    }
+
+    /// Fills the placeholders of the template and parses the result. Placeholders the event
+    /// doesn't know become empty.
+    pub fn render(
+        &self,
+        template: &str,
+        network: &str,
+        time: i64,
+    ) -> Result<serde_json::Value, String> {
+        let time = chrono::DateTime::from_timestamp(time, 0)
+            .unwrap_or_default()
+            .to_rfc3339();
+        let mut rendered = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            rendered.push_str(&rest[..start]);
+            let Some(length) = rest[start + 2..].find("}}") else {
+                return Err("A {{ of the template isn't closed".to_string());
+            };
+            let placeholder = rest[start + 2..start + 2 + length].trim();
+            let value = match placeholder {
+                "event" => self.event.as_str(),
+                "network" => network,
+                "time" => time.as_str(),
+                "message" => self.message.as_str(),
+                other => self.fields.get(other).map(String::as_str).unwrap_or(""),
+            };
+            rendered.push_str(&escape_json_string(value));
+            rest = &rest[start + 2 + length + 2..];
+        }
+        rendered.push_str(rest);
+        serde_json::from_str(&rendered)
+            .map_err(|e| format!("The template doesn't give valid JSON: {}", e))
+    }
+}
+
+/// The value as the inside of a JSON string, without the quotes
+fn escape_json_string(value: &str) -> String {
+    let quoted = serde_json::to_string(value).expect("strings serialize");
+    quoted[1..quoted.len() - 1].to_string()
+}
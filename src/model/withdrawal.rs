@@ -0,0 +1,140 @@
+use crate::model::finality::{FinalityPolicy, L1State};
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::TimestampMillis;
+use dash_sdk::dpp::prelude::Identifier;
+use std::fmt;
+
+/// The `status` of a document of the withdrawals contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WithdrawalStatus {
+    Queued,
+    Pooled,
+    Broadcasted,
+    Completed,
+    Expired,
+}
+
+impl WithdrawalStatus {
+    /// Statuses of withdrawals that are not paid out yet
+    pub const PENDING: [WithdrawalStatus; 3] = [
+        WithdrawalStatus::Queued,
+        WithdrawalStatus::Pooled,
+        WithdrawalStatus::Broadcasted,
+    ];
+
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(WithdrawalStatus::Queued),
+            1 => Some(WithdrawalStatus::Pooled),
+            2 => Some(WithdrawalStatus::Broadcasted),
+            3 => Some(WithdrawalStatus::Completed),
+            4 => Some(WithdrawalStatus::Expired),
+            _ => None,
+        }
+    }
+
+    pub fn to_u8(self) -> u8 {
+        match self {
+            WithdrawalStatus::Queued => 0,
+            WithdrawalStatus::Pooled => 1,
+            WithdrawalStatus::Broadcasted => 2,
+            WithdrawalStatus::Completed => 3,
+            WithdrawalStatus::Expired => 4,
+        }
+    }
+
+    pub fn is_pending(self) -> bool {
+        Self::PENDING.contains(&self)
+    }
+}
+
+impl fmt::Display for WithdrawalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            WithdrawalStatus::Queued => "Queued",
+            WithdrawalStatus::Pooled => "Pooled",
+            WithdrawalStatus::Broadcasted => "Broadcasted",
+            WithdrawalStatus::Completed => "Completed",
+            WithdrawalStatus::Expired => "Expired",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// A withdrawal document from Platform, along with what Core knows about its payout
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithdrawalRecord {
+    pub document_id: Identifier,
+    pub owner_id: Identifier,
+    pub amount: Credits,
+    pub status: WithdrawalStatus,
+    /// Index of the asset unlock transaction, set once the withdrawal is pooled
+    pub transaction_index: Option<u64>,
+    pub address: Option<String>,
+    pub created_at: Option<TimestampMillis>,
+    pub updated_at: Option<TimestampMillis>,
+    /// Whether the withdrawal belongs to one of our identities
+    pub is_mine: bool,
+    /// Status of the asset unlock transaction as reported by Core, if Core could be asked
+    pub core_status: Option<String>,
+    /// Position among the queued withdrawals, only known when the whole queue was fetched
+    pub queue_position: Option<usize>,
+}
+
+impl WithdrawalRecord {
+    /// The state of the asset unlock transaction from Core's status, `None` before Core knows
+    /// about it. Core only tells whether it was mined, not how deep, so a mined transaction
+    /// counts as 1 confirmation.
+    pub fn l1_state(&self) -> Option<L1State> {
+        match self.core_status.as_deref()? {
+            "Chainlocked" => Some(L1State {
+                confirmations: 1,
+                instant_locked: false,
+                chain_locked: true,
+            }),
+            "Mined" => Some(L1State {
+                confirmations: 1,
+                ..L1State::default()
+            }),
+            "Mempooled" => Some(L1State::default()),
+            _ => None,
+        }
+    }
+
+    /// Whether the payout is final by the policy. Platform only completes a withdrawal once
+    /// its transaction is chain locked, so completed withdrawals always are.
+    pub fn is_final(&self, policy: &FinalityPolicy) -> bool {
+        match self.status {
+            WithdrawalStatus::Completed => true,
+            WithdrawalStatus::Broadcasted => {
+                self.l1_state().is_some_and(|state| policy.is_final(&state))
+            }
+            _ => false,
+        }
+    }
+
+    /// A human readable estimate of when the withdrawal will be paid out, waiting for what the
+    /// policy requires
+    pub fn expected_completion(&self, policy: &FinalityPolicy) -> String {
+        match self.status {
+            WithdrawalStatus::Queued => match self.queue_position {
+                Some(position) => format!("Waiting to be pooled (#{} in queue)", position + 1),
+                None => "Waiting to be pooled".to_string(),
+            },
+            WithdrawalStatus::Pooled => "In the next asset unlock transaction".to_string(),
+            WithdrawalStatus::Broadcasted if self.is_final(policy) => format!(
+                "Final by your policy ({}), Platform completes it after the chain lock",
+                self.core_status.as_deref().unwrap_or_default()
+            ),
+            WithdrawalStatus::Broadcasted => match &self.core_status {
+                Some(core_status) => format!("Awaiting {} ({})", policy.describe(), core_status),
+                None => format!("Awaiting {}", policy.describe()),
+            },
+            WithdrawalStatus::Completed => match &self.core_status {
+                Some(core_status) => format!("Paid out ({})", core_status),
+                None => "Paid out".to_string(),
+            },
+            WithdrawalStatus::Expired => "Expired, not paid out".to_string(),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::model::node_log::{expand_home, NodeLogOrigin, NodeLogSource};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Most lines kept per log, older ones are dropped
+pub const MAX_LINES: usize = 5000;
+
+/// Lines read from the end of a log when tailing starts
+const INITIAL_LINES: usize = 500;
+
+/// How often a log file is checked for new lines
+const FILE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The lines read so far and the error that stopped the tail, if any
+#[derive(Debug, Default)]
+struct TailState {
+    lines: VecDeque<String>,
+    error: Option<String>,
+}
+
+impl TailState {
+    fn push(&mut self, line: String) {
+        if self.lines.len() == MAX_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+}
+
+/// Follows a node log on background threads until dropped
+pub struct LogTail {
+    pub source: NodeLogSource,
+    state: Arc<Mutex<TailState>>,
+    stop: Arc<AtomicBool>,
+    /// The `docker logs --follow` process of a container source
+    child: Option<Child>,
+}
+
+impl LogTail {
+    pub fn start(source: NodeLogSource) -> Self {
+        let state = Arc::new(Mutex::new(TailState::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let child = match &source.origin {
+            NodeLogOrigin::File { path } => {
+                let path = expand_home(path);
+                let (state, stop) = (state.clone(), stop.clone());
+                std::thread::spawn(move || {
+                    if let Err(e) = follow_file(&path, &state, &stop) {
+                        state.lock().unwrap().error = Some(e);
+                    }
+                });
+                None
+            }
+            NodeLogOrigin::Docker { container } => match spawn_docker_logs(container, &state) {
+                Ok(child) => Some(child),
+                Err(e) => {
+                    state.lock().unwrap().error = Some(e);
+                    None
+                }
+            },
+        };
+        Self {
+            source,
+            state,
+            stop,
+            child,
+        }
+    }
+
+    /// Copies the lines read so far, oldest first
+    pub fn lines(&self) -> Vec<String> {
+        self.state.lock().unwrap().lines.iter().cloned().collect()
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.state.lock().unwrap().error.clone()
+    }
+
+    pub fn clear(&self) {
+        self.state.lock().unwrap().lines.clear();
+    }
+}
+
+impl Drop for LogTail {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(child) = &mut self.child {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// Reads the last lines of the file, then the lines appended to it. Starts over when the file
+/// is truncated or rotated.
+fn follow_file(path: &str, state: &Mutex<TailState>, stop: &AtomicBool) -> Result<(), String> {
+    let open = || File::open(path).map_err(|e| format!("Failed to open {}: {}", path, e));
+    let mut file = open()?;
+    let contents = read_to_end_lossy(&mut file)?;
+    let mut position = file.stream_position().map_err(|e| e.to_string())?;
+    {
+        let mut state = state.lock().unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        for line in &lines[lines.len().saturating_sub(INITIAL_LINES)..] {
+            state.push(line.to_string());
+        }
+    }
+    let mut partial = String::new();
+    while !stop.load(Ordering::Relaxed) {
+        std::thread::sleep(FILE_POLL_INTERVAL);
+        let length = std::fs::metadata(path)
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        if length < position {
+            file = open()?;
+            position = 0;
+            partial.clear();
+        }
+        if length == position {
+            continue;
+        }
+        file.seek(SeekFrom::Start(position))
+            .map_err(|e| e.to_string())?;
+        partial.push_str(&read_to_end_lossy(&mut file)?);
+        position = file.stream_position().map_err(|e| e.to_string())?;
+        // Keep an unfinished last line until its end is written
+        let complete = partial.rfind('\n').map_or(0, |index| index + 1);
+        let mut state = state.lock().unwrap();
+        for line in partial[..complete].lines() {
+            state.push(line.to_string());
+        }
+        partial.drain(..complete);
+    }
+    Ok(())
+}
+
+fn read_to_end_lossy(file: &mut File) -> Result<String, String> {
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Starts `docker logs --follow` and reads its output and errors into the state, as the
+/// containers log to both
+fn spawn_docker_logs(container: &str, state: &Arc<Mutex<TailState>>) -> Result<Child, String> {
+    let mut child = Command::new("docker")
+        .args(["logs", "--follow", "--tail", &INITIAL_LINES.to_string()])
+        .arg(container)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run docker logs: {}", e))?;
+    let stdout = child
+        .stdout
+        .take()
+        .map(|out| Box::new(out) as Box<dyn Read + Send>);
+    let stderr = child
+        .stderr
+        .take()
+        .map(|err| Box::new(err) as Box<dyn Read + Send>);
+    for output in [stdout, stderr].into_iter().flatten() {
+        let state = state.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(output).lines() {
+                match line {
+                    Ok(line) => state.lock().unwrap().push(line),
+                    Err(_) => break,
+                }
+            }
+        });
+    }
+    Ok(child)
+}
@@ -0,0 +1,146 @@
+use crate::context::AppContext;
+use crate::model::clock_skew::network_now;
+use crate::model::notification_sound::{NotificationSounds, SoundChoice, SoundEvent};
+use crate::model::withdrawal::{WithdrawalRecord, WithdrawalStatus};
+use dash_sdk::platform::Identifier;
+use rodio::source::SineWave;
+use rodio::{Decoder, OutputStream, Sink, Source};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::BufReader;
+use std::time::{Duration, Instant};
+
+/// How often the watched contests are checked for ones ending without our vote
+const CONTEST_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Plays the sound on its own thread, so a slow or missing audio device never blocks the UI
+pub fn play_sound(sound: &SoundChoice, volume: f32) {
+    let sound = sound.clone();
+    std::thread::spawn(move || {
+        if let Err(e) = play_blocking(&sound, volume) {
+            tracing::warn!("Failed to play a notification sound: {}", e);
+        }
+    });
+}
+
+fn play_blocking(sound: &SoundChoice, volume: f32) -> Result<(), String> {
+    let (_stream, handle) = OutputStream::try_default().map_err(|e| e.to_string())?;
+    let sink = Sink::try_new(&handle).map_err(|e| e.to_string())?;
+    sink.set_volume(volume.clamp(0.0, 1.0));
+    match sound {
+        SoundChoice::File { path } => {
+            let file = File::open(path).map_err(|e| format!("{}: {}", path, e))?;
+            let decoder =
+                Decoder::new(BufReader::new(file)).map_err(|e| format!("{}: {}", path, e))?;
+            sink.append(decoder);
+        }
+        built_in => {
+            for (frequency, millis) in tones(built_in) {
+                sink.append(
+                    SineWave::new(*frequency)
+                        .take_duration(Duration::from_millis(*millis))
+                        .amplify(0.3),
+                );
+            }
+        }
+    }
+    sink.sleep_until_end();
+    Ok(())
+}
+
+/// The notes of a built-in sound as frequency in Hz and length in milliseconds, 0 Hz is a pause
+fn tones(sound: &SoundChoice) -> &'static [(f32, u64)] {
+    match sound {
+        SoundChoice::Chime => &[(880.0, 150), (1318.5, 300)],
+        SoundChoice::Beep => &[(1000.0, 200)],
+        SoundChoice::Alarm => &[
+            (988.0, 150),
+            (0.0, 80),
+            (988.0, 150),
+            (0.0, 80),
+            (988.0, 150),
+        ],
+        SoundChoice::File { .. } => &[],
+    }
+}
+
+/// Decides when an event deserves its sound, so each contest and withdrawal plays once
+#[derive(Default)]
+pub struct SoundAlerts {
+    /// Watched contests already reminded of, by network and normalized name
+    reminded_contests: HashSet<(String, String)>,
+    /// Last seen status of our withdrawals
+    withdrawal_statuses: HashMap<Identifier, WithdrawalStatus>,
+    last_contest_check: Option<Instant>,
+}
+
+impl SoundAlerts {
+    /// Plays the sound of the event if the user turned it on
+    pub fn play(&self, event: SoundEvent, sounds: &NotificationSounds) {
+        if let Some(sound) = sounds.sound_for(event) {
+            play_sound(sound, sounds.volume);
+        }
+    }
+
+    /// Plays a sound when one of our withdrawals turned completed since it was last seen.
+    /// Withdrawals that were already completed when first seen are old news.
+    pub fn check_withdrawals(&mut self, records: &[WithdrawalRecord], sounds: &NotificationSounds) {
+        let mut completed = false;
+        for record in records.iter().filter(|record| record.is_mine) {
+            let previous = self
+                .withdrawal_statuses
+                .insert(record.document_id, record.status);
+            completed |= record.status == WithdrawalStatus::Completed
+                && previous.is_some_and(|previous| previous != WithdrawalStatus::Completed);
+        }
+        if completed {
+            self.play(SoundEvent::WithdrawalCompleted, sounds);
+        }
+    }
+
+    /// Reminds of watched contests that end within the warning time while none of our
+    /// identities voted on them, checked once a minute
+    pub fn check_contests(&mut self, app_context: &AppContext) {
+        if self
+            .last_contest_check
+            .is_some_and(|last_check| last_check.elapsed() < CONTEST_CHECK_INTERVAL)
+        {
+            return;
+        }
+        self.last_contest_check = Some(Instant::now());
+        let sounds = app_context.preferences().notification_sounds;
+        if sounds.sound_for(SoundEvent::ContestEndingUnvoted).is_none() {
+            return;
+        }
+
+        let watched = match app_context.db.get_watched_contests(app_context) {
+            Ok(watched) => watched,
+            Err(e) => {
+                tracing::warn!("Failed to load the watched contests: {}", e);
+                return;
+            }
+        };
+        if watched.is_empty() {
+            return;
+        }
+        let contested_names = app_context.all_contested_names().unwrap_or_default();
+        let now = network_now().timestamp_millis().max(0) as u64;
+        let warning_millis = sounds.contest_warning_minutes as u64 * 60_000;
+        let network = app_context.network_string();
+        let mut remind = false;
+        for contested_name in contested_names {
+            let name = &contested_name.normalized_contested_name;
+            let ends_soon = contested_name
+                .end_time
+                .is_some_and(|end_time| end_time > now && end_time - now <= warning_millis);
+            if ends_soon && watched.contains(name) && contested_name.my_votes.is_empty() {
+                remind |= self
+                    .reminded_contests
+                    .insert((network.clone(), name.clone()));
+            }
+        }
+        if remind {
+            self.play(SoundEvent::ContestEndingUnvoted, &sounds);
+        }
+    }
+}
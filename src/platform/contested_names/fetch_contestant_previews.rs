@@ -0,0 +1,163 @@
+use crate::context::AppContext;
+use crate::model::contested_name::{ContestantPreview, PreorderPreview};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::Value;
+use dash_sdk::dpp::util::hash::hash_double;
+use dash_sdk::dpp::voting::contender_structs::ContenderWithSerializedDocument;
+use dash_sdk::dpp::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+use dash_sdk::drive::query::vote_poll_vote_state_query::{
+    ContestedDocumentVotePollDriveQuery, ContestedDocumentVotePollDriveQueryResultType,
+};
+use dash_sdk::drive::query::{WhereClause, WhereOperator};
+use dash_sdk::platform::{Document, DocumentQuery, Fetch, FetchMany, Identifier, Identity};
+use dash_sdk::Sdk;
+
+impl AppContext {
+    /// Fetches the domain and preorder documents of each contestant of a contest, and the
+    /// identity behind it
+    pub(super) async fn fetch_contestant_previews(
+        &self,
+        name: &str,
+        sdk: &Sdk,
+    ) -> Result<Vec<ContestantPreview>, String> {
+        let data_contract = self.dpns_contract.as_ref();
+        let document_type = data_contract
+            .document_type_for_name("domain")
+            .expect("expected document type");
+        let Some(contested_index) = document_type.find_contested_index() else {
+            return Err("No contested index on dpns domains".to_string());
+        };
+        let vote_poll = ContestedDocumentResourceVotePoll {
+            index_name: contested_index.name.clone(),
+            index_values: vec![Value::from("dash"), Value::Text(name.to_string())], // hardcoded for dpns
+            document_type_name: document_type.name().to_string(),
+            contract_id: data_contract.id(),
+        };
+        let contenders_query = ContestedDocumentVotePollDriveQuery {
+            limit: None,
+            offset: None,
+            start_at: None,
+            vote_poll,
+            allow_include_locked_and_abstaining_vote_tally: false,
+            result_type: ContestedDocumentVotePollDriveQueryResultType::Documents,
+        };
+        // Contested domains are only stored with the vote poll until the contest ends
+        let contenders = ContenderWithSerializedDocument::fetch_many(sdk, contenders_query)
+            .await
+            .map_err(|e| format!("Error fetching contestants: {}", e))?;
+
+        let mut previews = vec![];
+        for (identity_id, contender) in &contenders.contenders {
+            let contender = contender
+                .try_to_contender(document_type, self.platform_version)
+                .map_err(|e| format!("Invalid contestant document: {}", e))?;
+            let Some(domain) = contender.document() else {
+                continue;
+            };
+            previews.push(
+                self.fetch_contestant_preview(sdk, *identity_id, domain)
+                    .await?,
+            );
+        }
+        Ok(previews)
+    }
+
+    async fn fetch_contestant_preview(
+        &self,
+        sdk: &Sdk,
+        identity_id: Identifier,
+        domain: &Document,
+    ) -> Result<ContestantPreview, String> {
+        let text = |field: &str| {
+            domain
+                .get(field)
+                .and_then(|value| value.as_text())
+                .unwrap_or_default()
+                .to_string()
+        };
+        let label = text("label");
+        let records_identity = domain
+            .get("records.identity")
+            .and_then(|identity| identity.to_identifier().ok());
+
+        // The preorder committed to the salted hash of the full name
+        let preorder = match domain
+            .get("preorderSalt")
+            .and_then(|salt| salt.to_binary_bytes().ok())
+        {
+            Some(salt) => {
+                let mut salted_domain = salt;
+                salted_domain.extend(format!("{}.dash", text("normalizedLabel")).as_bytes());
+                let preorder_query = DocumentQuery::new(self.dpns_contract.clone(), "preorder")
+                    .map_err(|e| e.to_string())?
+                    .with_where(WhereClause {
+                        field: "saltedDomainHash".to_string(),
+                        operator: WhereOperator::Equal,
+                        value: hash_double(salted_domain).into(),
+                    });
+                Document::fetch(sdk, preorder_query)
+                    .await
+                    .map_err(|e| format!("Error fetching preorder: {}", e))?
+                    .map(|preorder| PreorderPreview {
+                        document_id: preorder.id(),
+                        created_at: preorder.created_at(),
+                        created_at_block_height: preorder.created_at_block_height(),
+                    })
+            }
+            None => None,
+        };
+
+        let identity = Identity::fetch_by_identifier(sdk, identity_id)
+            .await
+            .map_err(|e| format!("Error fetching identity: {}", e))?;
+
+        // Names the identity already owns
+        let domain_query = DocumentQuery::new(self.dpns_contract.clone(), "domain")
+            .map_err(|e| e.to_string())?
+            .with_where(WhereClause {
+                field: "records.identity".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(identity_id.to_buffer()),
+            });
+        let domains = Document::fetch_many(sdk, domain_query)
+            .await
+            .map_err(|e| format!("Error fetching DPNS names: {}", e))?;
+        let mut other_names: Vec<String> = domains
+            .values()
+            .flatten()
+            .filter_map(|document| {
+                let label = document.get("label")?.as_text()?;
+                let parent = document.get("normalizedParentDomainName")?.as_text()?;
+                Some(format!("{}.{}", label, parent))
+            })
+            .collect();
+        other_names.sort();
+
+        let active_since = domains
+            .values()
+            .flatten()
+            .filter_map(|document| document.created_at())
+            .chain(domain.created_at())
+            .chain(preorder.as_ref().and_then(|preorder| preorder.created_at))
+            .min();
+
+        Ok(ContestantPreview {
+            identity_id,
+            label,
+            domain_document_id: domain.id(),
+            records_identity,
+            domain_created_at: domain.created_at(),
+            domain_created_at_block_height: domain.created_at_block_height(),
+            preorder,
+            balance: identity.as_ref().map(|identity| identity.balance()),
+            public_key_count: identity
+                .as_ref()
+                .map_or(0, |identity| identity.public_keys().len()),
+            other_names,
+            active_since,
+        })
+    }
+}
@@ -0,0 +1,71 @@
+use crate::context::AppContext;
+use crate::model::contestant_reputation::{ContestOutcomes, ContestantReputation};
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::platform_value::Value;
+use dash_sdk::drive::query::{WhereClause, WhereOperator};
+use dash_sdk::platform::{Document, DocumentQuery, Fetch, FetchMany, Identifier};
+use dash_sdk::Sdk;
+
+impl AppContext {
+    /// Fetches the names and Dashpay profile of each contestant and stores them. Returns the
+    /// reputation of every contestant fetched so far.
+    pub(super) async fn fetch_contestant_reputations(
+        &self,
+        identity_ids: &[Identifier],
+        sdk: &Sdk,
+    ) -> Result<Vec<ContestantReputation>, String> {
+        for identity_id in identity_ids {
+            let reputation = self.fetch_contestant_reputation(sdk, *identity_id).await?;
+            self.db
+                .save_contestant_reputation(&reputation, self)
+                .map_err(|e| e.to_string())?;
+        }
+        self.db
+            .get_contestant_reputations(self)
+            .map(|reputations| reputations.into_values().collect())
+            .map_err(|e| e.to_string())
+    }
+
+    async fn fetch_contestant_reputation(
+        &self,
+        sdk: &Sdk,
+        identity_id: Identifier,
+    ) -> Result<ContestantReputation, String> {
+        let domain_query = DocumentQuery::new(self.dpns_contract.clone(), "domain")
+            .map_err(|e| e.to_string())?
+            .with_where(WhereClause {
+                field: "records.identity".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(identity_id.to_buffer()),
+            });
+        let domains = Document::fetch_many(sdk, domain_query)
+            .await
+            .map_err(|e| format!("Error fetching DPNS names: {}", e))?;
+        let domains: Vec<&Document> = domains.values().flatten().collect();
+
+        let profile_query = DocumentQuery::new(self.dashpay_contract.clone(), "profile")
+            .map_err(|e| e.to_string())?
+            .with_where(WhereClause {
+                field: "$ownerId".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(identity_id.to_buffer()),
+            });
+        let profile = Document::fetch(sdk, profile_query)
+            .await
+            .map_err(|e| format!("Error fetching Dashpay profile: {}", e))?;
+
+        Ok(ContestantReputation {
+            identity_id,
+            names_held: domains.len() as u32,
+            has_dashpay_profile: profile.is_some(),
+            active_since: domains
+                .iter()
+                .filter_map(|document| document.created_at())
+                .chain(profile.as_ref().and_then(|profile| profile.created_at()))
+                .min(),
+            fetched_at: chrono::Utc::now().timestamp(),
+            // Filled in from the local contests when read back
+            outcomes: ContestOutcomes::default(),
+        })
+    }
+}
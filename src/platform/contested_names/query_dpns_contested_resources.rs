@@ -1,11 +1,13 @@
 use crate::app::TaskResult;
 use crate::context::AppContext;
+use crate::model::webhook::{WebhookEvent, WebhookNotification};
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dash_sdk::drive::query::vote_polls_by_document_type_query::VotePollsByDocumentTypeQuery;
 use dash_sdk::platform::FetchMany;
 use dash_sdk::query_types::ContestedResource;
 use dash_sdk::Sdk;
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::{mpsc, OwnedSemaphorePermit, Semaphore};
 
@@ -53,11 +55,41 @@ impl AppContext {
             })
             .collect();
 
+        // Names known before this refresh, to tell the contests that just appeared
+        let known_names: HashSet<String> = self
+            .db
+            .get_all_contested_names(self)
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|contested_name| contested_name.normalized_contested_name)
+            .collect();
+        let new_names: Vec<String> = contested_resources_as_strings
+            .iter()
+            .filter(|name| !known_names.contains(*name))
+            .cloned()
+            .collect();
+
         let names_to_be_updated = self
             .db
             .insert_name_contests_as_normalized_names(contested_resources_as_strings, &self)
             .map_err(|e| e.to_string())?;
 
+        // On the first refresh every contest is new, that isn't worth a notification
+        if !known_names.is_empty() && !new_names.is_empty() {
+            self.notify_webhooks(
+                WebhookNotification::new(
+                    WebhookEvent::NewContest,
+                    format!(
+                        "{} new contest(s): {}",
+                        new_names.len(),
+                        new_names.join(", ")
+                    ),
+                )
+                .with_field("names", new_names.join(", "))
+                .with_field("count", new_names.len()),
+            );
+        }
+
         sender
             .send(TaskResult::Refresh)
             .await
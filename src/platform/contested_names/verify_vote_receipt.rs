@@ -0,0 +1,98 @@
+use crate::context::AppContext;
+use crate::model::vote_proof::{vote_choice_label, VoteProofReceipt};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::platform_value::{Identifier, Value};
+use dash_sdk::dpp::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+use dash_sdk::dpp::voting::votes::resource_vote::accessors::v0::ResourceVoteGettersV0;
+use dash_sdk::dpp::voting::votes::Vote;
+use dash_sdk::platform::query::{Query, VoteQuery};
+use dash_sdk::platform::FromProof;
+
+impl AppContext {
+    /// Checks the proof of the receipt against the quorum that signed it, and that it proves
+    /// the vote the receipt claims on the contest it names. Nothing is fetched besides the
+    /// quorum's public key.
+    pub(super) fn verify_vote_receipt(&self, receipt: &VoteProofReceipt) -> Result<(), String> {
+        if receipt.network != self.network_string() {
+            return Err(format!(
+                "The receipt is for {}, switch to that network to verify it",
+                receipt.network
+            ));
+        }
+        let identifier = |field: &str, value: &str| {
+            Identifier::from_string(value, Encoding::Base58)
+                .map_err(|_| format!("The {} of the receipt is not a valid id", field))
+        };
+        let vote_poll_id = identifier("vote poll id", &receipt.vote_poll_id)?;
+        // The poll id is recomputed from the name, so a proof of a vote on another contest
+        // can't be passed off as a vote on this one
+        if vote_poll_id != self.dpns_vote_poll_id(&receipt.contest)? {
+            return Err(format!(
+                "The vote poll of the receipt is not the contest for {}",
+                receipt.contest
+            ));
+        }
+        let query = VoteQuery {
+            identity_id: identifier("voter id", &receipt.voter_id)?,
+            vote_poll_id,
+        };
+        let request = query.query(true).map_err(|e| e.to_string())?;
+        let response = receipt.to_response()?;
+        let sdk = self.sdk();
+        let provider = sdk
+            .context_provider()
+            .ok_or("No context provider to look up the quorum with".to_string())?;
+
+        let (vote, _, _) = Vote::maybe_from_proof_with_metadata(
+            request,
+            response,
+            self.network,
+            self.platform_version,
+            &provider,
+        )
+        .map_err(|e| format!("The proof is not valid: {}", e))?;
+        let Some(Vote::ResourceVote(resource_vote)) = vote else {
+            return Err("The proof shows no vote of the masternode on the contest".to_string());
+        };
+        let proved_poll_id = resource_vote
+            .vote_poll()
+            .unique_id()
+            .map_err(|e| format!("The proved vote poll has no id: {}", e))?;
+        if proved_poll_id != vote_poll_id {
+            return Err(format!(
+                "The proof shows a vote on another contest than {}",
+                receipt.contest
+            ));
+        }
+        let choice = vote_choice_label(&resource_vote.resource_vote_choice());
+        if choice != receipt.choice {
+            return Err(format!(
+                "The proof shows the vote {}, not {}",
+                choice, receipt.choice
+            ));
+        }
+        Ok(())
+    }
+
+    /// Id of the vote poll of the contest for a normalized DPNS name, computed the way the
+    /// vote was cast
+    fn dpns_vote_poll_id(&self, name: &str) -> Result<Identifier, String> {
+        let data_contract = self.dpns_contract.as_ref();
+        let document_type = data_contract
+            .document_type_for_name("domain")
+            .map_err(|e| e.to_string())?;
+        let Some(contested_index) = document_type.find_contested_index() else {
+            return Err("No contested index on dpns domains".to_string());
+        };
+        let vote_poll = ContestedDocumentResourceVotePoll {
+            index_name: contested_index.name.clone(),
+            // Hardcoded values for DPNS
+            index_values: vec![Value::from("dash"), Value::Text(name.to_string())],
+            document_type_name: document_type.name().to_string(),
+            contract_id: data_contract.id(),
+        };
+        vote_poll.unique_id().map_err(|e| e.to_string())
+    }
+}
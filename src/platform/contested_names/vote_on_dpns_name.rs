@@ -1,17 +1,30 @@
 use crate::app::TaskResult;
 use crate::context::AppContext;
+use crate::model::clock_skew::network_now;
 use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::vote_broadcast::{reconcile, BroadcastAttempt, BroadcastFailure};
+use crate::model::vote_proof::{vote_choice_label, VoteProofReceipt, VOTE_RECEIPT_VERSION};
+use crate::model::vote_webhook::{VoteReceipt, VoteSessionSummary};
 use crate::platform::BackendTaskSuccessResult;
+use dash_sdk::dpp::dashcore::hashes::{sha256, Hash};
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
-use dash_sdk::dpp::platform_value::Value;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::platform_value::{Identifier, Value};
+use dash_sdk::dpp::serialization::PlatformSerializable;
+use dash_sdk::dpp::state_transition::masternode_vote_transition::methods::MasternodeVoteTransitionMethodsV0;
+use dash_sdk::dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
+use dash_sdk::dpp::state_transition::StateTransition;
 use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
 use dash_sdk::dpp::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
 use dash_sdk::dpp::voting::votes::resource_vote::v0::ResourceVoteV0;
 use dash_sdk::dpp::voting::votes::resource_vote::ResourceVote;
 use dash_sdk::dpp::voting::votes::Vote;
-use dash_sdk::platform::transition::vote::PutVote;
+use dash_sdk::platform::query::VoteQuery;
+use dash_sdk::platform::transition::broadcast::BroadcastStateTransition;
+use dash_sdk::platform::Fetch;
 use dash_sdk::Sdk;
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -23,8 +36,70 @@ impl AppContext {
         vote_choice: ResourceVoteChoice,
         voters: &Vec<QualifiedIdentity>,
         sdk: Sdk,
-        _sender: mpsc::Sender<TaskResult>,
+        sender: mpsc::Sender<TaskResult>,
     ) -> Result<BackendTaskSuccessResult, String> {
+        let mut receipts = vec![];
+        let result = self
+            .cast_dpns_votes(name, vote_choice, voters, &sdk, &mut receipts)
+            .await;
+        let failures = match &result {
+            Ok(_) => vec![],
+            Err(e) => vec![format!("{}: {}", name, e)],
+        };
+        self.report_vote_session(receipts, failures, &sender).await;
+        result.map(BackendTaskSuccessResult::SuccessfulVotes)
+    }
+
+    /// Votes on each name in turn. A failed name does not stop the others, its error is sent
+    /// to the UI as it happens and the task only fails when no vote went through.
+    pub(super) async fn vote_on_multiple_dpns_names(
+        self: &Arc<Self>,
+        votes: &[(String, ResourceVoteChoice)],
+        voters: &Vec<QualifiedIdentity>,
+        sdk: Sdk,
+        sender: mpsc::Sender<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let mut vote_results = vec![];
+        let mut receipts = vec![];
+        let mut failures = vec![];
+
+        for (name, vote_choice) in votes {
+            match self
+                .cast_dpns_votes(name, *vote_choice, voters, &sdk, &mut receipts)
+                .await
+            {
+                Ok(results) => vote_results.extend(results),
+                Err(e) => {
+                    let error = format!("{}: {}", name, e);
+                    sender
+                        .send(TaskResult::Error(format!("Vote failed on {}", error)))
+                        .await
+                        .expect("expected to send error");
+                    failures.push(error);
+                }
+            }
+        }
+
+        self.report_vote_session(receipts, failures.clone(), &sender)
+            .await;
+
+        if !votes.is_empty() && failures.len() == votes.len() {
+            return Err(format!("All votes failed: {}", failures.join("; ")));
+        }
+
+        Ok(BackendTaskSuccessResult::SuccessfulVotes(vote_results))
+    }
+
+    /// Casts the vote with each voter, adding a receipt for every vote that went through even
+    /// when a later voter fails
+    async fn cast_dpns_votes(
+        &self,
+        name: &str,
+        vote_choice: ResourceVoteChoice,
+        voters: &[QualifiedIdentity],
+        sdk: &Sdk,
+        receipts: &mut Vec<VoteReceipt>,
+    ) -> Result<Vec<Vote>, String> {
         // Fetch DPNS contract and document type information
         let data_contract = self.dpns_contract.as_ref();
         let document_type = data_contract
@@ -36,7 +111,7 @@ impl AppContext {
         };
 
         // Hardcoded values for DPNS
-        let index_values = [Value::from("dash"), Value::Text(name.clone())];
+        let index_values = [Value::from("dash"), Value::Text(name.to_string())];
 
         // Create the vote poll to use in the vote
         let vote_poll = ContestedDocumentResourceVotePoll {
@@ -45,13 +120,19 @@ impl AppContext {
             document_type_name: document_type.name().to_string(),
             contract_id: data_contract.id(),
         };
+        let vote_poll_id = vote_poll
+            .unique_id()
+            .map_err(|e| format!("Error voting: {}", e))?;
 
+        let redundant = self.redundant_broadcast_applies(name);
         let mut vote_results = vec![];
         let mut strength = 0;
 
         // Iterate over the provided voters (QualifiedIdentity)
         for qualified_identity in voters.iter() {
-            if let Some((_, public_key)) = &qualified_identity.associated_voter_identity {
+            if let Some((voter_identity, public_key)) =
+                &qualified_identity.associated_voter_identity
+            {
                 // Create the resource vote
                 let resource_vote = ResourceVoteV0 {
                     vote_poll: vote_poll.clone().into(),
@@ -59,20 +140,78 @@ impl AppContext {
                 };
                 let vote = Vote::ResourceVote(ResourceVote::V0(resource_vote));
 
+                // The transition is built here rather than by the SDK to know its hash
+                let nonce = sdk
+                    .get_identity_nonce(voter_identity.id(), true, None)
+                    .await
+                    .map_err(|e| format!("Error voting: {}", e))?;
+                let transition = MasternodeVoteTransition::try_from_vote_with_signer(
+                    vote.clone(),
+                    qualified_identity,
+                    qualified_identity.identity.id(),
+                    public_key,
+                    nonce,
+                    sdk.version(),
+                    None,
+                )
+                .map_err(|e| format!("Error voting: {}", e))?;
+                let transition_bytes = transition
+                    .serialize_to_bytes()
+                    .map_err(|e| format!("Error voting: {}", e))?;
+
                 // Submit the vote to the platform and await a response
-                let result = vote
-                    .put_to_platform_and_wait_for_response(
-                        qualified_identity.identity.id(),
-                        public_key,
-                        &sdk,
-                        qualified_identity,
-                        None,
-                    )
+                self.broadcast_vote(&transition, sdk, redundant)
                     .await
                     .map_err(|e| format!("Error voting: {}", e))?;
 
                 strength += qualified_identity.identity_type.vote_strength();
-                vote_results.push(result);
+                vote_results.push(vote);
+                let transition_hash =
+                    hex::encode(sha256::Hash::hash(&transition_bytes).to_byte_array());
+                receipts.push(VoteReceipt {
+                    contest: name.to_string(),
+                    choice: vote_choice_label(&vote_choice),
+                    voter: qualified_identity.display_string(),
+                    transition_hash: transition_hash.clone(),
+                });
+
+                // The vote counts without its proof, a failure to fetch it is only logged
+                match self
+                    .prove_vote(
+                        name,
+                        &vote_choice,
+                        qualified_identity,
+                        vote_poll_id,
+                        transition_hash,
+                    )
+                    .await
+                {
+                    Ok(receipt) => {
+                        if let Err(e) = self.db.save_vote_proof(&receipt, self) {
+                            tracing::warn!(
+                                "Failed to store the receipt of the vote on {}: {}",
+                                name,
+                                e
+                            );
+                        }
+                    }
+                    Err(e) => tracing::warn!(
+                        "Failed to fetch the proof of the vote of {} on {}: {}",
+                        qualified_identity.display_string(),
+                        name,
+                        e
+                    ),
+                }
+
+                self.db
+                    .insert_my_vote(
+                        name,
+                        qualified_identity.identity.id(),
+                        public_key.id(),
+                        vote_choice,
+                        self,
+                    )
+                    .map_err(|e| format!("error recording vote: {}", e))?;
             } else {
                 return Err(format!(
                     "No associated voter identity for qualified identity: {:?}",
@@ -90,6 +229,141 @@ impl AppContext {
             )
             .map_err(|e| format!("error updating ending time: {}", e))?;
 
-        Ok(BackendTaskSuccessResult::SuccessfulVotes(vote_results))
+        Ok(vote_results)
+    }
+
+    /// Whether votes on the contest are sent through several DAPI nodes, given the setting and
+    /// how soon the contest ends
+    fn redundant_broadcast_applies(&self, name: &str) -> bool {
+        let settings = self.preferences().redundant_vote_broadcast;
+        if !settings.enabled {
+            return false;
+        }
+        let end_time = self
+            .db
+            .get_ongoing_contested_names(self)
+            .unwrap_or_default()
+            .into_iter()
+            .find(|contest| contest.normalized_contested_name == name)
+            .and_then(|contest| contest.end_time);
+        let now = network_now().timestamp_millis() as u64;
+        settings.applies(end_time, now)
+    }
+
+    /// Sends the vote and waits for it to be confirmed. When `redundant` the same transition is
+    /// sent through several DAPI nodes at once and the vote counts once any of them confirms it.
+    async fn broadcast_vote(
+        &self,
+        transition: &StateTransition,
+        sdk: &Sdk,
+        redundant: bool,
+    ) -> Result<(), String> {
+        let count = self.preferences().redundant_vote_broadcast.endpoint_count();
+        let endpoint_sdks = self.endpoint_sdks();
+        let endpoints: Vec<&(String, Sdk)> = endpoint_sdks
+            .iter()
+            .filter(|(address, _)| !self.endpoint_health.is_flagged(address))
+            .take(count)
+            .collect();
+        if !redundant || endpoints.len() < 2 {
+            return transition
+                .broadcast_and_wait(sdk, None)
+                .await
+                .map(|_| ())
+                .map_err(|e| self.rejection_message("vote", "Broadcasting error", &e));
+        }
+        let attempts = futures::future::join_all(endpoints.into_iter().map(
+            |(address, endpoint_sdk)| async move {
+                BroadcastAttempt {
+                    address: address.clone(),
+                    result: transition
+                        .broadcast_and_wait(endpoint_sdk, None)
+                        .await
+                        .map(|_| ())
+                        .map_err(BroadcastFailure::from),
+                }
+            },
+        ))
+        .await;
+        for attempt in &attempts {
+            if let Err(e) = &attempt.result {
+                tracing::debug!(
+                    "Vote broadcast through {} failed: {}",
+                    attempt.address,
+                    e.message
+                );
+            }
+        }
+        let outcome = reconcile(&attempts)?;
+        tracing::info!("{}", outcome);
+        Ok(())
+    }
+
+    /// Fetches the vote of the masternode on the contest with its proof, which is always
+    /// requested whatever the verification mode
+    async fn prove_vote(
+        &self,
+        name: &str,
+        vote_choice: &ResourceVoteChoice,
+        qualified_identity: &QualifiedIdentity,
+        vote_poll_id: Identifier,
+        transition_hash: String,
+    ) -> Result<VoteProofReceipt, String> {
+        let query = VoteQuery {
+            identity_id: qualified_identity.identity.id(),
+            vote_poll_id,
+        };
+        let (vote, metadata, proof) = Vote::fetch_with_metadata_and_proof(&self.sdk(), query, None)
+            .await
+            .map_err(|e| e.to_string())?;
+        if vote.is_none() {
+            return Err("Platform does not show the vote yet".to_string());
+        }
+        Ok(VoteProofReceipt {
+            version: VOTE_RECEIPT_VERSION,
+            network: self.network_string(),
+            contest: name.to_string(),
+            choice: vote_choice_label(vote_choice),
+            voter_id: qualified_identity.identity.id().to_string(Encoding::Base58),
+            voter_name: qualified_identity.display_string(),
+            vote_poll_id: vote_poll_id.to_string(Encoding::Base58),
+            transition_hash,
+            proof: (&proof).into(),
+            metadata: (&metadata).into(),
+        })
+    }
+
+    /// Posts the summary of the session to the vote webhook, if one is set up. A failed post
+    /// is reported without failing the votes.
+    async fn report_vote_session(
+        &self,
+        receipts: Vec<VoteReceipt>,
+        failures: Vec<String>,
+        sender: &mpsc::Sender<TaskResult>,
+    ) {
+        let settings = self.preferences().vote_webhook;
+        let Some(url) = settings.url() else {
+            return;
+        };
+        if receipts.is_empty() && failures.is_empty() {
+            return;
+        }
+        let summary = VoteSessionSummary {
+            network: self.network_string(),
+            finished_at: chrono::Utc::now().timestamp(),
+            receipts,
+            failures,
+        };
+        let body = summary.webhook_body(settings.format);
+        let result = self.post_webhook(url, &body).await;
+        if let Err(e) = result {
+            sender
+                .send(TaskResult::Error(format!(
+                    "The votes went through but posting their summary to the webhook failed: {}",
+                    e
+                )))
+                .await
+                .expect("expected to send error");
+        }
     }
 }
@@ -1,4 +1,8 @@
 use crate::context::AppContext;
+use crate::model::qualified_contract::ContractKind;
+use crate::platform::BackendTaskSuccessResult;
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::dpp::system_data_contracts::dpns_contract;
 use dash_sdk::platform::{DataContract, Fetch, Identifier};
 use dash_sdk::Sdk;
@@ -7,18 +11,32 @@ use dash_sdk::Sdk;
 pub(crate) enum ContractTask {
     FetchDPNSContract,
     FetchContract(Identifier, Option<String>),
+    /// Compares the cached version of every registered contract with the one on Platform
+    CheckContractUpdates,
+    /// Replaces the cached schema of a contract with the version on Platform
+    RefreshContract(Identifier),
 }
 
 impl AppContext {
-    pub async fn run_contract_task(&self, task: ContractTask, sdk: &Sdk) -> Result<(), String> {
+    pub async fn run_contract_task(
+        &self,
+        task: ContractTask,
+        sdk: &Sdk,
+    ) -> Result<BackendTaskSuccessResult, String> {
         match task {
             ContractTask::FetchContract(identifier, name) => {
                 match DataContract::fetch(sdk, identifier).await {
                     Ok(Some(data_contract)) => self
                         .db
-                        .insert_contract_if_not_exists(&data_contract, name.as_deref(), self)
+                        .register_contract(
+                            &data_contract,
+                            name.as_deref(),
+                            ContractKind::Custom,
+                            self,
+                        )
+                        .map(|_| BackendTaskSuccessResult::None)
                         .map_err(|e| e.to_string()),
-                    Ok(None) => Ok(()),
+                    Ok(None) => Ok(BackendTaskSuccessResult::None),
                     Err(e) => Err(e.to_string()),
                 }
             }
@@ -28,12 +46,65 @@ impl AppContext {
                 {
                     Ok(Some(data_contract)) => self
                         .db
-                        .insert_contract_if_not_exists(&data_contract, Some("dpns"), self)
+                        .register_contract(&data_contract, Some("dpns"), ContractKind::Dpns, self)
+                        .map(|_| BackendTaskSuccessResult::None)
                         .map_err(|e| e.to_string()),
                     Ok(None) => Err("No DPNS contract found".to_string()),
                     Err(e) => Err(e.to_string()),
                 }
             }
+            ContractTask::CheckContractUpdates => self.check_contract_updates(sdk).await,
+            ContractTask::RefreshContract(identifier) => {
+                match DataContract::fetch(sdk, identifier).await {
+                    Ok(Some(data_contract)) => {
+                        self.db
+                            .replace_contract(&data_contract, self)
+                            .map_err(|e| e.to_string())?;
+                        Ok(BackendTaskSuccessResult::Message(format!(
+                            "Contract updated to version {}",
+                            data_contract.version()
+                        )))
+                    }
+                    Ok(None) => Err("Contract not found on Platform".to_string()),
+                    Err(e) => Err(e.to_string()),
+                }
+            }
         }
     }
+
+    async fn check_contract_updates(&self, sdk: &Sdk) -> Result<BackendTaskSuccessResult, String> {
+        let contracts = self
+            .db
+            .get_registered_contracts(self)
+            .map_err(|e| e.to_string())?;
+
+        let mut outdated = Vec::new();
+        for registered_contract in contracts {
+            let contract_id = registered_contract.qualified_contract.contract.id();
+            let cached_version = registered_contract.cached_version();
+            let onchain_version = DataContract::fetch(sdk, contract_id)
+                .await
+                .map_err(|e| format!("Error fetching contract {}: {}", contract_id, e))?
+                .map(|data_contract| data_contract.version());
+            self.db
+                .update_contract_onchain_version(contract_id, onchain_version, self)
+                .map_err(|e| e.to_string())?;
+
+            if onchain_version.is_some_and(|version| version > cached_version) {
+                outdated.push(
+                    registered_contract
+                        .qualified_contract
+                        .alias
+                        .unwrap_or_else(|| contract_id.to_string(Encoding::Base58)),
+                );
+            }
+        }
+
+        let message = if outdated.is_empty() {
+            "All known contracts are up to date".to_string()
+        } else {
+            format!("Outdated contracts: {}", outdated.join(", "))
+        };
+        Ok(BackendTaskSuccessResult::Message(message))
+    }
 }
@@ -1,16 +1,50 @@
 use crate::context::AppContext;
+use crate::model::fee::{
+    fee_per_byte_from_kilobyte, FeeChoice, FeeEstimates, FeeSpeed, FundingFee, MIN_FEE_PER_BYTE,
+};
+use crate::model::masternode_message::SignedMasternodeMessage;
+use crate::model::masternode_payout::MasternodePayout;
+use crate::model::masternode_status::{
+    CoreMasternodeState, MasternodeDashboard, MasternodeStatus, TenderdashStatus,
+};
+use crate::model::qualified_identity::IdentityType;
 use crate::platform::BackendTaskSuccessResult;
 use dash_sdk::dashcore_rpc::RpcApi;
 use dash_sdk::dpp::dashcore::{ChainLock, Network};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum CoreTask {
     GetBestChainLock,
+    /// Scans the payout addresses of our masternodes for coinbase payouts since the last scan
+    SyncMasternodePayouts,
+    /// Reads the state of our masternodes from Core and of our evonodes from their Tenderdash
+    FetchMasternodeDashboard,
+    /// Checks a message signed with a masternode key against the deterministic masternode list
+    VerifyMasternodeMessage(SignedMasternodeMessage),
+    /// Asks Core for the fee rates of each speed
+    EstimateFees,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum CoreItem {
     ChainLock(ChainLock, Network),
+    FeeEstimates(FeeEstimates),
+}
+
+/// One entry of Core's `getaddressdeltas`
+#[derive(Debug, Deserialize)]
+struct AddressDelta {
+    satoshis: i64,
+    txid: String,
+    index: u32,
+    /// Position of the transaction in its block, 0 for the coinbase
+    blockindex: u32,
+    height: u32,
 }
 
 impl AppContext {
@@ -26,6 +60,227 @@ impl AppContext {
                     ))
                 })
                 .map_err(|e| e.to_string()),
+            CoreTask::SyncMasternodePayouts => {
+                self.sync_masternode_payouts()?;
+                self.db
+                    .get_masternode_payouts(self)
+                    .map(BackendTaskSuccessResult::MasternodePayouts)
+                    .map_err(|e| e.to_string())
+            }
+            CoreTask::FetchMasternodeDashboard => self
+                .fetch_masternode_dashboard()
+                .await
+                .map(BackendTaskSuccessResult::MasternodeDashboard),
+            CoreTask::VerifyMasternodeMessage(signed) => self
+                .verify_masternode_message(&signed)
+                .map(|_| BackendTaskSuccessResult::VerifiedMasternodeMessage(signed)),
+            CoreTask::EstimateFees => Ok(BackendTaskSuccessResult::CoreItem(
+                CoreItem::FeeEstimates(self.fee_estimates()),
+            )),
         }
     }
+
+    /// Core's fee rate estimates, the relay minimum for the speeds Core has no estimate of,
+    /// as is common on testnet and devnets
+    pub(crate) fn fee_estimates(&self) -> FeeEstimates {
+        let mut estimates = FeeEstimates::default();
+        for speed in FeeSpeed::ALL {
+            let rate = match self
+                .core_client
+                .estimate_smart_fee(speed.confirmation_target(), None)
+            {
+                Ok(result) => result.fee_rate.map(|fee_rate| {
+                    estimates.estimated = true;
+                    fee_per_byte_from_kilobyte(fee_rate.to_sat())
+                }),
+                Err(e) => {
+                    tracing::debug!("Core could not estimate the fee: {}", e);
+                    None
+                }
+            };
+            let rate = rate.unwrap_or(MIN_FEE_PER_BYTE);
+            match speed {
+                FeeSpeed::Slow => estimates.slow = rate,
+                FeeSpeed::Normal => estimates.normal = rate,
+                FeeSpeed::Fast => estimates.fast = rate,
+            }
+        }
+        estimates
+    }
+
+    /// The fee rate and coin selection of an asset lock with the choice
+    pub(crate) fn funding_fee(&self, choice: FeeChoice) -> FundingFee {
+        FundingFee {
+            fee_per_byte: self.fee_estimates().rate(choice.speed),
+            coin_selection: choice.coin_selection,
+        }
+    }
+
+    fn verify_masternode_message(&self, signed: &SignedMasternodeMessage) -> Result<(), String> {
+        let network = self.network_string();
+        if signed.claim.network != network {
+            return Err(format!(
+                "The message was signed on {}, this tool is on {}",
+                signed.claim.network, network
+            ));
+        }
+        let protx_info = self
+            .core_client
+            .call::<serde_json::Value>(
+                "protx",
+                &["info".into(), signed.claim.pro_tx_hash.clone().into()],
+            )
+            .map_err(|e| format!("protx info failed: {}", e))?;
+        signed.verify(&protx_info, self.network)
+    }
+
+    async fn fetch_masternode_dashboard(&self) -> Result<MasternodeDashboard, String> {
+        let identities = self
+            .load_local_qualified_identities()
+            .map_err(|e| e.to_string())?;
+        let tenderdash_rpc_urls = self.preferences().tenderdash_rpc_urls;
+        let http_client = self.http_client(Duration::from_secs(10))?;
+        let mut nodes = Vec::new();
+        for qualified_identity in identities.iter().filter(|qualified_identity| {
+            matches!(
+                qualified_identity.identity_type,
+                IdentityType::Masternode | IdentityType::Evonode
+            )
+        }) {
+            let identity_id = qualified_identity.identity.id();
+            let core = self
+                .core_client
+                .call::<serde_json::Value>(
+                    "protx",
+                    &["info".into(), identity_id.to_string(Encoding::Hex).into()],
+                )
+                .map_err(|e| format!("protx info failed: {}", e))
+                .and_then(|info| CoreMasternodeState::from_protx_info(&info));
+            let tenderdash = match tenderdash_rpc_urls
+                .get(&identity_id.to_string(Encoding::Base58))
+                .filter(|url| !url.trim().is_empty())
+            {
+                Some(url) => Some(fetch_tenderdash_status(&http_client, url).await),
+                None => None,
+            };
+            nodes.push(MasternodeStatus {
+                identity_id,
+                core,
+                tenderdash,
+            });
+        }
+        Ok(MasternodeDashboard {
+            core_block_height: self
+                .core_client
+                .get_block_count()
+                .map_err(|e| e.to_string()),
+            nodes,
+        })
+    }
+
+    /// Stores the coinbase outputs paying our masternodes' payout addresses. Needs Core's
+    /// address index.
+    fn sync_masternode_payouts(&self) -> Result<(), String> {
+        let tip = self
+            .core_client
+            .get_block_count()
+            .map_err(|e| e.to_string())? as u32;
+        let identities = self
+            .load_local_qualified_identities()
+            .map_err(|e| e.to_string())?;
+        let mut block_times: HashMap<u32, i64> = HashMap::new();
+        for qualified_identity in identities.iter().filter(|qualified_identity| {
+            matches!(
+                qualified_identity.identity_type,
+                IdentityType::Masternode | IdentityType::Evonode
+            )
+        }) {
+            let Some(payout_address) = qualified_identity.masternode_payout_address(self.network)
+            else {
+                continue;
+            };
+            let payout_address = payout_address.to_string();
+            let start = self
+                .db
+                .get_last_payout_height(&payout_address, self)
+                .map_err(|e| e.to_string())?
+                .map_or(1, |height| height + 1);
+            if start > tip {
+                continue;
+            }
+            let deltas: Vec<AddressDelta> = self
+                .core_client
+                .call(
+                    "getaddressdeltas",
+                    &[serde_json::json!({
+                        "addresses": [payout_address],
+                        "start": start,
+                        "end": tip,
+                    })],
+                )
+                .map_err(|e| {
+                    format!(
+                        "Failed to scan {}, Dash Core needs addressindex=1: {}",
+                        payout_address, e
+                    )
+                })?;
+            let mut payouts = Vec::new();
+            for delta in deltas
+                .into_iter()
+                .filter(|delta| delta.blockindex == 0 && delta.satoshis > 0)
+            {
+                let paid_at = match block_times.get(&delta.height) {
+                    Some(time) => *time,
+                    None => {
+                        let block_hash = self
+                            .core_client
+                            .get_block_hash(delta.height as u64)
+                            .map_err(|e| e.to_string())?;
+                        let time = self
+                            .core_client
+                            .get_block_header_info(&block_hash)
+                            .map_err(|e| e.to_string())?
+                            .time as i64;
+                        block_times.insert(delta.height, time);
+                        time
+                    }
+                };
+                payouts.push(MasternodePayout {
+                    identity_id: qualified_identity.identity.id(),
+                    payout_address: payout_address.clone(),
+                    txid: delta.txid,
+                    output_index: delta.index,
+                    duffs: delta.satoshis as u64,
+                    height: delta.height,
+                    paid_at,
+                });
+            }
+            self.db
+                .insert_masternode_payouts(&payouts, self)
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+/// Queries `status` and `net_info` of a Tenderdash RPC endpoint
+async fn fetch_tenderdash_status(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<TenderdashStatus, String> {
+    let base = url.trim().trim_end_matches('/');
+    let mut answers = Vec::with_capacity(2);
+    for method in ["status", "net_info"] {
+        let answer: serde_json::Value = client
+            .get(format!("{}/{}", base, method))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("Tenderdash {} failed: {}", method, e))?
+            .json()
+            .await
+            .map_err(|e| format!("Tenderdash {} did not answer with JSON: {}", method, e))?;
+        answers.push(answer);
+    }
+    TenderdashStatus::from_rpc(&answers[0], &answers[1])
 }
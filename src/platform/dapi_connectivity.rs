@@ -0,0 +1,382 @@
+use crate::config::NetworkConfig;
+use crate::dapi_tls::dapi_client_config;
+use crate::model::health_check::{
+    CheckStatus, ConnectionLayer, DapiConnectivityReport, LayerCheck,
+};
+use dash_sdk::sdk::Uri;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{self, CertificateError, ClientConfig};
+use tokio_rustls::TlsConnector;
+
+/// How long each layer may take before it counts as failed
+const LAYER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A cheap DAPI method that needs neither Drive nor a proof
+const PROBE_METHOD: &str = "org.dash.platform.dapi.v0.Core/getBlockchainStatus";
+
+/// Resolves the address, then opens a TCP connection, the TLS handshake and a gRPC call one
+/// after the other, and reports the first layer that fails
+pub async fn check_dapi_address(config: &NetworkConfig, address: &str) -> DapiConnectivityReport {
+    let mut layers = Vec::new();
+    let suggestion = probe(config, address.trim(), &mut layers).await;
+    DapiConnectivityReport {
+        address: address.trim().to_string(),
+        layers,
+        suggestion,
+    }
+}
+
+/// Adds a check per layer tried and returns the suggestion for the one that failed
+async fn probe(
+    config: &NetworkConfig,
+    address: &str,
+    layers: &mut Vec<LayerCheck>,
+) -> Option<String> {
+    let mut tls_config = match dapi_client_config(config) {
+        Ok(tls_config) => tls_config,
+        Err(e) => {
+            layers.push(layer_check(
+                ConnectionLayer::Tls,
+                CheckStatus::Failed,
+                e,
+                Instant::now(),
+            ));
+            return Some("Fix dapi_ca_certificate in the configuration.".to_string());
+        }
+    };
+    tls_config.alpn_protocols = vec![b"h2".to_vec()];
+
+    let uri = match Uri::from_str(address) {
+        Ok(uri) if uri.host().is_some() => uri,
+        _ => {
+            layers.push(layer_check(
+                ConnectionLayer::Dns,
+                CheckStatus::Failed,
+                "Not a valid address",
+                Instant::now(),
+            ));
+            return Some(
+                "Correct the address in dapi_addresses, e.g. https://1.2.3.4:443.".to_string(),
+            );
+        }
+    };
+    let host = uri.host().unwrap_or_default();
+    let host = host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    let uses_tls = uri.scheme_str() == Some("https");
+    let port = uri.port_u16().unwrap_or(if uses_tls { 443 } else { 80 });
+
+    // DNS
+    let started = Instant::now();
+    let addresses: Vec<SocketAddr> =
+        match with_timeout(tokio::net::lookup_host((host.as_str(), port))).await {
+            Ok(addresses) => addresses.collect(),
+            Err(e) => {
+                layers.push(layer_check(
+                    ConnectionLayer::Dns,
+                    CheckStatus::Failed,
+                    e,
+                    started,
+                ));
+                return Some(format!(
+                    "{} does not resolve. Check the spelling of the host in dapi_addresses and \
+                     the DNS servers of this machine, or use the node's IP address.",
+                    host
+                ));
+            }
+        };
+    if addresses.is_empty() {
+        layers.push(layer_check(
+            ConnectionLayer::Dns,
+            CheckStatus::Failed,
+            "Resolves to no address",
+            started,
+        ));
+        return Some(format!(
+            "{} has no DNS records, use the node's IP address.",
+            host
+        ));
+    }
+    let detail = if host.parse::<std::net::IpAddr>().is_ok() {
+        "An IP address, nothing to resolve".to_string()
+    } else {
+        addresses
+            .iter()
+            .map(|address| address.ip().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    layers.push(layer_check(
+        ConnectionLayer::Dns,
+        CheckStatus::Passed,
+        detail,
+        started,
+    ));
+
+    // TCP, to each resolved address until one accepts
+    let started = Instant::now();
+    let mut connected = None;
+    let mut refused = false;
+    let mut errors = Vec::new();
+    for socket_address in &addresses {
+        match with_timeout(TcpStream::connect(socket_address)).await {
+            Ok(stream) => {
+                connected = Some((stream, *socket_address));
+                break;
+            }
+            Err(e) => {
+                refused |= e.contains("refused");
+                errors.push(format!("{}: {}", socket_address, e));
+            }
+        }
+    }
+    let Some((stream, socket_address)) = connected else {
+        layers.push(layer_check(
+            ConnectionLayer::Tcp,
+            CheckStatus::Failed,
+            errors.join("; "),
+            started,
+        ));
+        return Some(if refused {
+            format!(
+                "Nothing accepts connections on port {} of {}. Check the port in \
+                 dapi_addresses, nodes serve DAPI on 443 on mainnet and on 1443 on testnet.",
+                port, host
+            )
+        } else {
+            format!(
+                "The connection to port {} does not get through. A firewall on this machine, \
+                 the network or the node drops it, or the node is down. Allow outgoing \
+                 connections to port {} or try another node.",
+                port, port
+            )
+        });
+    };
+    layers.push(layer_check(
+        ConnectionLayer::Tcp,
+        CheckStatus::Passed,
+        format!("Connected to {}", socket_address),
+        started,
+    ));
+
+    // TLS
+    if uses_tls {
+        let started = Instant::now();
+        if let Err((detail, suggestion)) = tls_handshake(stream, &host, tls_config.clone()).await {
+            layers.push(layer_check(
+                ConnectionLayer::Tls,
+                CheckStatus::Failed,
+                detail,
+                started,
+            ));
+            return Some(suggestion);
+        }
+        layers.push(if config.dapi_insecure_tls {
+            layer_check(
+                ConnectionLayer::Tls,
+                CheckStatus::Warning,
+                "Handshake done, the certificate is not verified as dapi_insecure_tls is on",
+                started,
+            )
+        } else {
+            layer_check(
+                ConnectionLayer::Tls,
+                CheckStatus::Passed,
+                "Certificate valid",
+                started,
+            )
+        });
+    }
+
+    // gRPC
+    let started = Instant::now();
+    let (status, detail, suggestion) = grpc_call(&uri, tls_config).await;
+    layers.push(layer_check(ConnectionLayer::Grpc, status, detail, started));
+    suggestion
+}
+
+/// Runs the TLS handshake with the certificates the SDK trusts, and explains a failure
+async fn tls_handshake(
+    stream: TcpStream,
+    host: &str,
+    config: ClientConfig,
+) -> Result<(), (String, String)> {
+    let server_name = ServerName::try_from(host.to_string()).map_err(|e| {
+        (
+            e.to_string(),
+            format!("{} is not a valid TLS server name.", host),
+        )
+    })?;
+    let connector = TlsConnector::from(Arc::new(config));
+    let error =
+        match tokio::time::timeout(LAYER_TIMEOUT, connector.connect(server_name, stream)).await {
+            Ok(Ok(_)) => return Ok(()),
+            Ok(Err(e)) => e,
+            Err(_) => {
+                return Err((
+                    format!("No answer within {} s", LAYER_TIMEOUT.as_secs()),
+                    "The port accepts connections but does not answer the TLS handshake. It may \
+                 not speak TLS, check the port or whether the address should start with \
+                 http://."
+                        .to_string(),
+                ))
+            }
+        };
+    let suggestion = match error
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<rustls::Error>())
+    {
+        Some(rustls::Error::InvalidCertificate(CertificateError::Expired)) => {
+            "The node's certificate expired. Its operator has to renew it, use another node \
+             meanwhile. If the certificate should be valid, check the clock of this machine."
+                .to_string()
+        }
+        Some(rustls::Error::InvalidCertificate(CertificateError::NotValidForName)) => format!(
+            "The node's certificate is not issued for {}. Check the host in dapi_addresses.",
+            host
+        ),
+        Some(rustls::Error::InvalidCertificate(CertificateError::UnknownIssuer)) => {
+            "The node's certificate is not signed by a trusted authority, e.g. it is self \
+             signed, or a proxy on this network intercepts TLS. Use another node. For a devnet \
+             with its own CA set dapi_ca_certificate, or dapi_insecure_tls for self-signed \
+             certificates."
+                .to_string()
+        }
+        Some(rustls::Error::InvalidMessage(_))
+        | Some(rustls::Error::InappropriateMessage { .. }) => {
+            "The port does not speak TLS. Check the port or whether the address should start \
+             with http://."
+                .to_string()
+        }
+        _ => "The TLS handshake failed. Check the clock of this machine and any proxy that \
+              intercepts TLS."
+            .to_string(),
+    };
+    Err((error.to_string(), suggestion))
+}
+
+/// Calls the probe method and returns the status, the detail and a suggestion when it did not
+/// work
+async fn grpc_call(uri: &Uri, tls_config: ClientConfig) -> (CheckStatus, String, Option<String>) {
+    let url = format!(
+        "{}://{}/{}",
+        uri.scheme_str().unwrap_or("https"),
+        uri.authority()
+            .map(|authority| authority.as_str())
+            .unwrap_or_default(),
+        PROBE_METHOD
+    );
+    let client = match reqwest::Client::builder()
+        .use_preconfigured_tls(tls_config)
+        .http2_prior_knowledge()
+        .timeout(LAYER_TIMEOUT)
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => return (CheckStatus::Failed, e.to_string(), None),
+    };
+    // An empty, uncompressed request message
+    let response = client
+        .post(&url)
+        .header("content-type", "application/grpc")
+        .header("te", "trailers")
+        .body(vec![0u8; 5])
+        .send()
+        .await;
+    let response =
+        match response {
+            Ok(response) => response,
+            Err(e) if e.is_timeout() => return (
+                CheckStatus::Failed,
+                format!("No answer within {} s", LAYER_TIMEOUT.as_secs()),
+                Some(
+                    "The node accepts connections but DAPI does not answer. It may be overloaded \
+                     or syncing, try another node."
+                        .to_string(),
+                ),
+            ),
+            Err(e) => {
+                return (
+                    CheckStatus::Failed,
+                    e.to_string(),
+                    Some(
+                        "The port does not answer over HTTP/2, it may serve something other than \
+                     DAPI. Check the port in dapi_addresses."
+                            .to_string(),
+                    ),
+                )
+            }
+        };
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let is_grpc = header("content-type").is_some_and(|kind| kind.starts_with("application/grpc"));
+    if !response.status().is_success() || !is_grpc {
+        return (
+            CheckStatus::Failed,
+            format!(
+                "Answered with HTTP {} and {}",
+                response.status(),
+                header("content-type").unwrap_or_else(|| "no content type".to_string())
+            ),
+            Some(
+                "A web server or proxy answers instead of DAPI. Check the port in \
+                 dapi_addresses."
+                    .to_string(),
+            ),
+        );
+    }
+    match header("grpc-status").filter(|code| code != "0") {
+        None => (CheckStatus::Passed, "DAPI answered".to_string(), None),
+        Some(code) => {
+            let suggestion = if code == "14" {
+                "DAPI runs but cannot reach the node's Core or Drive. Try another node."
+            } else {
+                "DAPI answered with an error, the node may run an incompatible version. Try \
+                 another node."
+            };
+            (
+                CheckStatus::Warning,
+                format!(
+                    "gRPC status {}: {}",
+                    code,
+                    header("grpc-message").unwrap_or_default()
+                ),
+                Some(suggestion.to_string()),
+            )
+        }
+    }
+}
+
+async fn with_timeout<T>(future: impl Future<Output = std::io::Result<T>>) -> Result<T, String> {
+    match tokio::time::timeout(LAYER_TIMEOUT, future).await {
+        Ok(result) => result.map_err(|e| e.to_string()),
+        Err(_) => Err(format!("Timed out after {} s", LAYER_TIMEOUT.as_secs())),
+    }
+}
+
+fn layer_check(
+    layer: ConnectionLayer,
+    status: CheckStatus,
+    detail: impl Into<String>,
+    started: Instant,
+) -> LayerCheck {
+    LayerCheck {
+        layer,
+        status,
+        detail: detail.into(),
+        elapsed_ms: started.elapsed().as_millis(),
+    }
+}
@@ -1,12 +1,48 @@
+use crate::app::TaskResult;
 use crate::context::AppContext;
+use crate::model::document_revision::{document_to_json, DocumentRevision};
+use crate::model::document_snapshot::{DocumentSnapshot, SnapshotDocument, MAX_SNAPSHOT_DOCUMENTS};
+use crate::model::rate_limit::Endpoint;
+use crate::platform::document_deletion::{DocumentDeletion, DocumentSelection};
+use crate::platform::document_import::DocumentImport;
+use crate::platform::document_trade::DocumentTrade;
 use crate::platform::BackendTaskSuccessResult;
-use dash_sdk::platform::{Document, DocumentQuery, FetchMany};
+use dash_sdk::dapi_grpc::platform::v0::get_documents_request::get_documents_request_v0::Start;
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::{Document, DocumentQuery, FetchMany, Identifier};
+use dash_sdk::query_types::Documents;
 use dash_sdk::Sdk;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Documents fetched per request while taking a snapshot
+const SNAPSHOT_PAGE_SIZE: u32 = 100;
 
 pub type DocumentTypeName = String;
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum DocumentTask {
     FetchDocuments(DocumentQuery),
+    /// Searches registered DPNS names by a prefix, `*` in the pattern matches any characters
+    SearchDpnsNames(String),
+    /// Checks whether each of the DPNS names could be registered
+    CheckDpnsNames(Vec<String>),
+    /// Fetches all documents of the type and stores them as a snapshot with the label
+    SnapshotDocuments(Arc<DataContract>, DocumentTypeName, String),
+    /// Snapshots every document type of the contract with the label
+    SnapshotContract(Arc<DataContract>, String),
+    /// Creates the documents of an import file
+    ImportDocuments(DocumentImport),
+    /// Finds the documents of the owner matching the conditions, without deleting them
+    PreviewDocumentDeletion(DocumentSelection),
+    /// Deletes the documents found by a preview
+    DeleteDocuments(DocumentDeletion),
+    /// Lists a document of the identity for a price, 0 takes it off sale
+    SetDocumentPrice(DocumentTrade),
+    /// Buys a listed document for the identity
+    PurchaseDocument(DocumentTrade),
 }
 
 impl AppContext {
@@ -14,12 +50,147 @@ impl AppContext {
         &self,
         task: DocumentTask,
         sdk: &Sdk,
+        sender: mpsc::Sender<TaskResult>,
     ) -> Result<BackendTaskSuccessResult, String> {
         match task {
-            DocumentTask::FetchDocuments(drive_query) => Document::fetch_many(sdk, drive_query)
+            DocumentTask::FetchDocuments(drive_query) => {
+                let contract_id = drive_query.data_contract.id();
+                let document_type = drive_query.document_type_name.clone();
+                let documents = Document::fetch_many(sdk, drive_query)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                self.cache_document_revisions(&contract_id, &document_type, &documents);
+                Ok(BackendTaskSuccessResult::Documents(documents))
+            }
+            DocumentTask::SearchDpnsNames(pattern) => self
+                .search_dpns_names(sdk, &pattern)
+                .await
+                .map(BackendTaskSuccessResult::DpnsNames),
+            DocumentTask::CheckDpnsNames(names) => self
+                .check_dpns_names(sdk, &names)
+                .await
+                .map(BackendTaskSuccessResult::DpnsNameChecks),
+            DocumentTask::SnapshotDocuments(contract, document_type, label) => self
+                .snapshot_documents(sdk, contract, &document_type, &label)
+                .await
+                .map(BackendTaskSuccessResult::DocumentSnapshot),
+            DocumentTask::SnapshotContract(contract, label) => {
+                let mut snapshots = Vec::new();
+                for document_type in contract.document_types().keys() {
+                    if !snapshots.is_empty() {
+                        self.throttle(Endpoint::Dapi).await;
+                    }
+                    snapshots.push(
+                        self.snapshot_documents(sdk, contract.clone(), document_type, &label)
+                            .await?,
+                    );
+                }
+                Ok(BackendTaskSuccessResult::ContractSnapshots(snapshots))
+            }
+            DocumentTask::ImportDocuments(import) => {
+                self.import_documents(sdk, import, sender).await
+            }
+            DocumentTask::PreviewDocumentDeletion(selection) => self
+                .preview_document_deletion(sdk, selection)
                 .await
-                .map(BackendTaskSuccessResult::Documents)
-                .map_err(|e| e.to_string()),
+                .map(BackendTaskSuccessResult::DeletionPreview),
+            DocumentTask::DeleteDocuments(deletion) => {
+                self.delete_documents(sdk, deletion, sender).await
+            }
+            DocumentTask::SetDocumentPrice(trade) => {
+                let contract_id = trade.contract.id();
+                let document_type = trade.document_type.clone();
+                let documents = self.set_document_price(sdk, trade).await?;
+                self.cache_document_revisions(&contract_id, &document_type, &documents);
+                Ok(BackendTaskSuccessResult::Documents(documents))
+            }
+            DocumentTask::PurchaseDocument(trade) => {
+                let contract_id = trade.contract.id();
+                let document_type = trade.document_type.clone();
+                let documents = self.purchase_document(sdk, trade).await?;
+                self.cache_document_revisions(&contract_id, &document_type, &documents);
+                Ok(BackendTaskSuccessResult::Documents(documents))
+            }
+        }
+    }
+
+    /// Pages through all documents of the type and stores them as a snapshot
+    async fn snapshot_documents(
+        &self,
+        sdk: &Sdk,
+        contract: Arc<DataContract>,
+        document_type: &str,
+        label: &str,
+    ) -> Result<DocumentSnapshot, String> {
+        let contract_id = contract.id();
+        let mut documents = Vec::new();
+        let mut start_after: Option<Identifier> = None;
+        let truncated = loop {
+            let mut query = DocumentQuery::new(contract.clone(), document_type)
+                .map_err(|e| format!("Invalid document query: {}", e))?;
+            query.limit = SNAPSHOT_PAGE_SIZE;
+            query.start = start_after.map(|id| Start::StartAfter(id.to_vec()));
+            let page = Document::fetch_many(sdk, query)
+                .await
+                .map_err(|e| format!("Error fetching {} documents: {}", document_type, e))?;
+            let page_len = page.len();
+            start_after = page.keys().last().copied();
+            documents.extend(page.values().flatten().map(|document| SnapshotDocument {
+                document_id: document.id().to_string(Encoding::Base58),
+                revision: document.revision().unwrap_or_default(),
+                json: document_to_json(document),
+            }));
+            if documents.len() >= MAX_SNAPSHOT_DOCUMENTS {
+                documents.truncate(MAX_SNAPSHOT_DOCUMENTS);
+                break true;
+            }
+            if page_len < SNAPSHOT_PAGE_SIZE as usize || start_after.is_none() {
+                break false;
+            }
+        };
+        let id = self
+            .db
+            .insert_document_snapshot(
+                &contract_id,
+                document_type,
+                label,
+                &documents,
+                truncated,
+                self,
+            )
+            .map_err(|e| format!("Failed to store the snapshot: {}", e))?;
+        Ok(DocumentSnapshot {
+            id,
+            contract_id,
+            document_type: document_type.to_string(),
+            label: label.to_string(),
+            taken_at: chrono::Utc::now().timestamp(),
+            document_count: documents.len(),
+            truncated,
+        })
+    }
+
+    /// Keeps a copy of every fetched revision so it can be compared with later ones
+    fn cache_document_revisions(
+        &self,
+        contract_id: &Identifier,
+        document_type: &str,
+        documents: &Documents,
+    ) {
+        let fetched_at = chrono::Utc::now().timestamp();
+        for document in documents.values().flatten() {
+            let revision = DocumentRevision {
+                document_id: document.id(),
+                revision: document.revision().unwrap_or_default(),
+                json: document_to_json(document),
+                fetched_at,
+            };
+            if let Err(e) =
+                self.db
+                    .save_document_revision(contract_id, document_type, &revision, self)
+            {
+                tracing::warn!("Failed to cache document revision: {}", e);
+            }
         }
     }
 }
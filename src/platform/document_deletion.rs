@@ -0,0 +1,215 @@
+use crate::app::TaskResult;
+use crate::context::AppContext;
+use crate::model::document_deletion::{estimate_deletion_fee, MAX_DELETION_DOCUMENTS};
+use crate::model::key_usage::TRANSITION_DOCUMENT_DELETE;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::rate_limit::Endpoint;
+use crate::platform::BackendTaskSuccessResult;
+use dash_sdk::dapi_grpc::platform::v0::get_documents_request::get_documents_request_v0::Start;
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::prelude::UserFeeIncrease;
+use dash_sdk::dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use dash_sdk::dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+use dash_sdk::drive::query::WhereClause;
+use dash_sdk::platform::transition::broadcast::BroadcastStateTransition;
+use dash_sdk::platform::{Document, DocumentQuery, Fetch, FetchMany, Identifier, Identity};
+use dash_sdk::Sdk;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Documents fetched per request while selecting documents
+const SELECTION_PAGE_SIZE: u32 = 100;
+
+/// The documents of a type matching the conditions that are owned by the identity
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentSelection {
+    pub contract: Arc<DataContract>,
+    pub document_type: String,
+    pub owner: QualifiedIdentity,
+    pub conditions: Vec<WhereClause>,
+}
+
+/// What deleting the selected documents would do, without deleting anything
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeletionPreview {
+    pub documents: Vec<Document>,
+    /// Documents matching the conditions that belong to other identities, which can't be
+    /// deleted
+    pub not_owned: usize,
+    /// Whether more than `MAX_DELETION_DOCUMENTS` documents matched
+    pub truncated: bool,
+    pub estimated_fee: Credits,
+}
+
+/// Documents to delete, as selected by a preview
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentDeletion {
+    pub contract: Arc<DataContract>,
+    pub document_type: String,
+    pub owner: QualifiedIdentity,
+    pub documents: Vec<Document>,
+    /// Documents deleted between two progress reports. The deletion stops after a batch in
+    /// which every document failed.
+    pub batch_size: usize,
+}
+
+impl AppContext {
+    /// Pages through the documents matching the conditions and keeps those of the owner
+    pub(super) async fn preview_document_deletion(
+        &self,
+        sdk: &Sdk,
+        selection: DocumentSelection,
+    ) -> Result<DeletionPreview, String> {
+        let owner_id = selection.owner.identity.id();
+        let mut documents = Vec::new();
+        let mut not_owned = 0;
+        let mut start_after: Option<Identifier> = None;
+        let truncated = loop {
+            let mut query =
+                DocumentQuery::new(selection.contract.clone(), &selection.document_type)
+                    .map_err(|e| format!("Invalid document query: {}", e))?;
+            for condition in &selection.conditions {
+                query = query.with_where(condition.clone());
+            }
+            query.limit = SELECTION_PAGE_SIZE;
+            query.start = start_after.map(|id| Start::StartAfter(id.to_vec()));
+            let page = Document::fetch_many(sdk, query).await.map_err(|e| {
+                format!(
+                    "Error fetching {} documents: {}",
+                    selection.document_type, e
+                )
+            })?;
+            let page_len = page.len();
+            start_after = page.keys().last().copied();
+            for document in page.into_values().flatten() {
+                if document.owner_id() == owner_id {
+                    documents.push(document);
+                } else {
+                    not_owned += 1;
+                }
+            }
+            if documents.len() > MAX_DELETION_DOCUMENTS {
+                documents.truncate(MAX_DELETION_DOCUMENTS);
+                break true;
+            }
+            if page_len < SELECTION_PAGE_SIZE as usize || start_after.is_none() {
+                break false;
+            }
+            self.throttle(Endpoint::Dapi).await;
+        };
+        Ok(DeletionPreview {
+            estimated_fee: estimate_deletion_fee(documents.len()),
+            documents,
+            not_owned,
+            truncated,
+        })
+    }
+
+    /// Deletes the documents one transition after the other, following the DAPI rate limit,
+    /// and reports the outcome for each document and the fee paid
+    pub(super) async fn delete_documents(
+        &self,
+        sdk: &Sdk,
+        deletion: DocumentDeletion,
+        sender: mpsc::Sender<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let document_type = deletion
+            .contract
+            .document_type_for_name(&deletion.document_type)
+            .map_err(|_| format!("Document type {} not found", deletion.document_type))?;
+        let owner_id = deletion.owner.identity.id();
+        let public_key = deletion
+            .owner
+            .document_signing_key(&document_type)
+            .ok_or(
+                "Identity doesn't have an authentication key for signing document transitions"
+                    .to_string(),
+            )?
+            .clone();
+        let balance_before = Identity::fetch_by_identifier(sdk, owner_id)
+            .await
+            .map_err(|e| format!("Fetch identity error: {}", e))?
+            .ok_or("Identity not found on Platform".to_string())?
+            .balance();
+        let total = deletion.documents.len();
+        let mut outcomes: Vec<(Identifier, Result<(), String>)> = Vec::with_capacity(total);
+
+        for batch in deletion.documents.chunks(deletion.batch_size.max(1)) {
+            let mut batch_failed = true;
+            for document in batch {
+                self.throttle(Endpoint::Dapi).await;
+                let result = async {
+                    let nonce = sdk
+                        .get_identity_contract_nonce(owner_id, deletion.contract.id(), true, None)
+                        .await
+                        .map_err(|e| format!("Fetch nonce error: {}", e))?;
+                    let transition =
+                        DocumentsBatchTransition::new_document_deletion_transition_from_document(
+                            document.clone(),
+                            document_type,
+                            &public_key,
+                            nonce,
+                            UserFeeIncrease::default(),
+                            &deletion.owner,
+                            sdk.version(),
+                            None,
+                            None,
+                            None,
+                        )
+                        .map_err(|e| format!("Deletion transition error: {}", e))?;
+                    transition
+                        .broadcast_and_wait(sdk, None)
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| {
+                            self.rejection_message("document deletion", "Broadcasting error", &e)
+                        })
+                }
+                .await;
+                if result.is_ok() {
+                    batch_failed = false;
+                    self.record_key_usage(&owner_id, public_key.id(), TRANSITION_DOCUMENT_DELETE);
+                }
+                outcomes.push((document.id(), result));
+            }
+            let progress = BackendTaskSuccessResult::DocumentDeletionProgress {
+                done: outcomes.len(),
+                total,
+            };
+            if sender.send(TaskResult::Success(progress)).await.is_err() {
+                tracing::warn!("Failed to report the deletion progress");
+            }
+            if batch_failed {
+                break;
+            }
+        }
+
+        let deleted = outcomes.iter().filter(|(_, result)| result.is_ok()).count();
+        if deleted > 0 {
+            self.record_transition_fee(
+                sdk,
+                owner_id,
+                balance_before,
+                &format!("Deleted {} {} documents", deleted, deletion.document_type),
+            )
+            .await;
+        }
+        let submitted = outcomes.len();
+        outcomes.extend(deletion.documents[submitted..].iter().map(|document| {
+            (
+                document.id(),
+                Err(
+                    "Not submitted, the deletion stopped after a batch in which every \
+                     document failed"
+                        .to_string(),
+                ),
+            )
+        }));
+        Ok(BackendTaskSuccessResult::DeletedDocuments(outcomes))
+    }
+}
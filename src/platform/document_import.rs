@@ -0,0 +1,134 @@
+use crate::app::TaskResult;
+use crate::context::AppContext;
+use crate::model::key_usage::TRANSITION_DOCUMENT_CREATE;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::rate_limit::Endpoint;
+use crate::platform::BackendTaskSuccessResult;
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::document::DocumentV0;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::platform_value::{Bytes32, Value};
+use dash_sdk::platform::transition::put_document::PutDocument;
+use dash_sdk::platform::{Document, Identifier};
+use dash_sdk::Sdk;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Documents to create from an import file, each with the number of its record
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentImport {
+    pub contract: Arc<DataContract>,
+    pub document_type: String,
+    pub owner: QualifiedIdentity,
+    pub documents: Vec<(usize, BTreeMap<String, Value>)>,
+    /// Documents sent between two progress reports. The import stops after a batch in which
+    /// every document failed, as the rest would most likely fail the same way.
+    pub batch_size: usize,
+}
+
+impl AppContext {
+    /// Creates the documents one transition after the other, following the DAPI rate limit,
+    /// and reports the outcome for each record
+    pub(super) async fn import_documents(
+        &self,
+        sdk: &Sdk,
+        import: DocumentImport,
+        sender: mpsc::Sender<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let document_type = import
+            .contract
+            .document_type_for_name(&import.document_type)
+            .map_err(|_| format!("Document type {} not found", import.document_type))?;
+        let owner_id = import.owner.identity.id();
+        let public_key = import
+            .owner
+            .document_signing_key(&document_type)
+            .ok_or(
+                "Identity doesn't have an authentication key for signing document transitions"
+                    .to_string(),
+            )?
+            .clone();
+        let mut rng = StdRng::from_entropy();
+        let total = import.documents.len();
+        let mut outcomes: Vec<(usize, Result<Identifier, String>)> = Vec::with_capacity(total);
+
+        for batch in import.documents.chunks(import.batch_size.max(1)) {
+            let mut batch_failed = true;
+            for (number, properties) in batch {
+                if !outcomes.is_empty() {
+                    self.throttle(Endpoint::Dapi).await;
+                }
+                let entropy = Bytes32::random_with_rng(&mut rng);
+                let document_id = Document::generate_document_id_v0(
+                    &import.contract.id(),
+                    &owner_id,
+                    document_type.name().as_str(),
+                    entropy.as_slice(),
+                );
+                let document = Document::V0(DocumentV0 {
+                    id: document_id,
+                    owner_id,
+                    properties: properties.clone(),
+                    revision: None,
+                    created_at: None,
+                    updated_at: None,
+                    transferred_at: None,
+                    created_at_block_height: None,
+                    updated_at_block_height: None,
+                    transferred_at_block_height: None,
+                    created_at_core_block_height: None,
+                    updated_at_core_block_height: None,
+                    transferred_at_core_block_height: None,
+                });
+                let result = document
+                    .put_to_platform_and_wait_for_response(
+                        sdk,
+                        document_type.to_owned_document_type(),
+                        entropy.0,
+                        public_key.clone(),
+                        import.contract.clone(),
+                        &import.owner,
+                    )
+                    .await
+                    .map(|_| document_id)
+                    .map_err(|e| {
+                        self.rejection_message("document create", "Error creating the document", &e)
+                    });
+                if result.is_ok() {
+                    batch_failed = false;
+                    self.record_key_usage(&owner_id, public_key.id(), TRANSITION_DOCUMENT_CREATE);
+                }
+                outcomes.push((*number, result));
+            }
+            let progress = BackendTaskSuccessResult::DocumentImportProgress {
+                done: outcomes.len(),
+                total,
+            };
+            if sender.send(TaskResult::Success(progress)).await.is_err() {
+                tracing::warn!("Failed to report the import progress");
+            }
+            if batch_failed {
+                break;
+            }
+        }
+
+        let submitted = outcomes.len();
+        outcomes.extend(import.documents[submitted..].iter().map(|(number, _)| {
+            (
+                *number,
+                Err(
+                    "Not submitted, the import stopped after a batch in which every document \
+                     failed"
+                        .to_string(),
+                ),
+            )
+        }));
+        Ok(BackendTaskSuccessResult::ImportedDocuments(outcomes))
+    }
+}
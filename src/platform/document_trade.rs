@@ -0,0 +1,223 @@
+use crate::context::AppContext;
+use crate::model::credit_operation::CreditOperationKind;
+use crate::model::document_trade::{check_purchase_balance, listed_price};
+use crate::model::key_usage::{TRANSITION_DOCUMENT_PRICE, TRANSITION_DOCUMENT_PURCHASE};
+use crate::model::qualified_identity::QualifiedIdentity;
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::data_contract::document_type::DocumentTypeRef;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::document::{DocumentV0Getters, DocumentV0Setters};
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::identity::KeyID;
+use dash_sdk::dpp::nft::TradeMode;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::prelude::{IdentityNonce, UserFeeIncrease};
+use dash_sdk::dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use dash_sdk::dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+use dash_sdk::dpp::state_transition::StateTransition;
+use dash_sdk::platform::transition::broadcast::BroadcastStateTransition;
+use dash_sdk::platform::{Document, DocumentQuery, Fetch, FetchMany, Identity, IdentityPublicKey};
+use dash_sdk::query_types::Documents;
+use dash_sdk::Sdk;
+use std::sync::Arc;
+
+/// A document to list for a price, or to buy for the price it is listed for
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentTrade {
+    pub contract: Arc<DataContract>,
+    pub document_type: String,
+    pub document: Document,
+    /// The owner setting the price, or the buyer
+    pub identity: QualifiedIdentity,
+    /// The new price, or the price the buyer agreed to pay. A price of 0 takes the document off
+    /// sale.
+    pub price: Credits,
+}
+
+impl AppContext {
+    /// Lists the document for the price, returns it as fetched afterwards
+    pub(super) async fn set_document_price(
+        &self,
+        sdk: &Sdk,
+        trade: DocumentTrade,
+    ) -> Result<Documents, String> {
+        let owner_id = trade.identity.identity.id();
+        if trade.document.owner_id() != owner_id {
+            return Err("Only the owner of a document can set its price".to_string());
+        }
+        let balance_before = self.fetch_balance(sdk, &trade.identity).await?;
+        let key_id = self
+            .broadcast_trade(sdk, &trade, |document, document_type, key, nonce| {
+                DocumentsBatchTransition::new_document_update_price_transition_from_document(
+                    document,
+                    document_type,
+                    trade.price,
+                    key,
+                    nonce,
+                    UserFeeIncrease::default(),
+                    &trade.identity,
+                    sdk.version(),
+                    None,
+                    None,
+                    None,
+                )
+            })
+            .await?;
+        self.record_key_usage(&owner_id, key_id, TRANSITION_DOCUMENT_PRICE);
+        self.record_transition_fee(sdk, owner_id, balance_before, "Document price updated")
+            .await;
+        self.fetch_traded_document(sdk, &trade).await
+    }
+
+    /// Buys the document for the price it is listed for, after checking the buyer can pay it.
+    /// Returns the document as fetched afterwards.
+    pub(super) async fn purchase_document(
+        &self,
+        sdk: &Sdk,
+        trade: DocumentTrade,
+    ) -> Result<Documents, String> {
+        let buyer_id = trade.identity.identity.id();
+        if trade.document.owner_id() == buyer_id {
+            return Err("The identity already owns this document".to_string());
+        }
+        // The price may have changed since the buyer saw it
+        let current = self.fetch_traded_document(sdk, &trade).await?;
+        let document = current
+            .get(&trade.document.id())
+            .cloned()
+            .flatten()
+            .ok_or("The document no longer exists".to_string())?;
+        match listed_price(&document) {
+            Some(price) if price == trade.price => {}
+            Some(price) => {
+                return Err(format!(
+                    "The price changed to {} credits, check it again before buying",
+                    price
+                ))
+            }
+            None => return Err("The document is no longer for sale".to_string()),
+        }
+        let balance_before = self.fetch_balance(sdk, &trade.identity).await?;
+        check_purchase_balance(balance_before, trade.price)?;
+
+        let trade = DocumentTrade { document, ..trade };
+        let key_id = self
+            .broadcast_trade(sdk, &trade, |document, document_type, key, nonce| {
+                DocumentsBatchTransition::new_document_purchase_transition_from_document(
+                    document,
+                    document_type,
+                    buyer_id,
+                    trade.price,
+                    key,
+                    nonce,
+                    UserFeeIncrease::default(),
+                    &trade.identity,
+                    sdk.version(),
+                    None,
+                    None,
+                    None,
+                )
+            })
+            .await?;
+        self.record_key_usage(&buyer_id, key_id, TRANSITION_DOCUMENT_PURCHASE);
+        if let Ok(Some(buyer)) = Identity::fetch_by_identifier(sdk, buyer_id).await {
+            self.record_credit_operation(
+                buyer_id,
+                CreditOperationKind::DocumentPurchase,
+                trade.price,
+                balance_before
+                    .saturating_sub(buyer.balance())
+                    .saturating_sub(trade.price),
+                format!(
+                    "{} document {}",
+                    trade.document_type,
+                    trade.document.id().to_string(Encoding::Base58)
+                ),
+            );
+        }
+        self.fetch_traded_document(sdk, &trade).await
+    }
+
+    async fn fetch_balance(
+        &self,
+        sdk: &Sdk,
+        identity: &QualifiedIdentity,
+    ) -> Result<Credits, String> {
+        Ok(Identity::fetch_by_identifier(sdk, identity.identity.id())
+            .await
+            .map_err(|e| format!("Fetch identity error: {}", e))?
+            .ok_or("Identity not found on Platform".to_string())?
+            .balance())
+    }
+
+    /// Signs the transition built by `build` with the identity's document key and broadcasts
+    /// it, returns the id of the signing key
+    async fn broadcast_trade<F, E>(
+        &self,
+        sdk: &Sdk,
+        trade: &DocumentTrade,
+        build: F,
+    ) -> Result<KeyID, String>
+    where
+        F: FnOnce(
+            Document,
+            DocumentTypeRef,
+            &IdentityPublicKey,
+            IdentityNonce,
+        ) -> Result<StateTransition, E>,
+        E: std::fmt::Display,
+    {
+        let document_type = trade
+            .contract
+            .document_type_for_name(&trade.document_type)
+            .map_err(|_| format!("Document type {} not found", trade.document_type))?;
+        if document_type.trade_mode() != TradeMode::DirectPurchase {
+            return Err(format!(
+                "{} documents can't be sold, the contract doesn't allow it",
+                trade.document_type
+            ));
+        }
+        let public_key = trade
+            .identity
+            .document_signing_key(&document_type)
+            .ok_or(
+                "Identity doesn't have an authentication key for signing document transitions"
+                    .to_string(),
+            )?
+            .clone();
+        let nonce = sdk
+            .get_identity_contract_nonce(
+                trade.identity.identity.id(),
+                trade.contract.id(),
+                true,
+                None,
+            )
+            .await
+            .map_err(|e| format!("Fetch nonce error: {}", e))?;
+        let mut document = trade.document.clone();
+        document.bump_revision();
+        let transition = build(document, document_type, &public_key, nonce)
+            .map_err(|e| format!("Document transition error: {}", e))?;
+        transition
+            .broadcast_and_wait(sdk, None)
+            .await
+            .map_err(|e| self.rejection_message("document trade", "Broadcasting error", &e))?;
+        Ok(public_key.id())
+    }
+
+    async fn fetch_traded_document(
+        &self,
+        sdk: &Sdk,
+        trade: &DocumentTrade,
+    ) -> Result<Documents, String> {
+        let query = DocumentQuery::new(trade.contract.clone(), &trade.document_type)
+            .map_err(|e| format!("Invalid document query: {}", e))?
+            .with_document_id(&trade.document.id());
+        Document::fetch_many(sdk, query)
+            .await
+            .map_err(|e| format!("Error fetching the document: {}", e))
+    }
+}
@@ -0,0 +1,122 @@
+use crate::context::AppContext;
+use crate::model::contested_name::ContestState;
+use crate::model::dpns_lookup::{
+    is_contested_label, matches_pattern, split_search_pattern, validate_label, DpnsNameRecord,
+    NameAvailability, NameCheck, DPNS_SEARCH_LIMIT,
+};
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::platform_value::Value;
+use dash_sdk::dpp::util::strings::convert_to_homograph_safe_chars;
+use dash_sdk::drive::query::{OrderClause, WhereClause, WhereOperator};
+use dash_sdk::platform::{Document, DocumentQuery, FetchMany, Identifier};
+use dash_sdk::Sdk;
+
+impl AppContext {
+    fn dpns_domain_query(&self) -> Result<DocumentQuery, String> {
+        Ok(DocumentQuery::new(self.dpns_contract.clone(), "domain")
+            .map_err(|e| e.to_string())?
+            .with_where(WhereClause {
+                field: "normalizedParentDomainName".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Text("dash".to_string()),
+            }))
+    }
+
+    /// Names under `dash` matching the pattern, searched on Platform by the part before the
+    /// first `*` and filtered by the rest
+    pub(super) async fn search_dpns_names(
+        &self,
+        sdk: &Sdk,
+        pattern: &str,
+    ) -> Result<Vec<DpnsNameRecord>, String> {
+        let (prefix, pattern) = split_search_pattern(pattern)?;
+        let mut query = self
+            .dpns_domain_query()?
+            .with_where(WhereClause {
+                field: "normalizedLabel".to_string(),
+                operator: WhereOperator::StartsWith,
+                value: Value::Text(prefix),
+            })
+            .with_order_by(OrderClause {
+                field: "normalizedLabel".to_string(),
+                ascending: true,
+            });
+        query.limit = DPNS_SEARCH_LIMIT;
+        let documents = Document::fetch_many(sdk, query)
+            .await
+            .map_err(|e| format!("Error searching DPNS names: {}", e))?;
+        Ok(documents
+            .values()
+            .flatten()
+            .filter_map(|document| {
+                let label = document.get("label")?.as_text()?.to_string();
+                let normalized_label = document.get("normalizedLabel")?.as_text()?.to_string();
+                Some(DpnsNameRecord {
+                    label,
+                    normalized_label,
+                    identity_id: owner_of(document),
+                })
+            })
+            .filter(|record| matches_pattern(&record.normalized_label, &pattern))
+            .collect())
+    }
+
+    /// Checks each candidate name, against Platform for registered names and against the
+    /// local contest list for names being voted on
+    pub(super) async fn check_dpns_names(
+        &self,
+        sdk: &Sdk,
+        names: &[String],
+    ) -> Result<Vec<NameCheck>, String> {
+        let contests = self
+            .db
+            .get_all_contested_names(self)
+            .map_err(|e| format!("Database error: {}", e))?;
+        let mut checks = Vec::with_capacity(names.len());
+        for name in names {
+            let label = name.trim().trim_end_matches(".dash");
+            let normalized_label = convert_to_homograph_safe_chars(label);
+            let availability = if let Err(reason) = validate_label(label) {
+                NameAvailability::Invalid(reason)
+            } else {
+                let query = self.dpns_domain_query()?.with_where(WhereClause {
+                    field: "normalizedLabel".to_string(),
+                    operator: WhereOperator::Equal,
+                    value: Value::Text(normalized_label.clone()),
+                });
+                let documents = Document::fetch_many(sdk, query)
+                    .await
+                    .map_err(|e| format!("Error checking {}: {}", name, e))?;
+                let contest = contests
+                    .iter()
+                    .find(|contest| contest.normalized_contested_name == normalized_label);
+                match (documents.values().flatten().next(), contest) {
+                    (Some(document), _) => NameAvailability::Taken(owner_of(document)),
+                    (None, Some(contest)) => match contest.state {
+                        ContestState::Locked => NameAvailability::Locked,
+                        ContestState::WonBy(identity_id) => NameAvailability::Taken(identity_id),
+                        _ => NameAvailability::InContest,
+                    },
+                    (None, None) if is_contested_label(&normalized_label) => {
+                        NameAvailability::AvailableContested
+                    }
+                    (None, None) => NameAvailability::Available,
+                }
+            };
+            checks.push(NameCheck {
+                name: label.to_string(),
+                normalized_label,
+                availability,
+            });
+        }
+        Ok(checks)
+    }
+}
+
+/// The identity a domain document resolves to, falling back to its owner
+fn owner_of(document: &Document) -> Identifier {
+    document
+        .get("records.identity")
+        .and_then(|identity| identity.to_identifier().ok())
+        .unwrap_or(document.owner_id())
+}
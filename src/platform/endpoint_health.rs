@@ -0,0 +1,76 @@
+use crate::model::endpoint_health::{same_address, EndpointFlag};
+use chrono::Utc;
+use std::sync::Mutex;
+
+/// Remembers the DAPI nodes that answered with proofs that did not verify, so queries avoid
+/// them for a while
+#[derive(Debug, Default)]
+pub(crate) struct EndpointHealth {
+    flags: Mutex<Vec<EndpointFlag>>,
+}
+
+impl EndpointHealth {
+    /// Flags the node, or renews its flag and counts the failure when it already was
+    pub fn flag(&self, address: &str, reason: &str) {
+        let now = Utc::now();
+        let mut flags = self.flags.lock().unwrap();
+        match flags
+            .iter_mut()
+            .find(|flag| same_address(&flag.address, address))
+        {
+            Some(flag) => {
+                if !flag.is_active(now) {
+                    flag.first_flagged_at = now;
+                    flag.failures = 0;
+                }
+                flag.reason = reason.to_string();
+                flag.last_flagged_at = now;
+                flag.failures += 1;
+            }
+            None => flags.push(EndpointFlag {
+                address: address.to_string(),
+                reason: reason.to_string(),
+                first_flagged_at: now,
+                last_flagged_at: now,
+                failures: 1,
+            }),
+        }
+        tracing::warn!(
+            "Flagged the DAPI node {} after a proof failed to verify: {}",
+            address,
+            reason
+        );
+    }
+
+    pub fn is_flagged(&self, address: &str) -> bool {
+        let now = Utc::now();
+        self.flags
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|flag| same_address(&flag.address, address) && flag.is_active(now))
+    }
+
+    /// The nodes that are still avoided, most recently flagged first
+    pub fn active_flags(&self) -> Vec<EndpointFlag> {
+        let now = Utc::now();
+        let mut flags: Vec<EndpointFlag> = self
+            .flags
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|flag| flag.is_active(now))
+            .cloned()
+            .collect();
+        flags.sort_by(|a, b| b.last_flagged_at.cmp(&a.last_flagged_at));
+        flags
+    }
+
+    /// Trusts the node again
+    pub fn clear(&self, address: &str) {
+        self.flags
+            .lock()
+            .unwrap()
+            .retain(|flag| !same_address(&flag.address, address));
+    }
+}
@@ -0,0 +1,232 @@
+use crate::config::Config;
+use crate::context::AppContext;
+use crate::model::clock_skew::{apply_clock_offset, ClockSkew};
+use crate::model::health_check::{HealthCheck, HealthReport};
+use crate::platform::dapi_connectivity::check_dapi_address;
+use crate::platform::BackendTaskSuccessResult;
+use dash_sdk::dashcore_rpc::RpcApi;
+use dash_sdk::dpp::block::extended_epoch_info::ExtendedEpochInfo;
+use dash_sdk::platform::FetchCurrent;
+use dash_sdk::sdk::Uri;
+use std::str::FromStr;
+use std::time::Instant;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum HealthTask {
+    /// Checks the database, configuration, Core, DAPI and the local clock
+    RunChecks,
+    /// Compares the local clock with Platform and Core, to correct countdowns
+    MeasureClockSkew,
+    /// Finds the layer at which each configured DAPI address fails
+    TroubleshootDapi,
+}
+
+impl AppContext {
+    pub async fn run_health_task(
+        &self,
+        task: HealthTask,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        match task {
+            HealthTask::RunChecks => {
+                let mut checks = vec![
+                    self.check_database(),
+                    self.check_config(),
+                    self.check_core(),
+                ];
+                let (dapi_check, platform_time_ms) = self.check_dapi().await;
+                checks.push(dapi_check);
+                checks.push(check_clock(&self.record_clock_skew(platform_time_ms)));
+                let report = HealthReport {
+                    network: self.network_string(),
+                    checked_at: chrono::Utc::now(),
+                    checks,
+                };
+                *self.health_report.lock().unwrap() = Some(report.clone());
+                Ok(BackendTaskSuccessResult::HealthReport(report))
+            }
+            HealthTask::MeasureClockSkew => {
+                if self.dapi_refused_by_proxy().is_some() {
+                    self.record_clock_skew(None);
+                    return Ok(BackendTaskSuccessResult::None);
+                }
+                let platform_time_ms =
+                    ExtendedEpochInfo::fetch_current_with_metadata(&self.unproved_sdk())
+                        .await
+                        .map(|(_, metadata)| metadata.time_ms)
+                        .ok();
+                self.record_clock_skew(platform_time_ms);
+                Ok(BackendTaskSuccessResult::None)
+            }
+            HealthTask::TroubleshootDapi => {
+                if let Some(reason) = self.dapi_refused_by_proxy() {
+                    return Err(reason);
+                }
+                let checks = self
+                    .config
+                    .dapi_addresses
+                    .split(',')
+                    .filter(|address| !address.trim().is_empty())
+                    .map(|address| check_dapi_address(&self.config, address));
+                Ok(BackendTaskSuccessResult::DapiConnectivity(
+                    futures::future::join_all(checks).await,
+                ))
+            }
+        }
+    }
+
+    /// Compares the local clock with the Platform block time and Core's peers, and corrects
+    /// countdowns by the result
+    fn record_clock_skew(&self, platform_time_ms: Option<u64>) -> ClockSkew {
+        let now = chrono::Utc::now();
+        let core_offset_ms = match self.core_client.get_network_info() {
+            // Core's offset is the peers' median time minus the local time
+            Ok(network_info) => Some(-(network_info.time_offset as i64) * 1000),
+            Err(e) => {
+                tracing::debug!("Could not get the network time from Core: {}", e);
+                None
+            }
+        };
+        let skew = ClockSkew {
+            platform_offset_ms: platform_time_ms
+                .map(|time_ms| now.timestamp_millis() - time_ms as i64),
+            core_offset_ms,
+            measured_at: now,
+        };
+        *self.clock_skew.lock().unwrap() = Some(skew);
+        apply_clock_offset(&skew);
+        if skew.exceeds_threshold() {
+            tracing::warn!("{}", skew.describe());
+        }
+        skew
+    }
+
+    fn check_database(&self) -> HealthCheck {
+        let name = "Database";
+        let database_path = self.app_dirs.database_path().display().to_string();
+        match self.db.integrity_problems() {
+            Ok(problems) if problems.is_empty() => HealthCheck::passed(name, "No problems found"),
+            Ok(problems) => HealthCheck::failed(
+                name,
+                format!("{} problems, first: {}", problems.len(), problems[0]),
+                format!(
+                    "Close the tool, back up {} and restore an earlier copy. Deleting it starts \
+                     over with an empty database.",
+                    database_path
+                ),
+            ),
+            Err(e) => HealthCheck::failed(
+                name,
+                format!("The integrity check could not run: {}", e),
+                format!(
+                    "Make sure {} is readable and not locked by another program.",
+                    database_path
+                ),
+            ),
+        }
+    }
+
+    fn check_config(&self) -> HealthCheck {
+        let name = "Configuration";
+        let config_path = self.app_dirs.config_path();
+        match Config::load(&config_path) {
+            Ok(_) if Uri::from_str(&self.config.insight_api_url).is_err() => HealthCheck::warning(
+                name,
+                format!(
+                    "insight_api_url \"{}\" is not a valid URL",
+                    self.config.insight_api_url
+                ),
+                format!("Correct insight_api_url in {}.", config_path.display()),
+            ),
+            Ok(_) if self.config.dapi_insecure_tls => HealthCheck::warning(
+                name,
+                "dapi_insecure_tls is on, DAPI certificates are not verified",
+                "Only use this for a private devnet. Set dapi_ca_certificate to the devnet's CA \
+                 instead where possible.",
+            ),
+            Ok(_) => HealthCheck::passed(name, format!("{} is valid", config_path.display())),
+            Err(e) => HealthCheck::failed(
+                name,
+                e.to_string(),
+                format!(
+                    "Fix {}, or delete it to get the default configuration back on the next start.",
+                    config_path.display()
+                ),
+            ),
+        }
+    }
+
+    fn check_core(&self) -> HealthCheck {
+        let name = "Dash Core RPC";
+        match self.core_client.get_block_count() {
+            Ok(height) => HealthCheck::passed(name, format!("Reachable at block {}", height)),
+            Err(e) => HealthCheck::failed(
+                name,
+                e.to_string(),
+                format!(
+                    "Make sure Dash Core runs with RPC enabled and that core_host, core_rpc_port \
+                     and the RPC credentials in {} match it.",
+                    self.app_dirs.config_path().display()
+                ),
+            ),
+        }
+    }
+
+    /// Also returns the Platform block time of the response, for the clock check
+    async fn check_dapi(&self) -> (HealthCheck, Option<u64>) {
+        let name = "DAPI";
+        if let Some(reason) = self.dapi_refused_by_proxy() {
+            return (
+                HealthCheck::warning(name, "Not checked, the proxy is on", reason),
+                None,
+            );
+        }
+        let started = Instant::now();
+        // Without proofs so a Core problem doesn't show up as a DAPI problem as well
+        match ExtendedEpochInfo::fetch_current_with_metadata(&self.unproved_sdk()).await {
+            Ok((_, metadata)) => (
+                HealthCheck::passed(
+                    name,
+                    format!(
+                        "Reachable at Platform height {}, answered in {} ms",
+                        metadata.height,
+                        started.elapsed().as_millis()
+                    ),
+                ),
+                Some(metadata.time_ms),
+            ),
+            Err(e) => (
+                HealthCheck::failed(
+                    name,
+                    e.to_string(),
+                    format!(
+                        "Check the internet connection and the dapi_addresses in {}. The DAPI \
+                         troubleshooter in Diagnostics shows which layer of the connection fails.",
+                        self.app_dirs.config_path().display()
+                    ),
+                ),
+                None,
+            ),
+        }
+    }
+}
+
+fn check_clock(skew: &ClockSkew) -> HealthCheck {
+    let name = "Clock";
+    if skew.platform_offset_ms.is_none() && skew.core_offset_ms.is_none() {
+        return HealthCheck::warning(
+            name,
+            "Not compared, neither Platform nor Core answered",
+            "Fix the DAPI or Core connection first.",
+        );
+    }
+    if skew.exceeds_threshold() {
+        HealthCheck::warning(
+            name,
+            skew.describe(),
+            "Turn on automatic time synchronization in the system settings. Contest countdowns \
+             are corrected by the measured skew until then.",
+        )
+    } else {
+        HealthCheck::passed(name, skew.describe())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::context::AppContext;
+use crate::model::key_usage::TRANSITION_IDENTITY_UPDATE;
 use crate::model::qualified_identity::EncryptedPrivateKeyTarget::PrivateKeyOnMainIdentity;
 use crate::model::qualified_identity::QualifiedIdentity;
 use dash_sdk::dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
@@ -33,6 +34,7 @@ impl AppContext {
             .await
             .map_err(|e| format!("Fetch nonce error: {}", e))?
             .unwrap();
+        let balance_before = identity.balance();
         qualified_identity.identity = identity;
         qualified_identity.identity.bump_revision();
         public_key_to_add.set_id(qualified_identity.identity.get_public_key_max_id() + 1);
@@ -56,7 +58,19 @@ impl AppContext {
         let result = state_transition
             .broadcast_and_wait(sdk, None)
             .await
-            .map_err(|e| format!("Broadcasting error: {}", e))?;
+            .map_err(|e| self.rejection_message("key update", "Broadcasting error", &e))?;
+        self.record_key_usage(
+            &qualified_identity.identity.id(),
+            master_key_id,
+            TRANSITION_IDENTITY_UPDATE,
+        );
+        self.record_transition_fee(
+            sdk,
+            qualified_identity.identity.id(),
+            balance_before,
+            "Key added",
+        )
+        .await;
 
         if let StateTransitionProofResult::VerifiedPartialIdentity(identity) = result {
             for public_key in identity.loaded_public_keys.into_values() {
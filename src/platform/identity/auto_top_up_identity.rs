@@ -0,0 +1,41 @@
+use crate::context::AppContext;
+use crate::model::approval::AuditEntry;
+use crate::platform::identity::IdentityTopUpInfo;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+
+impl AppContext {
+    /// Runs a top up on behalf of an automatic top up rule. The attempt and its outcome go to
+    /// the audit trail and funded amounts count towards the rule's weekly limit.
+    pub(super) async fn auto_top_up_identity(
+        &self,
+        input: IdentityTopUpInfo,
+    ) -> Result<String, String> {
+        let identity_id = input.qualified_identity.identity.id();
+        let amount = input.amount;
+        let action = format!(
+            "Automatic top up of {} with {:.8} DASH",
+            input.qualified_identity.display_short_string(),
+            amount as f64 * 1e-8
+        );
+        let result = self.top_up_identity(input).await;
+        let outcome = match &result {
+            Ok(message) => {
+                if let Err(e) = self.db.record_auto_top_up(&identity_id, amount, self) {
+                    tracing::warn!("Failed to record the automatic top up: {}", e);
+                }
+                message.clone()
+            }
+            Err(e) => format!("Failed: {}", e),
+        };
+        let entry = AuditEntry {
+            action,
+            outcome,
+            approver: None,
+            logged_at: chrono::Utc::now().timestamp(),
+        };
+        if let Err(e) = self.db.insert_audit_entry(&entry, self) {
+            tracing::error!("Failed to write the audit trail: {}", e);
+        }
+        result
+    }
+}
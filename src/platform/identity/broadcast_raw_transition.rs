@@ -0,0 +1,41 @@
+use crate::context::AppContext;
+use crate::model::transition_sandbox::{transition_credit_spending, SandboxTransitionKind};
+use dash_sdk::dpp::dashcore::hashes::{sha256, Hash};
+use dash_sdk::dpp::dashcore::Network;
+use dash_sdk::dpp::serialization::PlatformSerializable;
+use dash_sdk::dpp::state_transition::StateTransition;
+use dash_sdk::platform::transition::broadcast::BroadcastStateTransition;
+use dash_sdk::Sdk;
+
+impl AppContext {
+    /// Broadcasts a transition built in the transition sandbox and waits for Platform to
+    /// process it. Refused on mainnet, the sandbox is for experimenting on testnet and devnets.
+    pub(super) async fn broadcast_raw_transition(
+        &self,
+        sdk: &Sdk,
+        transition: StateTransition,
+    ) -> Result<String, String> {
+        if self.network == Network::Dash {
+            return Err("Transitions from the sandbox can't be broadcast on mainnet".to_string());
+        }
+        let bytes = transition
+            .serialize_to_bytes()
+            .map_err(|e| format!("Failed to serialize the transition: {}", e))?;
+        let hash = hex::encode(sha256::Hash::hash(&bytes).to_byte_array());
+        transition
+            .broadcast_and_wait(sdk, None)
+            .await
+            .map_err(|e| self.rejection_message("raw", "Broadcasting error", &e))?;
+        // Raw transfers and withdrawals count against the daily limit like the others
+        if let Some((_, identity_id, credits)) = transition_credit_spending(&transition) {
+            if let Err(e) = self.db.record_credit_spending(&identity_id, credits, self) {
+                tracing::warn!("Failed to record spent credits: {}", e);
+            }
+        }
+        Ok(format!(
+            "{} {} was accepted by Platform",
+            SandboxTransitionKind::of(&transition).label(),
+            hash
+        ))
+    }
+}
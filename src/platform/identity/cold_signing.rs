@@ -0,0 +1,234 @@
+use crate::context::AppContext;
+use crate::model::cold_signing::{
+    request_id, ColdSigningFile, ColdSigningRecord, ColdSigningStatus, ColdWithdrawal,
+    UnsignedSigner, COLD_SIGNING_FORMAT_VERSION,
+};
+use crate::model::credit_operation::CreditOperationKind;
+use crate::model::fee::withdrawal_fee_per_byte;
+use crate::model::key_usage::TRANSITION_WITHDRAWAL;
+use crate::model::qualified_identity::QualifiedIdentity;
+use dash_sdk::dpp::dashcore::Address;
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::core_script::CoreScript;
+use dash_sdk::dpp::identity::signer::Signer;
+use dash_sdk::dpp::identity::{KeyID, SecurityLevel};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::prelude::UserFeeIncrease;
+use dash_sdk::dpp::serialization::PlatformSerializable;
+use dash_sdk::dpp::state_transition::identity_credit_withdrawal_transition::methods::{
+    IdentityCreditWithdrawalTransitionMethodsV0, PreferredKeyPurposeForSigningWithdrawal,
+};
+use dash_sdk::dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
+use dash_sdk::dpp::withdrawal::Pooling;
+use dash_sdk::dpp::ProtocolError;
+use dash_sdk::platform::transition::broadcast::BroadcastStateTransition;
+use dash_sdk::platform::{Fetch, Identifier, Identity};
+use dash_sdk::Sdk;
+
+impl AppContext {
+    /// Builds a withdrawal without signing it, for an identity whose keys are on an offline
+    /// machine. Only the nonce is fetched, the key must be one of the identity's withdrawal
+    /// keys.
+    pub(super) async fn prepare_cold_withdrawal(
+        &self,
+        sdk: &Sdk,
+        qualified_identity: QualifiedIdentity,
+        to_address: Option<Address>,
+        credits: Credits,
+        key_id: KeyID,
+    ) -> Result<ColdSigningFile, String> {
+        let identity = &qualified_identity.identity;
+        let signing_key = identity
+            .get_public_key_by_id(key_id)
+            .ok_or(format!("The identity has no key {}", key_id))?;
+        let nonce = sdk
+            .get_identity_nonce(identity.id(), true, None)
+            .await
+            .map_err(|e| format!("Fetch nonce error: {}", e))?;
+        // The transition is signed offline, so the fee is settled now at the preferred speed
+        let speed = self.preferences().fee_choice.speed;
+        let core_fee_per_byte = withdrawal_fee_per_byte(self.fee_estimates().rate(speed));
+        let transition = IdentityCreditWithdrawalTransition::try_from_identity(
+            identity,
+            to_address
+                .as_ref()
+                .map(|address| CoreScript::new(address.script_pubkey())),
+            credits,
+            Pooling::Never,
+            core_fee_per_byte,
+            UserFeeIncrease::default(),
+            UnsignedSigner,
+            Some(signing_key),
+            PreferredKeyPurposeForSigningWithdrawal::TransferPreferred,
+            nonce,
+            sdk.version(),
+            None,
+        )
+        .map_err(|e| format!("Withdrawal transition error: {}", e))?;
+        let bytes = transition
+            .serialize_to_bytes()
+            .map_err(|e| format!("Failed to serialize the transition: {}", e))?;
+
+        let file = ColdSigningFile {
+            version: COLD_SIGNING_FORMAT_VERSION,
+            network: self.network_string(),
+            request_id: request_id(&transition)?,
+            identity_id: identity.id().to_string(Encoding::Base58),
+            key_id,
+            description: ColdWithdrawal {
+                credits,
+                to_address,
+            }
+            .description(),
+            credits,
+            transition: hex::encode(bytes),
+            signed: false,
+        };
+        self.record_cold_signing_status(&file, ColdSigningStatus::AwaitingSignature, None);
+        Ok(file)
+    }
+
+    /// Signs the transition of the file with the private key of a local identity, on the
+    /// offline machine. Returns the file to take back to the online machine.
+    pub(crate) fn sign_cold_transition(
+        &self,
+        file: &ColdSigningFile,
+    ) -> Result<ColdSigningFile, String> {
+        if file.signed {
+            return Err("The transition is already signed".to_string());
+        }
+        if file.network != self.network_string() {
+            return Err(format!(
+                "The transition is for {}, switch to that network to sign it",
+                file.network
+            ));
+        }
+        let (mut transition, withdrawal) = file.decode_withdrawal(self.network)?;
+        let identity_id = Identifier::from_string(&file.identity_id, Encoding::Base58)
+            .map_err(|e| format!("Invalid identity id: {}", e))?;
+        let qualified_identity = self
+            .db
+            .get_qualified_identity_by_id(&identity_id, self)
+            .map_err(|e| format!("Database error: {}", e))?
+            .ok_or("The identity isn't loaded on this machine".to_string())?;
+        let public_key = qualified_identity
+            .identity
+            .get_public_key_by_id(file.key_id)
+            .ok_or(format!("The identity has no key {}", file.key_id))?
+            .clone();
+        if !qualified_identity.can_sign_with(&public_key) {
+            return Err(format!(
+                "The private key of key {} isn't on this machine",
+                file.key_id
+            ));
+        }
+        transition
+            .sign_external(
+                &public_key,
+                &qualified_identity,
+                None::<fn(Identifier, String) -> Result<SecurityLevel, ProtocolError>>,
+            )
+            .map_err(|e| format!("Signing error: {}", e))?;
+        let bytes = transition
+            .serialize_to_bytes()
+            .map_err(|e| format!("Failed to serialize the transition: {}", e))?;
+        let signed = ColdSigningFile {
+            description: withdrawal.description(),
+            transition: hex::encode(bytes),
+            signed: true,
+            ..file.clone()
+        };
+        self.record_key_usage(&identity_id, file.key_id, TRANSITION_WITHDRAWAL);
+        self.record_cold_signing_status(&signed, ColdSigningStatus::Signed, None);
+        Ok(signed)
+    }
+
+    /// Broadcasts the transition of a file signed on the offline machine
+    pub(super) async fn broadcast_cold_signed(
+        &self,
+        sdk: &Sdk,
+        file: ColdSigningFile,
+    ) -> Result<String, String> {
+        let result = self.broadcast_cold_signed_transition(sdk, &file).await;
+        match &result {
+            Ok(_) => self.record_cold_signing_status(&file, ColdSigningStatus::Broadcast, None),
+            Err(e) => {
+                self.record_cold_signing_status(&file, ColdSigningStatus::Failed, Some(e.clone()))
+            }
+        }
+        result
+    }
+
+    async fn broadcast_cold_signed_transition(
+        &self,
+        sdk: &Sdk,
+        file: &ColdSigningFile,
+    ) -> Result<String, String> {
+        if !file.signed {
+            return Err("The transition isn't signed yet, sign it on the offline machine".into());
+        }
+        if file.network != self.network_string() {
+            return Err(format!("The transition is for {}", file.network));
+        }
+        let (transition, withdrawal) = file.decode_withdrawal(self.network)?;
+        let description = withdrawal.description();
+        let identity_id = Identifier::from_string(&file.identity_id, Encoding::Base58)
+            .map_err(|e| format!("Invalid identity id: {}", e))?;
+        let balance_before = Identity::fetch_by_identifier(sdk, identity_id)
+            .await
+            .map_err(|e| format!("Fetch identity error: {}", e))?
+            .ok_or("Identity not found on Platform".to_string())?
+            .balance();
+        transition
+            .broadcast_and_wait(sdk, None)
+            .await
+            .map_err(|e| self.rejection_message("withdrawal", "Broadcasting error", &e))?;
+        if let Err(e) = self
+            .db
+            .record_credit_spending(&identity_id, withdrawal.credits, self)
+        {
+            tracing::warn!("Failed to record spent credits: {}", e);
+        }
+
+        if let Ok(Some(identity)) = Identity::fetch_by_identifier(sdk, identity_id).await {
+            self.record_credit_operation(
+                identity_id,
+                CreditOperationKind::Withdrawal,
+                withdrawal.credits,
+                balance_before
+                    .saturating_sub(withdrawal.credits)
+                    .saturating_sub(identity.balance()),
+                format!("{} (cold signed)", description),
+            );
+            if let Ok(Some(mut qualified_identity)) =
+                self.db.get_qualified_identity_by_id(&identity_id, self)
+            {
+                qualified_identity.identity = identity;
+                if let Err(e) = self.insert_local_qualified_identity(&qualified_identity) {
+                    tracing::warn!("Failed to store the identity: {}", e);
+                }
+            }
+        }
+        Ok(format!("Broadcast: {}", description))
+    }
+
+    fn record_cold_signing_status(
+        &self,
+        file: &ColdSigningFile,
+        status: ColdSigningStatus,
+        error: Option<String>,
+    ) {
+        let record = ColdSigningRecord {
+            request_id: file.request_id.clone(),
+            identity_id: file.identity_id.clone(),
+            description: file.description.clone(),
+            status,
+            error,
+            updated_at: chrono::Utc::now().timestamp(),
+        };
+        if let Err(e) = self.db.set_cold_signing_status(&record, self) {
+            tracing::warn!("Failed to record the cold signing status: {}", e);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::context::AppContext;
+use crate::model::external_funding::{
+    check_signed_transaction, decode_psbt, decode_signed_transaction, encode_psbt,
+    estimate_asset_lock_fee,
+};
+use crate::model::rate_limit::Endpoint;
+use crate::platform::identity::IdentityRegistrationInfo;
+use dash_sdk::dapi_client::DapiRequestExecutor;
+use dash_sdk::dapi_grpc::core::v0::{GetTransactionRequest, GetTransactionResponse};
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::dashcore::consensus::encode::deserialize;
+use dash_sdk::dpp::dashcore::psbt::PartiallySignedTransaction;
+use dash_sdk::dpp::dashcore::transaction::special_transaction::asset_lock::AssetLockPayload;
+use dash_sdk::dpp::dashcore::transaction::special_transaction::TransactionPayload;
+use dash_sdk::dpp::dashcore::{Address, OutPoint, ScriptBuf, Transaction, TxIn, TxOut};
+use dash_sdk::{RequestSettings, Sdk};
+
+impl AppContext {
+    /// Builds the asset lock of an identity registration spending outputs of a wallet outside
+    /// the tool, returns it as an unsigned PSBT in base64. The one-time key the credits are
+    /// locked to comes from our wallet, the external wallet only signs the inputs.
+    pub(super) async fn prepare_external_asset_lock(
+        &self,
+        sdk: &Sdk,
+        input: IdentityRegistrationInfo,
+        outpoints: Vec<OutPoint>,
+        change_address: Address,
+    ) -> Result<String, String> {
+        let asset_lock_key = input
+            .wallet
+            .read()
+            .unwrap()
+            .identity_registration_ecdsa_private_key(sdk.network, input.identity_index);
+        let one_time_key_hash = asset_lock_key.public_key(&Secp256k1::new()).pubkey_hash();
+
+        let mut previous_transactions = Vec::with_capacity(outpoints.len());
+        let mut funds = 0;
+        for (index, outpoint) in outpoints.iter().enumerate() {
+            if index > 0 {
+                self.throttle(Endpoint::Dapi).await;
+            }
+            let previous = fetch_transaction(sdk, outpoint).await?;
+            let output = previous
+                .output
+                .get(outpoint.vout as usize)
+                .ok_or(format!("{} has no output {}", outpoint.txid, outpoint.vout))?;
+            funds += output.value;
+            previous_transactions.push(previous);
+        }
+
+        // The change output is needed, the confirmation of the asset lock is followed on it
+        let fee = estimate_asset_lock_fee(outpoints.len());
+        let change = funds.saturating_sub(input.amount + fee);
+        if change == 0 {
+            return Err(format!(
+                "The outputs hold {} duffs, more than {} are needed with the fee",
+                funds,
+                input.amount + fee
+            ));
+        }
+        let outputs = vec![
+            TxOut {
+                value: input.amount,
+                script_pubkey: ScriptBuf::new_op_return(&[]),
+            },
+            TxOut {
+                value: change,
+                script_pubkey: change_address.script_pubkey(),
+            },
+        ];
+        let transaction = Transaction {
+            version: 3,
+            lock_time: 0,
+            input: outpoints
+                .iter()
+                .map(|outpoint| TxIn {
+                    previous_output: *outpoint,
+                    ..Default::default()
+                })
+                .collect(),
+            output: outputs,
+            special_transaction_payload: Some(TransactionPayload::AssetLockPayloadType(
+                AssetLockPayload {
+                    version: 1,
+                    credit_outputs: vec![TxOut {
+                        value: input.amount,
+                        script_pubkey: ScriptBuf::new_p2pkh(&one_time_key_hash),
+                    }],
+                },
+            )),
+        };
+
+        let mut psbt = PartiallySignedTransaction::from_unsigned_tx(transaction)
+            .map_err(|e| format!("Failed to make the PSBT: {}", e))?;
+        for (psbt_input, previous) in psbt.inputs.iter_mut().zip(previous_transactions) {
+            psbt_input.non_witness_utxo = Some(previous);
+        }
+        Ok(encode_psbt(&psbt))
+    }
+
+    /// Checks the transaction the external wallet signed against the exported PSBT, then
+    /// broadcasts it and registers the identity
+    pub(super) async fn register_identity_with_signed_asset_lock(
+        &self,
+        sdk: &Sdk,
+        input: IdentityRegistrationInfo,
+        unsigned_psbt: String,
+        signed: String,
+    ) -> Result<(), String> {
+        let unsigned = decode_psbt(&unsigned_psbt)?.unsigned_tx;
+        let signed = decode_signed_transaction(&signed)?;
+        check_signed_transaction(&unsigned, &signed)?;
+        let address = signed
+            .output
+            .get(1)
+            .and_then(|output| Address::from_script(&output.script_pubkey, sdk.network).ok())
+            .ok_or("The asset lock has no change output".to_string())?;
+        let asset_lock_key = input
+            .wallet
+            .read()
+            .unwrap()
+            .identity_registration_ecdsa_private_key(sdk.network, input.identity_index);
+        let credit_script =
+            ScriptBuf::new_p2pkh(&asset_lock_key.public_key(&Secp256k1::new()).pubkey_hash());
+        let locks_to_identity = match &signed.special_transaction_payload {
+            Some(TransactionPayload::AssetLockPayloadType(payload)) => {
+                payload.credit_outputs.iter().any(|output| {
+                    output.script_pubkey == credit_script && output.value == input.amount
+                })
+            }
+            _ => false,
+        };
+        if !locks_to_identity {
+            return Err(
+                "The asset lock isn't for this identity index and amount, export the PSBT again"
+                    .to_string(),
+            );
+        }
+        self.register_identity_with_asset_lock(
+            sdk,
+            input.alias_input,
+            input.amount,
+            input.keys,
+            &signed,
+            &asset_lock_key,
+            &address,
+        )
+        .await
+    }
+}
+
+async fn fetch_transaction(sdk: &Sdk, outpoint: &OutPoint) -> Result<Transaction, String> {
+    let GetTransactionResponse { transaction, .. } = sdk
+        .execute(
+            GetTransactionRequest {
+                id: outpoint.txid.to_string(),
+            },
+            RequestSettings::default(),
+        )
+        .await
+        .map_err(|e| format!("Failed to fetch transaction {}: {}", outpoint.txid, e))?;
+    deserialize(&transaction).map_err(|e| format!("Invalid transaction {}: {}", outpoint.txid, e))
+}
@@ -0,0 +1,73 @@
+use crate::context::AppContext;
+use crate::model::identity_details::{DashpayProfile, IdentityDetails};
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::platform_value::Value;
+use dash_sdk::drive::query::{WhereClause, WhereOperator};
+use dash_sdk::platform::{Document, DocumentQuery, Fetch, FetchMany, Identifier, Identity};
+use dash_sdk::Sdk;
+
+impl AppContext {
+    pub(super) async fn fetch_identity_details(
+        &self,
+        sdk: &Sdk,
+        identity_id: Identifier,
+    ) -> Result<IdentityDetails, String> {
+        let identity = Identity::fetch_by_identifier(sdk, identity_id)
+            .await
+            .map_err(|e| format!("Error fetching identity: {}", e))?;
+
+        // DPNS domains that resolve to this identity
+        let domain_query = DocumentQuery::new(self.dpns_contract.clone(), "domain")
+            .map_err(|e| e.to_string())?
+            .with_where(WhereClause {
+                field: "records.identity".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(identity_id.to_buffer()),
+            });
+        let domains = Document::fetch_many(sdk, domain_query)
+            .await
+            .map_err(|e| format!("Error fetching DPNS names: {}", e))?;
+        let mut dpns_names: Vec<String> = domains
+            .values()
+            .flatten()
+            .filter_map(|document| {
+                let label = document.get("label")?.as_text()?;
+                let parent = document.get("normalizedParentDomainName")?.as_text()?;
+                Some(format!("{}.{}", label, parent))
+            })
+            .collect();
+        dpns_names.sort();
+
+        // Dashpay profile, there is at most one per identity
+        let profile_query = DocumentQuery::new(self.dashpay_contract.clone(), "profile")
+            .map_err(|e| e.to_string())?
+            .with_where(WhereClause {
+                field: "$ownerId".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(identity_id.to_buffer()),
+            });
+        let profiles = Document::fetch_many(sdk, profile_query)
+            .await
+            .map_err(|e| format!("Error fetching Dashpay profile: {}", e))?;
+        let dashpay_profile = profiles.values().flatten().next().map(|document| {
+            let text = |field: &str| {
+                document
+                    .get(field)
+                    .and_then(|value| value.as_text())
+                    .map(|text| text.to_string())
+            };
+            DashpayProfile {
+                display_name: text("displayName"),
+                public_message: text("publicMessage"),
+                avatar_url: text("avatarUrl"),
+            }
+        });
+
+        Ok(IdentityDetails {
+            identity_id,
+            identity,
+            dpns_names,
+            dashpay_profile,
+        })
+    }
+}
@@ -0,0 +1,59 @@
+use crate::context::AppContext;
+use crate::model::identity_details::{ContractNonce, IdentityNonces};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::platform::{Fetch, Identifier};
+use dash_sdk::query_types::{IdentityContractNonceFetcher, IdentityNonceFetcher};
+use dash_sdk::Sdk;
+
+impl AppContext {
+    /// Queries the identity nonce and the nonces for every known contract directly from
+    /// Platform, bypassing the nonce cache of the SDK
+    pub(super) async fn fetch_identity_nonces(
+        &self,
+        sdk: &Sdk,
+        identity_id: Identifier,
+    ) -> Result<IdentityNonces, String> {
+        let identity_nonce = IdentityNonceFetcher::fetch(sdk, identity_id)
+            .await
+            .map_err(|e| format!("Error fetching identity nonce: {}", e))?
+            .map(|fetcher| fetcher.0);
+
+        // This is the value the next transition would use, which may be ahead of Platform
+        // if earlier transitions never made it into a block
+        let sdk_cached_nonce = match sdk.get_identity_nonce(identity_id, false, None).await {
+            Ok(nonce) => Some(nonce),
+            Err(e) => {
+                tracing::warn!("Failed to get cached identity nonce: {}", e);
+                None
+            }
+        };
+
+        let contracts = self
+            .get_registered_contracts()
+            .map_err(|e| format!("Database error: {}", e))?;
+        let mut contract_nonces = Vec::new();
+        for registered_contract in contracts {
+            let contract = &registered_contract.qualified_contract;
+            let contract_id = contract.contract.id();
+            let nonce = IdentityContractNonceFetcher::fetch(sdk, (identity_id, contract_id))
+                .await
+                .map_err(|e| format!("Error fetching contract nonce: {}", e))?
+                .map(|fetcher| fetcher.0);
+            // Contracts the identity never used have no nonce
+            if let Some(nonce) = nonce.filter(|nonce| *nonce > 0) {
+                contract_nonces.push(ContractNonce {
+                    contract_id,
+                    contract_alias: contract.alias.clone(),
+                    nonce,
+                });
+            }
+        }
+
+        Ok(IdentityNonces {
+            identity_id,
+            identity_nonce,
+            sdk_cached_nonce,
+            contract_nonces,
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use crate::context::AppContext;
+use crate::model::masternode_import::ImportedMasternode;
+use crate::platform::identity::IdentityInputToLoad;
+use dash_sdk::dashcore_rpc::dashcore::Address;
+use dash_sdk::dashcore_rpc::RpcApi;
+use dash_sdk::Sdk;
+use std::str::FromStr;
+
+impl AppContext {
+    /// Loads every masternode as a voting identity and reports how many succeeded. Voting keys
+    /// that were not in the imported file are looked up in the Core wallet by voting address.
+    pub(super) async fn import_masternodes(
+        &self,
+        sdk: &Sdk,
+        masternodes: Vec<ImportedMasternode>,
+    ) -> Result<String, String> {
+        let total = masternodes.len();
+        let mut failures = Vec::new();
+
+        for masternode in masternodes {
+            let name = if masternode.alias.is_empty() {
+                masternode.pro_tx_hash.clone()
+            } else {
+                masternode.alias.clone()
+            };
+            let voting_private_key = if masternode.voting_private_key.trim().is_empty() {
+                match self.voting_key_from_core_wallet(masternode.voting_address.as_deref()) {
+                    Ok(key) => key,
+                    Err(e) => {
+                        failures.push(format!("{}: {}", name, e));
+                        continue;
+                    }
+                }
+            } else {
+                masternode.voting_private_key
+            };
+
+            let input = IdentityInputToLoad {
+                identity_id_input: masternode.pro_tx_hash,
+                identity_type: masternode.identity_type,
+                alias_input: masternode.alias,
+                voting_private_key_input: voting_private_key,
+                owner_private_key_input: String::new(),
+                payout_address_private_key_input: String::new(),
+                keys_input: vec![],
+            };
+            if let Err(e) = self.load_identity(sdk, input).await {
+                failures.push(format!("{}: {}", name, e));
+            }
+        }
+
+        let imported = total - failures.len();
+        if imported == 0 {
+            return Err(format!(
+                "No masternodes were imported. {}",
+                failures.join("; ")
+            ));
+        }
+        if failures.is_empty() {
+            Ok(format!("Imported {} voting identities", imported))
+        } else {
+            Ok(format!(
+                "Imported {} of {} voting identities. Failed: {}",
+                imported,
+                total,
+                failures.join("; ")
+            ))
+        }
+    }
+
+    fn voting_key_from_core_wallet(&self, voting_address: Option<&str>) -> Result<String, String> {
+        let voting_address =
+            voting_address.ok_or("no voting key or voting address found".to_string())?;
+        let address = Address::from_str(voting_address)
+            .map_err(|e| format!("invalid voting address {}: {}", voting_address, e))?
+            .require_network(self.network)
+            .map_err(|e| format!("voting address is for another network: {}", e))?;
+        self.core_client
+            .dump_private_key(&address)
+            .map(|private_key| private_key.to_wif())
+            .map_err(|e| format!("voting key not found in the Core wallet: {}", e))
+    }
+}
@@ -1,24 +1,44 @@
 mod add_key_to_identity;
+mod auto_top_up_identity;
+mod broadcast_raw_transition;
+mod cold_signing;
+mod external_asset_lock;
+mod fetch_identity_details;
+mod fetch_identity_nonces;
+mod import_masternodes;
 mod load_identity;
+mod refresh_identities;
 mod register_dpns_name;
 mod register_identity;
+mod top_up_identity;
+mod transfer_credits;
+mod update_identity_keys;
+mod verify_ownership_proof;
 mod withdraw_from_identity;
 
 use crate::context::AppContext;
+use crate::model::cold_signing::ColdSigningFile;
+use crate::model::credit_operation::{CreditOperation, CreditOperationKind};
+use crate::model::fee::{FeeChoice, FeeSpeed};
+use crate::model::masternode_import::ImportedMasternode;
+use crate::model::ownership_proof::OwnershipProof;
 use crate::model::qualified_identity::{
     EncryptedPrivateKeyTarget, IdentityType, QualifiedIdentity,
 };
+use crate::model::rate_limit::Endpoint;
 use crate::model::wallet::Wallet;
+use crate::platform::BackendTaskSuccessResult;
 use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
-use dash_sdk::dashcore_rpc::dashcore::{Address, PrivateKey};
+use dash_sdk::dashcore_rpc::dashcore::{Address, OutPoint, PrivateKey};
 use dash_sdk::dpp::balances::credits::Duffs;
 use dash_sdk::dpp::fee::Credits;
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
 use dash_sdk::dpp::identity::identity_public_key::v0::IdentityPublicKeyV0;
 use dash_sdk::dpp::identity::{KeyID, KeyType, Purpose, SecurityLevel};
+use dash_sdk::dpp::state_transition::StateTransition;
 use dash_sdk::dpp::ProtocolError;
-use dash_sdk::platform::{Identity, IdentityPublicKey};
+use dash_sdk::platform::{Fetch, Identifier, Identity, IdentityPublicKey};
 use dash_sdk::Sdk;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::{Arc, RwLock};
@@ -141,6 +161,8 @@ pub struct IdentityRegistrationInfo {
     pub keys: IdentityKeys,
     pub identity_index: u32,
     pub wallet: Arc<RwLock<Wallet>>,
+    /// Fee speed and coin selection of the asset lock funding the registration
+    pub fee: FeeChoice,
 }
 
 impl PartialEq for IdentityRegistrationInfo {
@@ -149,6 +171,26 @@ impl PartialEq for IdentityRegistrationInfo {
             && self.amount == other.amount
             && self.keys == other.keys
             && self.identity_index == other.identity_index
+            && self.fee == other.fee
+    }
+}
+
+/// Credits an identity with an asset lock funded from one of our wallets
+#[derive(Debug, Clone)]
+pub struct IdentityTopUpInfo {
+    pub qualified_identity: QualifiedIdentity,
+    pub amount: Duffs,
+    pub wallet: Arc<RwLock<Wallet>>,
+    /// Fee speed and coin selection of the asset lock
+    pub fee: FeeChoice,
+}
+
+impl PartialEq for IdentityTopUpInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.qualified_identity == other.qualified_identity
+            && self.amount == other.amount
+            && Arc::ptr_eq(&self.wallet, &other.wallet)
+            && self.fee == other.fee
     }
 }
 
@@ -161,10 +203,60 @@ pub struct RegisterDpnsNameInput {
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum IdentityTask {
     LoadIdentity(IdentityInputToLoad),
+    /// Loads masternodes found in a dashmate config, `protx info` output or dash.conf as
+    /// voting identities
+    ImportMasternodes(Vec<ImportedMasternode>),
+    /// Loads many identities at once, reporting the outcome for each one
+    LoadIdentities(Vec<IdentityInputToLoad>),
+    /// Re-fetches local identities to update their balances and keys
+    RefreshIdentities(Vec<QualifiedIdentity>),
     RegisterIdentity(IdentityRegistrationInfo),
+    TopUpIdentity(IdentityTopUpInfo),
+    /// A top up started by an automatic top up rule, written to the audit trail
+    AutoTopUpIdentity(IdentityTopUpInfo),
     AddKeyToIdentity(QualifiedIdentity, IdentityPublicKey, [u8; 32]),
-    WithdrawFromIdentity(QualifiedIdentity, Option<Address>, Credits, Option<KeyID>),
+    /// Withdraws to the address, or the payout address, at the fee speed
+    WithdrawFromIdentity(
+        QualifiedIdentity,
+        Option<Address>,
+        Credits,
+        Option<KeyID>,
+        FeeSpeed,
+    ),
     RegisterDpnsName(RegisterDpnsNameInput),
+    FetchIdentityDetails(Identifier),
+    /// Re-queries the identity nonce and contract nonces from Platform
+    FetchIdentityNonces(Identifier),
+    /// Adds the keys, with their private keys, and disables the key ids in one identity update
+    UpdateIdentityKeys(
+        QualifiedIdentity,
+        Vec<(IdentityPublicKey, [u8; 32])>,
+        Vec<KeyID>,
+    ),
+    /// Builds an unsigned withdrawal to be signed on an offline machine with the key
+    PrepareColdWithdrawal(QualifiedIdentity, Option<Address>, Credits, KeyID),
+    /// Broadcasts a transition signed on an offline machine
+    BroadcastColdSigned(ColdSigningFile),
+    /// Builds the asset lock of a registration spending the outputs of an external wallet, as
+    /// an unsigned PSBT sending the change to the address
+    PrepareExternalAssetLock(IdentityRegistrationInfo, Vec<OutPoint>, Address),
+    /// Registers the identity with the asset lock of the exported PSBT, signed by the external
+    /// wallet
+    RegisterIdentityWithSignedAssetLock(IdentityRegistrationInfo, String, String),
+    /// Checks a proof of ownership signed by someone else against their identity's key
+    VerifyOwnershipProof(OwnershipProof),
+    /// Broadcasts a transition built and signed in the transition sandbox
+    BroadcastRawTransition(StateTransition),
+    /// Sends credits to the identity, with the key if given, and the memo encrypted for it
+    TransferCredits(
+        QualifiedIdentity,
+        Identifier,
+        Credits,
+        Option<KeyID>,
+        Option<String>,
+    ),
+    /// Fetches and decrypts the memos sent to the identity with transfers
+    FetchTransferMemos(QualifiedIdentity),
 }
 
 fn verify_key_input(
@@ -199,6 +291,65 @@ fn verify_key_input(
 }
 
 impl AppContext {
+    /// Remembers which key signed a transition, for the key usage analyzer
+    pub(crate) fn record_key_usage(
+        &self,
+        identity_id: &Identifier,
+        key_id: KeyID,
+        transition: &str,
+    ) {
+        if let Err(e) = self
+            .db
+            .record_key_usage(identity_id, key_id, transition, self)
+        {
+            tracing::warn!("Failed to record key usage: {}", e);
+        }
+    }
+
+    /// Remembers a change of an identity's credits, for the accounting export
+    pub(crate) fn record_credit_operation(
+        &self,
+        identity_id: Identifier,
+        kind: CreditOperationKind,
+        credits: Credits,
+        fee: Credits,
+        description: String,
+    ) {
+        let operation = CreditOperation {
+            identity_id,
+            kind,
+            credits,
+            fee,
+            description,
+            occurred_at: chrono::Utc::now().timestamp(),
+        };
+        if let Err(e) = self.db.insert_credit_operation(&operation, self) {
+            tracing::warn!("Failed to record the credit operation: {}", e);
+        }
+    }
+
+    /// Records the fee of a transition that moved no credits, as the drop of the balance
+    /// fetched after it
+    pub(crate) async fn record_transition_fee(
+        &self,
+        sdk: &Sdk,
+        identity_id: Identifier,
+        balance_before: Credits,
+        description: &str,
+    ) {
+        match Identity::fetch_by_identifier(sdk, identity_id).await {
+            Ok(Some(identity)) => self.record_credit_operation(
+                identity_id,
+                CreditOperationKind::Fee,
+                0,
+                balance_before.saturating_sub(identity.balance()),
+                description.to_string(),
+            ),
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Failed to fetch the balance for the fee record: {}", e),
+        }
+    }
+
     fn verify_voting_key_exists_on_identity(
         &self,
         voting_identity: &Identity,
@@ -343,21 +494,136 @@ impl AppContext {
         Ok(key)
     }
 
-    pub async fn run_identity_task(&self, task: IdentityTask, sdk: &Sdk) -> Result<(), String> {
+    pub async fn run_identity_task(
+        &self,
+        task: IdentityTask,
+        sdk: &Sdk,
+    ) -> Result<BackendTaskSuccessResult, String> {
         match task {
-            IdentityTask::LoadIdentity(input) => self.load_identity(sdk, input).await,
-            IdentityTask::WithdrawFromIdentity(qualified_identity, to_address, credits, id) => {
-                self.withdraw_from_identity(qualified_identity, to_address, credits, id)
-                    .await
+            IdentityTask::LoadIdentity(input) => self
+                .load_identity(sdk, input)
+                .await
+                .map(|_| BackendTaskSuccessResult::None),
+            IdentityTask::LoadIdentities(inputs) => {
+                let mut results = Vec::with_capacity(inputs.len());
+                for (index, input) in inputs.into_iter().enumerate() {
+                    // The dispatcher took the slot of the first request
+                    if index > 0 {
+                        self.throttle(Endpoint::Dapi).await;
+                    }
+                    let identity_id = input.identity_id_input.clone();
+                    results.push((identity_id, self.load_identity(sdk, input).await));
+                }
+                Ok(BackendTaskSuccessResult::LoadedIdentities(results))
             }
+            IdentityTask::RefreshIdentities(qualified_identities) => self
+                .refresh_identities(sdk, qualified_identities)
+                .await
+                .map(BackendTaskSuccessResult::Message),
+            IdentityTask::ImportMasternodes(masternodes) => self
+                .import_masternodes(sdk, masternodes)
+                .await
+                .map(BackendTaskSuccessResult::Message),
+            IdentityTask::WithdrawFromIdentity(
+                qualified_identity,
+                to_address,
+                credits,
+                id,
+                speed,
+            ) => self
+                .withdraw_from_identity(qualified_identity, to_address, credits, id, speed)
+                .await
+                .map(|_| BackendTaskSuccessResult::None),
+            IdentityTask::TransferCredits(
+                qualified_identity,
+                recipient_id,
+                credits,
+                key_id,
+                memo,
+            ) => self
+                .transfer_credits(sdk, qualified_identity, recipient_id, credits, key_id, memo)
+                .await
+                .map(BackendTaskSuccessResult::Message),
+            IdentityTask::FetchTransferMemos(qualified_identity) => self
+                .fetch_transfer_memos(sdk, qualified_identity)
+                .await
+                .map(BackendTaskSuccessResult::TransferMemos),
             IdentityTask::AddKeyToIdentity(qualified_identity, public_key_to_add, private_key) => {
                 self.add_key_to_identity(sdk, qualified_identity, public_key_to_add, private_key)
                     .await
+                    .map(|_| BackendTaskSuccessResult::None)
             }
-            IdentityTask::RegisterIdentity(registration_info) => {
-                self.register_identity(registration_info).await
+            IdentityTask::RegisterIdentity(registration_info) => self
+                .register_identity(registration_info)
+                .await
+                .map(|_| BackendTaskSuccessResult::None),
+            IdentityTask::TopUpIdentity(top_up_info) => self
+                .top_up_identity(top_up_info)
+                .await
+                .map(BackendTaskSuccessResult::Message),
+            IdentityTask::AutoTopUpIdentity(top_up_info) => self
+                .auto_top_up_identity(top_up_info)
+                .await
+                .map(BackendTaskSuccessResult::Message),
+            IdentityTask::RegisterDpnsName(input) => self
+                .register_dpns_name(sdk, input)
+                .await
+                .map(|_| BackendTaskSuccessResult::None),
+            IdentityTask::FetchIdentityDetails(identity_id) => self
+                .fetch_identity_details(sdk, identity_id)
+                .await
+                .map(BackendTaskSuccessResult::IdentityDetails),
+            IdentityTask::FetchIdentityNonces(identity_id) => self
+                .fetch_identity_nonces(sdk, identity_id)
+                .await
+                .map(BackendTaskSuccessResult::IdentityNonces),
+            IdentityTask::UpdateIdentityKeys(qualified_identity, keys_to_add, keys_to_disable) => {
+                self.update_identity_keys(sdk, qualified_identity, keys_to_add, keys_to_disable)
+                    .await
+                    .map(BackendTaskSuccessResult::UpdatedIdentityKeys)
             }
-            IdentityTask::RegisterDpnsName(input) => self.register_dpns_name(sdk, input).await,
+            IdentityTask::PrepareColdWithdrawal(
+                qualified_identity,
+                to_address,
+                credits,
+                key_id,
+            ) => self
+                .prepare_cold_withdrawal(sdk, qualified_identity, to_address, credits, key_id)
+                .await
+                .map(BackendTaskSuccessResult::ColdSigningFile),
+            IdentityTask::BroadcastColdSigned(file) => self
+                .broadcast_cold_signed(sdk, file)
+                .await
+                .map(BackendTaskSuccessResult::Message),
+            IdentityTask::PrepareExternalAssetLock(
+                registration_info,
+                outpoints,
+                change_address,
+            ) => self
+                .prepare_external_asset_lock(sdk, registration_info, outpoints, change_address)
+                .await
+                .map(BackendTaskSuccessResult::UnsignedAssetLock),
+            IdentityTask::RegisterIdentityWithSignedAssetLock(
+                registration_info,
+                unsigned_psbt,
+                signed,
+            ) => self
+                .register_identity_with_signed_asset_lock(
+                    sdk,
+                    registration_info,
+                    unsigned_psbt,
+                    signed,
+                )
+                .await
+                .map(|_| BackendTaskSuccessResult::None),
+            IdentityTask::VerifyOwnershipProof(proof) => self
+                .verify_ownership_proof(sdk, proof)
+                .await
+                .map(BackendTaskSuccessResult::VerifiedOwnershipProof),
+            IdentityTask::BroadcastRawTransition(transition) => self
+                .broadcast_raw_transition(sdk, transition)
+                .await
+                .map(BackendTaskSuccessResult::Message),
         }
     }
 }
@@ -0,0 +1,64 @@
+use crate::context::AppContext;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::rate_limit::Endpoint;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::platform::{Fetch, Identity};
+use dash_sdk::Sdk;
+
+impl AppContext {
+    /// Fetches the latest state, and with it the balance, of each identity and stores it
+    pub(super) async fn refresh_identities(
+        &self,
+        sdk: &Sdk,
+        qualified_identities: Vec<QualifiedIdentity>,
+    ) -> Result<String, String> {
+        let total = qualified_identities.len();
+        let mut failures = Vec::new();
+
+        for (index, mut qualified_identity) in qualified_identities.into_iter().enumerate() {
+            // The dispatcher took the slot of the first request
+            if index > 0 {
+                self.throttle(Endpoint::Dapi).await;
+            }
+            let name = qualified_identity.display_short_string();
+            match Identity::fetch_by_identifier(sdk, qualified_identity.identity.id()).await {
+                Ok(Some(identity)) => qualified_identity.identity = identity,
+                Ok(None) => {
+                    failures.push(format!("{}: not found", name));
+                    continue;
+                }
+                Err(e) => {
+                    failures.push(format!("{}: {}", name, e));
+                    continue;
+                }
+            }
+            if let Some((voter_identity, _)) = qualified_identity.associated_voter_identity.as_mut()
+            {
+                if let Ok(Some(identity)) =
+                    Identity::fetch_by_identifier(sdk, voter_identity.id()).await
+                {
+                    *voter_identity = identity;
+                }
+            }
+            if let Err(e) = self.insert_local_qualified_identity(&qualified_identity) {
+                failures.push(format!("{}: database error: {}", name, e));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(format!("Refreshed {} identities", total))
+        } else if failures.len() == total {
+            Err(format!(
+                "Failed to refresh identities: {}",
+                failures.join("; ")
+            ))
+        } else {
+            Ok(format!(
+                "Refreshed {} of {} identities. Failed: {}",
+                total - failures.len(),
+                total,
+                failures.join("; ")
+            ))
+        }
+    }
+}
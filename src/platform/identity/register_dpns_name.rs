@@ -1,17 +1,21 @@
 use std::collections::BTreeMap;
 
 use crate::context::AppContext;
+use crate::model::key_usage::TRANSITION_DPNS_REGISTRATION;
 use dash_sdk::{
     dpp::{
         data_contract::{
             accessors::v0::DataContractV0Getters, document_type::accessors::DocumentTypeV0Getters,
         },
         document::DocumentV0,
-        identity::accessors::IdentityGettersV0,
+        identity::{
+            accessors::IdentityGettersV0,
+            identity_public_key::accessors::v0::IdentityPublicKeyGettersV0,
+        },
         platform_value::Bytes32,
         util::{hash::hash_double, strings::convert_to_homograph_safe_chars},
     },
-    platform::{transition::put_document::PutDocument, Document},
+    platform::{transition::put_document::PutDocument, Document, Fetch, Identity},
     Sdk,
 };
 use rand::{rngs::StdRng, Rng, SeedableRng};
@@ -27,6 +31,14 @@ impl AppContext {
         let dpns_contract = self.dpns_contract.clone();
 
         let qualified_identity = input.qualified_identity;
+        // Fresh balance to tell the fees of the two documents apart from earlier changes
+        let balance_before = Identity::fetch_by_identifier(sdk, qualified_identity.identity.id())
+            .await
+            .ok()
+            .flatten()
+            .map_or(qualified_identity.identity.balance(), |identity| {
+                identity.balance()
+            });
 
         let entropy = Bytes32::random_with_rng(&mut rng);
         let preorder_document_type = dpns_contract
@@ -134,7 +146,7 @@ impl AppContext {
                 &qualified_identity,
             )
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| self.rejection_message("DPNS registration", "Registration error", &e))?;
 
         let _ = domain_document
             .put_to_platform_and_wait_for_response(
@@ -146,7 +158,19 @@ impl AppContext {
                 &qualified_identity,
             )
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| self.rejection_message("DPNS registration", "Registration error", &e))?;
+        self.record_key_usage(
+            &qualified_identity.identity.id(),
+            public_key.id(),
+            TRANSITION_DPNS_REGISTRATION,
+        );
+        self.record_transition_fee(
+            sdk,
+            qualified_identity.identity.id(),
+            balance_before,
+            &format!("DPNS name {}.dash", input.name_input),
+        )
+        .await;
 
         Ok(())
     }
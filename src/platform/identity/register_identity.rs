@@ -1,6 +1,8 @@
 use crate::context::AppContext;
+use crate::model::credit_operation::CreditOperationKind;
+use crate::model::finality::L1State;
 use crate::model::qualified_identity::{IdentityType, QualifiedIdentity};
-use crate::platform::identity::IdentityRegistrationInfo;
+use crate::platform::identity::{IdentityKeys, IdentityRegistrationInfo};
 use dash_sdk::dapi_client::DapiRequestExecutor;
 use dash_sdk::dapi_grpc::core::v0::{
     BroadcastTransactionRequest, GetBlockchainStatusRequest, GetTransactionRequest,
@@ -8,8 +10,10 @@ use dash_sdk::dapi_grpc::core::v0::{
 };
 use dash_sdk::dashcore_rpc::dashcore::PrivateKey;
 use dash_sdk::dashcore_rpc::RpcApi;
+use dash_sdk::dpp::balances::credits::Duffs;
 use dash_sdk::dpp::dashcore::psbt::serialize::Serialize;
 use dash_sdk::dpp::dashcore::{Address, Transaction};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::prelude::AssetLockProof;
 use dash_sdk::platform::transition::put_identity::PutIdentity;
 use dash_sdk::platform::Identity;
@@ -19,6 +23,12 @@ use std::collections::BTreeMap;
 use std::time::Duration;
 use tokio::sync::MutexGuard;
 
+/// How often Core is asked whether an asset lock is final by the settings
+const FINALITY_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Failed Core requests in a row after which waiting for an asset lock to be final gives up
+const MAX_FINALITY_CORE_FAILURES: u32 = 10;
+
 impl AppContext {
     pub(crate) async fn broadcast_and_retrieve_asset_lock(
         &self,
@@ -34,7 +44,7 @@ impl AppContext {
         //     tracing::debug!("Starting asset lock broadcast.");
         // });
 
-        let sdk = &self.sdk;
+        let sdk = &self.sdk();
 
         let block_hash = sdk
             .execute(GetBlockchainStatusRequest {}, RequestSettings::default())
@@ -95,12 +105,58 @@ impl AppContext {
 
         // tracing::debug!("Waiting for asset lock proof.");
 
-        sdk.wait_for_asset_lock_proof_for_transaction(
-            asset_lock_stream,
-            asset_lock_transaction,
-            Some(Duration::from_secs(4 * 60)),
-        )
-        .await
+        let asset_lock_proof = sdk
+            .wait_for_asset_lock_proof_for_transaction(
+                asset_lock_stream,
+                asset_lock_transaction,
+                Some(Duration::from_secs(4 * 60)),
+            )
+            .await?;
+        self.wait_for_asset_lock_finality(asset_lock_transaction, &asset_lock_proof)
+            .await?;
+        Ok(asset_lock_proof)
+    }
+
+    /// Waits until the asset lock is final by the policy in the settings, by default its
+    /// InstantSend lock is enough and this returns right away
+    async fn wait_for_asset_lock_finality(
+        &self,
+        asset_lock_transaction: &Transaction,
+        asset_lock_proof: &AssetLockProof,
+    ) -> Result<(), dash_sdk::Error> {
+        let policy = self.preferences().finality.asset_locks;
+        let txid = asset_lock_transaction.txid();
+        let mut state = L1State {
+            confirmations: 0,
+            instant_locked: matches!(asset_lock_proof, AssetLockProof::Instant(_)),
+            chain_locked: matches!(asset_lock_proof, AssetLockProof::Chain(_)),
+        };
+        let mut core_failures = 0;
+        while !policy.is_final(&state) {
+            tracing::debug!("Waiting for {} on asset lock {}", policy.describe(), txid);
+            tokio::time::sleep(FINALITY_POLL_INTERVAL).await;
+            match self.core_client.get_raw_transaction_info(&txid, None) {
+                Ok(info) => {
+                    core_failures = 0;
+                    state.confirmations = info.confirmations.unwrap_or_default();
+                    state.chain_locked = info.chainlock;
+                }
+                Err(e) if core_failures < MAX_FINALITY_CORE_FAILURES => {
+                    core_failures += 1;
+                    tracing::debug!("Could not get asset lock {} from Core: {}", txid, e);
+                }
+                Err(e) => {
+                    return Err(dash_sdk::Error::Generic(format!(
+                        "Could not follow the confirmations of asset lock {} on Core, it needs \
+                         {} by the settings: {}",
+                        txid,
+                        policy.describe(),
+                        e
+                    )))
+                }
+            }
+        }
+        Ok(())
     }
 
     pub(super) async fn register_identity(
@@ -113,14 +169,22 @@ impl AppContext {
             keys,
             identity_index,
             wallet,
+            fee,
         } = input;
 
-        let sdk = self.sdk.clone();
+        let sdk = self.sdk();
+        let fee = self.funding_fee(fee);
 
         // Scope the write lock to avoid holding it across an await.
         let (asset_lock_transaction, asset_lock_proof_private_key, change_address) = {
             let mut wallet = wallet.write().unwrap();
-            match wallet.asset_lock_transaction(sdk.network, amount, identity_index, Some(self)) {
+            match wallet.asset_lock_transaction(
+                sdk.network,
+                amount,
+                identity_index,
+                fee,
+                Some(self),
+            ) {
                 Ok(transaction) => transaction,
                 Err(_) => {
                     wallet
@@ -130,14 +194,38 @@ impl AppContext {
                         sdk.network,
                         amount,
                         identity_index,
+                        fee,
                         Some(self),
                     )?
                 }
             }
         };
 
+        self.register_identity_with_asset_lock(
+            &sdk,
+            alias_input,
+            amount,
+            keys,
+            &asset_lock_transaction,
+            &asset_lock_proof_private_key,
+            &change_address,
+        )
+        .await
+    }
+
+    /// Broadcasts the signed asset lock and registers an identity with the keys funded by it
+    pub(super) async fn register_identity_with_asset_lock(
+        &self,
+        sdk: &Sdk,
+        alias_input: String,
+        amount: Duffs,
+        keys: IdentityKeys,
+        asset_lock_transaction: &Transaction,
+        asset_lock_proof_private_key: &PrivateKey,
+        address: &Address,
+    ) -> Result<(), String> {
         let asset_lock_proof = self
-            .broadcast_and_retrieve_asset_lock(&asset_lock_transaction, &change_address)
+            .broadcast_and_retrieve_asset_lock(asset_lock_transaction, address)
             .await
             .map_err(|e| e.to_string())?;
 
@@ -155,21 +243,35 @@ impl AppContext {
             associated_operator_identity: None,
             associated_owner_key_id: None,
             identity_type: IdentityType::User,
-            alias: None,
+            alias: if alias_input.is_empty() {
+                None
+            } else {
+                Some(alias_input)
+            },
             encrypted_private_keys: keys.to_encrypted_private_keys(),
         };
 
         let updated_identity = identity
             .put_to_platform_and_wait_for_response(
-                &sdk,
+                sdk,
                 asset_lock_proof.clone(),
-                &asset_lock_proof_private_key,
+                asset_lock_proof_private_key,
                 &qualified_identity,
             )
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| {
+                self.rejection_message("identity registration", "Registration error", &e)
+            })?;
 
         qualified_identity.identity = updated_identity;
+        let balance = qualified_identity.identity.balance();
+        self.record_credit_operation(
+            identity_id,
+            CreditOperationKind::Registration,
+            balance,
+            (amount * 1000).saturating_sub(balance),
+            format!("Registration with {:.8} DASH", amount as f64 / 1e8),
+        );
 
         self.insert_local_qualified_identity(&qualified_identity)
             .map_err(|e| e.to_string())?;
@@ -0,0 +1,74 @@
+use crate::context::AppContext;
+use crate::model::credit_operation::CreditOperationKind;
+use crate::platform::identity::IdentityTopUpInfo;
+use dash_sdk::dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
+use dash_sdk::platform::transition::top_up_identity::TopUpIdentity;
+
+impl AppContext {
+    /// Funds an asset lock from the wallet and credits it to the identity, returning a message
+    /// with the new balance
+    pub(super) async fn top_up_identity(&self, input: IdentityTopUpInfo) -> Result<String, String> {
+        let IdentityTopUpInfo {
+            mut qualified_identity,
+            amount,
+            wallet,
+            fee,
+        } = input;
+
+        let network = self.sdk().network;
+        let fee = self.funding_fee(fee);
+
+        // Scope the write lock to avoid holding it across an await.
+        let (asset_lock_transaction, asset_lock_proof_private_key, change_address) = {
+            let mut wallet = wallet.write().unwrap();
+            match wallet.top_up_asset_lock_transaction(network, amount, fee, self) {
+                Ok(transaction) => transaction,
+                Err(_) => {
+                    wallet
+                        .reload_utxos(&self.core_client)
+                        .map_err(|e| e.to_string())?;
+                    wallet.top_up_asset_lock_transaction(network, amount, fee, self)?
+                }
+            }
+        };
+
+        let asset_lock_proof = self
+            .broadcast_and_retrieve_asset_lock(&asset_lock_transaction, &change_address)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let balance_before = qualified_identity.identity.balance();
+        let new_balance = qualified_identity
+            .identity
+            .top_up_identity(
+                &self.sdk(),
+                asset_lock_proof,
+                &asset_lock_proof_private_key,
+                None,
+                None,
+            )
+            .await
+            .map_err(|e| self.rejection_message("top up", "Top up error", &e))?;
+
+        // The asset lock pays the processing fee, the rest arrives as credits
+        let credited = new_balance.saturating_sub(balance_before);
+        self.record_credit_operation(
+            qualified_identity.identity.id(),
+            CreditOperationKind::TopUp,
+            credited,
+            (amount * 1000).saturating_sub(credited),
+            format!("Top up with {:.8} DASH", amount as f64 / 1e8),
+        );
+
+        qualified_identity.identity.set_balance(new_balance);
+        self.insert_local_qualified_identity(&qualified_identity)
+            .map_err(|e| format!("Database error: {}", e))?;
+
+        Ok(format!(
+            "Topped up {} with {:.8} DASH, the balance is now {} credits",
+            qualified_identity.display_short_string(),
+            amount as f64 / 1e8,
+            new_balance
+        ))
+    }
+}
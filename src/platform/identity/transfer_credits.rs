@@ -0,0 +1,375 @@
+use crate::context::AppContext;
+use crate::model::credit_operation::CreditOperationKind;
+use crate::model::key_usage::{TRANSITION_CREDIT_TRANSFER, TRANSITION_DOCUMENT_CREATE};
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::transfer_memo::{
+    account_reference, decrypt_memo, encrypt_for, encrypt_memo, is_memo_key, EncryptedMemo,
+    ReceivedMemo, CONTACT_REQUEST_DOCUMENT_TYPE, MEMO_QUERY_LIMIT,
+};
+use dash_sdk::dashcore_rpc::RpcApi;
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::document::{DocumentV0, DocumentV0Getters};
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::identity::{KeyID, Purpose};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::platform_value::{Bytes32, Value};
+use dash_sdk::drive::query::{OrderClause, WhereClause, WhereOperator};
+use dash_sdk::platform::transition::put_document::PutDocument;
+use dash_sdk::platform::transition::transfer::TransferToIdentity;
+use dash_sdk::platform::{Document, DocumentQuery, Fetch, FetchMany, Identifier, Identity};
+use dash_sdk::Sdk;
+use std::collections::BTreeMap;
+
+impl AppContext {
+    /// Sends credits to another identity, followed by the memo encrypted for the recipient
+    /// when there is one. A memo that can't be sent doesn't undo the transfer, the message
+    /// says so instead.
+    pub(super) async fn transfer_credits(
+        &self,
+        sdk: &Sdk,
+        mut qualified_identity: QualifiedIdentity,
+        recipient_id: Identifier,
+        credits: Credits,
+        key_id: Option<KeyID>,
+        memo: Option<String>,
+    ) -> Result<String, String> {
+        let sender_id = qualified_identity.identity.id();
+        if sender_id == recipient_id {
+            return Err("Credits can't be transferred to the identity sending them".to_string());
+        }
+        let recipient = Identity::fetch_by_identifier(sdk, recipient_id)
+            .await
+            .map_err(|e| format!("Error fetching the recipient: {}", e))?
+            .ok_or("The recipient identity doesn't exist on this network".to_string())?;
+        // Checked before the transfer, so a memo that can't be encrypted stops it
+        let memo = match memo.filter(|memo| !memo.trim().is_empty()) {
+            Some(memo) => Some(
+                self.encrypt_memo_for(sdk, &qualified_identity, &recipient, &memo)
+                    .await?,
+            ),
+            None => None,
+        };
+        let signing_key = key_id.and_then(|key_id| {
+            qualified_identity
+                .identity
+                .get_public_key_by_id(key_id)
+                .cloned()
+        });
+
+        let balance_before = qualified_identity.identity.balance();
+        qualified_identity
+            .identity
+            .clone()
+            .transfer_credits(
+                sdk,
+                recipient_id,
+                credits,
+                signing_key.as_ref(),
+                qualified_identity.clone(),
+                None,
+            )
+            .await
+            .map_err(|e| self.rejection_message("credit transfer", "Transfer error", &e))?;
+        if let Some(key) = &signing_key {
+            self.record_key_usage(&sender_id, key.id(), TRANSITION_CREDIT_TRANSFER);
+        }
+        if let Err(e) = self.db.record_credit_spending(&sender_id, credits, self) {
+            tracing::warn!("Failed to record spent credits: {}", e);
+        }
+        let recipient_label = recipient_id.to_string(Encoding::Base58);
+        if let Ok(Some(identity)) = Identity::fetch_by_identifier(sdk, sender_id).await {
+            self.record_credit_operation(
+                sender_id,
+                CreditOperationKind::Transfer,
+                credits,
+                balance_before
+                    .saturating_sub(credits)
+                    .saturating_sub(identity.balance()),
+                format!("Transfer to {}", recipient_label),
+            );
+            qualified_identity.identity.set_balance(identity.balance());
+            if let Err(e) = self.insert_local_qualified_identity(&qualified_identity) {
+                tracing::warn!("Failed to store the identity: {}", e);
+            }
+        }
+
+        let Some(memo) = memo else {
+            return Ok(format!(
+                "Transferred {} credits to {}",
+                credits, recipient_label
+            ));
+        };
+        match self
+            .send_memo(sdk, &qualified_identity, recipient_id, memo)
+            .await
+        {
+            Ok(()) => Ok(format!(
+                "Transferred {} credits to {} with an encrypted memo",
+                credits, recipient_label
+            )),
+            Err(e) => Ok(format!(
+                "Transferred {} credits to {}, but the memo couldn't be sent: {}",
+                credits, recipient_label, e
+            )),
+        }
+    }
+
+    /// Encrypts the memo with the sender's encryption key for the recipient's decryption key,
+    /// along with the extended public key of a new contact account of the first wallet
+    async fn encrypt_memo_for(
+        &self,
+        sdk: &Sdk,
+        sender: &QualifiedIdentity,
+        recipient: &Identity,
+        memo: &str,
+    ) -> Result<EncryptedMemo, String> {
+        let (sender_key, private_key) = sender.ecdsa_private_key(Purpose::ENCRYPTION).ok_or(
+            "Sending a memo needs an ECDSA encryption key with its private key loaded".to_string(),
+        )?;
+        let recipient_key = recipient
+            .public_keys()
+            .values()
+            .find(|key| is_memo_key(key, Purpose::DECRYPTION))
+            .ok_or(
+                "The recipient has no ECDSA decryption key to encrypt the memo for".to_string(),
+            )?;
+        let wallet = self.wallets.read().unwrap().first().cloned().ok_or(
+            "Sending a memo needs a wallet, the Dashpay contact request carries one of its accounts"
+                .to_string(),
+        )?;
+        // Contact requests are unique per account, so each memo to the recipient opens the next
+        let account = self
+            .sent_contact_requests(sdk, sender.identity.id(), recipient.id())
+            .await? as u32;
+        let extended_public_key = wallet.read().unwrap().contact_extended_public_key(
+            self.network,
+            account,
+            &sender.identity.id(),
+            &recipient.id(),
+        )?;
+        let recipient_public_key = recipient_key.data().as_slice();
+        Ok(EncryptedMemo {
+            sender_key_id: sender_key.id(),
+            recipient_key_id: recipient_key.id(),
+            account_reference: account_reference(private_key, &extended_public_key, account),
+            encrypted_public_key: encrypt_for(
+                private_key,
+                recipient_public_key,
+                &extended_public_key,
+            )?,
+            encrypted_label: encrypt_memo(private_key, recipient_public_key, memo)?,
+        })
+    }
+
+    /// How many Dashpay contact requests the sender already sent to the recipient
+    async fn sent_contact_requests(
+        &self,
+        sdk: &Sdk,
+        sender_id: Identifier,
+        recipient_id: Identifier,
+    ) -> Result<usize, String> {
+        let query =
+            DocumentQuery::new(self.dashpay_contract.clone(), CONTACT_REQUEST_DOCUMENT_TYPE)
+                .map_err(|e| e.to_string())?
+                .with_where(WhereClause {
+                    field: "$ownerId".to_string(),
+                    operator: WhereOperator::Equal,
+                    value: Value::Identifier(sender_id.to_buffer()),
+                })
+                .with_where(WhereClause {
+                    field: "toUserId".to_string(),
+                    operator: WhereOperator::Equal,
+                    value: Value::Identifier(recipient_id.to_buffer()),
+                });
+        let documents = Document::fetch_many(sdk, query)
+            .await
+            .map_err(|e| format!("Error fetching contact requests: {}", e))?;
+        Ok(documents.values().flatten().count())
+    }
+
+    /// Creates the Dashpay contact request carrying the memo, owned by the sender and addressed
+    /// to the recipient
+    async fn send_memo(
+        &self,
+        sdk: &Sdk,
+        sender: &QualifiedIdentity,
+        recipient_id: Identifier,
+        memo: EncryptedMemo,
+    ) -> Result<(), String> {
+        let contract = self.dashpay_contract.clone();
+        let document_type = contract
+            .document_type_for_name(CONTACT_REQUEST_DOCUMENT_TYPE)
+            .map_err(|_| {
+                format!(
+                    "The Dashpay contract has no {} documents",
+                    CONTACT_REQUEST_DOCUMENT_TYPE
+                )
+            })?;
+        let public_key = sender
+            .document_signing_key(&document_type)
+            .ok_or(
+                "Identity doesn't have an authentication key for signing document transitions"
+                    .to_string(),
+            )?
+            .clone();
+        let mut properties = BTreeMap::from([
+            (
+                "toUserId".to_string(),
+                Value::Identifier(recipient_id.to_buffer()),
+            ),
+            ("senderKeyIndex".to_string(), Value::U32(memo.sender_key_id)),
+            (
+                "recipientKeyIndex".to_string(),
+                Value::U32(memo.recipient_key_id),
+            ),
+            (
+                "accountReference".to_string(),
+                Value::U32(memo.account_reference),
+            ),
+            (
+                "encryptedPublicKey".to_string(),
+                Value::Bytes(memo.encrypted_public_key),
+            ),
+            (
+                "encryptedAccountLabel".to_string(),
+                Value::Bytes(memo.encrypted_label),
+            ),
+        ]);
+        // Older versions of the contract date requests with the Core height themselves
+        if document_type
+            .properties()
+            .contains_key("coreHeightCreatedAt")
+        {
+            let core_height = self
+                .core_client
+                .get_block_count()
+                .map_err(|e| format!("Error getting the Core block height: {}", e))?;
+            properties.insert(
+                "coreHeightCreatedAt".to_string(),
+                Value::U32(core_height as u32),
+            );
+        }
+        let owner_id = sender.identity.id();
+        let entropy = Bytes32::random_with_rng(&mut rand::thread_rng());
+        let document = Document::V0(DocumentV0 {
+            id: Document::generate_document_id_v0(
+                &contract.id(),
+                &owner_id,
+                document_type.name().as_str(),
+                entropy.as_slice(),
+            ),
+            owner_id,
+            properties,
+            revision: None,
+            created_at: None,
+            updated_at: None,
+            transferred_at: None,
+            created_at_block_height: None,
+            updated_at_block_height: None,
+            transferred_at_block_height: None,
+            created_at_core_block_height: None,
+            updated_at_core_block_height: None,
+            transferred_at_core_block_height: None,
+        });
+        document
+            .put_to_platform_and_wait_for_response(
+                sdk,
+                document_type.to_owned_document_type(),
+                entropy.0,
+                public_key.clone(),
+                contract.clone(),
+                sender,
+            )
+            .await
+            .map_err(|e| self.rejection_message("memo", "Error creating the memo", &e))?;
+        self.record_key_usage(&owner_id, public_key.id(), TRANSITION_DOCUMENT_CREATE);
+        Ok(())
+    }
+
+    /// The memos sent to the identity with Dashpay contact requests, newest first, decrypted
+    /// with its decryption key. A memo whose keys aren't enabled ECDSA encryption and
+    /// decryption keys is rejected rather than decrypted.
+    pub(super) async fn fetch_transfer_memos(
+        &self,
+        sdk: &Sdk,
+        qualified_identity: QualifiedIdentity,
+    ) -> Result<Vec<ReceivedMemo>, String> {
+        let identity_id = qualified_identity.identity.id();
+        let mut query =
+            DocumentQuery::new(self.dashpay_contract.clone(), CONTACT_REQUEST_DOCUMENT_TYPE)
+                .map_err(|e| e.to_string())?
+                .with_where(WhereClause {
+                    field: "toUserId".to_string(),
+                    operator: WhereOperator::Equal,
+                    value: Value::Identifier(identity_id.to_buffer()),
+                })
+                .with_order_by(OrderClause {
+                    field: "$createdAt".to_string(),
+                    ascending: false,
+                });
+        query.limit = MEMO_QUERY_LIMIT as u32;
+        let documents = Document::fetch_many(sdk, query)
+            .await
+            .map_err(|e| format!("Error fetching memos: {}", e))?;
+
+        let decryption_key = qualified_identity
+            .encrypted_private_keys
+            .values()
+            .filter(|(public_key, _)| is_memo_key(public_key, Purpose::DECRYPTION))
+            .map(|(public_key, private_key)| (public_key.id(), *private_key))
+            .collect::<BTreeMap<KeyID, [u8; 32]>>();
+        let mut senders: BTreeMap<Identifier, Option<Identity>> = BTreeMap::new();
+        let mut memos = Vec::new();
+        for document in documents.values().flatten() {
+            // Contact requests without a label carry no memo
+            let Some(data) = document
+                .get("encryptedAccountLabel")
+                .and_then(|value| value.as_bytes())
+            else {
+                continue;
+            };
+            let sender_id = document.owner_id();
+            if !senders.contains_key(&sender_id) {
+                let sender = Identity::fetch_by_identifier(sdk, sender_id)
+                    .await
+                    .map_err(|e| format!("Error fetching the sender: {}", e))?;
+                senders.insert(sender_id, sender);
+            }
+            let integer = |field: &str| {
+                document
+                    .get(field)
+                    .and_then(|value| value.to_integer::<u64>().ok())
+            };
+            let memo = (|| {
+                let recipient_key_id = integer("recipientKeyIndex").unwrap_or_default() as KeyID;
+                let private_key = decryption_key.get(&recipient_key_id).ok_or(format!(
+                    "Encrypted for key {}, which isn't a decryption key whose private key is loaded",
+                    recipient_key_id
+                ))?;
+                let sender_key_id = integer("senderKeyIndex").unwrap_or_default() as KeyID;
+                let sender_key = senders
+                    .get(&sender_id)
+                    .and_then(Option::as_ref)
+                    .and_then(|sender| sender.get_public_key_by_id(sender_key_id))
+                    .ok_or(format!("The sender has no key {}", sender_key_id))?;
+                if !is_memo_key(sender_key, Purpose::ENCRYPTION) {
+                    return Err(format!(
+                        "Rejected, the sender's key {} isn't an enabled ECDSA encryption key",
+                        sender_key_id
+                    ));
+                }
+                decrypt_memo(private_key, sender_key.data().as_slice(), data)
+            })();
+            memos.push(ReceivedMemo {
+                document_id: document.id(),
+                sender_id,
+                created_at: document.created_at(),
+                memo,
+            });
+        }
+        Ok(memos)
+    }
+}
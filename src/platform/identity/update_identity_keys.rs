@@ -0,0 +1,96 @@
+use crate::context::AppContext;
+use crate::model::credit_operation::CreditOperationKind;
+use crate::model::key_usage::TRANSITION_IDENTITY_UPDATE;
+use crate::model::qualified_identity::EncryptedPrivateKeyTarget::PrivateKeyOnMainIdentity;
+use crate::model::qualified_identity::QualifiedIdentity;
+use dash_sdk::dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::{
+    IdentityPublicKeyGettersV0, IdentityPublicKeySettersV0,
+};
+use dash_sdk::dpp::identity::KeyID;
+use dash_sdk::dpp::prelude::UserFeeIncrease;
+use dash_sdk::dpp::state_transition::identity_update_transition::methods::IdentityUpdateTransitionMethodsV0;
+use dash_sdk::dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
+use dash_sdk::platform::transition::broadcast::BroadcastStateTransition;
+use dash_sdk::platform::{Fetch, Identity, IdentityPublicKey};
+use dash_sdk::Sdk;
+
+impl AppContext {
+    /// Adds and disables keys of an identity in one identity update signed with the master key.
+    /// Added keys get the next free ids. Returns the identity as fetched afterwards.
+    pub(super) async fn update_identity_keys(
+        &self,
+        sdk: &Sdk,
+        mut qualified_identity: QualifiedIdentity,
+        keys_to_add: Vec<(IdentityPublicKey, [u8; 32])>,
+        keys_to_disable: Vec<KeyID>,
+    ) -> Result<QualifiedIdentity, String> {
+        let identity_id = qualified_identity.identity.id();
+        let master_key_id = qualified_identity
+            .can_sign_with_master_key()
+            .map(|master_key| master_key.id())
+            .ok_or("The master key of this identity is not loaded".to_string())?;
+        if keys_to_disable.contains(&master_key_id) {
+            return Err("The key signing the update can not disable itself".to_string());
+        }
+        let new_identity_nonce = sdk
+            .get_identity_nonce(identity_id, true, None)
+            .await
+            .map_err(|e| format!("Fetch nonce error: {}", e))?;
+        qualified_identity.identity = Identity::fetch_by_identifier(sdk, identity_id)
+            .await
+            .map_err(|e| format!("Fetch identity error: {}", e))?
+            .ok_or("Identity not found on Platform".to_string())?;
+        let balance_before = qualified_identity.identity.balance();
+        qualified_identity.identity.bump_revision();
+
+        let mut next_key_id = qualified_identity.identity.get_public_key_max_id() + 1;
+        let mut public_keys_to_add = Vec::with_capacity(keys_to_add.len());
+        for (mut public_key, private_key) in keys_to_add {
+            public_key.set_id(next_key_id);
+            next_key_id += 1;
+            qualified_identity.encrypted_private_keys.insert(
+                (PrivateKeyOnMainIdentity, public_key.id()),
+                (public_key.clone(), private_key),
+            );
+            public_keys_to_add.push(public_key);
+        }
+
+        let state_transition = IdentityUpdateTransition::try_from_identity_with_signer(
+            &qualified_identity.identity,
+            &master_key_id,
+            public_keys_to_add,
+            keys_to_disable,
+            new_identity_nonce,
+            UserFeeIncrease::default(),
+            &qualified_identity,
+            sdk.version(),
+            None,
+        )
+        .map_err(|e| format!("IdentityUpdateTransition error: {}", e))?;
+
+        state_transition
+            .broadcast_and_wait(sdk, None)
+            .await
+            .map_err(|e| self.rejection_message("key update", "Broadcasting error", &e))?;
+        self.record_key_usage(&identity_id, master_key_id, TRANSITION_IDENTITY_UPDATE);
+
+        // The proof only covers the added keys, fetch the whole identity to see disabled ones
+        if let Some(identity) = Identity::fetch_by_identifier(sdk, identity_id)
+            .await
+            .map_err(|e| format!("Fetch identity error: {}", e))?
+        {
+            qualified_identity.identity = identity;
+            self.record_credit_operation(
+                identity_id,
+                CreditOperationKind::Fee,
+                0,
+                balance_before.saturating_sub(qualified_identity.identity.balance()),
+                "Keys updated".to_string(),
+            );
+        }
+        self.insert_local_qualified_identity(&qualified_identity)
+            .map_err(|e| format!("Database error: {}", e))?;
+        Ok(qualified_identity)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::context::AppContext;
+use crate::model::ownership_proof::OwnershipProof;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::{Fetch, Identifier, Identity};
+use dash_sdk::Sdk;
+
+impl AppContext {
+    /// Checks the proof against the identity's key as it is on Platform now, so a proof signed
+    /// with a key disabled since no longer counts
+    pub(super) async fn verify_ownership_proof(
+        &self,
+        sdk: &Sdk,
+        proof: OwnershipProof,
+    ) -> Result<OwnershipProof, String> {
+        let network = self.network_string();
+        if proof.claim.network != network {
+            return Err(format!(
+                "The proof was signed on {}, this tool is on {}",
+                proof.claim.network, network
+            ));
+        }
+        let identity_id = Identifier::from_string(&proof.claim.identity_id, Encoding::Base58)
+            .map_err(|_| "The identity id of the proof is not valid".to_string())?;
+        let identity = Identity::fetch_by_identifier(sdk, identity_id)
+            .await
+            .map_err(|e| format!("Error fetching identity: {}", e))?
+            .ok_or_else(|| format!("Identity {} does not exist", proof.claim.identity_id))?;
+        let public_key = identity
+            .public_keys()
+            .get(&proof.claim.key_id)
+            .ok_or_else(|| {
+                format!(
+                    "Identity {} has no key {}",
+                    proof.claim.identity_id, proof.claim.key_id
+                )
+            })?;
+        proof.verify(public_key)?;
+        Ok(proof)
+    }
+}
@@ -1,4 +1,7 @@
 use crate::context::AppContext;
+use crate::model::credit_operation::CreditOperationKind;
+use crate::model::fee::{withdrawal_fee_per_byte, FeeSpeed};
+use crate::model::key_usage::TRANSITION_WITHDRAWAL;
 use crate::model::qualified_identity::QualifiedIdentity;
 use dash_sdk::dpp::dashcore::Address;
 use dash_sdk::dpp::fee::Credits;
@@ -13,21 +16,54 @@ impl AppContext {
         to_address: Option<Address>,
         credits: Credits,
         id: Option<KeyID>,
+        speed: FeeSpeed,
     ) -> Result<(), String> {
+        let recipient = to_address.as_ref().map(|address| address.to_string());
+        let balance_before = qualified_identity.identity.balance();
         let remaining_balance = qualified_identity
             .identity
             .clone()
             .withdraw(
-                &self.sdk,
+                &self.sdk(),
                 to_address,
                 credits,
-                Some(1),
+                Some(withdrawal_fee_per_byte(self.fee_estimates().rate(speed))),
                 id.and_then(|key_id| qualified_identity.identity.get_public_key_by_id(key_id)),
                 qualified_identity.clone(),
                 None,
             )
             .await
-            .map_err(|e| format!("Withdrawal error: {}", e))?;
+            .map_err(|e| self.rejection_message("withdrawal", "Withdrawal error", &e))?;
+        if let Some(key_id) = id {
+            self.record_key_usage(
+                &qualified_identity.identity.id(),
+                key_id,
+                TRANSITION_WITHDRAWAL,
+            );
+        }
+        if let Err(e) =
+            self.db
+                .record_credit_spending(&qualified_identity.identity.id(), credits, self)
+        {
+            tracing::warn!("Failed to record spent credits: {}", e);
+        }
+        self.record_credit_operation(
+            qualified_identity.identity.id(),
+            CreditOperationKind::Withdrawal,
+            credits,
+            balance_before
+                .saturating_sub(credits)
+                .saturating_sub(remaining_balance),
+            match &recipient {
+                Some(recipient) => format!("Withdrawal to {}", recipient),
+                None => "Withdrawal to the payout address".to_string(),
+            },
+        );
+        if let Some(recipient) = recipient {
+            if let Err(e) = self.db.record_used_recipient(&recipient, self) {
+                tracing::warn!("Failed to record the recipient: {}", e);
+            }
+        }
         qualified_identity.identity.set_balance(remaining_balance);
         self.insert_local_qualified_identity(&qualified_identity)
             .map_err(|e| format!("Database error: {}", e))
@@ -1,20 +1,58 @@
-use crate::app::TaskResult;
+use crate::app::{AppEvent, TaskResult};
 use crate::context::AppContext;
+use crate::model::approval::AuditEntry;
+use crate::model::cold_signing::ColdSigningFile;
+use crate::model::consensus_error::{explain_consensus_error, rejection_cause};
+use crate::model::contestant_reputation::ContestantReputation;
+use crate::model::contested_name::ContestantPreview;
+use crate::model::document_snapshot::DocumentSnapshot;
+use crate::model::dpns_lookup::{DpnsNameRecord, NameCheck};
+use crate::model::endpoint_health::{is_proof_error, same_address, MAX_PROOF_RETRIES};
+use crate::model::health_check::{DapiConnectivityReport, HealthReport};
+use crate::model::identity_details::{IdentityDetails, IdentityNonces};
+use crate::model::masternode_message::SignedMasternodeMessage;
+use crate::model::masternode_payout::MasternodePayout;
+use crate::model::masternode_status::MasternodeDashboard;
+use crate::model::ownership_proof::OwnershipProof;
+use crate::model::preferences::VerificationMode;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::rate_limit::Endpoint;
+use crate::model::transfer_memo::ReceivedMemo;
+use crate::model::vote_proof::VoteProofReceipt;
+use crate::model::withdrawal::WithdrawalRecord;
 use crate::platform::contested_names::ContestedResourceTask;
 use crate::platform::contract::ContractTask;
 use crate::platform::core::{CoreItem, CoreTask};
 use crate::platform::document::DocumentTask;
+use crate::platform::document_deletion::DeletionPreview;
+use crate::platform::health::HealthTask;
 use crate::platform::identity::IdentityTask;
+use crate::platform::price::PriceTask;
+use crate::platform::withdrawals::WithdrawalsTask;
 use dash_sdk::dpp::voting::votes::Vote;
+use dash_sdk::platform::Identifier;
 use dash_sdk::query_types::Documents;
+use dash_sdk::Sdk;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
 pub mod contested_names;
 pub mod contract;
 pub mod core;
-mod document;
+mod dapi_connectivity;
+pub mod document;
+pub mod document_deletion;
+pub mod document_import;
+pub mod document_trade;
+mod dpns_lookup;
+pub mod endpoint_health;
+pub mod health;
 pub mod identity;
+pub mod price;
+pub mod rate_limit;
+pub mod response_cache;
+pub mod task_gate;
+pub mod withdrawals;
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum BackendTask {
@@ -23,6 +61,9 @@ pub(crate) enum BackendTask {
     ContractTask(ContractTask),
     ContestedResourceTask(ContestedResourceTask),
     CoreTask(CoreTask),
+    WithdrawalsTask(WithdrawalsTask),
+    HealthTask(HealthTask),
+    PriceTask(PriceTask),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -32,8 +73,171 @@ pub(crate) enum BackendTaskSuccessResult {
     Documents(Documents),
     CoreItem(CoreItem),
     SuccessfulVotes(Vec<Vote>),
+    IdentityDetails(IdentityDetails),
+    IdentityNonces(IdentityNonces),
+    Withdrawals(Vec<WithdrawalRecord>),
+    /// Outcome of loading each identity of a bulk import, keyed by the identity id input
+    LoadedIdentities(Vec<(String, Result<(), String>)>),
+    /// The identity after its keys were added or disabled
+    UpdatedIdentityKeys(QualifiedIdentity),
+    /// Registered names matching a DPNS search
+    DpnsNames(Vec<DpnsNameRecord>),
+    /// Availability of candidate DPNS names
+    DpnsNameChecks(Vec<NameCheck>),
+    HealthReport(HealthReport),
+    /// Connectivity of each configured DAPI address, layer by layer
+    DapiConnectivity(Vec<DapiConnectivityReport>),
+    /// Stored payouts to our masternodes, oldest first
+    MasternodePayouts(Vec<MasternodePayout>),
+    MasternodeDashboard(MasternodeDashboard),
+    /// A snapshot of the documents of a type, just stored
+    DocumentSnapshot(DocumentSnapshot),
+    /// Snapshots of every document type of a contract, just stored
+    ContractSnapshots(Vec<DocumentSnapshot>),
+    /// Sent while an import runs, after each batch
+    DocumentImportProgress {
+        done: usize,
+        total: usize,
+    },
+    /// The id of the created document or the error, by the number of the imported record
+    ImportedDocuments(Vec<(usize, Result<Identifier, String>)>),
+    /// The documents a deletion would remove and its estimated fee
+    DeletionPreview(DeletionPreview),
+    /// Sent while a deletion runs, after each batch
+    DocumentDeletionProgress {
+        done: usize,
+        total: usize,
+    },
+    /// Whether each document was deleted, by document id
+    DeletedDocuments(Vec<(Identifier, Result<(), String>)>),
+    /// An unsigned transition to take to the offline machine
+    ColdSigningFile(ColdSigningFile),
+    /// Base64 PSBT of an asset lock to sign in an external wallet
+    UnsignedAssetLock(String),
+    /// A proof of ownership whose signature matches the identity's key on Platform
+    VerifiedOwnershipProof(OwnershipProof),
+    /// A message whose masternode key matches the deterministic masternode list
+    VerifiedMasternodeMessage(SignedMasternodeMessage),
+    /// A vote receipt whose proof shows the vote it claims
+    VerifiedVoteReceipt(VoteProofReceipt),
+    /// The contestants of a name as shown on Platform, by normalized name
+    ContestantPreviews(String, Vec<ContestantPreview>),
+    /// Every contestant reputation stored, after some were fetched
+    ContestantReputations(Vec<ContestantReputation>),
+    /// Memos received with transfers, newest first
+    TransferMemos(Vec<ReceivedMemo>),
 }
 
+impl BackendTask {
+    /// Events published when the task succeeds
+    pub fn events_on_success(&self) -> Vec<AppEvent> {
+        match self {
+            BackendTask::IdentityTask(
+                IdentityTask::LoadIdentity(_)
+                | IdentityTask::ImportMasternodes(_)
+                | IdentityTask::LoadIdentities(_)
+                | IdentityTask::RegisterIdentity(_)
+                | IdentityTask::RegisterIdentityWithSignedAssetLock(..),
+            ) => vec![AppEvent::IdentityAdded],
+            BackendTask::IdentityTask(
+                IdentityTask::RefreshIdentities(_)
+                | IdentityTask::AddKeyToIdentity(..)
+                | IdentityTask::UpdateIdentityKeys(..)
+                | IdentityTask::WithdrawFromIdentity(..)
+                | IdentityTask::TransferCredits(..)
+                | IdentityTask::BroadcastColdSigned(_)
+                | IdentityTask::BroadcastRawTransition(_)
+                | IdentityTask::TopUpIdentity(_)
+                | IdentityTask::AutoTopUpIdentity(_)
+                | IdentityTask::RegisterDpnsName(_),
+            ) => vec![AppEvent::IdentitiesUpdated],
+            BackendTask::ContestedResourceTask(
+                ContestedResourceTask::QueryDPNSContestedResources
+                | ContestedResourceTask::QueryDPNSVoteContenders(_),
+            ) => vec![AppEvent::ContestsUpdated],
+            BackendTask::ContestedResourceTask(
+                ContestedResourceTask::VoteOnDPNSName(..)
+                | ContestedResourceTask::VoteOnMultipleDPNSNames(..),
+            ) => vec![AppEvent::VoteSubmitted],
+            _ => vec![],
+        }
+    }
+
+    /// Whether the task only reads from Platform, so it can run without proofs in fast mode
+    pub fn is_query(&self) -> bool {
+        match self {
+            BackendTask::IdentityTask(task) => matches!(
+                task,
+                IdentityTask::LoadIdentity(_)
+                    | IdentityTask::ImportMasternodes(_)
+                    | IdentityTask::LoadIdentities(_)
+                    | IdentityTask::RefreshIdentities(_)
+                    | IdentityTask::FetchIdentityDetails(_)
+                    | IdentityTask::FetchIdentityNonces(_)
+                    | IdentityTask::VerifyOwnershipProof(_)
+                    | IdentityTask::FetchTransferMemos(_)
+            ),
+            BackendTask::ContestedResourceTask(task) => matches!(
+                task,
+                ContestedResourceTask::QueryDPNSContestedResources
+                    | ContestedResourceTask::QueryDPNSVoteContenders(_)
+                    | ContestedResourceTask::FetchContestantPreviews(_)
+                    | ContestedResourceTask::FetchContestantReputations(_)
+            ),
+            BackendTask::DocumentTask(task) => !matches!(
+                task,
+                DocumentTask::ImportDocuments(_)
+                    | DocumentTask::DeleteDocuments(_)
+                    | DocumentTask::SetDocumentPrice(_)
+                    | DocumentTask::PurchaseDocument(_)
+            ),
+            BackendTask::ContractTask(_) | BackendTask::WithdrawalsTask(_) => true,
+            BackendTask::CoreTask(_) | BackendTask::HealthTask(_) | BackendTask::PriceTask(_) => {
+                false
+            }
+        }
+    }
+
+    /// Short name of the kind of task, used as a metrics label
+    pub fn kind(&self) -> &'static str {
+        match self {
+            BackendTask::IdentityTask(_) => "identity",
+            BackendTask::DocumentTask(_) => "document",
+            BackendTask::ContractTask(_) => "contract",
+            BackendTask::ContestedResourceTask(_) => "contested_resource",
+            BackendTask::CoreTask(_) => "core",
+            BackendTask::WithdrawalsTask(_) => "withdrawals",
+            BackendTask::HealthTask(_) => "health",
+            BackendTask::PriceTask(_) => "price",
+        }
+    }
+
+    /// The endpoint whose rate limit and backoff the task follows. Health checks are exempt so
+    /// they can look into an endpoint that is backing off.
+    pub fn endpoint(&self) -> Option<Endpoint> {
+        match self {
+            BackendTask::IdentityTask(_)
+            | BackendTask::DocumentTask(_)
+            | BackendTask::ContractTask(_)
+            | BackendTask::ContestedResourceTask(_)
+            | BackendTask::WithdrawalsTask(_) => Some(Endpoint::Dapi),
+            BackendTask::CoreTask(_) => Some(Endpoint::CoreRpc),
+            BackendTask::PriceTask(_) => Some(Endpoint::Http),
+            BackendTask::HealthTask(_) => None,
+        }
+    }
+}
+
+/// Kinds of tasks that run queries against Platform with their display names, the keys of the
+/// per-task verification settings
+pub const QUERY_TASK_KINDS: [(&str, &str); 5] = [
+    ("identity", "Identities"),
+    ("document", "Documents"),
+    ("contract", "Contracts"),
+    ("contested_resource", "Contested names"),
+    ("withdrawals", "Withdrawals"),
+];
+
 impl BackendTaskSuccessResult {}
 
 impl AppContext {
@@ -48,29 +252,150 @@ impl AppContext {
         Ok(())
     }
 
+    /// How the task's responses are checked, state transitions are always verified
+    pub fn verification_mode(&self, task: &BackendTask) -> VerificationMode {
+        if task.is_query() {
+            self.preferences().query_verification.mode_for(task.kind())
+        } else {
+            VerificationMode::Verified
+        }
+    }
+
     pub async fn run_backend_task(
         self: &Arc<Self>,
         task: BackendTask,
         sender: mpsc::Sender<TaskResult>,
     ) -> Result<BackendTaskSuccessResult, String> {
-        let sdk = self.sdk.clone();
-        match task {
-            BackendTask::ContractTask(contract_task) => self
-                .run_contract_task(contract_task, &sdk)
+        if task.endpoint() == Some(Endpoint::Dapi) {
+            if let Some(reason) = self.dapi_refused_by_proxy() {
+                return Err(reason);
+            }
+            self.pin_dapi_certificates().await;
+        }
+        let verification_mode = self.verification_mode(&task);
+        let sdk = match verification_mode {
+            VerificationMode::Verified => self.sdk(),
+            VerificationMode::Fast => self.unproved_sdk(),
+        };
+        if verification_mode == VerificationMode::Fast || !task.is_query() {
+            return self.run_backend_task_on(task, &sdk, sender).await;
+        }
+        let started_at = chrono::Utc::now();
+        match self
+            .run_backend_task_on(task.clone(), &sdk, sender.clone())
+            .await
+        {
+            Err(e) if is_proof_error(&e) => {
+                let address = self.request_metrics.last_endpoint_since(started_at);
+                self.retry_after_proof_failure(task, sender, address, e)
+                    .await
+            }
+            result => result,
+        }
+    }
+
+    /// Retries a query whose proof did not verify on other DAPI nodes, one at a time. The node
+    /// that answered and every node whose proof fails as well are flagged so retries avoid them.
+    async fn retry_after_proof_failure(
+        self: &Arc<Self>,
+        task: BackendTask,
+        sender: mpsc::Sender<TaskResult>,
+        failed_address: Option<String>,
+        error: String,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        if let Some(address) = &failed_address {
+            self.endpoint_health.flag(address, &error);
+        }
+        let endpoint_sdks = self.endpoint_sdks();
+        let candidates: Vec<&(String, Sdk)> = endpoint_sdks
+            .iter()
+            .filter(|(address, _)| {
+                !failed_address
+                    .as_deref()
+                    .is_some_and(|failed| same_address(failed, address))
+                    && !self.endpoint_health.is_flagged(address)
+            })
+            .take(MAX_PROOF_RETRIES)
+            .collect();
+        let mut tried = 0;
+        for (address, sdk) in candidates {
+            tried += 1;
+            tracing::info!(
+                "Retrying a {} query on {} after a proof did not verify",
+                task.kind(),
+                address
+            );
+            match self
+                .run_backend_task_on(task.clone(), sdk, sender.clone())
                 .await
-                .map(|_| BackendTaskSuccessResult::None),
+            {
+                Err(e) if is_proof_error(&e) => self.endpoint_health.flag(address, &e),
+                result => return result,
+            }
+        }
+        Err(format!(
+            "The proof did not verify on {} DAPI nodes: {}",
+            tried + 1,
+            error
+        ))
+    }
+
+    /// Runs the task with the given SDK
+    async fn run_backend_task_on(
+        self: &Arc<Self>,
+        task: BackendTask,
+        sdk: &Sdk,
+        sender: mpsc::Sender<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        match task {
+            BackendTask::ContractTask(contract_task) => {
+                self.run_contract_task(contract_task, sdk).await
+            }
             BackendTask::ContestedResourceTask(contested_resource_task) => {
-                self.run_contested_resource_task(contested_resource_task, &sdk, sender)
+                self.run_contested_resource_task(contested_resource_task, sdk, sender)
                     .await
             }
-            BackendTask::IdentityTask(identity_task) => self
-                .run_identity_task(identity_task, &sdk)
-                .await
-                .map(|_| BackendTaskSuccessResult::None),
+            BackendTask::IdentityTask(identity_task) => {
+                self.run_identity_task(identity_task, sdk).await
+            }
             BackendTask::DocumentTask(document_task) => {
-                self.run_document_task(document_task, &sdk).await
+                self.run_document_task(document_task, sdk, sender).await
             }
             BackendTask::CoreTask(core_task) => self.run_core_task(core_task).await,
+            BackendTask::WithdrawalsTask(withdrawals_task) => {
+                self.run_withdrawals_task(withdrawals_task, sdk).await
+            }
+            BackendTask::HealthTask(health_task) => self.run_health_task(health_task).await,
+            BackendTask::PriceTask(price_task) => self.run_price_task(price_task).await,
+        }
+    }
+
+    /// The message of a state transition that failed. When Platform rejected it, what the
+    /// consensus error means and the field it points at are added, and the rejection is recorded
+    /// in the audit trail.
+    pub(crate) fn rejection_message(
+        &self,
+        transition: &str,
+        context: &str,
+        error: &dash_sdk::Error,
+    ) -> String {
+        let message = format!("{}: {}", context, error);
+        let Some(explanation) = rejection_cause(error).and_then(explain_consensus_error) else {
+            return message;
+        };
+        let entry = AuditEntry {
+            action: format!("Platform rejected a {} transition", transition),
+            outcome: explanation.summary(),
+            approver: None,
+            logged_at: chrono::Utc::now().timestamp(),
+        };
+        if let Err(e) = self.db.insert_audit_entry(&entry, self) {
+            tracing::error!("Failed to write the audit trail: {}", e);
+        }
+        let mut message = format!("{}\n\n{}", message, explanation.explanation);
+        if let Some(field) = explanation.field_sentence() {
+            message.push_str(&format!("\n{}", field));
         }
+        message
     }
 }
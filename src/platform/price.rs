@@ -0,0 +1,53 @@
+use crate::context::AppContext;
+use crate::model::price_feed::{extract_price, set_fiat_price, FiatPrice};
+use crate::platform::BackendTaskSuccessResult;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PriceTask {
+    /// Fetches the price of DASH from the configured provider and caches it
+    FetchPrice,
+}
+
+impl AppContext {
+    pub async fn run_price_task(
+        &self,
+        task: PriceTask,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        match task {
+            PriceTask::FetchPrice => {
+                let settings = self.preferences().price_feed;
+                if !settings.enabled {
+                    return Ok(BackendTaskSuccessResult::None);
+                }
+                let (url, json_path) = settings.provider.request(&settings.currency);
+                let client = self
+                    .http_client(Duration::from_secs(10))
+                    .map_err(|e| format!("Price feed error: {}", e))?;
+                let json: serde_json::Value = client
+                    .get(&url)
+                    .send()
+                    .await
+                    .and_then(|response| response.error_for_status())
+                    .map_err(|e| format!("Failed to fetch the price of DASH: {}", e))?
+                    .json()
+                    .await
+                    .map_err(|e| format!("The price feed did not answer with JSON: {}", e))?;
+                let price = extract_price(&json, &json_path).ok_or(format!(
+                    "The price feed's answer has no price at {}",
+                    json_path
+                ))?;
+                let fiat_price = FiatPrice {
+                    currency: settings.currency.trim().to_uppercase(),
+                    price,
+                    fetched_at: chrono::Utc::now().timestamp(),
+                };
+                if let Err(e) = self.db.save_fiat_price(&fiat_price) {
+                    tracing::warn!("Failed to cache the price of DASH: {}", e);
+                }
+                set_fiat_price(fiat_price);
+                Ok(BackendTaskSuccessResult::None)
+            }
+        }
+    }
+}
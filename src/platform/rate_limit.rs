@@ -0,0 +1,58 @@
+use crate::model::rate_limit::{Endpoint, EndpointPolicy};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default)]
+struct EndpointState {
+    /// Earliest start of the next task
+    next_start: Option<Instant>,
+    failures_in_a_row: u32,
+}
+
+/// Spaces out the tasks of each endpoint and pauses an endpoint after failures
+#[derive(Debug, Default)]
+pub(crate) struct RateLimiter {
+    endpoints: Mutex<HashMap<Endpoint, EndpointState>>,
+}
+
+impl RateLimiter {
+    /// Waits until the endpoint may be used again and reserves the slot
+    pub async fn acquire(&self, endpoint: Endpoint, policy: &EndpointPolicy) {
+        let wait = {
+            let mut endpoints = self.endpoints.lock().unwrap();
+            let state = endpoints.entry(endpoint).or_default();
+            let now = Instant::now();
+            let start = state
+                .next_start
+                .map_or(now, |next_start| next_start.max(now));
+            state.next_start = Some(start + policy.interval());
+            start - now
+        };
+        if wait > Duration::ZERO {
+            tracing::debug!("{} rate limited for {:?}", endpoint.label(), wait);
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Resets the backoff after a success, or pauses the endpoint after a failure
+    pub fn record(&self, endpoint: Endpoint, policy: &EndpointPolicy, success: bool) {
+        let mut endpoints = self.endpoints.lock().unwrap();
+        let state = endpoints.entry(endpoint).or_default();
+        if success {
+            state.failures_in_a_row = 0;
+            return;
+        }
+        state.failures_in_a_row += 1;
+        let resume = Instant::now() + policy.backoff(state.failures_in_a_row);
+        state.next_start = Some(state.next_start.map_or(resume, |next| next.max(resume)));
+    }
+
+    /// How long the endpoint is paused after failures, for the settings screen
+    pub fn backing_off(&self, endpoint: Endpoint) -> Option<(u32, Duration)> {
+        let endpoints = self.endpoints.lock().unwrap();
+        let state = endpoints.get(&endpoint)?;
+        let remaining = state.next_start?.checked_duration_since(Instant::now())?;
+        (state.failures_in_a_row > 0).then_some((state.failures_in_a_row, remaining))
+    }
+}
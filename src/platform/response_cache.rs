@@ -0,0 +1,132 @@
+use crate::model::response_cache::QueryClass;
+use crate::platform::contested_names::ContestedResourceTask;
+use crate::platform::contract::ContractTask;
+use crate::platform::document::DocumentTask;
+use crate::platform::identity::IdentityTask;
+use crate::platform::withdrawals::WithdrawalsTask;
+use crate::platform::BackendTask;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+impl BackendTask {
+    /// The class and parameters a query's result is cached under, `None` for tasks whose
+    /// results are not cached
+    pub(crate) fn cache_key(&self) -> Option<(QueryClass, String)> {
+        let class = match self {
+            BackendTask::ContractTask(
+                ContractTask::FetchDPNSContract | ContractTask::FetchContract(..),
+            ) => QueryClass::Contracts,
+            BackendTask::DocumentTask(DocumentTask::FetchDocuments(_)) => QueryClass::Documents,
+            BackendTask::DocumentTask(
+                DocumentTask::SearchDpnsNames(_) | DocumentTask::CheckDpnsNames(_),
+            ) => QueryClass::Names,
+            BackendTask::ContestedResourceTask(
+                ContestedResourceTask::QueryDPNSContestedResources
+                | ContestedResourceTask::QueryDPNSVoteContenders(_)
+                | ContestedResourceTask::FetchContestantPreviews(_),
+            ) => QueryClass::Contests,
+            BackendTask::IdentityTask(IdentityTask::FetchIdentityDetails(_)) => {
+                QueryClass::Identities
+            }
+            BackendTask::WithdrawalsTask(WithdrawalsTask::QueryWithdrawals { .. }) => {
+                QueryClass::Withdrawals
+            }
+            _ => return None,
+        };
+        // Queries carry no secrets, their debug output names the query and all its parameters
+        Some((class, format!("{:?}", self)))
+    }
+
+    /// Classes of cached results that are stale once this task succeeded
+    pub(crate) fn invalidated_classes(&self) -> Vec<QueryClass> {
+        match self {
+            // A transition built by hand can change anything
+            BackendTask::IdentityTask(IdentityTask::BroadcastRawTransition(_)) => {
+                QueryClass::ALL.to_vec()
+            }
+            BackendTask::IdentityTask(IdentityTask::RegisterDpnsName(_)) => vec![
+                QueryClass::Identities,
+                QueryClass::Names,
+                QueryClass::Contests,
+                QueryClass::Documents,
+            ],
+            BackendTask::IdentityTask(
+                IdentityTask::RegisterIdentity(_)
+                | IdentityTask::RegisterIdentityWithSignedAssetLock(..)
+                | IdentityTask::TopUpIdentity(_)
+                | IdentityTask::AutoTopUpIdentity(_)
+                | IdentityTask::AddKeyToIdentity(..)
+                | IdentityTask::WithdrawFromIdentity(..)
+                | IdentityTask::TransferCredits(..)
+                | IdentityTask::BroadcastColdSigned(_)
+                | IdentityTask::UpdateIdentityKeys(..),
+            ) => vec![QueryClass::Identities, QueryClass::Withdrawals],
+            BackendTask::ContestedResourceTask(
+                ContestedResourceTask::VoteOnDPNSName(..)
+                | ContestedResourceTask::VoteOnMultipleDPNSNames(..),
+            ) => vec![QueryClass::Contests],
+            BackendTask::DocumentTask(
+                DocumentTask::ImportDocuments(_)
+                | DocumentTask::DeleteDocuments(_)
+                | DocumentTask::SetDocumentPrice(_)
+                | DocumentTask::PurchaseDocument(_),
+            ) => vec![QueryClass::Documents, QueryClass::Identities],
+            BackendTask::ContractTask(ContractTask::RefreshContract(_)) => {
+                vec![QueryClass::Contracts, QueryClass::Documents]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// Results of recent queries, so screens opened again don't repeat the same network calls
+#[derive(Debug)]
+pub(crate) struct ResponseCache<V> {
+    entries: Mutex<HashMap<(QueryClass, String), (Instant, V)>>,
+}
+
+impl<V> Default for ResponseCache<V> {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<V: Clone> ResponseCache<V> {
+    /// The cached value if it is younger than the time to live
+    pub fn get(&self, class: QueryClass, key: &str, ttl: Duration) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry_key = (class, key.to_string());
+        let (stored_at, value) = entries.get(&entry_key)?;
+        if stored_at.elapsed() < ttl {
+            return Some(value.clone());
+        }
+        entries.remove(&entry_key);
+        None
+    }
+
+    pub fn insert(&self, class: QueryClass, key: String, value: V) {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert((class, key), (Instant::now(), value));
+    }
+
+    pub fn invalidate(&self, class: QueryClass) {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|(entry_class, _), _| *entry_class != class);
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Number of cached results, fresh or not
+    pub fn entry_count(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+}
@@ -0,0 +1,209 @@
+use crate::platform::contested_names::ContestedResourceTask;
+use crate::platform::contract::ContractTask;
+use crate::platform::core::CoreTask;
+use crate::platform::health::HealthTask;
+use crate::platform::identity::IdentityTask;
+use crate::platform::price::PriceTask;
+use crate::platform::withdrawals::WithdrawalsTask;
+use crate::platform::BackendTask;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How a read-only task is de-duplicated. Tasks with the same key are not started while one
+/// is running, nor again within the cooldown after it started.
+pub(crate) struct CoalescingRule {
+    pub key: String,
+    /// Describes the task in messages, e.g. "A contested names refresh"
+    pub label: &'static str,
+    pub cooldown: Duration,
+}
+
+impl BackendTask {
+    /// Only queries are coalesced, tasks that change state always run. Automatic top ups are the
+    /// exception, so a low balance seen again while one is running isn't funded twice.
+    pub(crate) fn coalescing_rule(&self) -> Option<CoalescingRule> {
+        let (key, label, cooldown_secs) = match self {
+            BackendTask::ContestedResourceTask(
+                ContestedResourceTask::QueryDPNSContestedResources,
+            ) => (
+                "query_dpns_contested_resources".to_string(),
+                "A contested names refresh",
+                10,
+            ),
+            BackendTask::ContestedResourceTask(ContestedResourceTask::QueryDPNSVoteContenders(
+                name,
+            )) => (
+                format!("query_dpns_vote_contenders:{}", name),
+                "A contenders refresh",
+                5,
+            ),
+            BackendTask::IdentityTask(IdentityTask::FetchIdentityDetails(identity_id)) => (
+                format!(
+                    "fetch_identity_details:{}",
+                    identity_id.to_string(Encoding::Base58)
+                ),
+                "An identity refresh",
+                5,
+            ),
+            BackendTask::IdentityTask(IdentityTask::FetchIdentityNonces(identity_id)) => (
+                format!(
+                    "fetch_identity_nonces:{}",
+                    identity_id.to_string(Encoding::Base58)
+                ),
+                "A nonce refresh",
+                5,
+            ),
+            BackendTask::WithdrawalsTask(WithdrawalsTask::QueryWithdrawals { include_network }) => {
+                (
+                    format!("query_withdrawals:{}", include_network),
+                    "A withdrawals refresh",
+                    10,
+                )
+            }
+            BackendTask::ContractTask(ContractTask::CheckContractUpdates) => (
+                "check_contract_updates".to_string(),
+                "A contract update check",
+                60,
+            ),
+            BackendTask::IdentityTask(IdentityTask::AutoTopUpIdentity(top_up_info)) => (
+                format!(
+                    "auto_top_up:{}",
+                    top_up_info
+                        .qualified_identity
+                        .identity
+                        .id()
+                        .to_string(Encoding::Base58)
+                ),
+                "An automatic top up",
+                15 * 60,
+            ),
+            BackendTask::CoreTask(CoreTask::SyncMasternodePayouts) => {
+                ("sync_masternode_payouts".to_string(), "A payout sync", 10)
+            }
+            BackendTask::CoreTask(CoreTask::FetchMasternodeDashboard) => (
+                "fetch_masternode_dashboard".to_string(),
+                "A masternode status refresh",
+                5,
+            ),
+            BackendTask::HealthTask(HealthTask::RunChecks) => {
+                ("health_checks".to_string(), "A health check", 5)
+            }
+            BackendTask::HealthTask(HealthTask::MeasureClockSkew) => {
+                ("measure_clock_skew".to_string(), "A clock check", 30)
+            }
+            BackendTask::HealthTask(HealthTask::TroubleshootDapi) => (
+                "troubleshoot_dapi".to_string(),
+                "A DAPI connectivity check",
+                5,
+            ),
+            BackendTask::PriceTask(PriceTask::FetchPrice) => {
+                ("fetch_price".to_string(), "A price refresh", 30)
+            }
+            _ => return None,
+        };
+        Some(CoalescingRule {
+            key,
+            label,
+            cooldown: Duration::from_secs(cooldown_secs),
+        })
+    }
+}
+
+/// Why a task was not started
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TaskRejection {
+    AlreadyRunning(&'static str),
+    CoolingDown(&'static str, Duration),
+}
+
+impl Display for TaskRejection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskRejection::AlreadyRunning(label) => write!(f, "{} is already running", label),
+            TaskRejection::CoolingDown(label, remaining) => write!(
+                f,
+                "{} just ran, try again in {}s",
+                label,
+                remaining.as_secs().max(1)
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct GateState {
+    running: HashSet<String>,
+    /// Tasks holding a ticket, coalesced or not
+    pending: usize,
+    last_started: HashMap<String, Instant>,
+}
+
+/// Keeps identical queries from piling up when a button is clicked repeatedly
+#[derive(Debug, Default)]
+pub(crate) struct TaskGate {
+    state: Arc<Mutex<GateState>>,
+}
+
+/// Marks a task as running until it is dropped
+pub(crate) struct TaskTicket {
+    state: Arc<Mutex<GateState>>,
+    key: Option<String>,
+}
+
+impl Drop for TaskTicket {
+    fn drop(&mut self) {
+        let mut state = self.state.lock().unwrap();
+        state.pending = state.pending.saturating_sub(1);
+        if let Some(key) = &self.key {
+            state.running.remove(key);
+        }
+    }
+}
+
+impl TaskGate {
+    /// Returns a ticket to hold while the task runs, or why the task should not start
+    pub(crate) fn try_start(&self, task: &BackendTask) -> Result<TaskTicket, TaskRejection> {
+        let Some(rule) = task.coalescing_rule() else {
+            self.state.lock().unwrap().pending += 1;
+            return Ok(TaskTicket {
+                state: self.state.clone(),
+                key: None,
+            });
+        };
+        let mut state = self.state.lock().unwrap();
+        if state.running.contains(&rule.key) {
+            return Err(TaskRejection::AlreadyRunning(rule.label));
+        }
+        if let Some(last_started) = state.last_started.get(&rule.key) {
+            let elapsed = last_started.elapsed();
+            if elapsed < rule.cooldown {
+                return Err(TaskRejection::CoolingDown(
+                    rule.label,
+                    rule.cooldown - elapsed,
+                ));
+            }
+        }
+        state.running.insert(rule.key.clone());
+        state.pending += 1;
+        state.last_started.insert(rule.key.clone(), Instant::now());
+        Ok(TaskTicket {
+            state: self.state.clone(),
+            key: Some(rule.key),
+        })
+    }
+
+    /// How many tasks are running
+    pub(crate) fn pending_count(&self) -> usize {
+        self.state.lock().unwrap().pending
+    }
+
+    /// Whether a task with the same coalescing key as this one is running
+    pub(crate) fn is_running(&self, task: &BackendTask) -> bool {
+        task.coalescing_rule()
+            .is_some_and(|rule| self.state.lock().unwrap().running.contains(&rule.key))
+    }
+}
@@ -0,0 +1,180 @@
+use crate::context::AppContext;
+use crate::model::withdrawal::{WithdrawalRecord, WithdrawalStatus};
+use crate::platform::BackendTaskSuccessResult;
+use dash_sdk::dashcore_rpc::RpcApi;
+use dash_sdk::dpp::dashcore::{Address, ScriptBuf};
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::Value;
+use dash_sdk::drive::query::{WhereClause, WhereOperator};
+use dash_sdk::platform::{Document, DocumentQuery, FetchMany, Identifier};
+use dash_sdk::Sdk;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Maximum number of withdrawal documents fetched per query
+const WITHDRAWALS_QUERY_LIMIT: u32 = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum WithdrawalsTask {
+    /// Queries the withdrawals of our identities, and the pending withdrawals of everyone if
+    /// `include_network` is set
+    QueryWithdrawals { include_network: bool },
+}
+
+impl AppContext {
+    pub async fn run_withdrawals_task(
+        &self,
+        task: WithdrawalsTask,
+        sdk: &Sdk,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        match task {
+            WithdrawalsTask::QueryWithdrawals { include_network } => self
+                .query_withdrawals(sdk, include_network)
+                .await
+                .map(BackendTaskSuccessResult::Withdrawals),
+        }
+    }
+
+    async fn query_withdrawals(
+        &self,
+        sdk: &Sdk,
+        include_network: bool,
+    ) -> Result<Vec<WithdrawalRecord>, String> {
+        let my_identity_ids: BTreeSet<Identifier> = self
+            .load_local_qualified_identities()
+            .map_err(|e| format!("Database error: {}", e))?
+            .into_iter()
+            .map(|qualified_identity| qualified_identity.identity.id())
+            .collect();
+
+        let mut where_clauses = my_identity_ids
+            .iter()
+            .map(|identity_id| WhereClause {
+                field: "$ownerId".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(identity_id.to_buffer()),
+            })
+            .collect::<Vec<_>>();
+        if include_network {
+            where_clauses.extend(WithdrawalStatus::PENDING.iter().map(|status| WhereClause {
+                field: "status".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::U8(status.to_u8()),
+            }));
+        }
+
+        // The same withdrawal can be returned by several queries
+        let mut documents: BTreeMap<Identifier, Document> = BTreeMap::new();
+        for where_clause in where_clauses {
+            let mut query = DocumentQuery::new(self.withdrawals_contract.clone(), "withdrawal")
+                .map_err(|e| e.to_string())?
+                .with_where(where_clause);
+            query.limit = WITHDRAWALS_QUERY_LIMIT;
+            let fetched = Document::fetch_many(sdk, query)
+                .await
+                .map_err(|e| format!("Error fetching withdrawals: {}", e))?;
+            documents.extend(
+                fetched
+                    .into_iter()
+                    .filter_map(|(id, document)| document.map(|document| (id, document))),
+            );
+        }
+
+        let mut records: Vec<WithdrawalRecord> = documents
+            .values()
+            .filter_map(|document| self.withdrawal_record(document, &my_identity_ids))
+            .collect();
+
+        if include_network {
+            // Queued withdrawals are pooled oldest first
+            let mut queued: Vec<&mut WithdrawalRecord> = records
+                .iter_mut()
+                .filter(|record| record.status == WithdrawalStatus::Queued)
+                .collect();
+            queued.sort_by_key(|record| record.created_at);
+            for (position, record) in queued.into_iter().enumerate() {
+                record.queue_position = Some(position);
+            }
+        }
+
+        self.fill_core_statuses(&mut records);
+
+        records.sort_by(|a, b| {
+            a.status
+                .cmp(&b.status)
+                .then(b.updated_at.cmp(&a.updated_at))
+        });
+        Ok(records)
+    }
+
+    fn withdrawal_record(
+        &self,
+        document: &Document,
+        my_identity_ids: &BTreeSet<Identifier>,
+    ) -> Option<WithdrawalRecord> {
+        let status = document
+            .get("status")
+            .and_then(|value| value.to_integer::<u8>().ok())
+            .and_then(WithdrawalStatus::from_u8)?;
+        let amount = document
+            .get("amount")
+            .and_then(|value| value.to_integer::<u64>().ok())
+            .unwrap_or_default();
+        let transaction_index = document
+            .get("transactionIndex")
+            .and_then(|value| value.to_integer::<u64>().ok());
+        let address = document
+            .get("outputScript")
+            .and_then(|value| value.as_bytes())
+            .and_then(|bytes| {
+                Address::from_script(&ScriptBuf::from_bytes(bytes.clone()), self.network).ok()
+            })
+            .map(|address| address.to_string());
+        let owner_id = document.owner_id();
+        Some(WithdrawalRecord {
+            document_id: document.id(),
+            owner_id,
+            amount,
+            status,
+            transaction_index,
+            address,
+            created_at: document.created_at(),
+            updated_at: document.updated_at(),
+            is_mine: my_identity_ids.contains(&owner_id),
+            core_status: None,
+            queue_position: None,
+        })
+    }
+
+    /// Asks Core about the asset unlock transactions of pooled withdrawals, a Core node is
+    /// optional here so failures are only logged
+    fn fill_core_statuses(&self, records: &mut [WithdrawalRecord]) {
+        let indices: Vec<u64> = records
+            .iter()
+            .filter(|record| {
+                matches!(
+                    record.status,
+                    WithdrawalStatus::Broadcasted | WithdrawalStatus::Completed
+                )
+            })
+            .filter_map(|record| record.transaction_index)
+            .collect();
+        if indices.is_empty() {
+            return;
+        }
+        match self.core_client.get_asset_unlock_statuses(&indices, None) {
+            Ok(statuses) => {
+                let statuses: BTreeMap<u64, String> = statuses
+                    .into_iter()
+                    .map(|status| (status.index, format!("{:?}", status.status)))
+                    .collect();
+                for record in records.iter_mut() {
+                    if let Some(index) = record.transaction_index {
+                        record.core_status = statuses.get(&index).cloned();
+                    }
+                }
+            }
+            Err(e) => tracing::warn!("Failed to get asset unlock statuses from Core: {}", e),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::app::AppState;
+use crate::app_dir::{AppDirs, DEFAULT_PROFILE, PROFILE_FLAG};
+use eframe::egui::{self, Color32, RichText};
+use eframe::App;
+
+/// Asks which profile to open before anything of a profile is loaded, then runs the app on it.
+///
+/// The picker is skipped when there are no profiles besides the default one, or when a profile
+/// is named on the command line.
+pub struct ProfileLauncher {
+    root: AppDirs,
+    profiles: Vec<String>,
+    selected: String,
+    new_profile_name: String,
+    error: Option<String>,
+    app: Option<AppState>,
+}
+
+impl ProfileLauncher {
+    pub fn new() -> Self {
+        let root = AppDirs::resolve().expect("expected to create the data directory");
+        let profiles = root.profiles();
+        let selected = root
+            .last_profile()
+            .filter(|profile| profiles.contains(profile))
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+        let mut launcher = Self {
+            root,
+            profiles,
+            selected,
+            new_profile_name: String::new(),
+            error: None,
+            app: None,
+        };
+        match AppDirs::profile_from_args() {
+            Some(profile) => launcher.open(&profile),
+            None if launcher.profiles.len() == 1 => launcher.open(DEFAULT_PROFILE),
+            None => {}
+        }
+        launcher
+    }
+
+    fn open(&mut self, profile: &str) {
+        match self.root.for_profile(profile) {
+            Ok(app_dirs) => {
+                if let Err(e) = app_dirs.save_last_profile() {
+                    eprintln!("Failed to remember the profile: {}", e);
+                }
+                // An invalid configuration is shown here, so it can be fixed and opened again
+                match AppState::new(app_dirs) {
+                    Ok(app) => self.app = Some(app),
+                    Err(e) => {
+                        tracing::error!("Configuration error: {}", e);
+                        self.selected = profile.to_string();
+                        self.error = Some(format!("Can't open {}: {}", profile, e));
+                    }
+                }
+            }
+            Err(e) => {
+                self.selected = DEFAULT_PROFILE.to_string();
+                self.error = Some(e.to_string());
+            }
+        }
+    }
+
+    fn render_picker(&mut self, ctx: &egui::Context) {
+        let mut open = None;
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(40.0);
+                ui.heading("Choose a Profile");
+                ui.label(
+                    RichText::new(
+                        "Each profile has its own identities, wallets and settings, nothing is \
+                         shared between them.",
+                    )
+                    .color(Color32::GRAY),
+                );
+                ui.add_space(20.0);
+
+                for profile in &self.profiles {
+                    let response = ui.selectable_label(
+                        self.selected == *profile,
+                        RichText::new(profile).size(16.0),
+                    );
+                    if response.clicked() {
+                        self.selected = profile.clone();
+                    }
+                    if response.double_clicked() {
+                        open = Some(profile.clone());
+                    }
+                }
+
+                ui.add_space(10.0);
+                if ui.button(format!("Open {}", self.selected)).clicked() {
+                    open = Some(self.selected.clone());
+                }
+
+                ui.add_space(30.0);
+                ui.separator();
+                ui.label("New profile:");
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.new_profile_name)
+                            .hint_text("e.g. Company masternodes")
+                            .desired_width(220.0),
+                    );
+                    if ui.button("Create").clicked() {
+                        match self.root.create_profile(&self.new_profile_name) {
+                            Ok(app_dirs) => {
+                                self.profiles = self.root.profiles();
+                                self.selected = app_dirs.profile;
+                                self.new_profile_name.clear();
+                                self.error = None;
+                            }
+                            Err(e) => self.error = Some(e),
+                        }
+                    }
+                });
+                if let Some(error) = &self.error {
+                    ui.colored_label(Color32::DARK_RED, error);
+                }
+
+                ui.add_space(10.0);
+                ui.label(
+                    RichText::new(format!(
+                        "Starting with {} <name> opens a profile without asking.",
+                        PROFILE_FLAG
+                    ))
+                    .color(Color32::GRAY),
+                );
+            });
+        });
+        if let Some(profile) = open {
+            self.open(&profile);
+        }
+    }
+}
+
+impl App for ProfileLauncher {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        match &mut self.app {
+            Some(app) => app.update(ctx, frame),
+            None => self.render_picker(ctx),
+        }
+    }
+}
@@ -0,0 +1,485 @@
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// How many requests are kept for the diagnostics screen
+const MAX_RECORDED_REQUESTS: usize = 1000;
+
+/// Targets whose spans are DAPI requests made by the SDK
+const REQUEST_TARGETS: [&str; 2] = ["dash_sdk", "rs_dapi_client"];
+
+/// Targets whose spans are spent verifying proofs
+const PROOF_TARGETS: [&str; 1] = ["drive_proof_verifier"];
+
+/// What the proof of a response was verified against, as far as the SDK logs it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProofInfo {
+    /// Hex of the hash of the quorum that signed the state root
+    pub quorum_hash: Option<String>,
+    pub quorum_type: Option<u32>,
+    /// Platform height of the response metadata
+    pub height: Option<u64>,
+    /// Block time of the response metadata in milliseconds
+    pub time_ms: Option<u64>,
+}
+
+impl ProofInfo {
+    fn merge(&mut self, other: ProofInfo) {
+        self.quorum_hash = self.quorum_hash.take().or(other.quorum_hash);
+        self.quorum_type = self.quorum_type.or(other.quorum_type);
+        self.height = self.height.or(other.height);
+        self.time_ms = self.time_ms.or(other.time_ms);
+    }
+
+    /// Picks the values out of a field, either a number or the debug output of a struct such as
+    /// the response metadata
+    fn record(&mut self, name: &str, value: &str) {
+        for (key, target) in [("height", &mut self.height), ("time_ms", &mut self.time_ms)] {
+            if target.is_none() {
+                *target = field_number(name, value, key);
+            }
+        }
+        if self.quorum_type.is_none() {
+            self.quorum_type =
+                field_number(name, value, "quorum_type").and_then(|n| u32::try_from(n).ok());
+        }
+        if self.quorum_hash.is_none() {
+            let hash = if name == "quorum_hash" {
+                Some(value)
+            } else {
+                debug_field(value, "quorum_hash")
+            };
+            self.quorum_hash = hash.map(bytes_to_hex);
+        }
+    }
+}
+
+/// The number of the field `key`, given as the field itself or inside a debug struct
+fn field_number(name: &str, value: &str, key: &str) -> Option<u64> {
+    if name == key {
+        return value.trim().parse().ok();
+    }
+    debug_field(value, key)?.parse().ok()
+}
+
+/// The text after `key: ` up to the next field of a debug struct
+fn debug_field<'a>(text: &'a str, key: &str) -> Option<&'a str> {
+    let pattern = format!("{}: ", key);
+    let start = text
+        .match_indices(&pattern)
+        .find(|(i, _)| {
+            // Skip matches inside a longer name such as "core_chain_locked_height"
+            !text[..*i].ends_with(|c: char| c.is_alphanumeric() || c == '_')
+        })?
+        .0
+        + pattern.len();
+    let rest = &text[start..];
+    let end = if rest.starts_with('[') {
+        rest.find(']').map_or(rest.len(), |end| end + 1)
+    } else {
+        rest.find([',', ' ', '}', ')']).unwrap_or(rest.len())
+    };
+    Some(&rest[..end])
+}
+
+/// Turns a debug list of bytes such as `[1, 2, 255]` into hex, other values are kept
+fn bytes_to_hex(value: &str) -> String {
+    let Some(list) = value
+        .trim()
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+    else {
+        return value.trim().trim_matches('"').to_string();
+    };
+    let bytes: Option<Vec<u8>> = list
+        .split(',')
+        .map(|byte| byte.trim().parse::<u8>().ok())
+        .collect();
+    bytes.map_or_else(|| value.to_string(), hex::encode)
+}
+
+/// Timing of a single SDK request
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetric {
+    pub method: String,
+    pub endpoint: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub latency: Duration,
+    pub proof_verification: Duration,
+    pub retries: u32,
+    pub success: bool,
+    /// Set when the response came with a proof that was verified
+    pub proof: Option<ProofInfo>,
+}
+
+impl RequestMetric {
+    /// Time spent on the network, everything that is not proof verification
+    pub fn network_time(&self) -> Duration {
+        self.latency.saturating_sub(self.proof_verification)
+    }
+}
+
+/// Percentile summary of the requests of one method
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodSummary {
+    pub method: String,
+    pub count: usize,
+    pub errors: usize,
+    pub retries: u32,
+    pub latency_p50: Duration,
+    pub latency_p90: Duration,
+    pub latency_p99: Duration,
+    pub proof_p50: Duration,
+    /// Share of the total latency spent verifying proofs, from 0 to 1
+    pub proof_share: f64,
+}
+
+/// The most recent SDK requests, shared by the logger and the diagnostics screen
+#[derive(Debug, Default)]
+pub struct RequestMetrics {
+    requests: Mutex<VecDeque<RequestMetric>>,
+}
+
+impl RequestMetrics {
+    pub fn record(&self, metric: RequestMetric) {
+        let mut requests = self.requests.lock().unwrap();
+        if requests.len() == MAX_RECORDED_REQUESTS {
+            requests.pop_front();
+        }
+        requests.push_back(metric);
+    }
+
+    /// The recorded requests, most recent first
+    pub fn recent(&self, limit: usize) -> Vec<RequestMetric> {
+        let requests = self.requests.lock().unwrap();
+        requests.iter().rev().take(limit).cloned().collect()
+    }
+
+    pub fn clear(&self) {
+        self.requests.lock().unwrap().clear();
+    }
+
+    /// The most recent successful request whose method contains one of the patterns, compared
+    /// without case and punctuation so "contested_resource" matches "GetContestedResources"
+    pub fn last_response(&self, method_patterns: &[&str]) -> Option<RequestMetric> {
+        let normalize = |text: &str| {
+            text.chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+                .to_lowercase()
+        };
+        let patterns: Vec<String> = method_patterns.iter().map(|p| normalize(p)).collect();
+        let requests = self.requests.lock().unwrap();
+        requests
+            .iter()
+            .rev()
+            .find(|request| {
+                let method = normalize(&request.method);
+                request.success && patterns.iter().any(|pattern| method.contains(pattern))
+            })
+            .cloned()
+    }
+
+    /// The endpoint that most likely answered a failed task started at the given time: the one
+    /// of its most recent failed request, or of its most recent request when none was marked
+    /// failed. Requests of other tasks running at the same time can't be told apart, so this is
+    /// a best guess.
+    pub fn last_endpoint_since(&self, since: DateTime<Utc>) -> Option<String> {
+        let requests = self.requests.lock().unwrap();
+        let since_start: Vec<&RequestMetric> = requests
+            .iter()
+            .rev()
+            .filter(|request| request.started_at >= since && request.endpoint.is_some())
+            .collect();
+        since_start
+            .iter()
+            .find(|request| !request.success)
+            .or_else(|| since_start.first())
+            .and_then(|request| request.endpoint.clone())
+    }
+
+    /// Summaries per method, slowest median first
+    pub fn summaries(&self) -> Vec<MethodSummary> {
+        let requests = self.requests.lock().unwrap();
+        let mut by_method: BTreeMap<&str, Vec<&RequestMetric>> = BTreeMap::new();
+        for request in requests.iter() {
+            by_method.entry(&request.method).or_default().push(request);
+        }
+        let mut summaries: Vec<MethodSummary> = by_method
+            .into_iter()
+            .map(|(method, requests)| {
+                let mut latencies: Vec<Duration> =
+                    requests.iter().map(|request| request.latency).collect();
+                latencies.sort();
+                let mut proof_times: Vec<Duration> = requests
+                    .iter()
+                    .map(|request| request.proof_verification)
+                    .collect();
+                proof_times.sort();
+                let total_latency: Duration = latencies.iter().sum();
+                let total_proof: Duration = proof_times.iter().sum();
+                MethodSummary {
+                    method: method.to_string(),
+                    count: requests.len(),
+                    errors: requests.iter().filter(|request| !request.success).count(),
+                    retries: requests.iter().map(|request| request.retries).sum(),
+                    latency_p50: percentile(&latencies, 50),
+                    latency_p90: percentile(&latencies, 90),
+                    latency_p99: percentile(&latencies, 99),
+                    proof_p50: percentile(&proof_times, 50),
+                    proof_share: if total_latency.is_zero() {
+                        0.0
+                    } else {
+                        total_proof.as_secs_f64() / total_latency.as_secs_f64()
+                    },
+                }
+            })
+            .collect();
+        summaries.sort_by(|a, b| b.latency_p50.cmp(&a.latency_p50));
+        summaries
+    }
+}
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[Duration], percentile: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (percentile * sorted.len()).div_ceil(100).max(1);
+    sorted[rank.min(sorted.len()) - 1]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SpanKind {
+    Request,
+    Proof,
+}
+
+/// Stored in the extensions of the spans we track
+struct SpanTiming {
+    kind: SpanKind,
+    method: String,
+    endpoint: Option<String>,
+    started: Instant,
+    started_at: DateTime<Utc>,
+    proof_verification: Duration,
+    retries: u32,
+    failed: bool,
+    /// Whether a proof verifier span ran inside
+    verified: bool,
+    proof_info: ProofInfo,
+}
+
+/// Picks the endpoint, method and proof details out of span and event fields
+#[derive(Default)]
+struct FieldVisitor {
+    endpoint: Option<String>,
+    method: Option<String>,
+    message: Option<String>,
+    proof_info: ProofInfo,
+}
+
+impl Visit for FieldVisitor {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record_value(field, value.to_string());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record_value(field, value.to_string());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_value(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.record_value(field, format!("{:?}", value));
+    }
+}
+
+impl FieldVisitor {
+    fn record_value(&mut self, field: &Field, value: String) {
+        self.proof_info.record(field.name(), &value);
+        match field.name() {
+            "address" | "uri" | "endpoint" => self.endpoint = Some(value),
+            "method" | "request_name" => self.method = Some(value),
+            "message" => self.message = Some(value),
+            _ => {}
+        }
+    }
+}
+
+/// A tracing layer turning the spans of the SDK into [RequestMetric]s.
+///
+/// The outermost SDK span of a request is timed as a whole, proof verifier spans inside of it
+/// count as proof verification time and retry warnings inside of it as retries.
+pub struct RequestMetricsLayer {
+    metrics: Arc<RequestMetrics>,
+}
+
+impl RequestMetricsLayer {
+    pub fn new(metrics: Arc<RequestMetrics>) -> Self {
+        Self { metrics }
+    }
+
+    /// The SDK targets this layer needs to see, at every level
+    pub fn targets() -> Targets {
+        Targets::new().with_targets(
+            REQUEST_TARGETS
+                .iter()
+                .chain(PROOF_TARGETS.iter())
+                .map(|target| (*target, Level::TRACE)),
+        )
+    }
+}
+
+fn span_kind(target: &str, name: &str) -> Option<SpanKind> {
+    if PROOF_TARGETS.iter().any(|proof| target.starts_with(proof)) || name.contains("proof") {
+        Some(SpanKind::Proof)
+    } else if REQUEST_TARGETS
+        .iter()
+        .any(|request| target.starts_with(request))
+    {
+        Some(SpanKind::Request)
+    } else {
+        None
+    }
+}
+
+impl<S> Layer<S> for RequestMetricsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let metadata = attrs.metadata();
+        let Some(kind) = span_kind(metadata.target(), metadata.name()) else {
+            return;
+        };
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        span.extensions_mut().insert(SpanTiming {
+            kind,
+            method: visitor
+                .method
+                .unwrap_or_else(|| metadata.name().to_string()),
+            endpoint: visitor.endpoint,
+            started: Instant::now(),
+            started_at: Utc::now(),
+            proof_verification: Duration::ZERO,
+            retries: 0,
+            failed: false,
+            verified: kind == SpanKind::Proof,
+            proof_info: visitor.proof_info,
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        let Some(timing) = extensions.get_mut::<SpanTiming>() else {
+            return;
+        };
+        let mut visitor = FieldVisitor::default();
+        values.record(&mut visitor);
+        if let Some(endpoint) = visitor.endpoint {
+            timing.endpoint = Some(endpoint);
+        }
+        timing.proof_info.merge(visitor.proof_info);
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let Some(scope) = ctx.event_scope(event) else {
+            return;
+        };
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let is_retry = visitor
+            .message
+            .as_deref()
+            .is_some_and(|message| message.contains("retrying"));
+        let is_error = *event.metadata().level() == Level::ERROR;
+
+        // Attribute the event to the outermost request span
+        let Some(span) = scope
+            .from_root()
+            .find(|span| span.extensions().get::<SpanTiming>().is_some())
+        else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        let Some(timing) = extensions.get_mut::<SpanTiming>() else {
+            return;
+        };
+        if is_retry {
+            timing.retries += 1;
+        }
+        if is_error {
+            timing.failed = true;
+        }
+        if timing.endpoint.is_none() {
+            timing.endpoint = visitor.endpoint;
+        }
+        timing.proof_info.merge(visitor.proof_info);
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(timing) = span.extensions_mut().remove::<SpanTiming>() else {
+            return;
+        };
+        let elapsed = timing.started.elapsed();
+
+        // Nested spans add to the outermost tracked span, which becomes the request
+        let outer = span
+            .scope()
+            .skip(1)
+            .filter(|parent| parent.extensions().get::<SpanTiming>().is_some())
+            .last();
+        if let Some(outer) = outer {
+            if let Some(outer_timing) = outer.extensions_mut().get_mut::<SpanTiming>() {
+                if timing.kind == SpanKind::Proof {
+                    outer_timing.proof_verification += elapsed;
+                }
+                outer_timing.retries += timing.retries;
+                outer_timing.failed |= timing.failed;
+                outer_timing.verified |= timing.verified;
+                outer_timing.proof_info.merge(timing.proof_info);
+                if outer_timing.endpoint.is_none() {
+                    outer_timing.endpoint = timing.endpoint;
+                }
+            }
+            return;
+        }
+
+        let proof_verification = match timing.kind {
+            SpanKind::Proof => elapsed,
+            SpanKind::Request => timing.proof_verification,
+        };
+        self.metrics.record(RequestMetric {
+            method: timing.method,
+            endpoint: timing.endpoint,
+            started_at: timing.started_at,
+            latency: elapsed,
+            proof_verification,
+            retries: timing.retries,
+            success: !timing.failed,
+            proof: timing.verified.then_some(timing.proof_info),
+        });
+    }
+}
@@ -0,0 +1,95 @@
+use crate::context::AppContext;
+use crate::model::schedule::{ScheduledJob, ScheduledTask};
+use crate::platform::contested_names::ContestedResourceTask;
+use crate::platform::contract::ContractTask;
+use crate::platform::document::DocumentTask;
+use crate::platform::identity::IdentityTask;
+use crate::platform::BackendTask;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How often the schedule is looked through for due tasks
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Label of the snapshots taken by scheduled tasks
+pub const SCHEDULED_SNAPSHOT_LABEL: &str = "scheduled";
+
+/// Starts the scheduled tasks of the current network when their interval passed
+#[derive(Default)]
+pub struct Scheduler {
+    last_check: Option<Instant>,
+}
+
+impl Scheduler {
+    /// The enabled tasks whose interval passed since they last started, once per check
+    /// interval. Tasks still running from their last start are left alone.
+    pub fn due_tasks(&mut self, app_context: &AppContext) -> Vec<ScheduledTask> {
+        if self
+            .last_check
+            .is_some_and(|last_check| last_check.elapsed() < SCHEDULE_CHECK_INTERVAL)
+        {
+            return vec![];
+        }
+        self.last_check = Some(Instant::now());
+        let tasks = app_context.scheduled_tasks();
+        if tasks.is_empty() {
+            return vec![];
+        }
+        let runs: HashMap<u32, _> = match app_context.db.get_scheduled_runs(app_context) {
+            Ok(runs) => runs.into_iter().map(|run| (run.task_id, run)).collect(),
+            Err(e) => {
+                tracing::warn!("Failed to load the scheduled runs: {}", e);
+                return vec![];
+            }
+        };
+        let now = chrono::Utc::now().timestamp();
+        tasks
+            .into_iter()
+            .filter(|task| {
+                let run = runs.get(&task.id);
+                !run.is_some_and(|run| run.is_running())
+                    && task.is_due(run.map(|run| run.started_at), now)
+            })
+            .collect()
+    }
+}
+
+/// The backend task a scheduled job runs on the current network
+pub fn scheduled_backend_task(
+    job: &ScheduledJob,
+    app_context: &AppContext,
+) -> Result<BackendTask, String> {
+    Ok(match job {
+        ScheduledJob::RefreshContests => {
+            BackendTask::ContestedResourceTask(ContestedResourceTask::QueryDPNSContestedResources)
+        }
+        ScheduledJob::RefreshBalances => {
+            let identities = app_context
+                .load_local_qualified_identities()
+                .map_err(|e| format!("Failed to load the identities: {}", e))?;
+            if identities.is_empty() {
+                return Err("No identities are loaded".to_string());
+            }
+            BackendTask::IdentityTask(IdentityTask::RefreshIdentities(identities))
+        }
+        ScheduledJob::SnapshotContract { contract_id } => {
+            let id = Identifier::from_string(contract_id, Encoding::Base58)
+                .map_err(|_| format!("{} is not a contract id", contract_id))?;
+            let contract = app_context
+                .db
+                .get_contract_by_id(id, app_context)
+                .map_err(|e| format!("Failed to load the contract: {}", e))?
+                .ok_or_else(|| format!("The contract {} is not stored locally", contract_id))?;
+            BackendTask::DocumentTask(DocumentTask::SnapshotContract(
+                Arc::new(contract.contract),
+                SCHEDULED_SNAPSHOT_LABEL.to_string(),
+            ))
+        }
+        ScheduledJob::CheckContractUpdates => {
+            BackendTask::ContractTask(ContractTask::CheckContractUpdates)
+        }
+    })
+}
@@ -1,4 +1,5 @@
 use crate::config::NetworkConfig;
+use dash_sdk::dapi_grpc::tonic::transport::Certificate;
 use dash_sdk::dpp::dashcore::Network;
 use dash_sdk::dpp::version::PlatformVersion;
 use dash_sdk::platform::ContextProvider;
@@ -6,10 +7,52 @@ use dash_sdk::{RequestSettings, Sdk, SdkBuilder}; // Adjust imports
 use std::time::Duration;
 use tracing::info;
 
+/// The SDKs of a network, rebuilt together when the trusted certificates change
+#[derive(Debug, Clone)]
+pub struct NetworkSdks {
+    pub sdk: Sdk,
+    /// Same as `sdk` but without proofs, for queries run in fast mode
+    pub unproved_sdk: Sdk,
+    /// An SDK with proofs for each DAPI address on its own, to retry a query on another node
+    /// after a proof did not verify
+    pub endpoint_sdks: Vec<(String, Sdk)>,
+}
+
+/// Builds every SDK of a network with the same certificates
+pub fn initialize_network_sdks<P: ContextProvider + Clone + 'static>(
+    config: &NetworkConfig,
+    network: Network,
+    context_provider: P,
+    ca_certificates: Option<&[u8]>,
+) -> NetworkSdks {
+    NetworkSdks {
+        sdk: initialize_sdk(
+            config,
+            network,
+            context_provider.clone(),
+            true,
+            ca_certificates,
+        ),
+        unproved_sdk: initialize_sdk(
+            config,
+            network,
+            context_provider.clone(),
+            false,
+            ca_certificates,
+        ),
+        endpoint_sdks: initialize_endpoint_sdks(config, network, context_provider, ca_certificates),
+    }
+}
+
+/// Builds the SDK of a network. Without `proofs` responses are not verified, which is faster
+/// but trusts the DAPI node that answers. `ca_certificates` are PEM certificates trusted besides
+/// the public roots: the configured CA and, in insecure mode, the ones the nodes present.
 pub fn initialize_sdk<P: ContextProvider + 'static>(
     config: &NetworkConfig,
     network: Network,
     context_provider: P,
+    proofs: bool,
+    ca_certificates: Option<&[u8]>,
 ) -> Sdk {
     // Setup Platform SDK
     let address_list = config.dapi_address_list();
@@ -20,15 +63,46 @@ pub fn initialize_sdk<P: ContextProvider + 'static>(
         ban_failed_address: Some(false),
     };
 
-    let sdk = SdkBuilder::new(address_list)
+    let mut builder = SdkBuilder::new(address_list)
         .with_version(PlatformVersion::get(4).unwrap())
         .with_network(network)
         .with_context_provider(context_provider)
         .with_settings(request_settings)
-        .build()
-        .expect("Failed to build SDK");
+        .with_proofs(proofs);
+    if let Some(pem) = ca_certificates {
+        builder = builder.with_ca_certificate(Certificate::from_pem(pem));
+    }
+    let sdk = builder.build().expect("Failed to build SDK");
 
     info!("SDK initialized successfully");
 
     sdk
 }
+
+/// Builds an SDK with proofs for each DAPI address of the network on its own, so a query can be
+/// retried on another node when the proof of a node did not verify
+pub fn initialize_endpoint_sdks<P: ContextProvider + Clone + 'static>(
+    config: &NetworkConfig,
+    network: Network,
+    context_provider: P,
+    ca_certificates: Option<&[u8]>,
+) -> Vec<(String, Sdk)> {
+    config
+        .dapi_addresses
+        .split(',')
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .map(|address| {
+            let mut endpoint_config = config.clone();
+            endpoint_config.dapi_addresses = address.to_string();
+            let sdk = initialize_sdk(
+                &endpoint_config,
+                network,
+                context_provider.clone(),
+                true,
+                ca_certificates,
+            );
+            (address.to_string(), sdk)
+        })
+        .collect()
+}
@@ -0,0 +1,102 @@
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Opens a TCP connection to `host:port` through a SOCKS5 proxy without authentication. Host
+/// names are passed to the proxy so it resolves them, which keeps DNS lookups off the local
+/// network.
+pub async fn connect(proxy: &(String, u16), host: &str, port: u16) -> io::Result<TcpStream> {
+    let mut stream = TcpStream::connect((proxy.0.as_str(), proxy.1)).await?;
+    stream.write_all(&[5, 1, 0]).await?;
+    let mut choice = [0u8; 2];
+    stream.read_exact(&mut choice).await?;
+    if choice != [5, 0] {
+        return Err(io::Error::other(
+            "The proxy does not accept connections without authentication",
+        ));
+    }
+
+    let mut request = vec![5, 1, 0];
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(1);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(4);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            let name = host.as_bytes();
+            let length = u8::try_from(name.len())
+                .map_err(|_| io::Error::other("The host name is too long for SOCKS5"))?;
+            request.push(3);
+            request.push(length);
+            request.extend_from_slice(name);
+        }
+    }
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[1] != 0 {
+        return Err(io::Error::other(format!(
+            "The proxy could not connect to {}:{}: {}",
+            host,
+            port,
+            reply_message(reply[1])
+        )));
+    }
+    // Skip the address the proxy bound
+    let bound_length = match reply[3] {
+        1 => 4,
+        4 => 16,
+        3 => stream.read_u8().await? as usize,
+        _ => return Err(io::Error::other("The proxy sent an invalid reply")),
+    };
+    let mut bound = vec![0u8; bound_length + 2];
+    stream.read_exact(&mut bound).await?;
+    Ok(stream)
+}
+
+/// Listens on a local port and forwards each connection through the proxy to `host:port`, for
+/// clients that cannot use a proxy themselves. Must be called within the Tokio runtime.
+pub fn spawn_bridge(proxy: (String, u16), host: String, port: u16) -> io::Result<SocketAddr> {
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0))?;
+    listener.set_nonblocking(true)?;
+    let local_address = listener.local_addr()?;
+    let listener = TcpListener::from_std(listener)?;
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut inbound, _)) = listener.accept().await else {
+                continue;
+            };
+            let (proxy, host) = (proxy.clone(), host.clone());
+            tokio::spawn(async move {
+                match connect(&proxy, &host, port).await {
+                    Ok(mut outbound) => {
+                        let _ = tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await;
+                    }
+                    Err(e) => tracing::warn!("Proxy connection to {}:{} failed: {}", host, port, e),
+                }
+            });
+        }
+    });
+    Ok(local_address)
+}
+
+fn reply_message(code: u8) -> &'static str {
+    match code {
+        1 => "general failure",
+        2 => "not allowed by the proxy's rules",
+        3 => "network unreachable",
+        4 => "host unreachable",
+        5 => "connection refused",
+        6 => "TTL expired",
+        7 => "command not supported",
+        8 => "address type not supported",
+        _ => "unknown error",
+    }
+}
@@ -0,0 +1,65 @@
+use crate::context::AppContext;
+use crate::logging::session_log_files;
+use crate::model::settings_export::SettingsExport;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// How many sessions' JSON logs go into a bundle
+const BUNDLED_SESSIONS: usize = 3;
+
+/// Zips the recent logs, the diagnostics summary and the settings without secrets, for
+/// attaching to an issue. Returns a description of what was bundled.
+pub fn create_support_bundle(
+    app_context: &AppContext,
+    diagnostics: &str,
+    destination: &Path,
+) -> Result<String, String> {
+    let file = File::create(destination)
+        .map_err(|e| format!("Failed to create {}: {}", destination.display(), e))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut add = |name: &str, contents: &[u8]| -> Result<(), String> {
+        zip.start_file(name, options)
+            .and_then(|()| zip.write_all(contents).map_err(Into::into))
+            .map_err(|e| format!("Failed to add {} to the bundle: {}", name, e))
+    };
+
+    add("diagnostics.txt", diagnostics.as_bytes())?;
+
+    let mut settings = SettingsExport::collect(
+        app_context.preferences(),
+        &app_context.app_dirs.config_path(),
+    )?;
+    settings.redact_for_support();
+    add("settings.json", settings.to_json().as_bytes())?;
+
+    let mut logs = 0;
+    if let Ok(log) = std::fs::read(app_context.app_dirs.log_path()) {
+        add("logs/explorer.log", &log)?;
+        logs += 1;
+    }
+    for path in session_log_files(&app_context.app_dirs.logs_dir(), BUNDLED_SESSIONS) {
+        let Some(name) = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+        else {
+            continue;
+        };
+        let log = std::fs::read(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        add(&format!("logs/{}", name), &log)?;
+        logs += 1;
+    }
+
+    zip.finish()
+        .map_err(|e| format!("Failed to write {}: {}", destination.display(), e))?;
+    Ok(format!(
+        "Saved a support bundle with {} logs to {}. Check it before sharing, the logs can hold \
+         identity ids and node addresses.",
+        logs,
+        destination.display()
+    ))
+}
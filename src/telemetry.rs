@@ -0,0 +1,50 @@
+use crate::context::AppContext;
+use crate::model::telemetry::TelemetryReport;
+use dash_sdk::dpp::dashcore::Network;
+use std::sync::Arc;
+
+impl AppContext {
+    /// The report that would be sent now, from the counts since the last one
+    pub fn telemetry_report(&self) -> TelemetryReport {
+        let (unreported, _) = self.app_metrics.unreported();
+        TelemetryReport::new(telemetry_network(self.network), &unreported)
+    }
+
+    /// Posts the usage since the last report to the configured endpoint, in the background.
+    /// Nothing is sent while there is nothing to report.
+    pub fn send_telemetry(self: &Arc<Self>) {
+        let settings = self.preferences().telemetry;
+        let (unreported, snapshot) = self.app_metrics.unreported();
+        let report = TelemetryReport::new(telemetry_network(self.network), &unreported);
+        if !settings.enabled || report.is_empty() {
+            return;
+        }
+        let app_context = self.clone();
+        tokio::spawn(async move {
+            let body = serde_json::to_value(&report).expect("telemetry report serializes");
+            match app_context
+                .post_webhook(settings.endpoint.trim(), &body)
+                .await
+            {
+                Ok(()) => {
+                    app_context.app_metrics.mark_reported(snapshot);
+                    if let Err(e) = app_context.update_preferences(|preferences| {
+                        preferences.telemetry.last_sent_at = Some(chrono::Utc::now().timestamp())
+                    }) {
+                        tracing::warn!("Failed to save the telemetry time: {}", e);
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to send usage statistics: {}", e),
+            }
+        });
+    }
+}
+
+/// The network without anything that identifies a devnet
+fn telemetry_network(network: Network) -> &'static str {
+    match network {
+        Network::Dash => "mainnet",
+        Network::Testnet => "testnet",
+        _ => "other",
+    }
+}
@@ -0,0 +1,338 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::app_profile::{cell_text, AppProfile, AppProfileAction, AppProfileTable};
+use crate::model::document_revision::document_to_json;
+use crate::platform::document::DocumentTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::json_viewer::JsonViewer;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::display_scale::{apply_table_density, table_heights};
+use crate::ui::helpers::redaction::redact_id;
+use crate::ui::{MessageType, ScreenLike, ScreenType};
+use dash_sdk::dapi_grpc::platform::v0::get_documents_request::get_documents_request_v0::Start;
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::{DocumentQuery, Identifier};
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use egui_extras::{Column, TableBuilder};
+use std::sync::Arc;
+
+/// The admin panel of an app profile: a tab per configured document type, its documents in a
+/// table with the configured columns, and the actions the profile exposes
+pub struct AppProfileScreen {
+    pub app_context: Arc<AppContext>,
+    pub profile_name: String,
+    profile: Option<AppProfile>,
+    contract: Option<Arc<DataContract>>,
+    table_index: usize,
+    /// The fetched documents of the selected table as JSON
+    documents: Vec<serde_json::Value>,
+    /// Where the next page starts, `None` once the last page was fetched
+    start_after: Option<Identifier>,
+    needs_fetch: bool,
+    fetching: bool,
+    document_view: Option<JsonViewer>,
+    error_message: Option<String>,
+}
+
+impl AppProfileScreen {
+    pub fn new(profile_name: String, app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            profile_name,
+            profile: None,
+            contract: None,
+            table_index: 0,
+            documents: Vec::new(),
+            start_after: None,
+            needs_fetch: true,
+            fetching: false,
+            document_view: None,
+            error_message: None,
+        };
+        screen.reload();
+        screen
+    }
+
+    /// Reads the profile and its contract again, e.g. after the profile was edited
+    fn reload(&mut self) {
+        self.profile = self
+            .app_context
+            .app_profiles()
+            .into_iter()
+            .find(|profile| profile.name == self.profile_name);
+        let Some(profile) = &self.profile else {
+            self.contract = None;
+            self.error_message = Some(format!(
+                "There is no app profile named {} on this network",
+                self.profile_name
+            ));
+            return;
+        };
+        self.contract = Identifier::from_string(&profile.contract_id, Encoding::Base58)
+            .ok()
+            .and_then(|contract_id| {
+                self.app_context
+                    .db
+                    .get_contract_by_id(contract_id, &self.app_context)
+                    .ok()
+                    .flatten()
+            })
+            .map(|qualified_contract| Arc::new(qualified_contract.contract));
+        if self.contract.is_none() {
+            self.error_message = Some(format!(
+                "The contract {} is not stored locally, add it first",
+                profile.contract_id
+            ));
+        } else {
+            self.error_message = None;
+        }
+        if self.table_index >= profile.tables.len() {
+            self.table_index = 0;
+        }
+    }
+
+    fn table(&self) -> Option<&AppProfileTable> {
+        self.profile.as_ref()?.tables.get(self.table_index)
+    }
+
+    /// Fetches the first page of the selected table, or the next one with `more`
+    fn fetch(&mut self, more: bool) -> AppAction {
+        let (Some(contract), Some(table)) = (&self.contract, self.table()) else {
+            return AppAction::None;
+        };
+        let mut query = match DocumentQuery::new(contract.clone(), &table.document_type) {
+            Ok(query) => query,
+            Err(e) => {
+                self.error_message = Some(format!("Invalid document query: {}", e));
+                return AppAction::None;
+            }
+        };
+        query.limit = table.limit;
+        if more {
+            query.start = self.start_after.map(|id| Start::StartAfter(id.to_vec()));
+        } else {
+            self.documents.clear();
+            self.document_view = None;
+        }
+        self.fetching = true;
+        self.error_message = None;
+        AppAction::BackendTask(BackendTask::DocumentTask(DocumentTask::FetchDocuments(
+            query,
+        )))
+    }
+
+    fn render_tabs(&mut self, ui: &mut Ui) {
+        let Some(profile) = &self.profile else {
+            return;
+        };
+        let mut selected = self.table_index;
+        ui.horizontal_wrapped(|ui| {
+            for (index, table) in profile.tables.iter().enumerate() {
+                ui.selectable_value(&mut selected, index, table.title());
+            }
+        });
+        if selected != self.table_index {
+            self.table_index = selected;
+            self.start_after = None;
+            self.needs_fetch = true;
+        }
+    }
+
+    fn render_actions(&mut self, ui: &mut Ui, table: &AppProfileTable) -> AppAction {
+        let mut action = AppAction::None;
+        let Some(contract) = &self.contract else {
+            return action;
+        };
+        let contract_id = contract.id();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.fetching, egui::Button::new("Refresh"))
+                .clicked()
+            {
+                action = self.fetch(false);
+            }
+            if self.fetching {
+                ui.spinner();
+            }
+            let document_type = table.document_type.clone();
+            if table.has_action(AppProfileAction::Import)
+                && ui.button(AppProfileAction::Import.label()).clicked()
+            {
+                action = AppAction::AddScreen(
+                    ScreenType::ImportDocuments(contract_id, document_type.clone())
+                        .create_screen(&self.app_context),
+                );
+            }
+            if table.has_action(AppProfileAction::Delete)
+                && ui.button(AppProfileAction::Delete.label()).clicked()
+            {
+                action = AppAction::AddScreen(
+                    ScreenType::DeleteDocuments(contract_id, document_type)
+                        .create_screen(&self.app_context),
+                );
+            }
+            if table.has_action(AppProfileAction::Analytics)
+                && ui.button(AppProfileAction::Analytics.label()).clicked()
+            {
+                action = AppAction::AddScreen(
+                    ScreenType::ContractAnalytics(contract_id).create_screen(&self.app_context),
+                );
+            }
+        });
+        action
+    }
+
+    fn render_table(&mut self, ui: &mut Ui, table: &AppProfileTable) {
+        let privacy_mode = self.app_context.preferences().privacy_mode;
+        let density = self.app_context.preferences().table_density;
+        let viewable = table.has_action(AppProfileAction::View);
+        let mut viewed = None;
+        // Scoped so the compact style stays with the table
+        ui.scope(|ui| {
+            let (header_height, row_height) = table_heights(ui.ctx(), density, 25.0);
+            apply_table_density(ui, density);
+            let mut builder = TableBuilder::new(ui)
+                .striped(true)
+                .resizable(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .columns(Column::initial(150.0).clip(true), table.columns.len());
+            if viewable {
+                builder = builder.column(Column::auto());
+            }
+            builder
+                .header(header_height, |mut header| {
+                    for column in &table.columns {
+                        header.col(|ui| {
+                            ui.heading(column);
+                        });
+                    }
+                    if viewable {
+                        header.col(|ui| {
+                            ui.heading("Actions");
+                        });
+                    }
+                })
+                .body(|mut body| {
+                    for (index, document) in self.documents.iter().enumerate() {
+                        body.row(row_height, |mut row| {
+                            for column in &table.columns {
+                                row.col(|ui| {
+                                    let text = cell_text(document, column);
+                                    // Owners are identities, hidden like other identity ids
+                                    let text = if column == "$ownerId" {
+                                        redact_id(&text, privacy_mode)
+                                    } else {
+                                        text
+                                    };
+                                    ui.label(&text).on_hover_text(&text);
+                                });
+                            }
+                            if viewable {
+                                row.col(|ui| {
+                                    if ui.button(AppProfileAction::View.label()).clicked() {
+                                        viewed = Some(index);
+                                    }
+                                });
+                            }
+                        });
+                    }
+                });
+        });
+        if let Some(index) = viewed {
+            self.document_view = Some(JsonViewer::new(
+                format!("app_profile_document_{}", index),
+                self.documents[index].clone(),
+            ));
+        }
+    }
+}
+
+impl ScreenLike for AppProfileScreen {
+    fn refresh(&mut self) {
+        self.reload();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.fetching = false;
+            self.error_message = Some(message.to_string());
+        }
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if !self.fetching {
+            return;
+        }
+        if let BackendTaskSuccessResult::Documents(documents) = backend_task_success_result {
+            self.fetching = false;
+            let full_page = self
+                .table()
+                .is_some_and(|table| documents.len() >= table.limit as usize);
+            self.start_after = documents.keys().last().copied().filter(|_| full_page);
+            self.documents
+                .extend(documents.values().flatten().map(document_to_json));
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                (self.profile_name.as_str(), AppAction::None),
+            ],
+            vec![],
+        );
+
+        if self.needs_fetch && self.contract.is_some() {
+            self.needs_fetch = false;
+            action |= self.fetch(false);
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            if let Some(error) = &self.error_message {
+                ui.colored_label(Color32::RED, error);
+            }
+            if self.contract.is_none() {
+                return;
+            }
+            self.render_tabs(ui);
+            ui.separator();
+            let Some(table) = self.table().cloned() else {
+                return;
+            };
+            action |= self.render_actions(ui, &table);
+            ui.add_space(5.0);
+            if self.documents.is_empty() && !self.fetching {
+                ui.label(RichText::new("No documents").color(Color32::GRAY));
+            }
+            if let Some(viewer) = &mut self.document_view {
+                let mut close = false;
+                ui.horizontal(|ui| {
+                    ui.heading("Document");
+                    close = ui.button("Close").clicked();
+                });
+                viewer.show(ui);
+                ui.separator();
+                if close {
+                    self.document_view = None;
+                }
+            }
+            egui::ScrollArea::both().show(ui, |ui| {
+                self.render_table(ui, &table);
+                if self.start_after.is_some()
+                    && ui
+                        .add_enabled(!self.fetching, egui::Button::new("Load more"))
+                        .clicked()
+                {
+                    action |= self.fetch(true);
+                }
+            });
+        });
+
+        action
+    }
+}
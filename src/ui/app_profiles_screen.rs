@@ -0,0 +1,312 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::app_profile::AppProfile;
+use crate::model::qualified_contract::QualifiedContract;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{MessageType, ScreenLike, ScreenType};
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::sync::Arc;
+
+/// The name and JSON schema of each document type of the contract
+fn document_types(contract: &DataContract) -> Vec<(String, serde_json::Value)> {
+    contract
+        .document_types()
+        .iter()
+        .map(|(name, document_type)| {
+            let schema = serde_json::to_value(document_type.schema()).unwrap_or_default();
+            (name.clone(), schema)
+        })
+        .collect()
+}
+
+/// Registers app profiles: a contract with a description of which document types are shown as
+/// tables, with which columns and actions. Each profile gets an entry in the left panel that
+/// opens it as an admin panel.
+pub struct AppProfilesScreen {
+    pub app_context: Arc<AppContext>,
+    profiles: Vec<AppProfile>,
+    contracts: Vec<QualifiedContract>,
+    contract_index: usize,
+    /// The description being written, as JSON
+    editor: String,
+    /// Name of the saved profile the editor replaces
+    editing: Option<String>,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl AppProfilesScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            profiles: Vec::new(),
+            contracts: app_context.get_contracts(None, None).unwrap_or_default(),
+            contract_index: 0,
+            editor: String::new(),
+            editing: None,
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    /// Starts a profile of the contract, with a table per document type
+    pub fn with_contract(mut self, contract_id: Identifier) -> Self {
+        if let Some(index) = self
+            .contracts
+            .iter()
+            .position(|contract| contract.contract.id() == contract_id)
+        {
+            self.contract_index = index;
+            self.generate();
+        }
+        self
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    fn contract_name(contract: &QualifiedContract) -> String {
+        contract
+            .alias
+            .clone()
+            .unwrap_or_else(|| contract.contract.id().to_string(Encoding::Base58))
+    }
+
+    /// Fills the editor with a profile of the selected contract showing all its document types
+    fn generate(&mut self) {
+        let Some(contract) = self.contracts.get(self.contract_index) else {
+            return;
+        };
+        let profile = AppProfile::template(
+            &Self::contract_name(contract),
+            &contract.contract.id().to_string(Encoding::Base58),
+            &document_types(&contract.contract),
+        );
+        self.editor = profile.to_json();
+        self.editing = None;
+    }
+
+    /// Checks the description in the editor against its contract and stores it
+    fn save(&mut self) -> Result<String, String> {
+        let mut profile = AppProfile::from_json(&self.editor)?;
+        profile.name = profile.name.trim().to_string();
+        let contract_id = Identifier::from_string(&profile.contract_id, Encoding::Base58)
+            .map_err(|_| format!("{} is not a contract id", profile.contract_id))?;
+        let contract = self
+            .contracts
+            .iter()
+            .find(|contract| contract.contract.id() == contract_id)
+            .ok_or("The contract is not stored locally, add it first")?;
+        profile.validate(&document_types(&contract.contract))?;
+        profile.network = self.app_context.network_string();
+
+        let replaced = self.editing.clone().unwrap_or_else(|| profile.name.clone());
+        if profile.name != replaced && self.profiles.iter().any(|saved| saved.name == profile.name)
+        {
+            return Err(format!("There already is a profile named {}", profile.name));
+        }
+        let name = profile.name.clone();
+        self.app_context
+            .update_preferences(|preferences| {
+                let profiles = &mut preferences.app_profiles;
+                match profiles
+                    .iter_mut()
+                    .find(|saved| saved.network == profile.network && saved.name == replaced)
+                {
+                    Some(saved) => *saved = profile,
+                    None => profiles.push(profile),
+                }
+            })
+            .map_err(|e| format!("Failed to save the profile: {}", e))?;
+        self.editing = Some(name.clone());
+        self.refresh();
+        Ok(format!("Saved the app profile {}", name))
+    }
+
+    fn remove(&mut self, name: &str) {
+        let network = self.app_context.network_string();
+        if let Err(e) = self.app_context.update_preferences(|preferences| {
+            preferences
+                .app_profiles
+                .retain(|profile| profile.network != network || profile.name != name)
+        }) {
+            self.display_message(
+                &format!("Failed to remove the profile: {}", e),
+                MessageType::Error,
+            );
+            return;
+        }
+        if self.editing.as_deref() == Some(name) {
+            self.editing = None;
+        }
+        self.refresh();
+    }
+
+    fn render_profiles(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("Profiles");
+        if self.profiles.is_empty() {
+            ui.label("No app profiles on this network yet.");
+        }
+        let mut to_edit = None;
+        let mut to_remove = None;
+        for profile in &self.profiles {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(&profile.name).strong());
+                ui.label(
+                    RichText::new(format!(
+                        "{}, {} tables",
+                        profile.contract_id,
+                        profile.tables.len()
+                    ))
+                    .color(Color32::GRAY),
+                );
+                if ui.button("Open").clicked() {
+                    action = AppAction::AddScreen(
+                        ScreenType::AppProfile(profile.name.clone())
+                            .create_screen(&self.app_context),
+                    );
+                }
+                if ui.button("Edit").clicked() {
+                    to_edit = Some(profile.clone());
+                }
+                if ui.button("Remove").clicked() {
+                    to_remove = Some(profile.name.clone());
+                }
+            });
+        }
+        if let Some(profile) = to_edit {
+            self.editor = profile.to_json();
+            self.editing = Some(profile.name);
+        }
+        if let Some(name) = to_remove {
+            self.remove(&name);
+        }
+        action
+    }
+
+    fn render_editor(&mut self, ui: &mut Ui) {
+        ui.heading(match &self.editing {
+            Some(name) => format!("Edit {}", name),
+            None => "New Profile".to_string(),
+        });
+        ui.label(
+            RichText::new(
+                "Tables list the document types to show, `columns` the properties shown in \
+                 order, `$id`, `$ownerId`, `$revision`, `$createdAt` and `$updatedAt` \
+                 included. `actions` can hold \"view\", \"import\", \"delete\" and \
+                 \"analytics\".",
+            )
+            .color(Color32::GRAY),
+        );
+        ui.horizontal(|ui| {
+            ui.label("Contract:");
+            let selected = self
+                .contracts
+                .get(self.contract_index)
+                .map(Self::contract_name)
+                .unwrap_or_default();
+            egui::ComboBox::from_id_salt("app_profile_contract")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    for (index, contract) in self.contracts.iter().enumerate() {
+                        ui.selectable_value(
+                            &mut self.contract_index,
+                            index,
+                            Self::contract_name(contract),
+                        );
+                    }
+                });
+            if ui
+                .add_enabled(
+                    !self.contracts.is_empty(),
+                    egui::Button::new("Generate from contract"),
+                )
+                .on_hover_text("Replaces the description with a table per document type")
+                .clicked()
+            {
+                self.generate();
+            }
+        });
+        ui.add(
+            egui::TextEdit::multiline(&mut self.editor)
+                .desired_rows(16)
+                .desired_width(f32::INFINITY)
+                .code_editor(),
+        );
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.editor.trim().is_empty(), egui::Button::new("Save"))
+                .clicked()
+            {
+                match self.save() {
+                    Ok(message) => self.display_message(&message, MessageType::Success),
+                    Err(e) => self.display_message(&e, MessageType::Error),
+                }
+            }
+            if ui.button("Clear").clicked() {
+                self.editor.clear();
+                self.editing = None;
+            }
+        });
+    }
+}
+
+impl ScreenLike for AppProfilesScreen {
+    fn refresh(&mut self) {
+        self.profiles = self.app_context.app_profiles();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("App Profiles", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.label(
+                    "An app profile turns a contract into an admin panel: the document types \
+                     you pick are shown as tables in an entry of the left panel.",
+                );
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => ui.visuals().text_color(),
+                    };
+                    ui.colored_label(color, message);
+                }
+                ui.add_space(10.0);
+                action |= self.render_profiles(ui);
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_editor(ui);
+            });
+        });
+
+        action
+    }
+}
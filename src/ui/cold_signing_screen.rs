@@ -0,0 +1,356 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::cold_signing::{
+    ColdSigningFile, ColdSigningRecord, ColdSigningStatus, ColdWithdrawal,
+};
+use crate::platform::identity::IdentityTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::json_viewer::JsonViewer;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::time_format::TimeFormatter;
+use crate::ui::identities::add_new_identity_screen::{copy_to_clipboard, generate_qr_code_image};
+use crate::ui::{MessageType, ScreenLike};
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::sync::Arc;
+
+/// Moves transitions between an online machine without the keys and an offline machine with
+/// them. The online machine exports an unsigned transition, the offline machine imports,
+/// signs and exports it, and the online machine imports and broadcasts it.
+pub struct ColdSigningScreen {
+    pub app_context: Arc<AppContext>,
+    file: Option<ColdSigningFile>,
+    /// The decoded transition of the file, to check before signing
+    transition_viewer: Option<JsonViewer>,
+    /// What the transition of the file withdraws, `None` if it isn't a valid withdrawal
+    withdrawal: Option<ColdWithdrawal>,
+    import_path_input: String,
+    import_text_input: String,
+    export_path_input: String,
+    records: Vec<ColdSigningRecord>,
+    broadcasting: bool,
+    message: Option<(String, MessageType)>,
+}
+
+impl ColdSigningScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            file: None,
+            transition_viewer: None,
+            withdrawal: None,
+            import_path_input: String::new(),
+            import_text_input: String::new(),
+            export_path_input: String::new(),
+            records: Vec::new(),
+            broadcasting: false,
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    /// Opens the screen with a file, e.g. an unsigned transition just built
+    pub fn with_file(mut self, file: ColdSigningFile) -> Self {
+        self.set_file(file);
+        self
+    }
+
+    fn set_file(&mut self, file: ColdSigningFile) {
+        let decoded = file.decode_withdrawal(self.app_context.network).and_then(
+            |(transition, withdrawal)| {
+                JsonViewer::from_serializable("cold_signing_transition", &transition)
+                    .map(|viewer| (viewer, withdrawal))
+            },
+        );
+        (self.transition_viewer, self.withdrawal) = match decoded {
+            Ok((viewer, withdrawal)) => (Some(viewer), Some(withdrawal)),
+            Err(e) => {
+                self.message = Some((e, MessageType::Error));
+                (None, None)
+            }
+        };
+        let suffix = if file.signed { "signed" } else { "unsigned" };
+        self.export_path_input = format!(
+            "cold-signing-{}-{}.json",
+            &file.request_id[..file.request_id.len().min(8)],
+            suffix
+        );
+        self.file = Some(file);
+    }
+
+    fn import(&mut self, json: &str) {
+        match ColdSigningFile::from_json(json) {
+            Ok(file) => {
+                self.message = None;
+                self.import_text_input.clear();
+                self.set_file(file);
+            }
+            Err(e) => self.message = Some((e, MessageType::Error)),
+        }
+    }
+
+    fn render_import(&mut self, ui: &mut Ui) {
+        ui.heading("Import");
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            ui.text_edit_singleline(&mut self.import_path_input);
+            if ui.button("Load File").clicked() {
+                match std::fs::read_to_string(self.import_path_input.trim()) {
+                    Ok(json) => self.import(&json),
+                    Err(e) => {
+                        self.message = Some((
+                            format!("Failed to read {}: {}", self.import_path_input.trim(), e),
+                            MessageType::Error,
+                        ))
+                    }
+                }
+            }
+        });
+        ui.label("Or paste the text of a file, e.g. scanned from its QR code:");
+        ui.add(
+            egui::TextEdit::multiline(&mut self.import_text_input)
+                .desired_rows(3)
+                .desired_width(ui.available_width())
+                .code_editor(),
+        );
+        if ui
+            .add_enabled(
+                !self.import_text_input.trim().is_empty(),
+                egui::Button::new("Load Text"),
+            )
+            .clicked()
+        {
+            let json = self.import_text_input.clone();
+            self.import(&json);
+        }
+    }
+
+    fn render_file(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        let Some(file) = self.file.clone() else {
+            return action;
+        };
+        ui.heading("Transition");
+        egui::Grid::new("cold_signing_file_grid")
+            .num_columns(2)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("Withdrawal:");
+                match &self.withdrawal {
+                    Some(withdrawal) => {
+                        ui.label(RichText::new(withdrawal.description()).strong());
+                    }
+                    None => {
+                        ui.colored_label(Color32::RED, "Not a valid withdrawal of this identity");
+                    }
+                }
+                ui.end_row();
+                ui.label("Identity:");
+                ui.label(&file.identity_id);
+                ui.end_row();
+                ui.label("Key:");
+                ui.label(file.key_id.to_string());
+                ui.end_row();
+                ui.label("Network:");
+                ui.label(&file.network);
+                ui.end_row();
+                ui.label("Request:");
+                ui.label(RichText::new(&file.request_id).monospace());
+                ui.end_row();
+                ui.label("State:");
+                ui.label(if file.signed { "Signed" } else { "Unsigned" });
+                ui.end_row();
+            });
+        if let Some(viewer) = &mut self.transition_viewer {
+            egui::CollapsingHeader::new("Decoded transition")
+                .id_salt("cold_signing_decoded")
+                .show(ui, |ui| viewer.show(ui));
+        }
+
+        ui.add_space(5.0);
+        ui.horizontal(|ui| {
+            if !file.signed {
+                if ui
+                    .add_enabled(self.withdrawal.is_some(), egui::Button::new("Sign"))
+                    .on_hover_text("Sign with the identity's key on this machine")
+                    .clicked()
+                {
+                    match self.app_context.sign_cold_transition(&file) {
+                        Ok(signed) => {
+                            self.message = Some((
+                                "Signed, export the file and take it to the online machine".into(),
+                                MessageType::Success,
+                            ));
+                            self.set_file(signed);
+                            self.refresh();
+                        }
+                        Err(e) => self.message = Some((e, MessageType::Error)),
+                    }
+                }
+            } else if ui
+                .add_enabled(
+                    !self.broadcasting && self.withdrawal.is_some(),
+                    egui::Button::new("Broadcast"),
+                )
+                .clicked()
+            {
+                self.broadcasting = true;
+                action = AppAction::BackendTask(BackendTask::IdentityTask(
+                    IdentityTask::BroadcastColdSigned(file.clone()),
+                ));
+            }
+            if self.broadcasting {
+                ui.spinner();
+            }
+        });
+
+        ui.add_space(10.0);
+        ui.label(RichText::new("Export").strong());
+        let json = file.to_json();
+        ui.horizontal(|ui| {
+            ui.label("Save to:");
+            ui.text_edit_singleline(&mut self.export_path_input);
+            if ui.button("Save File").clicked() {
+                let path = self.export_path_input.trim().to_string();
+                self.message = Some(match std::fs::write(&path, &json) {
+                    Ok(()) => (format!("Saved to {}", path), MessageType::Success),
+                    Err(e) => (
+                        format!("Failed to write {}: {}", path, e),
+                        MessageType::Error,
+                    ),
+                });
+            }
+            if ui.button("Copy").clicked() {
+                if let Err(e) = copy_to_clipboard(&json) {
+                    self.message = Some((
+                        format!("Failed to copy to clipboard: {}", e),
+                        MessageType::Error,
+                    ));
+                }
+            }
+        });
+        match generate_qr_code_image(&json) {
+            Ok(image) => {
+                let texture = ui.ctx().load_texture(
+                    "cold_signing_qr_code",
+                    image,
+                    egui::TextureOptions::LINEAR,
+                );
+                ui.image(&texture);
+            }
+            Err(_) => {
+                ui.label("The transition is too large for a QR code, move it as a file.");
+            }
+        }
+        action
+    }
+
+    fn render_activity(&self, ui: &mut Ui) {
+        ui.heading("Activity");
+        if self.records.is_empty() {
+            ui.label("No cold signed transitions yet.");
+            return;
+        }
+        let time_format = TimeFormatter::new(&self.app_context.preferences());
+        egui::Grid::new("cold_signing_activity_grid")
+            .num_columns(4)
+            .spacing([15.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Updated").strong());
+                ui.label(RichText::new("Transition").strong());
+                ui.label(RichText::new("Identity").strong());
+                ui.label(RichText::new("Status").strong());
+                ui.end_row();
+                for record in &self.records {
+                    ui.label(time_format.format_seconds(record.updated_at.max(0) as u64));
+                    ui.label(&record.description);
+                    ui.label(&record.identity_id);
+                    let color = match record.status {
+                        ColdSigningStatus::Broadcast => Color32::DARK_GREEN,
+                        ColdSigningStatus::Failed => Color32::RED,
+                        _ => ui.visuals().text_color(),
+                    };
+                    let status = ui.colored_label(color, record.status.label());
+                    if let Some(error) = &record.error {
+                        status.on_hover_text(error);
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+}
+
+impl ScreenLike for ColdSigningScreen {
+    fn refresh(&mut self) {
+        match self
+            .app_context
+            .db
+            .get_cold_signing_records(&self.app_context)
+        {
+            Ok(records) => self.records = records,
+            Err(e) => {
+                self.message = Some((
+                    format!("Failed to load the activity: {}", e),
+                    MessageType::Error,
+                ))
+            }
+        }
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if self.broadcasting {
+            self.broadcasting = false;
+            self.refresh();
+        }
+        self.message = Some((message.to_string(), message_type));
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::ColdSigningFile(file) = backend_task_success_result {
+            self.set_file(file);
+            self.refresh();
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Cold Signing", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.label(
+                    RichText::new(
+                        "Withdrawals from an identity whose keys are on an offline machine are \
+                         built here unsigned, signed on the offline machine and broadcast from \
+                         here.",
+                    )
+                    .color(Color32::GRAY),
+                );
+                if let Some((message, message_type)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => Color32::GRAY,
+                    };
+                    ui.colored_label(color, message);
+                }
+                ui.add_space(10.0);
+                self.render_import(ui);
+                ui.separator();
+                action |= self.render_file(ui);
+                ui.separator();
+                self.render_activity(ui);
+            });
+        });
+
+        action
+    }
+}
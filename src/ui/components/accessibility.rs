@@ -0,0 +1,27 @@
+use egui::{Context, Key, Modifiers, Response, Ui, WidgetInfo, WidgetType};
+
+/// Gives the widget the name screen readers announce, for widgets whose visible text alone
+/// does not say what they do, such as icons and vote counts
+pub fn with_accessible_label(
+    response: Response,
+    widget_type: WidgetType,
+    label: impl Into<String>,
+) -> Response {
+    let label = label.into();
+    let enabled = response.enabled();
+    response.widget_info(|| WidgetInfo::labeled(widget_type, enabled, &label));
+    response
+}
+
+/// A checkbox without visible text, such as a table row selection, announced with the label
+pub fn unlabeled_checkbox(ui: &mut Ui, checked: &mut bool, label: &str) -> Response {
+    let response = ui.checkbox(checked, "").on_hover_text(label);
+    let (enabled, selected) = (response.enabled(), *checked);
+    response.widget_info(|| WidgetInfo::selected(WidgetType::Checkbox, enabled, selected, label));
+    response
+}
+
+/// Whether Escape was pressed this frame, consuming it so only one popup closes
+pub fn escape_pressed(ctx: &Context) -> bool {
+    ctx.input_mut(|input| input.consume_key(Modifiers::NONE, Key::Escape))
+}
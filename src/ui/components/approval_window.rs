@@ -0,0 +1,239 @@
+use crate::context::AppContext;
+use crate::model::approval::{ApprovalPolicy, AuditEntry};
+use crate::model::transition_sandbox::{transition_credit_spending, SandboxTransitionKind};
+use crate::platform::contract::ContractTask;
+use crate::platform::identity::IdentityTask;
+use crate::platform::BackendTask;
+use crate::ui::helpers::number_format::NumberFormatter;
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::prelude::Identifier;
+use eframe::egui::{self, Color32, Context};
+
+/// What needs the approver's confirmation about the task under the policy, `None` if nothing
+pub(crate) fn approval_reason(
+    task: &BackendTask,
+    policy: &ApprovalPolicy,
+    number_format: &NumberFormatter,
+) -> Option<String> {
+    if let Some(spending) = credit_spending(task) {
+        return policy
+            .withdrawal_threshold
+            .filter(|threshold| spending.credits > *threshold)
+            .map(|threshold| {
+                format!(
+                    "{} of {} from {}, above the limit of {}",
+                    spending.kind,
+                    number_format.format_credits_as_dash(spending.credits),
+                    spending.from,
+                    number_format.format_credits_as_dash(threshold)
+                )
+            });
+    }
+    match task {
+        // What else a raw transition does isn't checked, so any policy covers it
+        BackendTask::IdentityTask(IdentityTask::BroadcastRawTransition(transition))
+            if policy.is_enabled() =>
+        {
+            Some(format!(
+                "Broadcasting a raw {} transition from the sandbox",
+                SandboxTransitionKind::of(transition).label().to_lowercase()
+            ))
+        }
+        BackendTask::IdentityTask(
+            IdentityTask::AddKeyToIdentity(qualified_identity, ..)
+            | IdentityTask::UpdateIdentityKeys(qualified_identity, ..),
+        ) if policy.key_changes => Some(format!(
+            "Changing the keys of {}",
+            qualified_identity.display_string()
+        )),
+        BackendTask::ContractTask(ContractTask::RefreshContract(contract_id))
+            if policy.contract_updates =>
+        {
+            Some(format!(
+                "Updating contract {} to its version on Platform",
+                contract_id.to_string(Encoding::Base58)
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Credits a task moves out of an identity
+struct CreditSpending {
+    kind: &'static str,
+    identity_id: Identifier,
+    /// How the identity is shown to the user
+    from: String,
+    credits: Credits,
+}
+
+/// What the task spends, from which identity and how many credits, for tasks that move
+/// credits out of an identity. Raw transfers and withdrawals from the sandbox count too.
+fn credit_spending(task: &BackendTask) -> Option<CreditSpending> {
+    let (kind, qualified_identity, credits) = match task {
+        BackendTask::IdentityTask(
+            IdentityTask::WithdrawFromIdentity(qualified_identity, _, credits, ..)
+            | IdentityTask::PrepareColdWithdrawal(qualified_identity, _, credits, _),
+        ) => ("Withdrawal", qualified_identity, credits),
+        BackendTask::IdentityTask(IdentityTask::TransferCredits(
+            qualified_identity,
+            _,
+            credits,
+            ..,
+        )) => ("Transfer", qualified_identity, credits),
+        BackendTask::IdentityTask(IdentityTask::BroadcastRawTransition(transition)) => {
+            let (kind, identity_id, credits) = transition_credit_spending(transition)?;
+            return Some(CreditSpending {
+                kind,
+                identity_id,
+                from: identity_id.to_string(Encoding::Base58),
+                credits,
+            });
+        }
+        _ => return None,
+    };
+    Some(CreditSpending {
+        kind,
+        identity_id: qualified_identity.identity.id(),
+        from: qualified_identity.display_string(),
+        credits: *credits,
+    })
+}
+
+/// The identity's daily spending limit and what it spent of it in the last 24 hours
+pub(crate) fn daily_allowance(
+    app_context: &AppContext,
+    identity_id: &Identifier,
+) -> Option<(Credits, Credits)> {
+    let limit = app_context
+        .preferences()
+        .spending_limits
+        .limit_for(&identity_id.to_string(Encoding::Base58))?;
+    let spent = app_context
+        .db
+        .get_credits_spent_today(identity_id, app_context)
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to load spent credits: {}", e);
+            0
+        });
+    Some((limit, spent))
+}
+
+/// Why the task goes over the daily spending limit of its identity, `None` if it does not
+pub(crate) fn spending_limit_excess(
+    task: &BackendTask,
+    app_context: &AppContext,
+    number_format: &NumberFormatter,
+) -> Option<String> {
+    let spending = credit_spending(task)?;
+    let (limit, spent) = daily_allowance(app_context, &spending.identity_id)?;
+    (spent.saturating_add(spending.credits) > limit).then(|| {
+        format!(
+            "{} of {} from {} goes over its daily limit of {}, {} were already spent today",
+            spending.kind,
+            number_format.format_credits_as_dash(spending.credits),
+            spending.from,
+            number_format.format_credits_as_dash(limit),
+            number_format.format_credits_as_dash(spent)
+        )
+    })
+}
+
+pub(crate) enum ApprovalOutcome {
+    Pending,
+    Approved(BackendTask),
+    Rejected,
+}
+
+/// A task held back until the approver confirms it with their password
+pub(crate) struct ApprovalRequest {
+    task: BackendTask,
+    reason: String,
+    password_input: String,
+    error: Option<String>,
+    /// The password field takes the focus once, when the window opens
+    focus_requested: bool,
+}
+
+impl ApprovalRequest {
+    pub(crate) fn new(task: BackendTask, reason: String) -> Self {
+        Self {
+            task,
+            reason,
+            password_input: String::new(),
+            error: None,
+            focus_requested: false,
+        }
+    }
+
+    fn log(&self, app_context: &AppContext, outcome: &str, approver: Option<String>) {
+        let entry = AuditEntry {
+            action: self.reason.clone(),
+            outcome: outcome.to_string(),
+            approver,
+            logged_at: chrono::Utc::now().timestamp(),
+        };
+        if let Err(e) = app_context.db.insert_audit_entry(&entry, app_context) {
+            tracing::error!("Failed to write the audit trail: {}", e);
+        }
+    }
+
+    /// Shows the request, every decision and failed attempt goes to the audit trail
+    pub(crate) fn show(&mut self, ctx: &Context, app_context: &AppContext) -> ApprovalOutcome {
+        let mut outcome = ApprovalOutcome::Pending;
+        let mut approve = false;
+        let mut reject = false;
+        egui::Window::new("Approval Required")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(&self.reason);
+                ui.add_space(5.0);
+                ui.label("The approver has to confirm this action with their password.");
+                ui.horizontal(|ui| {
+                    ui.label("Approver password:");
+                    let response =
+                        ui.add(egui::TextEdit::singleline(&mut self.password_input).password(true));
+                    if !self.focus_requested {
+                        response.request_focus();
+                        self.focus_requested = true;
+                    }
+                    approve =
+                        response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                });
+                if let Some(error) = &self.error {
+                    ui.colored_label(Color32::RED, error);
+                }
+                ui.horizontal(|ui| {
+                    approve |= ui.button("Approve").clicked();
+                    reject = ui.button("Reject").clicked();
+                });
+            });
+
+        if reject {
+            self.log(app_context, "Rejected", None);
+            outcome = ApprovalOutcome::Rejected;
+        } else if approve {
+            match app_context.db.get_approver() {
+                Ok(Some(approver)) if approver.verify(&self.password_input) => {
+                    self.log(app_context, "Approved", Some(approver.name));
+                    outcome = ApprovalOutcome::Approved(self.task.clone());
+                }
+                Ok(Some(_)) => {
+                    self.log(app_context, "Wrong approver password", None);
+                    self.password_input.clear();
+                    self.error = Some("Wrong password".to_string());
+                }
+                Ok(None) => {
+                    self.error =
+                        Some("No approver is set up, set one in Settings first".to_string());
+                }
+                Err(e) => self.error = Some(format!("Failed to load the approver: {}", e)),
+            }
+        }
+        outcome
+    }
+}
@@ -0,0 +1,126 @@
+use egui::{pos2, vec2, Align2, FontId, Rect, Sense, Shape, Stroke, Ui};
+
+const BAR_HEIGHT: f32 = 16.0;
+const LABEL_WIDTH: f32 = 160.0;
+
+/// Horizontal bars, one row per label, scaled to the largest value
+pub fn bar_chart(ui: &mut Ui, bars: &[(String, f64)], value_text: impl Fn(f64) -> String) {
+    let max = bars.iter().map(|(_, value)| *value).fold(0.0, f64::max);
+    let color = ui.visuals().selection.bg_fill;
+    let text_color = ui.visuals().text_color();
+    for (label, value) in bars {
+        ui.horizontal(|ui| {
+            ui.add_sized(
+                [LABEL_WIDTH, BAR_HEIGHT],
+                egui::Label::new(label).truncate(),
+            )
+            .on_hover_text(label);
+            let width = (ui.available_width() - 10.0).max(50.0);
+            let (rect, response) = ui.allocate_exact_size(vec2(width, BAR_HEIGHT), Sense::hover());
+            let fraction = if max > 0.0 { (value / max) as f32 } else { 0.0 };
+            let bar = Rect::from_min_size(rect.min, vec2(rect.width() * fraction, rect.height()));
+            let painter = ui.painter();
+            painter.rect_filled(bar, 2.0, color);
+            painter.text(
+                pos2(rect.min.x + 4.0, rect.center().y),
+                Align2::LEFT_CENTER,
+                value_text(*value),
+                FontId::proportional(11.0),
+                text_color,
+            );
+            response.on_hover_text(format!("{}: {}", label, value_text(*value)));
+        });
+    }
+}
+
+/// A line through the points, ordered by x, with the x and y ranges written at the axes.
+/// Hovering shows the nearest point.
+pub fn line_chart(
+    ui: &mut Ui,
+    points: &[(i64, f64)],
+    height: f32,
+    x_text: impl Fn(i64) -> String,
+    y_text: impl Fn(f64) -> String,
+) {
+    let width = ui.available_width().max(100.0);
+    let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+    let axis = Stroke::new(1.0, visuals.weak_text_color());
+    let line = Stroke::new(2.0, visuals.selection.bg_fill);
+    let text_color = visuals.text_color();
+    let font = FontId::proportional(11.0);
+
+    let plot = Rect::from_min_max(
+        pos2(rect.min.x + 50.0, rect.min.y + 5.0),
+        pos2(rect.max.x - 5.0, rect.max.y - 20.0),
+    );
+    painter.line_segment([plot.left_bottom(), plot.right_bottom()], axis);
+    painter.line_segment([plot.left_top(), plot.left_bottom()], axis);
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        painter.text(
+            plot.center(),
+            Align2::CENTER_CENTER,
+            "No data",
+            font,
+            visuals.weak_text_color(),
+        );
+        return;
+    };
+
+    let (x_min, x_max) = (first.0, last.0.max(first.0 + 1));
+    let y_max = points.iter().map(|(_, y)| *y).fold(0.0, f64::max).max(1.0);
+    let to_screen = |(x, y): (i64, f64)| {
+        pos2(
+            plot.left() + plot.width() * ((x - x_min) as f32 / (x_max - x_min) as f32),
+            plot.bottom() - plot.height() * (y / y_max) as f32,
+        )
+    };
+    let screen_points: Vec<_> = points.iter().map(|point| to_screen(*point)).collect();
+    painter.add(Shape::line(screen_points.clone(), line));
+    for point in &screen_points {
+        painter.circle_filled(*point, 3.0, line.color);
+    }
+
+    painter.text(
+        pos2(plot.left() - 4.0, plot.top()),
+        Align2::RIGHT_TOP,
+        y_text(y_max),
+        font.clone(),
+        text_color,
+    );
+    painter.text(
+        pos2(plot.left() - 4.0, plot.bottom()),
+        Align2::RIGHT_BOTTOM,
+        y_text(0.0),
+        font.clone(),
+        text_color,
+    );
+    painter.text(
+        pos2(plot.left(), plot.bottom() + 4.0),
+        Align2::LEFT_TOP,
+        x_text(first.0),
+        font.clone(),
+        text_color,
+    );
+    if points.len() > 1 {
+        painter.text(
+            pos2(plot.right(), plot.bottom() + 4.0),
+            Align2::RIGHT_TOP,
+            x_text(last.0),
+            font,
+            text_color,
+        );
+    }
+
+    if let Some(hover) = response.hover_pos() {
+        let nearest = screen_points
+            .iter()
+            .zip(points)
+            .min_by(|(a, _), (b, _)| (a.x - hover.x).abs().total_cmp(&(b.x - hover.x).abs()))
+            .map(|(_, point)| *point);
+        if let Some((x, y)) = nearest {
+            response.on_hover_text(format!("{}: {}", x_text(x), y_text(y)));
+        }
+    }
+}
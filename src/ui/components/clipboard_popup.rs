@@ -0,0 +1,67 @@
+use crate::clipboard_monitor::ClipboardItem;
+use crate::context::AppContext;
+use crate::ui::components::explorer_link::ExplorerItem;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Context, RichText};
+
+/// What the user chose in the clipboard popup
+pub enum ClipboardAction {
+    Dismiss,
+    OpenIdentity(Identifier),
+    LookUpName(String),
+}
+
+/// A small popup in the bottom right corner offering to look up what was copied
+pub fn show_clipboard_popup(
+    ctx: &Context,
+    app_context: &AppContext,
+    item: &ClipboardItem,
+) -> Option<ClipboardAction> {
+    let mut action = None;
+    egui::Window::new("Copied to the clipboard")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -10.0])
+        .show(ctx, |ui| {
+            ui.label(RichText::new(item.description()).monospace());
+            ui.horizontal(|ui| {
+                match item {
+                    ClipboardItem::Identity(identity_id) => {
+                        if ui.button("Open identity").clicked() {
+                            action = Some(ClipboardAction::OpenIdentity(*identity_id));
+                        }
+                    }
+                    ClipboardItem::DpnsName(label) => {
+                        if ui.button("Look up name").clicked() {
+                            action = Some(ClipboardAction::LookUpName(label.clone()));
+                        }
+                    }
+                    ClipboardItem::Hash(hash) => {
+                        for (label, explorer_item) in [
+                            ("Core transaction", ExplorerItem::Transaction(hash.clone())),
+                            ("State transition", ExplorerItem::Transition(hash.clone())),
+                        ] {
+                            if let Some(url) = explorer_item.url(app_context) {
+                                if ui.button(label).on_hover_text(&url).clicked() {
+                                    ctx.open_url(egui::OpenUrl::new_tab(url));
+                                    action = Some(ClipboardAction::Dismiss);
+                                }
+                            }
+                        }
+                    }
+                    ClipboardItem::Address(address) => {
+                        if let Some(url) = ExplorerItem::Address(address.clone()).url(app_context) {
+                            if ui.button("Open in explorer").on_hover_text(&url).clicked() {
+                                ctx.open_url(egui::OpenUrl::new_tab(url));
+                                action = Some(ClipboardAction::Dismiss);
+                            }
+                        }
+                    }
+                }
+                if ui.button("Dismiss").clicked() {
+                    action = Some(ClipboardAction::Dismiss);
+                }
+            });
+        });
+    action
+}
@@ -1,27 +1,62 @@
 use crate::app::AppAction;
 use crate::context::AppContext;
+use crate::model::qualified_contract::RegisteredContract;
+use crate::platform::contract::ContractTask;
+use crate::platform::document::DocumentTypeName;
+use crate::platform::BackendTask;
+use crate::ui::helpers::time_format::TimeFormatter;
 use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
-use egui::{Context, Frame, Margin, SidePanel};
+use dash_sdk::dpp::prelude::Identifier;
+use egui::{Color32, Context, Frame, Margin, RichText, SidePanel, Ui};
 use std::sync::Arc;
+
+/// What the user picked in the contract chooser panel for the screen to show
+pub enum ContractPanelRequest {
+    /// Create a document, with the contract id, the form title and the JSON schema of the
+    /// document type
+    NewDocument(Identifier, String, serde_json::Value),
+    /// Inspect a contract, with its name and JSON
+    ViewContract(String, serde_json::Value),
+    /// Generate types for the document schemas of a contract, with its name and JSON
+    GenerateCode(String, serde_json::Value),
+    /// Fetch documents of a type of the contract
+    LookUpDocument(Arc<DataContract>, DocumentTypeName),
+    /// Snapshot the documents of a type of the contract and compare the snapshots
+    Snapshots(Arc<DataContract>, DocumentTypeName),
+    /// Create documents of a type of the contract from a CSV or JSON file
+    ImportDocuments(Identifier, DocumentTypeName),
+    /// Delete documents of a type of the contract selected by a query
+    DeleteDocuments(Identifier, DocumentTypeName),
+    /// Show the usage analytics of the contract
+    Analytics(Identifier),
+    /// Start an app profile of the contract
+    AppProfile(Identifier),
+}
+
+/// Adds the contract chooser panel, `request` is set when the user picks something in it
 pub fn add_contract_chooser_panel(
     ctx: &Context,
     current_search_term: &mut String,
     app_context: &Arc<AppContext>,
+    request: &mut Option<ContractPanelRequest>,
 ) -> AppAction {
     let mut action = AppAction::None;
 
-    // Fetch contracts from the app context
-    let contracts = app_context.get_contracts(None, None).unwrap_or_else(|e| {
+    // Fetch the known contracts registry from the app context
+    let contracts = app_context.get_registered_contracts().unwrap_or_else(|e| {
         eprintln!("Error fetching contracts: {}", e);
         vec![]
     });
+    let time_format = TimeFormatter::new(&app_context.preferences());
 
     // Filter the contracts based on the search term
     let filtered_contracts: Vec<_> = contracts
         .iter()
-        .filter(|contract| {
+        .filter(|registered_contract| {
+            let contract = &registered_contract.qualified_contract;
             let name_or_id = contract
                 .alias
                 .clone()
@@ -46,22 +81,114 @@ pub fn add_contract_chooser_panel(
                 ui.text_edit_singleline(current_search_term);
             });
 
+            if ui
+                .button("Check for updates")
+                .on_hover_text("Compare the cached contracts with their versions on Platform")
+                .clicked()
+            {
+                action = AppAction::BackendTask(BackendTask::ContractTask(
+                    ContractTask::CheckContractUpdates,
+                ));
+            }
+
             ui.separator(); // Separator below the search bar
 
             // Display filtered contracts with nested document types and indexes
             ui.vertical(|ui| {
-                for contract in filtered_contracts {
+                for registered_contract in filtered_contracts {
+                    let contract = &registered_contract.qualified_contract;
                     let name_or_id = contract
                         .alias
                         .clone()
                         .unwrap_or(contract.contract.id().to_string(Encoding::Base58));
+                    let title = if registered_contract.is_outdated() {
+                        RichText::new(format!("⚠ {}", name_or_id)).color(Color32::DARK_RED)
+                    } else {
+                        RichText::new(&name_or_id)
+                    };
 
                     // Expandable contract section
-                    ui.collapsing(name_or_id, |ui| {
+                    ui.collapsing(title, |ui| {
+                        if show_registry_info(ui, registered_contract, &time_format) {
+                            action = AppAction::BackendTask(BackendTask::ContractTask(
+                                ContractTask::RefreshContract(contract.contract.id()),
+                            ));
+                        }
+                        ui.horizontal(|ui| {
+                            if ui.button("View JSON").clicked() {
+                                *request = Some(ContractPanelRequest::ViewContract(
+                                    name_or_id.clone(),
+                                    contract_json(&contract.contract),
+                                ));
+                            }
+                            if ui
+                                .button("Generate code")
+                                .on_hover_text("Rust and TypeScript types of the document schemas")
+                                .clicked()
+                            {
+                                *request = Some(ContractPanelRequest::GenerateCode(
+                                    name_or_id.clone(),
+                                    contract_json(&contract.contract),
+                                ));
+                            }
+                            if ui
+                                .button("Analytics")
+                                .on_hover_text("Document counts, growth, owners and storage")
+                                .clicked()
+                            {
+                                *request =
+                                    Some(ContractPanelRequest::Analytics(contract.contract.id()));
+                            }
+                            if ui
+                                .button("App profile")
+                                .on_hover_text("An admin panel of the contract's documents")
+                                .clicked()
+                            {
+                                *request =
+                                    Some(ContractPanelRequest::AppProfile(contract.contract.id()));
+                            }
+                        });
+
                         // Loop over the document types in the contract
                         for (doc_name, doc_type) in contract.contract.document_types() {
                             // Expandable section for each document type
                             ui.collapsing(doc_name, |ui| {
+                                if ui.button("New document").clicked() {
+                                    match serde_json::to_value(doc_type.schema()) {
+                                        Ok(schema) => {
+                                            *request = Some(ContractPanelRequest::NewDocument(
+                                                contract.contract.id(),
+                                                format!("New {} document", doc_name),
+                                                schema,
+                                            ))
+                                        }
+                                        Err(e) => eprintln!("Error reading schema: {}", e),
+                                    }
+                                }
+                                if ui.button("Look up document").clicked() {
+                                    *request = Some(ContractPanelRequest::LookUpDocument(
+                                        Arc::new(contract.contract.clone()),
+                                        doc_name.clone(),
+                                    ));
+                                }
+                                if ui.button("Snapshots").clicked() {
+                                    *request = Some(ContractPanelRequest::Snapshots(
+                                        Arc::new(contract.contract.clone()),
+                                        doc_name.clone(),
+                                    ));
+                                }
+                                if ui.button("Import documents").clicked() {
+                                    *request = Some(ContractPanelRequest::ImportDocuments(
+                                        contract.contract.id(),
+                                        doc_name.clone(),
+                                    ));
+                                }
+                                if ui.button("Delete documents").clicked() {
+                                    *request = Some(ContractPanelRequest::DeleteDocuments(
+                                        contract.contract.id(),
+                                        doc_name.clone(),
+                                    ));
+                                }
                                 // Loop over the indexes in the document type
                                 for index in doc_type.indexes().values() {
                                     ui.label(format!("Index: {}", index.name));
@@ -82,3 +209,62 @@ pub fn add_contract_chooser_panel(
 
     action
 }
+
+/// The contract's id, owner, version and document schemas as JSON
+fn contract_json(contract: &DataContract) -> serde_json::Value {
+    let document_schemas: serde_json::Map<String, serde_json::Value> = contract
+        .document_types()
+        .iter()
+        .map(|(name, document_type)| {
+            (
+                name.clone(),
+                serde_json::to_value(document_type.schema()).unwrap_or_default(),
+            )
+        })
+        .collect();
+    serde_json::json!({
+        "id": contract.id().to_string(Encoding::Base58),
+        "ownerId": contract.owner_id().to_string(Encoding::Base58),
+        "version": contract.version(),
+        "documentSchemas": document_schemas,
+    })
+}
+
+/// Shows the registry information of a contract, returns true if a refresh was requested
+fn show_registry_info(
+    ui: &mut Ui,
+    registered_contract: &RegisteredContract,
+    time_format: &TimeFormatter,
+) -> bool {
+    let mut refresh_requested = false;
+    let kind = if registered_contract.kind.is_system() {
+        "System contract"
+    } else {
+        "User-added contract"
+    };
+    ui.label(RichText::new(kind).italics());
+    let onchain_version = registered_contract
+        .onchain_version
+        .map_or("unknown".to_string(), |version| version.to_string());
+    ui.label(format!(
+        "Version: {} (on Platform: {})",
+        registered_contract.cached_version(),
+        onchain_version
+    ));
+    if let Some(last_checked) = registered_contract.last_checked {
+        ui.label(format!(
+            "Last checked: {}",
+            time_format.format_millis(last_checked)
+        ));
+    }
+    if registered_contract.is_outdated() {
+        ui.horizontal(|ui| {
+            ui.colored_label(Color32::DARK_RED, "A newer version is on Platform");
+            if ui.button("Refresh").clicked() {
+                refresh_requested = true;
+            }
+        });
+    }
+    ui.separator();
+    refresh_requested
+}
@@ -0,0 +1,85 @@
+use egui::{Context, Id, Ui, ViewportBuilder, ViewportClass, ViewportId};
+
+/// Shows a window that can either float inside the main window or be detached into its own
+/// native OS window (an egui viewport), so it can be moved to another monitor.
+///
+/// `detached` and `open` are updated in place: the user can toggle detaching from the window
+/// header, and closing the native window (or the embedded one) sets `open` to false.
+pub fn show_detachable_window<R>(
+    ctx: &Context,
+    title: &str,
+    detached: &mut bool,
+    open: &mut bool,
+    mut add_contents: impl FnMut(&mut Ui) -> R,
+) -> Option<R> {
+    if !*open {
+        return None;
+    }
+
+    if *detached {
+        let viewport_id = ViewportId::from_hash_of(title);
+        let builder = ViewportBuilder::default()
+            .with_title(title)
+            .with_inner_size([520.0, 420.0]);
+
+        let mut embedded = false;
+        let result = ctx.show_viewport_immediate(viewport_id, builder, |ctx, class| {
+            if class == ViewportClass::Embedded {
+                // The backend can't open native windows, fall back to a floating window
+                embedded = true;
+                return None;
+            }
+
+            if ctx.input(|i| i.viewport().close_requested()) {
+                *open = false;
+            }
+
+            egui::CentralPanel::default()
+                .show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        if ui
+                            .button("Attach")
+                            .on_hover_text("Move this view back into the main window")
+                            .clicked()
+                        {
+                            *detached = false;
+                        }
+                    });
+                    ui.separator();
+                    Some(add_contents(ui))
+                })
+                .inner
+        });
+
+        if !embedded {
+            return result;
+        }
+        *detached = false;
+    }
+
+    let mut window_open = true;
+    let result = egui::Window::new(title)
+        .id(Id::new(title).with("embedded"))
+        .collapsible(false)
+        .open(&mut window_open)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .button("Detach")
+                    .on_hover_text("Open this view in a separate window")
+                    .clicked()
+                {
+                    *detached = true;
+                }
+            });
+            ui.separator();
+            add_contents(ui)
+        })
+        .and_then(|inner_response| inner_response.inner);
+
+    if !window_open {
+        *open = false;
+    }
+
+    result
+}
@@ -0,0 +1,69 @@
+use crate::context::AppContext;
+use crate::ui::components::accessibility::with_accessible_label;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Ui, WidgetType};
+
+/// Something that can be looked up in a block or platform explorer
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExplorerItem {
+    Identity(Identifier),
+    /// A state transition by its hex hash
+    Transition(String),
+    /// A core chain transaction by its txid, e.g. a ProTx
+    Transaction(String),
+    /// A core chain address
+    Address(String),
+}
+
+impl ExplorerItem {
+    /// The explorer URL of the item on the context's network, `None` when no explorer is set
+    pub fn url(&self, app_context: &AppContext) -> Option<String> {
+        let explorer = app_context.preferences().explorer;
+        let network = app_context.network_string();
+        match self {
+            ExplorerItem::Identity(identity_id) => {
+                explorer.identity_url(&network, &identity_id.to_string(Encoding::Base58))
+            }
+            ExplorerItem::Transition(hash) => explorer.transition_url(&network, hash),
+            ExplorerItem::Transaction(txid) => explorer.transaction_url(&network, txid),
+            ExplorerItem::Address(address) => explorer.address_url(&network, address),
+        }
+    }
+}
+
+/// A small button beside an id that opens it in the explorer. Nothing is shown when no
+/// explorer is set for the network.
+pub fn explorer_button(ui: &mut Ui, app_context: &AppContext, item: ExplorerItem) {
+    let Some(url) = item.url(app_context) else {
+        return;
+    };
+    let response = with_accessible_label(
+        ui.small_button("↗")
+            .on_hover_text(format!("Open in explorer: {}", url)),
+        WidgetType::Button,
+        "Open in explorer",
+    );
+    if response.clicked() {
+        ui.ctx().open_url(egui::OpenUrl::new_tab(url));
+    }
+}
+
+/// An entry for context menus such as "Open in explorer", disabled when no explorer is set
+pub fn explorer_menu_button(
+    ui: &mut Ui,
+    app_context: &AppContext,
+    label: &str,
+    item: ExplorerItem,
+) {
+    let url = item.url(app_context);
+    let response = ui
+        .add_enabled(url.is_some(), egui::Button::new(label))
+        .on_disabled_hover_text("No explorer is set for this network in Settings");
+    if response.clicked() {
+        if let Some(url) = url {
+            ui.ctx().open_url(egui::OpenUrl::new_tab(url));
+        }
+        ui.close_menu();
+    }
+}
@@ -0,0 +1,133 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::fee::{
+    asset_lock_fee, withdrawal_fee_per_byte, CoinSelection, FeeChoice, FeeEstimates, FeeSpeed,
+};
+use crate::platform::core::{CoreItem, CoreTask};
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use eframe::egui::{Color32, ComboBox, RichText, Ui};
+
+/// What a fee is chosen for, withdrawals are built by Platform which picks their inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeePurpose {
+    AssetLock,
+    Withdrawal,
+}
+
+/// The fee speed and coin selection of a funding transaction or withdrawal, with Core's
+/// estimate for each speed
+pub struct FeeSelector {
+    pub choice: FeeChoice,
+    purpose: FeePurpose,
+    estimates: Option<FeeEstimates>,
+    requested: bool,
+}
+
+impl FeeSelector {
+    /// Starts with the default choice from the settings
+    pub fn new(app_context: &AppContext, purpose: FeePurpose) -> Self {
+        Self {
+            choice: app_context.preferences().fee_choice,
+            purpose,
+            estimates: None,
+            requested: false,
+        }
+    }
+
+    /// Keeps Core's estimates if the result holds them. Returns whether it did, so the screen
+    /// doesn't treat it as the result of its own task.
+    pub fn take_estimates(&mut self, result: &BackendTaskSuccessResult) -> bool {
+        if let BackendTaskSuccessResult::CoreItem(CoreItem::FeeEstimates(estimates)) = result {
+            self.estimates = Some(*estimates);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Asks Core for the estimates again, e.g. after a while on the screen
+    pub fn refresh(&mut self) {
+        self.requested = false;
+    }
+
+    /// Renders the speeds, and the coin selection for asset locks. Returns the task fetching
+    /// the estimates the first time it is shown.
+    pub fn show(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        if !self.requested {
+            self.requested = true;
+            action = AppAction::BackendTask(BackendTask::CoreTask(CoreTask::EstimateFees));
+        }
+        ui.horizontal(|ui| {
+            ui.label("Fee:");
+            for speed in FeeSpeed::ALL {
+                let label = match &self.estimates {
+                    Some(estimates) => {
+                        format!(
+                            "{} ({} duffs/B)",
+                            speed.label(),
+                            self.rate(estimates, speed)
+                        )
+                    }
+                    None => speed.label().to_string(),
+                };
+                ui.radio_value(&mut self.choice.speed, speed, label);
+            }
+            if ui
+                .small_button("⟳")
+                .on_hover_text("Estimate again")
+                .clicked()
+            {
+                self.refresh();
+            }
+        });
+        if self.purpose == FeePurpose::AssetLock {
+            ui.horizontal(|ui| {
+                ui.label("Coin selection:");
+                ComboBox::from_id_salt("fee_coin_selection")
+                    .selected_text(self.choice.coin_selection.label())
+                    .show_ui(ui, |ui| {
+                        for strategy in CoinSelection::ALL {
+                            ui.selectable_value(
+                                &mut self.choice.coin_selection,
+                                strategy,
+                                strategy.label(),
+                            );
+                        }
+                    });
+            });
+        }
+        let note = match &self.estimates {
+            None => "Asking Core for fee estimates...".to_string(),
+            Some(estimates) => {
+                let rate = self.rate(estimates, self.choice.speed);
+                let mut note = match self.purpose {
+                    FeePurpose::AssetLock => format!(
+                        "About {} duffs with one input, {} more for each other input.",
+                        asset_lock_fee(1, rate),
+                        asset_lock_fee(2, rate) - asset_lock_fee(1, rate)
+                    ),
+                    FeePurpose::Withdrawal => format!(
+                        "The withdrawal pays {} duffs per byte, Platform only accepts rates \
+                         from the Fibonacci sequence.",
+                        rate
+                    ),
+                };
+                if !estimates.estimated {
+                    note.push_str(" Core has no estimates yet, these are the relay minimum.");
+                }
+                note
+            }
+        };
+        ui.label(RichText::new(note).color(Color32::GRAY));
+        action
+    }
+
+    fn rate(&self, estimates: &FeeEstimates, speed: FeeSpeed) -> u64 {
+        let rate = estimates.rate(speed);
+        match self.purpose {
+            FeePurpose::AssetLock => rate,
+            FeePurpose::Withdrawal => withdrawal_fee_per_byte(rate) as u64,
+        }
+    }
+}
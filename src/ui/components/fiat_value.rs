@@ -0,0 +1,52 @@
+use crate::context::AppContext;
+use crate::ui::helpers::number_format::{NumberFormatter, CREDITS_PER_DASH};
+use crate::ui::helpers::redaction::REDACTED;
+use chrono_humanize::HumanTime;
+use dash_sdk::dpp::dashcore::Network;
+use dash_sdk::dpp::fee::Credits;
+use eframe::egui::{self, Color32, RichText, Ui};
+
+/// The approximate fiat value of the credits, with a description of the price it is based on.
+/// Only mainnet credits have a value, `None` elsewhere or while the price feed is off or has no
+/// price yet.
+pub fn fiat_value(app_context: &AppContext, credits: Credits) -> Option<(String, String)> {
+    if app_context.network != Network::Dash {
+        return None;
+    }
+    let preferences = app_context.preferences();
+    let settings = &preferences.price_feed;
+    let price = settings.current_price()?;
+    let number_format = NumberFormatter::new(&preferences);
+    if preferences.privacy_mode {
+        return Some((
+            format!("≈ {} {}", REDACTED, price.currency),
+            "Hidden in privacy mode".to_string(),
+        ));
+    }
+    let value = format!(
+        "≈ {} {}",
+        number_format.format_decimal(credits as f64 / CREDITS_PER_DASH * price.price, 2),
+        price.currency
+    );
+    let mut source = format!(
+        "1 DASH = {} {} from {}, {}",
+        number_format.format_decimal(price.price, 2),
+        price.currency,
+        settings.provider.label(),
+        HumanTime::from(price.fetched_at())
+    );
+    if price.is_stale(settings) {
+        source.push_str(". The price feed is unreachable, this is the last known price.");
+    }
+    Some((value, source))
+}
+
+/// A gray label with the approximate fiat value of the credits, nothing when there is none
+pub fn fiat_label(ui: &mut Ui, app_context: &AppContext, credits: Credits) {
+    if let Some((value, source)) = fiat_value(app_context, credits) {
+        ui.add(
+            egui::Label::new(RichText::new(value).color(Color32::GRAY)).sense(egui::Sense::hover()),
+        )
+        .on_hover_text(source);
+    }
+}
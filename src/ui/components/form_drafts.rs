@@ -0,0 +1,128 @@
+use crate::context::AppContext;
+use crate::model::form_draft::FormDraft;
+use crate::ui::helpers::time_format::TimeFormatter;
+use eframe::egui::{self, Ui};
+use std::time::{Duration, Instant};
+
+/// How often a changed form is saved as a draft
+const DRAFT_SAVE_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Autosaves the input of a long form as a draft and lists the other drafts of the form.
+/// The screen decides what goes into a draft, secrets such as private keys must stay out.
+pub struct FormDrafts {
+    form: String,
+    /// The draft the input is saved to, `None` until there is input
+    draft_id: Option<i64>,
+    saved_content: String,
+    last_save: Instant,
+    drafts: Vec<FormDraft>,
+}
+
+impl FormDrafts {
+    pub fn new(form: impl Into<String>, app_context: &AppContext) -> Self {
+        let mut form_drafts = Self {
+            form: form.into(),
+            draft_id: None,
+            saved_content: String::new(),
+            last_save: Instant::now(),
+            drafts: Vec::new(),
+        };
+        form_drafts.reload(app_context);
+        form_drafts
+    }
+
+    fn reload(&mut self, app_context: &AppContext) {
+        self.drafts = app_context
+            .db
+            .get_form_drafts(&self.form, app_context)
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to load the drafts of {}: {}", self.form, e);
+                Vec::new()
+            });
+    }
+
+    /// Saves the content when it changed since the last save, at most every few seconds.
+    /// `None` means the form is empty and there is nothing worth keeping yet.
+    pub fn autosave(&mut self, title: &str, content: Option<String>, app_context: &AppContext) {
+        let Some(content) = content else {
+            return;
+        };
+        if content == self.saved_content || self.last_save.elapsed() < DRAFT_SAVE_INTERVAL {
+            return;
+        }
+        self.last_save = Instant::now();
+        match app_context.db.save_form_draft(
+            self.draft_id,
+            &self.form,
+            title,
+            &content,
+            app_context,
+        ) {
+            Ok(id) => {
+                self.draft_id = Some(id);
+                self.saved_content = content;
+            }
+            Err(e) => tracing::warn!("Failed to save the draft of {}: {}", self.form, e),
+        }
+    }
+
+    /// Deletes the draft of the current input, once it was submitted or cleared
+    pub fn discard(&mut self, app_context: &AppContext) {
+        if let Some(id) = self.draft_id.take() {
+            if let Err(e) = app_context.db.delete_form_draft(id) {
+                tracing::warn!("Failed to delete the draft of {}: {}", self.form, e);
+            }
+        }
+        self.saved_content.clear();
+        self.reload(app_context);
+    }
+
+    /// Lists the earlier drafts of the form. Returns the content of the draft to restore,
+    /// further input is then saved to that draft.
+    pub fn show(&mut self, ui: &mut Ui, app_context: &AppContext) -> Option<String> {
+        let earlier: Vec<&FormDraft> = self
+            .drafts
+            .iter()
+            .filter(|draft| Some(draft.id) != self.draft_id)
+            .collect();
+        if earlier.is_empty() {
+            return None;
+        }
+        let time_format = TimeFormatter::new(&app_context.preferences());
+        let mut restore = None;
+        let mut delete = None;
+        egui::CollapsingHeader::new(format!("Drafts ({})", earlier.len()))
+            .id_salt(("form_drafts", self.form.as_str()))
+            .show(ui, |ui| {
+                egui::Grid::new(("form_drafts_grid", self.form.as_str()))
+                    .num_columns(4)
+                    .spacing([10.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for draft in earlier {
+                            ui.label(&draft.title);
+                            ui.label(time_format.format_seconds(draft.saved_at.max(0) as u64));
+                            if ui.button("Restore").clicked() {
+                                restore = Some(draft.clone());
+                            }
+                            if ui.button("Delete").clicked() {
+                                delete = Some(draft.id);
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        if let Some(id) = delete {
+            if let Err(e) = app_context.db.delete_form_draft(id) {
+                tracing::warn!("Failed to delete the draft of {}: {}", self.form, e);
+            }
+            self.reload(app_context);
+        }
+        let draft = restore?;
+        self.draft_id = Some(draft.id);
+        self.saved_content = draft.content.clone();
+        self.reload(app_context);
+        Some(draft.content)
+    }
+}
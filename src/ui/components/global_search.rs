@@ -0,0 +1,283 @@
+//! The search box of the top panel. It looks through what is stored locally, contests,
+//! identities, contacts, contracts and the activity log, and opens the screen of the result the
+//! user picks.
+
+use crate::app::{AppAction, AppCommand};
+use crate::context::AppContext;
+use crate::deep_link::DeepLink;
+use crate::ui::helpers::display_scale::scaled_size;
+use crate::ui::helpers::redaction::redact_id;
+use crate::ui::{RootScreenType, ScreenType};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::util::strings::convert_to_homograph_safe_chars;
+use dash_sdk::platform::Identifier;
+use egui::{Area, Color32, Frame, Id, Key, Modifiers, Order, RichText, ScrollArea, TextEdit, Ui};
+use std::sync::Arc;
+
+/// Results shown per group, so one kind of data can't crowd out the others
+const MAX_RESULTS_PER_GROUP: usize = 5;
+
+/// Width of the search box
+pub const SEARCH_BOX_WIDTH: f32 = 180.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchGroup {
+    Contests,
+    Identities,
+    Contacts,
+    Contracts,
+    Activity,
+}
+
+impl SearchGroup {
+    fn label(&self) -> &'static str {
+        match self {
+            SearchGroup::Contests => "Contests",
+            SearchGroup::Identities => "Identities",
+            SearchGroup::Contacts => "Contacts",
+            SearchGroup::Contracts => "Contracts",
+            SearchGroup::Activity => "Activity Log",
+        }
+    }
+}
+
+/// Where picking a result goes
+#[derive(Debug, Clone, PartialEq)]
+enum SearchTarget {
+    Link(DeepLink),
+    Contract(Identifier),
+    MainScreen(RootScreenType),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct SearchResult {
+    group: SearchGroup,
+    title: String,
+    detail: String,
+    target: SearchTarget,
+}
+
+/// The text typed and the results found for it, kept between frames
+#[derive(Clone, Default)]
+struct SearchState {
+    query: String,
+    searched: String,
+    results: Vec<SearchResult>,
+}
+
+fn state_id() -> Id {
+    Id::new("global_search")
+}
+
+fn contains(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(needle)
+}
+
+/// Searches the local data of the current network, case insensitive
+fn search(app_context: &AppContext, query: &str) -> Vec<SearchResult> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return vec![];
+    }
+    let mut results = Vec::new();
+    let privacy_mode = app_context.preferences().privacy_mode;
+    let show_id = |id: &Identifier| redact_id(&id.to_string(Encoding::Base58), privacy_mode);
+
+    // Contest names are stored homograph safe, "alice" is found as "a11ce"
+    let safe_needle = convert_to_homograph_safe_chars(&needle);
+    let contests = app_context.all_contested_names().unwrap_or_default();
+    results.extend(
+        contests
+            .into_iter()
+            .filter(|contest| contest.normalized_contested_name.contains(&safe_needle))
+            .take(MAX_RESULTS_PER_GROUP)
+            .map(|contest| SearchResult {
+                group: SearchGroup::Contests,
+                detail: match &contest.contestants {
+                    Some(contestants) => format!("{} contestants", contestants.len()),
+                    None => "Contestants not fetched".to_string(),
+                },
+                target: SearchTarget::Link(DeepLink::Contest(
+                    contest.normalized_contested_name.clone(),
+                )),
+                title: contest.normalized_contested_name,
+            }),
+    );
+
+    let identities = app_context
+        .load_local_qualified_identities()
+        .unwrap_or_default();
+    results.extend(
+        identities
+            .iter()
+            .filter(|identity| {
+                let id = identity.identity.id().to_string(Encoding::Base58);
+                contains(&id, &needle)
+                    || identity
+                        .alias
+                        .as_ref()
+                        .is_some_and(|alias| contains(alias, &needle))
+            })
+            .take(MAX_RESULTS_PER_GROUP)
+            .map(|identity| SearchResult {
+                group: SearchGroup::Identities,
+                title: identity
+                    .alias
+                    .clone()
+                    .unwrap_or_else(|| show_id(&identity.identity.id())),
+                detail: format!("{:?} identity", identity.identity_type),
+                target: SearchTarget::Link(DeepLink::Identity(identity.identity.id())),
+            }),
+    );
+
+    let contacts = app_context.db.get_contacts(app_context).unwrap_or_default();
+    results.extend(
+        contacts
+            .into_iter()
+            .filter(|contact| {
+                contains(&contact.identity_id.to_string(Encoding::Base58), &needle)
+                    || contact
+                        .alias
+                        .as_ref()
+                        .is_some_and(|alias| contains(alias, &needle))
+            })
+            .take(MAX_RESULTS_PER_GROUP)
+            .map(|contact| {
+                let id = show_id(&contact.identity_id);
+                SearchResult {
+                    group: SearchGroup::Contacts,
+                    title: contact.alias.clone().unwrap_or_else(|| id.clone()),
+                    detail: id,
+                    target: SearchTarget::Link(DeepLink::Identity(contact.identity_id)),
+                }
+            }),
+    );
+
+    let contracts = app_context.get_contracts(None, None).unwrap_or_default();
+    results.extend(
+        contracts
+            .into_iter()
+            .filter(|contract| {
+                contains(&contract.contract.id().to_string(Encoding::Base58), &needle)
+                    || contract
+                        .alias
+                        .as_ref()
+                        .is_some_and(|alias| contains(alias, &needle))
+            })
+            .take(MAX_RESULTS_PER_GROUP)
+            .map(|contract| {
+                let id = contract.contract.id();
+                SearchResult {
+                    group: SearchGroup::Contracts,
+                    title: contract
+                        .alias
+                        .clone()
+                        .unwrap_or_else(|| id.to_string(Encoding::Base58)),
+                    detail: format!(
+                        "{} document types",
+                        contract.contract.document_types().len()
+                    ),
+                    target: SearchTarget::Contract(id),
+                }
+            }),
+    );
+
+    let activity = app_context
+        .db
+        .get_audit_log(200, app_context)
+        .unwrap_or_default();
+    results.extend(
+        activity
+            .into_iter()
+            .filter(|entry| contains(&entry.action, &needle) || contains(&entry.outcome, &needle))
+            .take(MAX_RESULTS_PER_GROUP)
+            .map(|entry| SearchResult {
+                group: SearchGroup::Activity,
+                title: entry.action,
+                detail: entry.outcome,
+                target: SearchTarget::MainScreen(RootScreenType::RootScreenNetworkChooser),
+            }),
+    );
+
+    results
+}
+
+fn target_action(target: SearchTarget, app_context: &Arc<AppContext>) -> AppAction {
+    match target {
+        SearchTarget::Link(link) => {
+            app_context.send(AppCommand::OpenLink(link));
+            AppAction::None
+        }
+        SearchTarget::Contract(contract_id) => AppAction::AddScreen(
+            ScreenType::ContractAnalytics(contract_id).create_screen(app_context),
+        ),
+        SearchTarget::MainScreen(screen_type) => AppAction::SetMainScreen(screen_type),
+    }
+}
+
+/// The search box, with the grouped results below it while there is a query. Ctrl+K focuses
+/// it and Escape clears it.
+pub fn add_global_search(ui: &mut Ui, app_context: &Arc<AppContext>) -> AppAction {
+    let mut action = AppAction::None;
+    let id = state_id();
+    let mut state: SearchState = ui.data(|data| data.get_temp(id)).unwrap_or_default();
+
+    let response = ui.add(
+        TextEdit::singleline(&mut state.query)
+            .hint_text("🔍 Search (Ctrl+K)")
+            .desired_width(scaled_size(ui.ctx(), SEARCH_BOX_WIDTH)),
+    );
+    if ui.input_mut(|input| input.consume_key(Modifiers::COMMAND, Key::K)) {
+        response.request_focus();
+    }
+    if (response.has_focus() || response.lost_focus())
+        && ui.input(|input| input.key_pressed(Key::Escape))
+    {
+        state.query.clear();
+    }
+    if state.query != state.searched {
+        state.results = search(app_context, &state.query);
+        state.searched = state.query.clone();
+    }
+
+    if !state.query.trim().is_empty() {
+        let mut picked = None;
+        Area::new(id.with("results"))
+            .order(Order::Foreground)
+            .fixed_pos(response.rect.left_bottom() + egui::vec2(0.0, 4.0))
+            .show(ui.ctx(), |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_width(scaled_size(ui.ctx(), 320.0));
+                    if state.results.is_empty() {
+                        ui.label(RichText::new("Nothing found").color(Color32::GRAY));
+                        return;
+                    }
+                    ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                        let mut group = None;
+                        for result in &state.results {
+                            if group != Some(result.group) {
+                                group = Some(result.group);
+                                ui.label(RichText::new(result.group.label()).strong());
+                            }
+                            if ui
+                                .selectable_label(false, &result.title)
+                                .on_hover_text(&result.detail)
+                                .clicked()
+                            {
+                                picked = Some(result.target.clone());
+                            }
+                        }
+                    });
+                });
+            });
+        if let Some(target) = picked {
+            action = target_action(target, app_context);
+            state = SearchState::default();
+        }
+    }
+
+    ui.data_mut(|data| data.insert_temp(id, state));
+    action
+}
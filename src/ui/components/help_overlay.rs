@@ -0,0 +1,121 @@
+use crate::ui::help::{screen_help, HelpTerm, ScreenHelp};
+use crate::ui::helpers::display_scale::scaled_size;
+use crate::ui::ScreenType;
+use eframe::egui::{self, Color32, Context, Id, Response, RichText, Ui};
+
+fn open_id() -> Id {
+    Id::new("help_overlay_open")
+}
+
+/// Position of the guided tour of a screen, absent when all callouts are listed
+fn tour_id(help: &ScreenHelp) -> Id {
+    Id::new(("help_overlay_tour", help.title))
+}
+
+pub fn is_help_open(ctx: &Context) -> bool {
+    ctx.data(|data| data.get_temp::<bool>(open_id()).unwrap_or(false))
+}
+
+/// Flips the help of the visible screen on or off, from the "?" of the top panel
+pub fn toggle_help(ctx: &Context) {
+    let open = is_help_open(ctx);
+    ctx.data_mut(|data| data.insert_temp(open_id(), !open));
+}
+
+pub fn open_help(ctx: &Context) {
+    ctx.data_mut(|data| data.insert_temp(open_id(), true));
+}
+
+/// Explains the term when the response is hovered, with a link to the help of the screen.
+/// `hint` is added below, e.g. what a right-click does on the widget.
+pub fn term_tooltip(response: Response, term: &HelpTerm, hint: Option<&str>) -> Response {
+    response.on_hover_ui(|ui| {
+        ui.set_max_width(scaled_size(ui.ctx(), 300.0));
+        ui.label(RichText::new(term.name).strong());
+        ui.label(term.text);
+        if let Some(hint) = hint {
+            ui.label(RichText::new(hint).color(Color32::GRAY));
+        }
+        if ui.link("More in the help (F1)").clicked() {
+            open_help(ui.ctx());
+        }
+    })
+}
+
+fn render_callout(ui: &mut Ui, label: &str, text: &str) {
+    ui.label(RichText::new(label).strong());
+    ui.label(text);
+    ui.add_space(6.0);
+}
+
+/// Shows the callouts of the visible screen next to it, all at once or one at a time as a tour
+pub fn show_help_overlay(ctx: &Context, screen_type: &ScreenType) {
+    let mut open = true;
+    let help = screen_help(screen_type);
+    egui::Window::new("Help")
+        .id(Id::new("help_overlay"))
+        .collapsible(false)
+        .resizable(false)
+        .default_width(320.0)
+        .anchor(egui::Align2::RIGHT_TOP, [-10.0, scaled_size(ctx, 60.0)])
+        .open(&mut open)
+        .show(ctx, |ui| {
+            let Some(help) = help else {
+                ui.label("There is no help for this screen yet.");
+                return;
+            };
+            ui.heading(help.title);
+            ui.label(RichText::new(help.summary).color(Color32::GRAY));
+            ui.separator();
+
+            let tour = tour_id(help);
+            let step = ui.data(|data| data.get_temp::<usize>(tour));
+            let mut next_step = step;
+            match step {
+                Some(index) if index < help.callouts.len() => {
+                    let callout = &help.callouts[index];
+                    ui.label(format!("Step {} of {}", index + 1, help.callouts.len()));
+                    render_callout(ui, callout.label, callout.text);
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add_enabled(index > 0, egui::Button::new("Back"))
+                            .clicked()
+                        {
+                            next_step = Some(index - 1);
+                        }
+                        if index + 1 < help.callouts.len() {
+                            if ui.button("Next").clicked() {
+                                next_step = Some(index + 1);
+                            }
+                        } else if ui.button("Finish").clicked() {
+                            next_step = None;
+                        }
+                        if ui.button("End Tour").clicked() {
+                            next_step = None;
+                        }
+                    });
+                }
+                _ => {
+                    egui::ScrollArea::vertical()
+                        .max_height(scaled_size(ui.ctx(), 400.0))
+                        .show(ui, |ui| {
+                            for callout in help.callouts {
+                                render_callout(ui, callout.label, callout.text);
+                            }
+                        });
+                    if !help.callouts.is_empty() && ui.button("Start Tour").clicked() {
+                        next_step = Some(0);
+                    }
+                }
+            }
+            if next_step != step {
+                ui.data_mut(|data| match next_step {
+                    Some(index) => data.insert_temp(tour, index),
+                    None => data.remove::<usize>(tour),
+                });
+            }
+        });
+    if !open {
+        ctx.data_mut(|data| data.insert_temp(open_id(), false));
+    }
+}
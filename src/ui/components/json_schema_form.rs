@@ -0,0 +1,628 @@
+use crate::ui::components::accessibility::with_accessible_label;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::platform_value::{Identifier, Value};
+use egui::{Color32, RichText, Ui, WidgetType};
+use regex::Regex;
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+
+const IDENTIFIER_MEDIA_TYPE: &str = "application/x.dash.dpp.identifier";
+
+/// An input form generated from a JSON schema, such as a document type schema of a data contract.
+///
+/// Supported are strings (with `pattern`, `minLength`, `maxLength` and `enum`), integers and
+/// numbers with ranges, booleans, byte arrays (`byteArray: true`, entered as hex, identifiers
+/// also as Base58), arrays and nested objects. Anything else is entered as raw JSON.
+pub struct JsonSchemaForm {
+    id_salt: String,
+    fields: Vec<FormField>,
+}
+
+struct FormField {
+    name: String,
+    title: Option<String>,
+    description: Option<String>,
+    required: bool,
+    kind: FieldKind,
+}
+
+enum FieldKind {
+    Text {
+        value: String,
+        pattern: Option<Regex>,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+        choices: Vec<String>,
+    },
+    Integer {
+        value: String,
+        minimum: Option<i64>,
+        maximum: Option<i64>,
+    },
+    Number {
+        value: String,
+        minimum: Option<f64>,
+        maximum: Option<f64>,
+    },
+    Boolean(bool),
+    Bytes {
+        value: String,
+        min_items: Option<usize>,
+        max_items: Option<usize>,
+        is_identifier: bool,
+    },
+    Array {
+        item_schema: JsonValue,
+        items: Vec<FormField>,
+        min_items: Option<usize>,
+        max_items: Option<usize>,
+    },
+    Object {
+        fields: Vec<FormField>,
+    },
+    RawJson {
+        value: String,
+    },
+}
+
+impl JsonSchemaForm {
+    /// Builds a form for an object schema, one field per property
+    pub fn new(id_salt: impl Into<String>, schema: &JsonValue) -> Self {
+        Self {
+            id_salt: id_salt.into(),
+            fields: parse_properties(schema),
+        }
+    }
+
+    /// Renders the form, showing validation errors next to the fields
+    pub fn show(&mut self, ui: &mut Ui) {
+        let id_salt = self.id_salt.clone();
+        egui::Grid::new(id_salt.as_str())
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for field in &mut self.fields {
+                    show_field(ui, field, &id_salt);
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Clears all inputs
+    pub fn reset(&mut self) {
+        for field in &mut self.fields {
+            field.reset();
+        }
+    }
+
+    /// Whether nothing was entered yet
+    pub fn is_empty(&self) -> bool {
+        !self.fields.iter().any(FormField::has_input)
+    }
+
+    /// The raw input of all fields as JSON, to be put back with `restore_inputs`
+    pub fn inputs(&self) -> JsonValue {
+        JsonValue::Object(
+            self.fields
+                .iter()
+                .map(|field| (field.name.clone(), field.input()))
+                .collect(),
+        )
+    }
+
+    /// Puts back input saved with `inputs`, fields the schema no longer has are skipped
+    pub fn restore_inputs(&mut self, inputs: &JsonValue) {
+        for field in &mut self.fields {
+            field.reset();
+            if let Some(input) = inputs.get(&field.name) {
+                field.restore_input(input);
+            }
+        }
+    }
+
+    /// Collects the entered values as document properties, or all validation errors
+    pub fn properties(&self) -> Result<BTreeMap<String, Value>, Vec<String>> {
+        let mut errors = Vec::new();
+        let mut properties = BTreeMap::new();
+        for field in &self.fields {
+            match field.value(&field.name) {
+                Ok(Some(value)) => {
+                    properties.insert(field.name.clone(), value);
+                }
+                Ok(None) => {}
+                Err(field_errors) => errors.extend(field_errors),
+            }
+        }
+        if errors.is_empty() {
+            Ok(properties)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Parses the `properties` of an object schema, ordered by `position` like Platform does
+fn parse_properties(schema: &JsonValue) -> Vec<FormField> {
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(JsonValue::as_array)
+        .map(|required| required.iter().filter_map(JsonValue::as_str).collect())
+        .unwrap_or_default();
+    let Some(properties) = schema.get("properties").and_then(JsonValue::as_object) else {
+        return Vec::new();
+    };
+    let mut properties: Vec<(&String, &JsonValue)> = properties.iter().collect();
+    properties.sort_by_key(|(name, property)| {
+        (
+            property
+                .get("position")
+                .and_then(JsonValue::as_u64)
+                .unwrap_or(u64::MAX),
+            name.to_string(),
+        )
+    });
+    properties
+        .into_iter()
+        .map(|(name, property)| parse_field(name, property, required.contains(&name.as_str())))
+        .collect()
+}
+
+fn parse_field(name: &str, schema: &JsonValue, required: bool) -> FormField {
+    let get_usize = |key: &str| {
+        schema
+            .get(key)
+            .and_then(JsonValue::as_u64)
+            .map(|value| value as usize)
+    };
+    let kind = match schema.get("type").and_then(JsonValue::as_str) {
+        Some("string") => FieldKind::Text {
+            value: String::new(),
+            pattern: schema
+                .get("pattern")
+                .and_then(JsonValue::as_str)
+                .and_then(|pattern| Regex::new(pattern).ok()),
+            min_length: get_usize("minLength"),
+            max_length: get_usize("maxLength"),
+            choices: schema
+                .get("enum")
+                .and_then(JsonValue::as_array)
+                .map(|choices| {
+                    choices
+                        .iter()
+                        .filter_map(|choice| choice.as_str().map(|choice| choice.to_string()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        },
+        Some("integer") => FieldKind::Integer {
+            value: String::new(),
+            minimum: schema.get("minimum").and_then(JsonValue::as_i64),
+            maximum: schema.get("maximum").and_then(JsonValue::as_i64),
+        },
+        Some("number") => FieldKind::Number {
+            value: String::new(),
+            minimum: schema.get("minimum").and_then(JsonValue::as_f64),
+            maximum: schema.get("maximum").and_then(JsonValue::as_f64),
+        },
+        Some("boolean") => FieldKind::Boolean(false),
+        Some("array") if schema.get("byteArray").and_then(JsonValue::as_bool) == Some(true) => {
+            FieldKind::Bytes {
+                value: String::new(),
+                min_items: get_usize("minItems"),
+                max_items: get_usize("maxItems"),
+                is_identifier: schema.get("contentMediaType").and_then(JsonValue::as_str)
+                    == Some(IDENTIFIER_MEDIA_TYPE),
+            }
+        }
+        Some("array") => FieldKind::Array {
+            item_schema: schema.get("items").cloned().unwrap_or(JsonValue::Null),
+            items: Vec::new(),
+            min_items: get_usize("minItems"),
+            max_items: get_usize("maxItems"),
+        },
+        Some("object") => FieldKind::Object {
+            fields: parse_properties(schema),
+        },
+        _ => FieldKind::RawJson {
+            value: String::new(),
+        },
+    };
+    FormField {
+        name: name.to_string(),
+        title: schema
+            .get("title")
+            .and_then(JsonValue::as_str)
+            .map(|title| title.to_string()),
+        description: schema
+            .get("description")
+            .and_then(JsonValue::as_str)
+            .map(|description| description.to_string()),
+        required,
+        kind,
+    }
+}
+
+fn show_field(ui: &mut Ui, field: &mut FormField, id_salt: &str) {
+    let mut label = field.title.clone().unwrap_or_else(|| field.name.clone());
+    if field.required {
+        label.push_str(" *");
+    }
+    let label_response = ui.label(RichText::new(label).strong());
+    if let Some(description) = &field.description {
+        label_response.on_hover_text(description);
+    }
+
+    let id_salt = format!("{}.{}", id_salt, field.name);
+    let error = ui
+        .vertical(|ui| {
+            match &mut field.kind {
+                FieldKind::Text { value, choices, .. } if !choices.is_empty() => {
+                    egui::ComboBox::from_id_salt(&id_salt)
+                        .selected_text(value.as_str())
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(value, String::new(), "-");
+                            for choice in choices.iter() {
+                                ui.selectable_value(value, choice.clone(), choice);
+                            }
+                        });
+                }
+                FieldKind::Text { value, .. }
+                | FieldKind::Integer { value, .. }
+                | FieldKind::Number { value, .. } => {
+                    ui.text_edit_singleline(value);
+                }
+                FieldKind::Bytes {
+                    value,
+                    is_identifier,
+                    ..
+                } => {
+                    let hint = if *is_identifier {
+                        "Base58 or hex identifier"
+                    } else {
+                        "Hex bytes"
+                    };
+                    ui.add(egui::TextEdit::singleline(value).hint_text(hint));
+                }
+                FieldKind::RawJson { value } => {
+                    ui.add(
+                        egui::TextEdit::multiline(value)
+                            .hint_text("JSON value")
+                            .desired_rows(2),
+                    );
+                }
+                FieldKind::Boolean(value) => {
+                    ui.checkbox(value, "");
+                }
+                FieldKind::Array {
+                    item_schema, items, ..
+                } => {
+                    let mut removed = None;
+                    for (i, item) in items.iter_mut().enumerate() {
+                        ui.horizontal(|ui| {
+                            let remove = with_accessible_label(
+                                ui.small_button("✖").on_hover_text("Remove item"),
+                                WidgetType::Button,
+                                format!("Remove item {} of {}", i + 1, field.name),
+                            );
+                            if remove.clicked() {
+                                removed = Some(i);
+                            }
+                            egui::Grid::new(format!("{}[{}]", id_salt, i))
+                                .num_columns(2)
+                                .show(ui, |ui| {
+                                    show_field(ui, item, &id_salt);
+                                });
+                        });
+                    }
+                    if let Some(i) = removed {
+                        items.remove(i);
+                    }
+                    if ui.button("Add item").clicked() {
+                        let name = format!("{}", items.len());
+                        items.push(parse_field(&name, item_schema, true));
+                    }
+                }
+                FieldKind::Object { fields } => {
+                    egui::Grid::new(&id_salt)
+                        .num_columns(2)
+                        .spacing([10.0, 6.0])
+                        .show(ui, |ui| {
+                            for nested in fields.iter_mut() {
+                                show_field(ui, nested, &id_salt);
+                                ui.end_row();
+                            }
+                        });
+                }
+            }
+            // Nested fields show their own errors
+            match &field.kind {
+                FieldKind::Object { .. } => None,
+                FieldKind::Array { .. } => field.own_error(),
+                _ => field
+                    .value(&field.name)
+                    .err()
+                    .and_then(|errors| errors.into_iter().next()),
+            }
+        })
+        .inner;
+
+    if let Some(error) = error {
+        if field.has_input() {
+            ui.end_row();
+            ui.label("");
+            ui.label(RichText::new(error).color(Color32::RED).small());
+        }
+    }
+}
+
+impl FormField {
+    fn reset(&mut self) {
+        match &mut self.kind {
+            FieldKind::Text { value, .. }
+            | FieldKind::Integer { value, .. }
+            | FieldKind::Number { value, .. }
+            | FieldKind::Bytes { value, .. }
+            | FieldKind::RawJson { value } => value.clear(),
+            FieldKind::Boolean(value) => *value = false,
+            FieldKind::Array { items, .. } => items.clear(),
+            FieldKind::Object { fields } => fields.iter_mut().for_each(FormField::reset),
+        }
+    }
+
+    fn input(&self) -> JsonValue {
+        match &self.kind {
+            FieldKind::Text { value, .. }
+            | FieldKind::Integer { value, .. }
+            | FieldKind::Number { value, .. }
+            | FieldKind::Bytes { value, .. }
+            | FieldKind::RawJson { value } => JsonValue::String(value.clone()),
+            FieldKind::Boolean(value) => JsonValue::Bool(*value),
+            FieldKind::Array { items, .. } => {
+                JsonValue::Array(items.iter().map(FormField::input).collect())
+            }
+            FieldKind::Object { fields } => JsonValue::Object(
+                fields
+                    .iter()
+                    .map(|nested| (nested.name.clone(), nested.input()))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn restore_input(&mut self, input: &JsonValue) {
+        // Imported files give arrays and objects as JSON text
+        if let JsonValue::String(text) = input {
+            if matches!(
+                self.kind,
+                FieldKind::Array { .. } | FieldKind::Object { .. }
+            ) {
+                if let Ok(parsed @ (JsonValue::Array(_) | JsonValue::Object(_))) =
+                    serde_json::from_str::<JsonValue>(text)
+                {
+                    self.restore_input(&parsed);
+                    return;
+                }
+            }
+        }
+        match (&mut self.kind, input) {
+            (
+                FieldKind::Text { value, .. }
+                | FieldKind::Integer { value, .. }
+                | FieldKind::Number { value, .. }
+                | FieldKind::Bytes { value, .. }
+                | FieldKind::RawJson { value },
+                JsonValue::String(input),
+            ) => *value = input.clone(),
+            (FieldKind::Boolean(value), JsonValue::Bool(input)) => *value = *input,
+            (FieldKind::Boolean(value), JsonValue::String(input)) => {
+                *value = matches!(input.trim().to_lowercase().as_str(), "true" | "yes" | "1")
+            }
+            (
+                FieldKind::Array {
+                    item_schema, items, ..
+                },
+                JsonValue::Array(inputs),
+            ) => {
+                *items = inputs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, input)| {
+                        let mut item = parse_field(&i.to_string(), item_schema, true);
+                        item.restore_input(input);
+                        item
+                    })
+                    .collect();
+            }
+            (FieldKind::Object { fields }, JsonValue::Object(_)) => {
+                for nested in fields.iter_mut() {
+                    if let Some(input) = input.get(&nested.name) {
+                        nested.restore_input(input);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether the user entered anything yet, errors are only shown after that
+    fn has_input(&self) -> bool {
+        match &self.kind {
+            FieldKind::Text { value, .. }
+            | FieldKind::Integer { value, .. }
+            | FieldKind::Number { value, .. }
+            | FieldKind::Bytes { value, .. }
+            | FieldKind::RawJson { value } => !value.is_empty(),
+            FieldKind::Boolean(_) => false,
+            FieldKind::Array { items, .. } => !items.is_empty(),
+            FieldKind::Object { fields } => fields.iter().any(FormField::has_input),
+        }
+    }
+
+    /// Errors of an array itself, not of its items
+    fn own_error(&self) -> Option<String> {
+        let FieldKind::Array {
+            items,
+            min_items,
+            max_items,
+            ..
+        } = &self.kind
+        else {
+            return None;
+        };
+        check_count(items.len(), *min_items, *max_items, "items")
+    }
+
+    /// The entered value, `None` for an empty optional field
+    fn value(&self, path: &str) -> Result<Option<Value>, Vec<String>> {
+        let error = |message: String| Err(vec![format!("{}: {}", path, message)]);
+
+        if !self.has_input()
+            && !matches!(self.kind, FieldKind::Boolean(_) | FieldKind::Object { .. })
+        {
+            return if self.required {
+                error("is required".to_string())
+            } else {
+                Ok(None)
+            };
+        }
+
+        match &self.kind {
+            FieldKind::Text {
+                value,
+                pattern,
+                min_length,
+                max_length,
+                ..
+            } => {
+                if let Some(message) = check_count(
+                    value.chars().count(),
+                    *min_length,
+                    *max_length,
+                    "characters",
+                ) {
+                    return error(message);
+                }
+                if let Some(pattern) = pattern {
+                    if !pattern.is_match(value) {
+                        return error(format!("must match {}", pattern.as_str()));
+                    }
+                }
+                Ok(Some(Value::Text(value.clone())))
+            }
+            FieldKind::Integer {
+                value,
+                minimum,
+                maximum,
+            } => {
+                let Ok(number) = value.trim().parse::<i64>() else {
+                    return error("must be a whole number".to_string());
+                };
+                if minimum.is_some_and(|minimum| number < minimum) {
+                    return error(format!("must be at least {}", minimum.unwrap()));
+                }
+                if maximum.is_some_and(|maximum| number > maximum) {
+                    return error(format!("must be at most {}", maximum.unwrap()));
+                }
+                Ok(Some(Value::I64(number)))
+            }
+            FieldKind::Number {
+                value,
+                minimum,
+                maximum,
+            } => {
+                let Ok(number) = value.trim().parse::<f64>() else {
+                    return error("must be a number".to_string());
+                };
+                if minimum.is_some_and(|minimum| number < minimum) {
+                    return error(format!("must be at least {}", minimum.unwrap()));
+                }
+                if maximum.is_some_and(|maximum| number > maximum) {
+                    return error(format!("must be at most {}", maximum.unwrap()));
+                }
+                Ok(Some(Value::Float(number)))
+            }
+            FieldKind::Boolean(value) => Ok(Some(Value::Bool(*value))),
+            FieldKind::Bytes {
+                value,
+                min_items,
+                max_items,
+                is_identifier,
+            } => {
+                let input = value.trim();
+                if *is_identifier {
+                    return match Identifier::from_string(input, Encoding::Base58)
+                        .or_else(|_| Identifier::from_string(input, Encoding::Hex))
+                    {
+                        Ok(identifier) => Ok(Some(Value::Identifier(identifier.to_buffer()))),
+                        Err(_) => error("must be a Base58 or hex identifier".to_string()),
+                    };
+                }
+                let Ok(bytes) = hex::decode(input) else {
+                    return error("must be hex encoded".to_string());
+                };
+                if let Some(message) = check_count(bytes.len(), *min_items, *max_items, "bytes") {
+                    return error(message);
+                }
+                Ok(Some(Value::Bytes(bytes)))
+            }
+            FieldKind::Array { items, .. } => {
+                if let Some(message) = self.own_error() {
+                    return error(message);
+                }
+                let mut errors = Vec::new();
+                let mut values = Vec::new();
+                for (i, item) in items.iter().enumerate() {
+                    match item.value(&format!("{}[{}]", path, i)) {
+                        Ok(Some(value)) => values.push(value),
+                        Ok(None) => {}
+                        Err(item_errors) => errors.extend(item_errors),
+                    }
+                }
+                if errors.is_empty() {
+                    Ok(Some(Value::Array(values)))
+                } else {
+                    Err(errors)
+                }
+            }
+            FieldKind::Object { fields } => {
+                if !self.has_input() && !self.required {
+                    return Ok(None);
+                }
+                let mut errors = Vec::new();
+                let mut entries = Vec::new();
+                for nested in fields {
+                    match nested.value(&format!("{}.{}", path, nested.name)) {
+                        Ok(Some(value)) => entries.push((Value::Text(nested.name.clone()), value)),
+                        Ok(None) => {}
+                        Err(nested_errors) => errors.extend(nested_errors),
+                    }
+                }
+                if errors.is_empty() {
+                    Ok(Some(Value::Map(entries)))
+                } else {
+                    Err(errors)
+                }
+            }
+            FieldKind::RawJson { value } => match serde_json::from_str::<JsonValue>(value) {
+                Ok(json) => Ok(Some(json.into())),
+                Err(e) => error(format!("invalid JSON: {}", e)),
+            },
+        }
+    }
+}
+
+fn check_count(
+    count: usize,
+    minimum: Option<usize>,
+    maximum: Option<usize>,
+    unit: &str,
+) -> Option<String> {
+    if minimum.is_some_and(|minimum| count < minimum) {
+        return Some(format!("must have at least {} {}", minimum.unwrap(), unit));
+    }
+    if maximum.is_some_and(|maximum| count > maximum) {
+        return Some(format!("must have at most {} {}", maximum.unwrap(), unit));
+    }
+    None
+}
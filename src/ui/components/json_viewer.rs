@@ -0,0 +1,275 @@
+use eframe::egui::{self, Color32, RichText, Ui};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Pretty-printed view of a JSON value with collapsible objects and arrays, colored by type.
+/// Entries can be filtered by a search term, right-click an entry to copy its path or value.
+pub struct JsonViewer {
+    id_salt: String,
+    value: Value,
+    search: String,
+    /// Opens or closes every node on the next frame
+    set_open: Option<bool>,
+}
+
+struct JsonColors {
+    key: Color32,
+    string: Color32,
+    number: Color32,
+    literal: Color32,
+    punctuation: Color32,
+}
+
+impl JsonColors {
+    fn for_ui(ui: &Ui) -> Self {
+        if ui.visuals().dark_mode {
+            Self {
+                key: Color32::from_rgb(156, 220, 254),
+                string: Color32::from_rgb(206, 145, 120),
+                number: Color32::from_rgb(181, 206, 168),
+                literal: Color32::from_rgb(86, 156, 214),
+                punctuation: Color32::GRAY,
+            }
+        } else {
+            Self {
+                key: Color32::from_rgb(4, 81, 165),
+                string: Color32::from_rgb(163, 21, 21),
+                number: Color32::from_rgb(9, 134, 88),
+                literal: Color32::from_rgb(0, 0, 255),
+                punctuation: Color32::DARK_GRAY,
+            }
+        }
+    }
+}
+
+impl JsonViewer {
+    pub fn new(id_salt: impl Into<String>, value: Value) -> Self {
+        Self {
+            id_salt: id_salt.into(),
+            value,
+            search: String::new(),
+            set_open: None,
+        }
+    }
+
+    pub fn from_serializable<T: Serialize>(
+        id_salt: impl Into<String>,
+        value: &T,
+    ) -> Result<Self, String> {
+        serde_json::to_value(value)
+            .map(|value| Self::new(id_salt, value))
+            .map_err(|e| format!("Failed to convert to JSON: {}", e))
+    }
+
+    pub fn show(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Search:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.search)
+                    .desired_width(200.0)
+                    .hint_text("Key or value"),
+            );
+            if ui.button("Expand all").clicked() {
+                self.set_open = Some(true);
+            }
+            if ui.button("Collapse all").clicked() {
+                self.set_open = Some(false);
+            }
+            if ui.button("Copy JSON").clicked() {
+                ui.ctx()
+                    .copy_text(serde_json::to_string_pretty(&self.value).unwrap_or_default());
+            }
+        });
+        ui.add_space(5.0);
+
+        let search = self.search.trim().to_lowercase();
+        let view = NodeView {
+            id_salt: &self.id_salt,
+            search: &search,
+            set_open: self.set_open.take(),
+            colors: JsonColors::for_ui(ui),
+        };
+        if !search.is_empty() && !view.matches(None, &self.value) {
+            ui.label(RichText::new("Nothing matches the search").color(Color32::GRAY));
+            return;
+        }
+        egui::ScrollArea::both()
+            .id_salt((&self.id_salt, "json_viewer_scroll"))
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                view.show_node(ui, None, &self.value, "$".to_string(), 0);
+            });
+    }
+}
+
+/// Rendering state of one frame
+struct NodeView<'a> {
+    id_salt: &'a str,
+    search: &'a str,
+    set_open: Option<bool>,
+    colors: JsonColors,
+}
+
+impl NodeView<'_> {
+    /// Whether the key or anything in the value contains the search term
+    fn matches(&self, key: Option<&str>, value: &Value) -> bool {
+        if self.search.is_empty() || key.is_some_and(|key| self.contains(key)) {
+            return true;
+        }
+        match value {
+            Value::Object(map) => map
+                .iter()
+                .any(|(key, value)| self.matches(Some(key), value)),
+            Value::Array(items) => items.iter().any(|item| self.matches(None, item)),
+            Value::String(s) => self.contains(s),
+            leaf => self.contains(&leaf.to_string()),
+        }
+    }
+
+    fn contains(&self, text: &str) -> bool {
+        !self.search.is_empty() && text.to_lowercase().contains(self.search)
+    }
+
+    fn key_text(&self, key: Option<&str>) -> Option<RichText> {
+        key.map(|key| {
+            let text = RichText::new(format!("\"{}\"", key))
+                .monospace()
+                .color(self.colors.key);
+            if self.contains(key) {
+                text.background_color(Color32::from_rgba_unmultiplied(255, 200, 0, 60))
+            } else {
+                text
+            }
+        })
+    }
+
+    fn show_node(&self, ui: &mut Ui, key: Option<&str>, value: &Value, path: String, depth: usize) {
+        match value {
+            Value::Object(_) | Value::Array(_) => self.show_container(ui, key, value, &path, depth),
+            leaf => {
+                let (text, color) = match leaf {
+                    Value::String(s) => (format!("\"{}\"", s), self.colors.string),
+                    Value::Number(n) => (n.to_string(), self.colors.number),
+                    other => (other.to_string(), self.colors.literal),
+                };
+                let mut value_text = RichText::new(&text).monospace().color(color);
+                if self.contains(&text) {
+                    value_text = value_text
+                        .background_color(Color32::from_rgba_unmultiplied(255, 200, 0, 60));
+                }
+                let response = ui
+                    .horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = 4.0;
+                        if let Some(key_text) = self.key_text(key) {
+                            ui.label(key_text);
+                            ui.label(RichText::new(":").color(self.colors.punctuation));
+                        }
+                        ui.add(egui::Label::new(value_text).sense(egui::Sense::click()))
+                    })
+                    .inner;
+                self.entry_menu(&response.on_hover_text(path.as_str()), &path, value);
+            }
+        }
+    }
+
+    fn show_container(
+        &self,
+        ui: &mut Ui,
+        key: Option<&str>,
+        value: &Value,
+        path: &str,
+        depth: usize,
+    ) {
+        let (children, open_bracket, close_bracket): (Vec<(Option<&str>, &Value, String)>, _, _) =
+            match value {
+                Value::Object(map) => (
+                    map.iter()
+                        .map(|(child_key, child)| {
+                            (Some(child_key.as_str()), child, child_path(path, child_key))
+                        })
+                        .collect(),
+                    "{",
+                    "}",
+                ),
+                Value::Array(items) => (
+                    items
+                        .iter()
+                        .enumerate()
+                        .map(|(i, item)| (None, item, format!("{}[{}]", path, i)))
+                        .collect(),
+                    "[",
+                    "]",
+                ),
+                _ => return,
+            };
+        let mut header = egui::text::LayoutJob::default();
+        let font = egui::TextStyle::Monospace.resolve(ui.style());
+        if let Some(key) = key {
+            header.append(
+                &format!("\"{}\": ", key),
+                0.0,
+                egui::TextFormat::simple(font.clone(), self.colors.key),
+            );
+        }
+        header.append(
+            &format!(
+                "{}{} {}{}",
+                open_bracket,
+                if children.is_empty() { "" } else { "…" },
+                children.len(),
+                close_bracket
+            ),
+            0.0,
+            egui::TextFormat::simple(font, self.colors.punctuation),
+        );
+
+        let searching = !self.search.is_empty();
+        let open = if searching { Some(true) } else { self.set_open };
+        let response = egui::CollapsingHeader::new(header)
+            .id_salt((self.id_salt, path))
+            .default_open(depth < 2)
+            .open(open)
+            .show(ui, |ui| {
+                for (child_key, child, child_path) in children {
+                    if searching && !self.matches(child_key, child) {
+                        continue;
+                    }
+                    self.show_node(ui, child_key, child, child_path, depth + 1);
+                }
+            });
+        self.entry_menu(&response.header_response.on_hover_text(path), path, value);
+    }
+
+    fn entry_menu(&self, response: &egui::Response, path: &str, value: &Value) {
+        response.context_menu(|ui| {
+            if ui.button("Copy path").clicked() {
+                ui.ctx().copy_text(path.to_string());
+                ui.close_menu();
+            }
+            if ui.button("Copy value").clicked() {
+                let text = match value {
+                    Value::String(s) => s.clone(),
+                    other => serde_json::to_string_pretty(other).unwrap_or_default(),
+                };
+                ui.ctx().copy_text(text);
+                ui.close_menu();
+            }
+        });
+    }
+}
+
+/// JSONPath of an object member, e.g. `$.documents` or `$["$type"]`
+fn child_path(parent: &str, key: &str) -> String {
+    let is_plain = !key.is_empty()
+        && !key.starts_with(|c: char| c.is_ascii_digit())
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_plain {
+        format!("{}.{}", parent, key)
+    } else {
+        format!(
+            "{}[{}]",
+            parent,
+            serde_json::to_string(key).unwrap_or_default()
+        )
+    }
+}
@@ -1,10 +1,17 @@
 use crate::app::AppAction;
 use crate::context::AppContext;
-use crate::ui::RootScreenType;
+use crate::model::clock_skew::network_now;
+use crate::ui::components::accessibility::with_accessible_label;
+use crate::ui::helpers::number_format::NumberFormatter;
+use crate::ui::{RootScreenType, ScreenType};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use eframe::epaint::{Color32, Margin};
-use egui::{Context, Frame, ImageButton, SidePanel, TextureHandle};
+use egui::{
+    Align2, Context, FontId, Frame, Id, ImageButton, Rect, SidePanel, TextureHandle, Ui, WidgetType,
+};
 use rust_embed::RustEmbed;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[derive(RustEmbed)]
 #[folder = "icons/"] // Adjust the folder path if necessary
@@ -35,6 +42,128 @@ fn load_icon(ctx: &Context, path: &str) -> Option<TextureHandle> {
     }
 }
 
+/// How long the badge counts are reused before they are read from the database again
+const BADGE_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Counts shown on the panel buttons so action items are noticed from any screen
+#[derive(Clone)]
+struct Badges {
+    active_contests: usize,
+    unvoted_contests: usize,
+    identities: usize,
+    identity_balance: u64,
+    loaded_at: Instant,
+}
+
+impl Badges {
+    fn load(app_context: &AppContext) -> Self {
+        let now = network_now().timestamp_millis() as u64;
+        let active: Vec<_> = app_context
+            .ongoing_contested_names()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|contested_name| !contested_name.has_ended(now))
+            .collect();
+        let identities = app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default();
+        Badges {
+            active_contests: active.len(),
+            unvoted_contests: active
+                .iter()
+                .filter(|contested_name| contested_name.my_votes.is_empty())
+                .count(),
+            identities: identities.len(),
+            identity_balance: identities
+                .iter()
+                .map(|identity| identity.identity.balance())
+                .sum(),
+            loaded_at: Instant::now(),
+        }
+    }
+
+    /// The cached counts for the network, reloaded once they are older than the interval
+    fn get(ctx: &Context, app_context: &AppContext) -> Self {
+        let id = Id::new(("left_panel_badges", app_context.network_string()));
+        if let Some(badges) = ctx.data(|data| data.get_temp::<Badges>(id)) {
+            if badges.loaded_at.elapsed() < BADGE_REFRESH_INTERVAL {
+                return badges;
+            }
+        }
+        let badges = Self::load(app_context);
+        ctx.data_mut(|data| data.insert_temp(id, badges.clone()));
+        badges
+    }
+
+    /// The count on a screen's button with its color, and a description for the hover
+    fn for_screen(
+        &self,
+        screen_type: RootScreenType,
+        number_format: &NumberFormatter,
+    ) -> Option<(usize, Color32, String)> {
+        match screen_type {
+            RootScreenType::RootScreenDPNSContestedNames if self.unvoted_contests > 0 => Some((
+                self.unvoted_contests,
+                Color32::from_rgb(255, 140, 0),
+                format!(
+                    "{} active contests, {} not voted on",
+                    self.active_contests, self.unvoted_contests
+                ),
+            )),
+            RootScreenType::RootScreenDPNSContestedNames if self.active_contests > 0 => Some((
+                self.active_contests,
+                Color32::GRAY,
+                format!("{} active contests, all voted on", self.active_contests),
+            )),
+            RootScreenType::RootScreenIdentities if self.identities > 0 => Some((
+                self.identities,
+                Color32::GRAY,
+                format!(
+                    "{} identities holding {}",
+                    self.identities,
+                    number_format.format_credits_as_dash(self.identity_balance)
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Draws a count in a small circle over the top right corner of a button
+fn paint_badge(ui: &Ui, button: Rect, count: usize, color: Color32) {
+    let text = if count > 99 {
+        "99+".to_string()
+    } else {
+        count.to_string()
+    };
+    let center = button.right_top() + egui::vec2(-4.0, 4.0);
+    let radius = if text.len() > 2 { 10.0 } else { 8.0 };
+    let painter = ui.painter();
+    painter.circle_filled(center, radius, color);
+    painter.text(
+        center,
+        Align2::CENTER_CENTER,
+        text,
+        FontId::proportional(10.0),
+        Color32::WHITE,
+    );
+}
+
+/// Up to two letters of an app profile's name for its button, e.g. "DS" for "Dash Social"
+fn profile_initials(name: &str) -> String {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    let initials: String = if words.len() > 1 {
+        words
+            .iter()
+            .filter_map(|word| word.chars().next())
+            .take(2)
+            .collect()
+    } else {
+        name.chars().take(2).collect()
+    };
+    initials.to_uppercase()
+}
+
 pub fn add_left_panel(
     ctx: &Context,
     app_context: &Arc<AppContext>,
@@ -44,21 +173,43 @@ pub fn add_left_panel(
 
     // Define the button details directly in this function
     let buttons = [
-        ("I", RootScreenType::RootScreenIdentities, "identity.png"),
+        (
+            "I",
+            "Identities",
+            RootScreenType::RootScreenIdentities,
+            "identity.png",
+        ),
         (
             "C",
+            "Contested Names",
             RootScreenType::RootScreenDPNSContestedNames,
             "voting.png",
         ),
-        ("Q", RootScreenType::RootScreenDocumentQuery, "doc.png"),
+        (
+            "Q",
+            "Document Query",
+            RootScreenType::RootScreenDocumentQuery,
+            "doc.png",
+        ),
         (
             "T",
+            "Tools",
             RootScreenType::RootScreenTransitionVisualizerScreen,
             "tools.png",
         ),
-        ("N", RootScreenType::RootScreenNetworkChooser, "config.png"),
+        (
+            "N",
+            "Network",
+            RootScreenType::RootScreenNetworkChooser,
+            "config.png",
+        ),
     ];
 
+    let badges = Badges::get(ctx, app_context);
+    let number_format = NumberFormatter::new(&app_context.preferences());
+    let pending_tasks = app_context.task_gate.pending_count();
+    let app_profiles = app_context.app_profiles();
+
     let panel_width = 50.0 + 20.0; // Button width (50) + 10px margin on each side (20 total)
 
     SidePanel::left("left_panel")
@@ -75,13 +226,18 @@ pub fn add_left_panel(
         )
         .show(ctx, |ui| {
             ui.vertical_centered(|ui| {
-                for (label, screen_type, icon_path) in buttons.iter() {
+                for (label, name, screen_type, icon_path) in buttons.iter() {
                     if *screen_type == RootScreenType::RootScreenDocumentQuery {
                         continue; // Skip rendering the document button for now
                     }
 
                     let texture: Option<TextureHandle> = load_icon(ctx, icon_path);
                     let is_selected = selected_screen == *screen_type;
+                    let badge = badges.for_screen(*screen_type, &number_format);
+                    let hover_text = match &badge {
+                        Some((_, _, description)) => format!("{}\n{}", name, description),
+                        None => name.to_string(),
+                    };
                     let button_color = if is_selected {
                         Color32::from_rgb(100, 149, 237) // Highlighted blue color for selected
                     } else {
@@ -94,7 +250,16 @@ pub fn add_left_panel(
                             .frame(false) // Remove button frame
                             .tint(button_color);
 
-                        if ui.add(button).clicked() {
+                        // The icon says nothing to a screen reader, announce the screen name
+                        let response = with_accessible_label(
+                            ui.add(button).on_hover_text(&hover_text),
+                            WidgetType::Button,
+                            &hover_text,
+                        );
+                        if let Some((count, color, _)) = &badge {
+                            paint_badge(ui, response.rect, *count, *color);
+                        }
+                        if response.clicked() {
                             action = AppAction::SetMainScreen(*screen_type);
                         }
                     } else {
@@ -103,13 +268,57 @@ pub fn add_left_panel(
                             .fill(button_color)
                             .min_size(egui::vec2(50.0, 50.0));
 
-                        if ui.add(button).clicked() {
+                        let response = with_accessible_label(
+                            ui.add(button).on_hover_text(&hover_text),
+                            WidgetType::Button,
+                            &hover_text,
+                        );
+                        if let Some((count, color, _)) = &badge {
+                            paint_badge(ui, response.rect, *count, *color);
+                        }
+                        if response.clicked() {
                             action = AppAction::SetMainScreen(*screen_type);
                         }
                     }
 
                     ui.add_space(10.0); // Add some space between buttons
                 }
+
+                if !app_profiles.is_empty() {
+                    ui.separator();
+                    ui.add_space(10.0);
+                }
+                for profile in &app_profiles {
+                    let button = egui::Button::new(profile_initials(&profile.name))
+                        .fill(Color32::from_rgb(169, 169, 169))
+                        .min_size(egui::vec2(50.0, 50.0));
+                    let hover_text = format!("{} (app profile)", profile.name);
+                    let response = with_accessible_label(
+                        ui.add(button).on_hover_text(&hover_text),
+                        WidgetType::Button,
+                        &hover_text,
+                    );
+                    if response.clicked() {
+                        action = AppAction::AddScreen(
+                            ScreenType::AppProfile(profile.name.clone()).create_screen(app_context),
+                        );
+                    }
+                    ui.add_space(10.0);
+                }
+
+                if pending_tasks > 0 {
+                    let description = if pending_tasks == 1 {
+                        "1 task running".to_string()
+                    } else {
+                        format!("{} tasks running", pending_tasks)
+                    };
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(pending_tasks.to_string());
+                    })
+                    .response
+                    .on_hover_text(description);
+                }
             });
         });
 
@@ -1,4 +1,20 @@
+pub mod accessibility;
+pub mod approval_window;
+pub mod charts;
+pub mod clipboard_popup;
 pub mod contract_chooser_panel;
+pub mod detachable_window;
 pub mod entropy_grid;
+pub mod explorer_link;
+pub mod fee_selector;
+pub mod fiat_value;
+pub mod form_drafts;
+pub mod global_search;
+pub mod help_overlay;
+pub mod json_schema_form;
+pub mod json_viewer;
 pub mod left_panel;
+pub mod proof_status;
+pub mod recipient_warning;
 pub mod top_panel;
+pub mod vote_triage;
@@ -0,0 +1,53 @@
+use crate::context::AppContext;
+use crate::ui::helpers::time_format::TimeFormatter;
+use eframe::egui::{Color32, Ui};
+
+/// Methods of the contested names queries
+pub const CONTESTED_NAMES_METHODS: [&str; 1] = ["contested_resource"];
+/// Methods of the identity queries
+pub const IDENTITY_METHODS: [&str; 1] = ["identity"];
+/// Methods of the document and contract queries
+pub const DOCUMENT_METHODS: [&str; 2] = ["documents", "data_contract"];
+
+/// A line telling whether the last response of the screen's queries was verified against a
+/// proof, with the quorum and the platform height and time it was verified at. Nothing is shown
+/// before the first response.
+pub fn add_proof_status(ui: &mut Ui, app_context: &AppContext, method_patterns: &[&str]) {
+    let Some(response) = app_context.request_metrics.last_response(method_patterns) else {
+        return;
+    };
+    let time_format = TimeFormatter::new(&app_context.preferences());
+    let received = time_format.format(response.started_at);
+    let endpoint = response.endpoint.as_deref().unwrap_or("unknown node");
+
+    match &response.proof {
+        Some(proof) => {
+            let mut text = "🔒 Proof verified".to_string();
+            if let Some(height) = proof.height {
+                text.push_str(&format!(" at height {}", height));
+            }
+            if let Some(time_ms) = proof.time_ms {
+                text.push_str(&format!(" ({})", time_format.format_millis(time_ms)));
+            }
+            let quorum = match (&proof.quorum_hash, proof.quorum_type) {
+                (Some(hash), Some(quorum_type)) => format!("{} (type {})", hash, quorum_type),
+                (Some(hash), None) => hash.clone(),
+                _ => "not reported".to_string(),
+            };
+            ui.colored_label(Color32::DARK_GREEN, text)
+                .on_hover_text(format!(
+                    "The last {} response from {} received {} was checked against the state root \
+                 signed by a quorum.\nQuorum: {}\nVerification took {:.0?}",
+                    response.method, endpoint, received, quorum, response.proof_verification
+                ));
+        }
+        None => {
+            ui.colored_label(Color32::from_rgb(200, 120, 0), "⚠ Not proof-verified")
+                .on_hover_text(format!(
+                    "The last {} response from {} received {} came without a verified proof. \
+                     Its data is only as trustworthy as that node.",
+                    response.method, endpoint, received
+                ));
+        }
+    }
+}
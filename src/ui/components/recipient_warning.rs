@@ -0,0 +1,82 @@
+use crate::context::AppContext;
+use crate::model::recipient_check::{find_lookalike, KnownRecipient, LookalikeRecipient};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use eframe::egui::{self, Color32, RichText, Ui};
+
+/// Checks the recipient against the contacts, the local identities and the addresses that
+/// credits were sent to before
+pub fn find_lookalike_recipient(
+    app_context: &AppContext,
+    recipient: &str,
+) -> Option<LookalikeRecipient> {
+    let mut known = Vec::new();
+    match app_context.db.get_contacts(app_context) {
+        Ok(contacts) => known.extend(contacts.into_iter().map(|contact| KnownRecipient {
+            recipient: contact.identity_id.to_string(Encoding::Base58),
+            source: match contact.alias {
+                Some(alias) => format!("contact {}", alias),
+                None => "contact".to_string(),
+            },
+        })),
+        Err(e) => tracing::warn!("Failed to load contacts: {}", e),
+    }
+    match app_context.load_local_qualified_identities() {
+        Ok(identities) => known.extend(identities.into_iter().map(|identity| KnownRecipient {
+            recipient: identity.identity.id().to_string(Encoding::Base58),
+            source: format!("local identity {}", identity.display_string()),
+        })),
+        Err(e) => tracing::warn!("Failed to load identities: {}", e),
+    }
+    match app_context.db.get_used_recipients(app_context) {
+        Ok(recipients) => known.extend(recipients.into_iter().map(|recipient| KnownRecipient {
+            recipient,
+            source: "previously used recipient".to_string(),
+        })),
+        Err(e) => tracing::warn!("Failed to load used recipients: {}", e),
+    }
+    find_lookalike(recipient, &known)
+}
+
+/// Warns that the recipient resembles a known one. Returns whether the user confirmed that the
+/// recipient is intended, through the checkbox bound to `acknowledged`.
+pub fn show_lookalike_warning(
+    ui: &mut Ui,
+    lookalike: &LookalikeRecipient,
+    acknowledged: &mut bool,
+) -> bool {
+    egui::Frame::none()
+        .fill(Color32::from_rgb(255, 235, 205))
+        .stroke(egui::Stroke::new(2.0, Color32::from_rgb(220, 80, 0)))
+        .inner_margin(8.0)
+        .rounding(4.0)
+        .show(ui, |ui| {
+            ui.label(
+                RichText::new("⚠ This recipient looks like one you know, but it is different")
+                    .strong()
+                    .color(Color32::from_rgb(180, 40, 0)),
+            );
+            ui.label(
+                RichText::new(format!("Entered:  {}", lookalike.entered))
+                    .monospace()
+                    .color(Color32::BLACK),
+            );
+            ui.label(
+                RichText::new(format!(
+                    "Known:    {} ({})",
+                    lookalike.known.recipient, lookalike.known.source
+                ))
+                .monospace()
+                .color(Color32::BLACK),
+            );
+            ui.label(
+                RichText::new(
+                    "Scammers send dust from look-alike addresses so that they get copied from \
+                     the history. Compare every character before sending.",
+                )
+                .color(Color32::BLACK),
+            );
+            ui.checkbox(acknowledged, "I checked it, this is the recipient I intend");
+        });
+    *acknowledged
+}
@@ -1,12 +1,86 @@
 use crate::app::{AppAction, DesiredAppAction};
 use crate::context::AppContext;
+use crate::model::preferences::VerificationMode;
+use crate::platform::QUERY_TASK_KINDS;
+use crate::ui::components::global_search::{add_global_search, SEARCH_BOX_WIDTH};
+use crate::ui::components::help_overlay::{is_help_open, toggle_help};
+use crate::ui::helpers::display_scale::scaled_size;
+use crate::ui::{RootScreenType, ScreenType};
 use dash_sdk::dashcore_rpc::dashcore::Network;
-use egui::{Align, Color32, Context, Frame, Layout, Margin, RichText, Stroke, TopBottomPanel, Ui};
+use egui::{
+    Align, Color32, Context, FontId, Frame, Layout, Margin, RichText, Stroke, TopBottomPanel, Ui,
+};
 use std::sync::Arc;
 
-fn add_location_view(ui: &mut Ui, location: Vec<(&str, AppAction)>) -> AppAction {
+/// The dropdown on the first breadcrumb segment, to jump to a main screen or switch network
+/// from anywhere
+fn add_app_menu(ui: &mut Ui, app_context: &Arc<AppContext>, font_id: FontId) -> AppAction {
     let mut action = AppAction::None;
-    let font_id = egui::FontId::proportional(22.0);
+    let button =
+        egui::Button::new(RichText::new("▾").font(font_id).color(Color32::WHITE)).frame(false);
+    egui::menu::menu_custom_button(ui, button, |ui| {
+        ui.label(RichText::new("Go to").strong());
+        let screens = [
+            ("Identities", RootScreenType::RootScreenIdentities),
+            (
+                "Contested Names",
+                RootScreenType::RootScreenDPNSContestedNames,
+            ),
+            (
+                "Tools",
+                RootScreenType::RootScreenTransitionVisualizerScreen,
+            ),
+        ];
+        for (label, screen_type) in screens {
+            if ui.button(label).clicked() {
+                action = AppAction::SetMainScreen(screen_type);
+                ui.close_menu();
+            }
+        }
+        if ui.button("App Profiles").clicked() {
+            action = AppAction::AddScreen(ScreenType::AppProfiles.create_screen(app_context));
+            ui.close_menu();
+        }
+        ui.separator();
+        ui.label(RichText::new("Network").strong());
+        for (label, network) in [
+            ("Mainnet", Network::Dash),
+            ("Testnet", Network::Testnet),
+            ("Devnet", Network::Devnet),
+        ] {
+            let current = app_context.network == network;
+            if ui
+                .add_enabled(!current, egui::Button::new(label).selected(current))
+                .clicked()
+            {
+                action = AppAction::SwitchNetwork(network);
+                ui.close_menu();
+            }
+        }
+        ui.separator();
+        if ui.button("Settings").clicked() {
+            action = AppAction::AddScreen(ScreenType::Settings.create_screen(app_context));
+            ui.close_menu();
+        }
+        ui.menu_button("About", |ui| {
+            ui.label(format!("Dash Evo Tool {}", env!("CARGO_PKG_VERSION")));
+            ui.label(
+                RichText::new("Identities, names and votes on Dash Platform").color(Color32::GRAY),
+            );
+        });
+    })
+    .response
+    .on_hover_text("Main screens, network and settings");
+    action
+}
+
+fn add_location_view(
+    ui: &mut Ui,
+    location: Vec<(&str, AppAction)>,
+    app_context: &Arc<AppContext>,
+) -> AppAction {
+    let mut action = AppAction::None;
+    let font_id = egui::FontId::proportional(scaled_size(ui.ctx(), 22.0));
 
     egui::menu::bar(ui, |ui| {
         ui.horizontal(|ui| {
@@ -22,6 +96,9 @@ fn add_location_view(ui: &mut Ui, location: Vec<(&str, AppAction)>) -> AppAction
                 {
                     action = location_action;
                 }
+                if index == 0 {
+                    action |= add_app_menu(ui, app_context, font_id.clone());
+                }
 
                 // Add a separator (e.g., '>' symbol) between buttons, except for the last one
                 if index < len - 1 {
@@ -37,6 +114,131 @@ fn add_location_view(ui: &mut Ui, location: Vec<(&str, AppAction)>) -> AppAction
 
     action
 }
+
+/// Space between the buttons on the right
+const RIGHT_BUTTON_SPACING: f32 = 8.0;
+
+fn right_button_font(ctx: &Context) -> FontId {
+    FontId::proportional(scaled_size(ctx, 16.0))
+}
+
+fn text_width(ui: &Ui, text: &str, font_id: FontId) -> f32 {
+    ui.fonts(|fonts| fonts.layout_no_wrap(text.to_string(), font_id, Color32::WHITE))
+        .size()
+        .x
+}
+
+/// A button in the style of the top panel's right buttons
+fn right_button(ui: &Ui, text: &str) -> egui::Button<'static> {
+    let font_id = right_button_font(ui.ctx());
+    let button_width = text_width(ui, text, font_id.clone()) + 12.0; // Add padding for the button
+    egui::Button::new(RichText::new(text).font(font_id).color(Color32::WHITE))
+        .fill(Color32::from_rgb(0, 128, 255))
+        .rounding(3.0)
+        .stroke(Stroke::new(1.0, Color32::WHITE))
+        .min_size(egui::vec2(button_width, scaled_size(ui.ctx(), 30.0)))
+}
+
+/// Width a right button takes in the panel, its spacing included
+fn right_button_width(ui: &Ui, text: &str) -> f32 {
+    text_width(ui, text, right_button_font(ui.ctx()))
+        + 12.0
+        + ui.spacing().button_padding.x * 2.0
+        + ui.spacing().item_spacing.x
+        + RIGHT_BUTTON_SPACING
+}
+
+/// How many of the first buttons go into the overflow menu so the rest fit in `available`.
+/// The last buttons, nearest the edge, stay visible the longest.
+fn overflow_count(widths: &[f32], overflow_button_width: f32, available: f32) -> usize {
+    if widths.iter().sum::<f32>() <= available {
+        return 0;
+    }
+    let mut used = overflow_button_width;
+    let mut count = widths.len();
+    for width in widths.iter().rev() {
+        if used + width > available {
+            break;
+        }
+        used += width;
+        count -= 1;
+    }
+    count
+}
+
+/// The text and fill of the verification indicator, with the modes of each query for the hover
+fn verification_state(app_context: &Arc<AppContext>) -> (&'static str, Color32, String) {
+    let verification = app_context.preferences().query_verification;
+    let modes: Vec<(&str, VerificationMode)> = QUERY_TASK_KINDS
+        .iter()
+        .map(|(kind, label)| (*label, verification.mode_for(kind)))
+        .collect();
+    let fast_count = modes
+        .iter()
+        .filter(|(_, mode)| *mode == VerificationMode::Fast)
+        .count();
+    let (text, fill) = if fast_count == 0 {
+        ("🔒 Verified", Color32::from_rgb(0, 110, 50))
+    } else if fast_count == modes.len() {
+        ("⚡ Fast (unproved)", Color32::from_rgb(180, 40, 40))
+    } else {
+        ("⚡ Partly unproved", Color32::from_rgb(180, 40, 40))
+    };
+    let details = modes
+        .iter()
+        .map(|(label, mode)| format!("{}: {}", label, mode.label()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    (text, fill, details)
+}
+
+/// Shows whether queries are verified, so fast mode is never on unnoticed
+fn add_verification_indicator(ui: &mut Ui, app_context: &Arc<AppContext>) {
+    let (text, fill, details) = verification_state(app_context);
+    Frame::none()
+        .fill(fill)
+        .rounding(3.0)
+        .inner_margin(Margin::symmetric(6.0, 3.0))
+        .show(ui, |ui| {
+            ui.label(RichText::new(text).color(Color32::WHITE));
+        })
+        .response
+        .on_hover_text(format!(
+            "{}\n\nUnproved responses are trusted as the DAPI node sent them. State \
+             transitions are always verified. Change this in the network settings.",
+            details
+        ));
+}
+
+/// Turns privacy mode on and off, highlighted while it hides ids, balances and keys
+fn add_privacy_toggle(ui: &mut Ui, app_context: &Arc<AppContext>) {
+    let privacy_mode = app_context.preferences().privacy_mode;
+    let button = egui::Button::new(RichText::new("👁").color(Color32::WHITE))
+        .fill(if privacy_mode {
+            Color32::from_rgb(0, 128, 255)
+        } else {
+            Color32::TRANSPARENT
+        })
+        .rounding(3.0)
+        .stroke(Stroke::new(1.0, Color32::WHITE))
+        .min_size(egui::vec2(
+            scaled_size(ui.ctx(), 30.0),
+            scaled_size(ui.ctx(), 30.0),
+        ));
+    let hover = if privacy_mode {
+        "Privacy mode is on: identity ids, balances and keys are hidden. Click to show them."
+    } else {
+        "Privacy mode: hide identity ids, balances and keys before taking a screenshot"
+    };
+    if ui.add(button).on_hover_text(hover).clicked() {
+        if let Err(e) =
+            app_context.update_preferences(|preferences| preferences.privacy_mode = !privacy_mode)
+        {
+            tracing::error!("Failed to save privacy mode: {:?}", e);
+        }
+    }
+}
+
 pub fn add_top_panel(
     ctx: &Context,
     app_context: &Arc<AppContext>,
@@ -59,41 +261,88 @@ pub fn add_top_panel(
                 .fill(color)
                 .inner_margin(Margin::symmetric(10.0, 10.0)),
         )
-        .exact_height(50.0)
+        .exact_height(scaled_size(ctx, 50.0))
         .show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
                 // Left-aligned content with location view
-                action = add_location_view(ui, location);
+                action = add_location_view(ui, location, app_context);
 
-                // Right-aligned content with buttons
+                // Right-aligned content with buttons, the first ones move into an overflow menu
+                // when the window is too narrow for all of them
                 ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                    for (text, right_button_action) in right_buttons.into_iter().rev() {
-                        ui.add_space(8.0);
-
-                        let font_id = egui::FontId::proportional(16.0); // Adjust font size as needed
-                        let color = Color32::WHITE;
-
-                        // Calculate text size using layout method
-                        let button_text = text.to_string();
-                        let text_size = ui
-                            .fonts(|fonts| {
-                                fonts.layout_no_wrap(button_text.clone(), font_id.clone(), color)
-                            })
-                            .size();
-
-                        let button_width = text_size.x + 12.0; // Add padding for the button
-
-                        let button = egui::Button::new(RichText::new(text).color(Color32::WHITE))
-                            .fill(Color32::from_rgb(0, 128, 255)) // Button background color
-                            .frame(true) // Button frame
-                            .rounding(3.0) // Rounded corners
-                            .stroke(Stroke::new(1.0, Color32::WHITE)) // Border stroke
-                            .min_size(egui::vec2(button_width, 30.0));
-
-                        if ui.add(button).clicked() {
+                    // The help and privacy mode buttons
+                    let help_width = 2.0
+                        * (scaled_size(ui.ctx(), 30.0)
+                            + ui.spacing().item_spacing.x
+                            + RIGHT_BUTTON_SPACING);
+                    let indicator_width = text_width(
+                        ui,
+                        verification_state(app_context).0,
+                        egui::TextStyle::Body.resolve(ui.style()),
+                    ) + 12.0
+                        + ui.spacing().item_spacing.x
+                        + RIGHT_BUTTON_SPACING;
+                    let search_width = scaled_size(ui.ctx(), SEARCH_BOX_WIDTH)
+                        + ui.spacing().item_spacing.x
+                        + RIGHT_BUTTON_SPACING;
+                    let available =
+                        ui.available_width() - help_width - indicator_width - search_width;
+                    let widths: Vec<f32> = right_buttons
+                        .iter()
+                        .map(|(text, _)| right_button_width(ui, text))
+                        .collect();
+                    let overflow_count =
+                        overflow_count(&widths, right_button_width(ui, "…"), available);
+                    let mut visible_buttons = right_buttons;
+                    let overflow_buttons: Vec<_> =
+                        visible_buttons.drain(..overflow_count).collect();
+
+                    for (text, right_button_action) in visible_buttons.into_iter().rev() {
+                        ui.add_space(RIGHT_BUTTON_SPACING);
+                        if ui.add(right_button(ui, text)).clicked() {
                             action = right_button_action.create_action(app_context);
                         }
                     }
+                    if !overflow_buttons.is_empty() {
+                        ui.add_space(RIGHT_BUTTON_SPACING);
+                        let button = right_button(ui, "…");
+                        egui::menu::menu_custom_button(ui, button, |ui| {
+                            for (text, overflow_action) in overflow_buttons {
+                                if ui.button(text).clicked() {
+                                    action = overflow_action.create_action(app_context);
+                                    ui.close_menu();
+                                }
+                            }
+                        })
+                        .response
+                        .on_hover_text("More actions");
+                    }
+                    ui.add_space(RIGHT_BUTTON_SPACING);
+                    let help = egui::Button::new(RichText::new("?").color(Color32::WHITE))
+                        .fill(if is_help_open(ui.ctx()) {
+                            Color32::from_rgb(0, 128, 255)
+                        } else {
+                            Color32::TRANSPARENT
+                        })
+                        .rounding(3.0)
+                        .stroke(Stroke::new(1.0, Color32::WHITE))
+                        .min_size(egui::vec2(
+                            scaled_size(ui.ctx(), 30.0),
+                            scaled_size(ui.ctx(), 30.0),
+                        ));
+                    if ui
+                        .add(help)
+                        .on_hover_text("Explain this screen (F1)")
+                        .clicked()
+                    {
+                        toggle_help(ui.ctx());
+                    }
+                    ui.add_space(RIGHT_BUTTON_SPACING);
+                    add_privacy_toggle(ui, app_context);
+                    ui.add_space(RIGHT_BUTTON_SPACING);
+                    add_verification_indicator(ui, app_context);
+                    ui.add_space(RIGHT_BUTTON_SPACING);
+                    action |= add_global_search(ui, app_context);
                 });
             });
         });
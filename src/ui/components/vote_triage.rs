@@ -0,0 +1,245 @@
+use crate::model::clock_skew::network_now;
+use crate::model::contested_name::ContestedName;
+use crate::ui::dpns_contested_names_screen::{
+    format_time_remaining, format_vote_choice, time_remaining_color,
+};
+use crate::ui::helpers::number_format::NumberFormatter;
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use egui::{Key, Modifiers, RichText, Ui};
+
+/// Keys choosing the contestants shown first, in order
+const CONTESTANT_KEYS: [Key; 9] = [
+    Key::Num1,
+    Key::Num2,
+    Key::Num3,
+    Key::Num4,
+    Key::Num5,
+    Key::Num6,
+    Key::Num7,
+    Key::Num8,
+    Key::Num9,
+];
+
+/// What the screen showing the triage has to do after a frame
+pub enum TriageOutcome {
+    Continue,
+    Close,
+    /// Vote on the contests, by normalized name
+    Submit(Vec<(String, ResourceVoteChoice)>),
+}
+
+/// Goes through the open contests one at a time and collects a vote for each from the
+/// keyboard: L locks, A abstains, 1 to 9 vote for a contestant, S skips and Backspace goes
+/// back. The votes are reviewed at the end and submitted together.
+pub struct VoteTriage {
+    /// Votable contests we did not vote on yet, ending soonest first
+    contests: Vec<ContestedName>,
+    /// The decision on each contest seen so far, None when it was skipped
+    decisions: Vec<Option<ResourceVoteChoice>>,
+}
+
+impl VoteTriage {
+    pub fn new(contested_names: &[ContestedName]) -> Self {
+        let now = network_now().timestamp_millis() as u64;
+        let mut contests: Vec<ContestedName> = contested_names
+            .iter()
+            .filter(|contested_name| {
+                contested_name.state.state_is_votable()
+                    && contested_name.my_votes.is_empty()
+                    && contested_name
+                        .end_time
+                        .map_or(true, |end_time| end_time > now)
+            })
+            .cloned()
+            .collect();
+        contests.sort_by_key(|contested_name| contested_name.end_time.unwrap_or(u64::MAX));
+        Self {
+            contests,
+            decisions: vec![],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contests.is_empty()
+    }
+
+    /// The votes decided so far, in the order they were made
+    fn pending_votes(&self) -> Vec<(String, ResourceVoteChoice)> {
+        self.contests
+            .iter()
+            .zip(&self.decisions)
+            .filter_map(|(contested_name, decision)| {
+                decision.map(|vote_choice| {
+                    (
+                        contested_name.normalized_contested_name.clone(),
+                        vote_choice,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn reviewing(&self) -> bool {
+        self.decisions.len() >= self.contests.len()
+    }
+
+    pub fn show(&mut self, ui: &mut Ui, number_format: &NumberFormatter) -> TriageOutcome {
+        if self.reviewing() {
+            self.show_review(ui)
+        } else {
+            self.show_contest(ui, number_format)
+        }
+    }
+
+    fn show_contest(&mut self, ui: &mut Ui, number_format: &NumberFormatter) -> TriageOutcome {
+        let contested_name = &self.contests[self.decisions.len()];
+        let contestants = contested_name.contestants.clone().unwrap_or_default();
+        // Shortcuts are off while a text field has the focus
+        let keys_enabled = !ui.ctx().wants_keyboard_input();
+        let pressed = |ui: &mut Ui, key: Key| {
+            keys_enabled && ui.input_mut(|i| i.consume_key(Modifiers::NONE, key))
+        };
+
+        let mut decision: Option<Option<ResourceVoteChoice>> = None;
+        let mut back = pressed(ui, Key::Backspace);
+        let mut close = false;
+
+        ui.horizontal(|ui| {
+            ui.heading("Vote Triage");
+            ui.label(format!(
+                "Contest {} of {}, {} votes pending",
+                self.decisions.len() + 1,
+                self.contests.len(),
+                self.pending_votes().len()
+            ));
+        });
+        ui.add_space(10.0);
+        ui.group(|ui| {
+            ui.heading(&contested_name.normalized_contested_name);
+            if let Some(end_time) = contested_name.end_time {
+                let text = RichText::new(match format_time_remaining(end_time) {
+                    Some(remaining) => format!("Ends in: {}", remaining),
+                    None => "Voting has ended".to_string(),
+                });
+                match time_remaining_color(end_time) {
+                    Some(color) => ui.label(text.color(color)),
+                    None => ui.label(text),
+                };
+            }
+            ui.add_space(5.0);
+            for (i, contestant) in contestants.iter().enumerate() {
+                let key = CONTESTANT_KEYS.get(i);
+                let label = format!(
+                    "{}{} ({} votes)",
+                    key.map(|_| format!("[{}] ", i + 1)).unwrap_or_default(),
+                    contestant.name,
+                    number_format.format_integer(contestant.votes as u64)
+                );
+                let chosen = key.is_some_and(|key| pressed(ui, *key));
+                if ui.button(label).clicked() || chosen {
+                    decision = Some(Some(ResourceVoteChoice::TowardsIdentity(contestant.id)));
+                }
+            }
+            ui.add_space(5.0);
+            ui.horizontal(|ui| {
+                let lock = format!(
+                    "[L] Lock ({})",
+                    number_format.format_integer(contested_name.locked_votes.unwrap_or(0) as u64)
+                );
+                if ui.button(lock).clicked() || pressed(ui, Key::L) {
+                    decision = Some(Some(ResourceVoteChoice::Lock));
+                }
+                let abstain = format!(
+                    "[A] Abstain ({})",
+                    number_format.format_integer(contested_name.abstain_votes.unwrap_or(0) as u64)
+                );
+                if ui.button(abstain).clicked() || pressed(ui, Key::A) {
+                    decision = Some(Some(ResourceVoteChoice::Abstain));
+                }
+                if ui.button("[S] Skip").clicked() || pressed(ui, Key::S) {
+                    decision = Some(None);
+                }
+            });
+        });
+        ui.add_space(10.0);
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.decisions.is_empty(), egui::Button::new("Back"))
+                .on_hover_text("Backspace")
+                .clicked()
+            {
+                back = true;
+            }
+            if ui.button("Review Now").clicked() {
+                // The contests left are skipped
+                self.decisions.resize(self.contests.len(), None);
+            }
+            if ui.button("Close Triage").on_hover_text("Escape").clicked() {
+                close = true;
+            }
+        });
+
+        if close {
+            return TriageOutcome::Close;
+        }
+        if let Some(decision) = decision {
+            self.decisions.push(decision);
+        } else if back {
+            self.decisions.pop();
+        }
+        TriageOutcome::Continue
+    }
+
+    fn show_review(&mut self, ui: &mut Ui) -> TriageOutcome {
+        let mut outcome = TriageOutcome::Continue;
+        let mut to_remove = None;
+        let pending_votes = self.pending_votes().len();
+
+        ui.heading("Review Votes");
+        ui.add_space(10.0);
+        if pending_votes == 0 {
+            ui.label("All contests were skipped.");
+        }
+        egui::Grid::new("vote_triage_review")
+            .striped(true)
+            .num_columns(3)
+            .show(ui, |ui| {
+                for (i, (contested_name, decision)) in
+                    self.contests.iter().zip(&self.decisions).enumerate()
+                {
+                    let Some(vote_choice) = decision else {
+                        continue;
+                    };
+                    ui.label(&contested_name.normalized_contested_name);
+                    ui.label(
+                        RichText::new(format_vote_choice(contested_name, vote_choice)).strong(),
+                    );
+                    if ui.button("Remove").clicked() {
+                        to_remove = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
+        ui.add_space(10.0);
+        ui.horizontal(|ui| {
+            let submit = format!("Submit {} Votes", pending_votes);
+            if ui
+                .add_enabled(pending_votes > 0, egui::Button::new(submit))
+                .clicked()
+            {
+                outcome = TriageOutcome::Submit(self.pending_votes());
+            }
+            if ui.button("Back").clicked() {
+                self.decisions.pop();
+            }
+            if ui.button("Close Triage").clicked() {
+                outcome = TriageOutcome::Close;
+            }
+        });
+
+        if let Some(i) = to_remove {
+            self.decisions[i] = None;
+        }
+        outcome
+    }
+}
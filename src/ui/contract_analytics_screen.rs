@@ -0,0 +1,343 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::contract_analytics::{top_owners, TypeUsage, TOP_OWNER_COUNT};
+use crate::platform::document::DocumentTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::charts::{bar_chart, line_chart};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::number_format::NumberFormatter;
+use crate::ui::helpers::time_format::TimeFormatter;
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::sync::Arc;
+
+/// Document counts, growth, top owners and storage estimates of a contract, computed from the
+/// snapshots of its document types
+pub struct ContractAnalyticsScreen {
+    pub app_context: Arc<AppContext>,
+    pub contract_id: Identifier,
+    contract: Option<Arc<DataContract>>,
+    contract_name: String,
+    types: Vec<TypeUsage>,
+    /// Document type whose growth is charted
+    growth_type: Option<String>,
+    label_input: String,
+    taking: bool,
+    error_message: Option<String>,
+}
+
+impl ContractAnalyticsScreen {
+    pub fn new(contract_id: Identifier, app_context: &Arc<AppContext>) -> Self {
+        let qualified_contract = app_context
+            .db
+            .get_contract_by_id(contract_id, app_context)
+            .ok()
+            .flatten();
+        let contract_name = qualified_contract
+            .as_ref()
+            .and_then(|qualified_contract| qualified_contract.alias.clone())
+            .unwrap_or_else(|| contract_id.to_string(Encoding::Base58));
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            contract_id,
+            contract: qualified_contract
+                .map(|qualified_contract| Arc::new(qualified_contract.contract)),
+            contract_name,
+            types: Vec::new(),
+            growth_type: None,
+            label_input: String::new(),
+            taking: false,
+            error_message: None,
+        };
+        screen.reload();
+        screen
+    }
+
+    /// Recomputes the analytics from the stored snapshots
+    fn reload(&mut self) {
+        let Some(contract) = &self.contract else {
+            self.error_message = Some("The contract is not stored locally, add it first".into());
+            return;
+        };
+        let db = &self.app_context.db;
+        let mut types = Vec::new();
+        for (name, document_type) in contract.document_types() {
+            let snapshots = match db.get_document_snapshots(&contract.id(), name, &self.app_context)
+            {
+                Ok(snapshots) => snapshots,
+                Err(e) => {
+                    self.error_message = Some(format!("Failed to load the snapshots: {}", e));
+                    return;
+                }
+            };
+            let documents = match snapshots.first() {
+                Some(latest) => db.get_snapshot_documents(latest.id).unwrap_or_default(),
+                None => Vec::new(),
+            };
+            types.push(TypeUsage::new(
+                name.clone(),
+                snapshots,
+                &documents,
+                document_type.indexes().len(),
+            ));
+        }
+        if self.growth_type.as_ref().map_or(true, |name| {
+            !types.iter().any(|usage| &usage.document_type == name)
+        }) {
+            self.growth_type = types
+                .iter()
+                .max_by_key(|usage| usage.growth.len())
+                .map(|usage| usage.document_type.clone());
+        }
+        self.types = types;
+    }
+
+    fn render_snapshot_controls(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.label(
+            RichText::new(
+                "Computed from the snapshots of the document types. Snapshot all types now and \
+                 then to see how the contract grows.",
+            )
+            .color(Color32::GRAY),
+        );
+        ui.horizontal(|ui| {
+            ui.label("Label:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.label_input)
+                    .hint_text("weekly")
+                    .desired_width(200.0),
+            );
+            let label = self.label_input.trim().to_string();
+            if ui
+                .add_enabled(
+                    !self.taking && !label.is_empty(),
+                    egui::Button::new("Snapshot All Types"),
+                )
+                .clicked()
+            {
+                if let Some(contract) = &self.contract {
+                    self.taking = true;
+                    action = AppAction::BackendTask(BackendTask::DocumentTask(
+                        DocumentTask::SnapshotContract(contract.clone(), label),
+                    ));
+                }
+            }
+            if self.taking {
+                ui.spinner();
+            }
+        });
+        action
+    }
+
+    fn render_counts(&self, ui: &mut Ui, number_format: &NumberFormatter) {
+        ui.heading("Documents per Type");
+        let bars: Vec<(String, f64)> = self
+            .types
+            .iter()
+            .map(|usage| (usage.document_type.clone(), usage.document_count() as f64))
+            .collect();
+        bar_chart(ui, &bars, |count| {
+            number_format.format_integer(count as u64)
+        });
+        let missing: Vec<&str> = self
+            .types
+            .iter()
+            .filter(|usage| usage.snapshot.is_none())
+            .map(|usage| usage.document_type.as_str())
+            .collect();
+        if !missing.is_empty() {
+            ui.label(
+                RichText::new(format!("No snapshot yet of {}", missing.join(", ")))
+                    .color(Color32::GRAY),
+            );
+        }
+        if self.types.iter().any(|usage| {
+            usage
+                .snapshot
+                .as_ref()
+                .is_some_and(|snapshot| snapshot.truncated)
+        }) {
+            ui.label(
+                RichText::new("Some snapshots were cut off, their types have more documents")
+                    .color(Color32::DARK_RED),
+            );
+        }
+    }
+
+    fn render_growth(&mut self, ui: &mut Ui, number_format: &NumberFormatter) {
+        ui.heading("Growth");
+        let time_format = TimeFormatter::new(&self.app_context.preferences());
+        ui.horizontal(|ui| {
+            ui.label("Document type:");
+            egui::ComboBox::from_id_salt("contract_analytics_growth_type")
+                .selected_text(self.growth_type.clone().unwrap_or_default())
+                .show_ui(ui, |ui| {
+                    for usage in &self.types {
+                        ui.selectable_value(
+                            &mut self.growth_type,
+                            Some(usage.document_type.clone()),
+                            &usage.document_type,
+                        );
+                    }
+                });
+        });
+        let points: Vec<(i64, f64)> = self
+            .types
+            .iter()
+            .find(|usage| Some(&usage.document_type) == self.growth_type.as_ref())
+            .map(|usage| {
+                usage
+                    .growth
+                    .iter()
+                    .map(|(taken_at, count)| (*taken_at, *count as f64))
+                    .collect()
+            })
+            .unwrap_or_default();
+        line_chart(
+            ui,
+            &points,
+            150.0,
+            |taken_at| time_format.format_seconds(taken_at.max(0) as u64),
+            |count| number_format.format_integer(count as u64),
+        );
+    }
+
+    fn render_owners(&self, ui: &mut Ui, number_format: &NumberFormatter) {
+        ui.heading(format!("Top {} Owners", TOP_OWNER_COUNT));
+        let aliases: Vec<(String, String)> = self
+            .app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|identity| {
+                let alias = identity.alias.clone()?;
+                Some((identity.identity.id().to_string(Encoding::Base58), alias))
+            })
+            .collect();
+        let bars: Vec<(String, f64)> = top_owners(&self.types)
+            .into_iter()
+            .map(|(owner, count)| {
+                let name = aliases
+                    .iter()
+                    .find(|(id, _)| id == &owner)
+                    .map(|(_, alias)| format!("{} ({})", alias, owner))
+                    .unwrap_or(owner);
+                (name, count as f64)
+            })
+            .collect();
+        if bars.is_empty() {
+            ui.label("No documents in the snapshots.");
+        } else {
+            bar_chart(ui, &bars, |count| {
+                number_format.format_integer(count as u64)
+            });
+        }
+    }
+
+    fn render_storage(&self, ui: &mut Ui, number_format: &NumberFormatter) {
+        ui.heading("Storage Estimate");
+        ui.label(
+            RichText::new(
+                "A rough estimate from the property values and index entries of the documents \
+                 in the latest snapshots.",
+            )
+            .color(Color32::GRAY),
+        );
+        let kilobytes = |bytes: u64| {
+            format!(
+                "{} KB",
+                number_format.format_decimal(bytes as f64 / 1000.0, 1)
+            )
+        };
+        egui::Grid::new("contract_analytics_storage")
+            .num_columns(3)
+            .spacing([20.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Document type").strong());
+                ui.label(RichText::new("Documents").strong());
+                ui.label(RichText::new("Estimated size").strong());
+                ui.end_row();
+                for usage in &self.types {
+                    ui.label(&usage.document_type);
+                    ui.label(number_format.format_integer(usage.document_count() as u64));
+                    ui.label(kilobytes(usage.estimated_bytes));
+                    ui.end_row();
+                }
+                let documents: usize = self.types.iter().map(TypeUsage::document_count).sum();
+                let bytes: u64 = self.types.iter().map(|usage| usage.estimated_bytes).sum();
+                ui.label(RichText::new("Total").strong());
+                ui.label(number_format.format_integer(documents as u64));
+                ui.label(kilobytes(bytes));
+                ui.end_row();
+            });
+    }
+}
+
+impl ScreenLike for ContractAnalyticsScreen {
+    fn refresh(&mut self) {
+        self.reload();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.taking = false;
+            self.error_message = Some(message.to_string());
+        }
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::ContractSnapshots(_) = backend_task_success_result {
+            self.taking = false;
+            self.label_input.clear();
+            self.reload();
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Contract Analytics", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading(format!("Analytics of {}", self.contract_name));
+                if let Some(error) = &self.error_message {
+                    ui.colored_label(Color32::RED, error);
+                }
+                if self.contract.is_none() {
+                    return;
+                }
+                action |= self.render_snapshot_controls(ui);
+                let number_format = NumberFormatter::new(&self.app_context.preferences());
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_counts(ui, &number_format);
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_growth(ui, &number_format);
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_owners(ui, &number_format);
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_storage(ui, &number_format);
+            });
+        });
+
+        action
+    }
+}
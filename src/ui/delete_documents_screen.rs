@@ -0,0 +1,446 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::document_deletion::{
+    ConditionOperator, SelectionCondition, MAX_DELETION_DOCUMENTS, SYSTEM_FIELDS,
+};
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::platform::document::{DocumentTask, DocumentTypeName};
+use crate::platform::document_deletion::{DeletionPreview, DocumentDeletion, DocumentSelection};
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::number_format::{NumberFormatter, CREDITS_PER_DASH};
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Deletes the documents of an identity selected by a query, after a dry run showing how many
+/// documents it removes and what it costs
+pub struct DeleteDocumentsScreen {
+    pub app_context: Arc<AppContext>,
+    pub contract_id: Identifier,
+    pub document_type: DocumentTypeName,
+    contract: Option<Arc<DataContract>>,
+    schema: serde_json::Value,
+    identities: Vec<QualifiedIdentity>,
+    owner_index: usize,
+    conditions: Vec<SelectionCondition>,
+    preview: Option<DeletionPreview>,
+    previewing: bool,
+    confirmed: bool,
+    batch_size: usize,
+    deleting: bool,
+    progress: Option<(usize, usize)>,
+    /// Whether each previewed document was deleted, by document id
+    outcomes: BTreeMap<Identifier, Result<(), String>>,
+    error_message: Option<String>,
+}
+
+impl DeleteDocumentsScreen {
+    pub fn new(
+        contract_id: Identifier,
+        document_type: DocumentTypeName,
+        app_context: &Arc<AppContext>,
+    ) -> Self {
+        let contract = app_context
+            .db
+            .get_contract_by_id(contract_id, app_context)
+            .ok()
+            .flatten()
+            .map(|qualified_contract| Arc::new(qualified_contract.contract));
+        let schema = contract
+            .as_ref()
+            .and_then(|contract| contract.document_type_for_name(&document_type).ok())
+            .and_then(|doc_type| serde_json::to_value(doc_type.schema()).ok())
+            .unwrap_or_default();
+        let error_message = match &contract {
+            None => Some("The contract is not stored locally, add it first".to_string()),
+            Some(_) if schema.is_null() => Some(format!(
+                "The contract has no document type {}",
+                document_type
+            )),
+            Some(_) => None,
+        };
+        Self {
+            app_context: app_context.clone(),
+            contract_id,
+            document_type,
+            contract,
+            schema,
+            identities: app_context
+                .load_local_qualified_identities()
+                .unwrap_or_default(),
+            owner_index: 0,
+            conditions: Vec::new(),
+            preview: None,
+            previewing: false,
+            confirmed: false,
+            batch_size: 10,
+            deleting: false,
+            progress: None,
+            outcomes: BTreeMap::new(),
+            error_message,
+        }
+    }
+
+    /// Properties of the document type followed by the system fields
+    fn fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self
+            .schema
+            .get("properties")
+            .and_then(serde_json::Value::as_object)
+            .map(|properties| properties.keys().cloned().collect())
+            .unwrap_or_default();
+        fields.extend(SYSTEM_FIELDS.iter().map(|field| field.to_string()));
+        fields
+    }
+
+    fn selection(&self) -> Result<DocumentSelection, String> {
+        let contract = self.contract.clone().ok_or("The contract is not loaded")?;
+        let owner = self
+            .identities
+            .get(self.owner_index)
+            .cloned()
+            .ok_or("Choose the identity owning the documents")?;
+        let conditions = self
+            .conditions
+            .iter()
+            .map(|condition| condition.where_clause(&self.schema))
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(DocumentSelection {
+            contract,
+            document_type: self.document_type.clone(),
+            owner,
+            conditions,
+        })
+    }
+
+    fn render_selection(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("1. Select Documents");
+        if self.identities.is_empty() {
+            ui.label("Load the identity owning the documents first.");
+            return action;
+        }
+        let before = (self.owner_index, self.conditions.clone());
+        self.owner_index = self.owner_index.min(self.identities.len() - 1);
+        ui.horizontal(|ui| {
+            ui.label("Owner:");
+            egui::ComboBox::from_id_salt("document_deletion_owner")
+                .selected_text(self.identities[self.owner_index].display_short_string())
+                .show_ui(ui, |ui| {
+                    for (i, identity) in self.identities.iter().enumerate() {
+                        ui.selectable_value(
+                            &mut self.owner_index,
+                            i,
+                            identity.display_short_string(),
+                        );
+                    }
+                });
+        });
+        ui.label(
+            RichText::new(
+                "Only documents of the owner are deleted. Platform answers queries that match \
+                 an index of the document type, with range conditions on its last field.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        let fields = self.fields();
+        let mut removed = None;
+        egui::Grid::new("document_deletion_conditions")
+            .num_columns(4)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                for (i, condition) in self.conditions.iter_mut().enumerate() {
+                    egui::ComboBox::from_id_salt(("document_deletion_field", i))
+                        .selected_text(condition.field.as_str())
+                        .show_ui(ui, |ui| {
+                            for field in &fields {
+                                ui.selectable_value(&mut condition.field, field.clone(), field);
+                            }
+                        });
+                    egui::ComboBox::from_id_salt(("document_deletion_operator", i))
+                        .selected_text(condition.operator.label())
+                        .show_ui(ui, |ui| {
+                            for operator in ConditionOperator::ALL {
+                                ui.selectable_value(
+                                    &mut condition.operator,
+                                    operator,
+                                    operator.label(),
+                                );
+                            }
+                        });
+                    ui.text_edit_singleline(&mut condition.value);
+                    if ui.button("Remove").clicked() {
+                        removed = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(i) = removed {
+            self.conditions.remove(i);
+        }
+        if ui.button("Add Condition").clicked() {
+            self.conditions.push(SelectionCondition {
+                field: fields.first().cloned().unwrap_or_default(),
+                operator: ConditionOperator::Equal,
+                value: String::new(),
+            });
+        }
+        if (self.owner_index, self.conditions.clone()) != before {
+            self.preview = None;
+            self.confirmed = false;
+        }
+
+        ui.add_space(5.0);
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    !self.previewing && !self.deleting,
+                    egui::Button::new("Preview Deletion"),
+                )
+                .on_hover_text("A dry run: finds the documents and estimates the fee")
+                .clicked()
+            {
+                match self.selection() {
+                    Ok(selection) => {
+                        self.previewing = true;
+                        self.preview = None;
+                        self.confirmed = false;
+                        self.outcomes.clear();
+                        self.progress = None;
+                        self.error_message = None;
+                        action = AppAction::BackendTask(BackendTask::DocumentTask(
+                            DocumentTask::PreviewDocumentDeletion(selection),
+                        ));
+                    }
+                    Err(e) => self.error_message = Some(e),
+                }
+            }
+            if self.previewing {
+                ui.spinner();
+            }
+        });
+        action
+    }
+
+    fn render_preview(&self, ui: &mut Ui, preview: &DeletionPreview) {
+        ui.heading("2. Dry Run");
+        let number_format = NumberFormatter::new(&self.app_context.preferences());
+        let count = preview.documents.len();
+        let mut summary = format!(
+            "{} documents would be deleted",
+            number_format.format_integer(count as u64)
+        );
+        if preview.not_owned > 0 {
+            summary.push_str(&format!(
+                ", {} more match but belong to other identities",
+                number_format.format_integer(preview.not_owned as u64)
+            ));
+        }
+        ui.label(summary);
+        if preview.truncated {
+            ui.colored_label(
+                Color32::DARK_RED,
+                format!(
+                    "More documents match, only the first {} are selected. Run it again \
+                     afterwards for the rest.",
+                    MAX_DELETION_DOCUMENTS
+                ),
+            );
+        }
+        ui.label(format!(
+            "Estimated fee: about {} DASH, {} transitions",
+            number_format.format_decimal(preview.estimated_fee as f64 / CREDITS_PER_DASH, 8),
+            count
+        ))
+        .on_hover_text(
+            "Processing fees only. Storage paid for the documents is partly refunded to the \
+             owner, the real fee is recorded for the accounting export.",
+        );
+        egui::ScrollArea::vertical()
+            .id_salt("document_deletion_preview")
+            .max_height(200.0)
+            .show(ui, |ui| {
+                for document in &preview.documents {
+                    let id = document.id();
+                    let text = format!(
+                        "{} (revision {})",
+                        id.to_string(Encoding::Base58),
+                        document.revision().unwrap_or_default()
+                    );
+                    match self.outcomes.get(&id) {
+                        Some(Ok(())) => {
+                            ui.label(RichText::new(text + ", deleted").color(Color32::DARK_GREEN));
+                        }
+                        Some(Err(e)) => {
+                            ui.colored_label(Color32::RED, format!("{}: {}", text, e));
+                        }
+                        None => {
+                            ui.label(text);
+                        }
+                    }
+                }
+            });
+    }
+
+    fn render_deletion(&mut self, ui: &mut Ui, preview: &DeletionPreview) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("3. Delete");
+        let pending: Vec<_> = preview
+            .documents
+            .iter()
+            .filter(|document| !matches!(self.outcomes.get(&document.id()), Some(Ok(()))))
+            .cloned()
+            .collect();
+        ui.horizontal(|ui| {
+            ui.label("Batch size:");
+            ui.add(egui::DragValue::new(&mut self.batch_size).range(1..=100))
+                .on_hover_text(
+                    "Progress is reported after each batch. The deletion stops after a batch \
+                     in which every document failed.",
+                );
+        });
+        ui.checkbox(
+            &mut self.confirmed,
+            "I understand deleted documents can't be restored",
+        );
+        ui.horizontal(|ui| {
+            let button = egui::Button::new(format!("Delete {} Documents", pending.len()))
+                .fill(Color32::DARK_RED);
+            if ui
+                .add_enabled(
+                    self.confirmed && !pending.is_empty() && !self.deleting,
+                    button,
+                )
+                .clicked()
+            {
+                match (&self.contract, self.identities.get(self.owner_index)) {
+                    (Some(contract), Some(owner)) => {
+                        self.deleting = true;
+                        self.progress = Some((0, pending.len()));
+                        action = AppAction::BackendTask(BackendTask::DocumentTask(
+                            DocumentTask::DeleteDocuments(DocumentDeletion {
+                                contract: contract.clone(),
+                                document_type: self.document_type.clone(),
+                                owner: owner.clone(),
+                                documents: pending,
+                                batch_size: self.batch_size,
+                            }),
+                        ));
+                    }
+                    _ => self.error_message = Some("The owner is no longer loaded".to_string()),
+                }
+            }
+            if let Some((done, total)) = self.progress {
+                if self.deleting {
+                    ui.spinner();
+                }
+                ui.add(
+                    egui::ProgressBar::new(done as f32 / total.max(1) as f32)
+                        .text(format!("{} of {}", done, total))
+                        .desired_width(200.0),
+                );
+            }
+        });
+        if !self.outcomes.is_empty() {
+            let deleted = self
+                .outcomes
+                .values()
+                .filter(|outcome| outcome.is_ok())
+                .count();
+            let failed = self.outcomes.len() - deleted;
+            let color = if failed == 0 {
+                Color32::DARK_GREEN
+            } else {
+                Color32::RED
+            };
+            ui.label(RichText::new(format!("{} deleted, {} failed", deleted, failed)).color(color));
+        }
+        action
+    }
+}
+
+impl ScreenLike for DeleteDocumentsScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.previewing = false;
+            self.deleting = false;
+            self.error_message = Some(message.to_string());
+        }
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        match backend_task_success_result {
+            BackendTaskSuccessResult::DeletionPreview(preview) => {
+                self.previewing = false;
+                self.preview = Some(preview);
+            }
+            BackendTaskSuccessResult::DocumentDeletionProgress { done, total } => {
+                self.progress = Some((done, total));
+            }
+            BackendTaskSuccessResult::DeletedDocuments(outcomes) => {
+                self.deleting = false;
+                self.confirmed = false;
+                self.outcomes.extend(outcomes);
+            }
+            _ => {}
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Delete Documents", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                let contract_name = self
+                    .contract
+                    .as_ref()
+                    .map(|contract| contract.id())
+                    .unwrap_or(self.contract_id)
+                    .to_string(Encoding::Base58);
+                ui.heading(format!(
+                    "Delete {} documents of {}",
+                    self.document_type, contract_name
+                ));
+                if let Some(error) = &self.error_message {
+                    ui.colored_label(Color32::RED, error);
+                }
+                if self.contract.is_none() || self.schema.is_null() {
+                    return;
+                }
+                ui.add_space(5.0);
+                action |= self.render_selection(ui);
+                let Some(preview) = self.preview.take() else {
+                    return;
+                };
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_preview(ui, &preview);
+                if !preview.documents.is_empty() {
+                    ui.add_space(10.0);
+                    ui.separator();
+                    action |= self.render_deletion(ui, &preview);
+                }
+                self.preview = Some(preview);
+            });
+        });
+
+        action
+    }
+}
@@ -0,0 +1,461 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::endpoint_health::FLAG_DURATION_MINUTES;
+use crate::model::health_check::{CheckStatus, DapiConnectivityReport, HealthReport};
+use crate::platform::health::HealthTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::request_metrics::{MethodSummary, RequestMetric};
+use crate::support_bundle::create_support_bundle;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::display_scale::{apply_table_density, table_heights};
+use crate::ui::helpers::time_format::TimeFormatter;
+use crate::ui::{MessageType, ScreenLike};
+use eframe::egui::{self, Context, RichText, Ui};
+use egui::Color32;
+use egui_extras::{Column, TableBuilder};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How many of the most recent requests are listed
+const RECENT_REQUESTS_SHOWN: usize = 50;
+
+/// Proof verification share above which requests are considered proof-verification bound
+const PROOF_BOUND_SHARE: f64 = 0.5;
+
+/// Shows the health checks and timings of the requests made by the SDK
+pub struct DiagnosticsScreen {
+    pub app_context: Arc<AppContext>,
+    /// Whether the health checks were started from this screen and did not finish yet
+    checking: bool,
+    /// Layer by layer results of the last DAPI troubleshooting
+    dapi_reports: Option<Vec<DapiConnectivityReport>>,
+    troubleshooting: bool,
+    bundle_path_input: String,
+    /// Outcome of the last support bundle, and whether it failed
+    bundle_message: Option<(String, bool)>,
+}
+
+impl DiagnosticsScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        Self {
+            app_context: app_context.clone(),
+            checking: false,
+            dapi_reports: None,
+            troubleshooting: false,
+            bundle_path_input: "dash-evo-tool-support.zip".to_string(),
+            bundle_message: None,
+        }
+    }
+
+    fn render_health_report(&self, ui: &mut Ui, report: &HealthReport) {
+        let time_format = TimeFormatter::new(&self.app_context.preferences());
+        ui.label(format!("Checked {}", time_format.format(report.checked_at)));
+        egui::Grid::new("diagnostics_health_grid")
+            .num_columns(4)
+            .spacing([15.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for check in &report.checks {
+                    ui.label(check.name);
+                    let color = match check.status {
+                        CheckStatus::Passed => Color32::DARK_GREEN,
+                        CheckStatus::Warning => Color32::from_rgb(200, 150, 0),
+                        CheckStatus::Failed => Color32::RED,
+                    };
+                    ui.colored_label(color, check.status.label());
+                    ui.label(&check.detail);
+                    ui.label(check.suggestion.as_deref().unwrap_or(""));
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn render_dapi_reports(&self, ui: &mut Ui, reports: &[DapiConnectivityReport]) {
+        egui::Grid::new("diagnostics_dapi_grid")
+            .num_columns(4)
+            .spacing([15.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for report in reports {
+                    ui.label(&report.address);
+                    for layer in &report.layers {
+                        let color = match layer.status {
+                            CheckStatus::Passed => Color32::DARK_GREEN,
+                            CheckStatus::Warning => Color32::from_rgb(200, 150, 0),
+                            CheckStatus::Failed => Color32::RED,
+                        };
+                        ui.colored_label(color, layer.layer.label())
+                            .on_hover_text(format!(
+                                "{} in {} ms: {}",
+                                layer.status.label(),
+                                layer.elapsed_ms,
+                                layer.detail
+                            ));
+                    }
+                    ui.end_row();
+                    if report.status() != CheckStatus::Passed {
+                        let failing = report
+                            .layers
+                            .iter()
+                            .find(|layer| layer.status != CheckStatus::Passed);
+                        ui.label("");
+                        ui.label(failing.map(|layer| layer.detail.as_str()).unwrap_or(""));
+                        ui.end_row();
+                    }
+                    if let Some(suggestion) = &report.suggestion {
+                        ui.label("");
+                        ui.label(suggestion);
+                        ui.end_row();
+                    }
+                }
+            });
+    }
+
+    /// DAPI nodes whose proofs did not verify, which queries are retried around
+    fn render_flagged_endpoints(&self, ui: &mut Ui) {
+        let flags = self.app_context.endpoint_health.active_flags();
+        if flags.is_empty() {
+            return;
+        }
+        ui.label(
+            RichText::new(format!(
+                "Nodes that answered with proofs that did not verify are avoided for {} minutes:",
+                FLAG_DURATION_MINUTES
+            ))
+            .color(Color32::from_rgb(200, 150, 0)),
+        );
+        let time_format = TimeFormatter::new(&self.app_context.preferences());
+        let mut cleared = None;
+        egui::Grid::new("diagnostics_flagged_endpoints")
+            .num_columns(4)
+            .spacing([15.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for flag in &flags {
+                    ui.label(&flag.address);
+                    ui.label(format!(
+                        "{} bad proofs, last {}",
+                        flag.failures,
+                        time_format.format(flag.last_flagged_at)
+                    ));
+                    ui.label(&flag.reason);
+                    if ui.button("Trust again").clicked() {
+                        cleared = Some(flag.address.clone());
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(address) = cleared {
+            self.app_context.endpoint_health.clear(&address);
+        }
+    }
+
+    /// Everything worth attaching to a bug report. Holds no keys, addresses or identities.
+    fn diagnostics_text(&self, summaries: &[MethodSummary]) -> String {
+        let mut text = format!(
+            "Dash Evo Tool {}\nOS: {} {}\nNetwork: {}\n\n",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            self.app_context.network_string()
+        );
+        match self.app_context.health_report() {
+            Some(report) => text.push_str(&report.to_text()),
+            None => text.push_str("Health checks did not finish yet\n"),
+        }
+        if let Some(reports) = &self.dapi_reports {
+            text.push('\n');
+            text.push_str(&DapiConnectivityReport::to_text(reports));
+        }
+        let flags = self.app_context.endpoint_health.active_flags();
+        if !flags.is_empty() {
+            text.push_str("\nDAPI nodes with bad proofs\n");
+            for flag in flags {
+                text.push_str(&format!(
+                    "- {}: {} bad proofs, last: {}\n",
+                    flag.address, flag.failures, flag.reason
+                ));
+            }
+        }
+        text.push_str("\nSDK requests\n");
+        for summary in summaries {
+            text.push_str(&format!(
+                "- {}: {} requests, p50 {}, p90 {}, proof {:.0}%, {} retries, {} errors\n",
+                summary.method,
+                summary.count,
+                format_duration(summary.latency_p50),
+                format_duration(summary.latency_p90),
+                summary.proof_share * 100.0,
+                summary.retries,
+                summary.errors
+            ));
+        }
+        text
+    }
+
+    fn render_verdict(&self, ui: &mut Ui, summaries: &[MethodSummary]) {
+        let count: usize = summaries.iter().map(|summary| summary.count).sum();
+        if count == 0 {
+            ui.label("No requests recorded yet.");
+            return;
+        }
+        let weighted_proof_share = summaries
+            .iter()
+            .map(|summary| summary.proof_share * summary.count as f64)
+            .sum::<f64>()
+            / count as f64;
+        let verdict = if weighted_proof_share >= PROOF_BOUND_SHARE {
+            "Requests are mostly proof-verification bound."
+        } else {
+            "Requests are mostly network bound."
+        };
+        ui.label(format!(
+            "{} requests recorded, {:.0}% of the time spent verifying proofs. {}",
+            count,
+            weighted_proof_share * 100.0,
+            verdict
+        ));
+    }
+
+    fn render_summaries(&self, ui: &mut Ui, summaries: &[MethodSummary]) {
+        egui::Grid::new("diagnostics_summary_grid")
+            .num_columns(9)
+            .spacing([15.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for title in [
+                    "Method",
+                    "Count",
+                    "p50",
+                    "p90",
+                    "p99",
+                    "Proof p50",
+                    "Proof %",
+                    "Retries",
+                    "Errors",
+                ] {
+                    ui.label(RichText::new(title).strong());
+                }
+                ui.end_row();
+
+                for summary in summaries {
+                    ui.label(&summary.method);
+                    ui.label(summary.count.to_string());
+                    ui.label(format_duration(summary.latency_p50));
+                    ui.label(format_duration(summary.latency_p90));
+                    ui.label(format_duration(summary.latency_p99));
+                    ui.label(format_duration(summary.proof_p50));
+                    ui.label(format!("{:.0}%", summary.proof_share * 100.0));
+                    ui.label(summary.retries.to_string());
+                    if summary.errors > 0 {
+                        ui.colored_label(Color32::RED, summary.errors.to_string());
+                    } else {
+                        ui.label("0");
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn render_recent(&self, ui: &mut Ui, recent: &[RequestMetric]) {
+        let time_format =
+            TimeFormatter::for_column(&self.app_context.preferences(), "diagnostics.started_at");
+        let density = self.app_context.preferences().table_density;
+        // Scoped so the compact style stays with the table
+        ui.scope(|ui| {
+            let (header_height, row_height) = table_heights(ui.ctx(), density, 22.0);
+            apply_table_density(ui, density);
+            TableBuilder::new(ui)
+                .striped(true)
+                .resizable(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(Column::auto()) // Time
+                .column(Column::auto()) // Method
+                .column(Column::auto()) // Endpoint
+                .column(Column::auto()) // Latency
+                .column(Column::auto()) // Network
+                .column(Column::auto()) // Proof
+                .column(Column::remainder()) // Retries
+                .header(header_height, |mut header| {
+                    for title in [
+                        "Time", "Method", "Endpoint", "Latency", "Network", "Proof", "Retries",
+                    ] {
+                        header.col(|ui| {
+                            ui.heading(title);
+                        });
+                    }
+                })
+                .body(|mut body| {
+                    for request in recent {
+                        body.row(row_height, |mut row| {
+                            row.col(|ui| {
+                                ui.label(time_format.format(request.started_at));
+                            });
+                            row.col(|ui| {
+                                let text = if request.success {
+                                    RichText::new(&request.method)
+                                } else {
+                                    RichText::new(&request.method).color(Color32::RED)
+                                };
+                                ui.label(text);
+                            });
+                            row.col(|ui| {
+                                ui.label(request.endpoint.as_deref().unwrap_or("-"));
+                            });
+                            row.col(|ui| {
+                                ui.label(format_duration(request.latency));
+                            });
+                            row.col(|ui| {
+                                ui.label(format_duration(request.network_time()));
+                            });
+                            row.col(|ui| {
+                                ui.label(format_duration(request.proof_verification));
+                            });
+                            row.col(|ui| {
+                                ui.label(request.retries.to_string());
+                            });
+                        });
+                    }
+                });
+        });
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_secs(1) {
+        format!("{} ms", duration.as_millis())
+    } else {
+        format!("{:.2} s", duration.as_secs_f64())
+    }
+}
+
+impl ScreenLike for DiagnosticsScreen {
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Diagnostics", AppAction::None),
+            ],
+            vec![],
+        );
+
+        let request_metrics = self.app_context.request_metrics.clone();
+        let summaries = request_metrics.summaries();
+        let recent = request_metrics.recent(RECENT_REQUESTS_SHOWN);
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.heading("Health Checks");
+                if self.checking {
+                    ui.spinner();
+                } else if ui.button("Run checks").clicked() {
+                    self.checking = true;
+                    action |=
+                        AppAction::BackendTask(BackendTask::HealthTask(HealthTask::RunChecks));
+                }
+                if ui.button("Copy diagnostics").clicked() {
+                    ui.ctx().copy_text(self.diagnostics_text(&summaries));
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("Support bundle:");
+                ui.text_edit_singleline(&mut self.bundle_path_input);
+                if ui
+                    .button("Create support bundle")
+                    .on_hover_text(
+                        "Zips the recent logs, this summary and the settings without passwords, \
+                         keys and webhook URLs, for attaching to a GitHub issue",
+                    )
+                    .clicked()
+                {
+                    let path = PathBuf::from(self.bundle_path_input.trim());
+                    let diagnostics = self.diagnostics_text(&summaries);
+                    self.bundle_message = Some(
+                        match create_support_bundle(&self.app_context, &diagnostics, &path) {
+                            Ok(message) => (message, false),
+                            Err(e) => (e, true),
+                        },
+                    );
+                }
+            });
+            if let Some((message, failed)) = &self.bundle_message {
+                let color = if *failed {
+                    Color32::RED
+                } else {
+                    Color32::DARK_GREEN
+                };
+                ui.colored_label(color, message);
+            }
+            match self.app_context.health_report() {
+                Some(report) => self.render_health_report(ui, &report),
+                None => {
+                    ui.label("The checks started on launch did not finish yet.");
+                }
+            }
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.heading("DAPI Connectivity");
+                if self.troubleshooting {
+                    ui.spinner();
+                } else if ui
+                    .button("Troubleshoot")
+                    .on_hover_text(
+                        "Resolves each DAPI address and tries TCP, TLS and gRPC one after the \
+                         other to find where the connection fails",
+                    )
+                    .clicked()
+                {
+                    self.troubleshooting = true;
+                    action |= AppAction::BackendTask(BackendTask::HealthTask(
+                        HealthTask::TroubleshootDapi,
+                    ));
+                }
+            });
+            if let Some(reports) = &self.dapi_reports {
+                self.render_dapi_reports(ui, reports);
+            }
+            self.render_flagged_endpoints(ui);
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.heading("SDK Requests");
+                if ui.button("Clear").clicked() {
+                    request_metrics.clear();
+                }
+            });
+            self.render_verdict(ui, &summaries);
+            ui.add_space(5.0);
+            egui::ScrollArea::horizontal()
+                .id_salt("diagnostics_summary_scroll")
+                .show(ui, |ui| {
+                    self.render_summaries(ui, &summaries);
+                });
+            ui.separator();
+            ui.heading("Recent Requests");
+            self.render_recent(ui, &recent);
+        });
+
+        // Keep the numbers moving while requests come in
+        ctx.request_repaint_after(Duration::from_secs(1));
+
+        action
+    }
+
+    fn display_message(&mut self, _message: &str, _message_type: MessageType) {
+        self.checking = false;
+        self.troubleshooting = false;
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        match backend_task_success_result {
+            BackendTaskSuccessResult::HealthReport(_) => self.checking = false,
+            BackendTaskSuccessResult::DapiConnectivity(reports) => {
+                self.troubleshooting = false;
+                self.dapi_reports = Some(reports);
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,15 +1,41 @@
 use crate::app::AppAction;
 use crate::context::AppContext;
 use crate::model::contested_name::ContestedName;
+use crate::model::contract_codegen::{generate_rust, generate_typescript};
+use crate::model::document_revision::{diff_json, DiffKind, DocumentRevision};
+use crate::model::document_snapshot::{
+    compare_snapshots, DocumentSnapshot, SnapshotChange, SnapshotDifference, MAX_SNAPSHOT_DOCUMENTS,
+};
+use crate::model::document_trade::{check_purchase_balance, listed_price, ESTIMATED_TRADE_FEE};
+use crate::model::qualified_identity::QualifiedIdentity;
 use crate::platform::contested_names::ContestedResourceTask;
-use crate::platform::BackendTask;
-use crate::ui::components::contract_chooser_panel::add_contract_chooser_panel;
+use crate::platform::document::{DocumentTask, DocumentTypeName};
+use crate::platform::document_trade::DocumentTrade;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::app_profiles_screen::AppProfilesScreen;
+use crate::ui::components::contract_chooser_panel::{
+    add_contract_chooser_panel, ContractPanelRequest,
+};
+use crate::ui::components::form_drafts::FormDrafts;
+use crate::ui::components::json_schema_form::JsonSchemaForm;
+use crate::ui::components::json_viewer::JsonViewer;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::proof_status::{add_proof_status, DOCUMENT_METHODS};
 use crate::ui::components::top_panel::add_top_panel;
-use crate::ui::{MessageType, RootScreenType, ScreenLike};
+use crate::ui::helpers::number_format::{NumberFormatter, CREDITS_PER_DASH};
+use crate::ui::{MessageType, RootScreenType, Screen, ScreenLike, ScreenType};
 use chrono::{DateTime, Utc};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::nft::TradeMode;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
-use egui::{Context, Ui};
+use dash_sdk::platform::{Document, DocumentQuery, Identifier};
+use egui::{Color32, Context, RichText, Ui};
 use std::sync::{Arc, Mutex};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -27,6 +53,203 @@ enum SortOrder {
     Descending,
 }
 
+/// A document looked up by id, with the copies of its revisions fetched so far
+struct DocumentLookup {
+    contract: Arc<DataContract>,
+    document_type: DocumentTypeName,
+    id_input: String,
+    document_id: Option<Identifier>,
+    /// Oldest revision first
+    revisions: Vec<DocumentRevision>,
+    /// Indexes into `revisions` of the older and newer compared revision
+    compared: (usize, usize),
+    only_changes: bool,
+    /// The latest revision while no earlier one is cached
+    latest_view: Option<JsonViewer>,
+    /// The document as last fetched, for setting its price or buying it
+    document: Option<Document>,
+    identities: Vec<QualifiedIdentity>,
+    price_input: String,
+    buyer_index: usize,
+    trading: bool,
+}
+
+impl DocumentLookup {
+    fn new(
+        contract: Arc<DataContract>,
+        document_type: DocumentTypeName,
+        app_context: &AppContext,
+    ) -> Self {
+        Self {
+            contract,
+            document_type,
+            id_input: String::new(),
+            document_id: None,
+            revisions: Vec::new(),
+            compared: (0, 0),
+            only_changes: true,
+            latest_view: None,
+            document: None,
+            identities: app_context
+                .load_local_qualified_identities()
+                .unwrap_or_default(),
+            price_input: String::new(),
+            buyer_index: 0,
+            trading: false,
+        }
+    }
+
+    /// Whether the contract lets documents of this type be sold
+    fn is_tradable(&self) -> bool {
+        self.contract
+            .document_type_for_name(&self.document_type)
+            .is_ok_and(|document_type| document_type.trade_mode() == TradeMode::DirectPurchase)
+    }
+
+    fn reload_revisions(&mut self, app_context: &AppContext) {
+        let Some(document_id) = self.document_id else {
+            return;
+        };
+        self.revisions = app_context
+            .db
+            .get_document_revisions(&document_id, app_context)
+            .unwrap_or_default();
+        let count = self.revisions.len();
+        self.compared = (count.saturating_sub(2), count.saturating_sub(1));
+        self.latest_view = self
+            .revisions
+            .last()
+            .map(|latest| JsonViewer::new("document_latest_revision", latest.json.clone()));
+    }
+}
+
+/// Snapshots of all documents of a type, and the comparison of two of them
+struct DocumentSnapshots {
+    contract: Arc<DataContract>,
+    document_type: DocumentTypeName,
+    label_input: String,
+    /// Newest first
+    snapshots: Vec<DocumentSnapshot>,
+    /// Ids of the older and newer compared snapshot
+    compared: (Option<i64>, Option<i64>),
+    differences: Option<Vec<SnapshotDifference>>,
+    taking: bool,
+}
+
+impl DocumentSnapshots {
+    fn new(
+        contract: Arc<DataContract>,
+        document_type: DocumentTypeName,
+        app_context: &AppContext,
+    ) -> Self {
+        let mut snapshots = Self {
+            contract,
+            document_type,
+            label_input: String::new(),
+            snapshots: Vec::new(),
+            compared: (None, None),
+            differences: None,
+            taking: false,
+        };
+        snapshots.reload(app_context);
+        snapshots
+    }
+
+    /// Reloads the list and compares the two newest snapshots unless others were chosen
+    fn reload(&mut self, app_context: &AppContext) {
+        self.snapshots = app_context
+            .db
+            .get_document_snapshots(&self.contract.id(), &self.document_type, app_context)
+            .unwrap_or_default();
+        let exists = |id: Option<i64>| {
+            id.is_some_and(|id| self.snapshots.iter().any(|snapshot| snapshot.id == id))
+        };
+        if !exists(self.compared.0) || !exists(self.compared.1) {
+            self.compared = (
+                self.snapshots.get(1).map(|snapshot| snapshot.id),
+                self.snapshots.first().map(|snapshot| snapshot.id),
+            );
+            self.differences = None;
+        }
+    }
+
+    fn compare(&mut self, app_context: &AppContext) -> Result<(), String> {
+        let (Some(old), Some(new)) = self.compared else {
+            return Ok(());
+        };
+        let load = |id| {
+            app_context
+                .db
+                .get_snapshot_documents(id)
+                .map_err(|e| format!("Failed to load the snapshot: {}", e))
+        };
+        self.differences = Some(compare_snapshots(&load(old)?, &load(new)?));
+        Ok(())
+    }
+}
+
+fn snapshot_label(snapshot: &DocumentSnapshot) -> String {
+    format!(
+        "{} ({})",
+        snapshot.label,
+        DateTime::from_timestamp(snapshot.taken_at, 0)
+            .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default()
+    )
+}
+
+/// Where the types generated for a contract are written
+struct CodegenRequest {
+    contract_name: String,
+    contract_json: serde_json::Value,
+    directory_input: String,
+    typescript: bool,
+}
+
+impl CodegenRequest {
+    /// Writes the generated files, returns their paths
+    fn write_files(&self) -> Result<Vec<String>, String> {
+        let schemas = self
+            .contract_json
+            .get("documentSchemas")
+            .and_then(serde_json::Value::as_object)
+            .filter(|schemas| !schemas.is_empty())
+            .ok_or("The contract has no document types")?;
+        let directory = std::path::Path::new(self.directory_input.trim());
+        std::fs::create_dir_all(directory)
+            .map_err(|e| format!("Failed to create {}: {}", directory.display(), e))?;
+        let file_stem: String = self
+            .contract_name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let mut files = vec![(
+            directory.join(format!("{}.rs", file_stem)),
+            generate_rust(&self.contract_name, schemas),
+        )];
+        if self.typescript {
+            files.push((
+                directory.join(format!("{}.ts", file_stem)),
+                generate_typescript(&self.contract_name, schemas),
+            ));
+        }
+        files
+            .into_iter()
+            .map(|(path, code)| {
+                std::fs::write(&path, code)
+                    .map(|_| path.display().to_string())
+                    .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+            })
+            .collect()
+    }
+}
+
 pub struct DocumentQueryScreen {
     contested_names: Arc<Mutex<Vec<ContestedName>>>,
     pub app_context: Arc<AppContext>,
@@ -35,6 +258,15 @@ pub struct DocumentQueryScreen {
     sort_order: SortOrder,
     show_vote_popup: Option<(String, ContestedResourceTask)>,
     contract_search_term: String,
+    document_form: Option<(String, JsonSchemaForm)>,
+    /// Drafts of the document form, saved while it is filled in
+    document_drafts: Option<FormDrafts>,
+    document_preview: Option<Result<JsonViewer, Vec<String>>>,
+    /// Name and JSON view of the contract chosen in the side panel
+    contract_view: Option<(String, JsonViewer)>,
+    document_lookup: Option<DocumentLookup>,
+    document_snapshots: Option<DocumentSnapshots>,
+    codegen: Option<CodegenRequest>,
 }
 
 impl DocumentQueryScreen {
@@ -50,6 +282,13 @@ impl DocumentQueryScreen {
             sort_order: SortOrder::Ascending,
             show_vote_popup: None,
             contract_search_term: String::new(),
+            document_form: None,
+            document_drafts: None,
+            document_preview: None,
+            contract_view: None,
+            document_lookup: None,
+            document_snapshots: None,
+            codegen: None,
         }
     }
 
@@ -138,6 +377,548 @@ impl DocumentQueryScreen {
         }
     }
 
+    fn show_document_form(&mut self, ui: &mut Ui) {
+        let Some((title, form)) = &mut self.document_form else {
+            return;
+        };
+        ui.heading(title.as_str());
+        ui.add_space(5.0);
+        if let Some(drafts) = &mut self.document_drafts {
+            if let Some(content) = drafts.show(ui, &self.app_context) {
+                match serde_json::from_str(&content) {
+                    Ok(inputs) => form.restore_inputs(&inputs),
+                    Err(e) => tracing::warn!("Failed to read the document draft: {}", e),
+                }
+                self.document_preview = None;
+            }
+        }
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            form.show(ui);
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.button("Preview").clicked() {
+                    self.document_preview = Some(form.properties().and_then(|properties| {
+                        JsonViewer::from_serializable("document_preview", &properties)
+                            .map_err(|e| vec![e])
+                    }));
+                }
+                if ui.button("Clear").clicked() {
+                    form.reset();
+                    self.document_preview = None;
+                    if let Some(drafts) = &mut self.document_drafts {
+                        drafts.discard(&self.app_context);
+                    }
+                }
+            });
+            match &mut self.document_preview {
+                Some(Ok(viewer)) => {
+                    ui.add_space(5.0);
+                    viewer.show(ui);
+                }
+                Some(Err(errors)) => {
+                    ui.add_space(5.0);
+                    for error in errors {
+                        ui.colored_label(egui::Color32::RED, error);
+                    }
+                }
+                None => {}
+            }
+        });
+        if let Some(drafts) = &mut self.document_drafts {
+            let content = (!form.is_empty()).then(|| form.inputs().to_string());
+            drafts.autosave(title, content, &self.app_context);
+        }
+    }
+
+    fn show_document_lookup(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        let Some(lookup) = &mut self.document_lookup else {
+            return action;
+        };
+        ui.heading(format!("Look up {} document", lookup.document_type));
+        let mut error = None;
+        ui.horizontal(|ui| {
+            ui.label("Document ID:");
+            ui.text_edit_singleline(&mut lookup.id_input);
+            if ui.button("Fetch").clicked() {
+                let input = lookup.id_input.trim();
+                match Identifier::from_string(input, Encoding::Base58)
+                    .or_else(|_| Identifier::from_string(input, Encoding::Hex))
+                {
+                    Ok(document_id) => {
+                        match DocumentQuery::new(lookup.contract.clone(), &lookup.document_type) {
+                            Ok(query) => {
+                                lookup.document_id = Some(document_id);
+                                lookup.reload_revisions(&self.app_context);
+                                action = AppAction::BackendTask(BackendTask::DocumentTask(
+                                    DocumentTask::FetchDocuments(
+                                        query.with_document_id(&document_id),
+                                    ),
+                                ));
+                            }
+                            Err(e) => error = Some(format!("Invalid document query: {}", e)),
+                        }
+                    }
+                    Err(_) => error = Some("Invalid document ID".to_string()),
+                }
+            }
+        });
+        ui.label(
+            RichText::new(
+                "Platform only serves the latest revision. Earlier ones are copies kept each \
+                 time the document was fetched.",
+            )
+            .color(Color32::GRAY),
+        );
+        ui.add_space(5.0);
+        if lookup.is_tradable() {
+            action |= Self::show_document_trade(ui, lookup, &self.app_context);
+            ui.add_space(5.0);
+        }
+
+        match lookup.revisions.len() {
+            0 if lookup.document_id.is_some() => {
+                ui.label("No copy of this document yet.");
+            }
+            0 => {}
+            1 => {
+                ui.label(format!(
+                    "Only revision {} is known, fetch again after it changes to compare.",
+                    lookup.revisions[0].revision
+                ));
+                if let Some(viewer) = &mut lookup.latest_view {
+                    viewer.show(ui);
+                }
+            }
+            _ => Self::show_revision_diff(ui, lookup),
+        }
+
+        if let Some(error) = error {
+            self.display_message(&error, MessageType::Error);
+        }
+        action
+    }
+
+    /// Sets the price of a document of a local identity, or buys a listed document for one
+    fn show_document_trade(
+        ui: &mut Ui,
+        lookup: &mut DocumentLookup,
+        app_context: &AppContext,
+    ) -> AppAction {
+        let mut action = AppAction::None;
+        let Some(document) = lookup.document.clone() else {
+            return action;
+        };
+        let number_format = NumberFormatter::new(&app_context.preferences());
+        let price = listed_price(&document);
+        ui.label(match price {
+            Some(price) => format!(
+                "For sale at {}",
+                number_format.format_credits_as_dash(price)
+            ),
+            None => "Not for sale".to_string(),
+        });
+        let trade = |identity: &QualifiedIdentity, price: Credits| {
+            AppAction::BackendTask(BackendTask::DocumentTask(DocumentTask::SetDocumentPrice(
+                DocumentTrade {
+                    contract: lookup.contract.clone(),
+                    document_type: lookup.document_type.clone(),
+                    document: document.clone(),
+                    identity: identity.clone(),
+                    price,
+                },
+            )))
+        };
+
+        if let Some(owner) = lookup
+            .identities
+            .iter()
+            .find(|identity| identity.identity.id() == document.owner_id())
+        {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "Price in DASH ({} owns it):",
+                    owner.display_short_string()
+                ));
+                ui.add(egui::TextEdit::singleline(&mut lookup.price_input).desired_width(100.0));
+                let new_price = lookup
+                    .price_input
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|dash| dash.is_finite() && *dash > 0.0)
+                    .map(|dash| (dash * CREDITS_PER_DASH).round() as Credits);
+                if ui
+                    .add_enabled(
+                        new_price.is_some() && !lookup.trading,
+                        egui::Button::new("Set Price"),
+                    )
+                    .clicked()
+                {
+                    lookup.trading = true;
+                    action = trade(owner, new_price.unwrap_or_default());
+                }
+                if ui
+                    .add_enabled(
+                        price.is_some() && !lookup.trading,
+                        egui::Button::new("Take Off Sale"),
+                    )
+                    .clicked()
+                {
+                    lookup.trading = true;
+                    action = trade(owner, 0);
+                }
+            });
+        } else if let Some(price) = price {
+            if lookup.identities.is_empty() {
+                ui.label("Load an identity to buy this document.");
+            } else {
+                lookup.buyer_index = lookup.buyer_index.min(lookup.identities.len() - 1);
+                let buyer = &lookup.identities[lookup.buyer_index];
+                let balance = buyer.identity.balance();
+                let balance_check = check_purchase_balance(balance, price);
+                ui.horizontal(|ui| {
+                    ui.label("Buyer:");
+                    egui::ComboBox::from_id_salt("document_buyer")
+                        .selected_text(buyer.display_short_string())
+                        .show_ui(ui, |ui| {
+                            for (i, identity) in lookup.identities.iter().enumerate() {
+                                ui.selectable_value(
+                                    &mut lookup.buyer_index,
+                                    i,
+                                    identity.display_short_string(),
+                                );
+                            }
+                        });
+                    ui.label(format!(
+                        "Balance: {}",
+                        number_format.format_credits_as_dash(balance)
+                    ));
+                });
+                if let Err(e) = &balance_check {
+                    ui.colored_label(Color32::DARK_RED, e);
+                }
+                let button = egui::Button::new(format!(
+                    "Buy for {}",
+                    number_format.format_credits_as_dash(price)
+                ));
+                if ui
+                    .add_enabled(balance_check.is_ok() && !lookup.trading, button)
+                    .on_hover_text(format!(
+                        "Plus a fee of about {}",
+                        number_format.format_credits_as_dash(ESTIMATED_TRADE_FEE)
+                    ))
+                    .clicked()
+                {
+                    lookup.trading = true;
+                    action = AppAction::BackendTask(BackendTask::DocumentTask(
+                        DocumentTask::PurchaseDocument(DocumentTrade {
+                            contract: lookup.contract.clone(),
+                            document_type: lookup.document_type.clone(),
+                            document: document.clone(),
+                            identity: lookup.identities[lookup.buyer_index].clone(),
+                            price,
+                        }),
+                    ));
+                }
+            }
+        }
+        if lookup.trading {
+            ui.spinner();
+        }
+        action
+    }
+
+    /// Side-by-side comparison of two cached revisions
+    fn show_revision_diff(ui: &mut Ui, lookup: &mut DocumentLookup) {
+        let revision_label = |revision: &DocumentRevision| {
+            format!(
+                "Revision {} (fetched {})",
+                revision.revision,
+                DateTime::from_timestamp(revision.fetched_at, 0)
+                    .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_default()
+            )
+        };
+        ui.horizontal(|ui| {
+            for (label, selected) in [
+                ("Old:", &mut lookup.compared.0),
+                ("New:", &mut lookup.compared.1),
+            ] {
+                ui.label(label);
+                egui::ComboBox::from_id_salt(("document_revision", label))
+                    .selected_text(revision_label(&lookup.revisions[*selected]))
+                    .show_ui(ui, |ui| {
+                        for (i, revision) in lookup.revisions.iter().enumerate() {
+                            ui.selectable_value(selected, i, revision_label(revision));
+                        }
+                    });
+            }
+            ui.checkbox(&mut lookup.only_changes, "Only changes");
+        });
+
+        let (old, new) = (
+            &lookup.revisions[lookup.compared.0],
+            &lookup.revisions[lookup.compared.1],
+        );
+        let rows: Vec<_> = diff_json(&old.json, &new.json)
+            .into_iter()
+            .filter(|row| !lookup.only_changes || row.kind != DiffKind::Unchanged)
+            .collect();
+        if rows.is_empty() {
+            ui.label("The revisions are identical.");
+            return;
+        }
+        egui::ScrollArea::both()
+            .id_salt("document_revision_diff")
+            .show(ui, |ui| {
+                egui::Grid::new("document_revision_diff_grid")
+                    .num_columns(3)
+                    .spacing([20.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label(RichText::new("Path").strong());
+                        ui.label(RichText::new(format!("Revision {}", old.revision)).strong());
+                        ui.label(RichText::new(format!("Revision {}", new.revision)).strong());
+                        ui.end_row();
+                        for row in rows {
+                            let (old_color, new_color) = match row.kind {
+                                DiffKind::Unchanged => (None, None),
+                                DiffKind::Added => (None, Some(Color32::DARK_GREEN)),
+                                DiffKind::Removed => (Some(Color32::RED), None),
+                                DiffKind::Changed => {
+                                    (Some(Color32::RED), Some(Color32::DARK_GREEN))
+                                }
+                            };
+                            ui.label(RichText::new(&row.path).monospace());
+                            for (value, color) in [(row.old, old_color), (row.new, new_color)] {
+                                let mut text = RichText::new(value.unwrap_or_default()).monospace();
+                                if let Some(color) = color {
+                                    text = text.color(color);
+                                }
+                                ui.label(text);
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    fn show_document_snapshots(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        let Some(snapshots) = &mut self.document_snapshots else {
+            return action;
+        };
+        let mut error = None;
+        ui.heading(format!(
+            "Snapshots of {} documents",
+            snapshots.document_type
+        ));
+        ui.label(
+            RichText::new(
+                "A snapshot stores every document of the type as it is now, so the documents can \
+                 be compared after a contract migration or a test run.",
+            )
+            .color(Color32::GRAY),
+        );
+        ui.horizontal(|ui| {
+            ui.label("Label:");
+            ui.add(
+                egui::TextEdit::singleline(&mut snapshots.label_input)
+                    .hint_text("before migration")
+                    .desired_width(200.0),
+            );
+            let label = snapshots.label_input.trim().to_string();
+            if ui
+                .add_enabled(
+                    !snapshots.taking && !label.is_empty(),
+                    egui::Button::new("Take snapshot"),
+                )
+                .clicked()
+            {
+                snapshots.taking = true;
+                action = AppAction::BackendTask(BackendTask::DocumentTask(
+                    DocumentTask::SnapshotDocuments(
+                        snapshots.contract.clone(),
+                        snapshots.document_type.clone(),
+                        label,
+                    ),
+                ));
+            }
+            if snapshots.taking {
+                ui.spinner();
+            }
+        });
+        ui.add_space(5.0);
+
+        let mut deleted = None;
+        egui::Grid::new("document_snapshots")
+            .num_columns(3)
+            .spacing([20.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for snapshot in &snapshots.snapshots {
+                    ui.label(snapshot_label(snapshot));
+                    let mut count = format!("{} documents", snapshot.document_count);
+                    if snapshot.truncated {
+                        count.push_str(" (cut off)");
+                    }
+                    ui.label(count);
+                    if ui.button("Delete").clicked() {
+                        deleted = Some(snapshot.id);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(id) = deleted {
+            if let Err(e) = self.app_context.db.delete_document_snapshot(id) {
+                error = Some(format!("Failed to delete the snapshot: {}", e));
+            }
+            snapshots.reload(&self.app_context);
+        }
+
+        if snapshots.snapshots.len() >= 2 {
+            ui.add_space(5.0);
+            ui.horizontal(|ui| {
+                let before = snapshots.compared;
+                for (label, selected) in [
+                    ("Old:", &mut snapshots.compared.0),
+                    ("New:", &mut snapshots.compared.1),
+                ] {
+                    ui.label(label);
+                    let selected_text = snapshots
+                        .snapshots
+                        .iter()
+                        .find(|snapshot| Some(snapshot.id) == *selected)
+                        .map(snapshot_label)
+                        .unwrap_or_default();
+                    egui::ComboBox::from_id_salt(("document_snapshot", label))
+                        .selected_text(selected_text)
+                        .show_ui(ui, |ui| {
+                            for snapshot in &snapshots.snapshots {
+                                ui.selectable_value(
+                                    selected,
+                                    Some(snapshot.id),
+                                    snapshot_label(snapshot),
+                                );
+                            }
+                        });
+                }
+                if snapshots.compared != before {
+                    snapshots.differences = None;
+                }
+                if ui.button("Compare").clicked() {
+                    if let Err(e) = snapshots.compare(&self.app_context) {
+                        error = Some(e);
+                    }
+                }
+            });
+        }
+
+        if let Some(differences) = &snapshots.differences {
+            Self::show_snapshot_differences(ui, differences);
+        }
+
+        if let Some(error) = error {
+            self.display_message(&error, MessageType::Error);
+        }
+        action
+    }
+
+    fn show_snapshot_differences(ui: &mut Ui, differences: &[SnapshotDifference]) {
+        let count = |change| {
+            differences
+                .iter()
+                .filter(|difference| difference.change == change)
+                .count()
+        };
+        ui.label(format!(
+            "{} created, {} updated, {} deleted",
+            count(SnapshotChange::Created),
+            count(SnapshotChange::Updated),
+            count(SnapshotChange::Deleted)
+        ));
+        if differences.is_empty() {
+            ui.label("The snapshots hold the same documents.");
+            return;
+        }
+        egui::ScrollArea::vertical()
+            .id_salt("document_snapshot_differences")
+            .show(ui, |ui| {
+                for difference in differences {
+                    let color = match difference.change {
+                        SnapshotChange::Created => Color32::DARK_GREEN,
+                        SnapshotChange::Updated => Color32::from_rgb(200, 120, 0),
+                        SnapshotChange::Deleted => Color32::RED,
+                    };
+                    let revisions = match (difference.old_revision, difference.new_revision) {
+                        (Some(old), Some(new)) => format!("revision {} to {}", old, new),
+                        (Some(revision), None) | (None, Some(revision)) => {
+                            format!("revision {}", revision)
+                        }
+                        (None, None) => String::new(),
+                    };
+                    let title = RichText::new(format!(
+                        "{} {} ({})",
+                        difference.change.label(),
+                        difference.document_id,
+                        revisions
+                    ))
+                    .color(color);
+                    if difference.rows.is_empty() {
+                        ui.label(title);
+                        continue;
+                    }
+                    ui.collapsing(title, |ui| {
+                        egui::Grid::new(("snapshot_difference", &difference.document_id))
+                            .num_columns(3)
+                            .spacing([20.0, 4.0])
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for row in &difference.rows {
+                                    ui.label(RichText::new(&row.path).monospace());
+                                    ui.label(
+                                        RichText::new(row.old.clone().unwrap_or_default())
+                                            .monospace()
+                                            .color(Color32::RED),
+                                    );
+                                    ui.label(
+                                        RichText::new(row.new.clone().unwrap_or_default())
+                                            .monospace()
+                                            .color(Color32::DARK_GREEN),
+                                    );
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                }
+            });
+    }
+
+    fn show_codegen(&mut self, ui: &mut Ui) {
+        let Some(codegen) = &mut self.codegen else {
+            return;
+        };
+        ui.heading(format!("Generate code for {}", codegen.contract_name));
+        ui.label("Writes a struct for each document type of the contract, with serde attributes.");
+        ui.horizontal(|ui| {
+            ui.label("Directory:");
+            ui.text_edit_singleline(&mut codegen.directory_input);
+        });
+        ui.checkbox(&mut codegen.typescript, "Also write TypeScript interfaces");
+        let can_generate = !codegen.directory_input.trim().is_empty();
+        if ui
+            .add_enabled(can_generate, egui::Button::new("Generate"))
+            .clicked()
+        {
+            match codegen.write_files() {
+                Ok(paths) => self
+                    .display_message(&format!("Wrote {}", paths.join(", ")), MessageType::Success),
+                Err(e) => self.display_message(&e, MessageType::Error),
+            }
+        }
+    }
+
     fn show_vote_popup(&mut self, ui: &mut Ui) -> AppAction {
         let mut app_action = AppAction::None;
         if let Some((message, action)) = self.show_vote_popup.clone() {
@@ -165,9 +946,59 @@ impl ScreenLike for DocumentQueryScreen {
     }
 
     fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            if let Some(snapshots) = &mut self.document_snapshots {
+                snapshots.taking = false;
+            }
+            if let Some(lookup) = &mut self.document_lookup {
+                lookup.trading = false;
+            }
+        }
         self.error_message = Some((message.to_string(), message_type, Utc::now()));
     }
 
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::DocumentSnapshot(snapshot) = &backend_task_success_result {
+            if let Some(snapshots) = &mut self.document_snapshots {
+                snapshots.taking = false;
+                snapshots.compared = (None, None);
+                snapshots.reload(&self.app_context);
+            }
+            let mut message = format!(
+                "Stored {} {} documents as \"{}\"",
+                snapshot.document_count, snapshot.document_type, snapshot.label
+            );
+            if snapshot.truncated {
+                message.push_str(&format!(", only the first {}", MAX_SNAPSHOT_DOCUMENTS));
+            }
+            self.display_message(&message, MessageType::Success);
+            return;
+        }
+        let BackendTaskSuccessResult::Documents(documents) = backend_task_success_result else {
+            return;
+        };
+        let Some(lookup) = &mut self.document_lookup else {
+            return;
+        };
+        lookup.reload_revisions(&self.app_context);
+        lookup.trading = false;
+        lookup.document = lookup
+            .document_id
+            .and_then(|document_id| documents.get(&document_id).cloned().flatten());
+        let found = lookup
+            .document_id
+            .is_some_and(|document_id| matches!(documents.get(&document_id), Some(Some(_))));
+        if found {
+            let revision = lookup.revisions.last().map_or(0, |latest| latest.revision);
+            self.display_message(
+                &format!("Fetched revision {}", revision),
+                MessageType::Success,
+            );
+        } else {
+            self.display_message("Document not found on Platform", MessageType::Info);
+        }
+    }
+
     fn ui(&mut self, ctx: &Context) -> AppAction {
         self.check_error_expiration();
         let mut action = add_top_panel(
@@ -183,8 +1014,119 @@ impl ScreenLike for DocumentQueryScreen {
             RootScreenType::RootScreenDocumentQuery,
         );
 
-        action |=
-            add_contract_chooser_panel(ctx, &mut self.contract_search_term, &self.app_context);
+        let mut panel_request = None;
+        action |= add_contract_chooser_panel(
+            ctx,
+            &mut self.contract_search_term,
+            &self.app_context,
+            &mut panel_request,
+        );
+        match panel_request {
+            Some(request) => {
+                // Only one of the views is shown at a time
+                self.document_form = None;
+                self.document_drafts = None;
+                self.document_preview = None;
+                self.contract_view = None;
+                self.document_lookup = None;
+                self.document_snapshots = None;
+                self.codegen = None;
+                match request {
+                    ContractPanelRequest::NewDocument(contract_id, title, schema) => {
+                        let form = JsonSchemaForm::new(title.clone(), &schema);
+                        self.document_drafts = Some(FormDrafts::new(
+                            format!(
+                                "document:{}:{}",
+                                contract_id.to_string(Encoding::Base58),
+                                title
+                            ),
+                            &self.app_context,
+                        ));
+                        self.document_form = Some((title, form));
+                    }
+                    ContractPanelRequest::ViewContract(name, json) => {
+                        let viewer = JsonViewer::new(format!("contract_{}", name), json);
+                        self.contract_view = Some((name, viewer));
+                    }
+                    ContractPanelRequest::GenerateCode(contract_name, contract_json) => {
+                        self.codegen = Some(CodegenRequest {
+                            contract_name,
+                            contract_json,
+                            directory_input: "generated".to_string(),
+                            typescript: false,
+                        });
+                    }
+                    ContractPanelRequest::LookUpDocument(contract, document_type) => {
+                        self.document_lookup = Some(DocumentLookup::new(
+                            contract,
+                            document_type,
+                            &self.app_context,
+                        ));
+                    }
+                    ContractPanelRequest::Snapshots(contract, document_type) => {
+                        self.document_snapshots = Some(DocumentSnapshots::new(
+                            contract,
+                            document_type,
+                            &self.app_context,
+                        ));
+                    }
+                    ContractPanelRequest::ImportDocuments(contract_id, document_type) => {
+                        action |= AppAction::AddScreen(
+                            ScreenType::ImportDocuments(contract_id, document_type)
+                                .create_screen(&self.app_context),
+                        );
+                    }
+                    ContractPanelRequest::Analytics(contract_id) => {
+                        action |= AppAction::AddScreen(
+                            ScreenType::ContractAnalytics(contract_id)
+                                .create_screen(&self.app_context),
+                        );
+                    }
+                    ContractPanelRequest::DeleteDocuments(contract_id, document_type) => {
+                        action |= AppAction::AddScreen(
+                            ScreenType::DeleteDocuments(contract_id, document_type)
+                                .create_screen(&self.app_context),
+                        );
+                    }
+                    ContractPanelRequest::AppProfile(contract_id) => {
+                        action |= AppAction::AddScreen(Screen::AppProfilesScreen(
+                            AppProfilesScreen::new(&self.app_context).with_contract(contract_id),
+                        ));
+                    }
+                }
+            }
+            None => {}
+        }
+
+        let mut close_contract_view = false;
+        egui::CentralPanel::default().show(ctx, |ui| {
+            add_proof_status(ui, &self.app_context, &DOCUMENT_METHODS);
+            if let Some((message, message_type, _)) = &self.error_message {
+                let message_color = match message_type {
+                    MessageType::Error => egui::Color32::RED,
+                    MessageType::Info => egui::Color32::GRAY,
+                    MessageType::Success => egui::Color32::DARK_GREEN,
+                };
+                ui.label(egui::RichText::new(message).color(message_color));
+            }
+            if let Some((name, viewer)) = &mut self.contract_view {
+                ui.horizontal(|ui| {
+                    ui.heading(format!("Contract {}", name));
+                    if ui.button("Close").clicked() {
+                        close_contract_view = true;
+                    }
+                });
+                ui.add_space(5.0);
+                viewer.show(ui);
+            }
+            self.show_document_form(ui);
+            action |= self.show_document_lookup(ui);
+            action |= self.show_document_snapshots(ui);
+            self.show_codegen(ui);
+        });
+        if close_contract_view {
+            self.contract_view = None;
+        }
 
         action
     }
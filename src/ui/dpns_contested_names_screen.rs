@@ -1,19 +1,51 @@
 use super::{Screen, ScreenType};
-use crate::app::{AppAction, DesiredAppAction};
+use crate::app::{AppAction, AppEvent, DesiredAppAction};
 use crate::context::AppContext;
-use crate::model::contested_name::ContestedName;
-use crate::model::qualified_identity::{IdentityType, QualifiedIdentity};
+use crate::deep_link::DeepLink;
+use crate::metrics_export::contest_calendar_path;
+use crate::model::clock_skew::network_now;
+use crate::model::contest_annotation::ContestAnnotation;
+use crate::model::contest_calendar::contests_to_ics;
+use crate::model::contest_view::{ContestFilter, ContestTableView};
+use crate::model::contestant_reputation::ContestantReputation;
+use crate::model::contested_name::{Contestant, ContestantPreview, ContestedName};
+use crate::model::junk_name::JunkNameRules;
+use crate::model::preferences::{MetricsExport, TimeDisplayMode};
+use crate::model::qualified_identity::{IdentityGroup, IdentityType, QualifiedIdentity};
+use crate::model::session::{SavedVoteChoice, SavedVotes};
+use crate::model::vote_list::VoteList;
+use crate::model::voting_bloc::VotingBloc;
 use crate::platform::contested_names::ContestedResourceTask;
-use crate::platform::BackendTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::accessibility::{
+    escape_pressed, unlabeled_checkbox, with_accessible_label,
+};
+use crate::ui::components::detachable_window::show_detachable_window;
+use crate::ui::components::explorer_link::{explorer_menu_button, ExplorerItem};
+use crate::ui::components::help_overlay::term_tooltip;
 use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::proof_status::{add_proof_status, CONTESTED_NAMES_METHODS};
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::components::vote_triage::{TriageOutcome, VoteTriage};
+use crate::ui::help::{
+    HelpTerm, TERM_ABSTAIN, TERM_CONTESTED_RESOURCE, TERM_LOCK, TERM_LOCKED_VOTES,
+    TERM_NORMALIZED_NAME,
+};
+use crate::ui::helpers::display_scale::{apply_table_density, table_heights};
+use crate::ui::helpers::number_format::NumberFormatter;
+use crate::ui::helpers::redaction::redact_id;
+use crate::ui::helpers::time_format::TimeFormatter;
 use crate::ui::identities::add_existing_identity_screen::AddExistingIdentityScreen;
+use crate::ui::identities::identity_detail_screen::IdentityDetailScreen;
 use crate::ui::{MessageType, RootScreenType, ScreenLike};
-use chrono::{DateTime, LocalResult, TimeZone, Utc};
-use chrono_humanize::HumanTime;
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::prelude::Identifier;
 use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
-use egui::{Context, Frame, Margin, Ui};
+use egui::{Context, Frame, Margin, RichText, Ui, WidgetType};
 use egui_extras::{Column, TableBuilder};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use tracing::error;
 
@@ -24,6 +56,150 @@ enum SortColumn {
     AbstainVotes,
     EndingTime,
     LastUpdated,
+    ContestantCount,
+    TimeRemaining,
+}
+
+/// Key under which this screen's preferences are stored
+const PREFERENCES_SCREEN_KEY: &str = "dpns_contested_names";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum ContestColumn {
+    ContestedName,
+    LockedVotes,
+    AbstainVotes,
+    EndingTime,
+    LastUpdated,
+    MyVote,
+    ContestantCount,
+    TimeRemaining,
+    Contestants,
+    Suggested,
+}
+
+impl ContestColumn {
+    /// All columns in display order
+    const ALL: [ContestColumn; 10] = [
+        ContestColumn::ContestedName,
+        ContestColumn::LockedVotes,
+        ContestColumn::AbstainVotes,
+        ContestColumn::EndingTime,
+        ContestColumn::LastUpdated,
+        ContestColumn::MyVote,
+        ContestColumn::ContestantCount,
+        ContestColumn::TimeRemaining,
+        ContestColumn::Contestants,
+        ContestColumn::Suggested,
+    ];
+
+    /// Stable identifier used when persisting column preferences
+    fn id(&self) -> &'static str {
+        match self {
+            ContestColumn::ContestedName => "contested_name",
+            ContestColumn::LockedVotes => "locked_votes",
+            ContestColumn::AbstainVotes => "abstain_votes",
+            ContestColumn::EndingTime => "ending_time",
+            ContestColumn::LastUpdated => "last_updated",
+            ContestColumn::MyVote => "my_vote",
+            ContestColumn::ContestantCount => "contestant_count",
+            ContestColumn::TimeRemaining => "time_remaining",
+            ContestColumn::Contestants => "contestants",
+            ContestColumn::Suggested => "suggested",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|column| column.id() == id)
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            ContestColumn::ContestedName => "Contested Name",
+            ContestColumn::LockedVotes => "Locked Votes",
+            ContestColumn::AbstainVotes => "Abstain Votes",
+            ContestColumn::EndingTime => "Ending Time",
+            ContestColumn::LastUpdated => "Last Updated",
+            ContestColumn::MyVote => "My Vote",
+            ContestColumn::ContestantCount => "Contestant Count",
+            ContestColumn::TimeRemaining => "Time Remaining",
+            ContestColumn::Contestants => "Contestants",
+            ContestColumn::Suggested => "Suggested",
+        }
+    }
+
+    /// The domain term the column shows, explained when hovering its header
+    fn term(&self) -> Option<&'static HelpTerm> {
+        match self {
+            ContestColumn::ContestedName => Some(&TERM_NORMALIZED_NAME),
+            ContestColumn::LockedVotes => Some(&TERM_LOCKED_VOTES),
+            ContestColumn::AbstainVotes => Some(&TERM_ABSTAIN),
+            _ => None,
+        }
+    }
+
+    /// Key used for per-column preferences such as the time display override
+    fn preferences_key(&self) -> String {
+        format!("{}.{}", PREFERENCES_SCREEN_KEY, self.id())
+    }
+
+    fn is_time_column(&self) -> bool {
+        matches!(self, ContestColumn::EndingTime | ContestColumn::LastUpdated)
+    }
+
+    fn visible_by_default(&self) -> bool {
+        !matches!(self, ContestColumn::MyVote | ContestColumn::ContestantCount)
+    }
+
+    fn initial_width(&self) -> f32 {
+        match self {
+            ContestColumn::ContestedName
+            | ContestColumn::EndingTime
+            | ContestColumn::LastUpdated => 200.0,
+            _ => 100.0,
+        }
+    }
+
+    fn sort_column(&self) -> Option<SortColumn> {
+        match self {
+            ContestColumn::ContestedName => Some(SortColumn::ContestedName),
+            ContestColumn::LockedVotes => Some(SortColumn::LockedVotes),
+            ContestColumn::AbstainVotes => Some(SortColumn::AbstainVotes),
+            ContestColumn::EndingTime => Some(SortColumn::EndingTime),
+            ContestColumn::LastUpdated => Some(SortColumn::LastUpdated),
+            ContestColumn::ContestantCount => Some(SortColumn::ContestantCount),
+            ContestColumn::TimeRemaining => Some(SortColumn::TimeRemaining),
+            ContestColumn::MyVote | ContestColumn::Contestants | ContestColumn::Suggested => None,
+        }
+    }
+
+    /// Loads the visible columns from the saved preferences, falling back to the defaults
+    fn load_visible(app_context: &AppContext) -> Vec<ContestColumn> {
+        let saved = app_context
+            .preferences()
+            .visible_columns
+            .get(PREFERENCES_SCREEN_KEY)
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|id| ContestColumn::from_id(id))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|columns| !columns.is_empty());
+        saved.unwrap_or_else(|| {
+            ContestColumn::ALL
+                .into_iter()
+                .filter(|column| column.visible_by_default())
+                .collect()
+        })
+    }
+}
+
+/// A note being written in the contest detail
+struct AnnotationDraft {
+    contest: String,
+    /// Base58 id of the contestant, None for a note on the contest
+    contestant: Option<String>,
+    tags_input: String,
+    note_input: String,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -35,6 +211,11 @@ enum SortOrder {
 pub struct DPNSContestedNamesScreen {
     // No need for Mutex as this can only refresh when entering screen
     voting_identities: Arc<Vec<QualifiedIdentity>>,
+    /// Groups the user moved identities to on the identities screen
+    identity_groups: HashMap<Identifier, IdentityGroup>,
+    voting_blocs: Vec<VotingBloc>,
+    /// Community vote lists whose suggestions are shown in the table
+    vote_lists: Vec<VoteList>,
     user_identities: Arc<Vec<QualifiedIdentity>>,
     contested_names: Arc<Mutex<Vec<ContestedName>>>,
     pub app_context: Arc<AppContext>,
@@ -42,6 +223,108 @@ pub struct DPNSContestedNamesScreen {
     sort_column: SortColumn,
     sort_order: SortOrder,
     show_vote_popup_info: Option<(String, ContestedResourceTask)>,
+    /// The pending votes last checked for contests that ended, None to check them again
+    ended_votes_checked: Option<ContestedResourceTask>,
+    vote_popup_detached: bool,
+    /// Whether the vote popup was shown last frame, its first button takes the focus when
+    /// it opens so voting works from the keyboard
+    vote_popup_open: bool,
+    contest_detail: Option<String>,
+    contest_detail_detached: bool,
+    watched_contests: HashSet<String>,
+    visible_columns: Vec<ContestColumn>,
+    ending_time_format: TimeFormatter,
+    last_updated_format: TimeFormatter,
+    number_format: NumberFormatter,
+    junk_name_rules: JunkNameRules,
+    identity_to_open: Option<Identifier>,
+    /// A default vote of a voting bloc chosen in a context menu, sent on the next frame
+    bloc_vote_to_cast: Option<ContestedResourceTask>,
+    /// Contests ticked in the table for a bulk vote, by normalized name
+    selected_contests: BTreeSet<String>,
+    calendar_path_input: String,
+    /// The keyboard triage shown instead of the table while it is open
+    triage: Option<VoteTriage>,
+    /// Documents and identities of the contestants fetched from the contest detail, by name
+    contestant_previews: HashMap<String, Vec<ContestantPreview>>,
+    /// Reputation signals fetched for contestants, stored across sessions
+    contestant_reputations: HashMap<Identifier, ContestantReputation>,
+    /// The user's notes on contests and contestants
+    annotations: Vec<ContestAnnotation>,
+    annotation_draft: Option<AnnotationDraft>,
+    /// Which contests the table shows
+    filter: ContestFilter,
+    /// The saved view last applied, until the layout is changed
+    active_view: Option<String>,
+    view_name_input: String,
+}
+
+/// Shown instead of the vote buttons of a contest whose end time passed
+const AWAITING_RESULT: &str = "Voting has ended, the result is not synced yet";
+
+/// Formats the time left until a contest ends, e.g. "3d 4h" or "25m", `None` once it ended
+pub(crate) fn format_time_remaining(ending_time: u64) -> Option<String> {
+    let now = network_now().timestamp_millis() as u64;
+    if ending_time <= now {
+        return None;
+    }
+    let remaining_minutes = (ending_time - now) / 60_000;
+    let days = remaining_minutes / (60 * 24);
+    let hours = (remaining_minutes / 60) % 24;
+    let minutes = remaining_minutes % 60;
+    Some(if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    })
+}
+
+/// The color of the time left until a contest ends: green with more than three days left,
+/// yellow under a day, red under two hours and gray once it ended
+pub(crate) fn time_remaining_color(ending_time: u64) -> Option<egui::Color32> {
+    const HOUR: u64 = 60 * 60 * 1000;
+    let now = network_now().timestamp_millis() as u64;
+    match ending_time.saturating_sub(now) {
+        0 => Some(egui::Color32::GRAY),
+        remaining if remaining < 2 * HOUR => Some(egui::Color32::from_rgb(200, 40, 40)),
+        remaining if remaining < 24 * HOUR => Some(egui::Color32::from_rgb(200, 150, 0)),
+        remaining if remaining > 3 * 24 * HOUR => Some(egui::Color32::from_rgb(0, 140, 0)),
+        _ => None,
+    }
+}
+
+/// A vote choice on a contest, with votes for a contestant shown by their name
+pub(crate) fn format_vote_choice(
+    contested_name: &ContestedName,
+    vote_choice: &ResourceVoteChoice,
+) -> String {
+    match vote_choice {
+        ResourceVoteChoice::TowardsIdentity(identity_id) => contested_name
+            .contestants
+            .as_ref()
+            .and_then(|contestants| contestants.iter().find(|c| &c.id == identity_id))
+            .map(|contestant| contestant.name.clone())
+            .unwrap_or_else(|| identity_id.to_string(Encoding::Base58)),
+        ResourceVoteChoice::Abstain => "Abstain".to_string(),
+        ResourceVoteChoice::Lock => "Lock".to_string(),
+    }
+}
+
+/// Summarizes the votes our identities cast on a contest
+fn format_my_votes(contested_name: &ContestedName) -> String {
+    if contested_name.my_votes.is_empty() {
+        return "-".to_string();
+    }
+    let mut choices: Vec<String> = contested_name
+        .my_votes
+        .values()
+        .map(|vote_choice| format_vote_choice(contested_name, vote_choice))
+        .collect();
+    choices.sort();
+    choices.dedup();
+    choices.join(", ")
 }
 
 impl DPNSContestedNamesScreen {
@@ -56,12 +339,40 @@ impl DPNSContestedNamesScreen {
             .db
             .get_local_voting_identities(&app_context)
             .unwrap_or_default();
+        let identity_groups = app_context
+            .db
+            .get_identity_groups(app_context)
+            .unwrap_or_default();
+        let voting_blocs = app_context
+            .db
+            .get_voting_blocs(app_context)
+            .unwrap_or_default();
+        let vote_lists = app_context
+            .db
+            .get_vote_lists(app_context)
+            .unwrap_or_default();
         let user_identities = app_context
             .db
             .get_local_user_identities(&app_context)
             .unwrap_or_default();
-        Self {
+        let watched_contests = app_context
+            .db
+            .get_watched_contests(&app_context)
+            .unwrap_or_default();
+        let contestant_reputations = app_context
+            .db
+            .get_contestant_reputations(app_context)
+            .unwrap_or_default();
+        let annotations = app_context
+            .db
+            .get_contest_annotations(app_context)
+            .unwrap_or_default();
+        let preferences = app_context.preferences();
+        let mut screen = Self {
             voting_identities: Arc::new(voting_identities),
+            identity_groups,
+            voting_blocs,
+            vote_lists,
             user_identities: Arc::new(user_identities),
             contested_names,
             app_context: app_context.clone(),
@@ -69,19 +380,480 @@ impl DPNSContestedNamesScreen {
             sort_column: SortColumn::ContestedName,
             sort_order: SortOrder::Ascending,
             show_vote_popup_info: None,
+            ended_votes_checked: None,
+            vote_popup_detached: false,
+            vote_popup_open: false,
+            contest_detail: None,
+            contest_detail_detached: false,
+            watched_contests,
+            visible_columns: ContestColumn::load_visible(app_context),
+            ending_time_format: TimeFormatter::for_column(
+                &preferences,
+                &ContestColumn::EndingTime.preferences_key(),
+            ),
+            last_updated_format: TimeFormatter::for_column(
+                &preferences,
+                &ContestColumn::LastUpdated.preferences_key(),
+            ),
+            number_format: NumberFormatter::new(&preferences),
+            junk_name_rules: preferences.junk_name_rules.clone(),
+            identity_to_open: None,
+            bloc_vote_to_cast: None,
+            selected_contests: BTreeSet::new(),
+            calendar_path_input: "dpns-contests.ics".to_string(),
+            triage: None,
+            contestant_previews: HashMap::new(),
+            contestant_reputations,
+            annotations,
+            annotation_draft: None,
+            filter: ContestFilter::default(),
+            active_view: None,
+            view_name_input: String::new(),
+        };
+        if let Some(view) = preferences.contest_views.default_view() {
+            screen.apply_view(view);
         }
+        screen
     }
 
+    /// Applies the filter, sort and columns of a saved view
+    fn apply_view(&mut self, view: &ContestTableView) {
+        self.filter = view.filter.clone();
+        if let Some(sort_column) =
+            ContestColumn::from_id(&view.sort_column).and_then(|column| column.sort_column())
+        {
+            self.sort_column = sort_column;
+        }
+        self.sort_order = if view.descending {
+            SortOrder::Descending
+        } else {
+            SortOrder::Ascending
+        };
+        let columns: Vec<ContestColumn> = view
+            .columns
+            .iter()
+            .filter_map(|id| ContestColumn::from_id(id))
+            .collect();
+        if !columns.is_empty() {
+            self.visible_columns = columns;
+            self.save_visible_columns();
+        }
+        self.active_view = Some(view.name.clone());
+    }
+
+    /// The current filter, sort and columns as a view
+    fn current_view(&self, name: String) -> ContestTableView {
+        let sort_column = ContestColumn::ALL
+            .into_iter()
+            .find(|column| column.sort_column() == Some(self.sort_column))
+            .unwrap_or(ContestColumn::ContestedName);
+        ContestTableView {
+            name,
+            filter: self.filter.clone(),
+            sort_column: sort_column.id().to_string(),
+            descending: self.sort_order == SortOrder::Descending,
+            columns: self
+                .visible_columns
+                .iter()
+                .map(|column| column.id().to_string())
+                .collect(),
+        }
+    }
+
+    /// Picks up display and junk name flag changes made in the settings or the column menus
+    fn reload_display_formats(&mut self) {
+        let preferences = self.app_context.preferences();
+        self.ending_time_format =
+            TimeFormatter::for_column(&preferences, &ContestColumn::EndingTime.preferences_key());
+        self.last_updated_format =
+            TimeFormatter::for_column(&preferences, &ContestColumn::LastUpdated.preferences_key());
+        self.number_format = NumberFormatter::new(&preferences);
+        self.junk_name_rules = preferences.junk_name_rules.clone();
+    }
+
+    fn set_column_time_display(&mut self, column: ContestColumn, mode: Option<TimeDisplayMode>) {
+        if let Err(e) = self
+            .app_context
+            .update_preferences(|preferences| match mode {
+                Some(mode) => {
+                    preferences
+                        .column_time_display
+                        .insert(column.preferences_key(), mode);
+                }
+                None => {
+                    preferences
+                        .column_time_display
+                        .remove(&column.preferences_key());
+                }
+            })
+        {
+            error!("Failed to save time display preferences: {:?}", e);
+        }
+        self.reload_display_formats();
+    }
+
+    /// Whether the contest still takes votes by the network's clock
+    fn accepts_votes(contested_name: &ContestedName) -> bool {
+        !contested_name.has_ended(network_now().timestamp_millis() as u64)
+    }
+
+    /// Whether the named contest still takes votes, contests not loaded are left to Platform
+    fn name_accepts_votes(&self, normalized_contested_name: &str) -> bool {
+        self.contested_names
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|contested_name| {
+                contested_name.normalized_contested_name == normalized_contested_name
+            })
+            .map_or(true, Self::accepts_votes)
+    }
+
+    /// Drops the votes waiting for confirmation on contests that ended meanwhile, Platform
+    /// would reject them. Only runs when the pending votes or the contests changed.
+    fn drop_ended_votes(&mut self) {
+        let pending = self.show_vote_popup_info.as_ref().map(|(_, task)| task);
+        if pending == self.ended_votes_checked.as_ref() {
+            return;
+        }
+        self.ended_votes_checked = pending.cloned();
+        let Some((message, task)) = self.show_vote_popup_info.clone() else {
+            return;
+        };
+        let ended: Vec<String> = match &task {
+            ContestedResourceTask::VoteOnDPNSName(name, ..) => vec![name.clone()],
+            ContestedResourceTask::VoteOnMultipleDPNSNames(votes, _) => {
+                votes.iter().map(|(name, _)| name.clone()).collect()
+            }
+            _ => vec![],
+        }
+        .into_iter()
+        .filter(|name| !self.name_accepts_votes(name))
+        .collect();
+        if ended.is_empty() {
+            return;
+        }
+        let task = match task {
+            ContestedResourceTask::VoteOnMultipleDPNSNames(mut votes, voters) => {
+                votes.retain(|(name, _)| !ended.contains(name));
+                (!votes.is_empty()).then_some(ContestedResourceTask::VoteOnMultipleDPNSNames(
+                    votes, voters,
+                ))
+            }
+            _ => None,
+        };
+        self.show_vote_popup_info = task.map(|task| (message, task));
+        self.ended_votes_checked = self
+            .show_vote_popup_info
+            .as_ref()
+            .map(|(_, task)| task.clone());
+        self.display_message(
+            &format!(
+                "Voting has ended on {}, the pending votes there were removed.",
+                ended.join(", ")
+            ),
+            MessageType::Info,
+        );
+    }
+
+    fn open_lock_vote_popup(&mut self, normalized_contested_name: &str) {
+        self.show_vote_popup_info = Some((
+            format!(
+                "Confirm Voting to Lock the name \"{}\".\n\nSelect the identity to vote with:",
+                normalized_contested_name
+            ),
+            ContestedResourceTask::VoteOnDPNSName(
+                normalized_contested_name.to_string(),
+                ResourceVoteChoice::Lock,
+                vec![],
+            ),
+        ));
+    }
+
+    fn open_abstain_vote_popup(&mut self, normalized_contested_name: &str) {
+        self.show_vote_popup_info = Some((
+            format!(
+                "Confirm Voting to Abstain on distribution of \"{}\".\n\nSelect the identity to vote with:",
+                normalized_contested_name
+            ),
+            ContestedResourceTask::VoteOnDPNSName(
+                normalized_contested_name.to_string(),
+                ResourceVoteChoice::Abstain,
+                vec![],
+            ),
+        ));
+    }
+
+    /// The contests ticked for a bulk vote and the vote waiting for confirmation, saved with
+    /// the session
+    pub fn saved_votes(&self) -> SavedVotes {
+        let pending_vote = self
+            .show_vote_popup_info
+            .as_ref()
+            .map(|(message, task)| {
+                let votes: Vec<(String, SavedVoteChoice)> = match task {
+                    ContestedResourceTask::VoteOnDPNSName(name, vote_choice, _) => {
+                        vec![(name.clone(), vote_choice.into())]
+                    }
+                    ContestedResourceTask::VoteOnMultipleDPNSNames(votes, _) => votes
+                        .iter()
+                        .map(|(name, vote_choice)| (name.clone(), vote_choice.into()))
+                        .collect(),
+                    _ => vec![],
+                };
+                (message.clone(), votes)
+            })
+            .filter(|(_, votes)| !votes.is_empty());
+        SavedVotes {
+            selected_contests: self.selected_contests.iter().cloned().collect(),
+            pending_vote,
+        }
+    }
+
+    /// Brings back the selection and unsent vote of a saved session. The voters are chosen
+    /// again in the confirmation popup.
+    pub fn restore_votes(&mut self, votes: SavedVotes) {
+        self.selected_contests = votes.selected_contests.into_iter().collect();
+        self.show_vote_popup_info = votes.pending_vote.and_then(|(message, votes)| {
+            let mut votes: Vec<(String, ResourceVoteChoice)> = votes
+                .into_iter()
+                .filter_map(|(name, vote_choice)| Some((name, vote_choice.to_vote_choice()?)))
+                .collect();
+            let task = match votes.len() {
+                0 => return None,
+                1 => {
+                    let (name, vote_choice) = votes.remove(0);
+                    ContestedResourceTask::VoteOnDPNSName(name, vote_choice, vec![])
+                }
+                _ => ContestedResourceTask::VoteOnMultipleDPNSNames(votes, vec![]),
+            };
+            Some((message, task))
+        });
+    }
+
+    /// Opens the detail of a contest, e.g. from a link. It shows once the contest is loaded.
+    pub fn open_contest(&mut self, normalized_contested_name: String) {
+        self.contest_detail = Some(normalized_contested_name);
+    }
+
+    fn open_bulk_vote_popup(&mut self, vote_choice: ResourceVoteChoice) {
+        let names: Vec<String> = self
+            .selected_contests
+            .iter()
+            .filter(|name| self.name_accepts_votes(name))
+            .cloned()
+            .collect();
+        if names.is_empty() {
+            self.display_message("Voting ended on all selected contests", MessageType::Info);
+            return;
+        }
+        let action = if vote_choice == ResourceVoteChoice::Lock {
+            "Lock"
+        } else {
+            "Abstain on"
+        };
+        self.show_vote_popup_info = Some((
+            format!(
+                "Confirm Voting to {} {} names: {}.\n\nSelect the identity to vote with:",
+                action,
+                names.len(),
+                names.join(", ")
+            ),
+            ContestedResourceTask::VoteOnMultipleDPNSNames(
+                names.into_iter().map(|name| (name, vote_choice)).collect(),
+                vec![],
+            ),
+        ));
+    }
+
+    /// The loaded voting identities that are members of the bloc
+    fn bloc_voters(&self, bloc: &VotingBloc) -> Vec<QualifiedIdentity> {
+        self.voting_identities
+            .iter()
+            .filter(|identity| bloc.members.contains(&identity.identity.id()))
+            .cloned()
+            .collect()
+    }
+
+    fn toggle_watch(&mut self, normalized_contested_name: &str) {
+        let watched = !self.watched_contests.contains(normalized_contested_name);
+        match self.app_context.db.set_contest_watched(
+            normalized_contested_name,
+            watched,
+            &self.app_context,
+        ) {
+            Ok(_) => {
+                if watched {
+                    self.watched_contests
+                        .insert(normalized_contested_name.to_string());
+                } else {
+                    self.watched_contests.remove(normalized_contested_name);
+                }
+            }
+            Err(e) => {
+                self.display_message(
+                    &format!("Failed to update watch list: {}", e),
+                    MessageType::Error,
+                );
+            }
+        }
+    }
+
+    /// Buttons that copy a link to the contest, opening either this tool or the platform explorer
+    fn render_share_link_buttons(&self, ui: &mut Ui, normalized_contested_name: &str) {
+        if ui
+            .button("Dash Evo Tool link")
+            .on_hover_text("Opens the contest in Dash Evo Tool")
+            .clicked()
+        {
+            let link = DeepLink::Contest(normalized_contested_name.to_string()).to_uri();
+            ui.ctx().copy_text(link);
+            ui.close_menu();
+        }
+        let explorer_url = self.app_context.preferences().explorer.contest_url(
+            &self.app_context.network_string(),
+            normalized_contested_name,
+        );
+        let explorer_button = ui
+            .add_enabled(
+                explorer_url.is_some(),
+                egui::Button::new("Platform explorer link"),
+            )
+            .on_disabled_hover_text("No platform explorer is set for this network in Settings");
+        if explorer_button.clicked() {
+            if let Some(url) = explorer_url {
+                ui.ctx().copy_text(url);
+            }
+            ui.close_menu();
+        }
+    }
+
+    /// Right-click menu shared by all cells of a contest row
+    fn show_row_context_menu(&mut self, response: &egui::Response, contested_name: &ContestedName) {
+        response.context_menu(|ui| self.show_row_actions(ui, contested_name));
+    }
+
+    /// The actions on a contest, in the row context menu and the row's actions button
+    fn show_row_actions(&mut self, ui: &mut Ui, contested_name: &ContestedName) {
+        let name = &contested_name.normalized_contested_name;
+        if term_tooltip(
+            ui.button("Copy normalized name"),
+            &TERM_NORMALIZED_NAME,
+            None,
+        )
+        .clicked()
+        {
+            ui.ctx().copy_text(name.clone());
+            ui.close_menu();
+        }
+        let contestant_ids = contested_name
+            .contestants
+            .as_ref()
+            .map(|contestants| {
+                contestants
+                    .iter()
+                    .map(|contestant| contestant.id.to_string(Encoding::Base58))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if ui
+            .add_enabled(
+                !contestant_ids.is_empty(),
+                egui::Button::new("Copy contestant IDs"),
+            )
+            .clicked()
+        {
+            ui.ctx().copy_text(contestant_ids.join("\n"));
+            ui.close_menu();
+        }
+        ui.menu_button("Copy shareable link", |ui| {
+            self.render_share_link_buttons(ui, name);
+        });
+        ui.separator();
+        if ui.button("Open contest detail").clicked() {
+            self.contest_detail = Some(name.clone());
+            ui.close_menu();
+        }
+        let watch_label = if self.watched_contests.contains(name) {
+            "Unwatch"
+        } else {
+            "Watch"
+        };
+        if ui.button(watch_label).clicked() {
+            self.toggle_watch(name);
+            ui.close_menu();
+        }
+        ui.separator();
+        if !Self::accepts_votes(contested_name) {
+            ui.label(RichText::new(AWAITING_RESULT).color(egui::Color32::GRAY));
+            return;
+        }
+        if term_tooltip(ui.button("Vote Lock"), &TERM_LOCK, None).clicked() {
+            self.open_lock_vote_popup(name);
+            ui.close_menu();
+        }
+        if term_tooltip(ui.button("Vote Abstain"), &TERM_ABSTAIN, None).clicked() {
+            self.open_abstain_vote_popup(name);
+            ui.close_menu();
+        }
+        for bloc in &self.voting_blocs {
+            let Some(default_vote) = bloc.default_vote else {
+                continue;
+            };
+            let voters = self.bloc_voters(bloc);
+            if voters.is_empty() {
+                continue;
+            }
+            if ui
+                .button(format!("Vote {} with {}", default_vote, bloc.name))
+                .clicked()
+            {
+                self.bloc_vote_to_cast = Some(ContestedResourceTask::VoteOnDPNSName(
+                    name.clone(),
+                    default_vote.vote_choice(),
+                    voters,
+                ));
+                ui.close_menu();
+            }
+        }
+    }
+
+    /// The actions on a contestant, in its context menu and the contest detail
+    fn show_contestant_actions(&mut self, ui: &mut Ui, contestant: &Contestant) {
+        if ui.button("View identity").clicked() {
+            self.identity_to_open = Some(contestant.id);
+            ui.close_menu();
+        }
+        if ui.button("Copy identity ID").clicked() {
+            ui.ctx()
+                .copy_text(contestant.id.to_string(Encoding::Base58));
+            ui.close_menu();
+        }
+        explorer_menu_button(
+            ui,
+            &self.app_context,
+            "Open in explorer",
+            ExplorerItem::Identity(contestant.id),
+        );
+    }
+
+    /// The contestants as vote buttons. With `action_buttons` each also gets a button for
+    /// the actions otherwise only in its context menu.
     fn show_contested_name_details(
         &mut self,
         ui: &mut Ui,
         contested_name: &ContestedName,
         is_locked_votes_bold: bool,
         max_contestant_votes: u32,
+        action_buttons: bool,
     ) {
         if let Some(contestants) = &contested_name.contestants {
             for contestant in contestants {
-                let button_text = format!("{} - {} votes", contestant.name, contestant.votes);
+                let button_text = format!(
+                    "{} - {} votes",
+                    contestant.name,
+                    self.number_format.format_integer(contestant.votes as u64)
+                );
 
                 // Determine if this contestant's votes should be bold
                 let text = if contestant.votes == max_contestant_votes && !is_locked_votes_bold {
@@ -92,7 +864,49 @@ impl DPNSContestedNamesScreen {
                     egui::RichText::new(button_text)
                 };
 
-                if ui.button(text).clicked() {
+                let accepts_votes = Self::accepts_votes(contested_name);
+                let response = ui
+                    .horizontal(|ui| {
+                        let response = with_accessible_label(
+                            ui.add_enabled(accepts_votes, egui::Button::new(text))
+                                .on_hover_text("Click to vote, right-click for more")
+                                .on_disabled_hover_text(AWAITING_RESULT),
+                            WidgetType::Button,
+                            format!(
+                                "Vote for {} on {}, {} votes",
+                                contestant.name,
+                                contested_name.normalized_contested_name,
+                                contestant.votes
+                            ),
+                        );
+                        if action_buttons {
+                            let menu = ui.menu_button("⋯", |ui| {
+                                self.show_contestant_actions(ui, contestant)
+                            });
+                            with_accessible_label(
+                                menu.response,
+                                WidgetType::Button,
+                                format!("Actions for contestant {}", contestant.name),
+                            );
+                        }
+                        response
+                    })
+                    .inner;
+                response.context_menu(|ui| self.show_contestant_actions(ui, contestant));
+                if action_buttons {
+                    self.show_reputation_signals(ui, contestant.id);
+                    let contestant_id = contestant.id.to_string(Encoding::Base58);
+                    if let Some(annotation) = self.annotations.iter().find(|annotation| {
+                        annotation.contest == contested_name.normalized_contested_name
+                            && annotation.contestant.as_ref() == Some(&contestant_id)
+                    }) {
+                        ui.horizontal_wrapped(|ui| {
+                            ui.add_space(10.0);
+                            ui.label(RichText::new(format!("✎ {}", annotation.summary())).small());
+                        });
+                    }
+                }
+                if response.clicked() {
                     self.show_vote_popup_info = Some((
                         format!(
                             "Confirm Voting for Contestant {} for name \"{}\".\n\nSelect the identity to vote with:",
@@ -108,6 +922,34 @@ impl DPNSContestedNamesScreen {
         }
     }
 
+    /// The reputation signals of a contestant on one line, those worth a closer look in orange
+    fn show_reputation_signals(&self, ui: &mut Ui, identity_id: Identifier) {
+        let Some(reputation) = self.contestant_reputations.get(&identity_id) else {
+            return;
+        };
+        let now = network_now().timestamp_millis() as u64;
+        ui.horizontal_wrapped(|ui| {
+            ui.add_space(10.0);
+            for (i, (signal, flagged)) in reputation.signals(now).into_iter().enumerate() {
+                if i > 0 {
+                    ui.label(RichText::new("·").small().color(egui::Color32::GRAY));
+                }
+                let text = RichText::new(signal).small();
+                if flagged {
+                    ui.label(text.color(egui::Color32::from_rgb(200, 150, 0)));
+                } else {
+                    ui.label(text.color(egui::Color32::GRAY));
+                }
+            }
+        })
+        .response
+        .on_hover_text(format!(
+            "Checked {}",
+            self.last_updated_format
+                .format_millis(reputation.fetched_at as u64 * 1000)
+        ));
+    }
+
     fn sort_contested_names(&self, contested_names: &mut Vec<ContestedName>) {
         contested_names.sort_by(|a, b| {
             let order = match self.sort_column {
@@ -118,6 +960,16 @@ impl DPNSContestedNamesScreen {
                 SortColumn::AbstainVotes => a.abstain_votes.cmp(&b.abstain_votes),
                 SortColumn::EndingTime => a.end_time.cmp(&b.end_time),
                 SortColumn::LastUpdated => a.last_updated.cmp(&b.last_updated),
+                SortColumn::ContestantCount => a
+                    .contestants
+                    .as_ref()
+                    .map(|contestants| contestants.len())
+                    .cmp(&b.contestants.as_ref().map(|contestants| contestants.len())),
+                // Contests without a known end time last
+                SortColumn::TimeRemaining => a
+                    .end_time
+                    .unwrap_or(u64::MAX)
+                    .cmp(&b.end_time.unwrap_or(u64::MAX)),
             };
 
             if self.sort_order == SortOrder::Descending {
@@ -137,41 +989,625 @@ impl DPNSContestedNamesScreen {
             let now = Utc::now();
             let elapsed = now.signed_duration_since(*timestamp);
 
-            // Automatically dismiss the error message after 5 seconds
-            if elapsed.num_seconds() > 5 {
-                self.dismiss_error();
-            }
+            // Automatically dismiss the error message after 5 seconds
+            if elapsed.num_seconds() > 5 {
+                self.dismiss_error();
+            }
+        }
+    }
+
+    fn toggle_sort(&mut self, column: SortColumn) {
+        self.active_view = None;
+        if self.sort_column == column {
+            self.sort_order = match self.sort_order {
+                SortOrder::Ascending => SortOrder::Descending,
+                SortOrder::Descending => SortOrder::Ascending,
+            };
+        } else {
+            self.sort_column = column;
+            self.sort_order = SortOrder::Ascending;
+        }
+    }
+
+    fn render_no_active_contests(&mut self, ui: &mut Ui) {
+        ui.vertical_centered(|ui| {
+            ui.add_space(20.0); // Add some space to separate from the top
+            ui.label(
+                egui::RichText::new("No active contests at the moment.")
+                    .heading()
+                    .strong()
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(10.0);
+            ui.label("Please check back later or try refreshing the list.");
+            ui.add_space(20.0);
+            if ui.button("Refresh").clicked() {
+                self.refresh(); // Call refresh logic when the user clicks "Refresh"
+            }
+        });
+    }
+
+    fn is_column_visible(&self, column: ContestColumn) -> bool {
+        self.visible_columns.contains(&column)
+    }
+
+    fn set_column_visible(&mut self, column: ContestColumn, visible: bool) {
+        let mut visible_columns: Vec<ContestColumn> = ContestColumn::ALL
+            .into_iter()
+            .filter(|c| {
+                if *c == column {
+                    visible
+                } else {
+                    self.visible_columns.contains(c)
+                }
+            })
+            .collect();
+        if visible_columns.is_empty() {
+            visible_columns.push(ContestColumn::ContestedName);
+        }
+        self.visible_columns = visible_columns;
+        self.active_view = None;
+        self.save_visible_columns();
+    }
+
+    fn save_visible_columns(&self) {
+        let column_ids = self
+            .visible_columns
+            .iter()
+            .map(|column| column.id().to_string())
+            .collect();
+        if let Err(e) = self.app_context.update_preferences(|preferences| {
+            preferences
+                .visible_columns
+                .insert(PREFERENCES_SCREEN_KEY.to_string(), column_ids);
+        }) {
+            error!("Failed to save column preferences: {:?}", e);
+        }
+    }
+
+    /// Menu to filter the table
+    fn show_filter_menu(&mut self, ui: &mut Ui) {
+        let before = self.filter.clone();
+        ui.checkbox(&mut self.filter.unvoted_only, "Not voted on yet");
+        ui.checkbox(&mut self.filter.watched_only, "Watched only");
+        ui.checkbox(&mut self.filter.hide_junk, "Hide likely junk");
+        let mut ending_soon = self.filter.ending_within_hours.is_some();
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut ending_soon, "Ending within");
+            let mut hours = self.filter.ending_within_hours.unwrap_or(24);
+            ui.add_enabled(
+                ending_soon,
+                egui::DragValue::new(&mut hours)
+                    .range(1..=24 * 14)
+                    .suffix(" h"),
+            );
+            self.filter.ending_within_hours = ending_soon.then_some(hours);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Name contains:");
+            ui.text_edit_singleline(&mut self.filter.name_contains);
+        });
+        if ui
+            .add_enabled(self.filter.is_active(), egui::Button::new("Clear filter"))
+            .clicked()
+        {
+            self.filter = ContestFilter::default();
+        }
+        if self.filter != before {
+            self.active_view = None;
+        }
+    }
+
+    /// Menu to apply, save and remove views and to choose the one applied on opening
+    fn show_views_menu(&mut self, ui: &mut Ui) {
+        let views = self.app_context.preferences().contest_views;
+        if views.views.is_empty() {
+            ui.label(RichText::new("No saved views").color(egui::Color32::GRAY));
+        }
+        let mut to_apply = None;
+        let mut to_remove = None;
+        let mut new_default = None;
+        for view in &views.views {
+            ui.horizontal(|ui| {
+                let active = self.active_view.as_ref() == Some(&view.name);
+                if ui.selectable_label(active, &view.name).clicked() {
+                    to_apply = Some(view.clone());
+                    ui.close_menu();
+                }
+                let is_default = views.default_view.as_ref() == Some(&view.name);
+                if ui
+                    .selectable_label(is_default, "Default")
+                    .on_hover_text("Apply this view when the screen opens")
+                    .clicked()
+                {
+                    new_default = Some((!is_default).then(|| view.name.clone()));
+                }
+                if ui.small_button("🗑").on_hover_text("Remove view").clicked() {
+                    to_remove = Some(view.name.clone());
+                }
+            });
+        }
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.view_name_input)
+                    .hint_text("Ending soon un-voted")
+                    .desired_width(160.0),
+            );
+            let name = self.view_name_input.trim().to_string();
+            if ui
+                .add_enabled(!name.is_empty(), egui::Button::new("Save Current"))
+                .on_hover_text("Save the filter, sort and columns, replacing a view of that name")
+                .clicked()
+            {
+                let view = self.current_view(name.clone());
+                if let Err(e) = self
+                    .app_context
+                    .update_preferences(|preferences| preferences.contest_views.save(view))
+                {
+                    error!("Failed to save table view: {:?}", e);
+                }
+                self.active_view = Some(name);
+                self.view_name_input.clear();
+            }
+        });
+
+        if let Some(view) = to_apply {
+            self.apply_view(&view);
+        }
+        if to_remove.is_some() || new_default.is_some() {
+            if let Err(e) = self.app_context.update_preferences(|preferences| {
+                if let Some(name) = &to_remove {
+                    preferences.contest_views.remove(name);
+                }
+                if let Some(default_view) = new_default {
+                    preferences.contest_views.default_view = default_view;
+                }
+            }) {
+                error!("Failed to save table views: {:?}", e);
+            }
+        }
+    }
+
+    /// Header context menu listing all columns with a visibility checkbox
+    fn show_column_chooser(&mut self, ui: &mut Ui) {
+        ui.label(RichText::new("Columns").strong());
+        for column in ContestColumn::ALL {
+            let mut visible = self.is_column_visible(column);
+            let enabled = column != ContestColumn::ContestedName;
+            if ui
+                .add_enabled(enabled, egui::Checkbox::new(&mut visible, column.title()))
+                .changed()
+            {
+                self.set_column_visible(column, visible);
+            }
+        }
+    }
+
+    /// Header context menu entries to override the global time display for one column
+    fn show_time_display_chooser(&mut self, ui: &mut Ui, column: ContestColumn) {
+        ui.label(RichText::new("Time display").strong());
+        let current = self
+            .app_context
+            .preferences()
+            .column_time_display
+            .get(&column.preferences_key())
+            .copied();
+        if ui.radio(current.is_none(), "Use global setting").clicked() {
+            self.set_column_time_display(column, None);
+            ui.close_menu();
+        }
+        for mode in TimeDisplayMode::ALL {
+            if ui.radio(current == Some(mode), mode.label()).clicked() {
+                self.set_column_time_display(column, Some(mode));
+                ui.close_menu();
+            }
+        }
+    }
+
+    fn render_header_cell(&mut self, ui: &mut Ui, column: ContestColumn) {
+        let response = if let Some(sort_column) = column.sort_column() {
+            let response = with_accessible_label(
+                ui.button(column.title()),
+                WidgetType::Button,
+                format!("Sort by {}", column.title()),
+            );
+            if response.clicked() {
+                self.toggle_sort(sort_column);
+            }
+            response
+        } else {
+            ui.add(
+                egui::Label::new(RichText::new(column.title()).heading())
+                    .sense(egui::Sense::click()),
+            )
+        };
+        let hint = "Right-click to choose columns";
+        let response = match column.term() {
+            Some(term) => term_tooltip(response, term, Some(hint)),
+            None => response.on_hover_text(hint),
+        };
+        response.context_menu(|ui| {
+            self.show_column_chooser(ui);
+            if column.is_time_column() {
+                ui.separator();
+                self.show_time_display_chooser(ui, column);
+            }
+        });
+    }
+
+    fn render_cell(
+        &mut self,
+        ui: &mut Ui,
+        column: ContestColumn,
+        contested_name: &ContestedName,
+        is_locked_votes_bold: bool,
+        max_contestant_votes: u32,
+    ) {
+        match column {
+            ContestColumn::ContestedName => {
+                if self
+                    .watched_contests
+                    .contains(&contested_name.normalized_contested_name)
+                {
+                    with_accessible_label(
+                        ui.label("★").on_hover_text("Watched"),
+                        WidgetType::Label,
+                        "Watched",
+                    );
+                }
+                let menu = ui.menu_button("⋯", |ui| self.show_row_actions(ui, contested_name));
+                with_accessible_label(
+                    menu.response.on_hover_text("Actions"),
+                    WidgetType::Button,
+                    format!("Actions for {}", contested_name.normalized_contested_name),
+                );
+                let response = ui
+                    .link(&contested_name.normalized_contested_name)
+                    .on_hover_text("Show contest details (right-click for more)");
+                if response.clicked() {
+                    self.contest_detail = Some(contested_name.normalized_contested_name.clone());
+                }
+                self.show_row_context_menu(&response, contested_name);
+                let reasons = self
+                    .junk_name_rules
+                    .classify(&contested_name.normalized_contested_name);
+                if !reasons.is_empty() {
+                    let reasons: Vec<String> =
+                        reasons.iter().map(|reason| reason.to_string()).collect();
+                    ui.label(
+                        RichText::new("⚑ likely junk")
+                            .small()
+                            .color(egui::Color32::from_rgb(200, 120, 0)),
+                    )
+                    .on_hover_text(format!("Flagged: {}", reasons.join(", ")));
+                }
+                if !Self::accepts_votes(contested_name) {
+                    ui.label(
+                        RichText::new("⏳ awaiting result")
+                            .small()
+                            .color(egui::Color32::GRAY),
+                    )
+                    .on_hover_text(AWAITING_RESULT);
+                }
+                let notes = self.annotation_lines(contested_name);
+                if !notes.is_empty() {
+                    ui.label(RichText::new("✎ notes").small())
+                        .on_hover_text(notes.join("\n"));
+                }
+            }
+            ContestColumn::LockedVotes => {
+                let label_text = if let Some(locked_votes) = contested_name.locked_votes {
+                    let label_text = self.number_format.format_integer(locked_votes as u64);
+                    if is_locked_votes_bold {
+                        egui::RichText::new(label_text).strong()
+                    } else {
+                        egui::RichText::new(label_text)
+                    }
+                } else {
+                    egui::RichText::new("Fetching".to_string())
+                };
+                // Vote button logic for locked votes
+                let response = with_accessible_label(
+                    ui.add_enabled(
+                        Self::accepts_votes(contested_name),
+                        egui::Button::new(label_text),
+                    )
+                    .on_disabled_hover_text(AWAITING_RESULT),
+                    WidgetType::Button,
+                    format!(
+                        "Vote Lock on {}, {} locked votes",
+                        contested_name.normalized_contested_name,
+                        contested_name
+                            .locked_votes
+                            .map_or("unknown".to_string(), |votes| votes.to_string())
+                    ),
+                );
+                if response.clicked() {
+                    self.open_lock_vote_popup(&contested_name.normalized_contested_name);
+                }
+            }
+            ContestColumn::AbstainVotes => {
+                let label_text = if let Some(abstain_votes) = contested_name.abstain_votes {
+                    self.number_format.format_integer(abstain_votes as u64)
+                } else {
+                    "Fetching".to_string()
+                };
+                let response = with_accessible_label(
+                    ui.add_enabled(
+                        Self::accepts_votes(contested_name),
+                        egui::Button::new(label_text),
+                    )
+                    .on_disabled_hover_text(AWAITING_RESULT),
+                    WidgetType::Button,
+                    format!(
+                        "Vote Abstain on {}, {} abstain votes",
+                        contested_name.normalized_contested_name,
+                        contested_name
+                            .abstain_votes
+                            .map_or("unknown".to_string(), |votes| votes.to_string())
+                    ),
+                );
+                if response.clicked() {
+                    self.open_abstain_vote_popup(&contested_name.normalized_contested_name);
+                }
+            }
+            ContestColumn::EndingTime => {
+                let text = contested_name
+                    .end_time
+                    .map_or("Fetching".to_string(), |end_time| {
+                        self.ending_time_format.format_millis(end_time)
+                    });
+                let response = ui.add(egui::Label::new(text).sense(egui::Sense::click()));
+                self.show_row_context_menu(&response, contested_name);
+            }
+            ContestColumn::LastUpdated => {
+                let text = contested_name
+                    .last_updated
+                    .map_or("Fetching".to_string(), |last_updated| {
+                        self.last_updated_format.format_seconds(last_updated)
+                    });
+                let response = ui.add(egui::Label::new(text).sense(egui::Sense::click()));
+                self.show_row_context_menu(&response, contested_name);
+            }
+            ContestColumn::MyVote => {
+                let response = ui.add(
+                    egui::Label::new(format_my_votes(contested_name)).sense(egui::Sense::click()),
+                );
+                self.show_row_context_menu(&response, contested_name);
+            }
+            ContestColumn::ContestantCount => {
+                let text = contested_name
+                    .contestants
+                    .as_ref()
+                    .map_or("Fetching".to_string(), |contestants| {
+                        contestants.len().to_string()
+                    });
+                let response = ui.add(egui::Label::new(text).sense(egui::Sense::click()));
+                self.show_row_context_menu(&response, contested_name);
+            }
+            ContestColumn::TimeRemaining => {
+                let text = match contested_name.end_time {
+                    Some(end_time) => {
+                        let text = RichText::new(
+                            format_time_remaining(end_time)
+                                .unwrap_or_else(|| "Awaiting result".to_string()),
+                        );
+                        match time_remaining_color(end_time) {
+                            Some(color) => text.color(color),
+                            None => text,
+                        }
+                    }
+                    None => RichText::new("Fetching"),
+                };
+                let response = ui.add(egui::Label::new(text).sense(egui::Sense::click()));
+                self.show_row_context_menu(&response, contested_name);
+            }
+            ContestColumn::Suggested => {
+                let suggestions: Vec<(&str, String)> = self
+                    .vote_lists
+                    .iter()
+                    .filter_map(|list| {
+                        let vote = list
+                            .contents
+                            .votes
+                            .get(&contested_name.normalized_contested_name)?;
+                        Some((
+                            list.contents.title.as_str(),
+                            format_vote_choice(contested_name, &vote.vote_choice()),
+                        ))
+                    })
+                    .collect();
+                if suggestions.is_empty() {
+                    ui.label("-");
+                    return;
+                }
+                let mut choices: Vec<&str> =
+                    suggestions.iter().map(|(_, vote)| vote.as_str()).collect();
+                choices.sort();
+                choices.dedup();
+                let mut details: Vec<String> = suggestions
+                    .iter()
+                    .map(|(title, vote)| format!("{}: {}", title, vote))
+                    .collect();
+                for list in &self.vote_lists {
+                    for annotation in &list.contents.annotations {
+                        if annotation.contest == contested_name.normalized_contested_name {
+                            details.push(format!(
+                                "{} notes: {}",
+                                list.contents.title,
+                                annotation.summary()
+                            ));
+                        }
+                    }
+                }
+                let response = ui
+                    .add(egui::Label::new(choices.join(", ")).sense(egui::Sense::click()))
+                    .on_hover_text(details.join("\n"));
+                self.show_row_context_menu(&response, contested_name);
+            }
+            ContestColumn::Contestants => {
+                self.show_contested_name_details(
+                    ui,
+                    contested_name,
+                    is_locked_votes_bold,
+                    max_contestant_votes,
+                    false,
+                );
+            }
+        }
+    }
+
+    /// Writes the end times of the selected contests, or of all when none are selected, to an
+    /// .ics file
+    fn export_calendar(&mut self) {
+        let contests: Vec<ContestedName> = self
+            .contested_names
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|contested_name| {
+                self.selected_contests.is_empty()
+                    || self
+                        .selected_contests
+                        .contains(&contested_name.normalized_contested_name)
+            })
+            .cloned()
+            .collect();
+        let ics = contests_to_ics(
+            &contests,
+            &self.annotations,
+            &self.app_context.network_string(),
+        );
+        let path = self.calendar_path_input.trim().to_string();
+        match std::fs::write(&path, ics) {
+            Ok(()) => self.display_message(
+                &format!("Exported {} contest deadlines to {}", contests.len(), path),
+                MessageType::Info,
+            ),
+            Err(e) => self.display_message(
+                &format!("Failed to write {}: {}", path, e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    fn render_calendar_export(&mut self, ui: &mut Ui) {
+        egui::CollapsingHeader::new("Calendar Export")
+            .id_salt("contest_calendar_export")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("File:");
+                    ui.text_edit_singleline(&mut self.calendar_path_input);
+                    let label = if self.selected_contests.is_empty() {
+                        "Export All Deadlines".to_string()
+                    } else {
+                        format!("Export {} Selected Deadlines", self.selected_contests.len())
+                    };
+                    if ui.button(label).clicked() {
+                        self.export_calendar();
+                    }
+                });
+                if let MetricsExport::Endpoint { port } =
+                    self.app_context.preferences().metrics_export
+                {
+                    let url = format!(
+                        "http://127.0.0.1:{}{}",
+                        port,
+                        contest_calendar_path(&self.app_context.network_string())
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label("Subscribe URL:");
+                        ui.label(RichText::new(&url).monospace());
+                        if ui.button("Copy").clicked() {
+                            ui.ctx().copy_text(url.clone());
+                        }
+                    });
+                } else {
+                    ui.label(
+                        RichText::new(
+                            "Enable the metrics HTTP endpoint in the settings to subscribe to \
+                             the deadlines from a calendar app.",
+                        )
+                        .color(egui::Color32::GRAY),
+                    );
+                }
+            });
+    }
+
+    fn start_triage(&mut self) {
+        let triage = VoteTriage::new(&self.contested_names.lock().unwrap());
+        if triage.is_empty() {
+            self.error_message = Some((
+                "There are no open contests left to vote on".to_string(),
+                MessageType::Info,
+                Utc::now(),
+            ));
+        } else {
+            self.triage = Some(triage);
         }
     }
 
-    fn toggle_sort(&mut self, column: SortColumn) {
-        if self.sort_column == column {
-            self.sort_order = match self.sort_order {
-                SortOrder::Ascending => SortOrder::Descending,
-                SortOrder::Descending => SortOrder::Ascending,
-            };
-        } else {
-            self.sort_column = column;
-            self.sort_order = SortOrder::Ascending;
+    /// Shows the triage and asks for the voters once its votes are submitted
+    fn render_triage(&mut self, ui: &mut Ui) {
+        let Some(triage) = self.triage.as_mut() else {
+            return;
+        };
+        match triage.show(ui, &self.number_format) {
+            TriageOutcome::Continue => {}
+            TriageOutcome::Close => self.triage = None,
+            TriageOutcome::Submit(votes) => {
+                let summary: Vec<String> = votes
+                    .iter()
+                    .map(|(name, vote_choice)| {
+                        let contested_name = self
+                            .contested_names
+                            .lock()
+                            .unwrap()
+                            .iter()
+                            .find(|contested_name| {
+                                &contested_name.normalized_contested_name == name
+                            })
+                            .cloned();
+                        let choice = contested_name
+                            .map(|contested_name| format_vote_choice(&contested_name, vote_choice))
+                            .unwrap_or_default();
+                        format!("{}: {}", name, choice)
+                    })
+                    .collect();
+                self.show_vote_popup_info = Some((
+                    format!(
+                        "Confirm {} votes:\n{}\n\nSelect the identity to vote with:",
+                        votes.len(),
+                        summary.join("\n")
+                    ),
+                    ContestedResourceTask::VoteOnMultipleDPNSNames(votes, vec![]),
+                ));
+                self.triage = None;
+            }
         }
     }
 
-    fn render_no_active_contests(&mut self, ui: &mut Ui) {
-        ui.vertical_centered(|ui| {
-            ui.add_space(20.0); // Add some space to separate from the top
-            ui.label(
-                egui::RichText::new("No active contests at the moment.")
-                    .heading()
-                    .strong()
-                    .color(egui::Color32::GRAY),
-            );
-            ui.add_space(10.0);
-            ui.label("Please check back later or try refreshing the list.");
-            ui.add_space(20.0);
-            if ui.button("Refresh").clicked() {
-                self.refresh(); // Call refresh logic when the user clicks "Refresh"
+    /// Bulk vote buttons for the contests selected in the table, only shown with a selection
+    fn render_bulk_action_bar(&mut self, ui: &mut Ui) {
+        if self.selected_contests.is_empty() {
+            return;
+        }
+        ui.horizontal(|ui| {
+            ui.label(format!("{} selected", self.selected_contests.len()));
+            for (label, vote_choice) in [
+                ("Abstain on selected", ResourceVoteChoice::Abstain),
+                ("Lock selected", ResourceVoteChoice::Lock),
+            ] {
+                if ui.button(label).clicked() {
+                    self.open_bulk_vote_popup(vote_choice);
+                }
+            }
+            if ui.button("Clear selection").clicked() {
+                self.selected_contests.clear();
             }
         });
+        ui.add_space(5.0);
     }
 
     fn render_table(&mut self, ui: &mut Ui) {
@@ -179,10 +1615,34 @@ impl DPNSContestedNamesScreen {
         let contested_names = {
             let contested_names_guard = self.contested_names.lock().unwrap();
             let mut contested_names = contested_names_guard.clone();
+            if self.filter.is_active() {
+                let now = network_now().timestamp_millis() as u64;
+                contested_names.retain(|contested_name| {
+                    let name = &contested_name.normalized_contested_name;
+                    self.filter.matches(
+                        contested_name,
+                        self.watched_contests.contains(name),
+                        !self.junk_name_rules.classify(name).is_empty(),
+                        now,
+                    )
+                });
+                if contested_names.len() < contested_names_guard.len() {
+                    ui.label(
+                        RichText::new(format!(
+                            "Showing {} of {} contests",
+                            contested_names.len(),
+                            contested_names_guard.len()
+                        ))
+                        .color(egui::Color32::GRAY),
+                    );
+                }
+            }
             self.sort_contested_names(&mut contested_names);
             contested_names
         };
 
+        let visible_columns = self.visible_columns.clone();
+
         egui::ScrollArea::vertical().show(ui, |ui| {
             Frame::group(ui.style())
                 .fill(ui.visuals().panel_fill)
@@ -192,49 +1652,53 @@ impl DPNSContestedNamesScreen {
                 ))
                 .inner_margin(Margin::same(8.0))
                 .show(ui, |ui| {
-                    TableBuilder::new(ui)
+                    let density = self.app_context.preferences().table_density;
+                    let (header_height, row_height) = table_heights(ui.ctx(), density, 25.0);
+                    apply_table_density(ui, density);
+                    let mut table = TableBuilder::new(ui)
                         .striped(true)
                         .resizable(true)
                         .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-                        .column(Column::initial(200.0).resizable(true)) // Contested Name
-                        .column(Column::initial(100.0).resizable(true)) // Locked Votes
-                        .column(Column::initial(100.0).resizable(true)) // Abstain Votes
-                        .column(Column::initial(200.0).resizable(true)) // Ending Time
-                        .column(Column::initial(200.0).resizable(true)) // Last Updated
-                        .column(Column::remainder()) // Contestants
-                        .header(30.0, |mut header| {
-                            header.col(|ui| {
-                                if ui.button("Contested Name").clicked() {
-                                    self.toggle_sort(SortColumn::ContestedName);
-                                }
-                            });
-                            header.col(|ui| {
-                                if ui.button("Locked Votes").clicked() {
-                                    self.toggle_sort(SortColumn::LockedVotes);
-                                }
-                            });
-                            header.col(|ui| {
-                                if ui.button("Abstain Votes").clicked() {
-                                    self.toggle_sort(SortColumn::AbstainVotes);
-                                }
-                            });
-                            header.col(|ui| {
-                                if ui.button("Ending Time").clicked() {
-                                    self.toggle_sort(SortColumn::EndingTime);
-                                }
-                            });
+                        .column(Column::exact(24.0)); // Selection
+                    for (i, column) in visible_columns.iter().enumerate() {
+                        table = if i + 1 == visible_columns.len() {
+                            table.column(Column::remainder())
+                        } else {
+                            table.column(Column::initial(column.initial_width()).resizable(true))
+                        };
+                    }
+                    table
+                        .header(header_height, |mut header| {
                             header.col(|ui| {
-                                if ui.button("Last Updated").clicked() {
-                                    self.toggle_sort(SortColumn::LastUpdated);
+                                let all_selected = !contested_names.is_empty()
+                                    && contested_names.iter().all(|contested_name| {
+                                        self.selected_contests
+                                            .contains(&contested_name.normalized_contested_name)
+                                    });
+                                let mut select_all = all_selected;
+                                if unlabeled_checkbox(ui, &mut select_all, "Select all contests")
+                                    .changed()
+                                {
+                                    if select_all {
+                                        self.selected_contests.extend(contested_names.iter().map(
+                                            |contested_name| {
+                                                contested_name.normalized_contested_name.clone()
+                                            },
+                                        ));
+                                    } else {
+                                        self.selected_contests.clear();
+                                    }
                                 }
                             });
-                            header.col(|ui| {
-                                ui.heading("Contestants");
-                            });
+                            for column in &visible_columns {
+                                header.col(|ui| {
+                                    self.render_header_cell(ui, *column);
+                                });
+                            }
                         })
                         .body(|mut body| {
                             for contested_name in &contested_names {
-                                body.row(25.0, |mut row| {
+                                body.row(row_height, |mut row| {
                                     let locked_votes = contested_name.locked_votes.unwrap_or(0);
 
                                     // Find the highest contestant votes, if any
@@ -242,107 +1706,41 @@ impl DPNSContestedNamesScreen {
                                         .contestants
                                         .as_ref()
                                         .map(|contestants| {
-                                            contestants
-                                                .iter()
-                                                .map(|c| c.votes)
-                                                .max()
-                                                .unwrap_or(0)
+                                            contestants.iter().map(|c| c.votes).max().unwrap_or(0)
                                         })
                                         .unwrap_or(0);
 
                                     // Determine if locked votes have strict priority
-                                    let is_locked_votes_bold =
-                                        locked_votes > max_contestant_votes;
+                                    let is_locked_votes_bold = locked_votes > max_contestant_votes;
 
                                     row.col(|ui| {
-                                        ui.label(&contested_name.normalized_contested_name);
-                                    });
-                                    row.col(|ui| {
-                                        let label_text = if let Some(locked_votes) =
-                                            contested_name.locked_votes
-                                        {
-                                            let label_text = format!("{}", locked_votes);
-                                            if is_locked_votes_bold {
-                                                egui::RichText::new(label_text).strong()
-                                            } else {
-                                                egui::RichText::new(label_text)
-                                            }
-                                        } else {
-                                            egui::RichText::new("Fetching".to_string())
-                                        };
-                                        // Vote button logic for locked votes
-                                        if ui.button(label_text).clicked() {
-                                            self.show_vote_popup_info = Some((format!("Confirm Voting to Lock the name \"{}\".\n\nSelect the identity to vote with:", contested_name.normalized_contested_name.clone()), ContestedResourceTask::VoteOnDPNSName(contested_name.normalized_contested_name.clone(), ResourceVoteChoice::Lock, vec![])));
-                                        }
-                                    });
-                                    row.col(|ui| {
-                                        let label_text = if let Some(abstain_votes) =
-                                            contested_name.abstain_votes
-                                        {
-                                            format!("{}", abstain_votes)
-                                        } else {
-                                            "Fetching".to_string()
-                                        };
-                                        if ui.button(label_text).clicked() {
-                                            self.show_vote_popup_info = Some((format!("Confirm Voting to Abstain on distribution of \"{}\".\n\nSelect the identity to vote with:", contested_name.normalized_contested_name.clone()), ContestedResourceTask::VoteOnDPNSName(contested_name.normalized_contested_name.clone(), ResourceVoteChoice::Abstain, vec![])));
-                                        }
-                                    });
-                                    row.col(|ui| {
-                                        if let Some(ending_time) = contested_name.end_time {
-                                            // Convert the timestamp to a DateTime object using timestamp_millis_opt
-                                            if let LocalResult::Single(datetime) =
-                                                Utc.timestamp_millis_opt(ending_time as i64)
-                                            {
-                                                // Format the ISO date up to seconds
-                                                let iso_date = datetime
-                                                    .format("%Y-%m-%d %H:%M:%S")
-                                                    .to_string();
-
-                                                // Use chrono-humanize to get the relative time
-                                                let relative_time =
-                                                    HumanTime::from(datetime).to_string();
-
-                                                // Combine both the ISO date and relative time
-                                                let display_text =
-                                                    format!("{} ({})", iso_date, relative_time);
-
-                                                ui.label(display_text);
-                                            } else {
-                                                // Handle case where the timestamp is invalid
-                                                ui.label("Invalid timestamp");
-                                            }
-                                        } else {
-                                            ui.label("Fetching");
-                                        }
-                                    });
-                                    row.col(|ui| {
-                                        if let Some(last_updated) = contested_name.last_updated
+                                        let name = &contested_name.normalized_contested_name;
+                                        let mut selected = self.selected_contests.contains(name);
+                                        if unlabeled_checkbox(
+                                            ui,
+                                            &mut selected,
+                                            &format!("Select {}", name),
+                                        )
+                                        .changed()
                                         {
-                                            // Convert the timestamp to a DateTime object using timestamp_millis_opt
-                                            if let LocalResult::Single(datetime) =
-                                                Utc.timestamp_opt(last_updated as i64, 0)
-                                            {
-                                                // Use chrono-humanize to get the relative time
-                                                let relative_time =
-                                                    HumanTime::from(datetime).to_string();
-
-                                                ui.label(relative_time);
+                                            if selected {
+                                                self.selected_contests.insert(name.clone());
                                             } else {
-                                                // Handle case where the timestamp is invalid
-                                                ui.label("Invalid timestamp");
+                                                self.selected_contests.remove(name);
                                             }
-                                        } else {
-                                            ui.label("Fetching");
                                         }
                                     });
-                                    row.col(|ui| {
-                                        self.show_contested_name_details(
-                                            ui,
-                                            contested_name,
-                                            is_locked_votes_bold,
-                                            max_contestant_votes,
-                                        );
-                                    });
+                                    for column in &visible_columns {
+                                        row.col(|ui| {
+                                            self.render_cell(
+                                                ui,
+                                                *column,
+                                                contested_name,
+                                                is_locked_votes_bold,
+                                                max_contestant_votes,
+                                            );
+                                        });
+                                    }
                                 });
                             }
                         });
@@ -350,11 +1748,458 @@ impl DPNSContestedNamesScreen {
         });
     }
 
-    fn show_vote_popup(&mut self, ui: &mut Ui) -> AppAction {
+    fn show_contest_detail(&mut self, ui: &mut Ui, contested_name: &ContestedName) -> AppAction {
+        let mut action = AppAction::None;
+        let locked_votes = contested_name.locked_votes.unwrap_or(0);
+        let max_contestant_votes = contested_name
+            .contestants
+            .as_ref()
+            .and_then(|contestants| contestants.iter().map(|c| c.votes).max())
+            .unwrap_or(0);
+        let is_locked_votes_bold = locked_votes > max_contestant_votes;
+
+        ui.horizontal(|ui| {
+            term_tooltip(
+                ui.heading(&contested_name.normalized_contested_name),
+                &TERM_CONTESTED_RESOURCE,
+                None,
+            );
+            let share = ui.menu_button("🔗 Share", |ui| {
+                self.render_share_link_buttons(ui, &contested_name.normalized_contested_name);
+            });
+            with_accessible_label(share.response, WidgetType::Button, "Share contest link");
+        });
+
+        egui::Grid::new("contest_detail_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("State:").strong());
+                if Self::accepts_votes(contested_name) {
+                    ui.label(format!("{:?}", contested_name.state));
+                } else {
+                    ui.label("Ended, awaiting result");
+                }
+                ui.end_row();
+
+                term_tooltip(
+                    ui.label(RichText::new("Locked Votes:").strong()),
+                    &TERM_LOCKED_VOTES,
+                    None,
+                );
+                ui.label(
+                    contested_name
+                        .locked_votes
+                        .map_or("Fetching".to_string(), |votes| {
+                            self.number_format.format_integer(votes as u64)
+                        }),
+                );
+                ui.end_row();
+
+                term_tooltip(
+                    ui.label(RichText::new("Abstain Votes:").strong()),
+                    &TERM_ABSTAIN,
+                    None,
+                );
+                ui.label(
+                    contested_name
+                        .abstain_votes
+                        .map_or("Fetching".to_string(), |votes| {
+                            self.number_format.format_integer(votes as u64)
+                        }),
+                );
+                ui.end_row();
+
+                ui.label(RichText::new("Ending Time:").strong());
+                ui.label(
+                    contested_name
+                        .end_time
+                        .map_or("Fetching".to_string(), |end_time| {
+                            self.ending_time_format.format_millis(end_time)
+                        }),
+                );
+                ui.end_row();
+            });
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Contestants (click to vote):").strong());
+            let identity_ids: Vec<Identifier> = contested_name
+                .contestants
+                .iter()
+                .flatten()
+                .map(|contestant| contestant.id)
+                .collect();
+            let task = BackendTask::ContestedResourceTask(
+                ContestedResourceTask::FetchContestantReputations(identity_ids),
+            );
+            let fetching = self.app_context.task_gate.is_running(&task);
+            if ui
+                .add_enabled(!fetching, egui::Button::new("Check Reputation"))
+                .on_hover_text(
+                    "Fetch the names and Dashpay profile of each contestant. The signals are \
+                     hints, not proof of who deserves the name.",
+                )
+                .clicked()
+            {
+                action = AppAction::BackendTask(task);
+            }
+            if fetching {
+                ui.spinner();
+            }
+        });
+        ui.vertical(|ui| {
+            self.show_contested_name_details(
+                ui,
+                contested_name,
+                is_locked_votes_bold,
+                max_contestant_votes,
+                true,
+            );
+        });
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            if !Self::accepts_votes(contested_name) {
+                ui.label(RichText::new(AWAITING_RESULT).color(egui::Color32::GRAY));
+                return;
+            }
+            if term_tooltip(ui.button("Vote Lock"), &TERM_LOCK, None).clicked() {
+                self.open_lock_vote_popup(&contested_name.normalized_contested_name);
+            }
+            if term_tooltip(ui.button("Vote Abstain"), &TERM_ABSTAIN, None).clicked() {
+                self.open_abstain_vote_popup(&contested_name.normalized_contested_name);
+            }
+        });
+
+        ui.separator();
+        egui::CollapsingHeader::new("Notes")
+            .id_salt("contest_notes")
+            .default_open(
+                self.annotations.iter().any(|annotation| {
+                    annotation.contest == contested_name.normalized_contested_name
+                }),
+            )
+            .show(ui, |ui| self.show_annotations(ui, contested_name));
+
+        ui.separator();
+        egui::CollapsingHeader::new("Contestant Documents")
+            .id_salt("contestant_documents")
+            .show(ui, |ui| {
+                action = self.show_contestant_previews(ui, contested_name);
+            });
+        action
+    }
+
+    /// The notes on a contest for hover texts, each contestant note named after its contestant
+    fn annotation_lines(&self, contested_name: &ContestedName) -> Vec<String> {
+        self.annotations
+            .iter()
+            .filter(|annotation| annotation.contest == contested_name.normalized_contested_name)
+            .map(|annotation| match &annotation.contestant {
+                Some(contestant_id) => format!(
+                    "{}: {}",
+                    self.contestant_label(contested_name, contestant_id),
+                    annotation.summary()
+                ),
+                None => annotation.summary(),
+            })
+            .collect()
+    }
+
+    /// The name of a contestant given by its Base58 id, or the id when it isn't one anymore
+    fn contestant_label(&self, contested_name: &ContestedName, contestant_id: &str) -> String {
+        contested_name
+            .contestants
+            .iter()
+            .flatten()
+            .find(|contestant| contestant.id.to_string(Encoding::Base58) == contestant_id)
+            .map(|contestant| contestant.name.clone())
+            .unwrap_or_else(|| contestant_id.to_string())
+    }
+
+    /// Lists the notes on a contest and edits them
+    fn show_annotations(&mut self, ui: &mut Ui, contested_name: &ContestedName) {
+        let name = &contested_name.normalized_contested_name;
+        let mut to_edit = None;
+        egui::Grid::new("contest_notes_grid")
+            .num_columns(3)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                for annotation in self
+                    .annotations
+                    .iter()
+                    .filter(|annotation| &annotation.contest == name)
+                {
+                    ui.label(match &annotation.contestant {
+                        Some(contestant_id) => self.contestant_label(contested_name, contestant_id),
+                        None => "Contest".to_string(),
+                    });
+                    ui.label(annotation.summary());
+                    if ui.button("Edit").clicked() {
+                        to_edit = Some(annotation.contestant.clone());
+                    }
+                    ui.end_row();
+                }
+            });
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Add a note on:");
+            if ui.button("The contest").clicked() {
+                to_edit = Some(None);
+            }
+            for contestant in contested_name.contestants.iter().flatten() {
+                if ui.button(&contestant.name).clicked() {
+                    to_edit = Some(Some(contestant.id.to_string(Encoding::Base58)));
+                }
+            }
+        });
+        if let Some(contestant) = to_edit {
+            let existing = self.annotations.iter().find(|annotation| {
+                &annotation.contest == name && annotation.contestant == contestant
+            });
+            self.annotation_draft = Some(AnnotationDraft {
+                contest: name.clone(),
+                tags_input: existing
+                    .map(|annotation| annotation.tags.join(", "))
+                    .unwrap_or_default(),
+                note_input: existing
+                    .map(|annotation| annotation.note.clone())
+                    .unwrap_or_default(),
+                contestant,
+            });
+        }
+
+        let Some(draft) = self
+            .annotation_draft
+            .as_mut()
+            .filter(|draft| &draft.contest == name)
+        else {
+            return;
+        };
+        ui.add_space(5.0);
+        ui.label(
+            RichText::new(match &draft.contestant {
+                Some(contestant_id) => format!(
+                    "Note on {}",
+                    contested_name
+                        .contestants
+                        .iter()
+                        .flatten()
+                        .find(|contestant| contestant.id.to_string(Encoding::Base58)
+                            == *contestant_id)
+                        .map_or(contestant_id.as_str(), |contestant| contestant
+                            .name
+                            .as_str())
+                ),
+                None => "Note on the contest".to_string(),
+            })
+            .strong(),
+        );
+        ui.horizontal(|ui| {
+            ui.label("Tags:");
+            ui.add(
+                egui::TextEdit::singleline(&mut draft.tags_input).hint_text("impersonation, brand"),
+            );
+        });
+        ui.add(
+            egui::TextEdit::multiline(&mut draft.note_input)
+                .desired_rows(3)
+                .desired_width(f32::INFINITY)
+                .hint_text("What you found and a link to the evidence"),
+        );
+        let mut save = false;
+        let mut cancel = false;
+        ui.horizontal(|ui| {
+            save = ui
+                .button("Save Note")
+                .on_hover_text("Saving an empty note removes it")
+                .clicked();
+            cancel = ui.button("Cancel").clicked();
+        });
+        if save {
+            let annotation = ContestAnnotation {
+                contest: draft.contest.clone(),
+                contestant: draft.contestant.clone(),
+                tags: ContestAnnotation::parse_tags(&draft.tags_input),
+                note: draft.note_input.trim().to_string(),
+            };
+            match self
+                .app_context
+                .db
+                .save_contest_annotation(&annotation, &self.app_context)
+            {
+                Ok(()) => {
+                    self.annotation_draft = None;
+                    self.annotations = self
+                        .app_context
+                        .db
+                        .get_contest_annotations(&self.app_context)
+                        .unwrap_or_default();
+                }
+                Err(e) => self.display_message(
+                    &format!("Failed to save the note: {}", e),
+                    MessageType::Error,
+                ),
+            }
+        } else if cancel {
+            self.annotation_draft = None;
+        }
+    }
+
+    /// The DPNS documents and identity of each contestant, fetched on request
+    fn show_contestant_previews(
+        &mut self,
+        ui: &mut Ui,
+        contested_name: &ContestedName,
+    ) -> AppAction {
+        let mut action = AppAction::None;
+        let privacy_mode = self.app_context.preferences().privacy_mode;
+        let name = &contested_name.normalized_contested_name;
+        let task = BackendTask::ContestedResourceTask(
+            ContestedResourceTask::FetchContestantPreviews(name.clone()),
+        );
+        let fetching = self.app_context.task_gate.is_running(&task);
+        ui.horizontal(|ui| {
+            let label = if self.contestant_previews.contains_key(name) {
+                "Refresh"
+            } else {
+                "Fetch from Platform"
+            };
+            if ui
+                .add_enabled(!fetching, egui::Button::new(label))
+                .clicked()
+            {
+                action = AppAction::BackendTask(task.clone());
+            }
+            if fetching {
+                ui.spinner();
+            }
+        });
+        let Some(previews) = self.contestant_previews.get(name) else {
+            ui.label(
+                RichText::new(
+                    "Fetch the domain and preorder documents of each contestant and the \
+                     identity that registered them.",
+                )
+                .color(egui::Color32::GRAY),
+            );
+            return action;
+        };
+        if previews.is_empty() {
+            ui.label("No contestant documents were found.");
+        }
+        for preview in previews {
+            ui.add_space(5.0);
+            ui.label(RichText::new(&preview.label).strong());
+            let time = |millis: Option<u64>| {
+                millis.map_or("Unknown".to_string(), |millis| {
+                    self.ending_time_format.format_millis(millis)
+                })
+            };
+            egui::Grid::new(("contestant_preview", preview.identity_id))
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("Identity:");
+                    ui.label(redact_id(
+                        &preview.identity_id.to_string(Encoding::Base58),
+                        privacy_mode,
+                    ));
+                    ui.end_row();
+
+                    ui.label("Balance:");
+                    ui.label(
+                        preview
+                            .balance
+                            .map_or("Identity not found".to_string(), |balance| {
+                                self.number_format.format_credits_as_dash(balance)
+                            }),
+                    );
+                    ui.end_row();
+
+                    ui.label("Keys:");
+                    ui.label(preview.public_key_count.to_string());
+                    ui.end_row();
+
+                    ui.label("Active Since:");
+                    ui.label(time(preview.active_since)).on_hover_text(
+                        "The earliest DPNS document of the identity. Platform doesn't record \
+                         when identities are created, so it is at least this old.",
+                    );
+                    ui.end_row();
+
+                    ui.label("Other Names:");
+                    ui.label(if preview.other_names.is_empty() {
+                        "None".to_string()
+                    } else {
+                        preview.other_names.join(", ")
+                    });
+                    ui.end_row();
+
+                    ui.label("Domain Document:");
+                    ui.label(format!(
+                        "{}, created {} at block {}",
+                        preview.domain_document_id.to_string(Encoding::Base58),
+                        time(preview.domain_created_at),
+                        preview
+                            .domain_created_at_block_height
+                            .map_or("unknown".to_string(), |height| height.to_string())
+                    ));
+                    ui.end_row();
+
+                    ui.label("Resolves To:");
+                    match preview.records_identity {
+                        Some(identity_id) if identity_id == preview.identity_id => {
+                            ui.label("The contestant");
+                        }
+                        Some(identity_id) => {
+                            ui.colored_label(
+                                egui::Color32::from_rgb(200, 150, 0),
+                                format!(
+                                    "Another identity: {}",
+                                    identity_id.to_string(Encoding::Base58)
+                                ),
+                            );
+                        }
+                        None => {
+                            ui.label("No identity");
+                        }
+                    }
+                    ui.end_row();
+
+                    ui.label("Preorder:");
+                    ui.label(preview.preorder.as_ref().map_or(
+                        "Not found".to_string(),
+                        |preorder| {
+                            format!(
+                                "{}, created {} at block {}",
+                                preorder.document_id.to_string(Encoding::Base58),
+                                time(preorder.created_at),
+                                preorder
+                                    .created_at_block_height
+                                    .map_or("unknown".to_string(), |height| height.to_string())
+                            )
+                        },
+                    ));
+                    ui.end_row();
+                });
+        }
+        action
+    }
+
+    /// The voter choice for the pending vote. With `take_focus` the first button is focused,
+    /// so the vote can go on from the keyboard.
+    fn show_vote_popup(&mut self, ui: &mut Ui, take_focus: bool) -> AppAction {
         let mut app_action = AppAction::None;
         if self.voting_identities.is_empty() {
             ui.label("Please load an Evonode or Masternode first before voting");
-            if ui.button("I want to load one now").clicked() {
+            let load_button = ui.button("I want to load one now");
+            if take_focus {
+                load_button.request_focus();
+            }
+            if load_button.clicked() {
                 self.show_vote_popup_info = None;
                 let mut screen = AddExistingIdentityScreen::new(&self.app_context);
                 screen.identity_type = IdentityType::Evonode;
@@ -363,64 +2208,82 @@ impl DPNSContestedNamesScreen {
             if ui.button("Cancel").clicked() {
                 self.show_vote_popup_info = None;
             }
-        } else if let Some((message, action)) = self.show_vote_popup_info.clone() {
+        } else if let Some((message, task)) = self.show_vote_popup_info.clone() {
             ui.label(message);
 
-            ui.horizontal(|ui| {
-                // Only modify `voters` if `action` is `VoteOnDPNSName`
-                if let ContestedResourceTask::VoteOnDPNSName(
-                    contested_name,
-                    vote_choice,
-                    mut voters,
-                ) = action
-                {
-                    // Iterate over the voting identities and create a button for each one
-                    for identity in self.voting_identities.iter() {
-                        if ui.button(identity.display_short_string()).clicked() {
-                            // Add the selected identity to the `voters` field
-                            voters.push(identity.clone());
-
-                            // Create a new `VoteOnDPNSName` task with updated voters
-                            let updated_action = ContestedResourceTask::VoteOnDPNSName(
-                                contested_name.clone(),
-                                vote_choice.clone(),
-                                voters.clone(), // Updated voters
-                            );
-
-                            // Pass updated action to BackendTask
-                            app_action = AppAction::BackendTask(
-                                BackendTask::ContestedResourceTask(updated_action),
-                            );
-                            self.show_vote_popup_info = None;
-                        }
+            let mut chosen_voters: Option<Vec<QualifiedIdentity>> = None;
+            ui.horizontal_wrapped(|ui| {
+                // Iterate over the voting identities and create a button for each one
+                for (i, identity) in self.voting_identities.iter().enumerate() {
+                    let response = with_accessible_label(
+                        ui.button(identity.display_short_string()),
+                        WidgetType::Button,
+                        format!("Vote with {}", identity.display_short_string()),
+                    );
+                    if take_focus && i == 0 {
+                        response.request_focus();
                     }
+                    if response.clicked() {
+                        chosen_voters = Some(vec![identity.clone()]);
+                    }
+                }
 
-                    // Vote with all identities
-                    if ui.button("All").clicked() {
-                        for identity in self.voting_identities.iter() {
-                            voters.push(identity.clone());
-                        }
-
-                        // Create a new `VoteOnDPNSName` task with all voters
-                        let updated_action = ContestedResourceTask::VoteOnDPNSName(
-                            contested_name.clone(),
-                            vote_choice.clone(),
-                            voters.clone(), // Updated voters
-                        );
+                // Vote with every voting identity of a group
+                for group in IdentityGroup::ALL {
+                    let members: Vec<QualifiedIdentity> = self
+                        .voting_identities
+                        .iter()
+                        .filter(|identity| {
+                            self.identity_groups
+                                .get(&identity.identity.id())
+                                .copied()
+                                .unwrap_or_else(|| identity.default_group())
+                                == group
+                        })
+                        .cloned()
+                        .collect();
+                    if !members.is_empty()
+                        && ui
+                            .button(format!("{} ({})", group, members.len()))
+                            .clicked()
+                    {
+                        chosen_voters = Some(members);
+                    }
+                }
 
-                        // Pass updated action to BackendTask
-                        app_action = AppAction::BackendTask(BackendTask::ContestedResourceTask(
-                            updated_action,
-                        ));
-                        self.show_vote_popup_info = None;
+                // Vote with every member of a voting bloc
+                for bloc in self.voting_blocs.iter() {
+                    let members = self.bloc_voters(bloc);
+                    if !members.is_empty()
+                        && ui
+                            .button(format!("{} ({})", bloc.name, members.len()))
+                            .on_hover_text("Voting bloc")
+                            .clicked()
+                    {
+                        chosen_voters = Some(members);
                     }
                 }
 
+                // Vote with all identities
+                if ui.button("All").clicked() {
+                    chosen_voters = Some(self.voting_identities.to_vec());
+                }
+
                 // Add the "Cancel" button
                 if ui.button("Cancel").clicked() {
                     self.show_vote_popup_info = None;
                 }
             });
+
+            if let Some(voters) = chosen_voters {
+                if matches!(task, ContestedResourceTask::VoteOnMultipleDPNSNames(..)) {
+                    self.selected_contests.clear();
+                }
+                app_action = AppAction::BackendTask(BackendTask::ContestedResourceTask(
+                    task.with_voters(voters),
+                ));
+                self.show_vote_popup_info = None;
+            }
         }
 
         app_action
@@ -428,12 +2291,34 @@ impl DPNSContestedNamesScreen {
 }
 
 impl ScreenLike for DPNSContestedNamesScreen {
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        match backend_task_success_result {
+            BackendTaskSuccessResult::ContestantPreviews(name, previews) => {
+                self.contestant_previews.insert(name, previews);
+            }
+            BackendTaskSuccessResult::ContestantReputations(reputations) => {
+                self.contestant_reputations = reputations
+                    .into_iter()
+                    .map(|reputation| (reputation.identity_id, reputation))
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
     fn refresh(&mut self) {
+        self.ended_votes_checked = None;
         let mut contested_names = self.contested_names.lock().unwrap();
         *contested_names = self
             .app_context
             .ongoing_contested_names()
             .unwrap_or_default();
+        // Drop selected contests that are no longer ongoing
+        self.selected_contests.retain(|name| {
+            contested_names
+                .iter()
+                .any(|contested_name| &contested_name.normalized_contested_name == name)
+        });
     }
 
     fn refresh_on_arrival(&mut self) {
@@ -444,12 +2329,48 @@ impl ScreenLike for DPNSContestedNamesScreen {
             .unwrap_or_default()
             .into();
 
+        self.identity_groups = self
+            .app_context
+            .db
+            .get_identity_groups(&self.app_context)
+            .unwrap_or_default();
+
+        self.voting_blocs = self
+            .app_context
+            .db
+            .get_voting_blocs(&self.app_context)
+            .unwrap_or_default();
+
+        self.vote_lists = self
+            .app_context
+            .db
+            .get_vote_lists(&self.app_context)
+            .unwrap_or_default();
+
+        self.contestant_reputations = self
+            .app_context
+            .db
+            .get_contestant_reputations(&self.app_context)
+            .unwrap_or_default();
+
         self.user_identities = self
             .app_context
             .db
             .get_local_user_identities(&self.app_context)
             .unwrap_or_default()
             .into();
+
+        self.reload_display_formats();
+    }
+
+    fn on_event(&mut self, event: AppEvent) {
+        match event {
+            AppEvent::ContestsUpdated | AppEvent::VoteSubmitted => self.refresh(),
+            AppEvent::IdentityAdded
+            | AppEvent::IdentitiesUpdated
+            | AppEvent::VotingBlocsChanged
+            | AppEvent::VoteListsChanged => self.refresh_on_arrival(),
+        }
     }
 
     fn display_message(&mut self, message: &str, message_type: MessageType) {
@@ -458,6 +2379,7 @@ impl ScreenLike for DPNSContestedNamesScreen {
 
     fn ui(&mut self, ctx: &Context) -> AppAction {
         self.check_error_expiration();
+        self.drop_ended_votes();
         let has_identity_that_can_register = !self.user_identities.is_empty();
         let query = (
             "Refresh",
@@ -465,16 +2387,36 @@ impl ScreenLike for DPNSContestedNamesScreen {
                 ContestedResourceTask::QueryDPNSContestedResources,
             )),
         );
+        let voting_blocs = (
+            "Voting Blocs",
+            DesiredAppAction::AddScreenType(ScreenType::VotingBlocs),
+        );
+        let vote_lists = (
+            "Vote Lists",
+            DesiredAppAction::AddScreenType(ScreenType::VoteLists),
+        );
+        let vote_receipts = (
+            "Receipts",
+            DesiredAppAction::AddScreenType(ScreenType::VoteReceipts),
+        );
+        let name_lookup = (
+            "Name Lookup",
+            DesiredAppAction::AddScreenType(ScreenType::DpnsLookup),
+        );
         let right_buttons = if has_identity_that_can_register {
             vec![
                 (
                     "Register Name",
                     DesiredAppAction::AddScreenType(ScreenType::RegisterDpnsName),
                 ),
+                name_lookup,
+                voting_blocs,
+                vote_lists,
+                vote_receipts,
                 query,
             ]
         } else {
-            vec![query]
+            vec![name_lookup, voting_blocs, vote_lists, vote_receipts, query]
         };
         let mut action = add_top_panel(
             ctx,
@@ -517,13 +2459,33 @@ impl ScreenLike for DPNSContestedNamesScreen {
                 }
             }
 
-            // Show vote popup if active
-            if self.show_vote_popup_info.is_some() {
-                egui::Window::new("Vote Confirmation")
-                    .collapsible(false)
-                    .show(ui.ctx(), |ui| {
-                        action |= self.show_vote_popup(ui);
-                    });
+            let refreshing =
+                self.app_context
+                    .task_gate
+                    .is_running(&BackendTask::ContestedResourceTask(
+                        ContestedResourceTask::QueryDPNSContestedResources,
+                    ));
+            if refreshing {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Refreshing contested names...");
+                });
+            } else {
+                add_proof_status(ui, &self.app_context, &CONTESTED_NAMES_METHODS);
+            }
+            if let Some(skew) = self
+                .app_context
+                .clock_skew()
+                .filter(|skew| skew.exceeds_threshold())
+            {
+                ui.colored_label(
+                    egui::Color32::from_rgb(200, 150, 0),
+                    format!(
+                        "{}. Time left is corrected for it, turn on automatic time \
+                         synchronization in the system settings to fix the clock.",
+                        skew.describe()
+                    ),
+                );
             }
 
             // Check if there are any contested names to display
@@ -532,7 +2494,39 @@ impl ScreenLike for DPNSContestedNamesScreen {
                 !contested_names.is_empty()
             };
 
-            if has_contested_names {
+            if has_contested_names && self.triage.is_some() {
+                self.render_triage(ui);
+            } else if has_contested_names {
+                self.render_calendar_export(ui);
+                ui.horizontal(|ui| {
+                    ui.menu_button("Columns", |ui| self.show_column_chooser(ui))
+                        .response
+                        .on_hover_text("Choose the columns of the table");
+                    let filter_label = if self.filter.is_active() {
+                        "Filter (on)"
+                    } else {
+                        "Filter"
+                    };
+                    ui.menu_button(filter_label, |ui| self.show_filter_menu(ui));
+                    let views_label = match &self.active_view {
+                        Some(name) => format!("View: {}", name),
+                        None => "Views".to_string(),
+                    };
+                    ui.menu_button(views_label, |ui| self.show_views_menu(ui))
+                        .response
+                        .on_hover_text("Saved filters, sorts and columns");
+                    if ui
+                        .button("Triage")
+                        .on_hover_text(
+                            "Vote on the open contests one at a time from the keyboard: L to \
+                             lock, A to abstain, 1 to 9 for a contestant and S to skip",
+                        )
+                        .clicked()
+                    {
+                        self.start_triage();
+                    }
+                });
+                self.render_bulk_action_bar(ui);
                 // Render the table if there are contested names
                 self.render_table(ui);
             } else {
@@ -541,6 +2535,75 @@ impl ScreenLike for DPNSContestedNamesScreen {
             }
         });
 
+        // Escape closes the vote popup first, then the contest detail, then the triage
+        if (self.show_vote_popup_info.is_some()
+            || self.contest_detail.is_some()
+            || self.triage.is_some())
+            && escape_pressed(ctx)
+        {
+            if self.show_vote_popup_info.is_some() {
+                self.show_vote_popup_info = None;
+            } else if self.contest_detail.is_some() {
+                self.contest_detail = None;
+            } else {
+                self.triage = None;
+            }
+        }
+
+        // Show the contest detail view if a contest was selected
+        let contest_detail = self.contest_detail.as_ref().and_then(|name| {
+            self.contested_names
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|contested_name| &contested_name.normalized_contested_name == name)
+                .cloned()
+        });
+        if let Some(contested_name) = contest_detail {
+            let mut open = true;
+            let mut detached = self.contest_detail_detached;
+            if let Some(detail_action) =
+                show_detachable_window(ctx, "Contest Detail", &mut detached, &mut open, |ui| {
+                    self.show_contest_detail(ui, &contested_name)
+                })
+            {
+                action |= detail_action;
+            }
+            self.contest_detail_detached = detached;
+            if !open {
+                self.contest_detail = None;
+            }
+        }
+
+        // Show vote popup if active
+        let take_focus = self.show_vote_popup_info.is_some() && !self.vote_popup_open;
+        self.vote_popup_open = self.show_vote_popup_info.is_some();
+        if self.show_vote_popup_info.is_some() {
+            let mut open = true;
+            let mut detached = self.vote_popup_detached;
+            if let Some(popup_action) =
+                show_detachable_window(ctx, "Vote Confirmation", &mut detached, &mut open, |ui| {
+                    self.show_vote_popup(ui, take_focus)
+                })
+            {
+                action |= popup_action;
+            }
+            self.vote_popup_detached = detached;
+            if !open {
+                self.show_vote_popup_info = None;
+            }
+        }
+
+        if let Some(task) = self.bloc_vote_to_cast.take() {
+            action |= AppAction::BackendTask(BackendTask::ContestedResourceTask(task));
+        }
+
+        if let Some(identity_id) = self.identity_to_open.take() {
+            action |= AppAction::AddScreen(Screen::IdentityDetailScreen(
+                IdentityDetailScreen::new(identity_id, &self.app_context),
+            ));
+        }
+
         action
     }
 }
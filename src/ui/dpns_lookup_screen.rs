@@ -0,0 +1,265 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::dpns_lookup::{DpnsNameRecord, NameAvailability, NameCheck, DPNS_SEARCH_LIMIT};
+use crate::platform::document::DocumentTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{MessageType, ScreenLike};
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use eframe::egui::{self, Color32, Context, Ui};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Searches registered DPNS names by prefix or pattern and checks many candidate names at once
+pub struct DpnsLookupScreen {
+    pub app_context: Arc<AppContext>,
+    pattern_input: String,
+    search_results: Option<Vec<DpnsNameRecord>>,
+    candidates_input: String,
+    checks: Option<Vec<NameCheck>>,
+    waiting: bool,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl DpnsLookupScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        Self {
+            app_context: app_context.clone(),
+            pattern_input: String::new(),
+            search_results: None,
+            candidates_input: String::new(),
+            checks: None,
+            waiting: false,
+            message: None,
+        }
+    }
+
+    /// The typed search pattern and candidate names, saved with the session
+    pub fn form_input(&self) -> (String, String) {
+        (self.pattern_input.clone(), self.candidates_input.clone())
+    }
+
+    /// Fills the form with a pattern and candidates saved with the session
+    pub fn with_form_input(mut self, pattern: String, candidates: String) -> Self {
+        self.pattern_input = pattern;
+        self.candidates_input = candidates;
+        self
+    }
+
+    /// Starts a search for the pattern as if it had been typed in
+    pub fn search(&mut self, pattern: &str) -> BackendTask {
+        self.pattern_input = pattern.to_string();
+        self.waiting = true;
+        BackendTask::DocumentTask(DocumentTask::SearchDpnsNames(pattern.to_string()))
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    /// Candidate names typed one per line or separated by commas or spaces
+    fn candidates(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.candidates_input
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty() && seen.insert(name.clone()))
+            .collect()
+    }
+
+    fn render_search(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("Search Registered Names");
+        ui.label("A prefix such as \"dash\", or a pattern where * matches anything, such as \"pay*shop\".");
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.pattern_input)
+                    .desired_width(250.0)
+                    .hint_text("Prefix or pattern"),
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            let can_search = !self.waiting && !self.pattern_input.trim().is_empty();
+            if (ui
+                .add_enabled(can_search, egui::Button::new("Search"))
+                .clicked()
+                || submitted)
+                && can_search
+            {
+                let pattern = self.pattern_input.trim().to_string();
+                action = AppAction::BackendTask(self.search(&pattern));
+            }
+        });
+
+        if let Some(results) = &self.search_results {
+            if results.is_empty() {
+                ui.label("No registered names match.");
+                return action;
+            }
+            if results.len() as u32 >= DPNS_SEARCH_LIMIT {
+                ui.colored_label(
+                    Color32::DARK_RED,
+                    format!(
+                        "Only the first {} names starting with the prefix were searched, \
+                         use a longer prefix to see more.",
+                        DPNS_SEARCH_LIMIT
+                    ),
+                );
+            }
+            egui::Grid::new("dpns_search_results")
+                .num_columns(2)
+                .striped(true)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    ui.strong("Name");
+                    ui.strong("Identity");
+                    ui.end_row();
+                    for record in results {
+                        ui.label(format!("{}.dash", record.label));
+                        let identity_id = record.identity_id.to_string(Encoding::Base58);
+                        if ui
+                            .link(&identity_id)
+                            .on_hover_text("Click to copy")
+                            .clicked()
+                        {
+                            ui.output_mut(|output| output.copied_text = identity_id);
+                        }
+                        ui.end_row();
+                    }
+                });
+        }
+        action
+    }
+
+    fn render_availability(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("Check Availability");
+        ui.label("Candidate names, one per line or separated by commas.");
+        ui.add(
+            egui::TextEdit::multiline(&mut self.candidates_input)
+                .desired_rows(5)
+                .desired_width(300.0)
+                .hint_text("acme\nacme-pay\nacmeshop"),
+        );
+        let candidates = self.candidates();
+        if ui
+            .add_enabled(
+                !self.waiting && !candidates.is_empty(),
+                egui::Button::new(format!("Check {} names", candidates.len())),
+            )
+            .clicked()
+        {
+            self.waiting = true;
+            action = AppAction::BackendTask(BackendTask::DocumentTask(
+                DocumentTask::CheckDpnsNames(candidates),
+            ));
+        }
+
+        if let Some(checks) = &self.checks {
+            egui::Grid::new("dpns_availability_results")
+                .num_columns(3)
+                .striped(true)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    ui.strong("Name");
+                    ui.strong("Normalized");
+                    ui.strong("Availability");
+                    ui.end_row();
+                    for check in checks {
+                        ui.label(&check.name);
+                        ui.label(&check.normalized_label);
+                        let color = match check.availability {
+                            NameAvailability::Available => Color32::DARK_GREEN,
+                            NameAvailability::AvailableContested => Color32::from_rgb(200, 120, 0),
+                            _ => Color32::RED,
+                        };
+                        ui.colored_label(color, check.availability.label());
+                        ui.end_row();
+                    }
+                });
+            let available: Vec<&str> = checks
+                .iter()
+                .filter(|check| check.availability.is_available())
+                .map(|check| check.name.as_str())
+                .collect();
+            if !available.is_empty() && ui.button("Copy available names").clicked() {
+                let text = available.join("\n");
+                ui.output_mut(|output| output.copied_text = text);
+            }
+            ui.label(
+                egui::RichText::new(
+                    "Contests are checked against the local list of contested names, refresh \
+                     it on the contested names screen for up to date results.",
+                )
+                .color(Color32::GRAY),
+            );
+        }
+        action
+    }
+}
+
+impl ScreenLike for DpnsLookupScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.waiting = false;
+        }
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        match backend_task_success_result {
+            BackendTaskSuccessResult::DpnsNames(records) => {
+                self.waiting = false;
+                self.search_results = Some(records);
+            }
+            BackendTaskSuccessResult::DpnsNameChecks(checks) => {
+                self.waiting = false;
+                self.checks = Some(checks);
+            }
+            _ => {}
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Name Lookup", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => ui.visuals().text_color(),
+                    };
+                    ui.colored_label(color, message);
+                }
+                if self.waiting {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label("Querying Platform...");
+                    });
+                }
+                action |= self.render_search(ui);
+                ui.add_space(10.0);
+                ui.separator();
+                action |= self.render_availability(ui);
+            });
+        });
+
+        action
+    }
+}
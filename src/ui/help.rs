@@ -0,0 +1,565 @@
+//! What the help overlay says about each screen. To document a screen, add a `ScreenHelp`
+//! and return it from `screen_help`, the callouts are shown in order and walked through by the
+//! guided tour.
+
+use crate::ui::ScreenType;
+
+/// One thing on a screen worth explaining, a column, a button or a field
+pub struct HelpCallout {
+    /// The name of the element as shown on the screen
+    pub label: &'static str,
+    pub text: &'static str,
+}
+
+/// A domain term explained in a tooltip where it appears
+pub struct HelpTerm {
+    pub name: &'static str,
+    pub text: &'static str,
+}
+
+pub const TERM_CONTESTED_RESOURCE: HelpTerm = HelpTerm {
+    name: "Contested resource",
+    text: "Something only one identity can have, here a DPNS name, that more than one \
+           identity asked for. Masternodes vote on who gets it until the contest ends.",
+};
+
+pub const TERM_NORMALIZED_NAME: HelpTerm = HelpTerm {
+    name: "Normalized name",
+    text: "The name in lowercase with o written as 0 and i and l written as 1, so names that \
+           look alike are the same name. \"Alice\" and \"a1ice\" both normalize to \"a11ce\" \
+           and compete for it.",
+};
+
+pub const TERM_LOCK: HelpTerm = HelpTerm {
+    name: "Lock",
+    text: "A vote that nobody should get the name, e.g. because it is offensive or \
+           impersonates someone. If the lock wins, the name can never be registered.",
+};
+
+pub const TERM_LOCKED_VOTES: HelpTerm = HelpTerm {
+    name: "Locked votes",
+    text: "The votes cast for locking the name. Shown in bold when they lead all contestants, \
+           the name is locked if that holds when the contest ends.",
+};
+
+pub const TERM_ABSTAIN: HelpTerm = HelpTerm {
+    name: "Abstain",
+    text: "A vote that takes no side. It is counted as cast but helps neither a contestant nor \
+           the lock, use it to show you looked at the contest.",
+};
+
+pub struct ScreenHelp {
+    pub title: &'static str,
+    /// What the screen is for, shown above the callouts
+    pub summary: &'static str,
+    pub callouts: &'static [HelpCallout],
+}
+
+const IDENTITIES: ScreenHelp = ScreenHelp {
+    title: "Identities",
+    summary: "The identities loaded in the tool on this network, with their balances and keys.",
+    callouts: &[
+        HelpCallout {
+            label: "Name",
+            text: "The alias you gave the identity, or its DPNS name. Only known to this tool.",
+        },
+        HelpCallout {
+            label: "Identity ID",
+            text: "The id of the identity on Platform, in base58.",
+        },
+        HelpCallout {
+            label: "Balance",
+            text: "Credits of the identity in DASH as of the last refresh. Refresh Balances \
+                   fetches them again.",
+        },
+        HelpCallout {
+            label: "Type",
+            text: "User identities are created by wallets. Masternode and evonode identities \
+                   belong to masternodes and vote on contested names with their voting key.",
+        },
+        HelpCallout {
+            label: "Keys",
+            text: "The public keys of the identity. Keys whose private key is in the tool can \
+                   sign, the others are shown for reference.",
+        },
+        HelpCallout {
+            label: "Withdraw",
+            text: "Moves credits back to Dash Core. Masternode identities withdraw to their \
+                   payout address.",
+        },
+    ],
+};
+
+const CONTESTED_NAMES: ScreenHelp = ScreenHelp {
+    title: "Contested Names",
+    summary: "DPNS names wanted by more than one identity. Masternodes vote on who gets them \
+              until the contest ends.",
+    callouts: &[
+        HelpCallout {
+            label: "Contested Name",
+            text: "The normalized name being contested, e.g. \"a11ce\" for \"Alice\".",
+        },
+        HelpCallout {
+            label: "Contestants",
+            text: "The identities that asked for the name. A vote for a contestant gives the \
+                   name to that identity if it wins.",
+        },
+        HelpCallout {
+            label: "Lock",
+            text: "Votes to lock the name so nobody gets it, e.g. for offensive or \
+                   impersonating names. A locked name can never be registered again.",
+        },
+        HelpCallout {
+            label: "Abstain",
+            text: "Votes without taking a side. It counts as a vote cast but helps neither a \
+                   contestant nor the lock.",
+        },
+        HelpCallout {
+            label: "Locked Votes / Abstain Votes",
+            text: "The votes cast so far for the lock and for abstaining.",
+        },
+        HelpCallout {
+            label: "Ending Time / Time Remaining",
+            text: "When the contest ends. Votes can't be cast or changed afterwards. The time \
+                   remaining is green with more than three days left, yellow under a day and \
+                   red under two hours.",
+        },
+        HelpCallout {
+            label: "My Vote",
+            text: "The vote your masternodes cast, as recorded by this tool.",
+        },
+        HelpCallout {
+            label: "Suggested",
+            text: "What the vote lists you follow suggest. Suggestions are never cast \
+                   automatically.",
+        },
+        HelpCallout {
+            label: "Check Reputation",
+            text: "Shows how many names a contestant holds, whether it has a Dashpay profile, \
+                   how long it has been active and how its earlier contests ended. Signals in \
+                   orange are worth a closer look, none of them proves who deserves a name.",
+        },
+        HelpCallout {
+            label: "Notes",
+            text: "Your own tags and notes on a contest or a contestant, e.g. \"impersonation\" \
+                   with a link to the evidence. They stay on this machine unless you export a \
+                   vote list or a calendar, which include them.",
+        },
+        HelpCallout {
+            label: "Triage",
+            text: "Shows the open contests you haven't voted on one at a time, ending soonest \
+                   first. Press L to lock, A to abstain, 1 to 9 for a contestant, S to skip \
+                   and Backspace to go back, then review the votes and pick the voters.",
+        },
+        HelpCallout {
+            label: "Filter / Views",
+            text: "Filter narrows the table, e.g. to contests ending within a day that you \
+                   haven't voted on. Views save the filter, sort and columns under a name; \
+                   the default view is applied whenever the screen opens.",
+        },
+    ],
+};
+
+const ADD_NEW_IDENTITY: ScreenHelp = ScreenHelp {
+    title: "Create Identity",
+    summary: "Registers a new identity funded by an asset lock, DASH burned on Core and \
+              credited to the identity on Platform.",
+    callouts: &[
+        HelpCallout {
+            label: "Funding Method",
+            text: "Where the DASH of the asset lock comes from: the balance of the wallet, a \
+                   payment to a QR code address, the attached Core wallet or a PSBT signed by \
+                   an external wallet.",
+        },
+        HelpCallout {
+            label: "Identity Index",
+            text: "Which identity of the wallet to derive the keys for. Each index gives \
+                   different keys, use the next unused one.",
+        },
+        HelpCallout {
+            label: "Master Private Key",
+            text: "The key that can add and disable the other keys of the identity. Keep it \
+                   safe, it isn't needed day to day.",
+        },
+        HelpCallout {
+            label: "Keys",
+            text: "Further keys: authentication keys sign transitions, transfer keys withdraw \
+                   and transfer credits.",
+        },
+    ],
+};
+
+const WITHDRAWAL: ScreenHelp = ScreenHelp {
+    title: "Withdraw",
+    summary: "Withdraws credits of the identity to a Core address. The DASH arrives once the \
+              withdrawal is processed, which can take a while.",
+    callouts: &[
+        HelpCallout {
+            label: "Amount",
+            text: "In DASH. A fee is taken from the identity's balance on top of it.",
+        },
+        HelpCallout {
+            label: "Address",
+            text: "The Core address receiving the DASH. Masternode identities always withdraw \
+                   to their payout address.",
+        },
+        HelpCallout {
+            label: "Sign on an offline machine",
+            text: "Builds the withdrawal unsigned, to sign it on an offline machine holding \
+                   the key and broadcast it from here.",
+        },
+    ],
+};
+
+const TOP_UP: ScreenHelp = ScreenHelp {
+    title: "Top Up",
+    summary: "Adds credits to the identity with a new asset lock funded from a wallet.",
+    callouts: &[HelpCallout {
+        label: "Amount",
+        text: "In DASH, burned on Core and credited to the identity. 1 DASH gives 100 billion \
+               credits.",
+    }],
+};
+
+const KEYS: ScreenHelp = ScreenHelp {
+    title: "Keys",
+    summary: "The keys of an identity, their purposes and security levels.",
+    callouts: &[
+        HelpCallout {
+            label: "Purpose",
+            text: "What the key is for: authentication signs transitions, transfer moves \
+                   credits, encryption and decryption are for messages between identities.",
+        },
+        HelpCallout {
+            label: "Security Level",
+            text: "Master keys manage the keys, critical keys are needed for the most sensitive \
+                   transitions, high and medium keys for everyday documents.",
+        },
+        HelpCallout {
+            label: "Disabled",
+            text: "A disabled key can no longer sign anything, disabling can't be undone.",
+        },
+    ],
+};
+
+const DOCUMENT_QUERY: ScreenHelp = ScreenHelp {
+    title: "Document Query",
+    summary: "Queries the documents of a data contract with a SQL like statement.",
+    callouts: &[
+        HelpCallout {
+            label: "Query",
+            text: "e.g. SELECT * FROM domain WHERE normalizedParentDomainName = 'dash'. Only \
+                   indexed properties can be filtered and ordered on.",
+        },
+        HelpCallout {
+            label: "Contracts",
+            text: "The contracts loaded in the tool. Pick one to see its document types.",
+        },
+    ],
+};
+
+const NETWORK_CHOOSER: ScreenHelp = ScreenHelp {
+    title: "Network",
+    summary: "Picks the network the tool works on and how it talks to it.",
+    callouts: &[
+        HelpCallout {
+            label: "Network",
+            text: "Mainnet moves real DASH, testnet and devnets are for trying things out. \
+                   Each network keeps its own identities and wallets.",
+        },
+        HelpCallout {
+            label: "Verification",
+            text: "Verified queries check the proofs of every response. Fast mode trusts the \
+                   DAPI node, it is quicker but a node could lie.",
+        },
+    ],
+};
+
+const SETTINGS: ScreenHelp = ScreenHelp {
+    title: "Settings",
+    summary: "Preferences shared by all networks, saved as soon as they change.",
+    callouts: &[
+        HelpCallout {
+            label: "Approval",
+            text: "Withdrawals and transfers above the threshold, key changes and transitions \
+                   from the sandbox wait for the approver's password. Changing these rules \
+                   needs it too.",
+        },
+        HelpCallout {
+            label: "Import and Export",
+            text: "Copies the preferences to another machine. Passwords, wallet keys and \
+                   identities are never exported.",
+        },
+    ],
+};
+
+const REGISTER_DPNS_NAME: ScreenHelp = ScreenHelp {
+    title: "Register Name",
+    summary: "Registers a DPNS name for an identity.",
+    callouts: &[
+        HelpCallout {
+            label: "Name",
+            text: "Names of up to 19 characters made only of letters a to z, 0, 1 and - go to a \
+                   contest that masternodes vote on, other names are registered right away.",
+        },
+        HelpCallout {
+            label: "Identity",
+            text: "The identity that owns the name, it pays the registration.",
+        },
+    ],
+};
+
+const WITHDRAWAL_QUEUE: ScreenHelp = ScreenHelp {
+    title: "Withdrawals",
+    summary: "Withdrawals waiting on Platform to be paid out on Core.",
+    callouts: &[HelpCallout {
+        label: "Status",
+        text: "Queued withdrawals wait for the next batch, pooled ones are being signed by \
+               the quorum, broadcasted ones are sent to Core and completed ones are paid. \
+               Expired ones were never paid and their credits are returned.",
+    }],
+};
+
+const VOTE_RECEIPTS: ScreenHelp = ScreenHelp {
+    title: "Vote Receipts",
+    summary: "Platform proofs of the votes cast by your masternodes, fetched right after each \
+              vote.",
+    callouts: &[
+        HelpCallout {
+            label: "Export",
+            text: "Writes the receipt of a vote to a file, to send to whoever delegated their \
+                   vote to the masternode.",
+        },
+        HelpCallout {
+            label: "Verify",
+            text: "Checks the proof of a receipt against the quorum that signed it. The \
+                   quorum's key comes from Dash Core, which must still know the quorum.",
+        },
+        HelpCallout {
+            label: "Reports for Owners",
+            text: "Writes a CSV of the votes of a period and their receipts for each voting \
+                   bloc, e.g. one bloc per owner whose masternodes you vote for.",
+        },
+    ],
+};
+
+const VOTE_LISTS: ScreenHelp = ScreenHelp {
+    title: "Vote Lists",
+    summary: "Lists of suggested votes published by others, shown in the Suggested column of \
+              the contested names.",
+    callouts: &[
+        HelpCallout {
+            label: "Import",
+            text: "Adds a list published by someone you trust, from a file or pasted text.",
+        },
+        HelpCallout {
+            label: "Remove",
+            text: "Stops showing the suggestions of the list. Nothing is ever voted \
+                   automatically.",
+        },
+    ],
+};
+
+const VOTING_BLOCS: ScreenHelp = ScreenHelp {
+    title: "Voting Blocs",
+    summary: "Groups of masternodes that vote together, so one vote is cast by all of them.",
+    callouts: &[
+        HelpCallout {
+            label: "Members",
+            text: "The loaded masternodes and evonodes ticked for the bloc.",
+        },
+        HelpCallout {
+            label: "Default vote",
+            text: "Offered as a one click vote for the bloc in the menu of a contest.",
+        },
+    ],
+};
+
+const COLD_SIGNING: ScreenHelp = ScreenHelp {
+    title: "Cold Signing",
+    summary: "Passes transitions between an online machine without the keys and an offline \
+              machine with them.",
+    callouts: &[
+        HelpCallout {
+            label: "Import",
+            text: "Load a file exported on the other machine, or paste its text scanned from \
+                   the QR code.",
+        },
+        HelpCallout {
+            label: "Sign",
+            text: "On the offline machine: signs the transition with the identity's key. \
+                   Check the decoded transition first.",
+        },
+        HelpCallout {
+            label: "Broadcast",
+            text: "On the online machine: sends the signed transition to Platform.",
+        },
+    ],
+};
+
+const OWNERSHIP_PROOF: ScreenHelp = ScreenHelp {
+    title: "Ownership Proofs",
+    summary: "Proves control of an identity or masternode by signing a message with one of its \
+              keys, and checks such proofs from others.",
+    callouts: &[
+        HelpCallout {
+            label: "Sign",
+            text: "Pick the identity and one of its ECDSA keys, write a message only the \
+                   other side knows about and send them the proof.",
+        },
+        HelpCallout {
+            label: "Masternode key",
+            text: "Signs with the owner, voting or operator key of a masternode, e.g. to \
+                   coordinate governance votes. A key not stored with the masternode is \
+                   entered for the one signature and not saved.",
+        },
+        HelpCallout {
+            label: "Verify",
+            text: "Paste a proof to see what it claims, then check its signature against the \
+                   key on Platform, or the masternode list from Core for masternode messages. \
+                   A proof signed with a key disabled or replaced since is not valid.",
+        },
+    ],
+};
+
+const TRANSITION_VISUALIZER: ScreenHelp = ScreenHelp {
+    title: "Transition Visualizer",
+    summary: "Decodes a serialized state transition to see what it does before signing or \
+              broadcasting it.",
+    callouts: &[HelpCallout {
+        label: "Input",
+        text: "The transition in hex or base64.",
+    }],
+};
+
+const TRANSITION_SANDBOX: ScreenHelp = ScreenHelp {
+    title: "Transition Sandbox",
+    summary: "Builds any state transition from its JSON to try edge cases against testnet or a \
+              devnet. Opened from the transition visualizer in developer mode.",
+    callouts: &[
+        HelpCallout {
+            label: "Load",
+            text: "Starts from a serialized transition, e.g. one copied from the visualizer, \
+                   and fills in its kind and JSON.",
+        },
+        HelpCallout {
+            label: "Sign",
+            text: "Signs with a key of a loaded identity whose private key is on this machine. \
+                   Identity creations and top ups carry the signature of their asset lock.",
+        },
+        HelpCallout {
+            label: "Local validation",
+            text: "Checks the encoding, the signing key and the signature. What depends on \
+                   Platform's state is only checked when broadcast.",
+        },
+        HelpCallout {
+            label: "Broadcast",
+            text: "Sends the transition even when a check failed. Not available on mainnet.",
+        },
+    ],
+};
+
+const DIAGNOSTICS: ScreenHelp = ScreenHelp {
+    title: "Diagnostics",
+    summary: "Checks the connections to Dash Core and to the DAPI nodes.",
+    callouts: &[
+        HelpCallout {
+            label: "Run checks",
+            text: "Runs the connection checks again.",
+        },
+        HelpCallout {
+            label: "Troubleshoot",
+            text: "Tries each DAPI address step by step to find where the connection fails.",
+        },
+        HelpCallout {
+            label: "Trust again",
+            text: "Stops avoiding a DAPI node that answered with a proof that did not verify.",
+        },
+    ],
+};
+
+const APP_PROFILES: ScreenHelp = ScreenHelp {
+    title: "App Profiles",
+    summary: "Turns a stored contract into an admin panel with an entry in the left panel.",
+    callouts: &[
+        HelpCallout {
+            label: "Generate from contract",
+            text: "Starts a description with a table per document type of the contract, \
+                   showing the ids and the first properties. Remove the tables and columns \
+                   you don't need.",
+        },
+        HelpCallout {
+            label: "Save",
+            text: "Checks that the document types and columns exist in the contract. A \
+                   profile belongs to the network it was saved on.",
+        },
+    ],
+};
+
+const APP_PROFILE: ScreenHelp = ScreenHelp {
+    title: "App Profile",
+    summary: "The documents of a contract as described by its app profile.",
+    callouts: &[
+        HelpCallout {
+            label: "Refresh",
+            text: "Fetches the first documents of the table again, \"Load more\" fetches the \
+                   next ones.",
+        },
+        HelpCallout {
+            label: "Actions",
+            text: "Views the JSON of a document, or opens the import, delete or analytics \
+                   screen of the document type, as the profile allows.",
+        },
+    ],
+};
+
+const SCHEDULER: ScreenHelp = ScreenHelp {
+    title: "Scheduler",
+    summary: "Recurring tasks run in the background while the tool is open.",
+    callouts: &[
+        HelpCallout {
+            label: "Status",
+            text: "How the last run ended, hover a failure to see why. A task is not started \
+                   again while its last run is still going.",
+        },
+        HelpCallout {
+            label: "Run now",
+            text: "Runs the task right away, its next run is counted from now.",
+        },
+        HelpCallout {
+            label: "Interval",
+            text: "Pick a preset or type a number of minutes, at least 5. Tasks belong to the \
+                   network they were added on.",
+        },
+    ],
+};
+
+/// The help of a screen, `None` for screens without one yet
+pub fn screen_help(screen_type: &ScreenType) -> Option<&'static ScreenHelp> {
+    let help = match screen_type {
+        ScreenType::Identities => &IDENTITIES,
+        ScreenType::DPNSContestedNames => &CONTESTED_NAMES,
+        ScreenType::AddNewIdentity => &ADD_NEW_IDENTITY,
+        ScreenType::WithdrawalScreen(_) | ScreenType::TransferScreen(_) => &WITHDRAWAL,
+        ScreenType::TopUpIdentity(_) => &TOP_UP,
+        ScreenType::Keys(_) | ScreenType::KeyInfo(..) => &KEYS,
+        ScreenType::DocumentQueryScreen => &DOCUMENT_QUERY,
+        ScreenType::NetworkChooser => &NETWORK_CHOOSER,
+        ScreenType::RegisterDpnsName => &REGISTER_DPNS_NAME,
+        ScreenType::WithdrawalQueue => &WITHDRAWAL_QUEUE,
+        ScreenType::VoteLists => &VOTE_LISTS,
+        ScreenType::VoteReceipts => &VOTE_RECEIPTS,
+        ScreenType::VotingBlocs => &VOTING_BLOCS,
+        ScreenType::ColdSigning => &COLD_SIGNING,
+        ScreenType::OwnershipProof => &OWNERSHIP_PROOF,
+        ScreenType::TransitionVisualizer => &TRANSITION_VISUALIZER,
+        ScreenType::TransitionSandbox => &TRANSITION_SANDBOX,
+        ScreenType::Diagnostics => &DIAGNOSTICS,
+        ScreenType::AppProfiles => &APP_PROFILES,
+        ScreenType::AppProfile(_) => &APP_PROFILE,
+        ScreenType::Scheduler => &SCHEDULER,
+        ScreenType::Settings => &SETTINGS,
+        _ => return None,
+    };
+    Some(help)
+}
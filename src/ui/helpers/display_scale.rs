@@ -0,0 +1,56 @@
+use crate::model::preferences::{DisplayScale, TableDensity};
+use egui::{vec2, Context, Style, TextStyle, Ui};
+
+/// Zooms the interface and sizes the text as the preferences say. Text sizes are derived
+/// from egui's defaults so switching presets back and forth does not compound.
+pub fn apply_display_scale(ctx: &Context, display_scale: &DisplayScale) {
+    ctx.set_zoom_factor(display_scale.ui_scale);
+    let factor = display_scale.font_size.factor();
+    let default_text_styles = Style::default().text_styles;
+    ctx.style_mut(|style| {
+        for (text_style, font_id) in style.text_styles.iter_mut() {
+            if let Some(default_font_id) = default_text_styles.get(text_style) {
+                font_id.size = default_font_id.size * factor;
+            }
+        }
+    });
+}
+
+/// A fixed size such as a panel height, table row height or explicit font size, grown with
+/// the text so larger fonts fit. The interface zoom needs no adjustment, it scales all sizes.
+pub fn scaled_size(ctx: &Context, size: f32) -> f32 {
+    let body_size = |style: &Style| {
+        style
+            .text_styles
+            .get(&TextStyle::Body)
+            .map_or(1.0, |font_id| font_id.size)
+    };
+    size * (body_size(&ctx.style()) / body_size(&Style::default())).max(1.0)
+}
+
+/// Header and row heights of a table for the density, from the comfortable row height
+pub fn table_heights(ctx: &Context, density: TableDensity, row_height: f32) -> (f32, f32) {
+    let factor = match density {
+        TableDensity::Comfortable => 1.0,
+        TableDensity::Compact => 0.8,
+    };
+    (
+        scaled_size(ctx, 30.0) * factor,
+        scaled_size(ctx, row_height) * factor,
+    )
+}
+
+/// Tightens the paddings and text of a compact table. Changes the whole ui, so it is called
+/// on one that holds just the table.
+pub fn apply_table_density(ui: &mut Ui, density: TableDensity) {
+    if density == TableDensity::Comfortable {
+        return;
+    }
+    let style = ui.style_mut();
+    style.spacing.item_spacing = vec2(6.0, 2.0);
+    style.spacing.button_padding = vec2(3.0, 0.0);
+    style.spacing.interact_size.y = 16.0;
+    for font_id in style.text_styles.values_mut() {
+        font_id.size *= 0.9;
+    }
+}
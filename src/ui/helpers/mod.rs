@@ -0,0 +1,4 @@
+pub mod display_scale;
+pub mod number_format;
+pub mod redaction;
+pub mod time_format;
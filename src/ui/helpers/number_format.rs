@@ -0,0 +1,120 @@
+use crate::model::preferences::{NumberFormatSetting, Preferences};
+use crate::ui::helpers::redaction::REDACTED;
+use std::env;
+
+/// Credits per DASH
+pub const CREDITS_PER_DASH: f64 = 1e11;
+
+/// Formats numbers with the digit grouping and decimal separators of the user's locale.
+/// Balances are hidden in privacy mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberFormatter {
+    group_separator: Option<char>,
+    decimal_separator: char,
+    hide_amounts: bool,
+}
+
+impl NumberFormatter {
+    pub fn new(preferences: &Preferences) -> Self {
+        Self {
+            hide_amounts: preferences.privacy_mode,
+            ..Self::for_setting(preferences.number_format)
+        }
+    }
+
+    pub fn for_setting(setting: NumberFormatSetting) -> Self {
+        let setting = match setting {
+            NumberFormatSetting::System => system_number_format(),
+            setting => setting,
+        };
+        let (group_separator, decimal_separator) = match setting {
+            NumberFormatSetting::System | NumberFormatSetting::CommaPeriod => (Some(','), '.'),
+            NumberFormatSetting::PeriodComma => (Some('.'), ','),
+            NumberFormatSetting::SpaceComma => (Some('\u{202F}'), ','),
+            NumberFormatSetting::Plain => (None, '.'),
+        };
+        Self {
+            group_separator,
+            decimal_separator,
+            hide_amounts: false,
+        }
+    }
+
+    /// Formats an integer with digit grouping, e.g. "1,234,567"
+    pub fn format_integer(&self, value: u64) -> String {
+        self.group_digits(&value.to_string())
+    }
+
+    /// Formats a decimal number with a fixed number of decimal places
+    pub fn format_decimal(&self, value: f64, decimals: usize) -> String {
+        let formatted = format!("{:.*}", decimals, value.abs());
+        let (integer_part, fraction_part) = match formatted.split_once('.') {
+            Some((integer_part, fraction_part)) => (integer_part, Some(fraction_part)),
+            None => (formatted.as_str(), None),
+        };
+        let mut result = String::new();
+        if value.is_sign_negative() && formatted.chars().any(|c| c != '0' && c != '.') {
+            result.push('-');
+        }
+        result.push_str(&self.group_digits(integer_part));
+        if let Some(fraction_part) = fraction_part {
+            result.push(self.decimal_separator);
+            result.push_str(fraction_part);
+        }
+        result
+    }
+
+    /// Formats a credit balance in DASH with 4 decimal places, e.g. "1,234.5678 DASH"
+    pub fn format_credits_as_dash(&self, credits: u64) -> String {
+        if self.hide_amounts {
+            return format!("{} DASH", REDACTED);
+        }
+        format!(
+            "{} DASH",
+            self.format_decimal(credits as f64 / CREDITS_PER_DASH, 4)
+        )
+    }
+
+    /// Formats a credit amount, e.g. "123,456,789 credits"
+    pub fn format_credits(&self, credits: u64) -> String {
+        if self.hide_amounts {
+            return format!("{} credits", REDACTED);
+        }
+        format!("{} credits", self.format_integer(credits))
+    }
+
+    fn group_digits(&self, digits: &str) -> String {
+        let Some(separator) = self.group_separator else {
+            return digits.to_string();
+        };
+        let mut result = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                result.push(separator);
+            }
+            result.push(digit);
+        }
+        result
+    }
+}
+
+/// Guesses the number format from the POSIX locale environment variables
+fn system_number_format() -> NumberFormatSetting {
+    let locale = ["LC_ALL", "LC_NUMERIC", "LANG"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default();
+    let language = locale
+        .split(['_', '-', '.'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    match language.as_str() {
+        "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl"
+        | "sr" => NumberFormatSetting::PeriodComma,
+        "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "uk" | "hu" | "bg"
+        | "lt" | "lv" | "et" => NumberFormatSetting::SpaceComma,
+        _ => NumberFormatSetting::CommaPeriod,
+    }
+}
@@ -0,0 +1,35 @@
+//! Privacy mode hides identity ids, balances and keys so screenshots can be shared when asking
+//! for help.
+
+use crate::model::qualified_identity::QualifiedIdentity;
+
+/// Shown in place of a hidden value
+pub const REDACTED: &str = "•••••";
+
+/// The first characters of an id followed by an ellipsis in privacy mode, the whole id otherwise.
+/// Enough is left to tell identities apart on one screen.
+pub fn redact_id(id: &str, privacy_mode: bool) -> String {
+    if !privacy_mode {
+        return id.to_string();
+    }
+    let start: String = id.chars().take(4).collect();
+    format!("{}…", start)
+}
+
+/// The alias of the identity, or its id redacted as by [`redact_id`] when it has none
+pub fn identity_label(qualified_identity: &QualifiedIdentity, privacy_mode: bool) -> String {
+    match &qualified_identity.alias {
+        Some(alias) => alias.clone(),
+        None => redact_id(&qualified_identity.display_string(), privacy_mode),
+    }
+}
+
+/// `REDACTED` in privacy mode, the value otherwise. For keys and anything else that must not
+/// show at all.
+pub fn redact_secret(value: &str, privacy_mode: bool) -> String {
+    if privacy_mode {
+        REDACTED.to_string()
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,87 @@
+use crate::model::clock_skew::network_now;
+use crate::model::preferences::{Preferences, TimeDisplayMode, TimeZoneSetting};
+use chrono::{DateTime, FixedOffset, Local, LocalResult, TimeZone, Utc};
+use chrono_humanize::HumanTime;
+
+const ABSOLUTE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Formats timestamps according to the user's time display preferences.
+///
+/// Build one per column (or per frame) and reuse it for every cell, instead of reading the
+/// preferences for each timestamp.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeFormatter {
+    pub mode: TimeDisplayMode,
+    pub time_zone: TimeZoneSetting,
+}
+
+impl TimeFormatter {
+    /// Formatter using the global time display preferences
+    pub fn new(preferences: &Preferences) -> Self {
+        Self {
+            mode: preferences.time_display,
+            time_zone: preferences.time_zone,
+        }
+    }
+
+    /// Formatter for a specific table column, honoring a per-column display override
+    pub fn for_column(preferences: &Preferences, column_key: &str) -> Self {
+        let mut formatter = Self::new(preferences);
+        if let Some(mode) = preferences.column_time_display.get(column_key) {
+            formatter.mode = *mode;
+        }
+        formatter
+    }
+
+    /// Formats a timestamp given in milliseconds since the Unix epoch
+    pub fn format_millis(&self, timestamp_millis: u64) -> String {
+        match Utc.timestamp_millis_opt(timestamp_millis as i64) {
+            LocalResult::Single(datetime) => self.format(datetime),
+            _ => "Invalid timestamp".to_string(),
+        }
+    }
+
+    /// Formats a timestamp given in seconds since the Unix epoch
+    pub fn format_seconds(&self, timestamp_seconds: u64) -> String {
+        match Utc.timestamp_opt(timestamp_seconds as i64, 0) {
+            LocalResult::Single(datetime) => self.format(datetime),
+            _ => "Invalid timestamp".to_string(),
+        }
+    }
+
+    pub fn format(&self, datetime: DateTime<Utc>) -> String {
+        match self.mode {
+            TimeDisplayMode::Relative => relative(datetime).to_string(),
+            TimeDisplayMode::Absolute => self.format_absolute(datetime),
+            TimeDisplayMode::Both => format!(
+                "{} ({})",
+                self.format_absolute(datetime),
+                relative(datetime)
+            ),
+        }
+    }
+
+    /// Formats the date and time in the selected time zone
+    pub fn format_absolute(&self, datetime: DateTime<Utc>) -> String {
+        match self.time_zone {
+            TimeZoneSetting::Utc => format!("{} UTC", datetime.format(ABSOLUTE_FORMAT)),
+            TimeZoneSetting::Local => datetime
+                .with_timezone(&Local)
+                .format(ABSOLUTE_FORMAT)
+                .to_string(),
+            TimeZoneSetting::FixedOffset { minutes } => match FixedOffset::east_opt(minutes * 60) {
+                Some(offset) => datetime
+                    .with_timezone(&offset)
+                    .format("%Y-%m-%d %H:%M:%S %:z")
+                    .to_string(),
+                None => format!("{} UTC", datetime.format(ABSOLUTE_FORMAT)),
+            },
+        }
+    }
+}
+
+/// Time from now, measured with the network's clock so a skewed local clock doesn't show
+/// contests ending earlier or later than they do
+fn relative(datetime: DateTime<Utc>) -> HumanTime {
+    HumanTime::from(datetime.signed_duration_since(network_now()))
+}
@@ -0,0 +1,369 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::credit_operation::{
+    operations_to_csv, price_at, AccountingExport, CreditOperation, DailyPrices, PriceSource,
+};
+use crate::ui::components::fiat_value::fiat_value;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::number_format::CREDITS_PER_DASH;
+use crate::ui::{MessageType, ScreenLike};
+use chrono::{DateTime, Datelike, Utc};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+/// Lists the operations that changed our identities' credits and exports them as CSV for
+/// accountants
+pub struct AccountingExportScreen {
+    pub app_context: Arc<AppContext>,
+    operations: Vec<CreditOperation>,
+    identity_names: BTreeMap<Identifier, String>,
+    /// Only operations of this year, all when `None`
+    selected_year: Option<i32>,
+    settings: AccountingExport,
+    /// Text of the fixed price input
+    fixed_price_input: String,
+    daily_prices: Result<DailyPrices, String>,
+    export_path_input: String,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl AccountingExportScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let settings = app_context.preferences().accounting_export;
+        let fixed_price_input = match &settings.price_source {
+            PriceSource::Fixed { price } => price.to_string(),
+            _ => String::new(),
+        };
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            operations: Vec::new(),
+            identity_names: BTreeMap::new(),
+            selected_year: None,
+            daily_prices: DailyPrices::load(&settings.price_source),
+            settings,
+            fixed_price_input,
+            export_path_input: "credit_operations.csv".to_string(),
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    fn identity_name(&self, identity_id: &Identifier) -> String {
+        self.identity_names
+            .get(identity_id)
+            .cloned()
+            .unwrap_or_else(|| identity_id.to_string(Encoding::Base58))
+    }
+
+    fn filtered_operations(&self) -> Vec<CreditOperation> {
+        self.operations
+            .iter()
+            .filter(|operation| {
+                self.selected_year
+                    .map_or(true, |year| operation.occurred_at().year() == year)
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn save_settings(&mut self) {
+        let settings = self.settings.clone();
+        self.daily_prices = DailyPrices::load(&settings.price_source);
+        if let Err(e) = self
+            .app_context
+            .update_preferences(|preferences| preferences.accounting_export = settings)
+        {
+            let message = format!("Failed to save the accounting export settings: {}", e);
+            tracing::error!("{}", message);
+            self.display_message(&message, MessageType::Error);
+        }
+    }
+
+    fn render_price_settings(&mut self, ui: &mut Ui) {
+        let before = self.settings.clone();
+        egui::Grid::new("accounting_price_settings")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Fiat currency:");
+                ui.add(egui::TextEdit::singleline(&mut self.settings.currency).desired_width(60.0));
+                ui.end_row();
+
+                ui.label("Price source:");
+                egui::ComboBox::from_id_salt("accounting_price_source")
+                    .selected_text(self.settings.price_source.label())
+                    .show_ui(ui, |ui| {
+                        let fixed = PriceSource::Fixed {
+                            price: self.fixed_price_input.trim().parse().unwrap_or_default(),
+                        };
+                        let daily_file = PriceSource::DailyFile {
+                            path: String::new(),
+                        };
+                        for source in [PriceSource::None, fixed, daily_file] {
+                            let selected = std::mem::discriminant(&source)
+                                == std::mem::discriminant(&self.settings.price_source);
+                            let label = source.label();
+                            if ui.selectable_label(selected, label).clicked() && !selected {
+                                self.settings.price_source = source;
+                            }
+                        }
+                    });
+                ui.end_row();
+
+                match &mut self.settings.price_source {
+                    PriceSource::None => {}
+                    PriceSource::Fixed { price } => {
+                        ui.label(format!("Price of 1 DASH ({}):", self.settings.currency));
+                        if ui
+                            .add(
+                                egui::TextEdit::singleline(&mut self.fixed_price_input)
+                                    .desired_width(100.0),
+                            )
+                            .changed()
+                        {
+                            if let Ok(parsed) = self.fixed_price_input.trim().parse::<f64>() {
+                                *price = parsed;
+                            }
+                        }
+                        ui.end_row();
+                    }
+                    PriceSource::DailyFile { path } => {
+                        ui.label("Price file:");
+                        ui.add(egui::TextEdit::singleline(path).hint_text("prices.csv"))
+                            .on_hover_text(
+                                "A CSV file with the date (YYYY-MM-DD) and the closing price of \
+                                 DASH on each line. Days without a price use the last known one.",
+                            );
+                        ui.end_row();
+                    }
+                }
+            });
+        if self.settings != before {
+            self.save_settings();
+        }
+        if let (PriceSource::DailyFile { path }, Err(e)) =
+            (&self.settings.price_source, &self.daily_prices)
+        {
+            if !path.is_empty() {
+                ui.colored_label(Color32::RED, e);
+            }
+        }
+    }
+
+    fn render_operations(&self, ui: &mut Ui, operations: &[CreditOperation]) {
+        let daily_prices = self.daily_prices.clone().unwrap_or_default();
+        let show_fiat = self.settings.price_source != PriceSource::None;
+        egui::Grid::new("accounting_operations")
+            .num_columns(if show_fiat { 7 } else { 6 })
+            .spacing([12.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for header in ["Date (UTC)", "Identity", "Operation", "Description"] {
+                    ui.label(RichText::new(header).strong());
+                }
+                ui.label(RichText::new("Amount (DASH)").strong());
+                ui.label(RichText::new("Fee (DASH)").strong());
+                if show_fiat {
+                    ui.label(RichText::new(format!("Value ({})", self.settings.currency)).strong());
+                }
+                ui.end_row();
+
+                for operation in operations.iter().rev() {
+                    let sign = if operation.kind.is_incoming() {
+                        1.0
+                    } else {
+                        -1.0
+                    };
+                    let amount = sign * operation.credits as f64 / CREDITS_PER_DASH;
+                    ui.label(operation.occurred_at().format("%Y-%m-%d %H:%M").to_string());
+                    ui.label(self.identity_name(&operation.identity_id));
+                    ui.label(operation.kind.label());
+                    ui.label(&operation.description);
+                    ui.label(format!("{:+.8}", amount));
+                    ui.label(format!("{:.8}", operation.fee as f64 / CREDITS_PER_DASH));
+                    if show_fiat {
+                        match price_at(&self.settings.price_source, &daily_prices, operation) {
+                            Some(price) => ui.label(format!("{:+.2}", amount * price)),
+                            None => ui.label("no price"),
+                        };
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn export_csv(&mut self) {
+        let daily_prices = match &self.daily_prices {
+            Ok(daily_prices) => daily_prices.clone(),
+            Err(e) => {
+                self.display_message(e, MessageType::Error);
+                return;
+            }
+        };
+        let operations = self.filtered_operations();
+        let csv = operations_to_csv(
+            &operations,
+            |identity_id| self.identity_name(identity_id),
+            &self.settings,
+            &daily_prices,
+        );
+        let path = self.export_path_input.trim().to_string();
+        match std::fs::write(&path, csv) {
+            Ok(()) => self.display_message(
+                &format!("Exported {} operations to {}", operations.len(), path),
+                MessageType::Success,
+            ),
+            Err(e) => self.display_message(
+                &format!("Failed to write {}: {}", path, e),
+                MessageType::Error,
+            ),
+        }
+    }
+}
+
+impl ScreenLike for AccountingExportScreen {
+    fn refresh(&mut self) {
+        let app_context = &self.app_context;
+        self.identity_names = app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|qualified_identity| {
+                (
+                    qualified_identity.identity.id(),
+                    qualified_identity.display_short_string(),
+                )
+            })
+            .collect();
+        self.operations = app_context
+            .db
+            .get_credit_operations(app_context)
+            .unwrap_or_default();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+
+        let action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Identities", AppAction::GoToMainScreen),
+                ("Accounting", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading("Accounting Export");
+                ui.label(
+                    RichText::new(
+                        "Registrations, top ups, withdrawals and fees of your identities, as \
+                         recorded when this tool ran them.",
+                    )
+                    .color(Color32::GRAY),
+                );
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => Color32::GRAY,
+                    };
+                    ui.colored_label(color, message);
+                }
+                ui.add_space(10.0);
+                self.render_price_settings(ui);
+                ui.separator();
+
+                let years: BTreeSet<i32> = self
+                    .operations
+                    .iter()
+                    .map(|operation| operation.occurred_at().year())
+                    .collect();
+                ui.horizontal(|ui| {
+                    ui.label("Year:");
+                    egui::ComboBox::from_id_salt("accounting_year")
+                        .selected_text(
+                            self.selected_year
+                                .map_or("All years".to_string(), |year| year.to_string()),
+                        )
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.selected_year, None, "All years");
+                            for year in years.iter().rev() {
+                                ui.selectable_value(
+                                    &mut self.selected_year,
+                                    Some(*year),
+                                    year.to_string(),
+                                );
+                            }
+                        });
+                });
+
+                let operations = self.filtered_operations();
+                let (incoming, outgoing, fees) =
+                    operations
+                        .iter()
+                        .fold((0, 0, 0), |(incoming, outgoing, fees), operation| {
+                            if operation.kind.is_incoming() {
+                                (incoming + operation.credits, outgoing, fees + operation.fee)
+                            } else {
+                                (incoming, outgoing + operation.credits, fees + operation.fee)
+                            }
+                        });
+                ui.label(format!(
+                    "{} operations: {:.8} DASH in, {:.8} DASH out, {:.8} DASH in fees",
+                    operations.len(),
+                    incoming as f64 / CREDITS_PER_DASH,
+                    outgoing as f64 / CREDITS_PER_DASH,
+                    fees as f64 / CREDITS_PER_DASH
+                ));
+                if let Some((value, source)) = fiat_value(&self.app_context, fees) {
+                    ui.label(
+                        RichText::new(format!("Fees at today's price: {}", value))
+                            .color(Color32::GRAY),
+                    )
+                    .on_hover_text(source);
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("CSV file:");
+                    ui.text_edit_singleline(&mut self.export_path_input);
+                    if ui
+                        .add_enabled(!operations.is_empty(), egui::Button::new("Export CSV"))
+                        .clicked()
+                    {
+                        self.export_csv();
+                    }
+                });
+                ui.separator();
+
+                if operations.is_empty() {
+                    ui.label("No credit operations recorded yet.");
+                } else {
+                    self.render_operations(ui, &operations);
+                }
+            });
+        });
+
+        action
+    }
+}
@@ -3,11 +3,13 @@ use crate::context::AppContext;
 use crate::model::qualified_identity::IdentityType;
 use crate::platform::identity::{IdentityInputToLoad, IdentityTask};
 use crate::platform::BackendTask;
+use crate::ui::components::accessibility::with_accessible_label;
+use crate::ui::components::form_drafts::FormDrafts;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::{MessageType, ScreenLike};
 use dash_sdk::dashcore_rpc::dashcore::Network;
 use dash_sdk::dpp::identity::TimestampMillis;
-use eframe::egui::Context;
+use eframe::egui::{Context, WidgetType};
 use rand::prelude::IteratorRandom;
 use rand::thread_rng;
 use serde::Deserialize;
@@ -88,6 +90,8 @@ pub struct AddExistingIdentityScreen {
     keys_input: Vec<String>,
     add_identity_status: AddIdentityStatus,
     testnet_loaded_nodes: Option<TestnetNodes>,
+    /// Drafts of the identity id, type and alias, private keys are never saved
+    drafts: FormDrafts,
     pub app_context: Arc<AppContext>,
 }
 
@@ -108,6 +112,7 @@ impl AddExistingIdentityScreen {
             keys_input: vec![String::new()],
             add_identity_status: AddIdentityStatus::NotStarted,
             testnet_loaded_nodes,
+            drafts: FormDrafts::new("identity_import", app_context),
             app_context: app_context.clone(),
         }
     }
@@ -129,6 +134,35 @@ impl AddExistingIdentityScreen {
         });
     }
 
+    /// The draft of the form without the private keys, `None` while nothing was entered
+    fn draft_content(&self) -> Option<String> {
+        if self.identity_id_input.trim().is_empty() && self.alias_input.trim().is_empty() {
+            return None;
+        }
+        Some(
+            serde_json::json!({
+                "identity_id": self.identity_id_input,
+                "identity_type": self.identity_type.to_string(),
+                "alias": self.alias_input,
+            })
+            .to_string(),
+        )
+    }
+
+    fn restore_draft(&mut self, content: &str) {
+        let Ok(draft) = serde_json::from_str::<serde_json::Value>(content) else {
+            return;
+        };
+        let text = |key: &str| draft[key].as_str().unwrap_or_default().to_string();
+        self.identity_id_input = text("identity_id");
+        self.alias_input = text("alias");
+        self.identity_type = match draft["identity_type"].as_str() {
+            Some("Masternode") => IdentityType::Masternode,
+            Some("Evonode") => IdentityType::Evonode,
+            _ => IdentityType::User,
+        };
+    }
+
     fn render_keys_input(&mut self, ui: &mut egui::Ui) {
         match self.identity_type {
             IdentityType::Masternode | IdentityType::Evonode => {
@@ -161,7 +195,12 @@ impl AddExistingIdentityScreen {
                     ui.horizontal(|ui| {
                         ui.label(format!("Private Key {} (Hex or WIF):", i + 1));
                         ui.text_edit_singleline(key);
-                        if ui.button("-").clicked() {
+                        let remove = with_accessible_label(
+                            ui.button("-"),
+                            WidgetType::Button,
+                            format!("Remove private key {}", i + 1),
+                        );
+                        if remove.clicked() {
                             keys_to_remove.push(i);
                         }
                     });
@@ -254,6 +293,10 @@ impl ScreenLike for AddExistingIdentityScreen {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Add Identity");
 
+            if let Some(content) = self.drafts.show(ui, &self.app_context) {
+                self.restore_draft(&content);
+            }
+
             if self.app_context.network == Network::Testnet && self.testnet_loaded_nodes.is_some() {
                 if ui.button("Fill Random HPMN").clicked() {
                     self.fill_random_hpmn();
@@ -325,11 +368,22 @@ impl ScreenLike for AddExistingIdentityScreen {
                     ui.label(format!("Error: {}", msg));
                 }
                 AddIdentityStatus::Complete => {
+                    self.drafts.discard(&self.app_context);
                     action = AppAction::PopScreenAndRefresh;
                 }
             }
         });
 
+        if !matches!(self.add_identity_status, AddIdentityStatus::Complete) {
+            let title = if self.alias_input.trim().is_empty() {
+                self.identity_id_input.trim().to_string()
+            } else {
+                self.alias_input.trim().to_string()
+            };
+            let content = self.draft_content();
+            self.drafts.autosave(&title, content, &self.app_context);
+        }
+
         action
     }
 }
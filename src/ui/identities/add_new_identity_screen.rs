@@ -1,13 +1,17 @@
 use crate::app::AppAction;
 use crate::context::AppContext;
+use crate::model::external_funding::parse_outpoints;
+use crate::model::fee::FeeChoice;
 use crate::model::wallet::Wallet;
 use crate::platform::identity::{IdentityKeys, IdentityRegistrationInfo, IdentityTask};
 use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::accessibility::with_accessible_label;
+use crate::ui::components::fee_selector::{FeePurpose, FeeSelector};
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::identities::add_new_identity_screen::AddNewIdentityScreenStep::{
     ChooseFundingMethod, FundsReceived, ReadyToCreate,
 };
-use crate::ui::ScreenLike;
+use crate::ui::{MessageType, ScreenLike};
 use arboard::Clipboard;
 use dash_sdk::dashcore_rpc::dashcore::Address;
 use dash_sdk::dashcore_rpc::RpcApi;
@@ -15,11 +19,12 @@ use dash_sdk::dpp::balances::credits::Duffs;
 use dash_sdk::dpp::dashcore::PrivateKey;
 use dash_sdk::dpp::identity::{KeyType, Purpose, SecurityLevel};
 use eframe::egui::Context;
-use egui::{Color32, ColorImage, ComboBox, TextureHandle, Ui};
+use egui::{Color32, ColorImage, ComboBox, TextureHandle, Ui, WidgetType};
 use image::Luma;
 use qrcode::QrCode;
 use serde::Deserialize;
 use std::cmp::PartialEq;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -38,6 +43,8 @@ pub enum FundingMethod {
     UseWalletBalance,
     AddressWithQRCode,
     AttachedCoreWallet,
+    /// Outputs of a wallet on another machine, signed there as a PSBT
+    ExternalWallet,
 }
 
 impl fmt::Display for FundingMethod {
@@ -47,6 +54,7 @@ impl fmt::Display for FundingMethod {
             FundingMethod::AddressWithQRCode => "Address with QR Code",
             FundingMethod::AttachedCoreWallet => "Attached Core Wallet",
             FundingMethod::UseWalletBalance => "Use Wallet Balance",
+            FundingMethod::ExternalWallet => "External Wallet (PSBT)",
         };
         write!(f, "{}", output)
     }
@@ -75,11 +83,23 @@ pub struct AddNewIdentityScreen {
     copied_to_clipboard: Option<Option<String>>,
     identity_keys: IdentityKeys,
     balance_check_handle: Option<(Arc<AtomicBool>, thread::JoinHandle<()>)>,
+    /// Outputs of the external wallet to spend, one `txid:vout` per line
+    external_outpoints_input: String,
+    external_change_address_input: String,
+    /// The exported unsigned asset lock, in base64
+    external_psbt: Option<String>,
+    external_psbt_path_input: String,
+    /// The signed PSBT or raw transaction returned by the external wallet
+    external_signed_input: String,
+    external_preparing: bool,
+    external_message: Option<String>,
+    /// Fee of the asset lock funded from the wallet
+    fee: FeeSelector,
     pub app_context: Arc<AppContext>,
 }
 
 // Function to generate a QR code image from the address
-fn generate_qr_code_image(pay_uri: &str) -> Result<ColorImage, qrcode::types::QrError> {
+pub(crate) fn generate_qr_code_image(pay_uri: &str) -> Result<ColorImage, qrcode::types::QrError> {
     // Generate the QR code
     let code = QrCode::new(pay_uri.as_bytes())?;
 
@@ -127,6 +147,14 @@ impl AddNewIdentityScreen {
                 keys_input: vec![],
             },
             balance_check_handle: None,
+            external_outpoints_input: String::new(),
+            external_change_address_input: String::new(),
+            external_psbt: None,
+            external_psbt_path_input: "asset-lock.psbt".to_string(),
+            external_signed_input: String::new(),
+            external_preparing: false,
+            external_message: None,
+            fee: FeeSelector::new(app_context, FeePurpose::AssetLock),
             app_context: app_context.clone(),
         }
     }
@@ -419,6 +447,11 @@ impl AddNewIdentityScreen {
                     FundingMethod::AttachedCoreWallet,
                     "Attached Core Wallet",
                 );
+                ui.selectable_value(
+                    &mut *funding_method,
+                    FundingMethod::ExternalWallet,
+                    "External Wallet (PSBT)",
+                );
             });
     }
 
@@ -474,7 +507,12 @@ impl AddNewIdentityScreen {
                         }
                     });
 
-                if ui.button("-").clicked() {
+                let remove = with_accessible_label(
+                    ui.button("-"),
+                    WidgetType::Button,
+                    format!("Remove key {}", i + 1),
+                );
+                if remove.clicked() {
                     keys_to_remove.push(i);
                 }
             });
@@ -491,12 +529,17 @@ impl AddNewIdentityScreen {
         }
     }
 
+    /// The funding amount in duffs, 0 if it isn't a number
+    fn funding_amount_duffs(&self) -> Duffs {
+        self.funding_amount_exact.unwrap_or_else(|| {
+            (self.funding_amount.parse::<f64>().unwrap_or_else(|_| 0.0) * 1e8) as u64
+        })
+    }
+
     fn register_identity_clicked(&mut self) -> AppAction {
         if self.identity_keys.master_private_key.is_some() {
             // Parse the funding amount or fall back to the default value
-            let amount = self.funding_amount_exact.unwrap_or_else(|| {
-                (self.funding_amount.parse::<f64>().unwrap_or_else(|_| 0.0) * 1e8) as u64
-            });
+            let amount = self.funding_amount_duffs();
 
             if amount == 0 {
                 return AppAction::None;
@@ -509,6 +552,7 @@ impl AddNewIdentityScreen {
                     keys: self.identity_keys.clone(),
                     identity_index: 0, // Default index, modify if needed
                     wallet: Arc::clone(selected_wallet), // Clone the Arc reference
+                    fee: self.fee.choice,
                 };
 
                 let mut step = self.step.write().unwrap();
@@ -620,6 +664,174 @@ impl AddNewIdentityScreen {
         }
     }
 
+    fn external_registration_info(&self) -> Result<IdentityRegistrationInfo, String> {
+        let wallet = self
+            .selected_wallet
+            .as_ref()
+            .ok_or("No wallet selected".to_string())?;
+        if self.identity_keys.master_private_key.is_none() {
+            return Err("Choose an identity index to derive the keys".to_string());
+        }
+        let amount = self.funding_amount_duffs();
+        if amount == 0 {
+            return Err("Enter the amount to lock".to_string());
+        }
+        Ok(IdentityRegistrationInfo {
+            alias_input: self.alias_input.clone(),
+            amount,
+            keys: self.identity_keys.clone(),
+            identity_index: self.identity_id_number,
+            wallet: Arc::clone(wallet),
+            // The external wallet's PSBT is built with the fixed fee of external funding
+            fee: FeeChoice::default(),
+        })
+    }
+
+    fn export_psbt_clicked(&mut self) -> Result<AppAction, String> {
+        let registration_info = self.external_registration_info()?;
+        let outpoints = parse_outpoints(&self.external_outpoints_input)?;
+        let change_address = Address::from_str(self.external_change_address_input.trim())
+            .map_err(|e| format!("Invalid change address: {}", e))?
+            .require_network(self.app_context.network)
+            .map_err(|_| "The change address is for another network".to_string())?;
+        self.external_preparing = true;
+        Ok(AppAction::BackendTask(BackendTask::IdentityTask(
+            IdentityTask::PrepareExternalAssetLock(registration_info, outpoints, change_address),
+        )))
+    }
+
+    fn create_with_signed_psbt_clicked(&mut self, psbt: String) -> Result<AppAction, String> {
+        let registration_info = self.external_registration_info()?;
+        if self.external_signed_input.trim().is_empty() {
+            return Err("Paste the signed PSBT or transaction".to_string());
+        }
+        *self.step.write().unwrap() = AddNewIdentityScreenStep::WaitingForAssetLock;
+        Ok(AppAction::BackendTask(BackendTask::IdentityTask(
+            IdentityTask::RegisterIdentityWithSignedAssetLock(
+                registration_info,
+                psbt,
+                self.external_signed_input.trim().to_string(),
+            ),
+        )))
+    }
+
+    /// Funding from a wallet on another machine: the asset lock is exported as a PSBT, signed
+    /// by the external wallet and imported back, its keys never reach the tool
+    fn render_external_funding(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        let step = *self.step.read().unwrap();
+
+        ui.heading("3. Enter the outputs of your external wallet to spend.");
+        ui.label("One txid:vout per line:");
+        ui.add(
+            egui::TextEdit::multiline(&mut self.external_outpoints_input)
+                .desired_rows(3)
+                .desired_width(ui.available_width())
+                .code_editor(),
+        );
+        ui.horizontal(|ui| {
+            ui.label("Change Address:");
+            ui.text_edit_singleline(&mut self.external_change_address_input);
+        });
+
+        ui.add_space(10.0);
+        ui.heading(
+            "4. Choose an identity index. Leave this 0 if this is your first identity for this wallet.",
+        );
+        if self.identity_keys.master_private_key.is_none() {
+            self.update_identity_key();
+        }
+        self.render_identity_index_input(ui);
+        ui.add_space(10.0);
+        if let Some(key) = self.identity_keys.master_private_key {
+            self.render_master_key(ui, key);
+        }
+        self.render_keys_input(ui);
+
+        ui.add_space(10.0);
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.external_preparing, egui::Button::new("Export PSBT"))
+                .clicked()
+            {
+                match self.export_psbt_clicked() {
+                    Ok(export) => {
+                        self.external_message = None;
+                        action = export;
+                    }
+                    Err(e) => self.external_message = Some(e),
+                }
+            }
+            if self.external_preparing {
+                ui.spinner();
+            }
+        });
+
+        if let Some(psbt) = self.external_psbt.clone() {
+            ui.add_space(10.0);
+            ui.heading("5. Sign the PSBT with your external wallet and paste the result.");
+            let mut shown = psbt.as_str();
+            ui.add(
+                egui::TextEdit::multiline(&mut shown)
+                    .desired_rows(3)
+                    .desired_width(ui.available_width())
+                    .code_editor(),
+            );
+            ui.horizontal(|ui| {
+                if ui.button("Copy PSBT").clicked() {
+                    if let Err(e) = copy_to_clipboard(&psbt) {
+                        self.external_message = Some(format!("Failed to copy to clipboard: {}", e));
+                    }
+                }
+                ui.label("Save to:");
+                ui.text_edit_singleline(&mut self.external_psbt_path_input);
+                if ui.button("Save File").clicked() {
+                    let path = self.external_psbt_path_input.trim().to_string();
+                    self.external_message = Some(match std::fs::write(&path, &psbt) {
+                        Ok(()) => format!("Saved to {}", path),
+                        Err(e) => format!("Failed to write {}: {}", path, e),
+                    });
+                }
+            });
+            ui.label("Signed PSBT in base64, or the signed transaction in hex:");
+            ui.add(
+                egui::TextEdit::multiline(&mut self.external_signed_input)
+                    .desired_rows(3)
+                    .desired_width(ui.available_width())
+                    .code_editor(),
+            );
+            if ui
+                .add_enabled(
+                    step == ChooseFundingMethod,
+                    egui::Button::new("Create Identity"),
+                )
+                .clicked()
+            {
+                match self.create_with_signed_psbt_clicked(psbt) {
+                    Ok(register) => {
+                        self.external_message = None;
+                        action = register;
+                    }
+                    Err(e) => self.external_message = Some(e),
+                }
+            }
+        }
+
+        if let Some(message) = &self.external_message {
+            ui.colored_label(Color32::RED, message);
+        }
+        action
+    }
+
+    /// What the asset lock is waited for, as set in the settings
+    fn render_finality_policy(&self, ui: &mut egui::Ui) {
+        let policy = self.app_context.preferences().finality.asset_locks;
+        ui.label(format!(
+            "The asset lock is used once it has {}, as set in the settings.",
+            policy.describe()
+        ));
+    }
+
     fn render_master_key(&mut self, ui: &mut egui::Ui, key: PrivateKey) {
         ui.horizontal(|ui| {
             ui.label("Master Private Key:");
@@ -644,7 +856,26 @@ impl AddNewIdentityScreen {
 }
 
 impl ScreenLike for AddNewIdentityScreen {
-    fn display_task_result(&mut self, _backend_task_success_result: BackendTaskSuccessResult) {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if *self.funding_method.read().unwrap() == FundingMethod::ExternalWallet
+            && message_type == MessageType::Error
+        {
+            self.external_preparing = false;
+            *self.step.write().unwrap() = ChooseFundingMethod;
+            self.external_message = Some(message.to_string());
+        }
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if self.fee.take_estimates(&backend_task_success_result) {
+            return;
+        }
+        if let BackendTaskSuccessResult::UnsignedAssetLock(psbt) = backend_task_success_result {
+            self.external_preparing = false;
+            self.external_signed_input.clear();
+            self.external_psbt = Some(psbt);
+            return;
+        }
         let mut step = self.step.write().unwrap();
         *step = AddNewIdentityScreenStep::WaitingForPlatformAcceptance;
     }
@@ -686,6 +917,24 @@ impl ScreenLike for AddNewIdentityScreen {
                 self.render_funding_amount_input(ui);
             }
 
+            if funding_method == FundingMethod::ExternalWallet {
+                ui.heading("2. How much would you like to lock for your new identity?");
+                self.render_funding_amount_input(ui);
+                ui.add_space(10.0);
+                action |= self.render_external_funding(ui);
+                match *self.step.read().unwrap() {
+                    AddNewIdentityScreenStep::WaitingForAssetLock => {
+                        ui.heading("Waiting for Asset Lock");
+                        self.render_finality_policy(ui);
+                    }
+                    AddNewIdentityScreenStep::WaitingForPlatformAcceptance => {
+                        ui.heading("Waiting for Platform Acknowledgement");
+                    }
+                    _ => {}
+                }
+                return;
+            }
+
             if funding_method == FundingMethod::UseWalletBalance {
                 self.show_wallet_balance(ui);
 
@@ -727,6 +976,10 @@ impl ScreenLike for AddNewIdentityScreen {
 
             self.render_keys_input(ui);
 
+            ui.add_space(10.0);
+            action |= self.fee.show(ui);
+            ui.add_space(10.0);
+
             if step == ReadyToCreate || funding_method == FundingMethod::UseWalletBalance {
                 if ui.button("Create Identity").clicked() {
                     action = self.register_identity_clicked();
@@ -735,6 +988,7 @@ impl ScreenLike for AddNewIdentityScreen {
 
             if step == AddNewIdentityScreenStep::WaitingForAssetLock {
                 ui.heading("Waiting for Asset Lock");
+                self.render_finality_policy(ui);
             }
 
             if step == AddNewIdentityScreenStep::WaitingForPlatformAcceptance {
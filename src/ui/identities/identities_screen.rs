@@ -1,60 +1,168 @@
-use crate::app::{AppAction, DesiredAppAction};
+use crate::app::{AppAction, AppEvent, DesiredAppAction};
 use crate::context::AppContext;
+use crate::model::balance_alert::BalanceAlerts;
 use crate::model::qualified_identity::EncryptedPrivateKeyTarget::{
     PrivateKeyOnMainIdentity, PrivateKeyOnVoterIdentity,
 };
-use crate::model::qualified_identity::{IdentityType, QualifiedIdentity};
+use crate::model::qualified_identity::{IdentityGroup, IdentityType, QualifiedIdentity};
+use crate::platform::identity::IdentityTask;
+use crate::platform::BackendTask;
 use crate::ui::add_key_screen::AddKeyScreen;
+use crate::ui::components::explorer_link::{explorer_menu_button, ExplorerItem};
+use crate::ui::components::fiat_value::fiat_value;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::display_scale::{apply_table_density, table_heights};
+use crate::ui::helpers::number_format::NumberFormatter;
+use crate::ui::helpers::redaction::redact_id;
+use crate::ui::identities::identity_detail_screen::IdentityDetailScreen;
+use crate::ui::identities::key_rotation_screen::KeyRotationScreen;
+use crate::ui::identities::top_up_identity_screen::TopUpIdentityScreen;
 use crate::ui::key_info_screen::KeyInfoScreen;
 use crate::ui::transfers::TransferScreen;
 use crate::ui::withdrawals::WithdrawalScreen;
-use crate::ui::{RootScreenType, Screen, ScreenLike, ScreenType};
+use crate::ui::{MessageType, RootScreenType, Screen, ScreenLike, ScreenType};
+use chrono::{DateTime, Utc};
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
 use dash_sdk::dpp::identity::Purpose;
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::dpp::prelude::IdentityPublicKey;
+use dash_sdk::platform::Identifier;
 use eframe::egui::{self, Context};
 use eframe::emath::Align;
 use egui::{Color32, Frame, Margin, RichText, Ui};
 use egui_extras::{Column, TableBuilder};
+use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
 
 pub struct IdentitiesScreen {
     pub identities: Arc<Mutex<Vec<QualifiedIdentity>>>,
+    /// Groups the user moved identities to, the others are in their default group
+    groups: HashMap<Identifier, IdentityGroup>,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
     pub app_context: Arc<AppContext>,
 }
 
+/// A requested move of an identity to a group, `None` moves it back to its default group
+type GroupChange = (Identifier, Option<IdentityGroup>);
+
+fn group_color(group: IdentityGroup) -> Color32 {
+    match group {
+        IdentityGroup::User => Color32::from_rgb(100, 149, 237),
+        IdentityGroup::MasternodeVoting => Color32::from_rgb(46, 160, 67),
+        IdentityGroup::Evonode => Color32::from_rgb(156, 39, 176),
+        IdentityGroup::WatchOnly => Color32::from_rgb(169, 169, 169),
+    }
+}
+
 impl IdentitiesScreen {
     fn show_alias(ui: &mut Ui, qualified_identity: &QualifiedIdentity) {
         if let Some(alias) = qualified_identity.alias.as_ref() {
             ui.label(alias.clone());
         }
     }
-    fn show_identity_id(ui: &mut Ui, qualified_identity: &QualifiedIdentity) {
+    fn show_identity_id(
+        &self,
+        ui: &mut Ui,
+        qualified_identity: &QualifiedIdentity,
+        group_change: &mut Option<GroupChange>,
+        privacy_mode: bool,
+    ) -> AppAction {
         let (encoding, helper) = match qualified_identity.identity_type {
             IdentityType::User => (Encoding::Base58, "UserId".to_string()),
             IdentityType::Masternode | IdentityType::Evonode => {
                 (Encoding::Hex, "ProTxHash".to_string())
             }
         };
-        let identifier_as_string = qualified_identity.identity.id().to_string(encoding);
-        ui.add(egui::Label::new(identifier_as_string).sense(egui::Sense::hover()))
-            .on_hover_text(helper);
+        let identifier_as_string = redact_id(
+            &qualified_identity.identity.id().to_string(encoding),
+            privacy_mode,
+        );
+        let response = ui
+            .link(identifier_as_string)
+            .on_hover_text(format!("{}, click to view details", helper));
+        response.context_menu(|ui| {
+            let identity_id = qualified_identity.identity.id();
+            let current_group = self.group_of(qualified_identity);
+            for group in IdentityGroup::ALL {
+                if group != current_group && ui.button(format!("Move to {}", group)).clicked() {
+                    *group_change = Some((identity_id, Some(group)));
+                    ui.close_menu();
+                }
+            }
+            if self.groups.contains_key(&identity_id)
+                && ui.button("Move to default group").clicked()
+            {
+                *group_change = Some((identity_id, None));
+                ui.close_menu();
+            }
+            ui.separator();
+            explorer_menu_button(
+                ui,
+                &self.app_context,
+                "Open in explorer",
+                ExplorerItem::Identity(identity_id),
+            );
+            if matches!(
+                qualified_identity.identity_type,
+                IdentityType::Masternode | IdentityType::Evonode
+            ) {
+                // A masternode identity id is the txid of the ProTx that registered it
+                explorer_menu_button(
+                    ui,
+                    &self.app_context,
+                    "Open ProTx in block explorer",
+                    ExplorerItem::Transaction(identity_id.to_string(Encoding::Hex)),
+                );
+            }
+        });
+        if response.clicked() {
+            AppAction::AddScreen(Screen::IdentityDetailScreen(IdentityDetailScreen::new(
+                qualified_identity.identity.id(),
+                &self.app_context,
+            )))
+        } else {
+            AppAction::None
+        }
     }
-    fn show_balance(ui: &mut Ui, qualified_identity: &QualifiedIdentity) {
-        // Calculate the balance in DASH (10^-11 conversion)
-        let balance_in_dash = qualified_identity.identity.balance() as f64 * 1e-11;
+    /// Shows the balance, flagged when it is below the identity's minimum. Returns whether it is.
+    fn show_balance(
+        ui: &mut Ui,
+        qualified_identity: &QualifiedIdentity,
+        number_format: &NumberFormatter,
+        balance_alerts: &BalanceAlerts,
+        app_context: &AppContext,
+    ) -> bool {
+        let balance = qualified_identity.identity.balance();
 
-        // Format the balance with 4 decimal places
-        let formatted_balance = format!("{:.4} DASH", balance_in_dash);
+        // Format the balance in DASH with 4 decimal places
+        let formatted_balance = number_format.format_credits_as_dash(balance);
+        let mut credits = number_format.format_credits(balance);
+        if let Some((value, _)) = fiat_value(app_context, balance) {
+            credits = format!("{}, {}", credits, value);
+        }
 
-        // Add the label with hover text
-        ui.add(egui::Label::new(formatted_balance).sense(egui::Sense::hover()))
-            .on_hover_text(format!("{}", qualified_identity.identity.balance()));
+        let identity_id = qualified_identity.identity.id().to_string(Encoding::Base58);
+        match balance_alerts.shortfall_minimum(&identity_id, balance) {
+            Some(minimum) => {
+                let text = RichText::new(format!("⚠ {}", formatted_balance)).color(Color32::RED);
+                ui.add(egui::Label::new(text).sense(egui::Sense::hover()))
+                    .on_hover_text(format!(
+                        "{}, below the minimum of {}",
+                        credits,
+                        number_format.format_credits_as_dash(minimum)
+                    ));
+                true
+            }
+            None => {
+                // Add the label with hover text
+                ui.add(egui::Label::new(formatted_balance).sense(egui::Sense::hover()))
+                    .on_hover_text(credits);
+                false
+            }
+        }
     }
 
     fn show_public_key(
@@ -160,81 +268,204 @@ impl IdentitiesScreen {
             });
     }
 
-    fn render_identities_view(&self, ui: &mut Ui, identities: &[QualifiedIdentity]) -> AppAction {
+    fn group_of(&self, qualified_identity: &QualifiedIdentity) -> IdentityGroup {
+        self.groups
+            .get(&qualified_identity.identity.id())
+            .copied()
+            .unwrap_or_else(|| qualified_identity.default_group())
+    }
+
+    fn render_identities_view(
+        &self,
+        ui: &mut Ui,
+        identities: &[QualifiedIdentity],
+        group_change: &mut Option<GroupChange>,
+    ) -> AppAction {
         let mut action = AppAction::None;
+        let number_format = NumberFormatter::new(&self.app_context.preferences());
 
         egui::ScrollArea::vertical().show(ui, |ui| {
-            // Define a frame with custom background color and border
-            Frame::group(ui.style())
-                .fill(ui.visuals().panel_fill) // Use panel fill color
-                .stroke(egui::Stroke::new(
-                    1.0,
-                    ui.visuals().widgets.inactive.bg_stroke.color,
-                ))
-                .inner_margin(Margin::same(8.0))
-                .show(ui, |ui| {
-                    // Build the table
-                    TableBuilder::new(ui)
-                        .striped(true)
-                        .resizable(true)
-                        .cell_layout(egui::Layout::left_to_right(Align::Center))
-                        // Define columns with resizing and alignment
-                        .column(Column::initial(40.0).resizable(true)) // Name
-                        .column(Column::initial(200.0).resizable(true)) // Identity ID
-                        .column(Column::initial(100.0).resizable(true)) // Balance
-                        .column(Column::initial(100.0).resizable(true)) // Type
-                        .column(Column::initial(80.0).resizable(true)) // Keys
-                        .column(Column::initial(80.0).resizable(true)) // Withdraw
-                        // .column(Column::initial(80.0).resizable(true)) // Transfer
-                        .header(30.0, |mut header| {
-                            header.col(|ui| {
-                                ui.heading("Name");
-                            });
-                            header.col(|ui| {
-                                ui.heading("Identity ID");
-                            });
-                            header.col(|ui| {
-                                ui.heading("Balance");
-                            });
-                            header.col(|ui| {
-                                ui.heading("Type");
-                            });
-                            header.col(|ui| {
-                                ui.heading("Keys");
-                            });
-                            header.col(|ui| {
-                                ui.heading("Withdraw");
-                            });
-                            // header.col(|ui| {
-                            //     ui.heading("Transfer");
-                            // });
-                        })
-                        .body(|mut body| {
-                            for qualified_identity in identities.iter() {
-                                let identity = &qualified_identity.identity;
-                                let public_keys = identity.public_keys();
-                                let voter_identity_public_keys = qualified_identity
-                                    .associated_voter_identity
-                                    .as_ref()
-                                    .map(|(identity, _)| identity.public_keys());
-                                body.row(25.0, |mut row| {
-                                    row.col(|ui| {
-                                        Self::show_alias(ui, qualified_identity);
-                                    });
-                                    row.col(|ui| {
-                                        Self::show_identity_id(ui, qualified_identity);
-                                    });
-                                    row.col(|ui| {
-                                        Self::show_balance(ui, qualified_identity);
-                                    });
-                                    row.col(|ui| {
-                                        ui.label(format!("{}", qualified_identity.identity_type));
-                                    });
-                                    row.col(|ui| {
-                                        for (key_id, key) in public_keys.iter() {
+            for group in IdentityGroup::ALL {
+                let members: Vec<&QualifiedIdentity> = identities
+                    .iter()
+                    .filter(|qualified_identity| self.group_of(qualified_identity) == group)
+                    .collect();
+                if members.is_empty() {
+                    continue;
+                }
+                let title = RichText::new(format!("{} ({})", group, members.len()))
+                    .color(group_color(group))
+                    .strong();
+                egui::CollapsingHeader::new(title)
+                    .id_salt(("identity_group", group))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        action |= self.render_group_actions(ui, group, &members);
+                        ui.push_id(("identity_group_table", group), |ui| {
+                            action |= self.render_identities_table(
+                                ui,
+                                &members,
+                                &number_format,
+                                group_change,
+                            );
+                        });
+                    });
+                ui.add_space(10.0);
+            }
+        });
+
+        action
+    }
+
+    fn render_group_actions(
+        &self,
+        ui: &mut Ui,
+        group: IdentityGroup,
+        members: &[&QualifiedIdentity],
+    ) -> AppAction {
+        let mut action = AppAction::None;
+        ui.horizontal(|ui| {
+            if ui.button("Refresh Balances").clicked() {
+                action = AppAction::BackendTask(BackendTask::IdentityTask(
+                    IdentityTask::RefreshIdentities(
+                        members.iter().map(|member| (*member).clone()).collect(),
+                    ),
+                ));
+            }
+            if group.is_voting()
+                && ui
+                    .button("Vote with Group")
+                    .on_hover_text("Open the contested names, the vote dialog offers this group")
+                    .clicked()
+            {
+                action = AppAction::SetMainScreen(RootScreenType::RootScreenDPNSContestedNames);
+            }
+        });
+        action
+    }
+
+    fn render_identities_table(
+        &self,
+        ui: &mut Ui,
+        identities: &[&QualifiedIdentity],
+        number_format: &NumberFormatter,
+        group_change: &mut Option<GroupChange>,
+    ) -> AppAction {
+        let mut action = AppAction::None;
+        // Define a frame with custom background color and border
+        Frame::group(ui.style())
+            .fill(ui.visuals().panel_fill) // Use panel fill color
+            .stroke(egui::Stroke::new(
+                1.0,
+                ui.visuals().widgets.inactive.bg_stroke.color,
+            ))
+            .inner_margin(Margin::same(8.0))
+            .show(ui, |ui| {
+                let density = self.app_context.preferences().table_density;
+                let preferences = self.app_context.preferences();
+                let balance_alerts = preferences.balance_alerts;
+                let privacy_mode = preferences.privacy_mode;
+                let (header_height, row_height) = table_heights(ui.ctx(), density, 25.0);
+                apply_table_density(ui, density);
+                // Build the table
+                TableBuilder::new(ui)
+                    .striped(true)
+                    .resizable(true)
+                    // The groups scroll together in the outer scroll area
+                    .vscroll(false)
+                    .cell_layout(egui::Layout::left_to_right(Align::Center))
+                    // Define columns with resizing and alignment
+                    .column(Column::initial(40.0).resizable(true)) // Name
+                    .column(Column::initial(200.0).resizable(true)) // Identity ID
+                    .column(Column::initial(100.0).resizable(true)) // Balance
+                    .column(Column::initial(100.0).resizable(true)) // Type
+                    .column(Column::initial(80.0).resizable(true)) // Keys
+                    .column(Column::initial(80.0).resizable(true)) // Withdraw
+                    // .column(Column::initial(80.0).resizable(true)) // Transfer
+                    .header(header_height, |mut header| {
+                        header.col(|ui| {
+                            ui.heading("Name");
+                        });
+                        header.col(|ui| {
+                            ui.heading("Identity ID");
+                        });
+                        header.col(|ui| {
+                            ui.heading("Balance");
+                        });
+                        header.col(|ui| {
+                            ui.heading("Type");
+                        });
+                        header.col(|ui| {
+                            ui.heading("Keys");
+                        });
+                        header.col(|ui| {
+                            ui.heading("Withdraw");
+                        });
+                        // header.col(|ui| {
+                        //     ui.heading("Transfer");
+                        // });
+                    })
+                    .body(|mut body| {
+                        for qualified_identity in identities.iter() {
+                            let identity = &qualified_identity.identity;
+                            let public_keys = identity.public_keys();
+                            let voter_identity_public_keys = qualified_identity
+                                .associated_voter_identity
+                                .as_ref()
+                                .map(|(identity, _)| identity.public_keys());
+                            body.row(row_height, |mut row| {
+                                row.col(|ui| {
+                                    Self::show_alias(ui, qualified_identity);
+                                });
+                                row.col(|ui| {
+                                    action |= self.show_identity_id(
+                                        ui,
+                                        qualified_identity,
+                                        group_change,
+                                        privacy_mode,
+                                    );
+                                });
+                                row.col(|ui| {
+                                    let low = Self::show_balance(
+                                        ui,
+                                        qualified_identity,
+                                        number_format,
+                                        &balance_alerts,
+                                        &self.app_context,
+                                    );
+                                    if low && ui.small_button("Top up").clicked() {
+                                        action = AppAction::AddScreen(Screen::TopUpIdentityScreen(
+                                            TopUpIdentityScreen::new(
+                                                qualified_identity.clone(),
+                                                &self.app_context,
+                                            )
+                                            .with_shortfall_amount(),
+                                        ));
+                                    }
+                                });
+                                row.col(|ui| {
+                                    ui.label(format!("{}", qualified_identity.identity_type));
+                                });
+                                row.col(|ui| {
+                                    for (key_id, key) in public_keys.iter() {
+                                        let holding_private_key = qualified_identity
+                                            .encrypted_private_keys
+                                            .get(&(PrivateKeyOnMainIdentity, *key_id))
+                                            .map(|(_, p)| p);
+                                        action |= self.show_public_key(
+                                            ui,
+                                            qualified_identity,
+                                            key,
+                                            holding_private_key,
+                                        );
+                                    }
+                                    if let Some(voting_identity_public_keys) =
+                                        voter_identity_public_keys
+                                    {
+                                        for (key_id, key) in voting_identity_public_keys.iter() {
                                             let holding_private_key = qualified_identity
                                                 .encrypted_private_keys
-                                                .get(&(PrivateKeyOnMainIdentity, *key_id))
+                                                .get(&(PrivateKeyOnVoterIdentity, *key_id))
                                                 .map(|(_, p)| p);
                                             action |= self.show_public_key(
                                                 ui,
@@ -243,26 +474,9 @@ impl IdentitiesScreen {
                                                 holding_private_key,
                                             );
                                         }
-                                        if let Some(voting_identity_public_keys) =
-                                            voter_identity_public_keys
-                                        {
-                                            for (key_id, key) in voting_identity_public_keys.iter()
-                                            {
-                                                let holding_private_key = qualified_identity
-                                                    .encrypted_private_keys
-                                                    .get(&(PrivateKeyOnVoterIdentity, *key_id))
-                                                    .map(|(_, p)| p);
-                                                action |= self.show_public_key(
-                                                    ui,
-                                                    qualified_identity,
-                                                    key,
-                                                    holding_private_key,
-                                                );
-                                            }
-                                        }
-                                        if qualified_identity.can_sign_with_master_key().is_some()
-                                            && ui.button("Add Key").clicked()
-                                        {
+                                    }
+                                    if qualified_identity.can_sign_with_master_key().is_some() {
+                                        if ui.button("Add Key").clicked() {
                                             action = AppAction::AddScreen(Screen::AddKeyScreen(
                                                 AddKeyScreen::new(
                                                     qualified_identity.clone(),
@@ -270,32 +484,40 @@ impl IdentitiesScreen {
                                                 ),
                                             ));
                                         }
-                                    });
-                                    row.col(|ui| {
-                                        if ui.button("Withdraw").clicked() {
+                                        if ui.button("Rotate Keys").clicked() {
                                             action = AppAction::AddScreen(
-                                                Screen::WithdrawalScreen(WithdrawalScreen::new(
+                                                Screen::KeyRotationScreen(KeyRotationScreen::new(
                                                     qualified_identity.clone(),
                                                     &self.app_context,
                                                 )),
                                             );
                                         }
-                                    });
-                                    // row.col(|ui| {
-                                    //     if ui.button("Transfer").clicked() {
-                                    //         action = AppAction::AddScreen(Screen::TransferScreen(
-                                    //             TransferScreen::new(
-                                    //                 qualified_identity.clone(),
-                                    //                 &self.app_context,
-                                    //             ),
-                                    //         ));
-                                    //     }
-                                    // });
+                                    }
                                 });
-                            }
-                        });
-                });
-        });
+                                row.col(|ui| {
+                                    if ui.button("Withdraw").clicked() {
+                                        action = AppAction::AddScreen(Screen::WithdrawalScreen(
+                                            WithdrawalScreen::new(
+                                                qualified_identity.clone(),
+                                                &self.app_context,
+                                            ),
+                                        ));
+                                    }
+                                });
+                                // row.col(|ui| {
+                                //     if ui.button("Transfer").clicked() {
+                                //         action = AppAction::AddScreen(Screen::TransferScreen(
+                                //             TransferScreen::new(
+                                //                 qualified_identity.clone(),
+                                //                 &self.app_context,
+                                //             ),
+                                //         ));
+                                //     }
+                                // });
+                            });
+                        }
+                    });
+            });
 
         action
     }
@@ -308,6 +530,21 @@ impl ScreenLike for IdentitiesScreen {
             .app_context
             .load_local_qualified_identities()
             .unwrap_or_default();
+        self.groups = self
+            .app_context
+            .db
+            .get_identity_groups(&self.app_context)
+            .unwrap_or_default();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn on_event(&mut self, event: AppEvent) {
+        if matches!(event, AppEvent::IdentityAdded | AppEvent::IdentitiesUpdated) {
+            self.refresh();
+        }
     }
 
     fn ui(&mut self, ctx: &Context) -> AppAction {
@@ -327,6 +564,34 @@ impl ScreenLike for IdentitiesScreen {
             // };
             vec![
                 // create_wallet_or_identity,
+                (
+                    "Withdrawals",
+                    DesiredAppAction::AddScreenType(ScreenType::WithdrawalQueue),
+                ),
+                (
+                    "Accounting",
+                    DesiredAppAction::AddScreenType(ScreenType::AccountingExport),
+                ),
+                (
+                    "Cold Signing",
+                    DesiredAppAction::AddScreenType(ScreenType::ColdSigning),
+                ),
+                (
+                    "Ownership Proofs",
+                    DesiredAppAction::AddScreenType(ScreenType::OwnershipProof),
+                ),
+                (
+                    "Masternodes",
+                    DesiredAppAction::AddScreenType(ScreenType::MasternodeDashboard),
+                ),
+                (
+                    "Import Masternodes",
+                    DesiredAppAction::AddScreenType(ScreenType::ImportMasternodes),
+                ),
+                (
+                    "Import CSV",
+                    DesiredAppAction::AddScreenType(ScreenType::ImportIdentitiesCsv),
+                ),
                 (
                     "Load Identity",
                     DesiredAppAction::AddScreenType(ScreenType::AddExistingIdentity),
@@ -342,16 +607,56 @@ impl ScreenLike for IdentitiesScreen {
 
         action |= add_left_panel(ctx, &self.app_context, RootScreenType::RootScreenIdentities);
 
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+
         let identities = self.identities.lock().unwrap();
+        let mut group_change = None;
 
         // Main content
         egui::CentralPanel::default().show(ctx, |ui| {
+            if let Some((message, message_type, _)) = &self.message {
+                let color = match message_type {
+                    MessageType::Error => Color32::RED,
+                    MessageType::Success => Color32::DARK_GREEN,
+                    MessageType::Info => ui.visuals().text_color(),
+                };
+                ui.colored_label(color, message);
+            }
             if identities.is_empty() {
                 self.render_no_identities_view(ui);
             } else {
-                action |= self.render_identities_view(ui, identities.as_slice());
+                action |= self.render_identities_view(ui, identities.as_slice(), &mut group_change);
             }
         });
+        drop(identities);
+
+        if let Some((identity_id, group)) = group_change {
+            match self
+                .app_context
+                .db
+                .set_identity_group(&identity_id, group, &self.app_context)
+            {
+                Ok(()) => {
+                    match group {
+                        Some(group) => self.groups.insert(identity_id, group),
+                        None => self.groups.remove(&identity_id),
+                    };
+                    self.app_context.publish(AppEvent::IdentitiesUpdated);
+                }
+                Err(e) => {
+                    self.message = Some((
+                        format!("Failed to move identity: {}", e),
+                        MessageType::Error,
+                        Utc::now(),
+                    ));
+                }
+            }
+        }
 
         action
     }
@@ -364,8 +669,14 @@ impl IdentitiesScreen {
                 .load_local_qualified_identities()
                 .unwrap_or_default(),
         ));
+        let groups = app_context
+            .db
+            .get_identity_groups(app_context)
+            .unwrap_or_default();
         Self {
             identities,
+            groups,
+            message: None,
             app_context: app_context.clone(),
         }
     }
@@ -0,0 +1,945 @@
+use crate::app::{AppAction, AppCommand, DesiredAppAction};
+use crate::context::AppContext;
+use crate::model::identity_details::{Contact, IdentityActivity, IdentityDetails, IdentityNonces};
+use crate::model::payment_request::PaymentRequest;
+use crate::model::qualified_identity::{EncryptedPrivateKeyTarget, QualifiedIdentity};
+use crate::model::transfer_memo::ReceivedMemo;
+use crate::platform::identity::IdentityTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::explorer_link::{explorer_button, ExplorerItem};
+use crate::ui::components::fiat_value::fiat_label;
+use crate::ui::components::proof_status::{add_proof_status, IDENTITY_METHODS};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::number_format::{NumberFormatter, CREDITS_PER_DASH};
+use crate::ui::helpers::redaction::redact_id;
+use crate::ui::helpers::time_format::TimeFormatter;
+use crate::ui::identities::add_new_identity_screen::{copy_to_clipboard, generate_qr_code_image};
+use crate::ui::identities::key_usage_screen::KeyUsageScreen;
+use crate::ui::identities::top_up_identity_screen::TopUpIdentityScreen;
+use crate::ui::key_info_screen::KeyInfoScreen;
+use crate::ui::transfers::TransferScreen;
+use crate::ui::{MessageType, Screen, ScreenLike};
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::fee::Credits;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_nonce::{
+    IDENTITY_NONCE_VALUE_FILTER, MISSING_IDENTITY_REVISIONS_FILTER,
+};
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dash_sdk::platform::{Identifier, Identity};
+use eframe::egui::{self, Context, RichText, Ui};
+use egui::Color32;
+use std::sync::Arc;
+use tracing::error;
+
+/// Shows everything known about an identity, whether it is one of ours or not
+pub struct IdentityDetailScreen {
+    pub identity_id: Identifier,
+    pub app_context: Arc<AppContext>,
+    local_identity: Option<QualifiedIdentity>,
+    details: Option<IdentityDetails>,
+    activity: Vec<IdentityActivity>,
+    contact: Option<Contact>,
+    contact_alias_input: String,
+    /// Balance in DASH below which the identity is flagged, only for local identities
+    minimum_balance_input: String,
+    watched: bool,
+    nonces: Option<IdentityNonces>,
+    fetching_nonces: bool,
+    /// Memos received with transfers, once loaded
+    memos: Option<Vec<ReceivedMemo>>,
+    fetching_memos: bool,
+    fetch_requested: bool,
+    fetching: bool,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+    /// Open while a payment request is being made for the identity
+    payment_request_form: Option<PaymentRequestForm>,
+}
+
+/// Typed fields of a payment request
+struct PaymentRequestForm {
+    /// Identity id or address
+    recipient: String,
+    /// Amount in DASH, may be empty
+    amount: String,
+    memo: String,
+    /// QR code of the last URI shown, `None` if it didn't fit, remade only when the URI
+    /// changes
+    qr_code: Option<(String, Option<egui::TextureHandle>)>,
+}
+
+impl IdentityDetailScreen {
+    pub fn new(identity_id: Identifier, app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            identity_id,
+            app_context: app_context.clone(),
+            local_identity: None,
+            details: None,
+            activity: Vec::new(),
+            contact: None,
+            contact_alias_input: String::new(),
+            minimum_balance_input: String::new(),
+            watched: false,
+            nonces: None,
+            fetching_nonces: false,
+            memos: None,
+            fetching_memos: false,
+            fetch_requested: false,
+            fetching: false,
+            message: None,
+            payment_request_form: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    /// The most recent identity we have, preferring a fresh fetch over the stored copy
+    fn identity(&self) -> Option<&Identity> {
+        self.details
+            .as_ref()
+            .and_then(|details| details.identity.as_ref())
+            .or(self
+                .local_identity
+                .as_ref()
+                .map(|qualified_identity| &qualified_identity.identity))
+    }
+
+    fn fetch_action(&self) -> BackendTask {
+        BackendTask::IdentityTask(IdentityTask::FetchIdentityDetails(self.identity_id))
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    fn toggle_watch(&mut self) {
+        let watched = !self.watched;
+        match self.app_context.db.set_identity_watched(
+            &self.identity_id,
+            watched,
+            &self.app_context,
+        ) {
+            Ok(_) => self.watched = watched,
+            Err(e) => self.display_message(
+                &format!("Failed to update watch list: {}", e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    fn save_contact(&mut self) {
+        let alias = self.contact_alias_input.trim();
+        let alias = (!alias.is_empty()).then_some(alias);
+        match self.app_context.db.insert_or_update_contact(
+            &self.identity_id,
+            alias,
+            &self.app_context,
+        ) {
+            Ok(_) => {
+                self.contact = Some(Contact {
+                    identity_id: self.identity_id,
+                    alias: alias.map(|alias| alias.to_string()),
+                });
+                self.display_message("Contact saved", MessageType::Success);
+            }
+            Err(e) => self.display_message(
+                &format!("Failed to save contact: {}", e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    /// Saves the typed minimum balance, an empty input removes it
+    fn save_minimum_balance(&mut self) {
+        let input = self.minimum_balance_input.trim();
+        let minimum = if input.is_empty() {
+            None
+        } else {
+            match input.parse::<f64>() {
+                Ok(dash) if dash.is_finite() && dash > 0.0 => {
+                    Some((dash * CREDITS_PER_DASH).round() as Credits)
+                }
+                _ => {
+                    self.display_message("Enter the minimum balance in DASH", MessageType::Error);
+                    return;
+                }
+            }
+        };
+        let identity_id = self.identity_id.to_string(Encoding::Base58);
+        let result = self.app_context.update_preferences(|preferences| {
+            let identity_minimums = &mut preferences.balance_alerts.identity_minimums;
+            match minimum {
+                Some(minimum) => identity_minimums.insert(identity_id, minimum),
+                None => identity_minimums.remove(&identity_id),
+            };
+        });
+        match result {
+            Ok(_) if minimum.is_some() => {
+                self.display_message("Minimum balance saved", MessageType::Success)
+            }
+            Ok(_) => self.display_message("Minimum balance removed", MessageType::Success),
+            Err(e) => self.display_message(
+                &format!("Failed to save the minimum balance: {}", e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    fn remove_contact(&mut self) {
+        match self
+            .app_context
+            .db
+            .remove_contact(&self.identity_id, &self.app_context)
+        {
+            Ok(_) => {
+                self.contact = None;
+                self.contact_alias_input.clear();
+            }
+            Err(e) => self.display_message(
+                &format!("Failed to remove contact: {}", e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    fn render_overview(&self, ui: &mut Ui) {
+        let preferences = self.app_context.preferences();
+        let number_format = NumberFormatter::new(&preferences);
+
+        egui::Grid::new("identity_detail_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Identity ID:").strong());
+                ui.horizontal(|ui| {
+                    ui.label(redact_id(
+                        &self.identity_id.to_string(Encoding::Base58),
+                        preferences.privacy_mode,
+                    ));
+                    explorer_button(
+                        ui,
+                        &self.app_context,
+                        ExplorerItem::Identity(self.identity_id),
+                    );
+                });
+                ui.end_row();
+
+                if let Some(local_identity) = &self.local_identity {
+                    ui.label(RichText::new("Type:").strong());
+                    ui.label(format!("{} (local)", local_identity.identity_type));
+                    ui.end_row();
+                }
+
+                ui.label(RichText::new("Balance:").strong());
+                match self.identity() {
+                    Some(identity) => {
+                        ui.horizontal(|ui| {
+                            let credits = number_format.format_credits(identity.balance());
+                            let formatted_balance =
+                                number_format.format_credits_as_dash(identity.balance());
+                            match self
+                                .app_context
+                                .preferences()
+                                .balance_alerts
+                                .shortfall_minimum(
+                                    &self.identity_id.to_string(Encoding::Base58),
+                                    identity.balance(),
+                                ) {
+                                Some(minimum) => {
+                                    ui.colored_label(
+                                        Color32::RED,
+                                        format!("⚠ {}", formatted_balance),
+                                    )
+                                    .on_hover_text(format!(
+                                        "{}, below the minimum of {}",
+                                        credits,
+                                        number_format.format_credits_as_dash(minimum)
+                                    ));
+                                }
+                                None => {
+                                    ui.label(formatted_balance).on_hover_text(credits);
+                                }
+                            }
+                            fiat_label(ui, &self.app_context, identity.balance());
+                        });
+                    }
+                    None if self.fetching => {
+                        ui.label("Fetching");
+                    }
+                    None => {
+                        ui.label("Unknown");
+                    }
+                }
+                ui.end_row();
+
+                ui.label(RichText::new("DPNS Names:").strong());
+                match &self.details {
+                    Some(details) if details.dpns_names.is_empty() => {
+                        ui.label("None");
+                    }
+                    Some(details) => {
+                        ui.label(details.dpns_names.join(", "));
+                    }
+                    None => {
+                        ui.label(if self.fetching { "Fetching" } else { "Unknown" });
+                    }
+                }
+                ui.end_row();
+            });
+    }
+
+    fn render_dashpay_profile(&self, ui: &mut Ui) {
+        ui.heading("Dashpay Profile");
+        let Some(details) = &self.details else {
+            ui.label(if self.fetching { "Fetching" } else { "Unknown" });
+            return;
+        };
+        let Some(profile) = &details.dashpay_profile else {
+            ui.label("This identity has no Dashpay profile.");
+            return;
+        };
+        egui::Grid::new("identity_dashpay_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                for (label, value) in [
+                    ("Display Name:", &profile.display_name),
+                    ("Public Message:", &profile.public_message),
+                    ("Avatar URL:", &profile.avatar_url),
+                ] {
+                    ui.label(RichText::new(label).strong());
+                    ui.label(value.as_deref().unwrap_or("-"));
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn render_keys(&self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.horizontal(|ui| {
+            ui.heading("Keys");
+            if ui
+                .button("Analyze usage")
+                .on_hover_text("Which keys signed what, and which ones are unused")
+                .clicked()
+            {
+                action = AppAction::AddScreen(Screen::KeyUsageScreen(KeyUsageScreen::new(
+                    self.identity_id,
+                    &self.app_context,
+                )));
+            }
+        });
+        let Some(identity) = self.identity() else {
+            ui.label(if self.fetching { "Fetching" } else { "Unknown" });
+            return action;
+        };
+
+        egui::Grid::new("identity_keys_grid")
+            .num_columns(5)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("ID").strong());
+                ui.label(RichText::new("Purpose").strong());
+                ui.label(RichText::new("Security Level").strong());
+                ui.label(RichText::new("Type").strong());
+                ui.label(RichText::new("Status").strong());
+                ui.end_row();
+
+                for (key_id, key) in identity.public_keys() {
+                    if ui.link(key_id.to_string()).clicked() {
+                        let qualified_identity = self
+                            .local_identity
+                            .clone()
+                            .unwrap_or_else(|| identity.clone().into());
+                        let private_key = qualified_identity
+                            .encrypted_private_keys
+                            .get(&(EncryptedPrivateKeyTarget::from(key.purpose()), *key_id))
+                            .map(|(_, private_key)| *private_key);
+                        action = AppAction::AddScreen(Screen::KeyInfoScreen(KeyInfoScreen::new(
+                            qualified_identity,
+                            key.clone(),
+                            private_key,
+                            &self.app_context,
+                        )));
+                    }
+                    ui.label(format!("{:?}", key.purpose()));
+                    ui.label(format!("{:?}", key.security_level()));
+                    ui.label(format!("{:?}", key.key_type()));
+                    ui.label(if key.disabled_at().is_some() {
+                        "Disabled"
+                    } else {
+                        "Enabled"
+                    });
+                    ui.end_row();
+                }
+            });
+        action
+    }
+
+    fn render_activity(&self, ui: &mut Ui) {
+        ui.heading("Recent Activity");
+        if self.activity.is_empty() {
+            ui.label("No activity seen by this tool yet.");
+            return;
+        }
+        let time_format = TimeFormatter::new(&self.app_context.preferences());
+        let format_time = |timestamp: Option<u64>| {
+            timestamp.map_or("-".to_string(), |timestamp| {
+                time_format.format_millis(timestamp)
+            })
+        };
+        egui::Grid::new("identity_activity_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for activity in &self.activity {
+                    ui.label(format_time(activity.timestamp()));
+                    match activity {
+                        IdentityActivity::Contestant {
+                            normalized_contested_name,
+                            votes,
+                            ..
+                        } => ui.label(format!(
+                            "Contestant for \"{}\" with {} votes",
+                            normalized_contested_name, votes
+                        )),
+                        IdentityActivity::Vote {
+                            normalized_contested_name,
+                            vote_choice,
+                            ..
+                        } => {
+                            let choice = match vote_choice {
+                                ResourceVoteChoice::TowardsIdentity(identity_id) => {
+                                    identity_id.to_string(Encoding::Base58)
+                                }
+                                ResourceVoteChoice::Abstain => "Abstain".to_string(),
+                                ResourceVoteChoice::Lock => "Lock".to_string(),
+                            };
+                            ui.label(format!(
+                                "Voted {} on \"{}\"",
+                                choice, normalized_contested_name
+                            ))
+                        }
+                    };
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Shows the nonces of one of our identities, only available for local identities since
+    /// only those sign transitions from this tool
+    fn render_nonces(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.horizontal(|ui| {
+            ui.heading("Nonces");
+            let label = if self.fetching_nonces {
+                "Resyncing..."
+            } else {
+                "Resync nonces"
+            };
+            if ui
+                .add_enabled(!self.fetching_nonces, egui::Button::new(label))
+                .on_hover_text("Query the current nonces from Platform")
+                .clicked()
+            {
+                self.fetching_nonces = true;
+                action = AppAction::BackendTask(BackendTask::IdentityTask(
+                    IdentityTask::FetchIdentityNonces(self.identity_id),
+                ));
+            }
+        });
+        let Some(nonces) = &self.nonces else {
+            ui.label("Resync to query the nonces from Platform.");
+            return action;
+        };
+
+        egui::Grid::new("identity_nonces_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Identity nonce:").strong());
+                match nonces.identity_nonce {
+                    Some(nonce) => {
+                        ui.label((nonce & IDENTITY_NONCE_VALUE_FILTER).to_string())
+                            .on_hover_text(format!(
+                                "Missing revisions bitmask: {:#x}",
+                                (nonce & MISSING_IDENTITY_REVISIONS_FILTER)
+                                    >> IDENTITY_NONCE_VALUE_FILTER.count_ones()
+                            ));
+                    }
+                    None => {
+                        ui.label("None");
+                    }
+                }
+                ui.end_row();
+
+                ui.label(RichText::new("Next nonce used by SDK:").strong());
+                ui.label(
+                    nonces
+                        .sdk_cached_nonce
+                        .map_or("Unknown".to_string(), |nonce| {
+                            (nonce & IDENTITY_NONCE_VALUE_FILTER).to_string()
+                        }),
+                );
+                ui.end_row();
+
+                for contract_nonce in &nonces.contract_nonces {
+                    let contract = contract_nonce
+                        .contract_alias
+                        .clone()
+                        .unwrap_or_else(|| contract_nonce.contract_id.to_string(Encoding::Base58));
+                    ui.label(RichText::new(format!("Nonce for {}:", contract)).strong());
+                    ui.label((contract_nonce.nonce & IDENTITY_NONCE_VALUE_FILTER).to_string());
+                    ui.end_row();
+                }
+            });
+
+        if let (Some(platform_nonce), Some(sdk_nonce)) =
+            (nonces.identity_nonce, nonces.sdk_cached_nonce)
+        {
+            let platform_nonce = platform_nonce & IDENTITY_NONCE_VALUE_FILTER;
+            let sdk_nonce = sdk_nonce & IDENTITY_NONCE_VALUE_FILTER;
+            if sdk_nonce > platform_nonce + 1 {
+                ui.colored_label(
+                    Color32::DARK_RED,
+                    format!(
+                        "The SDK is {} ahead of Platform, earlier transitions may not have been executed.",
+                        sdk_nonce - platform_nonce - 1
+                    ),
+                );
+            }
+        }
+        if nonces.contract_nonces.is_empty() {
+            ui.label("No contract nonces for the known contracts.");
+        }
+        action
+    }
+
+    /// Shows the memos sent with transfers to one of our identities, decrypted with its
+    /// decryption key
+    fn render_memos(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.horizontal(|ui| {
+            ui.heading("Received Memos");
+            let label = if self.fetching_memos {
+                "Loading..."
+            } else {
+                "Load memos"
+            };
+            if ui
+                .add_enabled(!self.fetching_memos, egui::Button::new(label))
+                .clicked()
+            {
+                if let Some(qualified_identity) = &self.local_identity {
+                    self.fetching_memos = true;
+                    action = AppAction::BackendTask(BackendTask::IdentityTask(
+                        IdentityTask::FetchTransferMemos(qualified_identity.clone()),
+                    ));
+                }
+            }
+        });
+        let Some(memos) = &self.memos else {
+            ui.label("Load the memos sent to this identity in Dashpay contact requests.");
+            return action;
+        };
+        if memos.is_empty() {
+            ui.label("No memos received.");
+            return action;
+        }
+        let preferences = self.app_context.preferences();
+        let time_format = TimeFormatter::new(&preferences);
+        egui::Grid::new("identity_memos_grid")
+            .num_columns(3)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for memo in memos {
+                    ui.label(memo.created_at.map_or("-".to_string(), |created_at| {
+                        time_format.format_millis(created_at)
+                    }));
+                    ui.label(redact_id(
+                        &memo.sender_id.to_string(Encoding::Base58),
+                        preferences.privacy_mode,
+                    ));
+                    match &memo.memo {
+                        Ok(text) => ui.label(text),
+                        Err(e) => ui.colored_label(Color32::GRAY, e),
+                    };
+                    ui.end_row();
+                }
+            });
+        action
+    }
+
+    fn render_actions(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("Actions");
+
+        ui.horizontal(|ui| {
+            let local_identities = self
+                .app_context
+                .load_local_qualified_identities()
+                .unwrap_or_default();
+            ui.add_enabled_ui(!local_identities.is_empty(), |ui| {
+                ui.menu_button("Transfer to", |ui| {
+                    ui.label("Send from:");
+                    for from_identity in local_identities {
+                        if from_identity.identity.id() == self.identity_id {
+                            continue;
+                        }
+                        if ui.button(from_identity.display_string()).clicked() {
+                            action = AppAction::AddScreen(Screen::TransferScreen(
+                                TransferScreen::new(from_identity, &self.app_context)
+                                    .with_recipient(self.identity_id.to_string(Encoding::Base58)),
+                            ));
+                            ui.close_menu();
+                        }
+                    }
+                })
+                .response
+                .on_disabled_hover_text("Load an identity first to transfer from it");
+            });
+
+            let watch_label = if self.watched { "Unwatch" } else { "Watch" };
+            if ui.button(watch_label).clicked() {
+                self.toggle_watch();
+            }
+
+            if let Some(local_identity) = &self.local_identity {
+                if ui.button("Top up").clicked() {
+                    action = AppAction::AddScreen(Screen::TopUpIdentityScreen(
+                        TopUpIdentityScreen::new(local_identity.clone(), &self.app_context)
+                            .with_shortfall_amount(),
+                    ));
+                }
+                if ui
+                    .button("Request Payment")
+                    .on_hover_text("Make a QR code and URI that others can pay with")
+                    .clicked()
+                {
+                    self.payment_request_form = Some(PaymentRequestForm {
+                        recipient: self.default_payment_recipient(local_identity),
+                        amount: String::new(),
+                        memo: String::new(),
+                        qr_code: None,
+                    });
+                }
+            }
+        });
+
+        if self.local_identity.is_some() {
+            ui.add_space(5.0);
+            ui.horizontal(|ui| {
+                ui.label("Minimum balance (DASH):");
+                ui.text_edit_singleline(&mut self.minimum_balance_input)
+                    .on_hover_text("Below this the identity is flagged and you are alerted");
+                if ui.button("Save minimum").clicked() {
+                    self.save_minimum_balance();
+                }
+            });
+        }
+
+        ui.add_space(5.0);
+        ui.horizontal(|ui| {
+            ui.label("Contact alias:");
+            ui.text_edit_singleline(&mut self.contact_alias_input);
+            let save_label = if self.contact.is_some() {
+                "Update contact"
+            } else {
+                "Add to contacts"
+            };
+            if ui.button(save_label).clicked() {
+                self.save_contact();
+            }
+            if self.contact.is_some() && ui.button("Remove from contacts").clicked() {
+                self.remove_contact();
+            }
+        });
+
+        action
+    }
+
+    /// Where payments to the identity can be sent: the payout address of a masternode, else
+    /// a receive address of our wallet. Empty when there is neither, for the user to fill in.
+    fn default_payment_recipient(&self, qualified_identity: &QualifiedIdentity) -> String {
+        let network = self.app_context.network;
+        if let Some(payout_address) = qualified_identity.masternode_payout_address(network) {
+            return payout_address.to_string();
+        }
+        let wallet = self.app_context.wallets.read().unwrap().first().cloned();
+        let Some(wallet) = wallet else {
+            return String::new();
+        };
+        let receive_address = wallet
+            .write()
+            .unwrap()
+            .receive_address(network, Some(&self.app_context));
+        receive_address
+            .map(|address| address.to_string())
+            .unwrap_or_else(|e| {
+                error!("Failed to derive a receive address: {}", e);
+                String::new()
+            })
+    }
+
+    fn show_payment_request_window(&mut self, ctx: &Context) {
+        let Some(form) = &mut self.payment_request_form else {
+            return;
+        };
+        let mut is_open = true;
+        let mut copy_result = None;
+        egui::Window::new("Request Payment")
+            .collapsible(false)
+            .open(&mut is_open)
+            .show(ctx, |ui| {
+                egui::Grid::new("payment_request_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label("Pay to:");
+                        ui.text_edit_singleline(&mut form.recipient).on_hover_text(
+                            "A Core address to be paid at, or the identity id to be paid in credits",
+                        );
+                        ui.end_row();
+                        ui.label("Amount (DASH):");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut form.amount).hint_text("any amount"),
+                        );
+                        ui.end_row();
+                        ui.label("Memo:");
+                        ui.text_edit_singleline(&mut form.memo);
+                        ui.end_row();
+                    });
+
+                let amount = form.amount.trim();
+                let amount = if amount.is_empty() {
+                    Ok(None)
+                } else {
+                    match amount.parse::<f64>() {
+                        Ok(dash) if dash.is_finite() && dash > 0.0 => {
+                            Ok(Some((dash * CREDITS_PER_DASH).round() as Credits))
+                        }
+                        _ => Err("Enter the amount in DASH"),
+                    }
+                };
+                let recipient = form.recipient.trim();
+                let amount = match amount {
+                    Ok(_) if recipient.is_empty() => {
+                        ui.colored_label(Color32::RED, "Enter who is paid");
+                        return;
+                    }
+                    Ok(amount) => amount,
+                    Err(e) => {
+                        ui.colored_label(Color32::RED, e);
+                        return;
+                    }
+                };
+                let uri = PaymentRequest {
+                    recipient: recipient.to_string(),
+                    amount,
+                    memo: form.memo.trim().to_string(),
+                }
+                .to_uri();
+
+                ui.add_space(10.0);
+                if form.qr_code.as_ref().map(|(shown, _)| shown) != Some(&uri) {
+                    let texture = generate_qr_code_image(&uri).ok().map(|image| {
+                        ui.ctx().load_texture(
+                            "payment_request_qr_code",
+                            image,
+                            egui::TextureOptions::LINEAR,
+                        )
+                    });
+                    form.qr_code = Some((uri.clone(), texture));
+                }
+                match form.qr_code.as_ref().and_then(|(_, texture)| texture.as_ref()) {
+                    Some(texture) => {
+                        ui.image(texture);
+                    }
+                    None => {
+                        ui.label("The request is too long for a QR code, shorten the memo.");
+                    }
+                }
+                ui.add_space(5.0);
+                ui.label(RichText::new(&uri).monospace());
+                if ui.button("Copy URI").clicked() {
+                    copy_result = Some(copy_to_clipboard(&uri));
+                }
+            });
+        if !is_open {
+            self.payment_request_form = None;
+        }
+        match copy_result {
+            Some(Ok(())) => self.display_message("Payment request copied", MessageType::Info),
+            Some(Err(e)) => self.display_message(
+                &format!("Failed to copy to clipboard: {}", e),
+                MessageType::Error,
+            ),
+            None => {}
+        }
+    }
+}
+
+impl ScreenLike for IdentityDetailScreen {
+    fn refresh(&mut self) {
+        let app_context = &self.app_context;
+        self.local_identity = app_context
+            .db
+            .get_qualified_identity_by_id(&self.identity_id, app_context)
+            .unwrap_or_else(|e| {
+                error!("Failed to load identity: {:?}", e);
+                None
+            });
+        self.activity = app_context
+            .db
+            .get_identity_activity(&self.identity_id, app_context)
+            .unwrap_or_default();
+        self.contact = app_context
+            .db
+            .get_contact(&self.identity_id, app_context)
+            .unwrap_or_default();
+        self.contact_alias_input = self
+            .contact
+            .as_ref()
+            .and_then(|contact| contact.alias.clone())
+            .unwrap_or_default();
+        self.minimum_balance_input = app_context
+            .preferences()
+            .balance_alerts
+            .minimum_for(&self.identity_id.to_string(Encoding::Base58))
+            .map(|minimum| (minimum as f64 / CREDITS_PER_DASH).to_string())
+            .unwrap_or_default();
+        self.watched = app_context
+            .db
+            .is_identity_watched(&self.identity_id, app_context)
+            .unwrap_or_default();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.fetching = false;
+            self.fetching_nonces = false;
+            self.fetching_memos = false;
+        }
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::IdentityNonces(nonces) = &backend_task_success_result {
+            if nonces.identity_id == self.identity_id {
+                self.fetching_nonces = false;
+                self.nonces = Some(nonces.clone());
+            }
+            return;
+        }
+        if let BackendTaskSuccessResult::TransferMemos(memos) = backend_task_success_result {
+            self.fetching_memos = false;
+            self.memos = Some(memos);
+            return;
+        }
+        if let BackendTaskSuccessResult::IdentityDetails(details) = backend_task_success_result {
+            if details.identity_id != self.identity_id {
+                return;
+            }
+            self.fetching = false;
+            if let Some(identity) = &details.identity {
+                if let Err(e) = self.app_context.db.insert_remote_identity_if_not_exists(
+                    &self.identity_id,
+                    Some(&identity.clone().into()),
+                    &self.app_context,
+                ) {
+                    error!("Failed to store fetched identity: {:?}", e);
+                }
+            } else {
+                self.display_message("Identity not found on Platform", MessageType::Error);
+            }
+            self.details = Some(details);
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Identity", AppAction::None),
+            ],
+            vec![(
+                "Refresh",
+                DesiredAppAction::BackendTask(self.fetch_action()),
+            )],
+        );
+
+        if !self.fetch_requested {
+            self.fetch_requested = true;
+            self.fetching = true;
+            self.app_context
+                .send(AppCommand::RunCachedTask(self.fetch_action()));
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                let title = self
+                    .contact
+                    .as_ref()
+                    .and_then(|contact| contact.alias.clone())
+                    .or_else(|| {
+                        self.local_identity
+                            .as_ref()
+                            .and_then(|identity| identity.alias.clone())
+                    })
+                    .or_else(|| {
+                        self.details
+                            .as_ref()
+                            .and_then(|details| details.dpns_names.first().cloned())
+                    })
+                    .unwrap_or_else(|| "Identity".to_string());
+                ui.heading(title);
+
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => Color32::GRAY,
+                    };
+                    ui.colored_label(color, message);
+                }
+
+                add_proof_status(ui, &self.app_context, &IDENTITY_METHODS);
+                ui.add_space(10.0);
+                self.render_overview(ui);
+                ui.separator();
+                action |= self.render_actions(ui);
+                ui.separator();
+                self.render_dashpay_profile(ui);
+                ui.separator();
+                action |= self.render_keys(ui);
+                ui.separator();
+                if self.local_identity.is_some() {
+                    action |= self.render_nonces(ui);
+                    ui.separator();
+                    action |= self.render_memos(ui);
+                    ui.separator();
+                }
+                self.render_activity(ui);
+            });
+        });
+        self.show_payment_request_window(ctx);
+
+        action
+    }
+}
@@ -0,0 +1,396 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::identity_csv::{parse_identity_csv, CsvIdentityRow, CsvKeyType};
+use crate::model::qualified_identity::IdentityType;
+use crate::platform::identity::{IdentityInputToLoad, IdentityTask};
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::display_scale::{apply_table_density, table_heights};
+use crate::ui::{MessageType, ScreenLike};
+use eframe::egui::{self, Context, RichText, Ui};
+use egui::Color32;
+use egui_extras::{Column, TableBuilder};
+use std::sync::Arc;
+
+/// The rows of the CSV that belong to one identity
+struct CsvIdentity {
+    identity_id: String,
+    identity_type: IdentityType,
+    alias: String,
+    voting_private_key: String,
+    owner_private_key: String,
+    payout_private_key: String,
+    user_keys: Vec<String>,
+    lines: Vec<usize>,
+    /// Identities with an invalid row are not imported
+    valid: bool,
+    result: Option<Result<(), String>>,
+}
+
+impl CsvIdentity {
+    fn from_rows(rows: &[CsvIdentityRow]) -> Vec<Self> {
+        let mut identities: Vec<CsvIdentity> = Vec::new();
+        for row in rows {
+            let index = match identities
+                .iter()
+                .position(|identity| identity.identity_id == row.identity_id)
+            {
+                Some(index) => index,
+                None => {
+                    identities.push(CsvIdentity {
+                        identity_id: row.identity_id.clone(),
+                        identity_type: IdentityType::User,
+                        alias: String::new(),
+                        voting_private_key: String::new(),
+                        owner_private_key: String::new(),
+                        payout_private_key: String::new(),
+                        user_keys: Vec::new(),
+                        lines: Vec::new(),
+                        valid: true,
+                        result: None,
+                    });
+                    identities.len() - 1
+                }
+            };
+            let identity = &mut identities[index];
+            identity.lines.push(row.line);
+            identity.valid &= row.is_valid();
+            if identity.alias.is_empty() {
+                identity.alias = row.alias.clone();
+            }
+            let private_key = row.private_key.clone();
+            match row.key_type {
+                Some(CsvKeyType::User) => identity.user_keys.push(private_key),
+                Some(CsvKeyType::Voting) => identity.voting_private_key = private_key,
+                Some(CsvKeyType::Owner) => identity.owner_private_key = private_key,
+                Some(CsvKeyType::Payout) => identity.payout_private_key = private_key,
+                None => {}
+            }
+            if row
+                .key_type
+                .is_some_and(|key_type| key_type.is_masternode_key())
+            {
+                identity.identity_type = IdentityType::Masternode;
+            }
+        }
+        identities
+    }
+
+    fn to_input(&self) -> IdentityInputToLoad {
+        IdentityInputToLoad {
+            identity_id_input: self.identity_id.clone(),
+            identity_type: self.identity_type,
+            alias_input: self.alias.clone(),
+            voting_private_key_input: self.voting_private_key.clone(),
+            owner_private_key_input: self.owner_private_key.clone(),
+            payout_address_private_key_input: self.payout_private_key.clone(),
+            keys_input: self.user_keys.clone(),
+        }
+    }
+}
+
+/// Loads many identities at once from a CSV of
+/// `identity_id_or_protx,key_type,private_key,alias` lines
+pub struct ImportIdentitiesCsvScreen {
+    pub app_context: Arc<AppContext>,
+    file_path_input: String,
+    pasted_input: String,
+    rows: Vec<CsvIdentityRow>,
+    identities: Vec<CsvIdentity>,
+    importing: bool,
+    error_message: Option<String>,
+}
+
+impl ImportIdentitiesCsvScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        Self {
+            app_context: app_context.clone(),
+            file_path_input: String::new(),
+            pasted_input: String::new(),
+            rows: Vec::new(),
+            identities: Vec::new(),
+            importing: false,
+            error_message: None,
+        }
+    }
+
+    fn validate(&mut self, contents: &str) {
+        self.rows = parse_identity_csv(contents);
+        self.identities = CsvIdentity::from_rows(&self.rows);
+        self.error_message = if self.rows.is_empty() {
+            Some("No rows found".to_string())
+        } else {
+            None
+        };
+    }
+
+    fn render_input(&mut self, ui: &mut Ui) {
+        ui.label(
+            "Each line is identity_id_or_protx,key_type,private_key,alias where key_type is \
+             user, voting, owner or payout. User keys are listed in key id order, starting at 0.",
+        );
+        ui.add_space(5.0);
+        ui.horizontal(|ui| {
+            ui.label("CSV file:");
+            ui.text_edit_singleline(&mut self.file_path_input);
+            if ui.button("Read File").clicked() {
+                match std::fs::read_to_string(self.file_path_input.trim()) {
+                    Ok(contents) => self.validate(&contents),
+                    Err(e) => {
+                        self.rows.clear();
+                        self.identities.clear();
+                        self.error_message = Some(format!("Failed to read file: {}", e));
+                    }
+                }
+            }
+        });
+        ui.label("Or paste the CSV:");
+        ui.add(
+            egui::TextEdit::multiline(&mut self.pasted_input)
+                .desired_rows(6)
+                .desired_width(f32::INFINITY)
+                .code_editor(),
+        );
+        if ui.button("Validate").clicked() {
+            let contents = self.pasted_input.clone();
+            self.validate(&contents);
+        }
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::RED, error);
+        }
+    }
+
+    fn render_row_errors(&self, ui: &mut Ui) {
+        let invalid: Vec<&CsvIdentityRow> =
+            self.rows.iter().filter(|row| !row.is_valid()).collect();
+        let valid = self.rows.len() - invalid.len();
+        ui.label(format!(
+            "{} rows, {} valid, {} with problems",
+            self.rows.len(),
+            valid,
+            invalid.len()
+        ));
+        for row in invalid {
+            ui.colored_label(
+                Color32::RED,
+                format!("Line {}: {}", row.line, row.errors.join(", ")),
+            );
+        }
+    }
+
+    fn render_identities_table(&mut self, ui: &mut Ui) {
+        let density = self.app_context.preferences().table_density;
+        // Scoped so the compact style stays with the table
+        ui.scope(|ui| {
+            let (header_height, row_height) = table_heights(ui.ctx(), density, 25.0);
+            apply_table_density(ui, density);
+            TableBuilder::new(ui)
+                .striped(true)
+                .resizable(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(Column::initial(220.0)) // Identity
+                .column(Column::auto()) // Alias
+                .column(Column::auto()) // Type
+                .column(Column::auto()) // Keys
+                .column(Column::auto()) // Lines
+                .column(Column::remainder()) // Result
+                .header(header_height, |mut header| {
+                    for title in ["Identity", "Alias", "Type", "Keys", "Lines", "Result"] {
+                        header.col(|ui| {
+                            ui.heading(title);
+                        });
+                    }
+                })
+                .body(|mut body| {
+                    for (i, identity) in self.identities.iter_mut().enumerate() {
+                        body.row(row_height, |mut row| {
+                            row.col(|ui| {
+                                ui.label(&identity.identity_id);
+                            });
+                            row.col(|ui| {
+                                ui.label(&identity.alias);
+                            });
+                            row.col(|ui| {
+                                if identity.identity_type == IdentityType::User {
+                                    ui.label("User");
+                                    return;
+                                }
+                                egui::ComboBox::from_id_salt(("csv_identity_type", i))
+                                    .selected_text(identity.identity_type.to_string())
+                                    .show_ui(ui, |ui| {
+                                        for identity_type in
+                                            [IdentityType::Masternode, IdentityType::Evonode]
+                                        {
+                                            ui.selectable_value(
+                                                &mut identity.identity_type,
+                                                identity_type,
+                                                identity_type.to_string(),
+                                            );
+                                        }
+                                    });
+                            });
+                            row.col(|ui| {
+                                let mut keys: Vec<String> = Vec::new();
+                                if !identity.user_keys.is_empty() {
+                                    keys.push(format!("{} user", identity.user_keys.len()));
+                                }
+                                for (name, key) in [
+                                    ("voting", &identity.voting_private_key),
+                                    ("owner", &identity.owner_private_key),
+                                    ("payout", &identity.payout_private_key),
+                                ] {
+                                    if !key.is_empty() {
+                                        keys.push(name.to_string());
+                                    }
+                                }
+                                ui.label(keys.join(", "));
+                            });
+                            row.col(|ui| {
+                                let lines: Vec<String> =
+                                    identity.lines.iter().map(|line| line.to_string()).collect();
+                                ui.label(lines.join(", "));
+                            });
+                            row.col(|ui| match &identity.result {
+                                _ if !identity.valid => {
+                                    ui.colored_label(
+                                        Color32::DARK_RED,
+                                        "Skipped, fix the rows first",
+                                    );
+                                }
+                                None => {
+                                    ui.label("Ready");
+                                }
+                                Some(Ok(())) => {
+                                    ui.colored_label(Color32::DARK_GREEN, "Imported");
+                                }
+                                Some(Err(e)) => {
+                                    ui.colored_label(Color32::RED, e);
+                                }
+                            });
+                        });
+                    }
+                });
+        });
+    }
+
+    fn render_summary(&self, ui: &mut Ui) {
+        let imported = self
+            .identities
+            .iter()
+            .filter(|identity| matches!(identity.result, Some(Ok(()))))
+            .count();
+        let failed = self
+            .identities
+            .iter()
+            .filter(|identity| matches!(identity.result, Some(Err(_))))
+            .count();
+        if imported + failed == 0 {
+            return;
+        }
+        let skipped = self
+            .identities
+            .iter()
+            .filter(|identity| !identity.valid)
+            .count();
+        let text = format!(
+            "{} imported, {} failed, {} skipped",
+            imported, failed, skipped
+        );
+        if failed > 0 {
+            ui.label(RichText::new(text).color(Color32::RED));
+        } else {
+            ui.label(RichText::new(text).color(Color32::DARK_GREEN));
+        }
+    }
+
+    fn import_action(&mut self) -> AppAction {
+        let inputs: Vec<IdentityInputToLoad> = self
+            .identities
+            .iter()
+            .filter(|identity| identity.valid && !matches!(identity.result, Some(Ok(()))))
+            .map(CsvIdentity::to_input)
+            .collect();
+        if inputs.is_empty() {
+            return AppAction::None;
+        }
+        self.importing = true;
+        AppAction::BackendTask(BackendTask::IdentityTask(IdentityTask::LoadIdentities(
+            inputs,
+        )))
+    }
+}
+
+impl ScreenLike for ImportIdentitiesCsvScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.importing = false;
+            self.error_message = Some(message.to_string());
+        }
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::LoadedIdentities(results) = backend_task_success_result {
+            self.importing = false;
+            for (identity_id, result) in results {
+                if let Some(identity) = self
+                    .identities
+                    .iter_mut()
+                    .find(|identity| identity.identity_id == identity_id)
+                {
+                    identity.result = Some(result);
+                }
+            }
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Identities", AppAction::GoToMainScreen),
+                ("Import CSV", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading("Import Identities from CSV");
+                self.render_input(ui);
+
+                if self.rows.is_empty() {
+                    return;
+                }
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_row_errors(ui);
+                ui.add_space(5.0);
+                self.render_identities_table(ui);
+                ui.add_space(10.0);
+
+                let importable = self
+                    .identities
+                    .iter()
+                    .filter(|identity| identity.valid && !matches!(identity.result, Some(Ok(()))))
+                    .count();
+                ui.horizontal(|ui| {
+                    let button = egui::Button::new(format!("Import {} Identities", importable));
+                    if ui
+                        .add_enabled(importable > 0 && !self.importing, button)
+                        .clicked()
+                    {
+                        action = self.import_action();
+                    }
+                    if self.importing {
+                        ui.spinner();
+                        ui.label("Importing...");
+                    }
+                });
+                self.render_summary(ui);
+            });
+        });
+
+        action
+    }
+}
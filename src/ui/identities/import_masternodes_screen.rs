@@ -0,0 +1,293 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::masternode_import::{parse_masternode_import, ImportedMasternode};
+use crate::model::qualified_identity::IdentityType;
+use crate::platform::identity::IdentityTask;
+use crate::platform::BackendTask;
+use crate::ui::components::accessibility::unlabeled_checkbox;
+use crate::ui::components::explorer_link::{explorer_button, ExplorerItem};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::display_scale::{apply_table_density, table_heights};
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use eframe::egui::{self, Context, RichText, Ui};
+use egui::Color32;
+use egui_extras::{Column, TableBuilder};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+struct ImportRow {
+    masternode: ImportedMasternode,
+    selected: bool,
+    already_loaded: bool,
+    /// Whether the voting key came with the input, as opposed to being typed in the review
+    key_in_input: bool,
+}
+
+#[derive(PartialEq)]
+enum ImportStatus {
+    NotStarted,
+    Importing,
+    Done(String),
+    Error(String),
+}
+
+/// Finds voting keys and ProTxHashes in a dashmate config, `protx info` output or dash.conf
+/// and lets the user review them before loading them as voting identities
+pub struct ImportMasternodesScreen {
+    pub app_context: Arc<AppContext>,
+    file_path_input: String,
+    pasted_input: String,
+    rows: Vec<ImportRow>,
+    parse_error: Option<String>,
+    import_status: ImportStatus,
+}
+
+impl ImportMasternodesScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        Self {
+            app_context: app_context.clone(),
+            file_path_input: String::new(),
+            pasted_input: String::new(),
+            rows: Vec::new(),
+            parse_error: None,
+            import_status: ImportStatus::NotStarted,
+        }
+    }
+
+    fn find_masternodes(&mut self, contents: &str) {
+        self.import_status = ImportStatus::NotStarted;
+        let masternodes = match parse_masternode_import(contents) {
+            Ok(masternodes) => masternodes,
+            Err(e) => {
+                self.rows.clear();
+                self.parse_error = Some(e);
+                return;
+            }
+        };
+        let loaded_ids: HashSet<String> = self
+            .app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default()
+            .iter()
+            .map(|identity| identity.identity.id().to_string(Encoding::Hex))
+            .collect();
+        self.rows = masternodes
+            .into_iter()
+            .map(|masternode| {
+                let already_loaded = loaded_ids.contains(&masternode.pro_tx_hash);
+                ImportRow {
+                    selected: !already_loaded,
+                    already_loaded,
+                    key_in_input: !masternode.voting_private_key.is_empty(),
+                    masternode,
+                }
+            })
+            .collect();
+        self.parse_error = None;
+    }
+
+    fn render_input(&mut self, ui: &mut Ui) {
+        ui.label(
+            "Accepted are a dashmate config, the JSON output of `protx info` or `protx list`, \
+             and dash.conf style key=value lines with protxhash and votingprivkey entries.",
+        );
+        ui.add_space(5.0);
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            ui.text_edit_singleline(&mut self.file_path_input);
+            if ui.button("Read File").clicked() {
+                match std::fs::read_to_string(self.file_path_input.trim()) {
+                    Ok(contents) => self.find_masternodes(&contents),
+                    Err(e) => {
+                        self.rows.clear();
+                        self.parse_error = Some(format!("Failed to read file: {}", e));
+                    }
+                }
+            }
+        });
+        ui.label("Or paste the contents:");
+        ui.add(
+            egui::TextEdit::multiline(&mut self.pasted_input)
+                .desired_rows(6)
+                .desired_width(f32::INFINITY)
+                .code_editor(),
+        );
+        if ui.button("Find Masternodes").clicked() {
+            let contents = self.pasted_input.clone();
+            self.find_masternodes(&contents);
+        }
+        if let Some(error) = &self.parse_error {
+            ui.colored_label(Color32::RED, error);
+        }
+    }
+
+    fn render_review_table(&mut self, ui: &mut Ui) {
+        let density = self.app_context.preferences().table_density;
+        // Scoped so the compact style stays with the table
+        ui.scope(|ui| {
+            let (header_height, row_height) = table_heights(ui.ctx(), density, 25.0);
+            apply_table_density(ui, density);
+            TableBuilder::new(ui)
+                .striped(true)
+                .resizable(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(Column::auto()) // Import
+                .column(Column::initial(150.0)) // Alias
+                .column(Column::initial(200.0)) // ProTxHash
+                .column(Column::auto()) // Type
+                .column(Column::remainder()) // Voting Key
+                .header(header_height, |mut header| {
+                    for title in ["Import", "Alias", "ProTxHash", "Type", "Voting Key"] {
+                        header.col(|ui| {
+                            ui.heading(title);
+                        });
+                    }
+                })
+                .body(|mut body| {
+                    for (i, row) in self.rows.iter_mut().enumerate() {
+                        body.row(row_height, |mut table_row| {
+                            table_row.col(|ui| {
+                                let label = format!("Import {}", row.masternode.pro_tx_hash);
+                                unlabeled_checkbox(ui, &mut row.selected, &label);
+                            });
+                            table_row.col(|ui| {
+                                ui.text_edit_singleline(&mut row.masternode.alias);
+                            });
+                            table_row.col(|ui| {
+                                let hash = &row.masternode.pro_tx_hash;
+                                ui.label(format!("{}…{}", &hash[..8], &hash[hash.len() - 8..]))
+                                    .on_hover_text(hash);
+                                explorer_button(
+                                    ui,
+                                    &self.app_context,
+                                    ExplorerItem::Transaction(hash.clone()),
+                                );
+                                if row.already_loaded {
+                                    ui.label(RichText::new("already loaded").color(Color32::GRAY));
+                                }
+                            });
+                            table_row.col(|ui| {
+                                egui::ComboBox::from_id_salt(("import_masternode_type", i))
+                                    .selected_text(row.masternode.identity_type.to_string())
+                                    .show_ui(ui, |ui| {
+                                        for identity_type in
+                                            [IdentityType::Masternode, IdentityType::Evonode]
+                                        {
+                                            ui.selectable_value(
+                                                &mut row.masternode.identity_type,
+                                                identity_type,
+                                                identity_type.to_string(),
+                                            );
+                                        }
+                                    });
+                            });
+                            table_row.col(|ui| {
+                                if row.key_in_input {
+                                    ui.label("Found in input");
+                                } else if let Some(address) = &row.masternode.voting_address {
+                                    ui.label(format!("From Core wallet ({})", address))
+                                        .on_hover_text(
+                                            "The key for this voting address is taken from the \
+                                             connected Dash Core wallet when importing",
+                                        );
+                                } else {
+                                    ui.colored_label(Color32::DARK_RED, "Missing, enter it:");
+                                    ui.add(
+                                        egui::TextEdit::singleline(
+                                            &mut row.masternode.voting_private_key,
+                                        )
+                                        .password(true)
+                                        .hint_text("WIF or hex"),
+                                    );
+                                }
+                            });
+                        });
+                    }
+                });
+        });
+    }
+
+    fn import_action(&mut self) -> AppAction {
+        let masternodes: Vec<ImportedMasternode> = self
+            .rows
+            .iter()
+            .filter(|row| row.selected)
+            .map(|row| row.masternode.clone())
+            .collect();
+        if masternodes.is_empty() {
+            return AppAction::None;
+        }
+        self.import_status = ImportStatus::Importing;
+        AppAction::BackendTask(BackendTask::IdentityTask(IdentityTask::ImportMasternodes(
+            masternodes,
+        )))
+    }
+}
+
+impl ScreenLike for ImportMasternodesScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.import_status = match message_type {
+            MessageType::Error => ImportStatus::Error(message.to_string()),
+            _ => ImportStatus::Done(message.to_string()),
+        };
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Identities", AppAction::GoToMainScreen),
+                ("Import Masternodes", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading("Import Masternode Voting Keys");
+                self.render_input(ui);
+
+                if self.rows.is_empty() {
+                    return;
+                }
+                ui.add_space(10.0);
+                ui.separator();
+                ui.heading("Review");
+                self.render_review_table(ui);
+                ui.add_space(10.0);
+
+                let selected = self.rows.iter().filter(|row| row.selected).count();
+                ui.horizontal(|ui| {
+                    let button = egui::Button::new(format!("Import {} Selected", selected));
+                    if ui
+                        .add_enabled(
+                            selected > 0 && self.import_status != ImportStatus::Importing,
+                            button,
+                        )
+                        .clicked()
+                    {
+                        action = self.import_action();
+                    }
+                    match &self.import_status {
+                        ImportStatus::NotStarted => {}
+                        ImportStatus::Importing => {
+                            ui.spinner();
+                            ui.label("Importing...");
+                        }
+                        ImportStatus::Done(message) => {
+                            ui.colored_label(Color32::DARK_GREEN, message);
+                        }
+                        ImportStatus::Error(message) => {
+                            ui.colored_label(Color32::RED, message);
+                        }
+                    }
+                });
+            });
+        });
+
+        action
+    }
+}
@@ -0,0 +1,582 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::wallet::Wallet;
+use crate::platform::identity::IdentityTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::redaction::identity_label;
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::v0::IdentityPublicKeyV0;
+use dash_sdk::dpp::identity::{KeyID, KeyType, Purpose, SecurityLevel};
+use dash_sdk::platform::{Identity, IdentityPublicKey};
+use eframe::egui::{self, Context, RichText, Ui};
+use egui::Color32;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, RwLock};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum RotationStep {
+    Generate,
+    Add,
+    Verify,
+    Disable,
+    RollBack,
+}
+
+impl RotationStep {
+    fn label(&self) -> &'static str {
+        match self {
+            RotationStep::Generate => "Generate new keys",
+            RotationStep::Add => "Add the new keys to the identity",
+            RotationStep::Verify => "Verify the new keys on Platform",
+            RotationStep::Disable => "Disable the old keys",
+            RotationStep::RollBack => "Roll back: disable the new keys",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum StepStatus {
+    Pending,
+    Running,
+    Done,
+    Failed(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum KeySource {
+    Random,
+    /// Derived from the identity authentication path of a wallet
+    Wallet,
+}
+
+/// A new key replacing an old one of the same purpose and security level
+struct Replacement {
+    old_key_id: KeyID,
+    public_key: IdentityPublicKey,
+    private_key: [u8; 32],
+}
+
+/// Walks through rotating the authentication keys of an identity: new keys are generated and
+/// added, checked on Platform, and only then are the old keys disabled. When a later step fails
+/// the new keys can be disabled again so the identity is left as it was.
+pub struct KeyRotationScreen {
+    pub identity: QualifiedIdentity,
+    pub app_context: Arc<AppContext>,
+    keys_to_rotate: BTreeSet<KeyID>,
+    key_source: KeySource,
+    selected_wallet: Option<Arc<RwLock<Wallet>>>,
+    identity_index: u32,
+    replacements: Vec<Replacement>,
+    /// Ids the new keys got on Platform
+    added_key_ids: Vec<KeyID>,
+    steps: BTreeMap<RotationStep, StepStatus>,
+    running: Option<RotationStep>,
+    verify_after_add: bool,
+}
+
+impl KeyRotationScreen {
+    pub fn new(identity: QualifiedIdentity, app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            identity,
+            app_context: app_context.clone(),
+            keys_to_rotate: BTreeSet::new(),
+            key_source: KeySource::Random,
+            selected_wallet: app_context.wallets.read().unwrap().first().cloned(),
+            identity_index: 0,
+            replacements: Vec::new(),
+            added_key_ids: Vec::new(),
+            steps: BTreeMap::new(),
+            running: None,
+            verify_after_add: false,
+        };
+        screen.reset_steps();
+        screen
+    }
+
+    fn reset_steps(&mut self) {
+        self.steps = [
+            RotationStep::Generate,
+            RotationStep::Add,
+            RotationStep::Verify,
+            RotationStep::Disable,
+        ]
+        .into_iter()
+        .map(|step| (step, StepStatus::Pending))
+        .collect();
+        self.replacements.clear();
+        self.added_key_ids.clear();
+        self.running = None;
+    }
+
+    fn status(&self, step: RotationStep) -> &StepStatus {
+        self.steps.get(&step).unwrap_or(&StepStatus::Pending)
+    }
+
+    fn is_done(&self, step: RotationStep) -> bool {
+        self.status(step) == &StepStatus::Done
+    }
+
+    fn is_failed(&self, step: RotationStep) -> bool {
+        matches!(self.status(step), StepStatus::Failed(_))
+    }
+
+    /// Enabled authentication keys that can be rotated, the master key signs the updates
+    fn rotatable_keys(&self) -> Vec<&IdentityPublicKey> {
+        self.identity
+            .identity
+            .public_keys()
+            .values()
+            .filter(|key| {
+                key.purpose() == Purpose::AUTHENTICATION
+                    && key.security_level() != SecurityLevel::MASTER
+                    && key.disabled_at().is_none()
+            })
+            .collect()
+    }
+
+    fn generate_keys(&mut self) -> Result<(), String> {
+        let network = self.app_context.network;
+        let wallet = match self.key_source {
+            KeySource::Random => None,
+            KeySource::Wallet => Some(
+                self.selected_wallet
+                    .clone()
+                    .ok_or("Select a wallet to derive the keys from".to_string())?,
+            ),
+        };
+        let mut rng = StdRng::from_entropy();
+        let mut next_key_id = self.identity.identity.get_public_key_max_id() + 1;
+        let mut replacements = Vec::new();
+        for old_key_id in &self.keys_to_rotate {
+            let old_key = self
+                .identity
+                .identity
+                .get_public_key_by_id(*old_key_id)
+                .ok_or(format!("Key {} not found", old_key_id))?;
+            let key_type = match old_key.key_type() {
+                KeyType::ECDSA_HASH160 => KeyType::ECDSA_HASH160,
+                _ => KeyType::ECDSA_SECP256K1,
+            };
+            let private_key = match &wallet {
+                // The key index is the id the key gets, so it can be derived again later
+                Some(wallet) => wallet
+                    .read()
+                    .unwrap()
+                    .identity_authentication_ecdsa_private_key(
+                        network,
+                        self.identity_index,
+                        next_key_id,
+                    )
+                    .inner
+                    .secret_bytes(),
+                None => {
+                    let (_, private_key) = key_type
+                        .random_public_and_private_key_data(
+                            &mut rng,
+                            self.app_context.platform_version,
+                        )
+                        .map_err(|e| format!("Failed to generate a key: {}", e))?;
+                    private_key
+                        .try_into()
+                        .map_err(|_| "Generated private key is not 32 bytes".to_string())?
+                }
+            };
+            let data = key_type
+                .public_key_data_from_private_key_data(&private_key, network)
+                .map_err(|e| format!("Failed to derive the public key: {}", e))?;
+            replacements.push(Replacement {
+                old_key_id: *old_key_id,
+                public_key: IdentityPublicKeyV0 {
+                    id: next_key_id,
+                    purpose: old_key.purpose(),
+                    security_level: old_key.security_level(),
+                    contract_bounds: old_key.contract_bounds().cloned(),
+                    key_type,
+                    read_only: false,
+                    data: data.into(),
+                    disabled_at: None,
+                }
+                .into(),
+                private_key,
+            });
+            next_key_id += 1;
+        }
+        self.replacements = replacements;
+        Ok(())
+    }
+
+    fn start(&mut self, step: RotationStep) -> AppAction {
+        let task = match step {
+            RotationStep::Generate => {
+                let status = match self.generate_keys() {
+                    Ok(()) => StepStatus::Done,
+                    Err(e) => StepStatus::Failed(e),
+                };
+                self.steps.insert(step, status);
+                return AppAction::None;
+            }
+            RotationStep::Add => IdentityTask::UpdateIdentityKeys(
+                self.identity.clone(),
+                self.replacements
+                    .iter()
+                    .map(|replacement| (replacement.public_key.clone(), replacement.private_key))
+                    .collect(),
+                vec![],
+            ),
+            RotationStep::Verify => IdentityTask::FetchIdentityDetails(self.identity.identity.id()),
+            RotationStep::Disable => IdentityTask::UpdateIdentityKeys(
+                self.identity.clone(),
+                vec![],
+                self.keys_to_rotate.iter().copied().collect(),
+            ),
+            RotationStep::RollBack => IdentityTask::UpdateIdentityKeys(
+                self.identity.clone(),
+                vec![],
+                self.added_key_ids.clone(),
+            ),
+        };
+        self.steps.insert(step, StepStatus::Running);
+        self.running = Some(step);
+        AppAction::BackendTask(BackendTask::IdentityTask(task))
+    }
+
+    /// Ids of the new keys on the identity, `Err` with what is missing unless all are enabled
+    fn find_new_keys(&self, identity: &Identity) -> Result<Vec<KeyID>, String> {
+        self.replacements
+            .iter()
+            .map(|replacement| {
+                identity
+                    .public_keys()
+                    .values()
+                    .find(|key| key.data() == replacement.public_key.data())
+                    .filter(|key| key.disabled_at().is_none())
+                    .map(|key| key.id())
+                    .ok_or(format!(
+                        "The replacement of key {} is not on the identity",
+                        replacement.old_key_id
+                    ))
+            })
+            .collect()
+    }
+
+    /// Whether the new keys are on Platform but the rotation did not finish
+    fn can_roll_back(&self) -> bool {
+        !self.added_key_ids.is_empty()
+            && !self.is_done(RotationStep::Disable)
+            && !self.is_done(RotationStep::RollBack)
+            && (self.is_failed(RotationStep::Verify) || self.is_failed(RotationStep::Disable))
+    }
+
+    /// The step the user can start next, if any
+    fn next_step(&self) -> Option<RotationStep> {
+        if self.running.is_some() || self.is_done(RotationStep::RollBack) {
+            return None;
+        }
+        [
+            RotationStep::Generate,
+            RotationStep::Add,
+            RotationStep::Verify,
+            RotationStep::Disable,
+        ]
+        .into_iter()
+        .find(|step| !self.is_done(*step))
+    }
+
+    fn render_key_selection(&mut self, ui: &mut Ui) {
+        ui.heading("1. Keys to rotate");
+        let rotatable: Vec<(KeyID, String)> = self
+            .rotatable_keys()
+            .into_iter()
+            .map(|key| {
+                (
+                    key.id(),
+                    format!(
+                        "Key {}: {:?}, {:?}",
+                        key.id(),
+                        key.security_level(),
+                        key.key_type()
+                    ),
+                )
+            })
+            .collect();
+        if rotatable.is_empty() {
+            ui.label("The identity has no enabled authentication keys besides the master key.");
+        }
+        let editable = self.replacements.is_empty();
+        ui.add_enabled_ui(editable, |ui| {
+            for (key_id, label) in rotatable {
+                let mut selected = self.keys_to_rotate.contains(&key_id);
+                if ui.checkbox(&mut selected, label).changed() {
+                    if selected {
+                        self.keys_to_rotate.insert(key_id);
+                    } else {
+                        self.keys_to_rotate.remove(&key_id);
+                    }
+                }
+            }
+
+            ui.add_space(5.0);
+            ui.horizontal(|ui| {
+                ui.label("New keys:");
+                ui.radio_value(&mut self.key_source, KeySource::Random, "Random");
+                ui.add_enabled_ui(self.selected_wallet.is_some(), |ui| {
+                    ui.radio_value(
+                        &mut self.key_source,
+                        KeySource::Wallet,
+                        "Derived from a wallet",
+                    )
+                    .on_disabled_hover_text("No wallet loaded");
+                });
+            });
+            if self.key_source == KeySource::Wallet {
+                ui.horizontal(|ui| {
+                    let wallets = self.app_context.wallets.read().unwrap().clone();
+                    let alias = |wallet: &Arc<RwLock<Wallet>>| {
+                        wallet
+                            .read()
+                            .ok()
+                            .and_then(|wallet| wallet.alias.clone())
+                            .unwrap_or_else(|| "Unnamed Wallet".to_string())
+                    };
+                    egui::ComboBox::from_label("Wallet")
+                        .selected_text(
+                            self.selected_wallet
+                                .as_ref()
+                                .map_or_else(|| "Select".to_string(), &alias),
+                        )
+                        .show_ui(ui, |ui| {
+                            for wallet in &wallets {
+                                let selected = self
+                                    .selected_wallet
+                                    .as_ref()
+                                    .is_some_and(|selected| Arc::ptr_eq(selected, wallet));
+                                if ui.selectable_label(selected, alias(wallet)).clicked() {
+                                    self.selected_wallet = Some(wallet.clone());
+                                }
+                            }
+                        });
+                    ui.label("Identity index:");
+                    ui.add(egui::DragValue::new(&mut self.identity_index).range(0..=100));
+                });
+                ui.label(
+                    RichText::new(
+                        "Keys are derived at the identity authentication path with the key id \
+                         as key index.",
+                    )
+                    .color(Color32::GRAY),
+                );
+            }
+        });
+    }
+
+    fn render_checklist(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("2. Checklist");
+        let next_step = self.next_step();
+        let mut steps = vec![
+            RotationStep::Generate,
+            RotationStep::Add,
+            RotationStep::Verify,
+            RotationStep::Disable,
+        ];
+        if self.can_roll_back() || self.steps.contains_key(&RotationStep::RollBack) {
+            steps.push(RotationStep::RollBack);
+        }
+
+        egui::Grid::new("key_rotation_checklist")
+            .num_columns(3)
+            .spacing([10.0, 8.0])
+            .show(ui, |ui| {
+                for step in steps {
+                    let status = self.status(step).clone();
+                    match &status {
+                        StepStatus::Pending => ui.label("☐"),
+                        StepStatus::Running => ui.spinner(),
+                        StepStatus::Done => ui.colored_label(Color32::DARK_GREEN, "✔"),
+                        StepStatus::Failed(_) => ui.colored_label(Color32::RED, "✖"),
+                    };
+                    ui.label(step.label());
+                    ui.horizontal(|ui| {
+                        if let StepStatus::Failed(error) = &status {
+                            ui.colored_label(Color32::RED, error);
+                        }
+                        let startable = match step {
+                            RotationStep::Generate => !self.keys_to_rotate.is_empty(),
+                            RotationStep::RollBack => {
+                                self.running.is_none() && self.can_roll_back()
+                            }
+                            _ => true,
+                        };
+                        if (next_step == Some(step) || step == RotationStep::RollBack) && startable
+                        {
+                            let label = if self.is_failed(step) {
+                                "Retry"
+                            } else {
+                                "Start"
+                            };
+                            if ui.button(label).clicked() {
+                                action = self.start(step);
+                            }
+                        }
+                        // A failed broadcast may still have been executed, checking Platform
+                        // tells whether the keys were added after all
+                        if step == RotationStep::Add
+                            && self.is_failed(step)
+                            && self.running.is_none()
+                            && ui.button("Check Platform").clicked()
+                        {
+                            action = self.start(RotationStep::Verify);
+                        }
+                    });
+                    ui.end_row();
+
+                    if step == RotationStep::Generate && self.is_done(step) {
+                        ui.label("");
+                        ui.vertical(|ui| {
+                            for replacement in &self.replacements {
+                                ui.label(
+                                    RichText::new(format!(
+                                        "Key {} → new key {}",
+                                        replacement.old_key_id,
+                                        hex::encode(replacement.public_key.data().as_slice())
+                                    ))
+                                    .monospace()
+                                    .small(),
+                                );
+                            }
+                        });
+                        ui.end_row();
+                    }
+                }
+            });
+
+        if self.is_done(RotationStep::Disable) {
+            ui.colored_label(
+                Color32::DARK_GREEN,
+                "Rotation complete. The old keys are disabled and the new ones are in use.",
+            );
+        } else if self.is_done(RotationStep::RollBack) {
+            ui.label("Rolled back. The new keys are disabled and the old keys remain in use.");
+        }
+        let finished = self.is_done(RotationStep::Disable) || self.is_done(RotationStep::RollBack);
+        if self.running.is_none()
+            && (finished || !self.is_done(RotationStep::Add))
+            && ui.button("Start over").clicked()
+        {
+            self.keys_to_rotate.clear();
+            self.reset_steps();
+        }
+        action
+    }
+}
+
+impl ScreenLike for KeyRotationScreen {
+    fn refresh(&mut self) {}
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            if let Some(step) = self.running.take() {
+                self.steps
+                    .insert(step, StepStatus::Failed(message.to_string()));
+            }
+        }
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        let Some(step) = self.running else {
+            return;
+        };
+        match backend_task_success_result {
+            BackendTaskSuccessResult::UpdatedIdentityKeys(identity) => {
+                self.running = None;
+                if step == RotationStep::Add {
+                    match self.find_new_keys(&identity.identity) {
+                        Ok(key_ids) => self.added_key_ids = key_ids,
+                        Err(e) => {
+                            self.identity = identity;
+                            self.steps.insert(step, StepStatus::Failed(e));
+                            return;
+                        }
+                    }
+                }
+                self.identity = identity;
+                self.steps.insert(step, StepStatus::Done);
+                // The keys must be seen on Platform before the old ones go
+                self.verify_after_add = step == RotationStep::Add;
+            }
+            BackendTaskSuccessResult::IdentityDetails(details)
+                if step == RotationStep::Verify
+                    && details.identity_id == self.identity.identity.id() =>
+            {
+                self.running = None;
+                let Some(identity) = details.identity else {
+                    self.steps.insert(
+                        step,
+                        StepStatus::Failed("Identity not found on Platform".to_string()),
+                    );
+                    return;
+                };
+                match self.find_new_keys(&identity) {
+                    Ok(key_ids) => {
+                        self.added_key_ids = key_ids;
+                        self.identity.identity = identity;
+                        // The keys may have been added by a broadcast that reported a failure
+                        self.steps.insert(RotationStep::Add, StepStatus::Done);
+                        self.steps.insert(step, StepStatus::Done);
+                    }
+                    Err(e) => {
+                        self.steps.insert(step, StepStatus::Failed(e));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Identities", AppAction::GoToMainScreen),
+                ("Rotate Keys", AppAction::None),
+            ],
+            vec![],
+        );
+
+        // Verification follows adding the keys without waiting for a click
+        if std::mem::take(&mut self.verify_after_add) {
+            action |= self.start(RotationStep::Verify);
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading(format!(
+                    "Rotate Keys of {}",
+                    identity_label(&self.identity, self.app_context.preferences().privacy_mode)
+                ));
+                if self.identity.can_sign_with_master_key().is_none() {
+                    ui.colored_label(
+                        Color32::RED,
+                        "The master key of this identity is not loaded, it is needed to add and \
+                         disable keys.",
+                    );
+                    return;
+                }
+                ui.add_space(10.0);
+                self.render_key_selection(ui);
+                ui.add_space(10.0);
+                ui.separator();
+                action |= self.render_checklist(ui);
+            });
+        });
+
+        action
+    }
+}
@@ -0,0 +1,319 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::identity_details::{IdentityActivity, IdentityDetails};
+use crate::model::key_usage::{
+    analyze_key_usage, DocumentActivity, KeyAssessment, KeyUsageRecord, KeyUsageRow,
+};
+use crate::model::qualified_identity::{IdentityType, QualifiedIdentity};
+use crate::platform::identity::IdentityTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::redaction::{identity_label, redact_id};
+use crate::ui::helpers::time_format::TimeFormatter;
+use crate::ui::{MessageType, ScreenLike};
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::identity::SecurityLevel;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::{Identifier, Identity};
+use eframe::egui::{self, Context, RichText, Ui};
+use egui::Color32;
+use std::sync::Arc;
+use tracing::error;
+
+/// Reports which keys of an identity signed which kinds of transitions, from what this tool
+/// recorded and optionally the identity's documents on Platform, and highlights unused keys
+pub struct KeyUsageScreen {
+    pub identity_id: Identifier,
+    pub app_context: Arc<AppContext>,
+    local_identity: Option<QualifiedIdentity>,
+    records: Vec<KeyUsageRecord>,
+    activity: Vec<IdentityActivity>,
+    details: Option<IdentityDetails>,
+    scanning: bool,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl KeyUsageScreen {
+    pub fn new(identity_id: Identifier, app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            identity_id,
+            app_context: app_context.clone(),
+            local_identity: None,
+            records: Vec::new(),
+            activity: Vec::new(),
+            details: None,
+            scanning: false,
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    /// The fetched identity if Platform was scanned, the stored copy otherwise
+    fn identity(&self) -> Option<&Identity> {
+        self.details
+            .as_ref()
+            .and_then(|details| details.identity.as_ref())
+            .or(self
+                .local_identity
+                .as_ref()
+                .map(|qualified_identity| &qualified_identity.identity))
+    }
+
+    fn document_security_level(contract: &DataContract, document_type: &str) -> SecurityLevel {
+        contract
+            .document_type_for_name(document_type)
+            .map(|document_type| document_type.security_level_requirement())
+            .unwrap_or(SecurityLevel::HIGH)
+    }
+
+    /// Documents the identity owns whose signing key is unknown: contested names seen by the
+    /// tool, and DPNS names and the Dashpay profile once Platform was scanned
+    fn document_activity(&self) -> Vec<DocumentActivity> {
+        let dpns_level = Self::document_security_level(&self.app_context.dpns_contract, "domain");
+        let mut documents = Vec::new();
+
+        let contested_names: Vec<&str> = self
+            .activity
+            .iter()
+            .filter_map(|activity| match activity {
+                IdentityActivity::Contestant {
+                    normalized_contested_name,
+                    ..
+                } => Some(normalized_contested_name.as_str()),
+                IdentityActivity::Vote { .. } => None,
+            })
+            .collect();
+        if !contested_names.is_empty() {
+            documents.push(DocumentActivity {
+                description: format!("contested names ({})", contested_names.join(", ")),
+                security_level: dpns_level,
+            });
+        }
+
+        if let Some(details) = &self.details {
+            if !details.dpns_names.is_empty() {
+                documents.push(DocumentActivity {
+                    description: format!("DPNS names ({})", details.dpns_names.join(", ")),
+                    security_level: dpns_level,
+                });
+            }
+            if details.dashpay_profile.is_some() {
+                documents.push(DocumentActivity {
+                    description: "a Dashpay profile".to_string(),
+                    security_level: Self::document_security_level(
+                        &self.app_context.dashpay_contract,
+                        "profile",
+                    ),
+                });
+            }
+        }
+        documents
+    }
+
+    fn rows(&self) -> Vec<KeyUsageRow> {
+        let Some(identity) = self.identity() else {
+            return Vec::new();
+        };
+        let masternode = self
+            .local_identity
+            .as_ref()
+            .is_some_and(|qualified_identity| {
+                qualified_identity.identity_type != IdentityType::User
+            });
+        analyze_key_usage(
+            identity,
+            &self.records,
+            &self.document_activity(),
+            masternode,
+        )
+    }
+
+    fn render_report(&self, ui: &mut Ui) {
+        let rows = self.rows();
+        if rows.is_empty() {
+            ui.label(if self.scanning {
+                "Scanning Platform"
+            } else {
+                "The identity is not stored locally, scan Platform to load its keys."
+            });
+            return;
+        }
+
+        let time_format = TimeFormatter::new(&self.app_context.preferences());
+        egui::Grid::new("key_usage_grid")
+            .num_columns(6)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("ID").strong());
+                ui.label(RichText::new("Purpose").strong());
+                ui.label(RichText::new("Security Level").strong());
+                ui.label(RichText::new("Type").strong());
+                ui.label(RichText::new("Signed").strong());
+                ui.label(RichText::new("Assessment").strong());
+                ui.end_row();
+
+                for row in &rows {
+                    ui.label(row.key_id.to_string());
+                    ui.label(format!("{:?}", row.purpose));
+                    ui.label(format!("{:?}", row.security_level));
+                    ui.label(format!("{:?}", row.key_type));
+                    ui.vertical(|ui| {
+                        if row.usages.is_empty() {
+                            ui.label("-");
+                        }
+                        for (transition, usage) in &row.usages {
+                            ui.label(format!(
+                                "{} × {}, last {}",
+                                usage.count,
+                                transition,
+                                time_format.format_millis(usage.last_used)
+                            ));
+                        }
+                    });
+                    let color = match row.assessment {
+                        KeyAssessment::Unused => Color32::from_rgb(255, 140, 0),
+                        KeyAssessment::Used => Color32::DARK_GREEN,
+                        KeyAssessment::Disabled => Color32::GRAY,
+                        KeyAssessment::PossiblyUsed(_) | KeyAssessment::Required(_) => {
+                            ui.visuals().text_color()
+                        }
+                    };
+                    ui.colored_label(color, row.assessment.label());
+                    ui.end_row();
+                }
+            });
+
+        let unused = rows
+            .iter()
+            .filter(|row| row.assessment == KeyAssessment::Unused)
+            .count();
+        ui.add_space(10.0);
+        ui.label(if unused == 0 {
+            "No key is unused.".to_string()
+        } else {
+            format!(
+                "{} key{} never signed anything this tool knows of and may be safely disabled.",
+                unused,
+                if unused == 1 { "" } else { "s" }
+            )
+        });
+    }
+}
+
+impl ScreenLike for KeyUsageScreen {
+    fn refresh(&mut self) {
+        let app_context = &self.app_context;
+        self.local_identity = app_context
+            .db
+            .get_qualified_identity_by_id(&self.identity_id, app_context)
+            .unwrap_or_else(|e| {
+                error!("Failed to load identity: {:?}", e);
+                None
+            });
+        self.records = app_context
+            .db
+            .get_key_usage(&self.identity_id, app_context)
+            .unwrap_or_default();
+        self.activity = app_context
+            .db
+            .get_identity_activity(&self.identity_id, app_context)
+            .unwrap_or_default();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.scanning = false;
+        }
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::IdentityDetails(details) = backend_task_success_result {
+            if details.identity_id != self.identity_id {
+                return;
+            }
+            self.scanning = false;
+            if details.identity.is_none() {
+                self.display_message("Identity not found on Platform", MessageType::Error);
+            }
+            self.details = Some(details);
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Key Usage", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                let privacy_mode = self.app_context.preferences().privacy_mode;
+                let name = match &self.local_identity {
+                    Some(qualified_identity) => identity_label(qualified_identity, privacy_mode),
+                    None => redact_id(&self.identity_id.to_string(Encoding::Base58), privacy_mode),
+                };
+                ui.heading(format!("Key Usage of {}", name));
+                ui.label(
+                    "Transitions this tool signed and votes it cast are attributed to their key. \
+                     Documents found on Platform do not tell which key signed them, so every key \
+                     that could have signed them is listed.",
+                );
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => Color32::GRAY,
+                    };
+                    ui.colored_label(color, message);
+                }
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    let label = if self.details.is_some() {
+                        "Scan Platform again"
+                    } else {
+                        "Scan Platform"
+                    };
+                    if ui
+                        .add_enabled(!self.scanning, egui::Button::new(label))
+                        .on_hover_text("Fetches the identity, its DPNS names and Dashpay profile")
+                        .clicked()
+                    {
+                        self.scanning = true;
+                        action |= AppAction::BackendTask(BackendTask::IdentityTask(
+                            IdentityTask::FetchIdentityDetails(self.identity_id),
+                        ));
+                    }
+                    if self.scanning {
+                        ui.spinner();
+                    }
+                });
+                ui.add_space(10.0);
+                self.render_report(ui);
+            });
+        });
+
+        action
+    }
+}
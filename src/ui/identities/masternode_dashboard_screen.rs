@@ -0,0 +1,365 @@
+use crate::app::{AppAction, DesiredAppAction};
+use crate::context::AppContext;
+use crate::model::masternode_status::{
+    CoreMasternodeState, MasternodeDashboard, MasternodeStatus, TenderdashStatus,
+};
+use crate::model::qualified_identity::{IdentityType, QualifiedIdentity};
+use crate::platform::core::CoreTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{MessageType, ScreenLike, ScreenType};
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Core and Tenderdash status of our masternodes and evonodes
+pub struct MasternodeDashboardScreen {
+    pub app_context: Arc<AppContext>,
+    nodes: Vec<QualifiedIdentity>,
+    dashboard: Option<MasternodeDashboard>,
+    /// Typed Tenderdash RPC addresses by node, saved with the button beside them
+    tenderdash_url_inputs: BTreeMap<Identifier, String>,
+    fetch_requested: bool,
+    fetching: bool,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl MasternodeDashboardScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            nodes: Vec::new(),
+            dashboard: None,
+            tenderdash_url_inputs: BTreeMap::new(),
+            fetch_requested: false,
+            fetching: false,
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    fn fetch_action(&self) -> BackendTask {
+        BackendTask::CoreTask(CoreTask::FetchMasternodeDashboard)
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    fn save_tenderdash_url(&mut self, identity_id: Identifier) -> AppAction {
+        let url = self
+            .tenderdash_url_inputs
+            .get(&identity_id)
+            .map(|url| url.trim().to_string())
+            .unwrap_or_default();
+        let result = self.app_context.update_preferences(|preferences| {
+            let key = identity_id.to_string(Encoding::Base58);
+            if url.is_empty() {
+                preferences.tenderdash_rpc_urls.remove(&key);
+            } else {
+                preferences.tenderdash_rpc_urls.insert(key, url);
+            }
+        });
+        match result {
+            Ok(()) => {
+                self.fetching = true;
+                AppAction::BackendTask(self.fetch_action())
+            }
+            Err(e) => {
+                self.display_message(
+                    &format!("Failed to save the Tenderdash address: {}", e),
+                    MessageType::Error,
+                );
+                AppAction::None
+            }
+        }
+    }
+
+    fn render_core_state(ui: &mut Ui, state: &CoreMasternodeState, core_height: Option<u64>) {
+        let color = if state.pose_ban_height.is_some() {
+            Color32::RED
+        } else if state.pose_penalty > 0 {
+            Color32::from_rgb(200, 120, 0)
+        } else {
+            Color32::DARK_GREEN
+        };
+        ui.label("Core:");
+        ui.colored_label(color, state.status());
+        ui.end_row();
+
+        ui.label("Service:");
+        ui.label(&state.service);
+        ui.end_row();
+
+        ui.label("PoSe penalty:");
+        match state.pose_ban_height {
+            Some(height) => ui.label(format!(
+                "{}, banned at block {}",
+                state.pose_penalty, height
+            )),
+            None => ui.label(state.pose_penalty.to_string()),
+        };
+        ui.end_row();
+
+        ui.label("Registered at block:");
+        ui.label(state.registered_height.to_string());
+        ui.end_row();
+
+        ui.label("Last paid:");
+        match (state.last_paid_height, core_height) {
+            (0, _) => ui.label("Never"),
+            (height, Some(core_height)) => ui.label(format!(
+                "Block {}, {} blocks ago",
+                height,
+                core_height.saturating_sub(height)
+            )),
+            (height, None) => ui.label(format!("Block {}", height)),
+        };
+        ui.end_row();
+    }
+
+    fn render_tenderdash_status(ui: &mut Ui, status: &TenderdashStatus) {
+        let color = if status.catching_up {
+            Color32::from_rgb(200, 120, 0)
+        } else if status.voting_power > 0 {
+            Color32::DARK_GREEN
+        } else {
+            Color32::GRAY
+        };
+        ui.label("Validator:");
+        ui.colored_label(color, status.validator_status())
+            .on_hover_text(format!("Voting power {}", status.voting_power));
+        ui.end_row();
+
+        ui.label("Latest Platform block:");
+        ui.label(format!(
+            "{} at {}",
+            status.latest_block_height, status.latest_block_time
+        ));
+        ui.end_row();
+
+        ui.label("Tenderdash:");
+        ui.label(format!(
+            "{} on {}, version {}",
+            status.moniker, status.network, status.version
+        ));
+        ui.end_row();
+
+        ui.label("Peers:");
+        ui.collapsing(status.peers.len().to_string(), |ui| {
+            for peer in &status.peers {
+                ui.label(format!(
+                    "{} {} {}",
+                    peer.moniker, peer.remote_ip, peer.node_id
+                ));
+            }
+        });
+        ui.end_row();
+    }
+
+    fn render_node(&mut self, ui: &mut Ui, node: &QualifiedIdentity) -> AppAction {
+        let mut action = AppAction::None;
+        let identity_id = node.identity.id();
+        let status: Option<MasternodeStatus> = self.dashboard.as_ref().and_then(|dashboard| {
+            dashboard
+                .nodes
+                .iter()
+                .find(|status| status.identity_id == identity_id)
+                .cloned()
+        });
+        let core_height = self
+            .dashboard
+            .as_ref()
+            .and_then(|dashboard| dashboard.core_block_height.clone().ok());
+
+        ui.group(|ui| {
+            ui.label(
+                RichText::new(format!(
+                    "{} ({})",
+                    node.display_short_string(),
+                    node.identity_type
+                ))
+                .strong(),
+            );
+            egui::Grid::new(("masternode_status", identity_id))
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    match status.as_ref().map(|status| &status.core) {
+                        Some(Ok(state)) => Self::render_core_state(ui, state, core_height),
+                        Some(Err(e)) => {
+                            ui.label("Core:");
+                            ui.colored_label(Color32::RED, e);
+                            ui.end_row();
+                        }
+                        None => {
+                            ui.label("Core:");
+                            ui.label(if self.fetching { "Loading" } else { "Unknown" });
+                            ui.end_row();
+                        }
+                    }
+
+                    if node.identity_type != IdentityType::Evonode {
+                        return;
+                    }
+                    ui.label("Tenderdash RPC:");
+                    ui.horizontal(|ui| {
+                        let input = self.tenderdash_url_inputs.entry(identity_id).or_default();
+                        ui.add(
+                            egui::TextEdit::singleline(input)
+                                .hint_text("http://1.2.3.4:26657")
+                                .desired_width(220.0),
+                        );
+                        if ui.button("Save").clicked() {
+                            action = self.save_tenderdash_url(identity_id);
+                        }
+                    });
+                    ui.end_row();
+                    match status
+                        .as_ref()
+                        .and_then(|status| status.tenderdash.as_ref())
+                    {
+                        Some(Ok(tenderdash)) => Self::render_tenderdash_status(ui, tenderdash),
+                        Some(Err(e)) => {
+                            ui.label("Tenderdash:");
+                            ui.colored_label(Color32::RED, e);
+                            ui.end_row();
+                        }
+                        None => {}
+                    }
+                });
+        });
+        action
+    }
+}
+
+impl ScreenLike for MasternodeDashboardScreen {
+    fn refresh(&mut self) {
+        self.nodes = self
+            .app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|qualified_identity| {
+                matches!(
+                    qualified_identity.identity_type,
+                    IdentityType::Masternode | IdentityType::Evonode
+                )
+            })
+            .collect();
+        let tenderdash_rpc_urls = self.app_context.preferences().tenderdash_rpc_urls;
+        self.tenderdash_url_inputs = self
+            .nodes
+            .iter()
+            .map(|node| {
+                let identity_id = node.identity.id();
+                let url = tenderdash_rpc_urls
+                    .get(&identity_id.to_string(Encoding::Base58))
+                    .cloned()
+                    .unwrap_or_default();
+                (identity_id, url)
+            })
+            .collect();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.fetching = false;
+        }
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::MasternodeDashboard(dashboard) =
+            backend_task_success_result
+        {
+            self.fetching = false;
+            self.dashboard = Some(dashboard);
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Identities", AppAction::GoToMainScreen),
+                ("Masternodes", AppAction::None),
+            ],
+            vec![
+                (
+                    "Payouts",
+                    DesiredAppAction::AddScreenType(ScreenType::MasternodePayouts),
+                ),
+                (
+                    "Logs",
+                    DesiredAppAction::AddScreenType(ScreenType::NodeLogs),
+                ),
+                (
+                    "Refresh",
+                    DesiredAppAction::BackendTask(self.fetch_action()),
+                ),
+            ],
+        );
+
+        if !self.fetch_requested {
+            self.fetch_requested = true;
+            self.fetching = true;
+            action |= AppAction::BackendTask(self.fetch_action());
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading("Masternode Dashboard");
+                match self
+                    .dashboard
+                    .as_ref()
+                    .map(|dashboard| &dashboard.core_block_height)
+                {
+                    Some(Ok(height)) => {
+                        ui.label(format!("Dash Core is at block {}", height));
+                    }
+                    Some(Err(e)) => {
+                        ui.colored_label(Color32::RED, format!("Dash Core is unreachable: {}", e));
+                    }
+                    None if self.fetching => {
+                        ui.label("Loading the status from Dash Core");
+                    }
+                    None => {}
+                }
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => Color32::GRAY,
+                    };
+                    ui.colored_label(color, message);
+                }
+                ui.add_space(10.0);
+
+                if self.nodes.is_empty() {
+                    ui.label("Load a masternode or evonode identity to see its status.");
+                    return;
+                }
+                for node in self.nodes.clone() {
+                    action |= self.render_node(ui, &node);
+                    ui.add_space(6.0);
+                }
+            });
+        });
+
+        action
+    }
+}
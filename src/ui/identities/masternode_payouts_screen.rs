@@ -0,0 +1,353 @@
+use crate::app::{AppAction, DesiredAppAction};
+use crate::context::AppContext;
+use crate::model::masternode_payout::{monthly_totals, payouts_to_csv, MasternodePayout};
+use crate::model::qualified_identity::IdentityType;
+use crate::platform::core::CoreTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{MessageType, ScreenLike};
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Context, RichText, Ui};
+use egui::Color32;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Core payouts to the payout addresses of our masternodes, as a calendar and monthly totals
+pub struct MasternodePayoutsScreen {
+    pub app_context: Arc<AppContext>,
+    payouts: Vec<MasternodePayout>,
+    /// Names of our masternodes and evonodes by identity
+    nodes: BTreeMap<Identifier, String>,
+    /// Only this node's payouts, all when `None`
+    selected_node: Option<Identifier>,
+    /// First day of the month the calendar shows
+    calendar_month: NaiveDate,
+    export_path_input: String,
+    sync_requested: bool,
+    syncing: bool,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl MasternodePayoutsScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let today = Utc::now().date_naive();
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            payouts: Vec::new(),
+            nodes: BTreeMap::new(),
+            selected_node: None,
+            calendar_month: today.with_day(1).unwrap_or(today),
+            export_path_input: "masternode_payouts.csv".to_string(),
+            sync_requested: false,
+            syncing: false,
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    fn sync_action(&self) -> BackendTask {
+        BackendTask::CoreTask(CoreTask::SyncMasternodePayouts)
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    fn node_name(&self, identity_id: &Identifier) -> String {
+        self.nodes
+            .get(identity_id)
+            .cloned()
+            .unwrap_or_else(|| identity_id.to_string(Encoding::Base58))
+    }
+
+    fn filtered_payouts(&self) -> Vec<MasternodePayout> {
+        self.payouts
+            .iter()
+            .filter(|payout| {
+                self.selected_node
+                    .map_or(true, |node| node == payout.identity_id)
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn render_node_selection(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Node:");
+            let selected_text = match &self.selected_node {
+                Some(node) => self.node_name(node),
+                None => "All nodes".to_string(),
+            };
+            egui::ComboBox::from_id_salt("payout_node")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.selected_node, None, "All nodes");
+                    for (identity_id, name) in &self.nodes {
+                        ui.selectable_value(&mut self.selected_node, Some(*identity_id), name);
+                    }
+                });
+        });
+    }
+
+    fn render_calendar(&mut self, ui: &mut Ui, payouts: &[MasternodePayout]) {
+        ui.horizontal(|ui| {
+            if ui.button("◀").clicked() {
+                self.calendar_month = self.calendar_month - Months::new(1);
+            }
+            ui.label(
+                RichText::new(format!(
+                    "{} {} (UTC)",
+                    MONTH_NAMES[self.calendar_month.month0() as usize],
+                    self.calendar_month.year()
+                ))
+                .strong(),
+            );
+            if ui.button("▶").clicked() {
+                self.calendar_month = self.calendar_month + Months::new(1);
+            }
+        });
+
+        let month = (self.calendar_month.year(), self.calendar_month.month());
+        let mut days: BTreeMap<u32, Vec<&MasternodePayout>> = BTreeMap::new();
+        for payout in payouts.iter().filter(|payout| payout.month() == month) {
+            days.entry(payout.paid_at().day()).or_default().push(payout);
+        }
+        let days_in_month = (self.calendar_month + Months::new(1))
+            .signed_duration_since(self.calendar_month)
+            .num_days() as u32;
+        let leading_blanks = self.calendar_month.weekday().num_days_from_monday();
+
+        egui::Grid::new("payout_calendar")
+            .num_columns(7)
+            .min_col_width(90.0)
+            .spacing([4.0, 4.0])
+            .show(ui, |ui| {
+                for weekday in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"] {
+                    ui.label(RichText::new(weekday).strong());
+                }
+                ui.end_row();
+                for _ in 0..leading_blanks {
+                    ui.label("");
+                }
+                for day in 1..=days_in_month {
+                    ui.vertical(|ui| {
+                        ui.label(RichText::new(day.to_string()).color(Color32::GRAY));
+                        if let Some(day_payouts) = days.get(&day) {
+                            let total: u64 = day_payouts.iter().map(|payout| payout.duffs).sum();
+                            let details = day_payouts
+                                .iter()
+                                .map(|payout| {
+                                    format!(
+                                        "{}: {:.8} DASH at block {}",
+                                        self.node_name(&payout.identity_id),
+                                        payout.duffs as f64 * 1e-8,
+                                        payout.height
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                                .join("\n");
+                            ui.colored_label(
+                                Color32::DARK_GREEN,
+                                format!("{:.4} DASH", total as f64 * 1e-8),
+                            )
+                            .on_hover_text(details);
+                        }
+                    });
+                    if (leading_blanks + day) % 7 == 0 {
+                        ui.end_row();
+                    }
+                }
+            });
+    }
+
+    fn render_monthly_totals(&self, ui: &mut Ui, payouts: &[MasternodePayout]) {
+        let totals = monthly_totals(payouts);
+        if totals.is_empty() {
+            return;
+        }
+        let nodes: Vec<Identifier> = match self.selected_node {
+            Some(node) => vec![node],
+            None => self.nodes.keys().copied().collect(),
+        };
+        egui::Grid::new("payout_monthly_totals")
+            .num_columns(nodes.len() + 2)
+            .spacing([12.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Month").strong());
+                for node in &nodes {
+                    ui.label(RichText::new(self.node_name(node)).strong());
+                }
+                ui.label(RichText::new("Total").strong());
+                ui.end_row();
+
+                for ((year, month), node_totals) in totals.iter().rev() {
+                    ui.label(format!("{}-{:02}", year, month));
+                    for node in &nodes {
+                        let duffs = node_totals.get(node).copied().unwrap_or_default();
+                        ui.label(format!("{:.8}", duffs as f64 * 1e-8));
+                    }
+                    let total: u64 = node_totals.values().sum();
+                    ui.label(RichText::new(format!("{:.8} DASH", total as f64 * 1e-8)).strong());
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn export_csv(&mut self) {
+        let payouts = self.filtered_payouts();
+        let csv = payouts_to_csv(&payouts, |identity_id| self.node_name(identity_id));
+        let path = self.export_path_input.trim().to_string();
+        match std::fs::write(&path, csv) {
+            Ok(()) => self.display_message(
+                &format!("Exported {} payouts to {}", payouts.len(), path),
+                MessageType::Success,
+            ),
+            Err(e) => self.display_message(
+                &format!("Failed to write {}: {}", path, e),
+                MessageType::Error,
+            ),
+        }
+    }
+}
+
+impl ScreenLike for MasternodePayoutsScreen {
+    fn refresh(&mut self) {
+        let app_context = &self.app_context;
+        self.nodes = app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|qualified_identity| {
+                matches!(
+                    qualified_identity.identity_type,
+                    IdentityType::Masternode | IdentityType::Evonode
+                )
+            })
+            .map(|qualified_identity| {
+                (
+                    qualified_identity.identity.id(),
+                    qualified_identity.display_short_string(),
+                )
+            })
+            .collect();
+        self.payouts = app_context
+            .db
+            .get_masternode_payouts(app_context)
+            .unwrap_or_default();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.syncing = false;
+        }
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::MasternodePayouts(payouts) = backend_task_success_result {
+            self.syncing = false;
+            self.payouts = payouts;
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Identities", AppAction::GoToMainScreen),
+                ("Masternode Payouts", AppAction::None),
+            ],
+            vec![("Sync", DesiredAppAction::BackendTask(self.sync_action()))],
+        );
+
+        if !self.sync_requested {
+            self.sync_requested = true;
+            self.syncing = true;
+            action |= AppAction::BackendTask(self.sync_action());
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading("Masternode Payouts");
+                ui.label(
+                    RichText::new(
+                        "Coinbase payouts to the payout addresses of your masternodes, read from \
+                         Dash Core. Core needs to run with addressindex=1.",
+                    )
+                    .color(Color32::GRAY),
+                );
+                if self.syncing {
+                    ui.label("Syncing payouts from Dash Core");
+                }
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => Color32::GRAY,
+                    };
+                    ui.colored_label(color, message);
+                }
+
+                if self.nodes.is_empty() {
+                    ui.label("Load a masternode or evonode identity to track its payouts.");
+                    return;
+                }
+                self.render_node_selection(ui);
+
+                let payouts = self.filtered_payouts();
+                let total: u64 = payouts.iter().map(|payout| payout.duffs).sum();
+                ui.label(format!(
+                    "{} payouts, {:.8} DASH in total",
+                    payouts.len(),
+                    total as f64 * 1e-8
+                ));
+                ui.separator();
+                self.render_calendar(ui, &payouts);
+                ui.separator();
+                ui.heading("Totals per Month (UTC)");
+                self.render_monthly_totals(ui, &payouts);
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label("CSV file:");
+                    ui.text_edit_singleline(&mut self.export_path_input);
+                    if ui
+                        .add_enabled(!payouts.is_empty(), egui::Button::new("Export CSV"))
+                        .clicked()
+                    {
+                        self.export_csv();
+                    }
+                });
+            });
+        });
+
+        action
+    }
+}
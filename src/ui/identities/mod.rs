@@ -1,5 +1,15 @@
+pub mod accounting_export_screen;
 pub mod add_existing_identity_screen;
 pub mod add_new_identity_screen;
 pub mod add_new_wallet_screen;
 pub mod identities_screen;
+pub mod identity_detail_screen;
+pub mod import_identities_csv_screen;
+pub mod import_masternodes_screen;
+pub mod key_rotation_screen;
+pub mod key_usage_screen;
+pub mod masternode_dashboard_screen;
+pub mod masternode_payouts_screen;
+pub mod ownership_proof_screen;
 pub mod register_dpns_name_screen;
+pub mod top_up_identity_screen;
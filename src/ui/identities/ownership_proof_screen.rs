@@ -0,0 +1,662 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::masternode_message::{
+    MasternodeKeyRole, MasternodeMessageClaim, SignedMasternodeMessage, MASTERNODE_MESSAGE_KIND,
+};
+use crate::model::ownership_proof::{armor_kind, OwnershipClaim, OwnershipProof};
+use crate::model::qualified_identity::{
+    EncryptedPrivateKeyTarget, IdentityType, QualifiedIdentity,
+};
+use crate::platform::core::CoreTask;
+use crate::platform::identity::IdentityTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::redaction::{identity_label, redact_id};
+use crate::ui::helpers::time_format::TimeFormatter;
+use crate::ui::identities::add_new_identity_screen::copy_to_clipboard;
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dashcore_rpc::dashcore::{Network, PrivateKey};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::identity::{IdentityPublicKey, Purpose};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::sync::Arc;
+
+/// Which kind of key messages are signed with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SignMode {
+    IdentityKey,
+    MasternodeKey,
+}
+
+/// A pasted blob, told apart by its armor lines
+#[derive(Debug, Clone, PartialEq)]
+enum PastedProof {
+    Identity(OwnershipProof),
+    Masternode(SignedMasternodeMessage),
+}
+
+impl PastedProof {
+    fn parse(blob: &str) -> Option<Self> {
+        if armor_kind(blob) == Some(MASTERNODE_MESSAGE_KIND) {
+            SignedMasternodeMessage::from_blob(blob)
+                .ok()
+                .map(PastedProof::Masternode)
+        } else {
+            OwnershipProof::from_blob(blob)
+                .ok()
+                .map(PastedProof::Identity)
+        }
+    }
+}
+
+/// Signs messages with a key of one of our identities or masternodes and verifies such
+/// messages from others, to prove who controls an identity, e.g. to support or an OTC
+/// counterparty, or a masternode when coordinating governance
+pub struct OwnershipProofScreen {
+    pub app_context: Arc<AppContext>,
+    sign_mode: SignMode,
+    identities: Vec<QualifiedIdentity>,
+    selected_identity: Option<usize>,
+    selected_key: Option<IdentityPublicKey>,
+    masternodes: Vec<QualifiedIdentity>,
+    selected_masternode: Option<usize>,
+    masternode_role: MasternodeKeyRole,
+    /// Private key for a role whose key isn't stored, only used for one signature
+    masternode_key_input: String,
+    message_input: String,
+    signed_blob: Option<String>,
+    verify_input: String,
+    /// The proof pasted to verify, once it parsed
+    pasted_proof: Option<PastedProof>,
+    verifying: bool,
+    /// What the last verification proved, or why it failed
+    verification: Option<Result<String, String>>,
+    message: Option<(String, MessageType)>,
+}
+
+impl OwnershipProofScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            sign_mode: SignMode::IdentityKey,
+            identities: Vec::new(),
+            selected_identity: None,
+            selected_key: None,
+            masternodes: Vec::new(),
+            selected_masternode: None,
+            masternode_role: MasternodeKeyRole::Voting,
+            masternode_key_input: String::new(),
+            message_input: String::new(),
+            signed_blob: None,
+            verify_input: String::new(),
+            pasted_proof: None,
+            verifying: false,
+            verification: None,
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    /// Keys of the identity itself we hold the private key of and can sign proofs with
+    fn signing_keys(qualified_identity: &QualifiedIdentity) -> Vec<IdentityPublicKey> {
+        qualified_identity
+            .encrypted_private_keys
+            .iter()
+            .filter(|((target, _), (public_key, _))| {
+                *target == EncryptedPrivateKeyTarget::PrivateKeyOnMainIdentity
+                    && OwnershipProof::supports_key(public_key)
+            })
+            .map(|(_, (public_key, _))| public_key.clone())
+            .collect()
+    }
+
+    /// The stored private key of the masternode for the role, if it was loaded with it
+    fn stored_masternode_key(
+        qualified_identity: &QualifiedIdentity,
+        role: MasternodeKeyRole,
+    ) -> Option<[u8; 32]> {
+        qualified_identity
+            .encrypted_private_keys
+            .iter()
+            .find(|((target, _), (public_key, _))| match role {
+                MasternodeKeyRole::Owner => {
+                    *target == EncryptedPrivateKeyTarget::PrivateKeyOnMainIdentity
+                        && public_key.purpose() == Purpose::OWNER
+                }
+                MasternodeKeyRole::Voting => {
+                    *target == EncryptedPrivateKeyTarget::PrivateKeyOnVoterIdentity
+                }
+                MasternodeKeyRole::Operator => {
+                    *target == EncryptedPrivateKeyTarget::PrivateKeyOnOperatorIdentity
+                }
+            })
+            .map(|(_, (_, private_key))| *private_key)
+    }
+
+    fn render_sign(&mut self, ui: &mut Ui) {
+        ui.heading("Sign a Message");
+        ui.horizontal(|ui| {
+            let mut mode = self.sign_mode;
+            ui.selectable_value(&mut mode, SignMode::IdentityKey, "Identity key");
+            ui.selectable_value(&mut mode, SignMode::MasternodeKey, "Masternode key");
+            if mode != self.sign_mode {
+                self.sign_mode = mode;
+                self.signed_blob = None;
+            }
+        });
+        ui.add_space(5.0);
+
+        let ready = match self.sign_mode {
+            SignMode::IdentityKey => self.render_identity_key(ui),
+            SignMode::MasternodeKey => self.render_masternode_key(ui),
+        };
+        if !ready {
+            return;
+        }
+
+        ui.label("Message:");
+        if ui
+            .add(
+                egui::TextEdit::multiline(&mut self.message_input)
+                    .hint_text("e.g. I control this identity, ticket #1234, 2026-10-17")
+                    .desired_rows(3)
+                    .desired_width(ui.available_width()),
+            )
+            .changed()
+        {
+            self.signed_blob = None;
+        }
+        ui.label(
+            RichText::new(
+                "Include something only this conversation knows, like a ticket number or the \
+                 date, so the proof can't be reused elsewhere.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        if ui
+            .add_enabled(
+                !self.message_input.trim().is_empty(),
+                egui::Button::new("Sign"),
+            )
+            .clicked()
+        {
+            let signed = match self.sign_mode {
+                SignMode::IdentityKey => self.sign_with_identity_key(),
+                SignMode::MasternodeKey => self.sign_with_masternode_key(),
+            };
+            match signed {
+                Ok(blob) => {
+                    self.signed_blob = Some(blob);
+                    self.message = None;
+                }
+                Err(e) => self.message = Some((e, MessageType::Error)),
+            }
+        }
+
+        if let Some(blob) = &self.signed_blob {
+            ui.add_space(5.0);
+            ui.label("Proof, send it as is:");
+            ui.add(
+                egui::TextEdit::multiline(&mut blob.as_str())
+                    .code_editor()
+                    .desired_width(ui.available_width()),
+            );
+            if ui.button("Copy").clicked() {
+                if let Err(e) = copy_to_clipboard(blob) {
+                    self.message = Some((
+                        format!("Failed to copy to clipboard: {}", e),
+                        MessageType::Error,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// The identity and key to sign with. Returns whether one is selected.
+    fn render_identity_key(&mut self, ui: &mut Ui) -> bool {
+        if self.identities.is_empty() {
+            ui.label("None of the loaded identities has an ECDSA key with its private key.");
+            return false;
+        }
+
+        let privacy_mode = self.app_context.preferences().privacy_mode;
+        let selected_text = self
+            .selected_identity
+            .and_then(|index| self.identities.get(index))
+            .map(|qualified_identity| identity_label(qualified_identity, privacy_mode))
+            .unwrap_or_else(|| "Select an identity".to_string());
+        ui.horizontal(|ui| {
+            ui.label("Identity:");
+            egui::ComboBox::from_id_salt("ownership_proof_identity")
+                .selected_text(selected_text)
+                .width(300.0)
+                .show_ui(ui, |ui| {
+                    for (index, qualified_identity) in self.identities.iter().enumerate() {
+                        if ui
+                            .selectable_label(
+                                self.selected_identity == Some(index),
+                                identity_label(qualified_identity, privacy_mode),
+                            )
+                            .clicked()
+                        {
+                            self.selected_identity = Some(index);
+                            self.selected_key =
+                                Self::signing_keys(qualified_identity).into_iter().next();
+                            self.signed_blob = None;
+                        }
+                    }
+                });
+        });
+
+        let Some(qualified_identity) = self
+            .selected_identity
+            .and_then(|index| self.identities.get(index))
+            .cloned()
+        else {
+            return false;
+        };
+        ui.horizontal(|ui| {
+            ui.label("Key:");
+            let selected_text = self
+                .selected_key
+                .as_ref()
+                .map(|key| format!("Key {} ({:?})", key.id(), key.purpose()))
+                .unwrap_or_default();
+            egui::ComboBox::from_id_salt("ownership_proof_key")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    for key in Self::signing_keys(&qualified_identity) {
+                        let label = format!(
+                            "Key {} ({:?}, {:?})",
+                            key.id(),
+                            key.purpose(),
+                            key.security_level()
+                        );
+                        let selected = self.selected_key.as_ref() == Some(&key);
+                        if ui.selectable_label(selected, label).clicked() {
+                            self.selected_key = Some(key);
+                            self.signed_blob = None;
+                        }
+                    }
+                });
+        });
+        self.selected_key.is_some()
+    }
+
+    /// The masternode and which of its keys to sign with. Returns whether one is selected.
+    fn render_masternode_key(&mut self, ui: &mut Ui) -> bool {
+        if self.masternodes.is_empty() {
+            ui.label("No masternodes or evonodes are loaded.");
+            return false;
+        }
+
+        let privacy_mode = self.app_context.preferences().privacy_mode;
+        let selected_text = self
+            .selected_masternode
+            .and_then(|index| self.masternodes.get(index))
+            .map(|qualified_identity| identity_label(qualified_identity, privacy_mode))
+            .unwrap_or_else(|| "Select a masternode".to_string());
+        ui.horizontal(|ui| {
+            ui.label("Masternode:");
+            egui::ComboBox::from_id_salt("ownership_proof_masternode")
+                .selected_text(selected_text)
+                .width(300.0)
+                .show_ui(ui, |ui| {
+                    for (index, qualified_identity) in self.masternodes.iter().enumerate() {
+                        if ui
+                            .selectable_label(
+                                self.selected_masternode == Some(index),
+                                identity_label(qualified_identity, privacy_mode),
+                            )
+                            .clicked()
+                        {
+                            self.selected_masternode = Some(index);
+                            self.signed_blob = None;
+                        }
+                    }
+                });
+        });
+        let Some(qualified_identity) = self
+            .selected_masternode
+            .and_then(|index| self.masternodes.get(index))
+        else {
+            return false;
+        };
+
+        ui.horizontal(|ui| {
+            ui.label("Key:");
+            for role in MasternodeKeyRole::ALL {
+                if ui
+                    .selectable_value(&mut self.masternode_role, role, role.to_string())
+                    .clicked()
+                {
+                    self.signed_blob = None;
+                }
+            }
+        });
+        if Self::stored_masternode_key(qualified_identity, self.masternode_role).is_none() {
+            ui.horizontal(|ui| {
+                ui.label(format!("{} private key:", self.masternode_role));
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.masternode_key_input)
+                        .password(true)
+                        .hint_text("hex or WIF")
+                        .desired_width(300.0),
+                );
+            });
+            ui.label(
+                RichText::new(
+                    "This key isn't stored with the masternode. It is only used for this \
+                     signature and not saved.",
+                )
+                .color(Color32::GRAY),
+            );
+        }
+        true
+    }
+
+    fn sign_with_identity_key(&self) -> Result<String, String> {
+        let (Some(qualified_identity), Some(key)) = (
+            self.selected_identity
+                .and_then(|index| self.identities.get(index)),
+            &self.selected_key,
+        ) else {
+            return Err("Select an identity and a key".to_string());
+        };
+        let claim = OwnershipClaim {
+            network: self.app_context.network_string(),
+            identity_id: qualified_identity.identity.id().to_string(Encoding::Base58),
+            key_id: key.id(),
+            message: self.message_input.trim().to_string(),
+            signed_at: chrono::Utc::now().timestamp(),
+        };
+        OwnershipProof::sign(claim, qualified_identity, key).map(|proof| proof.to_blob())
+    }
+
+    fn sign_with_masternode_key(&mut self) -> Result<String, String> {
+        let Some(qualified_identity) = self
+            .selected_masternode
+            .and_then(|index| self.masternodes.get(index))
+        else {
+            return Err("Select a masternode".to_string());
+        };
+        let private_key =
+            match Self::stored_masternode_key(qualified_identity, self.masternode_role) {
+                Some(private_key) => private_key,
+                None => parse_private_key(&self.masternode_key_input, self.app_context.network)?,
+            };
+        let signed = SignedMasternodeMessage::sign(
+            self.app_context.network,
+            qualified_identity.identity.id().to_string(Encoding::Hex),
+            self.masternode_role,
+            self.message_input.trim().to_string(),
+            &private_key,
+        )?;
+        self.masternode_key_input.clear();
+        Ok(signed.to_blob())
+    }
+
+    fn render_verify(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("Verify a Proof");
+        ui.label("Paste a proof or signed masternode message received from someone else:");
+        if ui
+            .add(
+                egui::TextEdit::multiline(&mut self.verify_input)
+                    .code_editor()
+                    .desired_rows(4)
+                    .desired_width(ui.available_width()),
+            )
+            .changed()
+        {
+            self.verification = None;
+            self.pasted_proof = PastedProof::parse(&self.verify_input);
+        }
+
+        if !self.verify_input.trim().is_empty() {
+            match &self.pasted_proof {
+                Some(PastedProof::Identity(proof)) => self.render_claim(ui, &proof.claim),
+                Some(PastedProof::Masternode(signed)) => {
+                    self.render_masternode_claim(ui, &signed.claim)
+                }
+                None => {
+                    ui.colored_label(Color32::RED, "This is not a proof blob");
+                }
+            }
+        }
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    self.pasted_proof.is_some() && !self.verifying,
+                    egui::Button::new("Verify"),
+                )
+                .on_hover_text(
+                    "Checks the signature against the identity's key on Platform, or the \
+                     masternode's key in Core's masternode list",
+                )
+                .clicked()
+            {
+                let task = match &self.pasted_proof {
+                    Some(PastedProof::Identity(proof)) => Some(BackendTask::IdentityTask(
+                        IdentityTask::VerifyOwnershipProof(proof.clone()),
+                    )),
+                    Some(PastedProof::Masternode(signed)) => Some(BackendTask::CoreTask(
+                        CoreTask::VerifyMasternodeMessage(signed.clone()),
+                    )),
+                    None => None,
+                };
+                if let Some(task) = task {
+                    self.verifying = true;
+                    self.verification = None;
+                    action = AppAction::BackendTask(task);
+                }
+            }
+            if self.verifying {
+                ui.spinner();
+            }
+        });
+
+        match &self.verification {
+            Some(Ok(proven)) => {
+                ui.colored_label(Color32::DARK_GREEN, format!("Valid: {}", proven));
+            }
+            Some(Err(e)) => {
+                ui.colored_label(Color32::RED, format!("Not valid: {}", e));
+            }
+            None => {}
+        }
+        action
+    }
+
+    fn render_claim(&self, ui: &mut Ui, claim: &OwnershipClaim) {
+        let time_format = TimeFormatter::new(&self.app_context.preferences());
+        egui::Grid::new("ownership_proof_claim_grid")
+            .num_columns(2)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("Identity:");
+                ui.label(
+                    RichText::new(redact_id(
+                        &claim.identity_id,
+                        self.app_context.preferences().privacy_mode,
+                    ))
+                    .monospace(),
+                );
+                ui.end_row();
+                ui.label("Key:");
+                ui.label(claim.key_id.to_string());
+                ui.end_row();
+                ui.label("Network:");
+                ui.label(&claim.network);
+                ui.end_row();
+                ui.label("Signed:");
+                ui.label(time_format.format_seconds(claim.signed_at.max(0) as u64));
+                ui.end_row();
+                ui.label("Message:");
+                ui.label(RichText::new(&claim.message).strong());
+                ui.end_row();
+            });
+    }
+
+    fn render_masternode_claim(&self, ui: &mut Ui, claim: &MasternodeMessageClaim) {
+        let time_format = TimeFormatter::new(&self.app_context.preferences());
+        egui::Grid::new("ownership_proof_masternode_claim_grid")
+            .num_columns(2)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("Masternode:");
+                ui.label(
+                    RichText::new(redact_id(
+                        &claim.pro_tx_hash,
+                        self.app_context.preferences().privacy_mode,
+                    ))
+                    .monospace(),
+                );
+                ui.end_row();
+                ui.label("Key:");
+                ui.label(claim.role.to_string());
+                ui.end_row();
+                ui.label("Network:");
+                ui.label(&claim.network);
+                ui.end_row();
+                ui.label("Signed:");
+                ui.label(time_format.format_seconds(claim.signed_at.max(0) as u64));
+                ui.end_row();
+                ui.label("Message:");
+                ui.label(RichText::new(&claim.message).strong());
+                ui.end_row();
+            });
+    }
+}
+
+/// A private key given as hex or WIF
+fn parse_private_key(input: &str, network: Network) -> Result<[u8; 32], String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("Enter the private key".to_string());
+    }
+    if let Ok(bytes) = hex::decode(input) {
+        return bytes
+            .try_into()
+            .map_err(|_| "A hex private key is 32 bytes".to_string());
+    }
+    let private_key = PrivateKey::from_wif(input)
+        .map_err(|_| "The private key is neither hex nor WIF".to_string())?;
+    if private_key.network != network {
+        return Err(format!("The private key is for {}", private_key.network));
+    }
+    Ok(private_key.inner.secret_bytes())
+}
+
+impl ScreenLike for OwnershipProofScreen {
+    fn refresh(&mut self) {
+        match self.app_context.load_local_qualified_identities() {
+            Ok(identities) => {
+                self.masternodes = identities
+                    .iter()
+                    .filter(|identity| {
+                        matches!(
+                            identity.identity_type,
+                            IdentityType::Masternode | IdentityType::Evonode
+                        )
+                    })
+                    .cloned()
+                    .collect();
+                self.identities = identities
+                    .into_iter()
+                    .filter(|identity| !Self::signing_keys(identity).is_empty())
+                    .collect();
+            }
+            Err(e) => {
+                self.message = Some((
+                    format!("Failed to load identities: {}", e),
+                    MessageType::Error,
+                ))
+            }
+        }
+        if self
+            .selected_identity
+            .is_some_and(|index| index >= self.identities.len())
+        {
+            self.selected_identity = None;
+            self.selected_key = None;
+        }
+        if self
+            .selected_masternode
+            .is_some_and(|index| index >= self.masternodes.len())
+        {
+            self.selected_masternode = None;
+        }
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if self.verifying && message_type == MessageType::Error {
+            self.verifying = false;
+            self.verification = Some(Err(message.to_string()));
+            return;
+        }
+        self.message = Some((message.to_string(), message_type));
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        let privacy_mode = self.app_context.preferences().privacy_mode;
+        let proven = match backend_task_success_result {
+            BackendTaskSuccessResult::VerifiedOwnershipProof(proof) => format!(
+                "whoever sent this controls key {} of identity {}",
+                proof.claim.key_id,
+                redact_id(&proof.claim.identity_id, privacy_mode)
+            ),
+            BackendTaskSuccessResult::VerifiedMasternodeMessage(signed) => format!(
+                "whoever sent this holds the {} key of masternode {}",
+                signed.claim.role.to_string().to_lowercase(),
+                redact_id(&signed.claim.pro_tx_hash, privacy_mode)
+            ),
+            _ => return,
+        };
+        self.verifying = false;
+        self.verification = Some(Ok(proven));
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Ownership Proofs", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.label(
+                    RichText::new(
+                        "A proof is a message signed with a key of an identity or masternode. \
+                         Whoever can sign it controls the key, without revealing it.",
+                    )
+                    .color(Color32::GRAY),
+                );
+                if let Some((message, message_type)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => Color32::GRAY,
+                    };
+                    ui.colored_label(color, message);
+                }
+                ui.add_space(10.0);
+                self.render_sign(ui);
+                ui.separator();
+                action |= self.render_verify(ui);
+            });
+        });
+
+        action
+    }
+}
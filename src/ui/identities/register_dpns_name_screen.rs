@@ -11,7 +11,7 @@ use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
 use dash_sdk::dpp::identity::{Purpose, SecurityLevel, TimestampMillis};
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
-use dash_sdk::platform::IdentityPublicKey;
+use dash_sdk::platform::{Identifier, IdentityPublicKey};
 use eframe::egui::Context;
 use futures::StreamExt;
 use std::sync::Arc;
@@ -74,6 +74,30 @@ impl RegisterDpnsNameScreen {
         }
     }
 
+    /// The chosen identity and the typed name, saved with the session
+    pub fn form_input(&self) -> (Option<Identifier>, String) {
+        (
+            self.selected_qualified_identity
+                .as_ref()
+                .map(|(qualified_identity, _)| qualified_identity.identity.id()),
+            self.name_input.clone(),
+        )
+    }
+
+    /// Fills the form with an identity and name saved with the session
+    pub fn with_form_input(mut self, identity_id: Option<Identifier>, name: String) -> Self {
+        if let Some(selected) = identity_id.and_then(|identity_id| {
+            self.qualified_identities
+                .iter()
+                .find(|(qualified_identity, _)| qualified_identity.identity.id() == identity_id)
+                .cloned()
+        }) {
+            self.selected_qualified_identity = Some(selected);
+        }
+        self.name_input = name;
+        self
+    }
+
     fn render_identity_id_selection(&mut self, ui: &mut egui::Ui) {
         ui.horizontal(|ui| {
             ui.label("Identity ID:");
@@ -0,0 +1,265 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::wallet::Wallet;
+use crate::platform::identity::{IdentityTask, IdentityTopUpInfo};
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::fee_selector::{FeePurpose, FeeSelector};
+use crate::ui::components::fiat_value::fiat_label;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::number_format::NumberFormatter;
+use crate::ui::helpers::redaction::identity_label;
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use eframe::egui::{self, Color32, ComboBox, Context, Ui};
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+pub enum TopUpIdentityStatus {
+    NotStarted,
+    WaitingForResult(Instant),
+    ErrorMessage(String),
+    Complete(String),
+}
+
+/// Funds an identity's credits from the balance of one of our wallets
+pub struct TopUpIdentityScreen {
+    pub identity: QualifiedIdentity,
+    selected_wallet: Option<Arc<RwLock<Wallet>>>,
+    /// Amount in DASH
+    amount: String,
+    fee: FeeSelector,
+    status: TopUpIdentityStatus,
+    pub app_context: Arc<AppContext>,
+}
+
+impl TopUpIdentityScreen {
+    pub fn new(identity: QualifiedIdentity, app_context: &Arc<AppContext>) -> Self {
+        let selected_wallet = app_context.wallets.read().unwrap().first().cloned();
+        Self {
+            identity,
+            selected_wallet,
+            amount: String::new(),
+            fee: FeeSelector::new(app_context, FeePurpose::AssetLock),
+            status: TopUpIdentityStatus::NotStarted,
+            app_context: app_context.clone(),
+        }
+    }
+
+    /// The typed amount, saved with the session
+    pub fn form_input(&self) -> String {
+        self.amount.clone()
+    }
+
+    /// Fills the form with an amount saved with the session
+    pub fn with_form_input(mut self, amount: String) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    /// Prefills the amount that brings the balance back up to its minimum, if it is below it
+    pub fn with_shortfall_amount(mut self) -> Self {
+        let balance = self.identity.identity.balance();
+        let identity_id = self.identity.identity.id().to_string(Encoding::Base58);
+        if let Some(minimum) = self
+            .app_context
+            .preferences()
+            .balance_alerts
+            .shortfall_minimum(&identity_id, balance)
+        {
+            // Credits are a thousandth of a duff
+            let shortfall_duffs = (minimum - balance).div_ceil(1000);
+            self.amount = format!("{:.8}", shortfall_duffs as f64 * 1e-8);
+        }
+        self
+    }
+
+    fn render_wallet_selection(&mut self, ui: &mut Ui) {
+        let wallets = self.app_context.wallets.read().unwrap();
+        let selected_wallet_alias = self
+            .selected_wallet
+            .as_ref()
+            .and_then(|wallet| wallet.read().ok()?.alias.clone())
+            .unwrap_or_else(|| "Select".to_string());
+        ui.horizontal(|ui| {
+            ui.label("Fund from wallet:");
+            ComboBox::from_id_salt("top_up_wallet")
+                .selected_text(selected_wallet_alias)
+                .show_ui(ui, |ui| {
+                    for wallet in wallets.iter() {
+                        let wallet_alias = wallet
+                            .read()
+                            .ok()
+                            .and_then(|w| w.alias.clone())
+                            .unwrap_or_else(|| "Unnamed Wallet".to_string());
+                        let is_selected = self
+                            .selected_wallet
+                            .as_ref()
+                            .is_some_and(|selected| Arc::ptr_eq(selected, wallet));
+                        if ui.selectable_label(is_selected, wallet_alias).clicked() {
+                            self.selected_wallet = Some(wallet.clone());
+                        }
+                    }
+                });
+        });
+        if let Some(wallet) = &self.selected_wallet {
+            let max_balance = wallet.read().unwrap().max_balance();
+            ui.label(format!(
+                "Wallet Balance: {:.8} DASH",
+                max_balance as f64 * 1e-8
+            ));
+        }
+    }
+
+    fn render_balance(&self, ui: &mut Ui) {
+        let number_format = NumberFormatter::new(&self.app_context.preferences());
+        let balance = self.identity.identity.balance();
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "Current balance: {}",
+                number_format.format_credits_as_dash(balance)
+            ));
+            fiat_label(ui, &self.app_context, balance);
+        });
+        let identity_id = self.identity.identity.id().to_string(Encoding::Base58);
+        let balance_alerts = self.app_context.preferences().balance_alerts;
+        if let Some(minimum) = balance_alerts.minimum_for(&identity_id) {
+            let text = format!(
+                "Minimum balance: {}",
+                number_format.format_credits_as_dash(minimum)
+            );
+            if balance < minimum {
+                ui.colored_label(Color32::RED, format!("⚠ {}", text));
+            } else {
+                ui.label(text);
+            }
+        }
+    }
+
+    fn parse_amount(&self) -> Result<Duffs, String> {
+        let dash = self
+            .amount
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| "Enter the amount in DASH".to_string())?;
+        if !dash.is_finite() || dash <= 0.0 {
+            return Err("The amount must be positive".to_string());
+        }
+        Ok((dash * 1e8).round() as Duffs)
+    }
+
+    fn top_up_clicked(&mut self) -> AppAction {
+        let Some(wallet) = self.selected_wallet.clone() else {
+            self.status = TopUpIdentityStatus::ErrorMessage("Select a wallet".to_string());
+            return AppAction::None;
+        };
+        let amount = match self.parse_amount() {
+            Ok(amount) => amount,
+            Err(e) => {
+                self.status = TopUpIdentityStatus::ErrorMessage(e);
+                return AppAction::None;
+            }
+        };
+        if amount > wallet.read().unwrap().max_balance() {
+            self.status =
+                TopUpIdentityStatus::ErrorMessage("The wallet does not hold that much".to_string());
+            return AppAction::None;
+        }
+        self.status = TopUpIdentityStatus::WaitingForResult(Instant::now());
+        AppAction::BackendTask(BackendTask::IdentityTask(IdentityTask::TopUpIdentity(
+            IdentityTopUpInfo {
+                qualified_identity: self.identity.clone(),
+                amount,
+                wallet,
+                fee: self.fee.choice,
+            },
+        )))
+    }
+}
+
+impl ScreenLike for TopUpIdentityScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.status = match message_type {
+            MessageType::Error => TopUpIdentityStatus::ErrorMessage(message.to_string()),
+            MessageType::Info | MessageType::Success => {
+                TopUpIdentityStatus::Complete(message.to_string())
+            }
+        };
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if !self.fee.take_estimates(&backend_task_success_result) {
+            self.display_message("Success", MessageType::Success);
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Identities", AppAction::GoToMainScreen),
+                ("Top Up", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading(format!(
+                "Top Up {}",
+                identity_label(&self.identity, self.app_context.preferences().privacy_mode)
+            ));
+            self.render_balance(ui);
+            ui.add_space(10.0);
+
+            if self.app_context.wallets.read().unwrap().is_empty() {
+                ui.label("Add a wallet with a balance to fund top ups from.");
+                return;
+            }
+            self.render_wallet_selection(ui);
+
+            ui.horizontal(|ui| {
+                ui.label("Amount (DASH):");
+                ui.text_edit_singleline(&mut self.amount);
+                if let Ok(duffs) = self.parse_amount() {
+                    // A duff is 1000 credits
+                    fiat_label(ui, &self.app_context, duffs * 1000);
+                }
+            });
+            action |= self.fee.show(ui);
+
+            let waiting = matches!(self.status, TopUpIdentityStatus::WaitingForResult(_));
+            if ui
+                .add_enabled(!waiting, egui::Button::new("Top Up"))
+                .clicked()
+            {
+                action = self.top_up_clicked();
+            }
+
+            match &self.status {
+                TopUpIdentityStatus::NotStarted => {}
+                TopUpIdentityStatus::WaitingForResult(started) => {
+                    let policy = self.app_context.preferences().finality.asset_locks;
+                    ui.label(format!(
+                        "Waiting for {} on the asset lock and the top up... {}s",
+                        policy.describe(),
+                        started.elapsed().as_secs()
+                    ));
+                }
+                TopUpIdentityStatus::ErrorMessage(message) => {
+                    ui.colored_label(Color32::RED, format!("Error: {}", message));
+                }
+                TopUpIdentityStatus::Complete(message) => {
+                    ui.label(message);
+                    if ui.button("Back to Identities").clicked() {
+                        action = AppAction::PopScreenAndRefresh;
+                    }
+                }
+            }
+        });
+
+        action
+    }
+}
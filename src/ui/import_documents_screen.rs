@@ -0,0 +1,450 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::document_import::{
+    auto_map, failure_report, form_inputs, parse_import_file, ImportRecord,
+};
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::platform::document::{DocumentTask, DocumentTypeName};
+use crate::platform::document_import::DocumentImport;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::json_schema_form::JsonSchemaForm;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dash_sdk::dpp::data_contract::DataContract;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::dpp::platform_value::Value;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// A record turned into document properties, or the problems found in it
+struct PreparedRecord {
+    number: usize,
+    properties: Result<BTreeMap<String, Value>, Vec<String>>,
+    /// The created document's id or why it was not created, once submitted
+    outcome: Option<Result<Identifier, String>>,
+}
+
+/// Creates documents of a type from the records of a CSV file or JSON array, with the columns
+/// mapped onto the properties of the document type
+pub struct ImportDocumentsScreen {
+    pub app_context: Arc<AppContext>,
+    pub contract_id: Identifier,
+    pub document_type: DocumentTypeName,
+    contract: Option<Arc<DataContract>>,
+    schema: serde_json::Value,
+    file_path_input: String,
+    pasted_input: String,
+    columns: Vec<String>,
+    records: Vec<ImportRecord>,
+    /// Each property with the column it is read from
+    mapping: Vec<(String, Option<String>)>,
+    prepared: Vec<PreparedRecord>,
+    identities: Vec<QualifiedIdentity>,
+    owner_index: usize,
+    batch_size: usize,
+    importing: bool,
+    progress: Option<(usize, usize)>,
+    error_message: Option<String>,
+}
+
+impl ImportDocumentsScreen {
+    pub fn new(
+        contract_id: Identifier,
+        document_type: DocumentTypeName,
+        app_context: &Arc<AppContext>,
+    ) -> Self {
+        let contract = app_context
+            .db
+            .get_contract_by_id(contract_id, app_context)
+            .ok()
+            .flatten()
+            .map(|qualified_contract| Arc::new(qualified_contract.contract));
+        let schema = contract
+            .as_ref()
+            .and_then(|contract| contract.document_type_for_name(&document_type).ok())
+            .and_then(|doc_type| serde_json::to_value(doc_type.schema()).ok())
+            .unwrap_or_default();
+        let error_message = match &contract {
+            None => Some("The contract is not stored locally, add it first".to_string()),
+            Some(_) if schema.is_null() => Some(format!(
+                "The contract has no document type {}",
+                document_type
+            )),
+            Some(_) => None,
+        };
+        Self {
+            app_context: app_context.clone(),
+            contract_id,
+            document_type,
+            contract,
+            schema,
+            file_path_input: String::new(),
+            pasted_input: String::new(),
+            columns: Vec::new(),
+            records: Vec::new(),
+            mapping: Vec::new(),
+            prepared: Vec::new(),
+            identities: app_context
+                .load_local_qualified_identities()
+                .unwrap_or_default(),
+            owner_index: 0,
+            batch_size: 10,
+            importing: false,
+            progress: None,
+            error_message,
+        }
+    }
+
+    fn properties(&self) -> Vec<String> {
+        self.schema
+            .get("properties")
+            .and_then(serde_json::Value::as_object)
+            .map(|properties| properties.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn load(&mut self, contents: &str) {
+        self.prepared.clear();
+        self.progress = None;
+        match parse_import_file(contents) {
+            Ok((columns, records)) => {
+                self.mapping = auto_map(&self.properties(), &columns);
+                self.columns = columns;
+                self.records = records;
+                self.error_message = None;
+            }
+            Err(e) => {
+                self.columns.clear();
+                self.records.clear();
+                self.mapping.clear();
+                self.error_message = Some(e);
+            }
+        }
+    }
+
+    /// Checks every record against the schema with the current mapping
+    fn prepare(&mut self) {
+        let mut form = JsonSchemaForm::new("document_import", &self.schema);
+        self.prepared = self
+            .records
+            .iter()
+            .map(|record| {
+                form.restore_inputs(&form_inputs(record, &self.mapping));
+                PreparedRecord {
+                    number: record.number,
+                    properties: form.properties(),
+                    outcome: None,
+                }
+            })
+            .collect();
+    }
+
+    fn failures(&self) -> Vec<(usize, String)> {
+        self.prepared
+            .iter()
+            .filter_map(|record| match (&record.properties, &record.outcome) {
+                (Err(errors), _) => Some((record.number, errors.join("; "))),
+                (_, Some(Err(e))) => Some((record.number, e.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn render_source(&mut self, ui: &mut Ui) {
+        ui.heading("1. Source");
+        ui.label(
+            "A CSV file with a header line naming the columns, or a JSON array of objects. \
+             Arrays and objects in CSV cells are written as JSON, byte arrays as hex.",
+        );
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            ui.text_edit_singleline(&mut self.file_path_input);
+            if ui.button("Read File").clicked() {
+                match std::fs::read_to_string(self.file_path_input.trim()) {
+                    Ok(contents) => self.load(&contents),
+                    Err(e) => self.error_message = Some(format!("Failed to read file: {}", e)),
+                }
+            }
+        });
+        ui.label("Or paste the records:");
+        ui.add(
+            egui::TextEdit::multiline(&mut self.pasted_input)
+                .desired_rows(5)
+                .desired_width(f32::INFINITY)
+                .code_editor(),
+        );
+        if ui.button("Read Pasted Records").clicked() {
+            let contents = self.pasted_input.clone();
+            self.load(&contents);
+        }
+        if !self.records.is_empty() {
+            ui.label(format!(
+                "{} records with the columns {}",
+                self.records.len(),
+                self.columns.join(", ")
+            ));
+        }
+    }
+
+    fn render_mapping(&mut self, ui: &mut Ui) {
+        ui.heading("2. Map Fields");
+        let before = self.mapping.clone();
+        egui::Grid::new("document_import_mapping")
+            .num_columns(2)
+            .spacing([20.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Property").strong());
+                ui.label(RichText::new("Column").strong());
+                ui.end_row();
+                for (property, column) in &mut self.mapping {
+                    ui.label(property.as_str());
+                    egui::ComboBox::from_id_salt(("document_import_column", property.as_str()))
+                        .selected_text(column.as_deref().unwrap_or("Not imported"))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(column, None, "Not imported");
+                            for name in &self.columns {
+                                ui.selectable_value(column, Some(name.clone()), name);
+                            }
+                        });
+                    ui.end_row();
+                }
+            });
+        if self.mapping != before {
+            self.prepared.clear();
+        }
+        if ui.button("Check Records").clicked() {
+            self.prepare();
+        }
+    }
+
+    fn render_review(&self, ui: &mut Ui) {
+        ui.heading("3. Review");
+        let invalid: Vec<&PreparedRecord> = self
+            .prepared
+            .iter()
+            .filter(|record| record.properties.is_err())
+            .collect();
+        ui.label(format!(
+            "{} records, {} valid, {} with problems that are skipped",
+            self.prepared.len(),
+            self.prepared.len() - invalid.len(),
+            invalid.len()
+        ));
+        egui::ScrollArea::vertical()
+            .id_salt("document_import_problems")
+            .max_height(150.0)
+            .show(ui, |ui| {
+                for record in invalid {
+                    if let Err(errors) = &record.properties {
+                        ui.colored_label(
+                            Color32::RED,
+                            format!("Record {}: {}", record.number, errors.join(", ")),
+                        );
+                    }
+                }
+            });
+    }
+
+    fn render_import(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("4. Import");
+        if self.identities.is_empty() {
+            ui.label("Load the identity that should own the documents first.");
+            return action;
+        }
+        self.owner_index = self.owner_index.min(self.identities.len() - 1);
+        ui.horizontal(|ui| {
+            ui.label("Owner:");
+            egui::ComboBox::from_id_salt("document_import_owner")
+                .selected_text(self.identities[self.owner_index].display_short_string())
+                .show_ui(ui, |ui| {
+                    for (i, identity) in self.identities.iter().enumerate() {
+                        ui.selectable_value(
+                            &mut self.owner_index,
+                            i,
+                            identity.display_short_string(),
+                        );
+                    }
+                });
+            ui.label("Batch size:");
+            ui.add(egui::DragValue::new(&mut self.batch_size).range(1..=100))
+                .on_hover_text(
+                    "Progress is reported after each batch. The import stops after a batch in \
+                     which every document failed.",
+                );
+        });
+        ui.label(
+            RichText::new(
+                "Each document is its own transition paid by the owner. They are sent one \
+                 after another within the DAPI rate limit.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        let pending: Vec<(usize, BTreeMap<String, Value>)> = self
+            .prepared
+            .iter()
+            .filter(|record| !matches!(record.outcome, Some(Ok(_))))
+            .filter_map(|record| {
+                let properties = record.properties.as_ref().ok()?;
+                Some((record.number, properties.clone()))
+            })
+            .collect();
+        ui.horizontal(|ui| {
+            let button = egui::Button::new(format!("Import {} Documents", pending.len()));
+            if ui
+                .add_enabled(!pending.is_empty() && !self.importing, button)
+                .clicked()
+            {
+                if let Some(contract) = &self.contract {
+                    self.importing = true;
+                    self.progress = Some((0, pending.len()));
+                    action = AppAction::BackendTask(BackendTask::DocumentTask(
+                        DocumentTask::ImportDocuments(DocumentImport {
+                            contract: contract.clone(),
+                            document_type: self.document_type.clone(),
+                            owner: self.identities[self.owner_index].clone(),
+                            documents: pending,
+                            batch_size: self.batch_size,
+                        }),
+                    ));
+                }
+            }
+            if let Some((done, total)) = self.progress {
+                if self.importing {
+                    ui.spinner();
+                }
+                ui.add(
+                    egui::ProgressBar::new(done as f32 / total.max(1) as f32)
+                        .text(format!("{} of {}", done, total))
+                        .desired_width(200.0),
+                );
+            }
+        });
+        self.render_outcome(ui);
+        action
+    }
+
+    fn render_outcome(&self, ui: &mut Ui) {
+        let created = self
+            .prepared
+            .iter()
+            .filter(|record| matches!(record.outcome, Some(Ok(_))))
+            .count();
+        let failures = self.failures();
+        if created == 0 && !self.prepared.iter().any(|record| record.outcome.is_some()) {
+            return;
+        }
+        let text = format!("{} created, {} not imported", created, failures.len());
+        let color = if failures.is_empty() {
+            Color32::DARK_GREEN
+        } else {
+            Color32::RED
+        };
+        ui.label(RichText::new(text).color(color));
+        if failures.is_empty() {
+            return;
+        }
+        if ui
+            .button("Copy Failure Report")
+            .on_hover_text("A CSV of the records that were not imported, with the reason")
+            .clicked()
+        {
+            ui.ctx().copy_text(failure_report(&failures));
+        }
+        egui::ScrollArea::vertical()
+            .id_salt("document_import_failures")
+            .max_height(150.0)
+            .show(ui, |ui| {
+                for (number, error) in &failures {
+                    ui.colored_label(Color32::RED, format!("Record {}: {}", number, error));
+                }
+            });
+    }
+}
+
+impl ScreenLike for ImportDocumentsScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.importing = false;
+            self.error_message = Some(message.to_string());
+        }
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        match backend_task_success_result {
+            BackendTaskSuccessResult::DocumentImportProgress { done, total } => {
+                self.progress = Some((done, total));
+            }
+            BackendTaskSuccessResult::ImportedDocuments(outcomes) => {
+                self.importing = false;
+                for (number, outcome) in outcomes {
+                    if let Some(record) = self
+                        .prepared
+                        .iter_mut()
+                        .find(|record| record.number == number)
+                    {
+                        record.outcome = Some(outcome);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Import Documents", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                let contract_name = self
+                    .contract
+                    .as_ref()
+                    .map(|contract| contract.id().to_string(Encoding::Base58))
+                    .unwrap_or_else(|| self.contract_id.to_string(Encoding::Base58));
+                ui.heading(format!(
+                    "Import {} documents of {}",
+                    self.document_type, contract_name
+                ));
+                if let Some(error) = &self.error_message {
+                    ui.colored_label(Color32::RED, error);
+                }
+                if self.contract.is_none() || self.schema.is_null() {
+                    return;
+                }
+                ui.add_space(5.0);
+                self.render_source(ui);
+                if self.records.is_empty() {
+                    return;
+                }
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_mapping(ui);
+                if self.prepared.is_empty() {
+                    return;
+                }
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_review(ui);
+                ui.add_space(10.0);
+                ui.separator();
+                action |= self.render_import(ui);
+            });
+        });
+
+        action
+    }
+}
@@ -2,6 +2,7 @@ use crate::app::AppAction;
 use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::redaction::{redact_secret, REDACTED};
 use crate::ui::ScreenLike;
 use dash_sdk::dpp::dashcore::address::Payload;
 use dash_sdk::dpp::dashcore::hashes::Hash;
@@ -40,6 +41,7 @@ impl ScreenLike for KeyInfoScreen {
             vec![],
         );
 
+        let privacy_mode = self.app_context.preferences().privacy_mode;
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Key Information");
 
@@ -88,12 +90,18 @@ impl ScreenLike for KeyInfoScreen {
                         KeyType::ECDSA_SECP256K1 | KeyType::BLS12_381 => {
                             // Public Key Hex
                             ui.label(RichText::new("Public Key (Hex):").strong());
-                            ui.label(self.key.data().to_string(Encoding::Hex));
+                            ui.label(redact_secret(
+                                &self.key.data().to_string(Encoding::Hex),
+                                privacy_mode,
+                            ));
                             ui.end_row();
 
                             // Public Key Hex
                             ui.label(RichText::new("Public Key (Base64):").strong());
-                            ui.label(self.key.data().to_string(Encoding::Base64));
+                            ui.label(redact_secret(
+                                &self.key.data().to_string(Encoding::Base64),
+                                privacy_mode,
+                            ));
                             ui.end_row();
                         }
                         _ => {}
@@ -104,7 +112,7 @@ impl ScreenLike for KeyInfoScreen {
                     match self.key.public_key_hash() {
                         Ok(hash) => {
                             let hash_hex = hex::encode(hash);
-                            ui.label(hash_hex);
+                            ui.label(redact_secret(&hash_hex, privacy_mode));
                         }
                         Err(e) => {
                             ui.colored_label(egui::Color32::RED, format!("Error: {}", e));
@@ -127,7 +135,7 @@ impl ScreenLike for KeyInfoScreen {
                                         Payload::PubkeyHash(PubkeyHash::from_byte_array(hash)),
                                     )
                                 };
-                                ui.label(address.to_string());
+                                ui.label(redact_secret(&address.to_string(), privacy_mode));
                             }
                             Err(e) => {
                                 ui.colored_label(egui::Color32::RED, format!("Error: {}", e));
@@ -141,7 +149,10 @@ impl ScreenLike for KeyInfoScreen {
             ui.separator();
 
             // Display the private key if available
-            if let Some(private_key) = &self.private_key_bytes {
+            if self.private_key_bytes.is_some() && privacy_mode {
+                ui.label("Private Key:");
+                ui.label(REDACTED);
+            } else if let Some(private_key) = &self.private_key_bytes {
                 ui.label("Private Key:");
                 let private_key_hex = hex::encode(private_key);
                 ui.add(
@@ -150,7 +161,7 @@ impl ScreenLike for KeyInfoScreen {
                 );
             } else {
                 ui.label("Enter Private Key:");
-                ui.text_edit_singleline(&mut self.private_key_input);
+                ui.add(TextEdit::singleline(&mut self.private_key_input).password(privacy_mode));
 
                 if ui.button("Add Private Key").clicked() {
                     self.validate_and_store_private_key();
@@ -1,39 +1,87 @@
-use crate::app::AppAction;
+use crate::app::{AppAction, AppEvent};
 use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::session::SavedScreen;
+use crate::platform::document::DocumentTypeName;
 use crate::platform::BackendTaskSuccessResult;
 use crate::ui::add_key_screen::AddKeyScreen;
+use crate::ui::app_profile_screen::AppProfileScreen;
+use crate::ui::app_profiles_screen::AppProfilesScreen;
+use crate::ui::cold_signing_screen::ColdSigningScreen;
+use crate::ui::contract_analytics_screen::ContractAnalyticsScreen;
+use crate::ui::delete_documents_screen::DeleteDocumentsScreen;
+use crate::ui::diagnostics_screen::DiagnosticsScreen;
 use crate::ui::document_query_screen::DocumentQueryScreen;
 use crate::ui::dpns_contested_names_screen::DPNSContestedNamesScreen;
+use crate::ui::dpns_lookup_screen::DpnsLookupScreen;
 use crate::ui::identities::add_new_wallet_screen::AddNewWalletScreen;
+use crate::ui::import_documents_screen::ImportDocumentsScreen;
 use crate::ui::key_info_screen::KeyInfoScreen;
 use crate::ui::keys_screen::KeysScreen;
 use crate::ui::network_chooser_screen::NetworkChooserScreen;
+use crate::ui::node_logs_screen::NodeLogsScreen;
+use crate::ui::scheduler_screen::SchedulerScreen;
+use crate::ui::settings_screen::SettingsScreen;
 use crate::ui::transfers::TransferScreen;
+use crate::ui::transition_sandbox_screen::TransitionSandboxScreen;
 use crate::ui::transition_visualizer_screen::TransitionVisualizerScreen;
+use crate::ui::vote_lists_screen::VoteListsScreen;
+use crate::ui::vote_receipts_screen::VoteReceiptsScreen;
+use crate::ui::voting_blocs_screen::VotingBlocsScreen;
+use crate::ui::withdrawals::queue_screen::WithdrawalQueueScreen;
 use crate::ui::withdrawals::WithdrawalScreen;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::identity::Identity;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::dpp::prelude::IdentityPublicKey;
+use dash_sdk::platform::Identifier;
 use egui::Context;
 use enum_dispatch::enum_dispatch;
+use identities::accounting_export_screen::AccountingExportScreen;
 use identities::add_existing_identity_screen::AddExistingIdentityScreen;
 use identities::add_new_identity_screen::AddNewIdentityScreen;
 use identities::identities_screen::IdentitiesScreen;
+use identities::identity_detail_screen::IdentityDetailScreen;
+use identities::import_identities_csv_screen::ImportIdentitiesCsvScreen;
+use identities::import_masternodes_screen::ImportMasternodesScreen;
+use identities::key_rotation_screen::KeyRotationScreen;
+use identities::key_usage_screen::KeyUsageScreen;
+use identities::masternode_dashboard_screen::MasternodeDashboardScreen;
+use identities::masternode_payouts_screen::MasternodePayoutsScreen;
+use identities::ownership_proof_screen::OwnershipProofScreen;
 use identities::register_dpns_name_screen::RegisterDpnsNameScreen;
+use identities::top_up_identity_screen::TopUpIdentityScreen;
 use std::fmt;
 use std::hash::Hash;
 use std::sync::Arc;
 
 mod add_key_screen;
+pub mod app_profile_screen;
+pub mod app_profiles_screen;
+pub mod cold_signing_screen;
 pub mod components;
+pub mod contract_analytics_screen;
+pub mod delete_documents_screen;
+pub mod diagnostics_screen;
 pub mod document_query_screen;
 pub mod dpns_contested_names_screen;
+pub mod dpns_lookup_screen;
+pub mod help;
+pub mod helpers;
 pub(crate) mod identities;
+pub mod import_documents_screen;
 pub mod key_info_screen;
 pub mod keys_screen;
 pub mod network_chooser_screen;
+pub mod node_logs_screen;
+pub mod scheduler_screen;
+pub mod settings_screen;
 pub mod transfers;
+pub mod transition_sandbox_screen;
 pub mod transition_visualizer_screen;
+pub mod vote_lists_screen;
+pub mod vote_receipts_screen;
+pub mod voting_blocs_screen;
 pub mod withdrawals;
 
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -92,15 +140,41 @@ pub enum ScreenType {
     AddNewIdentity,
     AddNewWallet,
     AddExistingIdentity,
+    ImportMasternodes,
+    ImportIdentitiesCsv,
     TransitionVisualizer,
     WithdrawalScreen(QualifiedIdentity),
     TransferScreen(QualifiedIdentity),
+    TopUpIdentity(QualifiedIdentity),
     AddKeyScreen(QualifiedIdentity),
+    KeyRotation(QualifiedIdentity),
     KeyInfo(QualifiedIdentity, IdentityPublicKey, Option<[u8; 32]>),
     Keys(Identity),
     DocumentQueryScreen,
     NetworkChooser,
     RegisterDpnsName,
+    IdentityDetail(Identifier),
+    KeyUsage(Identifier),
+    WithdrawalQueue,
+    MasternodePayouts,
+    MasternodeDashboard,
+    AccountingExport,
+    Diagnostics,
+    NodeLogs,
+    VotingBlocs,
+    VoteLists,
+    VoteReceipts,
+    DpnsLookup,
+    ImportDocuments(Identifier, DocumentTypeName),
+    DeleteDocuments(Identifier, DocumentTypeName),
+    ContractAnalytics(Identifier),
+    ColdSigning,
+    OwnershipProof,
+    AppProfiles,
+    AppProfile(String),
+    Scheduler,
+    TransitionSandbox,
+    Settings,
 }
 
 impl ScreenType {
@@ -116,6 +190,12 @@ impl ScreenType {
             ScreenType::AddExistingIdentity => {
                 Screen::AddExistingIdentityScreen(AddExistingIdentityScreen::new(app_context))
             }
+            ScreenType::ImportMasternodes => {
+                Screen::ImportMasternodesScreen(ImportMasternodesScreen::new(app_context))
+            }
+            ScreenType::ImportIdentitiesCsv => {
+                Screen::ImportIdentitiesCsvScreen(ImportIdentitiesCsvScreen::new(app_context))
+            }
             ScreenType::Keys(identity) => {
                 Screen::KeysScreen(KeysScreen::new(identity.clone(), app_context))
             }
@@ -139,18 +219,88 @@ impl ScreenType {
             ScreenType::TransferScreen(identity) => {
                 Screen::TransferScreen(TransferScreen::new(identity.clone(), app_context))
             }
+            ScreenType::TopUpIdentity(identity) => {
+                Screen::TopUpIdentityScreen(TopUpIdentityScreen::new(identity.clone(), app_context))
+            }
             ScreenType::NetworkChooser => {
                 unreachable!()
             }
             ScreenType::AddKeyScreen(identity) => {
                 Screen::AddKeyScreen(AddKeyScreen::new(identity.clone(), app_context))
             }
+            ScreenType::KeyRotation(identity) => {
+                Screen::KeyRotationScreen(KeyRotationScreen::new(identity.clone(), app_context))
+            }
             ScreenType::DocumentQueryScreen => {
                 Screen::DocumentQueryScreen(DocumentQueryScreen::new(app_context))
             }
             ScreenType::AddNewWallet => {
                 Screen::AddNewWalletScreen(AddNewWalletScreen::new(app_context))
             }
+            ScreenType::IdentityDetail(identity_id) => {
+                Screen::IdentityDetailScreen(IdentityDetailScreen::new(*identity_id, app_context))
+            }
+            ScreenType::KeyUsage(identity_id) => {
+                Screen::KeyUsageScreen(KeyUsageScreen::new(*identity_id, app_context))
+            }
+            ScreenType::WithdrawalQueue => {
+                Screen::WithdrawalQueueScreen(WithdrawalQueueScreen::new(app_context))
+            }
+            ScreenType::MasternodePayouts => {
+                Screen::MasternodePayoutsScreen(MasternodePayoutsScreen::new(app_context))
+            }
+            ScreenType::MasternodeDashboard => {
+                Screen::MasternodeDashboardScreen(MasternodeDashboardScreen::new(app_context))
+            }
+            ScreenType::AccountingExport => {
+                Screen::AccountingExportScreen(AccountingExportScreen::new(app_context))
+            }
+            ScreenType::ColdSigning => {
+                Screen::ColdSigningScreen(ColdSigningScreen::new(app_context))
+            }
+            ScreenType::OwnershipProof => {
+                Screen::OwnershipProofScreen(OwnershipProofScreen::new(app_context))
+            }
+            ScreenType::Diagnostics => {
+                Screen::DiagnosticsScreen(DiagnosticsScreen::new(app_context))
+            }
+            ScreenType::NodeLogs => Screen::NodeLogsScreen(NodeLogsScreen::new(app_context)),
+            ScreenType::VotingBlocs => {
+                Screen::VotingBlocsScreen(VotingBlocsScreen::new(app_context))
+            }
+            ScreenType::VoteLists => Screen::VoteListsScreen(VoteListsScreen::new(app_context)),
+            ScreenType::VoteReceipts => {
+                Screen::VoteReceiptsScreen(VoteReceiptsScreen::new(app_context))
+            }
+            ScreenType::DpnsLookup => Screen::DpnsLookupScreen(DpnsLookupScreen::new(app_context)),
+            ScreenType::ImportDocuments(contract_id, document_type) => {
+                Screen::ImportDocumentsScreen(ImportDocumentsScreen::new(
+                    *contract_id,
+                    document_type.clone(),
+                    app_context,
+                ))
+            }
+            ScreenType::ContractAnalytics(contract_id) => Screen::ContractAnalyticsScreen(
+                ContractAnalyticsScreen::new(*contract_id, app_context),
+            ),
+            ScreenType::DeleteDocuments(contract_id, document_type) => {
+                Screen::DeleteDocumentsScreen(DeleteDocumentsScreen::new(
+                    *contract_id,
+                    document_type.clone(),
+                    app_context,
+                ))
+            }
+            ScreenType::AppProfiles => {
+                Screen::AppProfilesScreen(AppProfilesScreen::new(app_context))
+            }
+            ScreenType::AppProfile(name) => {
+                Screen::AppProfileScreen(AppProfileScreen::new(name.clone(), app_context))
+            }
+            ScreenType::Scheduler => Screen::SchedulerScreen(SchedulerScreen::new(app_context)),
+            ScreenType::TransitionSandbox => {
+                Screen::TransitionSandboxScreen(TransitionSandboxScreen::new(app_context))
+            }
+            ScreenType::Settings => Screen::SettingsScreen(SettingsScreen::new(app_context)),
         }
     }
 }
@@ -163,14 +313,40 @@ pub enum Screen {
     AddNewWalletScreen(AddNewWalletScreen),
     AddNewIdentityScreen(AddNewIdentityScreen),
     AddExistingIdentityScreen(AddExistingIdentityScreen),
+    ImportMasternodesScreen(ImportMasternodesScreen),
+    ImportIdentitiesCsvScreen(ImportIdentitiesCsvScreen),
     KeyInfoScreen(KeyInfoScreen),
     KeysScreen(KeysScreen),
     RegisterDpnsNameScreen(RegisterDpnsNameScreen),
     WithdrawalScreen(WithdrawalScreen),
     TransferScreen(TransferScreen),
+    TopUpIdentityScreen(TopUpIdentityScreen),
     AddKeyScreen(AddKeyScreen),
+    KeyRotationScreen(KeyRotationScreen),
     TransitionVisualizerScreen(TransitionVisualizerScreen),
     NetworkChooserScreen(NetworkChooserScreen),
+    IdentityDetailScreen(IdentityDetailScreen),
+    KeyUsageScreen(KeyUsageScreen),
+    WithdrawalQueueScreen(WithdrawalQueueScreen),
+    MasternodePayoutsScreen(MasternodePayoutsScreen),
+    MasternodeDashboardScreen(MasternodeDashboardScreen),
+    AccountingExportScreen(AccountingExportScreen),
+    DiagnosticsScreen(DiagnosticsScreen),
+    NodeLogsScreen(NodeLogsScreen),
+    VotingBlocsScreen(VotingBlocsScreen),
+    VoteListsScreen(VoteListsScreen),
+    VoteReceiptsScreen(VoteReceiptsScreen),
+    DpnsLookupScreen(DpnsLookupScreen),
+    ImportDocumentsScreen(ImportDocumentsScreen),
+    DeleteDocumentsScreen(DeleteDocumentsScreen),
+    ContractAnalyticsScreen(ContractAnalyticsScreen),
+    ColdSigningScreen(ColdSigningScreen),
+    OwnershipProofScreen(OwnershipProofScreen),
+    AppProfilesScreen(AppProfilesScreen),
+    AppProfileScreen(AppProfileScreen),
+    SchedulerScreen(SchedulerScreen),
+    TransitionSandboxScreen(TransitionSandboxScreen),
+    SettingsScreen(SettingsScreen),
 }
 
 impl Screen {
@@ -179,17 +355,43 @@ impl Screen {
             Screen::IdentitiesScreen(screen) => screen.app_context = app_context,
             Screen::DPNSContestedNamesScreen(screen) => screen.app_context = app_context,
             Screen::AddExistingIdentityScreen(screen) => screen.app_context = app_context,
+            Screen::ImportMasternodesScreen(screen) => screen.app_context = app_context,
+            Screen::ImportIdentitiesCsvScreen(screen) => screen.app_context = app_context,
             Screen::KeyInfoScreen(screen) => screen.app_context = app_context,
             Screen::KeysScreen(screen) => screen.app_context = app_context,
             Screen::WithdrawalScreen(screen) => screen.app_context = app_context,
             Screen::TransitionVisualizerScreen(screen) => screen.app_context = app_context,
             Screen::NetworkChooserScreen(screen) => screen.current_network = app_context.network,
             Screen::AddKeyScreen(screen) => screen.app_context = app_context,
+            Screen::KeyRotationScreen(screen) => screen.app_context = app_context,
             Screen::DocumentQueryScreen(screen) => screen.app_context = app_context,
             Screen::AddNewIdentityScreen(screen) => screen.app_context = app_context,
             Screen::RegisterDpnsNameScreen(screen) => screen.app_context = app_context,
             Screen::AddNewWalletScreen(screen) => screen.app_context = app_context,
             Screen::TransferScreen(screen) => screen.app_context = app_context,
+            Screen::TopUpIdentityScreen(screen) => screen.app_context = app_context,
+            Screen::IdentityDetailScreen(screen) => screen.app_context = app_context,
+            Screen::KeyUsageScreen(screen) => screen.app_context = app_context,
+            Screen::WithdrawalQueueScreen(screen) => screen.app_context = app_context,
+            Screen::MasternodePayoutsScreen(screen) => screen.app_context = app_context,
+            Screen::MasternodeDashboardScreen(screen) => screen.app_context = app_context,
+            Screen::AccountingExportScreen(screen) => screen.app_context = app_context,
+            Screen::DiagnosticsScreen(screen) => screen.app_context = app_context,
+            Screen::NodeLogsScreen(screen) => screen.app_context = app_context,
+            Screen::VotingBlocsScreen(screen) => screen.app_context = app_context,
+            Screen::VoteListsScreen(screen) => screen.app_context = app_context,
+            Screen::VoteReceiptsScreen(screen) => screen.app_context = app_context,
+            Screen::ImportDocumentsScreen(screen) => screen.app_context = app_context,
+            Screen::DeleteDocumentsScreen(screen) => screen.app_context = app_context,
+            Screen::ContractAnalyticsScreen(screen) => screen.app_context = app_context,
+            Screen::ColdSigningScreen(screen) => screen.app_context = app_context,
+            Screen::OwnershipProofScreen(screen) => screen.app_context = app_context,
+            Screen::DpnsLookupScreen(screen) => screen.app_context = app_context,
+            Screen::AppProfilesScreen(screen) => screen.app_context = app_context,
+            Screen::AppProfileScreen(screen) => screen.app_context = app_context,
+            Screen::SchedulerScreen(screen) => screen.app_context = app_context,
+            Screen::TransitionSandboxScreen(screen) => screen.app_context = app_context,
+            Screen::SettingsScreen(screen) => screen.app_context = app_context,
         }
     }
 }
@@ -214,6 +416,9 @@ pub trait ScreenLike {
     }
 
     fn pop_on_success(&mut self) {}
+
+    /// Called for every event published anywhere in the app, whether the screen is visible or not
+    fn on_event(&mut self, _event: AppEvent) {}
 }
 
 // Implement Debug for Screen using the ScreenType
@@ -234,6 +439,8 @@ impl Screen {
     pub fn screen_type(&self) -> ScreenType {
         match self {
             Screen::AddExistingIdentityScreen(_) => ScreenType::AddExistingIdentity,
+            Screen::ImportMasternodesScreen(_) => ScreenType::ImportMasternodes,
+            Screen::ImportIdentitiesCsvScreen(_) => ScreenType::ImportIdentitiesCsv,
             Screen::KeysScreen(screen) => ScreenType::Keys(screen.identity.clone()),
             Screen::KeyInfoScreen(screen) => ScreenType::KeyInfo(
                 screen.identity.clone(),
@@ -248,11 +455,246 @@ impl Screen {
             }
             Screen::NetworkChooserScreen(_) => ScreenType::NetworkChooser,
             Screen::AddKeyScreen(screen) => ScreenType::AddKeyScreen(screen.identity.clone()),
+            Screen::KeyRotationScreen(screen) => ScreenType::KeyRotation(screen.identity.clone()),
             Screen::DocumentQueryScreen(_) => ScreenType::DocumentQueryScreen,
-            Screen::AddNewIdentityScreen(_) => ScreenType::AddExistingIdentity,
+            Screen::AddNewIdentityScreen(_) => ScreenType::AddNewIdentity,
             Screen::RegisterDpnsNameScreen(_) => ScreenType::RegisterDpnsName,
             Screen::AddNewWalletScreen(_) => ScreenType::AddNewWallet,
             Screen::TransferScreen(screen) => ScreenType::TransferScreen(screen.identity.clone()),
+            Screen::TopUpIdentityScreen(screen) => {
+                ScreenType::TopUpIdentity(screen.identity.clone())
+            }
+            Screen::IdentityDetailScreen(screen) => ScreenType::IdentityDetail(screen.identity_id),
+            Screen::KeyUsageScreen(screen) => ScreenType::KeyUsage(screen.identity_id),
+            Screen::WithdrawalQueueScreen(_) => ScreenType::WithdrawalQueue,
+            Screen::MasternodePayoutsScreen(_) => ScreenType::MasternodePayouts,
+            Screen::MasternodeDashboardScreen(_) => ScreenType::MasternodeDashboard,
+            Screen::AccountingExportScreen(_) => ScreenType::AccountingExport,
+            Screen::DiagnosticsScreen(_) => ScreenType::Diagnostics,
+            Screen::NodeLogsScreen(_) => ScreenType::NodeLogs,
+            Screen::VotingBlocsScreen(_) => ScreenType::VotingBlocs,
+            Screen::VoteListsScreen(_) => ScreenType::VoteLists,
+            Screen::VoteReceiptsScreen(_) => ScreenType::VoteReceipts,
+            Screen::DpnsLookupScreen(_) => ScreenType::DpnsLookup,
+            Screen::ImportDocumentsScreen(screen) => {
+                ScreenType::ImportDocuments(screen.contract_id, screen.document_type.clone())
+            }
+            Screen::DeleteDocumentsScreen(screen) => {
+                ScreenType::DeleteDocuments(screen.contract_id, screen.document_type.clone())
+            }
+            Screen::ContractAnalyticsScreen(screen) => {
+                ScreenType::ContractAnalytics(screen.contract_id)
+            }
+            Screen::ColdSigningScreen(_) => ScreenType::ColdSigning,
+            Screen::OwnershipProofScreen(_) => ScreenType::OwnershipProof,
+            Screen::AppProfilesScreen(_) => ScreenType::AppProfiles,
+            Screen::AppProfileScreen(screen) => ScreenType::AppProfile(screen.profile_name.clone()),
+            Screen::SchedulerScreen(_) => ScreenType::Scheduler,
+            Screen::TransitionSandboxScreen(_) => ScreenType::TransitionSandbox,
+            Screen::SettingsScreen(_) => ScreenType::Settings,
         }
     }
+
+    /// The screen as saved with the session, `None` for root screens and screens holding
+    /// private keys
+    pub fn to_saved(&self) -> Option<SavedScreen> {
+        let id = |identity_id: Identifier| identity_id.to_string(Encoding::Base58);
+        Some(match self {
+            Screen::AddNewIdentityScreen(_) => SavedScreen::AddNewIdentity,
+            Screen::AddExistingIdentityScreen(_) => SavedScreen::AddExistingIdentity,
+            Screen::AddNewWalletScreen(_) => SavedScreen::AddNewWallet,
+            Screen::ImportMasternodesScreen(_) => SavedScreen::ImportMasternodes,
+            Screen::ImportIdentitiesCsvScreen(_) => SavedScreen::ImportIdentitiesCsv,
+            Screen::IdentityDetailScreen(screen) => SavedScreen::IdentityDetail {
+                identity_id: id(screen.identity_id),
+            },
+            Screen::KeyUsageScreen(screen) => SavedScreen::KeyUsage {
+                identity_id: id(screen.identity_id),
+            },
+            Screen::KeysScreen(screen) => SavedScreen::Keys {
+                identity_id: id(screen.identity.id()),
+            },
+            Screen::AddKeyScreen(screen) => SavedScreen::AddKey {
+                identity_id: id(screen.identity.identity.id()),
+            },
+            Screen::KeyRotationScreen(screen) => SavedScreen::KeyRotation {
+                identity_id: id(screen.identity.identity.id()),
+            },
+            Screen::WithdrawalScreen(screen) => {
+                let (amount, address) = screen.form_input();
+                SavedScreen::Withdrawal {
+                    identity_id: id(screen.identity.identity.id()),
+                    amount,
+                    address,
+                }
+            }
+            Screen::TransferScreen(screen) => {
+                let (amount, address) = screen.form_input();
+                SavedScreen::Transfer {
+                    identity_id: id(screen.identity.identity.id()),
+                    amount,
+                    address,
+                }
+            }
+            Screen::TopUpIdentityScreen(screen) => SavedScreen::TopUpIdentity {
+                identity_id: id(screen.identity.identity.id()),
+                amount: screen.form_input(),
+            },
+            Screen::RegisterDpnsNameScreen(screen) => {
+                let (identity_id, name) = screen.form_input();
+                SavedScreen::RegisterDpnsName {
+                    identity_id: identity_id.map(id),
+                    name,
+                }
+            }
+            Screen::DpnsLookupScreen(screen) => {
+                let (pattern, candidates) = screen.form_input();
+                SavedScreen::DpnsLookup {
+                    pattern,
+                    candidates,
+                }
+            }
+            Screen::WithdrawalQueueScreen(_) => SavedScreen::WithdrawalQueue,
+            Screen::MasternodePayoutsScreen(_) => SavedScreen::MasternodePayouts,
+            Screen::MasternodeDashboardScreen(_) => SavedScreen::MasternodeDashboard,
+            Screen::AccountingExportScreen(_) => SavedScreen::AccountingExport,
+            Screen::DiagnosticsScreen(_) => SavedScreen::Diagnostics,
+            Screen::NodeLogsScreen(_) => SavedScreen::NodeLogs,
+            Screen::VotingBlocsScreen(_) => SavedScreen::VotingBlocs,
+            Screen::VoteListsScreen(_) => SavedScreen::VoteLists,
+            Screen::VoteReceiptsScreen(_) => SavedScreen::VoteReceipts,
+            Screen::ImportDocumentsScreen(screen) => SavedScreen::ImportDocuments {
+                contract_id: id(screen.contract_id),
+                document_type: screen.document_type.clone(),
+            },
+            Screen::DeleteDocumentsScreen(screen) => SavedScreen::DeleteDocuments {
+                contract_id: id(screen.contract_id),
+                document_type: screen.document_type.clone(),
+            },
+            Screen::ContractAnalyticsScreen(screen) => SavedScreen::ContractAnalytics {
+                contract_id: id(screen.contract_id),
+            },
+            Screen::ColdSigningScreen(_) => SavedScreen::ColdSigning,
+            Screen::OwnershipProofScreen(_) => SavedScreen::OwnershipProof,
+            Screen::AppProfilesScreen(_) => SavedScreen::AppProfiles,
+            Screen::AppProfileScreen(screen) => SavedScreen::AppProfile {
+                name: screen.profile_name.clone(),
+            },
+            Screen::SchedulerScreen(_) => SavedScreen::Scheduler,
+            Screen::TransitionSandboxScreen(_) => SavedScreen::TransitionSandbox,
+            Screen::SettingsScreen(_) => SavedScreen::Settings,
+            Screen::IdentitiesScreen(_)
+            | Screen::DPNSContestedNamesScreen(_)
+            | Screen::DocumentQueryScreen(_)
+            | Screen::TransitionVisualizerScreen(_)
+            | Screen::NetworkChooserScreen(_)
+            | Screen::KeyInfoScreen(_) => return None,
+        })
+    }
+}
+
+/// Recreates a screen saved with the session. Screens of identities that are no longer loaded
+/// are skipped.
+pub fn restore_screen(
+    saved: SavedScreen,
+    identities: &[QualifiedIdentity],
+    app_context: &Arc<AppContext>,
+) -> Option<Screen> {
+    let identity = |identity_id: &str| {
+        identities
+            .iter()
+            .find(|qualified_identity| {
+                qualified_identity.identity.id().to_string(Encoding::Base58) == identity_id
+            })
+            .cloned()
+    };
+    let identifier =
+        |identity_id: &str| Identifier::from_string(identity_id, Encoding::Base58).ok();
+    let screen_type = match saved {
+        SavedScreen::AddNewIdentity => ScreenType::AddNewIdentity,
+        SavedScreen::AddExistingIdentity => ScreenType::AddExistingIdentity,
+        SavedScreen::AddNewWallet => ScreenType::AddNewWallet,
+        SavedScreen::ImportMasternodes => ScreenType::ImportMasternodes,
+        SavedScreen::ImportIdentitiesCsv => ScreenType::ImportIdentitiesCsv,
+        SavedScreen::IdentityDetail { identity_id } => {
+            ScreenType::IdentityDetail(identifier(&identity_id)?)
+        }
+        SavedScreen::KeyUsage { identity_id } => ScreenType::KeyUsage(identifier(&identity_id)?),
+        SavedScreen::Keys { identity_id } => ScreenType::Keys(identity(&identity_id)?.identity),
+        SavedScreen::AddKey { identity_id } => ScreenType::AddKeyScreen(identity(&identity_id)?),
+        SavedScreen::KeyRotation { identity_id } => {
+            ScreenType::KeyRotation(identity(&identity_id)?)
+        }
+        SavedScreen::Withdrawal {
+            identity_id,
+            amount,
+            address,
+        } => {
+            return Some(Screen::WithdrawalScreen(
+                WithdrawalScreen::new(identity(&identity_id)?, app_context)
+                    .with_form_input(amount, address),
+            ))
+        }
+        SavedScreen::Transfer {
+            identity_id,
+            amount,
+            address,
+        } => {
+            return Some(Screen::TransferScreen(
+                TransferScreen::new(identity(&identity_id)?, app_context)
+                    .with_form_input(amount, address),
+            ))
+        }
+        SavedScreen::TopUpIdentity {
+            identity_id,
+            amount,
+        } => {
+            return Some(Screen::TopUpIdentityScreen(
+                TopUpIdentityScreen::new(identity(&identity_id)?, app_context)
+                    .with_form_input(amount),
+            ))
+        }
+        SavedScreen::RegisterDpnsName { identity_id, name } => {
+            return Some(Screen::RegisterDpnsNameScreen(
+                RegisterDpnsNameScreen::new(app_context)
+                    .with_form_input(identity_id.as_deref().and_then(identifier), name),
+            ))
+        }
+        SavedScreen::DpnsLookup {
+            pattern,
+            candidates,
+        } => {
+            return Some(Screen::DpnsLookupScreen(
+                DpnsLookupScreen::new(app_context).with_form_input(pattern, candidates),
+            ))
+        }
+        SavedScreen::WithdrawalQueue => ScreenType::WithdrawalQueue,
+        SavedScreen::MasternodePayouts => ScreenType::MasternodePayouts,
+        SavedScreen::MasternodeDashboard => ScreenType::MasternodeDashboard,
+        SavedScreen::AccountingExport => ScreenType::AccountingExport,
+        SavedScreen::Diagnostics => ScreenType::Diagnostics,
+        SavedScreen::NodeLogs => ScreenType::NodeLogs,
+        SavedScreen::VotingBlocs => ScreenType::VotingBlocs,
+        SavedScreen::VoteLists => ScreenType::VoteLists,
+        SavedScreen::VoteReceipts => ScreenType::VoteReceipts,
+        SavedScreen::ImportDocuments {
+            contract_id,
+            document_type,
+        } => ScreenType::ImportDocuments(identifier(&contract_id)?, document_type),
+        SavedScreen::DeleteDocuments {
+            contract_id,
+            document_type,
+        } => ScreenType::DeleteDocuments(identifier(&contract_id)?, document_type),
+        SavedScreen::ContractAnalytics { contract_id } => {
+            ScreenType::ContractAnalytics(identifier(&contract_id)?)
+        }
+        SavedScreen::ColdSigning => ScreenType::ColdSigning,
+        SavedScreen::OwnershipProof => ScreenType::OwnershipProof,
+        SavedScreen::AppProfiles => ScreenType::AppProfiles,
+        SavedScreen::AppProfile { name } => ScreenType::AppProfile(name),
+        SavedScreen::Scheduler => ScreenType::Scheduler,
+        SavedScreen::TransitionSandbox => ScreenType::TransitionSandbox,
+        SavedScreen::Settings => ScreenType::Settings,
+    };
+    Some(screen_type.create_screen(app_context))
 }
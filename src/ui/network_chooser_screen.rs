@@ -1,15 +1,20 @@
-use crate::app::AppAction;
+use crate::app::{AppAction, DesiredAppAction};
 use crate::context::AppContext;
+use crate::model::preferences::VerificationMode;
+use crate::model::rate_limit::Endpoint;
+use crate::model::response_cache::QueryClass;
 use crate::platform::core::{CoreItem, CoreTask};
-use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::platform::{BackendTask, BackendTaskSuccessResult, QUERY_TASK_KINDS};
+use crate::ui::components::accessibility::with_accessible_label;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::identities::add_new_wallet_screen::AddNewWalletScreen;
-use crate::ui::{RootScreenType, Screen, ScreenLike};
+use crate::ui::{MessageType, RootScreenType, Screen, ScreenLike, ScreenType};
+use chrono::{DateTime, Utc};
 use dash_sdk::dashcore_rpc::RpcApi;
 use dash_sdk::dpp::dashcore::Network;
 use dash_sdk::dpp::identity::TimestampMillis;
-use eframe::egui::{self, Color32, Context, Ui};
+use eframe::egui::{self, Color32, Context, Ui, WidgetType};
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::sync::Arc;
@@ -19,27 +24,34 @@ use std::{env, io};
 pub struct NetworkChooserScreen {
     pub mainnet_app_context: Arc<AppContext>,
     pub testnet_app_context: Option<Arc<AppContext>>,
+    pub devnet_app_context: Option<Arc<AppContext>>,
     pub current_network: Network,
     pub mainnet_core_status_online: bool,
     pub testnet_core_status_online: bool,
+    pub devnet_core_status_online: bool,
     status_checked: bool,
     pub recheck_time: Option<TimestampMillis>,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
 }
 
 impl NetworkChooserScreen {
     pub fn new(
         mainnet_app_context: &Arc<AppContext>,
         testnet_app_context: Option<&Arc<AppContext>>,
+        devnet_app_context: Option<&Arc<AppContext>>,
         current_network: Network,
     ) -> Self {
         Self {
             mainnet_app_context: mainnet_app_context.clone(),
             testnet_app_context: testnet_app_context.cloned(),
+            devnet_app_context: devnet_app_context.cloned(),
             current_network,
             mainnet_core_status_online: false,
             testnet_core_status_online: false,
+            devnet_core_status_online: false,
             status_checked: false,
             recheck_time: None,
+            message: None,
         }
     }
 
@@ -49,6 +61,9 @@ impl NetworkChooserScreen {
             Network::Testnet if self.testnet_app_context.is_some() => {
                 self.testnet_app_context.as_ref().unwrap()
             }
+            Network::Devnet if self.devnet_app_context.is_some() => {
+                self.devnet_app_context.as_ref().unwrap()
+            }
             _ => &self.mainnet_app_context,
         }
     }
@@ -57,6 +72,21 @@ impl NetworkChooserScreen {
         self.context_for_network(self.current_network)
     }
 
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    /// Logs a setting that couldn't be saved and shows why on the screen
+    fn report_save_error(&mut self, message: String) {
+        tracing::error!("{}", message);
+        self.display_message(&message, MessageType::Error);
+    }
+
     /// Function to check the status of Dash Core for a given network
     async fn check_core_status(app_context: &Arc<AppContext>) -> bool {
         app_context.core_client.get_best_chain_lock().is_ok()
@@ -84,7 +114,23 @@ impl NetworkChooserScreen {
 
                 // Render Testnet
                 app_action |= self.render_network_row(ui, Network::Testnet, "Testnet");
+
+                // Render the devnet when one is configured
+                if self.devnet_app_context.is_some() {
+                    app_action |= self.render_network_row(ui, Network::Devnet, "Devnet");
+                }
             });
+        if self
+            .devnet_app_context
+            .as_ref()
+            .is_some_and(|context| context.config.dapi_insecure_tls)
+        {
+            ui.colored_label(
+                Color32::RED,
+                "⚠ DAPI certificates are not verified on the devnet (dapi_insecure_tls). Anyone \
+                 between this machine and the nodes can read and change the traffic.",
+            );
+        }
         app_action
     }
 
@@ -128,12 +174,13 @@ impl NetworkChooserScreen {
         ui.label(wallet_count);
 
         // Add a button to start the network
-        if ui.button("+").clicked() {
-            let context = if network == Network::Dash || self.testnet_app_context.is_none() {
-                &self.mainnet_app_context
-            } else {
-                &self.testnet_app_context.as_ref().unwrap()
-            };
+        let add_wallet = with_accessible_label(
+            ui.button("+").on_hover_text("Add wallet"),
+            WidgetType::Button,
+            format!("Add wallet on {}", network),
+        );
+        if add_wallet.clicked() {
+            let context = self.context_for_network(network);
             app_action |=
                 AppAction::AddScreen(Screen::AddNewWalletScreen(AddNewWalletScreen::new(context)));
         }
@@ -165,6 +212,262 @@ impl NetworkChooserScreen {
         app_action
     }
 
+    fn render_proxy_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Proxy");
+
+        let app_context = self.current_app_context().clone();
+        let saved_proxy = app_context.preferences().proxy;
+        let mut proxy = saved_proxy.clone();
+
+        ui.horizontal(|ui| {
+            ui.label("SOCKS5 proxy:");
+            ui.add(
+                egui::TextEdit::singleline(&mut proxy.url)
+                    .hint_text("socks5h://127.0.0.1:9050")
+                    .desired_width(250.0),
+            )
+            .on_hover_text(
+                "With socks5h:// host names are resolved by the proxy. Tor listens on port 9050, \
+                 the Tor Browser on 9150.",
+            );
+        });
+        ui.horizontal(|ui| {
+            ui.label("Use it on:");
+            for (network, name) in [
+                (Network::Dash, "Mainnet"),
+                (Network::Testnet, "Testnet"),
+                (Network::Devnet, "Devnet"),
+            ] {
+                let mut enabled = proxy.enabled_for(network);
+                if ui.checkbox(&mut enabled, name).changed() {
+                    if enabled {
+                        proxy.networks.insert(network.to_string());
+                    } else {
+                        proxy.networks.remove(&network.to_string());
+                    }
+                }
+            }
+        });
+        if let Err(e) = proxy.address() {
+            ui.colored_label(Color32::DARK_RED, e);
+        }
+        ui.label(
+            egui::RichText::new(
+                "The price feed, webhooks, Tenderdash status and Core RPC of a remote node go \
+                 through the proxy. Core RPC switches on the next start and is refused when the \
+                 proxy can't be used. DAPI is not covered: the Platform SDK can't connect \
+                 through a proxy.",
+            )
+            .color(Color32::GRAY),
+        );
+        if !proxy.networks.is_empty() {
+            ui.checkbox(
+                &mut proxy.dapi_direct,
+                "Let DAPI connect directly on these networks",
+            )
+            .on_hover_text("Platform requests then reveal your IP address to the DAPI nodes");
+            if !proxy.dapi_direct {
+                ui.colored_label(
+                    Color32::DARK_RED,
+                    "DAPI is refused on the networks using the proxy, Platform features stop \
+                     working there.",
+                );
+            }
+        }
+
+        if proxy != saved_proxy {
+            if let Err(e) = app_context.update_preferences(|preferences| preferences.proxy = proxy)
+            {
+                self.report_save_error(format!("Failed to save proxy settings: {}", e));
+            }
+        }
+    }
+
+    fn render_rate_limit_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Rate Limits");
+
+        let app_context = self.current_app_context().clone();
+        let saved_limits = app_context.preferences().rate_limits;
+        let mut limits = saved_limits.clone();
+
+        egui::Grid::new("rate_limits")
+            .num_columns(7)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("Endpoint");
+                ui.label("Per minute")
+                    .on_hover_text("Most requests started per minute, 0 for no limit");
+                ui.label("First backoff (ms)");
+                ui.label("Multiplier");
+                ui.label("Max backoff (ms)");
+                ui.label("Retries")
+                    .on_hover_text("Only queries are retried, never changes to Platform");
+                ui.label("State");
+                ui.end_row();
+                for endpoint in Endpoint::ALL {
+                    let policy = limits.policy_mut(endpoint);
+                    ui.label(endpoint.label());
+                    ui.add(egui::DragValue::new(&mut policy.max_per_minute).range(0..=6000));
+                    ui.add(
+                        egui::DragValue::new(&mut policy.initial_backoff_ms)
+                            .range(0..=600_000)
+                            .speed(100),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut policy.backoff_multiplier)
+                            .range(1.0..=10.0)
+                            .speed(0.1)
+                            .fixed_decimals(1),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut policy.max_backoff_ms)
+                            .range(0..=3_600_000)
+                            .speed(1000),
+                    );
+                    ui.add(egui::DragValue::new(&mut policy.max_retries).range(0..=10));
+                    match app_context.rate_limiter.backing_off(endpoint) {
+                        Some((failures, remaining)) => ui.colored_label(
+                            Color32::DARK_RED,
+                            format!(
+                                "Backing off {:.1} s after {} failures",
+                                remaining.as_secs_f32(),
+                                failures
+                            ),
+                        ),
+                        None => ui.label("OK"),
+                    };
+                    ui.end_row();
+                }
+            });
+        ui.label(
+            egui::RichText::new(
+                "After a timeout or an unavailable node the endpoint pauses for the backoff, which \
+                 grows with each further failure and resets after a success. Identity refreshes \
+                 count each identity.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        if limits != saved_limits {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.rate_limits = limits)
+            {
+                self.report_save_error(format!("Failed to save rate limits: {}", e));
+            }
+        }
+    }
+
+    fn render_cache_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Response Cache");
+
+        let app_context = self.current_app_context().clone();
+        let saved_ttls = app_context.preferences().cache_ttls;
+        let mut ttls = saved_ttls.clone();
+
+        egui::Grid::new("cache_ttls")
+            .num_columns(2)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                for class in QueryClass::ALL {
+                    ui.label(class.label());
+                    ui.add(
+                        egui::DragValue::new(ttls.secs_mut(class))
+                            .range(0..=86_400)
+                            .suffix(" s"),
+                    );
+                    ui.end_row();
+                }
+            });
+        ui.label(
+            egui::RichText::new(
+                "Screens opened again reuse results younger than this instead of asking the \
+                 network, 0 turns caching off. Refresh buttons always ask the network.",
+            )
+            .color(Color32::GRAY),
+        );
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "{} cached results",
+                app_context.response_cache.entry_count()
+            ));
+            if ui.button("Clear cache").clicked() {
+                app_context.response_cache.clear();
+            }
+        });
+
+        if ttls != saved_ttls {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.cache_ttls = ttls)
+            {
+                self.report_save_error(format!("Failed to save cache settings: {}", e));
+            }
+        }
+    }
+
+    fn render_verification_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Query Verification");
+
+        let app_context = self.current_app_context().clone();
+        let saved_verification = app_context.preferences().query_verification;
+        let mut verification = saved_verification.clone();
+
+        egui::Grid::new("verification_settings_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Default:");
+                egui::ComboBox::from_id_salt("verification_default_mode")
+                    .selected_text(verification.default_mode.label())
+                    .show_ui(ui, |ui| {
+                        for mode in VerificationMode::ALL {
+                            ui.selectable_value(&mut verification.default_mode, mode, mode.label());
+                        }
+                    });
+                ui.end_row();
+
+                for (kind, label) in QUERY_TASK_KINDS {
+                    ui.label(format!("{}:", label));
+                    let mut task_mode = verification.task_modes.get(kind).copied();
+                    let default_label = format!("Default ({})", verification.default_mode.label());
+                    egui::ComboBox::from_id_salt(("verification_task_mode", kind))
+                        .selected_text(
+                            task_mode
+                                .map_or(default_label.clone(), |mode| mode.label().to_string()),
+                        )
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut task_mode, None, default_label);
+                            for mode in VerificationMode::ALL {
+                                ui.selectable_value(&mut task_mode, Some(mode), mode.label());
+                            }
+                        });
+                    match task_mode {
+                        Some(mode) => {
+                            verification.task_modes.insert(kind.to_string(), mode);
+                        }
+                        None => {
+                            verification.task_modes.remove(kind);
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        ui.label(
+            egui::RichText::new(
+                "Fast mode skips proofs for lower latency and trusts whatever the DAPI node \
+                 answers. Votes, registrations and other state transitions are always verified.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        if verification != saved_verification {
+            if let Err(e) = app_context.update_preferences(|preferences| {
+                preferences.query_verification = verification;
+            }) {
+                self.report_save_error(format!("Failed to save verification settings: {}", e));
+            }
+        }
+    }
+
     /// Function to start Dash QT based on the selected network
     fn start_dash_qt(&self, network: Network) -> io::Result<()> {
         // Determine the path to Dash-Qt based on the operating system
@@ -220,6 +523,7 @@ impl NetworkChooserScreen {
         match network {
             Network::Dash => self.mainnet_core_status_online,
             Network::Testnet => self.testnet_core_status_online,
+            Network::Devnet => self.devnet_core_status_online,
             _ => false,
         }
     }
@@ -237,16 +541,42 @@ impl ScreenLike for NetworkChooserScreen {
                 Network::Testnet => {
                     self.testnet_core_status_online = true;
                 }
+                Network::Devnet => {
+                    self.devnet_core_status_online = true;
+                }
                 _ => {}
             }
         }
     }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
     fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
         let mut action = add_top_panel(
             ctx,
             self.current_app_context(),
             vec![("Dash Evo Tool", AppAction::None)],
-            vec![],
+            vec![
+                (
+                    "Node Logs",
+                    DesiredAppAction::AddScreenType(ScreenType::NodeLogs),
+                ),
+                (
+                    "Diagnostics",
+                    DesiredAppAction::AddScreenType(ScreenType::Diagnostics),
+                ),
+                (
+                    "Scheduler",
+                    DesiredAppAction::AddScreenType(ScreenType::Scheduler),
+                ),
+                (
+                    "Settings",
+                    DesiredAppAction::AddScreenType(ScreenType::Settings),
+                ),
+            ],
         );
 
         if !self.status_checked {
@@ -261,7 +591,29 @@ impl ScreenLike for NetworkChooserScreen {
         );
 
         egui::CentralPanel::default().show(ctx, |ui| {
-            action |= self.render_network_table(ui);
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => ui.visuals().text_color(),
+                    };
+                    ui.colored_label(color, message);
+                }
+                action |= self.render_network_table(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_proxy_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_rate_limit_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_cache_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_verification_settings(ui);
+            });
         });
 
         action
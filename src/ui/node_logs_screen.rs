@@ -0,0 +1,293 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::node_log::{
+    line_matches, LogLevelFilter, LogLineLevel, NodeLogOrigin, NodeLogSource,
+};
+use crate::node_log_tail::{LogTail, MAX_LINES};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{MessageType, ScreenLike};
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Tails the logs of the local dashd, drive and dapi, so node issues can be debugged without a
+/// terminal
+pub struct NodeLogsScreen {
+    pub app_context: Arc<AppContext>,
+    sources: Vec<NodeLogSource>,
+    selected: usize,
+    /// Tail of the selected source, restarted when the selection or the source changes
+    tail: Option<LogTail>,
+    filter_text: String,
+    level_filter: LogLevelFilter,
+    /// Lines frozen when the view was paused
+    paused_lines: Option<Vec<String>>,
+    /// Why the sources couldn't be saved, until they are saved
+    save_error: Option<String>,
+}
+
+impl NodeLogsScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            sources: Vec::new(),
+            selected: 0,
+            tail: None,
+            filter_text: String::new(),
+            level_filter: LogLevelFilter::All,
+            paused_lines: None,
+            save_error: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    /// Starts tailing the selected source unless it is tailed already
+    fn ensure_tail(&mut self) {
+        let Some(source) = self.sources.get(self.selected) else {
+            self.tail = None;
+            return;
+        };
+        if self.tail.as_ref().map(|tail| &tail.source) != Some(source) {
+            self.paused_lines = None;
+            self.tail =
+                (!source.origin.target().trim().is_empty()).then(|| LogTail::start(source.clone()));
+        }
+    }
+
+    fn save_sources(&mut self) {
+        let sources = self.sources.clone();
+        if let Err(e) = self
+            .app_context
+            .update_preferences(|preferences| preferences.node_logs = sources)
+        {
+            let message = format!("Failed to save the node log sources: {}", e);
+            tracing::error!("{}", message);
+            self.save_error = Some(message);
+        } else {
+            self.save_error = None;
+        }
+    }
+
+    fn render_sources(&mut self, ui: &mut Ui) {
+        let before = self.sources.clone();
+        let mut removed = None;
+        egui::Grid::new("node_log_sources")
+            .num_columns(4)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                for (index, source) in self.sources.iter_mut().enumerate() {
+                    ui.add(egui::TextEdit::singleline(&mut source.name).desired_width(80.0));
+                    egui::ComboBox::from_id_salt(("node_log_origin", index))
+                        .selected_text(source.origin.label())
+                        .show_ui(ui, |ui| {
+                            let target = source.origin.target().to_string();
+                            let options = [
+                                NodeLogOrigin::File {
+                                    path: target.clone(),
+                                },
+                                NodeLogOrigin::Docker { container: target },
+                            ];
+                            for origin in options {
+                                let selected = std::mem::discriminant(&origin)
+                                    == std::mem::discriminant(&source.origin);
+                                let label = origin.label();
+                                if ui.selectable_label(selected, label).clicked() && !selected {
+                                    source.origin = origin;
+                                }
+                            }
+                        });
+                    let (target, hint) = match &mut source.origin {
+                        NodeLogOrigin::File { path } => (path, "~/.dashcore/debug.log"),
+                        NodeLogOrigin::Docker { container } => {
+                            (container, "dashmate_mainnet-drive_abci-1")
+                        }
+                    };
+                    ui.add(
+                        egui::TextEdit::singleline(target)
+                            .hint_text(hint)
+                            .desired_width(300.0),
+                    );
+                    if ui.button("Remove").clicked() {
+                        removed = Some(index);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(index) = removed {
+            self.sources.remove(index);
+        }
+        ui.horizontal(|ui| {
+            if ui.button("Add log").clicked() {
+                self.sources.push(NodeLogSource {
+                    name: format!("log {}", self.sources.len() + 1),
+                    origin: NodeLogOrigin::File {
+                        path: String::new(),
+                    },
+                });
+            }
+            if ui
+                .button("Add dashmate defaults")
+                .on_hover_text(
+                    "dashd's debug.log and the drive and dapi containers dashmate starts on \
+                     mainnet",
+                )
+                .clicked()
+            {
+                for source in NodeLogSource::defaults() {
+                    if !self
+                        .sources
+                        .iter()
+                        .any(|existing| existing.name == source.name)
+                    {
+                        self.sources.push(source);
+                    }
+                }
+            }
+        });
+        if let Some(error) = &self.save_error {
+            ui.colored_label(Color32::RED, error);
+        }
+        if self.sources != before {
+            self.selected = self.selected.min(self.sources.len().saturating_sub(1));
+            self.save_sources();
+        }
+    }
+
+    fn render_log(&mut self, ui: &mut Ui) {
+        let Some(tail) = &self.tail else {
+            ui.label("Enter the path or container of the log to tail it.");
+            return;
+        };
+        if let Some(error) = tail.error() {
+            ui.colored_label(Color32::RED, error);
+        }
+        let lines = match &self.paused_lines {
+            Some(lines) => lines.clone(),
+            None => tail.lines(),
+        };
+        let shown: Vec<&String> = lines
+            .iter()
+            .filter(|line| line_matches(line, self.level_filter, self.filter_text.trim()))
+            .collect();
+        ui.label(
+            RichText::new(format!(
+                "{} of {} lines, the last {} are kept",
+                shown.len(),
+                lines.len(),
+                MAX_LINES
+            ))
+            .color(Color32::GRAY),
+        );
+
+        let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .stick_to_bottom(self.paused_lines.is_none())
+            .show_rows(ui, row_height, shown.len(), |ui, range| {
+                for line in &shown[range] {
+                    let color = match LogLineLevel::of(line) {
+                        LogLineLevel::Error => Color32::RED,
+                        LogLineLevel::Warning => Color32::from_rgb(200, 120, 0),
+                        LogLineLevel::Other => ui.visuals().text_color(),
+                    };
+                    ui.add(
+                        egui::Label::new(RichText::new(line.as_str()).monospace().color(color))
+                            .wrap_mode(egui::TextWrapMode::Extend),
+                    );
+                }
+            });
+    }
+}
+
+impl ScreenLike for NodeLogsScreen {
+    fn refresh(&mut self) {
+        self.sources = self.app_context.preferences().node_logs;
+        self.selected = self.selected.min(self.sources.len().saturating_sub(1));
+    }
+
+    fn display_message(&mut self, _message: &str, _message_type: MessageType) {}
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Node Logs", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Node Logs");
+            ui.collapsing("Log sources", |ui| self.render_sources(ui));
+            self.ensure_tail();
+            if self.sources.is_empty() {
+                ui.label("Add the logs of your dashd, drive and dapi to tail them here.");
+                return;
+            }
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                for (index, source) in self.sources.iter().enumerate() {
+                    if ui
+                        .selectable_label(self.selected == index, &source.name)
+                        .on_hover_text(source.origin.target())
+                        .clicked()
+                    {
+                        self.selected = index;
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("Filter:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.filter_text)
+                        .hint_text("text to look for")
+                        .desired_width(200.0),
+                );
+                egui::ComboBox::from_id_salt("node_log_level")
+                    .selected_text(self.level_filter.label())
+                    .show_ui(ui, |ui| {
+                        for level in LogLevelFilter::ALL {
+                            ui.selectable_value(&mut self.level_filter, level, level.label());
+                        }
+                    });
+                let mut paused = self.paused_lines.is_some();
+                if ui.checkbox(&mut paused, "Pause").changed() {
+                    self.paused_lines =
+                        paused.then(|| self.tail.as_ref().map(LogTail::lines).unwrap_or_default());
+                }
+                if ui.button("Clear").clicked() {
+                    if let Some(tail) = &self.tail {
+                        tail.clear();
+                    }
+                    self.paused_lines = self.paused_lines.as_ref().map(|_| Vec::new());
+                }
+                if ui.button("Copy shown").clicked() {
+                    let lines = match &self.paused_lines {
+                        Some(lines) => lines.clone(),
+                        None => self.tail.as_ref().map(LogTail::lines).unwrap_or_default(),
+                    };
+                    let text = lines
+                        .iter()
+                        .filter(|line| {
+                            line_matches(line, self.level_filter, self.filter_text.trim())
+                        })
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    ui.ctx().copy_text(text);
+                }
+            });
+            ui.add_space(5.0);
+            self.render_log(ui);
+        });
+
+        // New lines arrive on the tail's threads
+        ctx.request_repaint_after(Duration::from_millis(500));
+
+        action
+    }
+}
@@ -0,0 +1,375 @@
+use crate::app::{AppAction, AppCommand};
+use crate::context::AppContext;
+use crate::model::qualified_contract::QualifiedContract;
+use crate::model::schedule::{
+    interval_label, ScheduledJob, ScheduledRun, ScheduledTask, INTERVAL_PRESETS,
+    MIN_INTERVAL_MINUTES,
+};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::time_format::TimeFormatter;
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How often the run status is read again while the screen is open
+const RUN_STATUS_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The kinds of jobs offered when adding a task
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JobKind {
+    RefreshContests,
+    RefreshBalances,
+    SnapshotContract,
+    CheckContractUpdates,
+}
+
+impl JobKind {
+    const ALL: [JobKind; 4] = [
+        JobKind::RefreshContests,
+        JobKind::RefreshBalances,
+        JobKind::SnapshotContract,
+        JobKind::CheckContractUpdates,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            JobKind::RefreshContests => "Refresh contests",
+            JobKind::RefreshBalances => "Refresh identity balances",
+            JobKind::SnapshotContract => "Snapshot a contract",
+            JobKind::CheckContractUpdates => "Check contracts for updates",
+        }
+    }
+}
+
+/// Recurring tasks run while the tool is open, e.g. refreshing contests every 15 minutes or
+/// snapshotting a contract daily, with the outcome of their last run
+pub struct SchedulerScreen {
+    pub app_context: Arc<AppContext>,
+    tasks: Vec<ScheduledTask>,
+    runs: Vec<ScheduledRun>,
+    runs_loaded_at: Instant,
+    contracts: Vec<QualifiedContract>,
+    job_kind: JobKind,
+    contract_index: usize,
+    interval_minutes: u32,
+    custom_interval_input: String,
+    error_message: Option<String>,
+}
+
+impl SchedulerScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            tasks: Vec::new(),
+            runs: Vec::new(),
+            runs_loaded_at: Instant::now(),
+            contracts: app_context.get_contracts(None, None).unwrap_or_default(),
+            job_kind: JobKind::RefreshContests,
+            contract_index: 0,
+            interval_minutes: INTERVAL_PRESETS[0].0,
+            custom_interval_input: String::new(),
+            error_message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    fn load_runs(&mut self) {
+        self.runs = self
+            .app_context
+            .db
+            .get_scheduled_runs(&self.app_context)
+            .unwrap_or_default();
+        self.runs_loaded_at = Instant::now();
+    }
+
+    fn contract_name(contract: &QualifiedContract) -> String {
+        contract
+            .alias
+            .clone()
+            .unwrap_or_else(|| contract.contract.id().to_string(Encoding::Base58))
+    }
+
+    /// How the task's job is shown, with the contract's alias instead of its id when known
+    fn job_label(&self, job: &ScheduledJob) -> String {
+        if let ScheduledJob::SnapshotContract { contract_id } = job {
+            if let Some(contract) = self
+                .contracts
+                .iter()
+                .find(|contract| &contract.contract.id().to_string(Encoding::Base58) == contract_id)
+            {
+                return format!("Snapshot contract {}", Self::contract_name(contract));
+            }
+        }
+        job.label()
+    }
+
+    fn new_job(&self) -> Result<ScheduledJob, String> {
+        Ok(match self.job_kind {
+            JobKind::RefreshContests => ScheduledJob::RefreshContests,
+            JobKind::RefreshBalances => ScheduledJob::RefreshBalances,
+            JobKind::SnapshotContract => {
+                let contract = self
+                    .contracts
+                    .get(self.contract_index)
+                    .ok_or("Add the contract to snapshot first")?;
+                ScheduledJob::SnapshotContract {
+                    contract_id: contract.contract.id().to_string(Encoding::Base58),
+                }
+            }
+            JobKind::CheckContractUpdates => ScheduledJob::CheckContractUpdates,
+        })
+    }
+
+    fn add_task(&mut self) -> Result<(), String> {
+        let job = self.new_job()?;
+        let interval_minutes = if self.custom_interval_input.trim().is_empty() {
+            self.interval_minutes
+        } else {
+            self.custom_interval_input
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| "The interval must be a number of minutes".to_string())?
+        };
+        if interval_minutes < MIN_INTERVAL_MINUTES {
+            return Err(format!(
+                "The interval must be at least {} minutes",
+                MIN_INTERVAL_MINUTES
+            ));
+        }
+        let network = self.app_context.network_string();
+        self.app_context
+            .update_preferences(|preferences| {
+                let id = preferences
+                    .scheduled_tasks
+                    .iter()
+                    .map(|task| task.id)
+                    .max()
+                    .map_or(1, |id| id + 1);
+                preferences.scheduled_tasks.push(ScheduledTask {
+                    id,
+                    job,
+                    interval_minutes,
+                    enabled: true,
+                    network,
+                });
+            })
+            .map_err(|e| format!("Failed to save the task: {}", e))?;
+        self.custom_interval_input.clear();
+        self.refresh();
+        Ok(())
+    }
+
+    fn set_enabled(&mut self, id: u32, enabled: bool) {
+        if let Err(e) = self.app_context.update_preferences(|preferences| {
+            if let Some(task) = preferences
+                .scheduled_tasks
+                .iter_mut()
+                .find(|task| task.id == id)
+            {
+                task.enabled = enabled;
+            }
+        }) {
+            self.error_message = Some(format!("Failed to save the task: {}", e));
+        }
+        self.refresh();
+    }
+
+    fn remove_task(&mut self, id: u32) {
+        if let Err(e) = self.app_context.update_preferences(|preferences| {
+            preferences.scheduled_tasks.retain(|task| task.id != id)
+        }) {
+            self.error_message = Some(format!("Failed to remove the task: {}", e));
+            return;
+        }
+        if let Err(e) = self
+            .app_context
+            .db
+            .delete_scheduled_run(id, &self.app_context)
+        {
+            tracing::warn!("Failed to remove the runs of the task: {}", e);
+        }
+        self.refresh();
+    }
+
+    fn render_tasks(&mut self, ui: &mut Ui) {
+        ui.heading("Scheduled Tasks");
+        if self.tasks.is_empty() {
+            ui.label("No tasks scheduled on this network yet.");
+            return;
+        }
+        let time_format = TimeFormatter::new(&self.app_context.preferences());
+        let mut toggled = None;
+        let mut removed = None;
+        egui::Grid::new("scheduled_tasks")
+            .num_columns(6)
+            .spacing([20.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for title in ["Task", "Interval", "Enabled", "Last run", "Status", ""] {
+                    ui.label(RichText::new(title).strong());
+                }
+                ui.end_row();
+                for task in &self.tasks {
+                    let run = self.runs.iter().find(|run| run.task_id == task.id);
+                    ui.label(self.job_label(&task.job));
+                    ui.label(task.interval_label());
+                    let mut enabled = task.enabled;
+                    if ui.checkbox(&mut enabled, "").changed() {
+                        toggled = Some((task.id, enabled));
+                    }
+                    match run {
+                        Some(run) => ui.label(time_format.format_seconds(run.started_at as u64)),
+                        None => ui.label(RichText::new("Never").color(Color32::GRAY)),
+                    };
+                    match run {
+                        Some(run) if run.is_running() => {
+                            ui.horizontal(|ui| {
+                                ui.spinner();
+                                ui.label("Running");
+                            });
+                        }
+                        Some(ScheduledRun {
+                            error: Some(error), ..
+                        }) => {
+                            ui.colored_label(Color32::RED, "Failed")
+                                .on_hover_text(error);
+                        }
+                        Some(_) => {
+                            ui.colored_label(Color32::DARK_GREEN, "Succeeded");
+                        }
+                        None => {
+                            ui.label("");
+                        }
+                    }
+                    ui.horizontal(|ui| {
+                        let running = run.is_some_and(ScheduledRun::is_running);
+                        if ui
+                            .add_enabled(!running, egui::Button::new("Run now"))
+                            .clicked()
+                        {
+                            self.app_context.send(AppCommand::RunScheduledTask(task.id));
+                        }
+                        if ui.button("Remove").clicked() {
+                            removed = Some(task.id);
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+        if let Some((id, enabled)) = toggled {
+            self.set_enabled(id, enabled);
+        }
+        if let Some(id) = removed {
+            self.remove_task(id);
+        }
+    }
+
+    fn render_add_task(&mut self, ui: &mut Ui) {
+        ui.heading("Add a Task");
+        ui.horizontal(|ui| {
+            ui.label("Task:");
+            egui::ComboBox::from_id_salt("scheduler_job_kind")
+                .selected_text(self.job_kind.label())
+                .show_ui(ui, |ui| {
+                    for kind in JobKind::ALL {
+                        ui.selectable_value(&mut self.job_kind, kind, kind.label());
+                    }
+                });
+            if self.job_kind == JobKind::SnapshotContract {
+                let selected = self
+                    .contracts
+                    .get(self.contract_index)
+                    .map(Self::contract_name)
+                    .unwrap_or_default();
+                egui::ComboBox::from_id_salt("scheduler_contract")
+                    .selected_text(selected)
+                    .show_ui(ui, |ui| {
+                        for (index, contract) in self.contracts.iter().enumerate() {
+                            ui.selectable_value(
+                                &mut self.contract_index,
+                                index,
+                                Self::contract_name(contract),
+                            );
+                        }
+                    });
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Interval:");
+            egui::ComboBox::from_id_salt("scheduler_interval")
+                .selected_text(interval_label(self.interval_minutes))
+                .show_ui(ui, |ui| {
+                    for (minutes, label) in INTERVAL_PRESETS {
+                        ui.selectable_value(&mut self.interval_minutes, minutes, label);
+                    }
+                });
+            ui.label("or every");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.custom_interval_input)
+                    .hint_text("minutes")
+                    .desired_width(60.0),
+            );
+            ui.label("minutes");
+        });
+        if ui.button("Add").clicked() {
+            match self.add_task() {
+                Ok(()) => self.error_message = None,
+                Err(e) => self.error_message = Some(e),
+            }
+        }
+    }
+}
+
+impl ScreenLike for SchedulerScreen {
+    fn refresh(&mut self) {
+        self.tasks = self.app_context.scheduled_tasks();
+        self.load_runs();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.error_message = Some(message.to_string());
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        if self.runs_loaded_at.elapsed() >= RUN_STATUS_REFRESH_INTERVAL {
+            self.load_runs();
+        }
+        let action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Scheduler", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.label(
+                    RichText::new(
+                        "Tasks run in the background at their interval while the tool is open. \
+                         A task that was due while the tool was closed runs when it opens.",
+                    )
+                    .color(Color32::GRAY),
+                );
+                if let Some(error) = &self.error_message {
+                    ui.colored_label(Color32::RED, error);
+                }
+                ui.add_space(10.0);
+                self.render_tasks(ui);
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_add_task(ui);
+            });
+        });
+
+        action
+    }
+}
@@ -0,0 +1,1861 @@
+use crate::app::AppAction;
+use crate::app_dir::{AppDirs, PORTABLE_FLAG, PROFILE_FLAG};
+use crate::context::AppContext;
+use crate::deep_link::{register_uri_scheme, URI_SCHEME};
+use crate::model::approval::{ApprovalPolicy, Approver};
+use crate::model::auto_top_up::AutoTopUpRule;
+use crate::model::explorer::TXID_PLACEHOLDER;
+use crate::model::fee::{CoinSelection, FeeSpeed};
+use crate::model::finality::MAX_CONFIRMATIONS;
+use crate::model::notification_sound::{SoundChoice, SoundEvent};
+use crate::model::preferences::{
+    FontSizePreset, MetricsExport, NumberFormatSetting, TableDensity, TimeDisplayMode,
+    TimeZoneSetting, DEFAULT_METRICS_PORT, MAX_UI_SCALE, MIN_UI_SCALE,
+};
+use crate::model::price_feed::{set_fiat_price, PriceFeedSettings, PriceProvider};
+use crate::model::settings_export::SettingsExport;
+use crate::model::spending_limit::{OverLimitAction, SpendingLimits};
+use crate::model::vote_broadcast::MAX_BROADCAST_ENDPOINTS;
+use crate::model::vote_webhook::VoteWebhookFormat;
+use crate::model::webhook::{
+    EventWebhook, WebhookEvent, COMMON_PLACEHOLDERS, TEMPLATE_DISCORD, TEMPLATE_GENERIC,
+    TEMPLATE_SLACK,
+};
+use crate::notification_sound::play_sound;
+use crate::platform::price::PriceTask;
+use crate::platform::BackendTask;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::number_format::CREDITS_PER_DASH;
+use crate::ui::helpers::time_format::TimeFormatter;
+use crate::ui::{MessageType, ScreenLike};
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use eframe::egui::{self, Color32, Context, Ui};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Preferences of the tool that don't depend on the network: display, alerts, fees, approval
+/// and spending rules, webhooks and data storage
+pub struct SettingsScreen {
+    pub app_context: Arc<AppContext>,
+    metrics_port_input: String,
+    metrics_path_input: String,
+    junk_brands_input: String,
+    link_registration_result: Option<Result<(), String>>,
+    new_profile_name: String,
+    /// The name of the created profile, or why creating it failed
+    profile_creation_result: Option<Result<String, String>>,
+    settings_file_input: String,
+    /// What the last settings export or import did, or why it failed
+    settings_transfer_result: Option<Result<String, String>>,
+    approval_policy: ApprovalPolicy,
+    approval_threshold_input: String,
+    approver_name_input: String,
+    approver_password_input: String,
+    approver_new_password_input: String,
+    approval_result: Option<Result<String, String>>,
+    over_limit_action: OverLimitAction,
+    spending_default_input: String,
+    /// Typed daily limits keyed by the base58 identity id
+    spending_identity_inputs: BTreeMap<String, String>,
+    spending_password_input: String,
+    spending_result: Option<Result<String, String>>,
+    /// Interface scale while the slider is dragged, applied when it is let go
+    ui_scale_input: f32,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl SettingsScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let (metrics_port_input, metrics_path_input) =
+            match app_context.preferences().metrics_export {
+                MetricsExport::Endpoint { port } => {
+                    (port.to_string(), "dash-evo-tool.prom".to_string())
+                }
+                MetricsExport::File { path } => (DEFAULT_METRICS_PORT.to_string(), path),
+                MetricsExport::Disabled => (
+                    DEFAULT_METRICS_PORT.to_string(),
+                    "dash-evo-tool.prom".to_string(),
+                ),
+            };
+        let junk_brands_input = app_context.preferences().junk_name_rules.brands.join(", ");
+        let approval_policy = app_context.preferences().approval_policy;
+        let approval_threshold_input = dash_input(approval_policy.withdrawal_threshold);
+        let spending_limits = app_context.preferences().spending_limits;
+        let spending_default_input = dash_input(spending_limits.default_daily_limit);
+        Self {
+            app_context: app_context.clone(),
+            metrics_port_input,
+            metrics_path_input,
+            junk_brands_input,
+            link_registration_result: None,
+            new_profile_name: String::new(),
+            profile_creation_result: None,
+            settings_file_input: "dash-evo-tool-settings.json".to_string(),
+            settings_transfer_result: None,
+            approval_policy,
+            approval_threshold_input,
+            approver_name_input: String::new(),
+            approver_password_input: String::new(),
+            approver_new_password_input: String::new(),
+            approval_result: None,
+            over_limit_action: spending_limits.over_limit,
+            spending_default_input,
+            spending_identity_inputs: spending_limits
+                .identity_limits
+                .iter()
+                .map(|(id, limit)| (id.clone(), dash_input(Some(*limit))))
+                .collect(),
+            spending_password_input: String::new(),
+            spending_result: None,
+            ui_scale_input: app_context.preferences().display_scale.ui_scale,
+            message: None,
+        }
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    /// Logs a setting that couldn't be saved and shows why on the screen
+    fn report_save_error(&mut self, message: String) {
+        tracing::error!("{}", message);
+        self.display_message(&message, MessageType::Error);
+    }
+
+    /// Render the display preferences shared by all networks
+    fn render_display_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Display Settings");
+
+        let app_context = self.app_context.clone();
+        let mut preferences = app_context.preferences();
+        let mut changed = false;
+
+        egui::Grid::new("display_settings_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Time display:");
+                egui::ComboBox::from_id_salt("time_display_mode")
+                    .selected_text(preferences.time_display.label())
+                    .show_ui(ui, |ui| {
+                        for mode in TimeDisplayMode::ALL {
+                            changed |= ui
+                                .selectable_value(&mut preferences.time_display, mode, mode.label())
+                                .changed();
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Time zone:");
+                egui::ComboBox::from_id_salt("time_zone")
+                    .selected_text(preferences.time_zone.label())
+                    .height(300.0)
+                    .show_ui(ui, |ui| {
+                        let fixed_offsets =
+                            (-24..=28).map(|half_hours| TimeZoneSetting::FixedOffset {
+                                minutes: half_hours * 30,
+                            });
+                        for time_zone in [TimeZoneSetting::Utc, TimeZoneSetting::Local]
+                            .into_iter()
+                            .chain(fixed_offsets)
+                        {
+                            changed |= ui
+                                .selectable_value(
+                                    &mut preferences.time_zone,
+                                    time_zone,
+                                    time_zone.label(),
+                                )
+                                .changed();
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Number format:");
+                egui::ComboBox::from_id_salt("number_format")
+                    .selected_text(preferences.number_format.label())
+                    .show_ui(ui, |ui| {
+                        for number_format in NumberFormatSetting::ALL {
+                            changed |= ui
+                                .selectable_value(
+                                    &mut preferences.number_format,
+                                    number_format,
+                                    number_format.label(),
+                                )
+                                .changed();
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Interface scale:");
+                ui.horizontal(|ui| {
+                    let slider = ui.add(
+                        egui::Slider::new(&mut self.ui_scale_input, MIN_UI_SCALE..=MAX_UI_SCALE)
+                            .step_by(0.05)
+                            .custom_formatter(|scale, _| format!("{:.0}%", scale * 100.0))
+                            .custom_parser(|text| {
+                                text.trim_end_matches('%')
+                                    .trim()
+                                    .parse::<f64>()
+                                    .ok()
+                                    .map(|percent| percent / 100.0)
+                            }),
+                    );
+                    // Zooming while dragging would move the slider under the pointer
+                    if slider.drag_stopped() || (slider.changed() && !slider.dragged()) {
+                        preferences.display_scale.ui_scale = self.ui_scale_input;
+                        changed = true;
+                    }
+                    if ui.button("Reset").clicked() {
+                        self.ui_scale_input = 1.0;
+                        preferences.display_scale.ui_scale = 1.0;
+                        changed = true;
+                    }
+                });
+                ui.end_row();
+
+                ui.label("Text size:");
+                egui::ComboBox::from_id_salt("font_size")
+                    .selected_text(preferences.display_scale.font_size.label())
+                    .show_ui(ui, |ui| {
+                        for font_size in FontSizePreset::ALL {
+                            changed |= ui
+                                .selectable_value(
+                                    &mut preferences.display_scale.font_size,
+                                    font_size,
+                                    font_size.label(),
+                                )
+                                .changed();
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Table density:");
+                egui::ComboBox::from_id_salt("table_density")
+                    .selected_text(preferences.table_density.label())
+                    .show_ui(ui, |ui| {
+                        for density in TableDensity::ALL {
+                            changed |= ui
+                                .selectable_value(
+                                    &mut preferences.table_density,
+                                    density,
+                                    density.label(),
+                                )
+                                .changed();
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Clipboard:");
+                changed |= ui
+                    .checkbox(
+                        &mut preferences.clipboard_monitor,
+                        "Offer to look up identity ids, names, hashes and addresses I copy",
+                    )
+                    .changed();
+                ui.end_row();
+            });
+        ui.label(
+            egui::RichText::new(
+                "Time columns can override the display mode from their header's right-click menu.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        if changed {
+            if let Err(e) = app_context.update_preferences(|saved| *saved = preferences) {
+                self.report_save_error(format!("Failed to save display settings: {}", e));
+            }
+        }
+    }
+
+    fn render_metrics_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Metrics Export");
+
+        let app_context = self.app_context.clone();
+        let saved_export = app_context.preferences().metrics_export;
+        let mut export = saved_export.clone();
+
+        egui::Grid::new("metrics_settings_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Export:");
+                let port = self
+                    .metrics_port_input
+                    .parse()
+                    .unwrap_or(DEFAULT_METRICS_PORT);
+                egui::ComboBox::from_id_salt("metrics_export")
+                    .selected_text(export.label())
+                    .show_ui(ui, |ui| {
+                        for option in [
+                            MetricsExport::Disabled,
+                            MetricsExport::Endpoint { port },
+                            MetricsExport::File {
+                                path: self.metrics_path_input.clone(),
+                            },
+                        ] {
+                            let label = option.label();
+                            ui.selectable_value(&mut export, option, label);
+                        }
+                    });
+                ui.end_row();
+
+                match &mut export {
+                    MetricsExport::Endpoint { port } => {
+                        ui.label("Port:");
+                        if ui
+                            .text_edit_singleline(&mut self.metrics_port_input)
+                            .changed()
+                        {
+                            if let Ok(new_port) = self.metrics_port_input.parse() {
+                                *port = new_port;
+                            }
+                        }
+                        ui.end_row();
+                    }
+                    MetricsExport::File { path } => {
+                        ui.label("File:");
+                        if ui
+                            .text_edit_singleline(&mut self.metrics_path_input)
+                            .changed()
+                        {
+                            *path = self.metrics_path_input.clone();
+                        }
+                        ui.end_row();
+                    }
+                    MetricsExport::Disabled => {}
+                }
+            });
+        ui.label(
+            egui::RichText::new(
+                "Task counts, request latencies, votes and errors in the Prometheus text format. Changes take effect after a restart.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        if export != saved_export {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.metrics_export = export)
+            {
+                self.report_save_error(format!("Failed to save metrics settings: {}", e));
+            }
+        }
+    }
+
+    fn render_balance_alert_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Low Balance Alerts");
+
+        let app_context = self.app_context.clone();
+        let saved_alerts = app_context.preferences().balance_alerts;
+        let mut alerts = saved_alerts.clone();
+
+        ui.horizontal(|ui| {
+            ui.label("Refresh balances of identities with a minimum every");
+            ui.add(
+                egui::DragValue::new(&mut alerts.poll_interval_minutes)
+                    .range(1..=1440)
+                    .suffix(" minutes"),
+            );
+        });
+        ui.label(
+            egui::RichText::new(format!(
+                "{} identities have a minimum balance. Set it on the identity's detail screen.",
+                alerts.identity_minimums.len()
+            ))
+            .color(Color32::GRAY),
+        );
+
+        if alerts != saved_alerts {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.balance_alerts = alerts)
+            {
+                self.report_save_error(format!("Failed to save balance alert settings: {}", e));
+            }
+        }
+    }
+
+    fn render_price_feed_settings(&mut self, ui: &mut Ui) -> AppAction {
+        ui.heading("Fiat Values");
+
+        let app_context = self.app_context.clone();
+        let saved_feed = app_context.preferences().price_feed;
+        let mut feed = saved_feed.clone();
+        let mut action = AppAction::None;
+
+        ui.checkbox(
+            &mut feed.enabled,
+            "Show the approximate fiat value of mainnet balances and fees",
+        );
+        egui::Grid::new("price_feed_settings_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Currency:");
+                ui.add(egui::TextEdit::singleline(&mut feed.currency).desired_width(60.0));
+                ui.end_row();
+
+                ui.label("Price from:");
+                egui::ComboBox::from_id_salt("price_provider")
+                    .selected_text(feed.provider.label())
+                    .show_ui(ui, |ui| {
+                        let custom = match &saved_feed.provider {
+                            PriceProvider::Custom { .. } => saved_feed.provider.clone(),
+                            PriceProvider::CoinGecko => PriceProvider::Custom {
+                                url: String::new(),
+                                json_path: String::new(),
+                            },
+                        };
+                        for provider in [PriceProvider::CoinGecko, custom] {
+                            let label = provider.label();
+                            ui.selectable_value(&mut feed.provider, provider, label);
+                        }
+                    });
+                ui.end_row();
+
+                if let PriceProvider::Custom { url, json_path } = &mut feed.provider {
+                    ui.label("URL:");
+                    ui.add(
+                        egui::TextEdit::singleline(url)
+                            .hint_text("https://example.com/price?fiat={currency}"),
+                    );
+                    ui.end_row();
+                    ui.label("Price at:");
+                    ui.add(egui::TextEdit::singleline(json_path).hint_text("data.{currency}"))
+                        .on_hover_text(
+                            "Dot separated keys leading to the price in the JSON answer. \
+                             {currency} is replaced with the lowercase currency code.",
+                        );
+                    ui.end_row();
+                }
+
+                ui.label("Refresh every:");
+                ui.add(
+                    egui::DragValue::new(&mut feed.refresh_minutes)
+                        .range(1..=1440)
+                        .suffix(" minutes"),
+                );
+                ui.end_row();
+            });
+
+        if feed.enabled {
+            ui.horizontal(|ui| {
+                match feed.current_price() {
+                    Some(price) => {
+                        let text = format!(
+                            "1 DASH = {:.2} {}, fetched {}",
+                            price.price,
+                            price.currency,
+                            TimeFormatter::new(&app_context.preferences())
+                                .format(price.fetched_at())
+                        );
+                        if price.is_stale(&feed) {
+                            ui.colored_label(
+                                Color32::DARK_RED,
+                                format!("{}. The price feed is unreachable.", text),
+                            );
+                        } else {
+                            ui.label(text);
+                        }
+                    }
+                    None => {
+                        ui.label("No price yet");
+                    }
+                }
+                if ui.button("Refresh now").clicked() {
+                    action = AppAction::BackendTask(BackendTask::PriceTask(PriceTask::FetchPrice));
+                }
+            });
+        }
+
+        if feed != saved_feed {
+            if feed.currency != saved_feed.currency {
+                load_cached_price(&app_context, &feed);
+            }
+            let refetch = feed.enabled
+                && (feed.currency != saved_feed.currency
+                    || feed.provider != saved_feed.provider
+                    || !saved_feed.enabled);
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.price_feed = feed)
+            {
+                self.report_save_error(format!("Failed to save price feed settings: {}", e));
+            } else if refetch {
+                action = AppAction::BackendTask(BackendTask::PriceTask(PriceTask::FetchPrice));
+            }
+        }
+        action
+    }
+
+    fn render_auto_top_up_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Automatic Top Ups");
+
+        let app_context = self.app_context.clone();
+        let saved_rules = app_context.preferences().auto_top_up_rules;
+        let mut rules = saved_rules.clone();
+        let identities = app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default();
+        let wallets: Vec<(String, String)> = app_context
+            .wallets
+            .read()
+            .unwrap()
+            .iter()
+            .map(|wallet| {
+                let wallet = wallet.read().unwrap();
+                (
+                    wallet.seed_hash(),
+                    wallet
+                        .alias
+                        .clone()
+                        .unwrap_or_else(|| "Unnamed Wallet".to_string()),
+                )
+            })
+            .collect();
+
+        ui.label(
+            egui::RichText::new(
+                "Keeps an identity above a balance by topping it up from a wallet when the \
+                 balance poller finds it low. The first top up of a rule asks for confirmation, \
+                 every top up and skip is written to the audit trail.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        let mut removed = None;
+        egui::Grid::new("auto_top_up_grid")
+            .num_columns(7)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                for heading in [
+                    "On",
+                    "Identity",
+                    "Keep above",
+                    "Fund from",
+                    "Per top up",
+                    "Per week",
+                    "",
+                ] {
+                    ui.label(egui::RichText::new(heading).strong());
+                }
+                ui.end_row();
+
+                for (i, rule) in rules.iter_mut().enumerate() {
+                    ui.checkbox(&mut rule.enabled, "");
+
+                    let identity_label = identities
+                        .iter()
+                        .find(|identity| {
+                            identity.identity.id().to_string(Encoding::Base58) == rule.identity_id
+                        })
+                        .map(|identity| identity.display_short_string())
+                        .unwrap_or_else(|| "Select".to_string());
+                    let previous_identity = rule.identity_id.clone();
+                    egui::ComboBox::from_id_salt(("auto_top_up_identity", i))
+                        .selected_text(identity_label)
+                        .show_ui(ui, |ui| {
+                            for identity in &identities {
+                                ui.selectable_value(
+                                    &mut rule.identity_id,
+                                    identity.identity.id().to_string(Encoding::Base58),
+                                    identity.display_string(),
+                                );
+                            }
+                        });
+
+                    let mut minimum_dash = rule.minimum as f64 / CREDITS_PER_DASH;
+                    if ui
+                        .add(
+                            egui::DragValue::new(&mut minimum_dash)
+                                .speed(0.01)
+                                .range(0.0..=10_000.0)
+                                .suffix(" DASH"),
+                        )
+                        .on_hover_text(format!("{} credits", rule.minimum))
+                        .changed()
+                    {
+                        rule.minimum = (minimum_dash * CREDITS_PER_DASH).round() as u64;
+                    }
+
+                    let wallet_label = wallets
+                        .iter()
+                        .find(|(seed_hash, _)| *seed_hash == rule.wallet_seed_hash)
+                        .map(|(_, alias)| alias.clone())
+                        .unwrap_or_else(|| "Select".to_string());
+                    let previous_wallet = rule.wallet_seed_hash.clone();
+                    egui::ComboBox::from_id_salt(("auto_top_up_wallet", i))
+                        .selected_text(wallet_label)
+                        .show_ui(ui, |ui| {
+                            for (seed_hash, alias) in &wallets {
+                                ui.selectable_value(
+                                    &mut rule.wallet_seed_hash,
+                                    seed_hash.clone(),
+                                    alias,
+                                );
+                            }
+                        });
+                    // A different identity or wallet needs a new first run confirmation
+                    if rule.identity_id != previous_identity
+                        || rule.wallet_seed_hash != previous_wallet
+                    {
+                        rule.confirmed = false;
+                    }
+
+                    for (duffs, id) in [
+                        (&mut rule.amount, "auto_top_up_amount"),
+                        (&mut rule.weekly_limit, "auto_top_up_weekly"),
+                    ] {
+                        let mut dash = *duffs as f64 * 1e-8;
+                        if ui
+                            .push_id((id, i), |ui| {
+                                ui.add(
+                                    egui::DragValue::new(&mut dash)
+                                        .speed(0.001)
+                                        .range(0.0..=1_000.0)
+                                        .suffix(" DASH"),
+                                )
+                            })
+                            .inner
+                            .changed()
+                        {
+                            *duffs = (dash * 1e8).round() as u64;
+                        }
+                    }
+
+                    ui.horizontal(|ui| {
+                        if !rule.confirmed {
+                            ui.label(egui::RichText::new("Not run yet").color(Color32::GRAY))
+                                .on_hover_text("The first top up asks for confirmation");
+                        }
+                        if ui.button("Remove").clicked() {
+                            removed = Some(i);
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+        if let Some(i) = removed {
+            rules.remove(i);
+        }
+        if ui
+            .add_enabled(
+                !identities.is_empty() && !wallets.is_empty(),
+                egui::Button::new("Add rule"),
+            )
+            .on_disabled_hover_text("Load an identity and a wallet first")
+            .clicked()
+        {
+            rules.push(AutoTopUpRule::default());
+        }
+
+        if rules != saved_rules {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.auto_top_up_rules = rules)
+            {
+                self.report_save_error(format!("Failed to save automatic top up rules: {}", e));
+            }
+        }
+    }
+
+    fn render_junk_name_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Junk Name Flags");
+
+        let app_context = self.app_context.clone();
+        let saved_rules = app_context.preferences().junk_name_rules;
+        let mut rules = saved_rules.clone();
+
+        ui.checkbox(
+            &mut rules.enabled,
+            "Flag likely abusive names in the contested names table",
+        );
+        ui.add_enabled_ui(rules.enabled, |ui| {
+            egui::Grid::new("junk_name_settings_grid")
+                .num_columns(2)
+                .spacing([10.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label("Short names up to:");
+                    ui.add(
+                        egui::DragValue::new(&mut rules.max_short_length)
+                            .range(0..=20)
+                            .suffix(" characters"),
+                    )
+                    .on_hover_text("0 turns this check off");
+                    ui.end_row();
+
+                    ui.label("Repeated character:");
+                    ui.add(
+                        egui::DragValue::new(&mut rules.max_repeated_chars)
+                            .range(0..=20)
+                            .suffix(" times in a row"),
+                    )
+                    .on_hover_text("0 turns this check off");
+                    ui.end_row();
+
+                    ui.label("Brands:");
+                    if ui
+                        .add(
+                            egui::TextEdit::multiline(&mut self.junk_brands_input)
+                                .desired_rows(2)
+                                .hint_text("Comma separated"),
+                        )
+                        .changed()
+                    {
+                        rules.brands = self
+                            .junk_brands_input
+                            .split(',')
+                            .map(|brand| brand.trim().to_lowercase())
+                            .filter(|brand| !brand.is_empty())
+                            .collect();
+                    }
+                    ui.end_row();
+
+                    ui.label("");
+                    ui.checkbox(
+                        &mut rules.match_homographs,
+                        "Match look-alike spellings such as g00gle or p4ypal",
+                    );
+                    ui.end_row();
+                });
+        });
+        ui.label(
+            egui::RichText::new("Flags are only a hint for triage, votes are always cast by hand.")
+                .color(Color32::GRAY),
+        );
+
+        if rules != saved_rules {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.junk_name_rules = rules)
+            {
+                self.report_save_error(format!("Failed to save junk name settings: {}", e));
+            }
+        }
+    }
+
+    fn render_sound_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Notification Sounds");
+
+        let app_context = self.app_context.clone();
+        let saved_sounds = app_context.preferences().notification_sounds;
+        let mut sounds = saved_sounds.clone();
+
+        egui::Grid::new("sound_settings_grid")
+            .num_columns(4)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Volume:");
+                ui.add(
+                    egui::Slider::new(&mut sounds.volume, 0.0..=1.0)
+                        .custom_formatter(|volume, _| format!("{:.0}%", volume * 100.0)),
+                );
+                ui.end_row();
+
+                for event in SoundEvent::ALL {
+                    let volume = sounds.volume;
+                    let event_sound = sounds.for_event_mut(event);
+                    ui.checkbox(&mut event_sound.enabled, event.label());
+                    ui.add_enabled_ui(event_sound.enabled, |ui| {
+                        egui::ComboBox::from_id_salt(("event_sound", event.label()))
+                            .selected_text(event_sound.sound.label())
+                            .show_ui(ui, |ui| {
+                                for sound in SoundChoice::BUILT_IN {
+                                    let label = sound.label();
+                                    ui.selectable_value(&mut event_sound.sound, sound, label);
+                                }
+                                let is_file = matches!(event_sound.sound, SoundChoice::File { .. });
+                                if ui.selectable_label(is_file, "WAV file").clicked() && !is_file {
+                                    event_sound.sound = SoundChoice::File {
+                                        path: String::new(),
+                                    };
+                                }
+                            });
+                    });
+                    if let SoundChoice::File { path } = &mut event_sound.sound {
+                        ui.add_enabled(
+                            event_sound.enabled,
+                            egui::TextEdit::singleline(path).hint_text("Path to a .wav file"),
+                        );
+                    } else {
+                        ui.label("");
+                    }
+                    if ui.button("Test").clicked() {
+                        play_sound(&event_sound.sound, volume);
+                    }
+                    ui.end_row();
+                }
+
+                ui.label("Contest reminder:");
+                ui.add(
+                    egui::DragValue::new(&mut sounds.contest_warning_minutes)
+                        .range(1..=7 * 24 * 60)
+                        .suffix(" minutes before the end"),
+                );
+                ui.end_row();
+            });
+        ui.label(
+            egui::RichText::new(
+                "The contest reminder plays once per watched contest none of your identities voted on.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        if sounds != saved_sounds {
+            if let Err(e) = app_context
+                .update_preferences(|preferences| preferences.notification_sounds = sounds)
+            {
+                self.report_save_error(format!(
+                    "Failed to save notification sound settings: {}",
+                    e
+                ));
+            }
+        }
+    }
+
+    fn render_finality_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Finality");
+
+        let app_context = self.app_context.clone();
+        let saved_finality = app_context.preferences().finality;
+        let mut finality = saved_finality;
+
+        egui::Grid::new("finality_settings_grid")
+            .num_columns(3)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                for (label, policy) in [
+                    ("Asset locks:", &mut finality.asset_locks),
+                    ("Withdrawals:", &mut finality.withdrawals),
+                ] {
+                    ui.label(label);
+                    ui.checkbox(&mut policy.require_chainlock, "Require a chain lock");
+                    ui.add_enabled_ui(!policy.require_chainlock, |ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut policy.confirmations)
+                                .range(0..=MAX_CONFIRMATIONS)
+                                .suffix(" confirmations"),
+                        );
+                    });
+                    ui.end_row();
+                }
+            });
+        ui.label(
+            egui::RichText::new(format!(
+                "Asset locks are used for registrations and top ups once they have {}. \
+                 Withdrawals show as final once their payout has {}. 0 confirmations accepts \
+                 an InstantSend lock, a chain lock is final whatever the number.",
+                finality.asset_locks.describe(),
+                finality.withdrawals.describe()
+            ))
+            .color(Color32::GRAY),
+        );
+
+        if finality != saved_finality {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.finality = finality)
+            {
+                self.report_save_error(format!("Failed to save finality settings: {}", e));
+            }
+        }
+    }
+
+    fn render_fee_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Fees");
+
+        let app_context = self.app_context.clone();
+        let saved_choice = app_context.preferences().fee_choice;
+        let mut choice = saved_choice;
+
+        ui.horizontal(|ui| {
+            ui.label("Default speed:");
+            for speed in FeeSpeed::ALL {
+                ui.radio_value(&mut choice.speed, speed, speed.label());
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Default coin selection:");
+            egui::ComboBox::from_id_salt("fee_coin_selection_setting")
+                .selected_text(choice.coin_selection.label())
+                .show_ui(ui, |ui| {
+                    for strategy in CoinSelection::ALL {
+                        ui.selectable_value(&mut choice.coin_selection, strategy, strategy.label());
+                    }
+                });
+        });
+        ui.label(
+            egui::RichText::new(
+                "Asset locks and withdrawals start with these and can be changed before sending. \
+                 The rate of each speed is estimated by Core when the transaction is built. \
+                 Minimizing inputs pays the smallest fee, minimizing change spends the outputs \
+                 closest to the amount.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        if choice != saved_choice {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.fee_choice = choice)
+            {
+                self.report_save_error(format!("Failed to save fee settings: {}", e));
+            }
+        }
+    }
+
+    fn render_approval_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Approvals");
+
+        let app_context = self.app_context.clone();
+        let approver = match app_context.db.get_approver() {
+            Ok(approver) => approver,
+            Err(e) => {
+                ui.colored_label(Color32::RED, format!("Failed to load the approver: {}", e));
+                return;
+            }
+        };
+        let saved_policy = app_context.preferences().approval_policy;
+
+        egui::Grid::new("approval_settings_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Approver:");
+                ui.label(
+                    approver
+                        .as_ref()
+                        .map_or("Not set up", |approver| approver.name.as_str()),
+                );
+                ui.end_row();
+
+                if approver.is_some() {
+                    ui.label("Approver password:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.approver_password_input)
+                            .password(true)
+                            .hint_text("Needed to change anything below"),
+                    );
+                    ui.end_row();
+                }
+
+                ui.label("New approver name:");
+                ui.text_edit_singleline(&mut self.approver_name_input);
+                ui.end_row();
+
+                ui.label("New approver password:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.approver_new_password_input)
+                        .password(true),
+                );
+                ui.end_row();
+
+                ui.label("");
+                ui.checkbox(
+                    &mut self.approval_policy.key_changes,
+                    "Adding, rotating or disabling identity keys",
+                );
+                ui.end_row();
+
+                ui.label("");
+                ui.checkbox(
+                    &mut self.approval_policy.contract_updates,
+                    "Updating a contract to its version on Platform",
+                );
+                ui.end_row();
+
+                ui.label("Withdrawals above:");
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.approval_threshold_input)
+                            .desired_width(100.0)
+                            .hint_text("Never"),
+                    );
+                    ui.label("DASH");
+                });
+                ui.end_row();
+            });
+
+        let withdrawal_threshold = parse_dash_input(&self.approval_threshold_input)
+            .map_err(|e| format!("Withdrawal limit: {}", e));
+        let changing_approver = !self.approver_name_input.trim().is_empty()
+            || !self.approver_new_password_input.is_empty();
+
+        ui.horizontal(|ui| {
+            if ui.button("Save").clicked() {
+                self.approval_result = Some(self.save_approval_settings(
+                    &app_context,
+                    approver.as_ref(),
+                    withdrawal_threshold,
+                    changing_approver,
+                ));
+            }
+            if self.approval_policy != saved_policy && ui.button("Discard changes").clicked() {
+                self.approval_policy = saved_policy.clone();
+                self.approval_threshold_input = dash_input(saved_policy.withdrawal_threshold);
+            }
+        });
+        match &self.approval_result {
+            Some(Ok(message)) => {
+                ui.colored_label(Color32::DARK_GREEN, message);
+            }
+            Some(Err(error)) => {
+                ui.colored_label(Color32::RED, error);
+            }
+            None => {}
+        }
+        ui.label(
+            egui::RichText::new(
+                "Checked actions wait for the approver's password before they run. Approvals, \
+                 rejections and wrong passwords are written to the audit trail.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        egui::CollapsingHeader::new("Audit trail")
+            .id_salt("approval_audit_trail")
+            .show(ui, |ui| {
+                let entries = match app_context.db.get_audit_log(50, &app_context) {
+                    Ok(entries) => entries,
+                    Err(e) => {
+                        ui.colored_label(
+                            Color32::RED,
+                            format!("Failed to load the audit trail: {}", e),
+                        );
+                        return;
+                    }
+                };
+                if entries.is_empty() {
+                    ui.label("Nothing logged yet");
+                    return;
+                }
+                let time_format = TimeFormatter::new(&app_context.preferences());
+                egui::Grid::new("approval_audit_grid")
+                    .num_columns(4)
+                    .striped(true)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.strong("Time");
+                        ui.strong("Action");
+                        ui.strong("Outcome");
+                        ui.strong("Approver");
+                        ui.end_row();
+                        for entry in entries {
+                            ui.label(time_format.format_seconds(entry.logged_at.max(0) as u64));
+                            ui.label(entry.action);
+                            ui.label(entry.outcome);
+                            ui.label(entry.approver.unwrap_or_default());
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    /// Saves the approver and policy, changing either needs the current approver's password
+    fn save_approval_settings(
+        &mut self,
+        app_context: &Arc<AppContext>,
+        approver: Option<&Approver>,
+        withdrawal_threshold: Result<Option<u64>, String>,
+        changing_approver: bool,
+    ) -> Result<String, String> {
+        self.approval_policy.withdrawal_threshold = withdrawal_threshold?;
+        if let Some(approver) = approver {
+            if !approver.verify(&self.approver_password_input) {
+                return Err("Wrong approver password".to_string());
+            }
+        } else if self.approval_policy.is_enabled() && !changing_approver {
+            return Err("Set up an approver before requiring approvals".to_string());
+        }
+        self.approver_password_input.clear();
+
+        if changing_approver {
+            if self.approver_name_input.trim().is_empty() {
+                return Err("The approver needs a name".to_string());
+            }
+            if self.approver_new_password_input.is_empty() {
+                return Err("The approver needs a password".to_string());
+            }
+            let new_approver =
+                Approver::new(&self.approver_name_input, &self.approver_new_password_input);
+            app_context
+                .db
+                .set_approver(&new_approver)
+                .map_err(|e| format!("Failed to save the approver: {}", e))?;
+            self.approver_name_input.clear();
+            self.approver_new_password_input.clear();
+        }
+
+        let policy = self.approval_policy.clone();
+        app_context
+            .update_preferences(|preferences| preferences.approval_policy = policy)
+            .map_err(|e| format!("Failed to save the approval policy: {}", e))?;
+        Ok("Approval settings saved".to_string())
+    }
+
+    fn render_spending_limit_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Spending Limits");
+
+        let app_context = self.app_context.clone();
+        let approver = app_context.db.get_approver().ok().flatten();
+
+        egui::Grid::new("spending_limit_settings_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Daily limit per identity:");
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.spending_default_input)
+                            .desired_width(100.0)
+                            .hint_text("None"),
+                    );
+                    ui.label("DASH");
+                });
+                ui.end_row();
+
+                ui.label("Over the limit:");
+                egui::ComboBox::from_id_salt("spending_over_limit")
+                    .selected_text(self.over_limit_action.label())
+                    .show_ui(ui, |ui| {
+                        for action in OverLimitAction::ALL {
+                            ui.selectable_value(
+                                &mut self.over_limit_action,
+                                action,
+                                action.label(),
+                            );
+                        }
+                    });
+                ui.end_row();
+
+                if approver.is_some() {
+                    ui.label("Approver password:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.spending_password_input)
+                            .password(true)
+                            .hint_text("Needed to change the limits"),
+                    );
+                    ui.end_row();
+                }
+            });
+
+        egui::CollapsingHeader::new("Limits of single identities")
+            .id_salt("spending_identity_limits")
+            .show(ui, |ui| {
+                let identities = match app_context.load_local_qualified_identities() {
+                    Ok(identities) => identities,
+                    Err(e) => {
+                        ui.colored_label(Color32::RED, format!("Failed to load identities: {}", e));
+                        return;
+                    }
+                };
+                egui::Grid::new("spending_identity_limits_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        for identity in identities {
+                            let id = identity.identity.id().to_string(Encoding::Base58);
+                            ui.label(identity.display_string());
+                            ui.horizontal(|ui| {
+                                ui.add(
+                                    egui::TextEdit::singleline(
+                                        self.spending_identity_inputs.entry(id).or_default(),
+                                    )
+                                    .desired_width(100.0)
+                                    .hint_text("Daily limit"),
+                                );
+                                ui.label("DASH");
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        if ui.button("Save limits").clicked() {
+            self.spending_result = Some(self.save_spending_limits(&app_context, approver.as_ref()));
+        }
+        match &self.spending_result {
+            Some(Ok(message)) => {
+                ui.colored_label(Color32::DARK_GREEN, message);
+            }
+            Some(Err(error)) => {
+                ui.colored_label(Color32::RED, error);
+            }
+            None => {}
+        }
+        ui.label(
+            egui::RichText::new(
+                "Caps the credits each identity withdraws within 24 hours. A limit of an \
+                 identity replaces the default one, empty fields mean no limit.",
+            )
+            .color(Color32::GRAY),
+        );
+    }
+
+    /// Saves the spending limits, needs the approver's password when there is an approver
+    fn save_spending_limits(
+        &mut self,
+        app_context: &Arc<AppContext>,
+        approver: Option<&Approver>,
+    ) -> Result<String, String> {
+        let mut limits = SpendingLimits {
+            default_daily_limit: parse_dash_input(&self.spending_default_input)
+                .map_err(|e| format!("Daily limit: {}", e))?,
+            identity_limits: BTreeMap::new(),
+            over_limit: self.over_limit_action,
+        };
+        for (id, input) in &self.spending_identity_inputs {
+            if let Some(limit) = parse_dash_input(input).map_err(|e| format!("{}: {}", id, e))? {
+                limits.identity_limits.insert(id.clone(), limit);
+            }
+        }
+        if let Some(approver) = approver {
+            if !approver.verify(&self.spending_password_input) {
+                return Err("Wrong approver password".to_string());
+            }
+        }
+        self.spending_password_input.clear();
+
+        app_context
+            .update_preferences(|preferences| preferences.spending_limits = limits)
+            .map_err(|e| format!("Failed to save the spending limits: {}", e))?;
+        Ok("Spending limits saved".to_string())
+    }
+
+    fn render_explorer_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Explorer Links");
+
+        let app_context = self.app_context.clone();
+        let saved_explorer = app_context.preferences().explorer;
+        let mut explorer = saved_explorer.clone();
+
+        egui::Grid::new("explorer_settings_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                for (network, label) in [("dash", "Mainnet"), ("testnet", "Testnet")] {
+                    ui.label(format!("{} platform explorer:", label));
+                    let url = explorer
+                        .platform_base_urls
+                        .entry(network.to_string())
+                        .or_default();
+                    ui.add(
+                        egui::TextEdit::singleline(url)
+                            .desired_width(300.0)
+                            .hint_text("Empty to disable explorer links"),
+                    );
+                    ui.end_row();
+
+                    ui.label(format!("{} block explorer:", label));
+                    let template = explorer
+                        .block_explorer_urls
+                        .entry(network.to_string())
+                        .or_default();
+                    ui.add(
+                        egui::TextEdit::singleline(template)
+                            .desired_width(300.0)
+                            .hint_text(format!("https://…/tx/{}", TXID_PLACEHOLDER)),
+                    );
+                    ui.end_row();
+                }
+            });
+        ui.label(
+            egui::RichText::new(format!(
+                "Used by shareable contest links and \"Open in explorer\" actions. The block \
+                 explorer URL needs {} where the transaction id goes.",
+                TXID_PLACEHOLDER
+            ))
+            .color(Color32::GRAY),
+        );
+
+        if explorer != saved_explorer {
+            if let Err(e) = app_context.update_preferences(|preferences| {
+                preferences.explorer = explorer;
+            }) {
+                self.report_save_error(format!("Failed to save explorer settings: {}", e));
+            }
+        }
+    }
+
+    fn render_vote_broadcast_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Vote Broadcasting");
+
+        let app_context = self.app_context.clone();
+        let saved_broadcast = app_context.preferences().redundant_vote_broadcast;
+        let mut broadcast = saved_broadcast;
+
+        ui.checkbox(
+            &mut broadcast.enabled,
+            "Send votes through several DAPI nodes at once",
+        );
+        ui.add_enabled_ui(broadcast.enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("DAPI nodes per vote:");
+                ui.add(
+                    egui::DragValue::new(&mut broadcast.endpoints)
+                        .range(2..=MAX_BROADCAST_ENDPOINTS),
+                );
+            });
+            ui.horizontal(|ui| {
+                ui.label("Only for contests ending within");
+                ui.add(
+                    egui::DragValue::new(&mut broadcast.within_minutes_of_end)
+                        .range(0..=60 * 24 * 14)
+                        .suffix(" minutes"),
+                );
+                ui.label("(0 for every vote)");
+            });
+        });
+        ui.label(
+            egui::RichText::new(
+                "The same vote transition is sent to each node and counts once any of them \
+                 confirms it, so one unresponsive node can't make a vote miss the end of a \
+                 contest. Nodes flagged for bad proofs are skipped.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        if broadcast != saved_broadcast {
+            if let Err(e) = app_context
+                .update_preferences(|preferences| preferences.redundant_vote_broadcast = broadcast)
+            {
+                self.report_save_error(format!("Failed to save vote broadcast settings: {}", e));
+            }
+        }
+    }
+
+    fn render_vote_webhook_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Vote Summaries");
+
+        let app_context = self.app_context.clone();
+        let saved_webhook = app_context.preferences().vote_webhook;
+        let mut webhook = saved_webhook.clone();
+
+        ui.checkbox(
+            &mut webhook.enabled,
+            "Post a summary of each voting session to a webhook",
+        );
+        ui.add_enabled_ui(webhook.enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Webhook URL:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut webhook.url)
+                        .hint_text("https://discord.com/api/webhooks/…")
+                        .desired_width(300.0),
+                );
+            });
+            ui.horizontal(|ui| {
+                ui.label("Format:");
+                egui::ComboBox::from_id_salt("vote_webhook_format")
+                    .selected_text(webhook.format.to_string())
+                    .show_ui(ui, |ui| {
+                        for format in VoteWebhookFormat::ALL {
+                            ui.selectable_value(&mut webhook.format, format, format.to_string());
+                        }
+                    });
+            });
+        });
+        ui.label(
+            egui::RichText::new(
+                "Sent after every vote and bulk vote: the contests, the choices, the identities \
+                 that voted and the hashes of the vote transitions. Anyone with access to the \
+                 channel sees which masternodes you run.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        if webhook != saved_webhook {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.vote_webhook = webhook)
+            {
+                self.report_save_error(format!("Failed to save vote webhook settings: {}", e));
+            }
+        }
+    }
+
+    fn render_telemetry_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Usage Statistics");
+
+        let app_context = self.app_context.clone();
+        let saved_telemetry = app_context.preferences().telemetry;
+        let mut telemetry = saved_telemetry.clone();
+
+        ui.checkbox(
+            &mut telemetry.enabled,
+            "Send anonymous usage statistics once a day",
+        );
+        ui.add_enabled_ui(telemetry.enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Endpoint:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut telemetry.endpoint)
+                        .hint_text("https://…")
+                        .desired_width(300.0),
+                );
+            });
+        });
+        ui.label(
+            egui::RichText::new(
+                "Off unless you turn it on. Reports count which kinds of tasks were used and                  why they failed since the last report, to help decide what to work on. They                  hold no ids, names, addresses, amounts or error messages, and nothing that                  identifies this installation.",
+            )
+            .color(Color32::GRAY),
+        );
+        if let Some(sent_at) = telemetry.last_sent_at {
+            let time_format = TimeFormatter::new(&app_context.preferences());
+            ui.label(format!(
+                "Last sent {}",
+                time_format.format_seconds(sent_at as u64)
+            ));
+        }
+        egui::CollapsingHeader::new("What would be sent now")
+            .id_salt("telemetry_preview")
+            .show(ui, |ui| {
+                let report = app_context.telemetry_report();
+                if report.is_empty() {
+                    ui.label("Nothing, no tasks ran since the last report.");
+                } else {
+                    ui.label(egui::RichText::new(report.to_json()).monospace());
+                }
+            });
+
+        if telemetry != saved_telemetry {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.telemetry = telemetry)
+            {
+                self.report_save_error(format!("Failed to save usage statistics settings: {}", e));
+            }
+        }
+    }
+
+    fn render_event_webhook_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Event Webhooks");
+
+        let app_context = self.app_context.clone();
+        let saved_settings = app_context.preferences().webhooks;
+        let mut settings = saved_settings.clone();
+
+        ui.label(
+            egui::RichText::new(
+                "Each webhook is posted its template when one of its events happens, with the \
+                 placeholders filled in.",
+            )
+            .color(Color32::GRAY),
+        );
+
+        let mut removed = None;
+        for (index, webhook) in settings.webhooks.iter_mut().enumerate() {
+            ui.push_id(("event_webhook", index), |ui| {
+                ui.add_space(5.0);
+                ui.group(|ui| {
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut webhook.enabled, "");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut webhook.name)
+                                .hint_text("Name")
+                                .desired_width(120.0),
+                        );
+                        ui.add(
+                            egui::TextEdit::singleline(&mut webhook.url)
+                                .hint_text("https://…")
+                                .desired_width(300.0),
+                        );
+                        if ui.button("Remove").clicked() {
+                            removed = Some(index);
+                        }
+                    });
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label("Events:");
+                        for event in WebhookEvent::ALL {
+                            let mut wanted = webhook.events.contains(&event);
+                            let placeholders = event.placeholders().join("}}, {{");
+                            if ui
+                                .checkbox(&mut wanted, event.label())
+                                .on_hover_text(format!("Fills {{{{{}}}}}", placeholders))
+                                .changed()
+                            {
+                                if wanted {
+                                    webhook.events.insert(event);
+                                } else {
+                                    webhook.events.remove(&event);
+                                }
+                            }
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Template:");
+                        for (label, template) in [
+                            ("Generic", TEMPLATE_GENERIC),
+                            ("Discord", TEMPLATE_DISCORD),
+                            ("Slack", TEMPLATE_SLACK),
+                        ] {
+                            if ui.small_button(label).clicked() {
+                                webhook.template = template.to_string();
+                            }
+                        }
+                    });
+                    ui.add(
+                        egui::TextEdit::multiline(&mut webhook.template)
+                            .code_editor()
+                            .desired_rows(4)
+                            .desired_width(f32::INFINITY),
+                    );
+                    if let Err(e) = webhook.validate_template() {
+                        ui.colored_label(Color32::DARK_RED, e);
+                    }
+                });
+            });
+        }
+        if let Some(index) = removed {
+            settings.webhooks.remove(index);
+        }
+
+        ui.add_space(5.0);
+        if ui.button("Add Webhook").clicked() {
+            settings.webhooks.push(EventWebhook {
+                name: format!("Webhook {}", settings.webhooks.len() + 1),
+                ..Default::default()
+            });
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Notify un-voted contests ending within");
+            ui.add(
+                egui::DragValue::new(&mut settings.contest_ending_hours)
+                    .range(1..=336)
+                    .suffix(" h"),
+            );
+        });
+        ui.label(
+            egui::RichText::new(format!(
+                "Placeholders of every event: {{{{{}}}}}. New contests are noticed when the \
+                 contested names are refreshed.",
+                COMMON_PLACEHOLDERS.join("}}, {{")
+            ))
+            .color(Color32::GRAY),
+        );
+
+        if settings != saved_settings {
+            if let Err(e) =
+                app_context.update_preferences(|preferences| preferences.webhooks = settings)
+            {
+                self.report_save_error(format!("Failed to save webhook settings: {}", e));
+            }
+        }
+    }
+
+    fn render_settings_transfer(&mut self, ui: &mut Ui) {
+        ui.heading("Import and Export Settings");
+
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            ui.add(egui::TextEdit::singleline(&mut self.settings_file_input).desired_width(300.0));
+            if ui.button("Export").clicked() {
+                self.settings_transfer_result = Some(self.export_settings());
+            }
+            if ui.button("Import").clicked() {
+                let result = self.import_settings();
+                if result.is_ok() {
+                    self.reload_settings_inputs();
+                }
+                self.settings_transfer_result = Some(result);
+            }
+        });
+        match &self.settings_transfer_result {
+            Some(Ok(message)) => {
+                ui.colored_label(Color32::DARK_GREEN, message);
+            }
+            Some(Err(e)) => {
+                ui.colored_label(Color32::RED, e);
+            }
+            None => {}
+        }
+        ui.label(
+            egui::RichText::new(
+                "Exports the preferences and the network profiles of this profile as JSON, to \
+                 set up other machines the same way. RPC passwords, wallet keys, identities and \
+                 the approver are never exported, an import keeps the ones the machine has.",
+            )
+            .color(Color32::GRAY),
+        );
+    }
+
+    fn export_settings(&self) -> Result<String, String> {
+        let app_context = &self.app_context;
+        let export = SettingsExport::collect(
+            app_context.preferences(),
+            &app_context.app_dirs.config_path(),
+        )?;
+        let path = self.settings_file_input.trim();
+        std::fs::write(path, export.to_json())
+            .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+        Ok(format!("Exported the settings to {}", path))
+    }
+
+    fn import_settings(&self) -> Result<String, String> {
+        let app_context = &self.app_context;
+        let path = self.settings_file_input.trim();
+        let contents =
+            std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        let export = SettingsExport::from_json(&contents)?;
+
+        // Approval and spending rules only change with the approver's password
+        let approver = app_context
+            .db
+            .get_approver()
+            .map_err(|e| format!("Failed to load the approver: {}", e))?;
+        let networks = export.apply_networks(&app_context.app_dirs.config_path())?;
+        let mut imported = export.preferences;
+        app_context
+            .update_preferences(|preferences| {
+                if approver.is_some() {
+                    imported.approval_policy = preferences.approval_policy.clone();
+                    imported.spending_limits = preferences.spending_limits.clone();
+                }
+                *preferences = imported;
+            })
+            .map_err(|e| format!("Failed to save the preferences: {}", e))?;
+
+        let mut message = format!("Imported the preferences from {}", path);
+        if !networks.is_empty() {
+            message.push_str(&format!(
+                ", and the {} network profiles, they take effect after a restart",
+                networks.join(" and ")
+            ));
+        }
+        if approver.is_some() {
+            message.push_str(
+                ". Approval and spending rules were kept, change them with the approver's password",
+            );
+        }
+        Ok(message)
+    }
+
+    /// Refreshes the inputs that were filled from the preferences, after they were replaced
+    fn reload_settings_inputs(&mut self) {
+        let reloaded = Self::new(&self.app_context);
+        self.metrics_port_input = reloaded.metrics_port_input;
+        self.metrics_path_input = reloaded.metrics_path_input;
+        self.junk_brands_input = reloaded.junk_brands_input;
+        self.approval_policy = reloaded.approval_policy;
+        self.approval_threshold_input = reloaded.approval_threshold_input;
+        self.over_limit_action = reloaded.over_limit_action;
+        self.spending_default_input = reloaded.spending_default_input;
+        self.spending_identity_inputs = reloaded.spending_identity_inputs;
+        self.ui_scale_input = reloaded.ui_scale_input;
+    }
+
+    fn render_storage_settings(&mut self, ui: &mut Ui) {
+        ui.heading("Data Storage");
+
+        let app_dirs = self.app_context.app_dirs.clone();
+        ui.horizontal(|ui| {
+            ui.label("Profile:");
+            ui.label(egui::RichText::new(&app_dirs.profile).strong());
+        });
+        ui.horizontal(|ui| {
+            ui.label("Data directory:");
+            ui.label(egui::RichText::new(app_dirs.data_dir.display().to_string()).monospace());
+        });
+        ui.horizontal(|ui| {
+            ui.label("New profile:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.new_profile_name)
+                    .hint_text("e.g. Company masternodes")
+                    .desired_width(200.0),
+            );
+            if ui.button("Create Profile").clicked() {
+                self.profile_creation_result = Some(
+                    app_dirs
+                        .create_profile(&self.new_profile_name)
+                        .map(|created| created.profile),
+                );
+                if matches!(self.profile_creation_result, Some(Ok(_))) {
+                    self.new_profile_name.clear();
+                }
+            }
+        });
+        match &self.profile_creation_result {
+            Some(Ok(profile)) => {
+                ui.colored_label(
+                    Color32::DARK_GREEN,
+                    format!("Created {}, restart to open it", profile),
+                );
+            }
+            Some(Err(e)) => {
+                ui.colored_label(Color32::RED, e);
+            }
+            None => {}
+        }
+        ui.label(
+            egui::RichText::new(format!(
+                "Each profile has its own database, settings and logs, e.g. to keep a company's \
+                 masternodes apart from personal identities. Once there is more than one, the \
+                 profile is chosen at startup, or with {} <name>.",
+                PROFILE_FLAG
+            ))
+            .color(Color32::GRAY),
+        );
+        ui.add_space(10.0);
+
+        let mut portable = AppDirs::is_portable_mode_saved();
+        if ui
+            .checkbox(&mut portable, "Portable mode")
+            .on_hover_text(
+                "Keep the database, config and logs in a folder beside the executable, e.g. to run from a USB stick",
+            )
+            .changed()
+        {
+            if let Err(e) = AppDirs::save_portable_mode(portable) {
+                self.report_save_error(format!("Failed to change portable mode: {}", e));
+            }
+        }
+        let note = if app_dirs.portable && !portable {
+            "Portable mode is on for this run, either by the command line flag or until restart."
+                .to_string()
+        } else {
+            format!(
+                "Takes effect after a restart. Starting with {} enables it for a single run.",
+                PORTABLE_FLAG
+            )
+        };
+        ui.label(egui::RichText::new(note).color(Color32::GRAY));
+
+        ui.add_space(10.0);
+        ui.horizontal(|ui| {
+            if ui
+                .button(format!("Open {}:// links with this tool", URI_SCHEME))
+                .on_hover_text("Links to contests and identities then open the right screen")
+                .clicked()
+            {
+                self.link_registration_result =
+                    Some(register_uri_scheme().map_err(|e| e.to_string()));
+            }
+            match &self.link_registration_result {
+                Some(Ok(())) => {
+                    ui.colored_label(Color32::DARK_GREEN, "Registered");
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(Color32::RED, format!("Failed to register: {}", e));
+                }
+                None => {}
+            }
+        });
+    }
+}
+
+impl ScreenLike for SettingsScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Settings", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => ui.visuals().text_color(),
+                    };
+                    ui.colored_label(color, message);
+                }
+                self.render_display_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_metrics_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_junk_name_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_sound_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_balance_alert_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_auto_top_up_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                action |= self.render_price_feed_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_finality_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_fee_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_approval_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_spending_limit_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_explorer_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_vote_broadcast_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_vote_webhook_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_event_webhook_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_telemetry_settings(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_settings_transfer(ui);
+                ui.add_space(20.0);
+                ui.separator();
+                self.render_storage_settings(ui);
+            });
+        });
+
+        action
+    }
+}
+
+/// Switches fiat values to the cached price of a newly chosen currency until it is refreshed
+fn load_cached_price(app_context: &AppContext, feed: &PriceFeedSettings) {
+    match app_context.db.get_fiat_price(&feed.currency) {
+        Ok(Some(price)) => set_fiat_price(price),
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Failed to load the cached price: {}", e),
+    }
+}
+
+/// Shows an optional amount of credits as DASH in a text input, empty for none
+fn dash_input(credits: Option<u64>) -> String {
+    credits
+        .map(|credits| (credits as f64 / CREDITS_PER_DASH).to_string())
+        .unwrap_or_default()
+}
+
+/// Parses an amount of DASH typed in a text input into credits, `None` if it is empty
+fn parse_dash_input(input: &str) -> Result<Option<u64>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    input
+        .parse::<f64>()
+        .ok()
+        .filter(|dash| dash.is_finite() && *dash >= 0.0)
+        .map(|dash| Some((dash * CREDITS_PER_DASH).round() as u64))
+        .ok_or(format!("\"{}\" is not a valid amount of DASH", input))
+}
@@ -1,9 +1,16 @@
 use crate::app::AppAction;
 use crate::context::AppContext;
+use crate::model::identity_details::Contact;
+use crate::model::payment_request::PaymentRequest;
 use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::recipient_check::LookalikeRecipient;
+use crate::model::transfer_memo::MAX_MEMO_BYTES;
 use crate::platform::identity::IdentityTask;
 use crate::platform::BackendTask;
+use crate::ui::components::approval_window::daily_allowance;
+use crate::ui::components::recipient_warning::{find_lookalike_recipient, show_lookalike_warning};
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::number_format::{NumberFormatter, CREDITS_PER_DASH};
 use crate::ui::key_info_screen::KeyInfoScreen;
 use crate::ui::{MessageType, Screen, ScreenLike};
 use dash_sdk::dashcore_rpc::dashcore::Address;
@@ -11,7 +18,8 @@ use dash_sdk::dpp::fee::Credits;
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
 use dash_sdk::dpp::identity::{KeyType, Purpose, SecurityLevel};
-use dash_sdk::platform::IdentityPublicKey;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::{Identifier, IdentityPublicKey};
 use eframe::egui::{self, Context, Ui};
 use std::convert::identity;
 use std::str::FromStr;
@@ -26,11 +34,31 @@ pub struct TransferScreen {
     max_amount: u64,
     pub app_context: Arc<AppContext>,
     confirmation_popup: bool,
+    /// Daily limit of the identity and what it spent of it, when it has a limit
+    daily_allowance: Option<(Credits, Credits)>,
+    /// Known recipient the entered address resembles, checked when confirming
+    lookalike_recipient: Option<LookalikeRecipient>,
+    lookalike_acknowledged: bool,
+    /// A payment request URI pasted to fill the form
+    payment_request_input: String,
+    /// Memo of the applied payment request
+    payment_memo: Option<String>,
+    /// Memo encrypted for the recipient, when sending to an identity
+    transfer_memo: String,
+    contacts: Vec<Contact>,
 }
 
 impl TransferScreen {
     pub fn new(identity: QualifiedIdentity, app_context: &Arc<AppContext>) -> Self {
         let max_amount = identity.identity.balance();
+        let daily_allowance = daily_allowance(app_context, &identity.identity.id());
+        let contacts = app_context
+            .db
+            .get_contacts(app_context)
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to load contacts: {}", e);
+                vec![]
+            });
         Self {
             identity,
             selected_key: None,
@@ -40,9 +68,93 @@ impl TransferScreen {
             max_amount,
             app_context: app_context.clone(),
             confirmation_popup: false,
+            daily_allowance,
+            lookalike_recipient: None,
+            lookalike_acknowledged: false,
+            payment_request_input: String::new(),
+            payment_memo: None,
+            transfer_memo: String::new(),
+            contacts,
         }
     }
 
+    /// The identity the credits go to, when the recipient is an identity id rather than an
+    /// address
+    fn recipient_identity(&self) -> Option<Identifier> {
+        Identifier::from_string(self.withdrawal_address.trim(), Encoding::Base58).ok()
+    }
+
+    /// Pre-fills the recipient, e.g. when starting a transfer from an identity's detail screen
+    pub fn with_recipient(mut self, recipient: String) -> Self {
+        self.withdrawal_address = recipient;
+        self
+    }
+
+    /// The typed amount and address, saved with the session
+    pub fn form_input(&self) -> (String, String) {
+        (
+            self.withdrawal_amount.clone(),
+            self.withdrawal_address.clone(),
+        )
+    }
+
+    /// Fills the form with an amount and address saved with the session
+    pub fn with_form_input(mut self, amount: String, address: String) -> Self {
+        self.withdrawal_amount = amount;
+        self.withdrawal_address = address;
+        self
+    }
+
+    fn render_payment_request_input(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Payment request:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.payment_request_input)
+                    .hint_text("dash-evo-tool://pay?to=...")
+                    .desired_width(300.0),
+            );
+            if ui
+                .add_enabled(
+                    !self.payment_request_input.trim().is_empty(),
+                    egui::Button::new("Apply"),
+                )
+                .clicked()
+            {
+                self.apply_payment_request();
+            }
+        });
+        if let Some(memo) = &self.payment_memo {
+            ui.label(format!("Memo: {}", memo));
+        }
+    }
+
+    /// Fills the form from a payment request, e.g. one opened as a link
+    pub fn with_payment_request(mut self, request: PaymentRequest) -> Self {
+        self.fill_from_request(request);
+        self
+    }
+
+    /// Fills the amount and address from the pasted payment request
+    fn apply_payment_request(&mut self) {
+        match PaymentRequest::parse(&self.payment_request_input) {
+            Ok(request) => self.fill_from_request(request),
+            Err(e) => self.error_message = Some(e),
+        }
+    }
+
+    fn fill_from_request(&mut self, request: PaymentRequest) {
+        if request.is_identity_recipient() {
+            self.transfer_memo = request.memo.clone();
+        }
+        self.withdrawal_address = request.recipient;
+        if let Some(amount) = request.amount {
+            self.withdrawal_amount = (amount as f64 / CREDITS_PER_DASH).to_string();
+        }
+        self.payment_memo = Some(request.memo).filter(|memo| !memo.is_empty());
+        self.payment_request_input.clear();
+        self.error_message = None;
+    }
+
     fn render_key_selection(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
             ui.label("Select Key:");
@@ -80,6 +192,14 @@ impl TransferScreen {
                 self.withdrawal_amount = self.max_amount.to_string();
             }
         });
+        if let Some((limit, spent)) = self.daily_allowance {
+            let number_format = NumberFormatter::new(&self.app_context.preferences());
+            ui.label(format!(
+                "Daily limit: {}, {} left",
+                number_format.format_credits_as_dash(limit),
+                number_format.format_credits_as_dash(limit.saturating_sub(spent))
+            ));
+        }
     }
 
     fn render_address_input(&mut self, ui: &mut Ui) {
@@ -90,9 +210,40 @@ impl TransferScreen {
         };
         if can_have_withdrawal_address || self.app_context.developer_mode {
             ui.horizontal(|ui| {
-                ui.label("Address:");
+                ui.label("Recipient:");
 
-                ui.text_edit_singleline(&mut self.withdrawal_address);
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.withdrawal_address)
+                        .hint_text("Address or identity id"),
+                );
+
+                if !self.contacts.is_empty() {
+                    egui::ComboBox::from_id_salt("transfer_contact")
+                        .selected_text("Contacts")
+                        .show_ui(ui, |ui| {
+                            for contact in &self.contacts {
+                                let identity_id = contact.identity_id.to_string(Encoding::Base58);
+                                let label = match &contact.alias {
+                                    Some(alias) => format!("{} ({})", alias, identity_id),
+                                    None => identity_id.clone(),
+                                };
+                                if ui.selectable_label(false, label).clicked() {
+                                    self.withdrawal_address = identity_id;
+                                }
+                            }
+                        });
+                }
+            });
+        }
+        if self.recipient_identity().is_some() {
+            ui.horizontal(|ui| {
+                ui.label("Memo:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.transfer_memo)
+                        .hint_text("Optional, encrypted for the recipient")
+                        .desired_width(300.0),
+                );
+                ui.label(format!("{}/{}", self.transfer_memo.len(), MAX_MEMO_BYTES));
             });
         }
     }
@@ -100,11 +251,18 @@ impl TransferScreen {
     fn show_confirmation_popup(&mut self, ui: &mut Ui) -> AppAction {
         let mut app_action = AppAction::None;
         let mut is_open = true;
-        egui::Window::new("Confirm Withdrawal")
+        let recipient_identity = self.recipient_identity();
+        let title = if recipient_identity.is_some() {
+            "Confirm Transfer"
+        } else {
+            "Confirm Withdrawal"
+        };
+        egui::Window::new(title)
             .collapsible(false)
             .open(&mut is_open)
             .show(ui.ctx(), |ui| {
-                let address = if self.withdrawal_address.is_empty() {
+                let address = if self.withdrawal_address.is_empty() || recipient_identity.is_some()
+                {
                     None
                 } else {
                     match Address::from_str(&self.withdrawal_address) {
@@ -116,7 +274,7 @@ impl TransferScreen {
                     }
                 };
 
-                let message_address = if address.is_some() {
+                let message_address = if recipient_identity.is_some() || address.is_some() {
                     self.withdrawal_address.clone()
                 } else if let Some(payout_address) = self
                     .identity
@@ -135,10 +293,20 @@ impl TransferScreen {
                     return;
                 };
 
-                ui.label(format!(
-                    "Are you sure you want to withdraw {} Dash to {}",
-                    self.withdrawal_amount, message_address
-                ));
+                if recipient_identity.is_some() {
+                    ui.label(format!(
+                        "Are you sure you want to transfer {} Dash to identity {}",
+                        self.withdrawal_amount, message_address
+                    ));
+                    if !self.transfer_memo.trim().is_empty() {
+                        ui.label(format!("Memo: {}", self.transfer_memo));
+                    }
+                } else {
+                    ui.label(format!(
+                        "Are you sure you want to withdraw {} Dash to {}",
+                        self.withdrawal_amount, message_address
+                    ));
+                }
                 let parts: Vec<&str> = self.withdrawal_amount.split('.').collect();
                 let mut credits: u128 = 0;
 
@@ -158,16 +326,35 @@ impl TransferScreen {
                     credits += fraction_number * multiplier; // Fractional Dash to credits
                 }
 
-                if ui.button("Confirm").clicked() {
+                let confirm_allowed = match &self.lookalike_recipient {
+                    Some(lookalike) => {
+                        show_lookalike_warning(ui, lookalike, &mut self.lookalike_acknowledged)
+                    }
+                    None => true,
+                };
+                if ui
+                    .add_enabled(confirm_allowed, egui::Button::new("Confirm"))
+                    .clicked()
+                {
                     self.confirmation_popup = false;
-                    app_action = AppAction::BackendTask(BackendTask::IdentityTask(
-                        IdentityTask::WithdrawFromIdentity(
+                    let task = match recipient_identity {
+                        Some(recipient_id) => IdentityTask::TransferCredits(
+                            self.identity.clone(),
+                            recipient_id,
+                            credits as Credits,
+                            Some(selected_key.id()),
+                            Some(self.transfer_memo.trim().to_string())
+                                .filter(|memo| !memo.is_empty()),
+                        ),
+                        None => IdentityTask::WithdrawFromIdentity(
                             self.identity.clone(),
                             address,
                             credits as Credits,
                             Some(selected_key.id()),
+                            self.app_context.preferences().fee_choice.speed,
                         ),
-                    ));
+                    };
+                    app_action = AppAction::BackendTask(BackendTask::IdentityTask(task));
                 }
                 if ui.button("Cancel").clicked() {
                     self.confirmation_popup = false;
@@ -230,12 +417,23 @@ impl ScreenLike for TransferScreen {
             } else {
                 ui.heading("Transfer Funds");
 
+                self.render_payment_request_input(ui);
                 self.render_key_selection(ui);
                 self.render_amount_input(ui);
                 self.render_address_input(ui);
 
                 if ui.button("Transfer").clicked() {
+                    if self.recipient_identity().is_some()
+                        && self.transfer_memo.len() > MAX_MEMO_BYTES
+                    {
+                        self.error_message =
+                            Some(format!("The memo is longer than {} bytes", MAX_MEMO_BYTES));
+                        return;
+                    }
                     self.confirmation_popup = true;
+                    self.lookalike_recipient =
+                        find_lookalike_recipient(&self.app_context, &self.withdrawal_address);
+                    self.lookalike_acknowledged = false;
                 }
 
                 if self.confirmation_popup {
@@ -0,0 +1,429 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::transition_sandbox::{
+    decode_transition, sign_transition, transition_from_json, transition_to_json,
+    validate_transition, SandboxCheck, SandboxTransitionKind,
+};
+use crate::platform::identity::IdentityTask;
+use crate::platform::BackendTask;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::identities::add_new_identity_screen::copy_to_clipboard;
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::dashcore::Network;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::identity::IdentityPublicKey;
+use dash_sdk::dpp::serialization::PlatformSerializable;
+use dash_sdk::dpp::state_transition::StateTransition;
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::sync::Arc;
+
+/// A developer sandbox where any kind of state transition is written as JSON, signed with a
+/// key of a local identity, validated locally and optionally broadcast, to try edge cases
+/// against testnet or a devnet
+pub struct TransitionSandboxScreen {
+    pub app_context: Arc<AppContext>,
+    kind: SandboxTransitionKind,
+    json_input: String,
+    /// A serialized transition in hex or base64 to start from
+    encoded_input: String,
+    identities: Vec<QualifiedIdentity>,
+    selected_identity: Option<usize>,
+    selected_key: Option<IdentityPublicKey>,
+    /// The transition last built from the JSON, with the checks it went through
+    transition: Option<StateTransition>,
+    checks: Vec<SandboxCheck>,
+    broadcasting: bool,
+    message: Option<(String, MessageType)>,
+}
+
+impl TransitionSandboxScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            kind: SandboxTransitionKind::IdentityCreditTransfer,
+            json_input: String::new(),
+            encoded_input: String::new(),
+            identities: Vec::new(),
+            selected_identity: None,
+            selected_key: None,
+            transition: None,
+            checks: Vec::new(),
+            broadcasting: false,
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    /// The keys of the identity whose private keys are on this machine
+    fn signing_keys(qualified_identity: &QualifiedIdentity) -> Vec<IdentityPublicKey> {
+        qualified_identity
+            .encrypted_private_keys
+            .values()
+            .map(|(public_key, _)| public_key.clone())
+            .collect()
+    }
+
+    /// The key the transition names as its signer, from the local identity that owns it, a
+    /// masternode's voter identity for votes
+    fn owner_key(&self, transition: &StateTransition) -> Option<IdentityPublicKey> {
+        let owner_id = transition.owner_id();
+        let key_id = transition.signature_public_key_id()?;
+        self.identities.iter().find_map(|qualified_identity| {
+            if qualified_identity.identity.id() == owner_id {
+                return qualified_identity
+                    .identity
+                    .get_public_key_by_id(key_id)
+                    .cloned();
+            }
+            let (voter_identity, _) = qualified_identity.associated_voter_identity.as_ref()?;
+            (voter_identity.id() == owner_id)
+                .then(|| voter_identity.get_public_key_by_id(key_id).cloned())
+                .flatten()
+        })
+    }
+
+    fn set_transition(&mut self, transition: StateTransition) {
+        self.checks = validate_transition(&transition, self.owner_key(&transition).as_ref());
+        self.transition = Some(transition);
+    }
+
+    fn build(&self) -> Result<StateTransition, String> {
+        transition_from_json(self.kind, &self.json_input)
+    }
+
+    fn build_clicked(&mut self) {
+        self.message = None;
+        match self.build() {
+            Ok(transition) => self.set_transition(transition),
+            Err(e) => {
+                self.transition = None;
+                self.checks.clear();
+                self.message = Some((e, MessageType::Error));
+            }
+        }
+    }
+
+    fn sign_clicked(&mut self) {
+        self.message = None;
+        let result = self.build().and_then(|mut transition| {
+            let qualified_identity = self
+                .selected_identity
+                .and_then(|index| self.identities.get(index))
+                .ok_or("Select the identity to sign with".to_string())?;
+            let public_key = self
+                .selected_key
+                .as_ref()
+                .ok_or("Select the key to sign with".to_string())?;
+            sign_transition(&mut transition, public_key, qualified_identity)?;
+            Ok(transition)
+        });
+        match result {
+            Ok(transition) => {
+                match transition_to_json(&transition) {
+                    Ok(json) => self.json_input = json,
+                    Err(e) => self.message = Some((e, MessageType::Error)),
+                }
+                self.set_transition(transition);
+            }
+            Err(e) => self.message = Some((e, MessageType::Error)),
+        }
+    }
+
+    fn load_encoded_clicked(&mut self) {
+        match decode_transition(&self.encoded_input)
+            .and_then(|transition| transition_to_json(&transition).map(|json| (transition, json)))
+        {
+            Ok((transition, json)) => {
+                self.message = None;
+                self.kind = SandboxTransitionKind::of(&transition);
+                self.json_input = json;
+                self.encoded_input.clear();
+                self.set_transition(transition);
+            }
+            Err(e) => self.message = Some((e, MessageType::Error)),
+        }
+    }
+
+    fn render_editor(&mut self, ui: &mut Ui) {
+        ui.heading("Transition");
+        ui.horizontal(|ui| {
+            ui.label("Start from a serialized transition (hex or base64):");
+            ui.text_edit_singleline(&mut self.encoded_input);
+            if ui
+                .add_enabled(
+                    !self.encoded_input.trim().is_empty(),
+                    egui::Button::new("Load"),
+                )
+                .clicked()
+            {
+                self.load_encoded_clicked();
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Kind:");
+            egui::ComboBox::from_id_salt("transition_sandbox_kind")
+                .selected_text(self.kind.label())
+                .show_ui(ui, |ui| {
+                    for kind in SandboxTransitionKind::ALL {
+                        ui.selectable_value(&mut self.kind, kind, kind.label());
+                    }
+                });
+        });
+        ui.label(
+            RichText::new(
+                "The JSON of the transition as the transition visualizer shows it, with \
+                 \"$version\" naming its version. Identifiers are base58, binary data base64.",
+            )
+            .color(Color32::GRAY),
+        );
+        ui.add(
+            egui::TextEdit::multiline(&mut self.json_input)
+                .desired_rows(14)
+                .desired_width(ui.available_width())
+                .code_editor(),
+        );
+        if ui.button("Validate").clicked() {
+            self.build_clicked();
+        }
+    }
+
+    fn render_signing(&mut self, ui: &mut Ui) {
+        ui.heading("Sign");
+        if !self.kind.signed_by_identity() {
+            ui.label(
+                "This kind is signed with the key of its asset lock, put the signature in the \
+                 JSON.",
+            );
+            return;
+        }
+        let signers: Vec<usize> = self
+            .identities
+            .iter()
+            .enumerate()
+            .filter(|(_, identity)| !identity.encrypted_private_keys.is_empty())
+            .map(|(index, _)| index)
+            .collect();
+        if signers.is_empty() {
+            ui.label("None of the loaded identities has a private key on this machine.");
+            return;
+        }
+        let selected_text = self
+            .selected_identity
+            .and_then(|index| self.identities.get(index))
+            .map(QualifiedIdentity::display_string)
+            .unwrap_or_else(|| "Select an identity".to_string());
+        ui.horizontal(|ui| {
+            ui.label("Identity:");
+            egui::ComboBox::from_id_salt("transition_sandbox_identity")
+                .selected_text(selected_text)
+                .width(300.0)
+                .show_ui(ui, |ui| {
+                    for index in signers {
+                        let qualified_identity = &self.identities[index];
+                        if ui
+                            .selectable_label(
+                                self.selected_identity == Some(index),
+                                qualified_identity.display_string(),
+                            )
+                            .clicked()
+                        {
+                            self.selected_identity = Some(index);
+                            self.selected_key =
+                                Self::signing_keys(qualified_identity).into_iter().next();
+                        }
+                    }
+                });
+        });
+        let Some(qualified_identity) = self
+            .selected_identity
+            .and_then(|index| self.identities.get(index))
+            .cloned()
+        else {
+            return;
+        };
+        ui.horizontal(|ui| {
+            ui.label("Key:");
+            let selected_text = self
+                .selected_key
+                .as_ref()
+                .map(|key| format!("Key {} ({:?})", key.id(), key.purpose()))
+                .unwrap_or_default();
+            egui::ComboBox::from_id_salt("transition_sandbox_key")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    for key in Self::signing_keys(&qualified_identity) {
+                        let label = format!(
+                            "Key {} ({:?}, {:?}, {:?})",
+                            key.id(),
+                            key.purpose(),
+                            key.security_level(),
+                            key.key_type()
+                        );
+                        let selected = self.selected_key.as_ref() == Some(&key);
+                        if ui.selectable_label(selected, label).clicked() {
+                            self.selected_key = Some(key);
+                        }
+                    }
+                });
+        });
+        if ui
+            .add_enabled(self.selected_key.is_some(), egui::Button::new("Sign"))
+            .on_hover_text("Builds the transition from the JSON and signs it with the key")
+            .clicked()
+        {
+            self.sign_clicked();
+        }
+    }
+
+    fn render_checks(&self, ui: &mut Ui) {
+        ui.heading("Local validation");
+        if self.checks.is_empty() {
+            ui.label("Validate or sign the transition to check it.");
+            return;
+        }
+        egui::Grid::new("transition_sandbox_checks")
+            .num_columns(2)
+            .spacing([15.0, 4.0])
+            .show(ui, |ui| {
+                for check in &self.checks {
+                    ui.label(check.name);
+                    match &check.result {
+                        Ok(None) => {
+                            ui.colored_label(Color32::DARK_GREEN, "✔ Passed");
+                        }
+                        Ok(Some(note)) => {
+                            ui.colored_label(Color32::DARK_GREEN, format!("✔ {}", note));
+                        }
+                        Err(e) => {
+                            ui.colored_label(Color32::RED, format!("✖ {}", e));
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        ui.label(
+            RichText::new(
+                "Only what holds without Platform's state is checked here. Nonces, balances \
+                 and the rules of contracts are checked by Platform when broadcast.",
+            )
+            .color(Color32::GRAY),
+        );
+    }
+
+    fn render_output(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        let Some(transition) = self.transition.clone() else {
+            return action;
+        };
+        ui.heading("Output");
+        match transition.serialize_to_bytes() {
+            Ok(bytes) => {
+                let encoded = hex::encode(bytes);
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(format!("{}…", &encoded[..encoded.len().min(64)]))
+                            .monospace(),
+                    );
+                    if ui.button("Copy hex").clicked() {
+                        if let Err(e) = copy_to_clipboard(&encoded) {
+                            self.message = Some((
+                                format!("Failed to copy to clipboard: {}", e),
+                                MessageType::Error,
+                            ));
+                        }
+                    }
+                });
+            }
+            Err(e) => {
+                ui.colored_label(Color32::RED, format!("Failed to serialize: {}", e));
+            }
+        }
+
+        if self.app_context.network == Network::Dash {
+            ui.label("Transitions from the sandbox can't be broadcast on mainnet.");
+            return action;
+        }
+        let all_passed = self.checks.iter().all(|check| check.result.is_ok());
+        let label = if all_passed {
+            "Broadcast"
+        } else {
+            "Broadcast anyway"
+        };
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.broadcasting, egui::Button::new(label))
+                .on_hover_text(format!(
+                    "Sends the transition to Platform on {}",
+                    self.app_context.network
+                ))
+                .clicked()
+            {
+                self.broadcasting = true;
+                self.message = None;
+                action = AppAction::BackendTask(BackendTask::IdentityTask(
+                    IdentityTask::BroadcastRawTransition(transition),
+                ));
+            }
+            if self.broadcasting {
+                ui.spinner();
+            }
+        });
+        action
+    }
+}
+
+impl ScreenLike for TransitionSandboxScreen {
+    fn refresh(&mut self) {
+        match self.app_context.load_local_qualified_identities() {
+            Ok(identities) => self.identities = identities,
+            Err(e) => {
+                self.message = Some((
+                    format!("Failed to load identities: {}", e),
+                    MessageType::Error,
+                ))
+            }
+        }
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.broadcasting = false;
+        self.message = Some((message.to_string(), message_type));
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Transition Sandbox", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                if let Some((message, message_type)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => Color32::GRAY,
+                    };
+                    ui.colored_label(color, message);
+                }
+                self.render_editor(ui);
+                ui.separator();
+                self.render_signing(ui);
+                ui.separator();
+                self.render_checks(ui);
+                ui.separator();
+                action |= self.render_output(ui);
+            });
+        });
+
+        action
+    }
+}
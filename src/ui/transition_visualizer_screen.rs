@@ -1,18 +1,23 @@
-use crate::app::AppAction;
+use crate::app::{AppAction, DesiredAppAction};
 use crate::context::AppContext;
+use crate::ui::components::explorer_link::{explorer_button, ExplorerItem};
+use crate::ui::components::json_viewer::JsonViewer;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::top_panel::add_top_panel;
-use crate::ui::{RootScreenType, ScreenLike};
+use crate::ui::{RootScreenType, ScreenLike, ScreenType};
 use base64::{engine::general_purpose::STANDARD, Engine};
+use dash_sdk::dpp::dashcore::hashes::{sha256, Hash};
 use dash_sdk::dpp::serialization::PlatformDeserializable;
 use dash_sdk::dpp::state_transition::StateTransition;
-use eframe::egui::{self, Context, ScrollArea, TextEdit, Ui};
+use eframe::egui::{self, Context, TextEdit, Ui};
 use std::sync::Arc;
 
 pub struct TransitionVisualizerScreen {
     pub app_context: Arc<AppContext>,
     input_data: String,
-    parsed_json: Option<String>,
+    parsed_transition: Option<JsonViewer>,
+    /// Hex of the SHA-256 of the transition bytes, its hash on the explorer
+    transition_hash: Option<String>,
     error_message: Option<String>,
 }
 
@@ -21,14 +26,16 @@ impl TransitionVisualizerScreen {
         Self {
             app_context: app_context.clone(),
             input_data: String::new(),
-            parsed_json: None,
+            parsed_transition: None,
+            transition_hash: None,
             error_message: None,
         }
     }
 
     fn parse_input(&mut self) {
         // Clear previous messages
-        self.parsed_json = None;
+        self.parsed_transition = None;
+        self.transition_hash = None;
         self.error_message = None;
 
         // Try to decode the input as hex first
@@ -43,13 +50,12 @@ impl TransitionVisualizerScreen {
                 // Try to deserialize into a StateTransition
                 match StateTransition::deserialize_from_bytes(&bytes) {
                     Ok(state_transition) => {
+                        self.transition_hash =
+                            Some(hex::encode(sha256::Hash::hash(&bytes).to_byte_array()));
                         // Convert state transition to JSON
-                        match serde_json::to_string_pretty(&state_transition) {
-                            Ok(json) => self.parsed_json = Some(json),
-                            Err(e) => {
-                                self.error_message =
-                                    Some(format!("Failed to serialize to JSON: {}", e))
-                            }
+                        match JsonViewer::from_serializable("state_transition", &state_transition) {
+                            Ok(viewer) => self.parsed_transition = Some(viewer),
+                            Err(e) => self.error_message = Some(e),
                         }
                     }
                     Err(e) => {
@@ -77,26 +83,28 @@ impl TransitionVisualizerScreen {
         }
     }
 
-    fn show_output(&self, ui: &mut Ui) {
+    fn show_output(&mut self, ui: &mut Ui) {
         ui.separator();
         ui.label("Parsed State Transition:");
-
-        ScrollArea::vertical().show(ui, |ui| {
-            ui.set_width(ui.available_width()); // Make the scroll area take the entire width
-
-            if let Some(ref json) = self.parsed_json {
-                ui.add(
-                    TextEdit::multiline(&mut json.clone())
-                        .desired_rows(10)
-                        .desired_width(ui.available_width()) // Make the output take the entire width
-                        .font(egui::TextStyle::Monospace), // Use a monospace font for JSON
+        if let Some(hash) = &self.transition_hash {
+            ui.horizontal(|ui| {
+                ui.label("Hash:");
+                ui.label(egui::RichText::new(hash).monospace());
+                explorer_button(
+                    ui,
+                    &self.app_context,
+                    ExplorerItem::Transition(hash.clone()),
                 );
-            } else if let Some(ref error) = self.error_message {
-                ui.colored_label(egui::Color32::RED, error);
-            } else {
-                ui.label("No valid state transition parsed yet.");
-            }
-        });
+            });
+        }
+
+        if let Some(viewer) = &mut self.parsed_transition {
+            viewer.show(ui);
+        } else if let Some(ref error) = self.error_message {
+            ui.colored_label(egui::Color32::RED, error);
+        } else {
+            ui.label("No valid state transition parsed yet.");
+        }
     }
 }
 
@@ -106,11 +114,20 @@ impl ScreenLike for TransitionVisualizerScreen {
     }
 
     fn ui(&mut self, ctx: &Context) -> AppAction {
+        // The sandbox builds and broadcasts arbitrary transitions, only for developers
+        let right_buttons = if self.app_context.developer_mode {
+            vec![(
+                "Sandbox",
+                DesiredAppAction::AddScreenType(ScreenType::TransitionSandbox),
+            )]
+        } else {
+            vec![]
+        };
         let mut action = add_top_panel(
             ctx,
             &self.app_context,
             vec![("Dash Evo Tool", AppAction::None)],
-            vec![],
+            right_buttons,
         );
 
         action |= add_left_panel(
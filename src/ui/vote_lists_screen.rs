@@ -0,0 +1,376 @@
+use crate::app::{AppAction, AppEvent};
+use crate::context::AppContext;
+use crate::model::vote_list::{SuggestedVote, VoteList, VoteListContents};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{MessageType, ScreenLike};
+use chrono::{DateTime, Utc};
+use dash_sdk::dashcore_rpc::dashcore::PrivateKey;
+use eframe::egui::{self, Context, RichText, Ui};
+use egui::Color32;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Imports recommended vote lists published by community members, shown as suggestions in the
+/// contested names table, and exports the user's own votes as such a list
+pub struct VoteListsScreen {
+    pub app_context: Arc<AppContext>,
+    lists: Vec<VoteList>,
+    file_path_input: String,
+    pasted_input: String,
+    export_title: String,
+    export_author: String,
+    export_key_input: String,
+    export_path_input: String,
+    /// Whether the notes on the voted contests go into the exported list
+    export_notes: bool,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl VoteListsScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            lists: Vec::new(),
+            file_path_input: String::new(),
+            pasted_input: String::new(),
+            export_title: String::new(),
+            export_author: String::new(),
+            export_key_input: String::new(),
+            export_path_input: "vote-list.json".to_string(),
+            export_notes: true,
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    fn import(&mut self, json: &str) {
+        let list = match VoteList::from_json(json) {
+            Ok(list) => list,
+            Err(e) => {
+                self.display_message(&e, MessageType::Error);
+                return;
+            }
+        };
+        match self.app_context.db.save_vote_list(&list, &self.app_context) {
+            Ok(()) => {
+                self.display_message(
+                    &format!(
+                        "Imported \"{}\" with {} suggestions",
+                        list.contents.title,
+                        list.contents.votes.len()
+                    ),
+                    MessageType::Success,
+                );
+                self.refresh();
+                self.app_context.publish(AppEvent::VoteListsChanged);
+            }
+            Err(e) => self.display_message(
+                &format!("Failed to save vote list: {}", e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    /// The votes the user's identities cast on ongoing contests, for names where they all agree
+    fn my_votes(&self) -> BTreeMap<String, SuggestedVote> {
+        self.app_context
+            .ongoing_contested_names()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|contested_name| {
+                let mut choices = contested_name.my_votes.values();
+                let first = choices.next()?;
+                choices.all(|choice| choice == first).then(|| {
+                    (
+                        contested_name.normalized_contested_name.clone(),
+                        SuggestedVote::from_vote_choice(first),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn export(&mut self) {
+        let private_key = match self.export_key_input.trim() {
+            "" => None,
+            input if input.len() == 64 => match hex::decode(input)
+                .ok()
+                .and_then(|bytes| PrivateKey::from_slice(&bytes, self.app_context.network).ok())
+            {
+                Some(key) => Some(key),
+                None => {
+                    self.display_message("The signing key is not valid hex", MessageType::Error);
+                    return;
+                }
+            },
+            input => match PrivateKey::from_wif(input) {
+                Ok(key) => Some(key),
+                Err(_) => {
+                    self.display_message(
+                        "The signing key is neither 64 hex characters nor WIF",
+                        MessageType::Error,
+                    );
+                    return;
+                }
+            },
+        };
+        let votes = self.my_votes();
+        if votes.is_empty() {
+            self.display_message(
+                "Your identities have not voted on any ongoing contest yet",
+                MessageType::Error,
+            );
+            return;
+        }
+        let mut annotations = self
+            .app_context
+            .db
+            .get_contest_annotations(&self.app_context)
+            .unwrap_or_default();
+        if self.export_notes {
+            annotations.retain(|annotation| votes.contains_key(&annotation.contest));
+        } else {
+            annotations.clear();
+        }
+        let contents = VoteListContents {
+            title: self.export_title.trim().to_string(),
+            author: self.export_author.trim().to_string(),
+            public_key: None,
+            votes,
+            annotations,
+        };
+        let list = match VoteList::new(contents, private_key.as_ref()) {
+            Ok(list) => list,
+            Err(e) => {
+                self.display_message(&e, MessageType::Error);
+                return;
+            }
+        };
+        let path = self.export_path_input.trim().to_string();
+        match std::fs::write(&path, list.to_json()) {
+            Ok(()) => self.display_message(
+                &format!(
+                    "Exported {} votes{} to {}",
+                    list.contents.votes.len(),
+                    if list.is_signed() { ", signed," } else { "" },
+                    path
+                ),
+                MessageType::Success,
+            ),
+            Err(e) => self.display_message(
+                &format!("Failed to write {}: {}", path, e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    fn render_import(&mut self, ui: &mut Ui) {
+        ui.heading("Import");
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            ui.text_edit_singleline(&mut self.file_path_input);
+            if ui.button("Import File").clicked() {
+                match std::fs::read_to_string(self.file_path_input.trim()) {
+                    Ok(contents) => self.import(&contents),
+                    Err(e) => self.display_message(
+                        &format!("Failed to read file: {}", e),
+                        MessageType::Error,
+                    ),
+                }
+            }
+        });
+        ui.label("Or paste the list:");
+        ui.add(
+            egui::TextEdit::multiline(&mut self.pasted_input)
+                .desired_rows(4)
+                .desired_width(f32::INFINITY)
+                .code_editor(),
+        );
+        if ui.button("Import Pasted List").clicked() {
+            let contents = self.pasted_input.clone();
+            self.import(&contents);
+        }
+    }
+
+    /// Renders one list, returns whether it should be removed
+    fn render_list(ui: &mut Ui, list: &VoteList) -> bool {
+        let mut remove = false;
+        ui.horizontal(|ui| {
+            match &list.contents.public_key {
+                Some(public_key) if list.is_signed() => {
+                    ui.colored_label(Color32::DARK_GREEN, "✔ Signed")
+                        .on_hover_text(format!("Public key {}", public_key));
+                    ui.label(
+                        RichText::new(format!("key {}…", &public_key[..public_key.len().min(8)]))
+                            .monospace(),
+                    );
+                }
+                _ => {
+                    ui.colored_label(Color32::GRAY, "Unsigned");
+                }
+            }
+            if ui.button("Remove").clicked() {
+                remove = true;
+            }
+        });
+        egui::Grid::new(("vote_list_grid", &list.contents.title))
+            .striped(true)
+            .show(ui, |ui| {
+                for (name, vote) in &list.contents.votes {
+                    ui.label(name);
+                    ui.label(vote.to_string());
+                    let notes: Vec<String> = list
+                        .contents
+                        .annotations
+                        .iter()
+                        .filter(|annotation| &annotation.contest == name)
+                        .map(|annotation| annotation.summary())
+                        .collect();
+                    ui.label(RichText::new(notes.join("; ")).color(Color32::GRAY));
+                    ui.end_row();
+                }
+            });
+        remove
+    }
+
+    fn render_export(&mut self, ui: &mut Ui) {
+        ui.heading("Export My Votes");
+        ui.label(
+            "Publishes the votes your identities cast on ongoing contests. Sign the list so \
+             others can check it was not altered.",
+        );
+        egui::Grid::new("vote_list_export_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Title:");
+                ui.text_edit_singleline(&mut self.export_title);
+                ui.end_row();
+                ui.label("Author:");
+                ui.text_edit_singleline(&mut self.export_author);
+                ui.end_row();
+                ui.label("Signing key:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.export_key_input)
+                        .password(true)
+                        .hint_text("WIF or hex, optional"),
+                );
+                ui.end_row();
+                ui.label("File:");
+                ui.text_edit_singleline(&mut self.export_path_input);
+                ui.end_row();
+                ui.label("");
+                ui.checkbox(&mut self.export_notes, "Include my notes on these contests");
+                ui.end_row();
+            });
+        let can_export =
+            !self.export_title.trim().is_empty() && !self.export_path_input.trim().is_empty();
+        if ui
+            .add_enabled(can_export, egui::Button::new("Export"))
+            .clicked()
+        {
+            self.export();
+        }
+    }
+}
+
+impl ScreenLike for VoteListsScreen {
+    fn refresh(&mut self) {
+        self.lists = self
+            .app_context
+            .db
+            .get_vote_lists(&self.app_context)
+            .unwrap_or_default();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+        let action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Vote Lists", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading("Community Vote Lists");
+                ui.label(
+                    "Suggestions from imported lists are shown in the contested names table. \
+                     Votes are never cast from a list, you still choose every vote yourself.",
+                );
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => ui.visuals().text_color(),
+                    };
+                    ui.colored_label(color, message);
+                }
+                ui.add_space(10.0);
+                self.render_import(ui);
+                ui.add_space(10.0);
+                ui.separator();
+
+                ui.heading("Imported Lists");
+                if self.lists.is_empty() {
+                    ui.label("No lists imported yet.");
+                }
+                let mut to_remove = None;
+                for list in &self.lists {
+                    let title = format!(
+                        "{} by {} ({} names)",
+                        list.contents.title,
+                        list.contents.author,
+                        list.contents.votes.len()
+                    );
+                    egui::CollapsingHeader::new(title)
+                        .id_salt(("vote_list", &list.contents.title))
+                        .show(ui, |ui| {
+                            if Self::render_list(ui, list) {
+                                to_remove = Some(list.contents.title.clone());
+                            }
+                        });
+                }
+                if let Some(title) = to_remove {
+                    match self
+                        .app_context
+                        .db
+                        .delete_vote_list(&title, &self.app_context)
+                    {
+                        Ok(()) => {
+                            self.lists.retain(|list| list.contents.title != title);
+                            self.app_context.publish(AppEvent::VoteListsChanged);
+                        }
+                        Err(e) => self.display_message(
+                            &format!("Failed to remove vote list: {}", e),
+                            MessageType::Error,
+                        ),
+                    }
+                }
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_export(ui);
+            });
+        });
+
+        action
+    }
+}
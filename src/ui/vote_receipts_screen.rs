@@ -0,0 +1,415 @@
+use crate::app::{AppAction, AppEvent};
+use crate::context::AppContext;
+use crate::model::operator_report::owner_reports;
+use crate::model::vote_proof::VoteProofReceipt;
+use crate::platform::contested_names::ContestedResourceTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::redaction::redact_id;
+use crate::ui::helpers::time_format::TimeFormatter;
+use crate::ui::{MessageType, ScreenLike};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use eframe::egui::{self, Color32, Context, RichText, Ui};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+/// The proofs of our masternodes' votes, fetched after each vote, exported as receipt files
+/// that anyone can verify, and the verification of receipts received from others
+pub struct VoteReceiptsScreen {
+    pub app_context: Arc<AppContext>,
+    receipts: Vec<VoteProofReceipt>,
+    export_dir_input: String,
+    verify_path_input: String,
+    report_from_input: String,
+    report_to_input: String,
+    report_dir_input: String,
+    /// The receipt whose proof is being checked
+    verifying: Option<VoteProofReceipt>,
+    /// The last receipt checked, and whether its proof holds
+    verification: Option<(VoteProofReceipt, Result<(), String>)>,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl VoteReceiptsScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let today = Utc::now().date_naive();
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            receipts: Vec::new(),
+            export_dir_input: ".".to_string(),
+            verify_path_input: String::new(),
+            report_from_input: today.with_day(1).unwrap_or(today).to_string(),
+            report_to_input: today.to_string(),
+            report_dir_input: "owner-reports".to_string(),
+            verifying: None,
+            verification: None,
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    fn export(&mut self, receipt: &VoteProofReceipt) {
+        let path = Path::new(self.export_dir_input.trim()).join(receipt.file_name());
+        match std::fs::write(&path, receipt.to_json()) {
+            Ok(()) => self.display_message(
+                &format!("Exported the receipt to {}", path.display()),
+                MessageType::Success,
+            ),
+            Err(e) => self.display_message(
+                &format!("Failed to write {}: {}", path.display(), e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    fn verify(&mut self, receipt: VoteProofReceipt) -> AppAction {
+        self.verifying = Some(receipt.clone());
+        self.verification = None;
+        AppAction::BackendTask(BackendTask::ContestedResourceTask(
+            ContestedResourceTask::VerifyVoteReceipt(receipt),
+        ))
+    }
+
+    fn render_receipts(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("My Vote Receipts");
+        if self.receipts.is_empty() {
+            ui.label("No receipts yet. One is stored for every vote cast from now on.");
+            return action;
+        }
+        ui.horizontal(|ui| {
+            ui.label("Export to folder:");
+            ui.add(egui::TextEdit::singleline(&mut self.export_dir_input).desired_width(300.0));
+        });
+
+        let preferences = self.app_context.preferences();
+        let time_format = TimeFormatter::new(&preferences);
+        let mut to_export = None;
+        let mut to_verify = None;
+        egui::Grid::new("vote_receipts_grid")
+            .striped(true)
+            .num_columns(5)
+            .spacing([15.0, 4.0])
+            .show(ui, |ui| {
+                ui.label(RichText::new("Contest").strong());
+                ui.label(RichText::new("Choice").strong());
+                ui.label(RichText::new("Masternode").strong());
+                ui.label(RichText::new("Proven at").strong());
+                ui.label("");
+                ui.end_row();
+                for receipt in &self.receipts {
+                    ui.label(&receipt.contest);
+                    ui.label(redact_choice(&receipt.choice, preferences.privacy_mode));
+                    ui.label(voter_label(receipt, preferences.privacy_mode))
+                        .on_hover_text(format!(
+                            "Identity {}",
+                            redact_id(&receipt.voter_id, preferences.privacy_mode)
+                        ));
+                    ui.label(format!(
+                        "height {}, {}",
+                        receipt.metadata.height,
+                        time_format.format_millis(receipt.metadata.time_ms)
+                    ));
+                    ui.horizontal(|ui| {
+                        if ui.button("Export").clicked() {
+                            to_export = Some(receipt.clone());
+                        }
+                        if ui
+                            .add_enabled(self.verifying.is_none(), egui::Button::new("Verify"))
+                            .clicked()
+                        {
+                            to_verify = Some(receipt.clone());
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+        if let Some(receipt) = to_export {
+            self.export(&receipt);
+        }
+        if let Some(receipt) = to_verify {
+            action = self.verify(receipt);
+        }
+        action
+    }
+
+    /// Writes a folder per owner with the CSV of the votes cast in the period and their
+    /// receipts
+    fn generate_reports(&self) -> Result<String, String> {
+        let date = |input: &str| {
+            NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d")
+                .map_err(|_| format!("{} is not a date like 2024-10-31", input.trim()))
+        };
+        let from = date(&self.report_from_input)?;
+        let to = date(&self.report_to_input)?;
+        if to < from {
+            return Err("The period ends before it starts".to_string());
+        }
+        let millis = |date: NaiveDate| {
+            date.and_hms_opt(0, 0, 0)
+                .map(|time| time.and_utc().timestamp_millis().max(0) as u64)
+                .unwrap_or_default()
+        };
+        // The end date is included
+        let votes = self
+            .app_context
+            .db
+            .get_reported_votes(
+                millis(from),
+                millis(to + Duration::days(1)),
+                &self.app_context,
+            )
+            .map_err(|e| format!("Failed to load the votes: {}", e))?;
+        if votes.is_empty() {
+            return Err(format!("No votes were cast from {} to {}", from, to));
+        }
+        let blocs = self
+            .app_context
+            .db
+            .get_voting_blocs(&self.app_context)
+            .map_err(|e| format!("Failed to load the voting blocs: {}", e))?;
+
+        let names: HashMap<Identifier, String> = self
+            .app_context
+            .load_local_qualified_identities()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|identity| (identity.identity.id(), identity.display_string()))
+            .collect();
+        let voter_name = |id: &Identifier| {
+            names
+                .get(id)
+                .cloned()
+                .unwrap_or_else(|| id.to_string(Encoding::Base58))
+        };
+
+        let directory = Path::new(self.report_dir_input.trim());
+        let reports = owner_reports(&votes, &blocs);
+        for report in &reports {
+            report.write(directory, voter_name)?;
+        }
+        let without_receipt = votes.iter().filter(|vote| vote.receipt.is_none()).count();
+        let mut message = format!(
+            "Wrote reports of {} votes for {} owners to {}",
+            votes.len(),
+            reports.len(),
+            directory.display()
+        );
+        if without_receipt > 0 {
+            message.push_str(&format!(
+                ", {} of the votes have no receipt",
+                without_receipt
+            ));
+        }
+        Ok(message)
+    }
+
+    fn render_owner_reports(&mut self, ui: &mut Ui) {
+        ui.heading("Reports for Owners");
+        ui.label(
+            "For hosting providers voting on behalf of masternode owners: one folder per voting \
+             bloc with a CSV of the votes its masternodes cast in the period and their receipts. \
+             Masternodes in no bloc are reported as Unassigned.",
+        );
+        egui::Grid::new("owner_reports_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("From:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.report_from_input)
+                        .hint_text("YYYY-MM-DD")
+                        .desired_width(120.0),
+                );
+                ui.end_row();
+                ui.label("To:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.report_to_input)
+                        .hint_text("YYYY-MM-DD")
+                        .desired_width(120.0),
+                );
+                ui.end_row();
+                ui.label("Folder:");
+                ui.add(egui::TextEdit::singleline(&mut self.report_dir_input).desired_width(300.0));
+                ui.end_row();
+            });
+        if ui.button("Generate Reports").clicked() {
+            match self.generate_reports() {
+                Ok(message) => self.display_message(&message, MessageType::Success),
+                Err(e) => self.display_message(&e, MessageType::Error),
+            }
+        }
+    }
+
+    fn render_verify_file(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.heading("Verify a Receipt");
+        ui.label(
+            "Checks a receipt file from a node operator against the quorum that signed its \
+             proof, so their vote can be confirmed without trusting them.",
+        );
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            ui.add(egui::TextEdit::singleline(&mut self.verify_path_input).desired_width(300.0));
+            if ui
+                .add_enabled(self.verifying.is_none(), egui::Button::new("Verify File"))
+                .clicked()
+            {
+                let receipt = std::fs::read_to_string(self.verify_path_input.trim())
+                    .map_err(|e| format!("Failed to read file: {}", e))
+                    .and_then(|json| VoteProofReceipt::from_json(&json));
+                match receipt {
+                    Ok(receipt) => action = self.verify(receipt),
+                    Err(e) => self.display_message(&e, MessageType::Error),
+                }
+            }
+            if self.verifying.is_some() {
+                ui.spinner();
+            }
+        });
+
+        if let Some((receipt, result)) = &self.verification {
+            let privacy_mode = self.app_context.preferences().privacy_mode;
+            ui.add_space(5.0);
+            egui::Grid::new("vote_receipt_claim_grid")
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("Contest:");
+                    ui.label(&receipt.contest);
+                    ui.end_row();
+                    ui.label("Choice:");
+                    ui.label(redact_choice(&receipt.choice, privacy_mode));
+                    ui.end_row();
+                    ui.label("Masternode:");
+                    ui.label(RichText::new(redact_id(&receipt.voter_id, privacy_mode)).monospace());
+                    ui.end_row();
+                    ui.label("Platform height:");
+                    ui.label(receipt.metadata.height.to_string());
+                    ui.end_row();
+                    ui.label("Quorum:");
+                    ui.label(RichText::new(&receipt.proof.quorum_hash).monospace());
+                    ui.end_row();
+                });
+            match result {
+                Ok(()) => {
+                    ui.colored_label(
+                        Color32::DARK_GREEN,
+                        "Valid: Platform recorded this vote of the masternode",
+                    );
+                }
+                Err(e) => {
+                    ui.colored_label(Color32::RED, format!("Not valid: {}", e));
+                }
+            }
+        }
+        action
+    }
+}
+
+impl ScreenLike for VoteReceiptsScreen {
+    fn refresh(&mut self) {
+        self.receipts = self
+            .app_context
+            .db
+            .get_vote_proofs(&self.app_context)
+            .unwrap_or_default();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            if let Some(receipt) = self.verifying.take() {
+                self.verification = Some((receipt, Err(message.to_string())));
+                return;
+            }
+        }
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::VerifiedVoteReceipt(receipt) = backend_task_success_result
+        {
+            self.verifying = None;
+            self.verification = Some((receipt, Ok(())));
+        }
+    }
+
+    fn on_event(&mut self, event: AppEvent) {
+        if event == AppEvent::VoteSubmitted {
+            self.refresh();
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Vote Receipts", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading("Vote Receipts");
+                ui.label(
+                    "After each vote the tool fetches the Platform proof that the vote was \
+                     recorded. A receipt holds that proof, so whoever delegated their vote to \
+                     you can check how it was cast.",
+                );
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => ui.visuals().text_color(),
+                    };
+                    ui.colored_label(color, message);
+                }
+                ui.add_space(10.0);
+                action |= self.render_receipts(ui);
+                ui.add_space(10.0);
+                ui.separator();
+                self.render_owner_reports(ui);
+                ui.add_space(10.0);
+                ui.separator();
+                action |= self.render_verify_file(ui);
+            });
+        });
+
+        action
+    }
+}
+
+/// The masternode's alias, or its id redacted in privacy mode when the vote was cast without one
+fn voter_label(receipt: &VoteProofReceipt, privacy_mode: bool) -> String {
+    if receipt.voter_name == receipt.voter_id {
+        redact_id(&receipt.voter_id, privacy_mode)
+    } else {
+        receipt.voter_name.clone()
+    }
+}
+
+/// The choice with the id of the identity voted for redacted in privacy mode
+fn redact_choice(choice: &str, privacy_mode: bool) -> String {
+    match choice.strip_prefix("for ") {
+        Some(identity_id) => format!("for {}", redact_id(identity_id, privacy_mode)),
+        None => choice.to_string(),
+    }
+}
@@ -0,0 +1,239 @@
+use crate::app::{AppAction, AppEvent};
+use crate::context::AppContext;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::voting_bloc::{BlocDefaultVote, VotingBloc};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{MessageType, ScreenLike};
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use eframe::egui::{self, Context, Ui};
+use egui::Color32;
+use std::sync::Arc;
+
+/// Named sets of voting identities that can vote together from the vote popup
+pub struct VotingBlocsScreen {
+    pub app_context: Arc<AppContext>,
+    blocs: Vec<VotingBloc>,
+    voting_identities: Vec<QualifiedIdentity>,
+    new_bloc_name: String,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl VotingBlocsScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        let mut screen = Self {
+            app_context: app_context.clone(),
+            blocs: Vec::new(),
+            voting_identities: Vec::new(),
+            new_bloc_name: String::new(),
+            message: None,
+        };
+        screen.refresh();
+        screen
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    fn save(&mut self, bloc: &VotingBloc) {
+        match self
+            .app_context
+            .db
+            .save_voting_bloc(bloc, &self.app_context)
+        {
+            Ok(()) => self.app_context.publish(AppEvent::VotingBlocsChanged),
+            Err(e) => self.display_message(
+                &format!("Failed to save voting bloc: {}", e),
+                MessageType::Error,
+            ),
+        }
+    }
+
+    fn create_bloc(&mut self) {
+        let name = self.new_bloc_name.trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        if self.blocs.iter().any(|bloc| bloc.name == name) {
+            self.display_message(
+                &format!("A voting bloc named \"{}\" already exists", name),
+                MessageType::Error,
+            );
+            return;
+        }
+        let bloc = VotingBloc {
+            name,
+            members: Vec::new(),
+            default_vote: None,
+        };
+        self.save(&bloc);
+        self.blocs.push(bloc);
+        self.new_bloc_name.clear();
+    }
+
+    /// Renders one bloc, returns whether it changed and whether it should be deleted
+    fn render_bloc(&self, ui: &mut Ui, bloc: &mut VotingBloc) -> (bool, bool) {
+        let mut changed = false;
+        let mut delete = false;
+        ui.horizontal(|ui| {
+            ui.label("Default vote:");
+            let selected = bloc
+                .default_vote
+                .map(|vote| vote.to_string())
+                .unwrap_or_else(|| "None".to_string());
+            egui::ComboBox::from_id_salt(("bloc_default_vote", &bloc.name))
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    changed |= ui
+                        .selectable_value(&mut bloc.default_vote, None, "None")
+                        .changed();
+                    for vote in BlocDefaultVote::ALL {
+                        changed |= ui
+                            .selectable_value(&mut bloc.default_vote, Some(vote), vote.to_string())
+                            .changed();
+                    }
+                })
+                .response
+                .on_hover_text("Offered as a one click vote in the contest menu");
+            if ui.button("Delete Bloc").clicked() {
+                delete = true;
+            }
+        });
+
+        if self.voting_identities.is_empty() {
+            ui.label("Load masternodes or evonodes to add them to the bloc.");
+        }
+        for identity in &self.voting_identities {
+            let identity_id = identity.identity.id();
+            let mut member = bloc.members.contains(&identity_id);
+            let label = format!("{} ({})", identity.display_string(), identity.identity_type);
+            if ui.checkbox(&mut member, label).changed() {
+                if member {
+                    bloc.members.push(identity_id);
+                } else {
+                    bloc.members.retain(|id| id != &identity_id);
+                }
+                changed = true;
+            }
+        }
+        (changed, delete)
+    }
+}
+
+impl ScreenLike for VotingBlocsScreen {
+    fn refresh(&mut self) {
+        self.blocs = self
+            .app_context
+            .db
+            .get_voting_blocs(&self.app_context)
+            .unwrap_or_default();
+        self.voting_identities = self
+            .app_context
+            .db
+            .get_local_voting_identities(&self.app_context)
+            .unwrap_or_default();
+    }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn on_event(&mut self, event: AppEvent) {
+        if matches!(event, AppEvent::IdentityAdded | AppEvent::IdentitiesUpdated) {
+            self.voting_identities = self
+                .app_context
+                .db
+                .get_local_voting_identities(&self.app_context)
+                .unwrap_or_default();
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+        let action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Back", AppAction::PopScreen),
+                ("Voting Blocs", AppAction::None),
+            ],
+            vec![],
+        );
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading("Voting Blocs");
+                ui.label(
+                    "Group voting identities, for example by datacenter, to vote with all of \
+                     them at once from the vote popup.",
+                );
+                if let Some((message, message_type, _)) = &self.message {
+                    let color = match message_type {
+                        MessageType::Error => Color32::RED,
+                        MessageType::Success => Color32::DARK_GREEN,
+                        MessageType::Info => ui.visuals().text_color(),
+                    };
+                    ui.colored_label(color, message);
+                }
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    ui.label("New bloc:");
+                    let response = ui.text_edit_singleline(&mut self.new_bloc_name);
+                    let submitted =
+                        response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if ui.button("Create").clicked() || submitted {
+                        self.create_bloc();
+                    }
+                });
+                ui.add_space(10.0);
+
+                let mut blocs = std::mem::take(&mut self.blocs);
+                let mut to_save = Vec::new();
+                let mut to_delete = None;
+                for bloc in blocs.iter_mut() {
+                    let title = format!("{} ({} members)", bloc.name, bloc.members.len());
+                    egui::CollapsingHeader::new(title)
+                        .id_salt(("voting_bloc", &bloc.name))
+                        .show(ui, |ui| {
+                            let (changed, delete) = self.render_bloc(ui, bloc);
+                            if changed {
+                                to_save.push(bloc.clone());
+                            }
+                            if delete {
+                                to_delete = Some(bloc.name.clone());
+                            }
+                        });
+                }
+                self.blocs = blocs;
+
+                for bloc in to_save {
+                    self.save(&bloc);
+                }
+                if let Some(name) = to_delete {
+                    match self
+                        .app_context
+                        .db
+                        .delete_voting_bloc(&name, &self.app_context)
+                    {
+                        Ok(()) => {
+                            self.blocs.retain(|bloc| bloc.name != name);
+                            self.app_context.publish(AppEvent::VotingBlocsChanged);
+                        }
+                        Err(e) => self.display_message(
+                            &format!("Failed to delete voting bloc: {}", e),
+                            MessageType::Error,
+                        ),
+                    }
+                }
+            });
+        });
+
+        action
+    }
+}
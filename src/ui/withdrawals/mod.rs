@@ -1,9 +1,17 @@
 use crate::app::AppAction;
 use crate::context::AppContext;
+use crate::model::cold_signing::ColdSigningFile;
 use crate::model::qualified_identity::{IdentityType, QualifiedIdentity};
+use crate::model::recipient_check::LookalikeRecipient;
 use crate::platform::identity::IdentityTask;
-use crate::platform::BackendTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::cold_signing_screen::ColdSigningScreen;
+use crate::ui::components::approval_window::daily_allowance;
+use crate::ui::components::fee_selector::{FeePurpose, FeeSelector};
+use crate::ui::components::fiat_value::fiat_label;
+use crate::ui::components::recipient_warning::{find_lookalike_recipient, show_lookalike_warning};
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::number_format::{NumberFormatter, CREDITS_PER_DASH};
 use crate::ui::key_info_screen::KeyInfoScreen;
 use crate::ui::{MessageType, Screen, ScreenLike};
 use dash_sdk::dashcore_rpc::dashcore::Address;
@@ -16,6 +24,8 @@ use eframe::egui::{self, Context, Ui};
 use std::str::FromStr;
 use std::sync::Arc;
 
+pub mod queue_screen;
+
 pub struct WithdrawalScreen {
     pub identity: QualifiedIdentity,
     selected_key: Option<IdentityPublicKey>,
@@ -25,11 +35,23 @@ pub struct WithdrawalScreen {
     max_amount: u64,
     pub app_context: Arc<AppContext>,
     confirmation_popup: bool,
+    /// Daily limit of the identity and what it spent of it, when it has a limit
+    daily_allowance: Option<(Credits, Credits)>,
+    /// Known recipient the entered address resembles, checked when confirming
+    lookalike_recipient: Option<LookalikeRecipient>,
+    lookalike_acknowledged: bool,
+    /// Build the withdrawal unsigned, for keys that are on an offline machine
+    cold_signing: bool,
+    /// An unsigned withdrawal just built, opened on the cold signing screen
+    prepared_cold_file: Option<ColdSigningFile>,
+    /// Fee speed of the asset unlock paying out the withdrawal
+    fee: FeeSelector,
 }
 
 impl WithdrawalScreen {
     pub fn new(identity: QualifiedIdentity, app_context: &Arc<AppContext>) -> Self {
         let max_amount = identity.identity.balance();
+        let daily_allowance = daily_allowance(app_context, &identity.identity.id());
         Self {
             identity,
             selected_key: None,
@@ -39,9 +61,30 @@ impl WithdrawalScreen {
             max_amount,
             app_context: app_context.clone(),
             confirmation_popup: false,
+            daily_allowance,
+            lookalike_recipient: None,
+            lookalike_acknowledged: false,
+            cold_signing: false,
+            prepared_cold_file: None,
+            fee: FeeSelector::new(app_context, FeePurpose::Withdrawal),
         }
     }
 
+    /// The typed amount and address, saved with the session
+    pub fn form_input(&self) -> (String, String) {
+        (
+            self.withdrawal_amount.clone(),
+            self.withdrawal_address.clone(),
+        )
+    }
+
+    /// Fills the form with an amount and address saved with the session
+    pub fn with_form_input(mut self, amount: String, address: String) -> Self {
+        self.withdrawal_amount = amount;
+        self.withdrawal_address = address;
+        self
+    }
+
     fn render_key_selection(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
             ui.label("Select Key:");
@@ -52,7 +95,13 @@ impl WithdrawalScreen {
                     None => "Select a key".to_string(),
                 })
                 .show_ui(ui, |ui| {
-                    if self.app_context.developer_mode {
+                    if self.cold_signing {
+                        for key in self.identity.withdrawal_public_keys() {
+                            let label =
+                                format!("Key ID: {} (Purpose: {:?})", key.id(), key.purpose());
+                            ui.selectable_value(&mut self.selected_key, Some(key.clone()), label);
+                        }
+                    } else if self.app_context.developer_mode {
                         for key in self.identity.identity.public_keys().values() {
                             let label =
                                 format!("Key ID: {} (Purpose: {:?})", key.id(), key.purpose());
@@ -78,7 +127,18 @@ impl WithdrawalScreen {
             if ui.button("Max").clicked() {
                 self.withdrawal_amount = self.max_amount.to_string();
             }
+            if let Ok(dash) = self.withdrawal_amount.trim().parse::<f64>() {
+                fiat_label(ui, &self.app_context, (dash * CREDITS_PER_DASH) as Credits);
+            }
         });
+        if let Some((limit, spent)) = self.daily_allowance {
+            let number_format = NumberFormatter::new(&self.app_context.preferences());
+            ui.label(format!(
+                "Daily limit: {}, {} left",
+                number_format.format_credits_as_dash(limit),
+                number_format.format_credits_as_dash(limit.saturating_sub(spent))
+            ));
+        }
     }
 
     fn render_address_input(&mut self, ui: &mut Ui) {
@@ -157,16 +217,39 @@ impl WithdrawalScreen {
                     credits += fraction_number * multiplier; // Fractional Dash to credits
                 }
 
-                if ui.button("Confirm").clicked() {
+                let confirm_allowed = match &self.lookalike_recipient {
+                    Some(lookalike) => {
+                        show_lookalike_warning(ui, lookalike, &mut self.lookalike_acknowledged)
+                    }
+                    None => true,
+                };
+                let confirm_label = if self.cold_signing {
+                    "Build Unsigned"
+                } else {
+                    "Confirm"
+                };
+                if ui
+                    .add_enabled(confirm_allowed, egui::Button::new(confirm_label))
+                    .clicked()
+                {
                     self.confirmation_popup = false;
-                    app_action = AppAction::BackendTask(BackendTask::IdentityTask(
+                    let task = if self.cold_signing {
+                        IdentityTask::PrepareColdWithdrawal(
+                            self.identity.clone(),
+                            address,
+                            credits as Credits,
+                            selected_key.id(),
+                        )
+                    } else {
                         IdentityTask::WithdrawFromIdentity(
                             self.identity.clone(),
                             address,
                             credits as Credits,
                             Some(selected_key.id()),
-                        ),
-                    ));
+                            self.fee.choice.speed,
+                        )
+                    };
+                    app_action = AppAction::BackendTask(BackendTask::IdentityTask(task));
                 }
                 if ui.button("Cancel").clicked() {
                     self.confirmation_popup = false;
@@ -184,6 +267,15 @@ impl ScreenLike for WithdrawalScreen {
         self.error_message = Some(message.to_string());
     }
 
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if self.fee.take_estimates(&backend_task_success_result) {
+            return;
+        }
+        if let BackendTaskSuccessResult::ColdSigningFile(file) = backend_task_success_result {
+            self.prepared_cold_file = Some(file);
+        }
+    }
+
     /// Renders the UI components for the withdrawal screen
     fn ui(&mut self, ctx: &Context) -> AppAction {
         let mut action = add_top_panel(
@@ -196,8 +288,26 @@ impl ScreenLike for WithdrawalScreen {
             vec![],
         );
 
+        if let Some(file) = self.prepared_cold_file.take() {
+            action |= AppAction::AddScreen(Screen::ColdSigningScreen(
+                ColdSigningScreen::new(&self.app_context).with_file(file),
+            ));
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
-            let has_keys = if self.app_context.developer_mode {
+            if ui
+                .checkbox(&mut self.cold_signing, "Sign on an offline machine")
+                .on_hover_text(
+                    "Build the withdrawal unsigned and export it, for keys that aren't on this \
+                     machine",
+                )
+                .changed()
+            {
+                self.selected_key = None;
+            }
+            let has_keys = if self.cold_signing {
+                !self.identity.withdrawal_public_keys().is_empty()
+            } else if self.app_context.developer_mode {
                 !self.identity.identity.public_keys().is_empty()
             } else {
                 !self.identity.available_withdrawal_keys().is_empty()
@@ -242,9 +352,15 @@ impl ScreenLike for WithdrawalScreen {
                 self.render_key_selection(ui);
                 self.render_amount_input(ui);
                 self.render_address_input(ui);
+                if !self.cold_signing {
+                    action |= self.fee.show(ui);
+                }
 
                 if ui.button("Withdraw").clicked() {
                     self.confirmation_popup = true;
+                    self.lookalike_recipient =
+                        find_lookalike_recipient(&self.app_context, &self.withdrawal_address);
+                    self.lookalike_acknowledged = false;
                 }
 
                 if self.confirmation_popup {
@@ -0,0 +1,266 @@
+use crate::app::{AppAction, AppCommand, DesiredAppAction};
+use crate::context::AppContext;
+use crate::model::withdrawal::{WithdrawalRecord, WithdrawalStatus};
+use crate::platform::withdrawals::WithdrawalsTask;
+use crate::platform::{BackendTask, BackendTaskSuccessResult};
+use crate::ui::components::explorer_link::{explorer_button, ExplorerItem};
+use crate::ui::components::proof_status::{add_proof_status, DOCUMENT_METHODS};
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::helpers::display_scale::{apply_table_density, table_heights};
+use crate::ui::helpers::number_format::NumberFormatter;
+use crate::ui::helpers::redaction::redact_id;
+use crate::ui::helpers::time_format::TimeFormatter;
+use crate::ui::{MessageType, ScreenLike};
+use chrono::{DateTime, Utc};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use eframe::egui::{self, Context, RichText, Ui};
+use egui::Color32;
+use egui_extras::{Column, TableBuilder};
+use std::sync::Arc;
+
+/// Lists pending and past Platform withdrawals of our identities, and optionally the whole
+/// network's queue
+pub struct WithdrawalQueueScreen {
+    pub app_context: Arc<AppContext>,
+    withdrawals: Vec<WithdrawalRecord>,
+    include_network: bool,
+    show_finished: bool,
+    fetch_requested: bool,
+    fetching: bool,
+    message: Option<(String, MessageType, DateTime<Utc>)>,
+}
+
+impl WithdrawalQueueScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        Self {
+            app_context: app_context.clone(),
+            withdrawals: Vec::new(),
+            include_network: false,
+            show_finished: true,
+            fetch_requested: false,
+            fetching: false,
+            message: None,
+        }
+    }
+
+    fn fetch_action(&self) -> BackendTask {
+        BackendTask::WithdrawalsTask(WithdrawalsTask::QueryWithdrawals {
+            include_network: self.include_network,
+        })
+    }
+
+    fn check_message_expiration(&mut self) {
+        if let Some((_, _, timestamp)) = &self.message {
+            // Automatically dismiss the message after 5 seconds
+            if Utc::now().signed_duration_since(*timestamp).num_seconds() > 5 {
+                self.message = None;
+            }
+        }
+    }
+
+    fn render_summary(&self, ui: &mut Ui, number_format: &NumberFormatter) {
+        let finality = self.app_context.preferences().finality.withdrawals;
+        let mine_pending: Vec<&WithdrawalRecord> = self
+            .withdrawals
+            .iter()
+            .filter(|record| {
+                record.is_mine && record.status.is_pending() && !record.is_final(&finality)
+            })
+            .collect();
+        let pending_amount: u64 = mine_pending.iter().map(|record| record.amount).sum();
+        ui.label(format!(
+            "My pending withdrawals: {} ({})",
+            mine_pending.len(),
+            number_format.format_credits_as_dash(pending_amount)
+        ));
+        if self.include_network {
+            let queued = self
+                .withdrawals
+                .iter()
+                .filter(|record| record.status == WithdrawalStatus::Queued)
+                .count();
+            ui.label(format!("Withdrawals queued on the network: {}", queued));
+        }
+    }
+
+    fn render_table(&self, ui: &mut Ui) {
+        let preferences = self.app_context.preferences();
+        let number_format = NumberFormatter::new(&preferences);
+        let time_format = TimeFormatter::for_column(&preferences, "withdrawal_queue.updated");
+        let finality = preferences.finality.withdrawals;
+
+        let rows: Vec<&WithdrawalRecord> = self
+            .withdrawals
+            .iter()
+            .filter(|record| self.show_finished || record.status.is_pending())
+            .collect();
+        if rows.is_empty() {
+            ui.label(if self.fetching {
+                "Fetching withdrawals"
+            } else {
+                "No withdrawals found."
+            });
+            return;
+        }
+
+        let preferences = self.app_context.preferences();
+        let density = preferences.table_density;
+        // Scoped so the compact style stays with the table
+        ui.scope(|ui| {
+            let (header_height, row_height) = table_heights(ui.ctx(), density, 25.0);
+            apply_table_density(ui, density);
+            TableBuilder::new(ui)
+                .striped(true)
+                .resizable(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(Column::auto()) // Owner
+                .column(Column::auto()) // Amount
+                .column(Column::auto()) // Status
+                .column(Column::auto()) // Address
+                .column(Column::auto()) // Updated
+                .column(Column::remainder()) // Expected completion
+                .header(header_height, |mut header| {
+                    for title in [
+                        "Owner",
+                        "Amount",
+                        "Status",
+                        "Address",
+                        "Updated",
+                        "Expected Completion",
+                    ] {
+                        header.col(|ui| {
+                            ui.heading(title);
+                        });
+                    }
+                })
+                .body(|mut body| {
+                    for record in rows {
+                        body.row(row_height, |mut row| {
+                            row.col(|ui| {
+                                let owner = redact_id(
+                                    &record.owner_id.to_string(Encoding::Base58),
+                                    preferences.privacy_mode,
+                                );
+                                let text = if record.is_mine {
+                                    RichText::new(owner).strong()
+                                } else {
+                                    RichText::new(owner)
+                                };
+                                ui.label(text);
+                                explorer_button(
+                                    ui,
+                                    &self.app_context,
+                                    ExplorerItem::Identity(record.owner_id),
+                                );
+                            });
+                            row.col(|ui| {
+                                ui.label(number_format.format_credits_as_dash(record.amount))
+                                    .on_hover_text(format!(
+                                        "{} credits",
+                                        number_format.format_integer(record.amount)
+                                    ));
+                            });
+                            row.col(|ui| {
+                                let color = match record.status {
+                                    _ if record.is_final(&finality) => Color32::DARK_GREEN,
+                                    WithdrawalStatus::Expired => Color32::RED,
+                                    _ => ui.visuals().text_color(),
+                                };
+                                ui.colored_label(color, record.status.to_string());
+                            });
+                            row.col(|ui| {
+                                ui.label(record.address.as_deref().unwrap_or("-"));
+                            });
+                            row.col(|ui| {
+                                ui.label(
+                                    record
+                                        .updated_at
+                                        .or(record.created_at)
+                                        .map_or("-".to_string(), |timestamp| {
+                                            time_format.format_millis(timestamp)
+                                        }),
+                                );
+                            });
+                            row.col(|ui| {
+                                ui.label(record.expected_completion(&finality));
+                            });
+                        });
+                    }
+                });
+        });
+    }
+}
+
+impl ScreenLike for WithdrawalQueueScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if message_type == MessageType::Error {
+            self.fetching = false;
+        }
+        self.message = Some((message.to_string(), message_type, Utc::now()));
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::Withdrawals(withdrawals) = backend_task_success_result {
+            self.fetching = false;
+            self.withdrawals = withdrawals;
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        self.check_message_expiration();
+
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Identities", AppAction::GoToMainScreen),
+                ("Withdrawals", AppAction::None),
+            ],
+            vec![(
+                "Refresh",
+                DesiredAppAction::BackendTask(self.fetch_action()),
+            )],
+        );
+
+        if !self.fetch_requested {
+            self.fetch_requested = true;
+            self.fetching = true;
+            self.app_context
+                .send(AppCommand::RunCachedTask(self.fetch_action()));
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Withdrawal Queue");
+            ui.add_space(5.0);
+
+            ui.horizontal(|ui| {
+                if ui
+                    .checkbox(&mut self.include_network, "Include network-wide queue")
+                    .on_hover_text("Also fetch the pending withdrawals of all identities")
+                    .changed()
+                {
+                    self.fetching = true;
+                    action |= AppAction::BackendTask(self.fetch_action());
+                }
+                ui.checkbox(&mut self.show_finished, "Show completed and expired");
+            });
+
+            if let Some((message, message_type, _)) = &self.message {
+                let color = match message_type {
+                    MessageType::Error => Color32::RED,
+                    MessageType::Success => Color32::DARK_GREEN,
+                    MessageType::Info => Color32::GRAY,
+                };
+                ui.colored_label(color, message);
+            }
+
+            add_proof_status(ui, &self.app_context, &DOCUMENT_METHODS);
+            let number_format = NumberFormatter::new(&self.app_context.preferences());
+            self.render_summary(ui, &number_format);
+            ui.separator();
+            self.render_table(ui);
+        });
+
+        action
+    }
+}
@@ -0,0 +1,119 @@
+use crate::context::AppContext;
+use crate::model::clock_skew::network_now;
+use crate::model::webhook::{WebhookEvent, WebhookNotification};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How often contests are checked for ones ending without our vote
+const CONTEST_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(15);
+
+impl AppContext {
+    /// Posts the JSON body to the URL, through the proxy when one is set up
+    pub async fn post_webhook(&self, url: &str, body: &serde_json::Value) -> Result<(), String> {
+        self.http_client(WEBHOOK_TIMEOUT)?
+            .post(url)
+            .json(body)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Posts the notification to every webhook wanting its event, in the background. Failures
+    /// are logged, a failing webhook must not fail anything else.
+    pub fn notify_webhooks(self: &Arc<Self>, notification: WebhookNotification) {
+        let settings = self.preferences().webhooks;
+        let webhooks: Vec<_> = settings
+            .webhooks
+            .into_iter()
+            .filter(|webhook| webhook.wants(notification.event))
+            .collect();
+        if webhooks.is_empty() {
+            return;
+        }
+        let app_context = self.clone();
+        let network = self.network_string();
+        let time = chrono::Utc::now().timestamp();
+        tokio::spawn(async move {
+            for webhook in webhooks {
+                let result = match notification.render(&webhook.template, &network, time) {
+                    Ok(body) => app_context.post_webhook(webhook.url.trim(), &body).await,
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = result {
+                    tracing::warn!(
+                        "Failed to notify the webhook {} of {}: {}",
+                        webhook.name,
+                        notification.event.as_str(),
+                        e
+                    );
+                }
+            }
+        });
+    }
+}
+
+/// Decides when contests deserve a webhook notification, so each contest is notified once
+#[derive(Default)]
+pub struct WebhookAlerts {
+    /// Contests already notified as ending un-voted, by network and normalized name
+    notified_contests: HashSet<(String, String)>,
+    last_contest_check: Option<Instant>,
+}
+
+impl WebhookAlerts {
+    /// Notifies of contests ending within the configured hours that none of our identities
+    /// voted on, checked once a minute
+    pub fn check_contests(&mut self, app_context: &Arc<AppContext>) {
+        if self
+            .last_contest_check
+            .is_some_and(|last_check| last_check.elapsed() < CONTEST_CHECK_INTERVAL)
+        {
+            return;
+        }
+        self.last_contest_check = Some(Instant::now());
+        let settings = app_context.preferences().webhooks;
+        if !settings.wants(WebhookEvent::ContestEndingUnvoted) {
+            return;
+        }
+
+        let contested_names = app_context.all_contested_names().unwrap_or_default();
+        let now = network_now().timestamp_millis().max(0) as u64;
+        let warning_millis = settings.contest_ending_hours as u64 * 3_600_000;
+        let network = app_context.network_string();
+        let mut names = vec![];
+        for contested_name in contested_names {
+            let name = &contested_name.normalized_contested_name;
+            let ends_soon = contested_name
+                .end_time
+                .is_some_and(|end_time| end_time > now && end_time - now <= warning_millis);
+            if ends_soon
+                && contested_name.my_votes.is_empty()
+                && self
+                    .notified_contests
+                    .insert((network.clone(), name.clone()))
+            {
+                names.push(name.clone());
+            }
+        }
+        if names.is_empty() {
+            return;
+        }
+        let message = format!(
+            "{} contest(s) end within {} hours without our vote: {}",
+            names.len(),
+            settings.contest_ending_hours,
+            names.join(", ")
+        );
+        app_context.notify_webhooks(
+            WebhookNotification::new(WebhookEvent::ContestEndingUnvoted, message)
+                .with_field("names", names.join(", "))
+                .with_field("count", names.len())
+                .with_field("hours", settings.contest_ending_hours),
+        );
+    }
+}